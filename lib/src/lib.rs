@@ -1,2 +1,3 @@
 pub mod rect;
+pub mod schemas;
 pub mod state;
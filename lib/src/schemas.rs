@@ -0,0 +1,31 @@
+use schemars::{schema::RootSchema, schema_for};
+
+use crate::state::{AppConfig, Placeholder, Settings, Theme, WegItem, WindowManagerLayout};
+
+/// Names of the generated schemas, each one is written as `<name>.schema.json`
+pub const SCHEMA_NAMES: [&str; 6] = [
+    "settings",
+    "placeholder",
+    "theme",
+    "layout",
+    "settings_by_app",
+    "weg_items",
+];
+
+pub fn schema_by_name(name: &str) -> Option<RootSchema> {
+    let schema = match name {
+        "settings" => schema_for!(Settings),
+        "placeholder" => schema_for!(Placeholder),
+        "theme" => schema_for!(Theme),
+        "layout" => schema_for!(WindowManagerLayout),
+        "settings_by_app" => schema_for!(Vec<AppConfig>),
+        "weg_items" => schema_for!(Vec<WegItem>),
+        _ => return None,
+    };
+    Some(schema)
+}
+
+/// Pretty printed JSON Schema of the user editable file with the given name
+pub fn schema_json(name: &str) -> Option<String> {
+    schema_by_name(name).and_then(|schema| serde_json::to_string_pretty(&schema).ok())
+}
@@ -101,6 +101,11 @@ common_item! {
     /// function imgFromExe (exe_path: string, size: number = 16): string
     /// function t(path: string): string
     /// ```
+    /// Values of the registered data sources by name, and a function to run their actions.
+    /// ```js
+    /// const sources: object;
+    /// function sourceAction(source: string, action: string, ...args: any[]): void
+    /// ```
     struct TextToolbarItem {}
 
     /// ## Generic Item Scope
@@ -224,6 +229,37 @@ common_item! {
     /// ```
     struct NotificationsToolbarItem {}
 
+    /// ## File Operations Item Scope
+    /// ```ts
+    /// interface FileOperation {
+    ///     id: number;
+    ///     kind: 'Copy' | 'Move';
+    ///     sources: string[];
+    ///     destination: string;
+    ///     totalBytes: number;
+    ///     doneBytes: number;
+    ///     totalFiles: number;
+    ///     doneFiles: number;
+    ///     current: string | null; // file being copied
+    ///     status: 'Running' | 'Completed' | 'Failed' | 'Cancelled';
+    ///     error: string | null;
+    /// }
+    ///
+    /// const operations: FileOperation[];
+    /// const progress: number; // from 0 to 1 of all the running operations
+    /// ```
+    struct FileOperationsToolbarItem {}
+
+    /// ## Privacy Item Scope
+    /// The item is hidden while no app is using the microphone or the camera.
+    /// ```ts
+    /// const usingMicrophone: boolean;
+    /// const usingCamera: boolean;
+    /// const microphoneMuted: boolean; // the default microphone is muted
+    /// const names: string; // comma separated names of the apps using them
+    /// ```
+    struct PrivacyToolbarItem {}
+
     /// ## Workspace Item Scope
     /// this module does no expand the scope of the item
     struct TrayToolbarItem {}
@@ -270,6 +306,8 @@ pub enum ToolbarItem {
     Network(NetworkToolbarItem),
     Media(MediaToolbarItem),
     Notifications(NotificationsToolbarItem),
+    FileOperations(FileOperationsToolbarItem),
+    Privacy(PrivacyToolbarItem),
     Tray(TrayToolbarItem),
     Device(DeviceToolbarItem),
     Settings(SettingsToolbarItem),
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_alias::serde_alias;
@@ -34,10 +35,82 @@ pub struct Settings {
     pub selected_theme: Vec<String>,
     /// enable or disable dev tools tab in settings
     pub dev_tools: bool,
+    /// disables gpu rendering and backdrop effects on the webviews, enabled automatically
+    /// when the dock or toolbar fail to render
+    pub rendering_fallback: bool,
+    /// only the tray icon and the settings window are started, the rest of the shell can be
+    /// started later from the tray. Same as the `--minimal` flag.
+    pub minimal_mode: bool,
     /// language to use, if null the system locale is used
     pub language: Option<String>,
     /// what virtual desktop implementation will be used, in case Native is not available we use Seelen
     pub virtual_desktop_strategy: VirtualDesktopStrategy,
+    /// touch and touchpad gestures config
+    pub gestures: GesturesSettings,
+    /// windows overview (exposé) config
+    pub overview: OverviewSettings,
+    /// manual window controls config
+    pub window_controls: WindowControlsSettings,
+    /// snap-layout zones config
+    pub zones: ZonesSettings,
+    /// duplicated and projected displays policy
+    pub projection: ProjectionSettings,
+    /// idle detection and actions config
+    pub idle: IdleSettings,
+    /// launcher search config
+    pub launcher: LauncherSettings,
+    /// on-screen display config
+    pub osd: OsdSettings,
+    /// light/dark theme schedule
+    pub theme_schedule: ThemeScheduleSettings,
+    /// desktop icons config
+    pub desktop_icons: DesktopIconsSettings,
+    /// apps relaunched at login config
+    pub session_restore: SessionRestoreSettings,
+    /// pen buttons config
+    pub pen: PenSettings,
+    /// extra mouse buttons and wheel over the dock config
+    pub mouse_buttons: MouseButtonsSettings,
+    /// game mode config
+    pub game_mode: GameModeSettings,
+    /// time and event based automation rules
+    pub automation: AutomationSettings,
+    /// heuristics to ignore transient popups
+    pub popup_filter: PopupFilterSettings,
+    /// local http and websocket api for external tools
+    pub remote_api: RemoteApiSettings,
+    /// publishes presence, focused app category and do not disturb to a mqtt broker
+    pub presence: PresenceSettings,
+    /// state exported to files for widget tools like rainmeter or yasb
+    pub data_bridge: DataBridgeSettings,
+    /// widgets shown before locking the session from Seelen
+    pub lock_screen: LockScreenSettings,
+    /// focus assist schedule and notification exceptions
+    pub do_not_disturb: DoNotDisturbSettings,
+    /// detection and mitigation of conflicting software
+    pub conflicts: ConflictsSettings,
+    /// disk limits of the generated icons and thumbnails
+    pub cache: CacheSettings,
+    /// keyboard layout remembered by window
+    pub keyboard_layout: KeyboardLayoutSettings,
+    /// text expander
+    pub snippets: SnippetsSettings,
+    /// rewrite rules for the window titles shown on the dock, previews and toolbar
+    pub window_titles: WindowTitlesSettings,
+    /// local record of the time spent on each app
+    pub app_usage: AppUsageSettings,
+    /// scheduled sessions where distracting apps are minimized or blocked
+    pub focus_sessions: FocusSessionsSettings,
+    /// brightness of the monitors supporting DDC/CI
+    pub brightness: BrightnessSettings,
+    /// named combinations of audio devices and volumes
+    pub audio_profiles: AudioProfilesSettings,
+    /// what is changed while the presentation mode is active
+    pub presentation_mode: PresentationModeSettings,
+    /// low battery and low disk space alerts
+    pub alerts: AlertsSettings,
+    /// apps launched minimized or hidden into their workspace when Seelen UI starts
+    pub quiet_launch: QuietLaunchSettings,
 }
 
 impl Default for Settings {
@@ -51,8 +124,43 @@ impl Default for Settings {
             window_manager: WindowManagerSettings::default(),
             ahk_variables: AhkVarList::default(),
             dev_tools: false,
+            rendering_fallback: false,
+            minimal_mode: false,
             language: Some(Self::get_system_language()),
             virtual_desktop_strategy: VirtualDesktopStrategy::Native,
+            gestures: GesturesSettings::default(),
+            overview: OverviewSettings::default(),
+            window_controls: WindowControlsSettings::default(),
+            zones: ZonesSettings::default(),
+            projection: ProjectionSettings::default(),
+            idle: IdleSettings::default(),
+            launcher: LauncherSettings::default(),
+            osd: OsdSettings::default(),
+            theme_schedule: ThemeScheduleSettings::default(),
+            desktop_icons: DesktopIconsSettings::default(),
+            session_restore: SessionRestoreSettings::default(),
+            pen: PenSettings::default(),
+            mouse_buttons: MouseButtonsSettings::default(),
+            game_mode: GameModeSettings::default(),
+            automation: AutomationSettings::default(),
+            popup_filter: PopupFilterSettings::default(),
+            remote_api: RemoteApiSettings::default(),
+            presence: PresenceSettings::default(),
+            data_bridge: DataBridgeSettings::default(),
+            lock_screen: LockScreenSettings::default(),
+            do_not_disturb: DoNotDisturbSettings::default(),
+            conflicts: ConflictsSettings::default(),
+            cache: CacheSettings::default(),
+            keyboard_layout: KeyboardLayoutSettings::default(),
+            snippets: SnippetsSettings::default(),
+            window_titles: WindowTitlesSettings::default(),
+            app_usage: AppUsageSettings::default(),
+            focus_sessions: FocusSessionsSettings::default(),
+            brightness: BrightnessSettings::default(),
+            audio_profiles: AudioProfilesSettings::default(),
+            presentation_mode: PresentationModeSettings::default(),
+            alerts: AlertsSettings::default(),
+            quiet_launch: QuietLaunchSettings::default(),
         }
     }
 }
@@ -68,6 +176,52 @@ impl Settings {
             None => "en".to_string(),
         }
     }
+
+    pub fn monitor_by_id(&self, id: &str) -> Option<&Monitor> {
+        self.monitors.iter().find(|m| m.id.as_deref() == Some(id))
+    }
+
+    /// modules that can be turned on/off by name from external tools
+    pub const TOGGLEABLE_MODULES: [&str; 8] = [
+        "toolbar",
+        "weg",
+        "wm",
+        "desktop-icons",
+        "osd",
+        "gestures",
+        "pen",
+        "game-mode",
+    ];
+
+    pub fn module_enabled(&self, name: &str) -> Option<bool> {
+        let enabled = match name {
+            "toolbar" => self.fancy_toolbar.enabled,
+            "weg" => self.seelenweg.enabled,
+            "wm" => self.window_manager.enabled,
+            "desktop-icons" => self.desktop_icons.enabled,
+            "osd" => self.osd.enabled,
+            "gestures" => self.gestures.enabled,
+            "pen" => self.pen.enabled,
+            "game-mode" => self.game_mode.enabled,
+            _ => return None,
+        };
+        Some(enabled)
+    }
+
+    pub fn module_enabled_mut(&mut self, name: &str) -> Option<&mut bool> {
+        let enabled = match name {
+            "toolbar" => &mut self.fancy_toolbar.enabled,
+            "weg" => &mut self.seelenweg.enabled,
+            "wm" => &mut self.window_manager.enabled,
+            "desktop-icons" => &mut self.desktop_icons.enabled,
+            "osd" => &mut self.osd.enabled,
+            "gestures" => &mut self.gestures.enabled,
+            "pen" => &mut self.pen.enabled,
+            "game-mode" => &mut self.game_mode.enabled,
+            _ => return None,
+        };
+        Some(enabled)
+    }
 }
 
 // ============== Fancy Toolbar Settings ==============
@@ -150,6 +304,36 @@ pub struct SeelenWegSettings {
     pub padding: u32,
     /// space between items in px
     pub space_between_items: u32,
+    /// exe names always shown on the dock, even if a rule would skip their windows
+    pub force_show: Vec<String>,
+    /// annotate dock items with the cpu and memory used by their process tree
+    pub resource_usage: bool,
+    /// interval in seconds between resource usage samples
+    pub resource_usage_interval: u64,
+    /// also sample the network throughput of the apps, shown on the usage tooltip
+    pub network_usage: bool,
+    /// ms the cursor has to rest on the edge of the screen to reveal the hidden dock
+    pub reveal_delay: u64,
+    /// ms to wait before hiding the dock after the cursor leaves it
+    pub hide_delay: u64,
+    /// duration in ms of the show and hide animations
+    pub animation_duration: u64,
+    /// max distance in px the cursor can move between checks while revealing the dock,
+    /// faster movements are considered as passing by the edge of the screen
+    pub hover_intent_threshold: u32,
+    /// show suspended uwp apps greyed out instead of skipping them, they are resumed on click
+    pub show_suspended_uwp: bool,
+    /// show the running dot on pinned apps without windows but with a tray icon, as the
+    /// apps minimized to the tray
+    pub show_background_apps: bool,
+    /// max number of rows (or columns on vertical docks) used when the items don't fit
+    /// on the monitor, 1 disables wrapping
+    pub max_rows: u32,
+    /// shrink the items when they don't fit on the monitor even using `max_rows`, the dock
+    /// scrolls if they still don't fit at `min_size`
+    pub auto_scale: bool,
+    /// smallest item size in px reached by `auto_scale`
+    pub min_size: u32,
 }
 
 impl Default for SeelenWegSettings {
@@ -165,6 +349,19 @@ impl Default for SeelenWegSettings {
             margin: 8,
             padding: 8,
             space_between_items: 8,
+            force_show: Vec::new(),
+            resource_usage: false,
+            resource_usage_interval: 2,
+            network_usage: false,
+            reveal_delay: 100,
+            hide_delay: 200,
+            animation_duration: 200,
+            hover_intent_threshold: 10,
+            show_suspended_uwp: false,
+            show_background_apps: false,
+            max_rows: 1,
+            auto_scale: false,
+            min_size: 24,
         }
     }
 }
@@ -210,6 +407,20 @@ pub struct WindowManagerSettings {
     pub floating: FloatingWindowSettings,
     /// default layout
     pub default_layout: String,
+    /// name the workspaces after the app with more windows on them
+    pub auto_workspace_names: bool,
+    /// template for the automatic names, supports `{index}`, `{app}` and `{name}`
+    pub workspace_name_template: String,
+    /// external tiling window manager providing the workspaces, the seelen tiling
+    /// is disabled while one is set. Changes are applied on restart.
+    pub interop: WmInterop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum WmInterop {
+    None,
+    Komorebi,
+    GlazeWM,
 }
 
 impl Default for Border {
@@ -243,9 +454,1228 @@ impl Default for WindowManagerSettings {
             global_work_area_offset: Rect::default(),
             floating: FloatingWindowSettings::default(),
             default_layout: String::from("default.yml"),
+            auto_workspace_names: false,
+            workspace_name_template: String::from("{index}: {app}"),
+            interop: WmInterop::None,
+        }
+    }
+}
+// ============== Gestures Settings ==============
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum GestureAction {
+    None,
+    /// shows the dock/taskbar if it is hidden
+    RevealWeg,
+    SwitchToNextWorkspace,
+    SwitchToPreviousWorkspace,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GesturesSettings {
+    /// enable or disable touch and precision touchpad gestures
+    pub enabled: bool,
+    /// action executed when swiping from the screen edge where the dock is placed
+    pub edge_swipe: GestureAction,
+    /// action executed on a three fingers swipe to the left
+    pub three_fingers_swipe_left: GestureAction,
+    /// action executed on a three fingers swipe to the right
+    pub three_fingers_swipe_right: GestureAction,
+    /// distance in px from the screen edge where a touch is considered an edge swipe
+    pub edge_size: u32,
+    /// min distance in px to recognize a swipe
+    pub swipe_threshold: u32,
+}
+
+impl Default for GesturesSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            edge_swipe: GestureAction::RevealWeg,
+            three_fingers_swipe_left: GestureAction::SwitchToNextWorkspace,
+            three_fingers_swipe_right: GestureAction::SwitchToPreviousWorkspace,
+            edge_size: 20,
+            swipe_threshold: 80,
         }
     }
 }
+
+// ============== Overview Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OverviewSettings {
+    /// open the overview when the cursor stays on the top left corner of a monitor
+    pub hot_corner: bool,
+    /// time in ms the cursor should stay on the corner to open the overview
+    pub hot_corner_delay: u64,
+}
+
+impl Default for OverviewSettings {
+    fn default() -> Self {
+        Self {
+            hot_corner: false,
+            hot_corner_delay: 300,
+        }
+    }
+}
+
+// ============== Window Controls Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum DialogCentering {
+    /// keep the position chosen by the app
+    #[default]
+    Disabled,
+    /// center on the monitor of the owner window
+    OwnerMonitor,
+    /// center on the monitor under the cursor
+    CursorMonitor,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WindowControlsSettings {
+    /// distance in px to move the window on each arrow press in resize mode
+    pub keyboard_move_step: u32,
+    /// distance in px to resize the window on each shift + arrow press in resize mode
+    pub keyboard_resize_step: u32,
+    /// remember the position of the windows by monitor configuration and restore them
+    /// when the app is reopened or the monitors are connected again.
+    pub remember_positions: bool,
+    /// center the newly created dialogs and owned floating windows.
+    pub center_dialogs: DialogCentering,
+}
+
+impl Default for WindowControlsSettings {
+    fn default() -> Self {
+        Self {
+            keyboard_move_step: 20,
+            keyboard_resize_step: 20,
+            remember_positions: false,
+            center_dialogs: DialogCentering::Disabled,
+        }
+    }
+}
+
+// ============== Zones Settings ==============
+
+/// Area of the monitor's work area, all the values are percentages (0 - 100)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Zone {
+    pub fn new(left: f64, top: f64, width: f64, height: f64) -> Self {
+        Self {
+            left,
+            top,
+            width,
+            height,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneLayout {
+    pub name: String,
+    pub zones: Vec<Zone>,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ZonesSettings {
+    /// show the zone picker when dragging a window while holding shift
+    pub enabled: bool,
+    /// available zone layouts
+    pub layouts: Vec<ZoneLayout>,
+    /// layout name to use by monitor id (the device id, or the device name like `DISPLAY1`
+    /// for monitors without it), monitors not listed here will use the first layout
+    pub by_monitor: HashMap<String, String>,
+    /// disable the native Snap Layouts flyout, `Win + Z` moves the focused window to the
+    /// next zone of its monitor instead
+    pub replace_snap_flyout: bool,
+}
+
+impl ZonesSettings {
+    pub fn layout_for(&self, monitor: &str) -> Option<&ZoneLayout> {
+        self.by_monitor
+            .get(monitor)
+            .and_then(|name| self.layouts.iter().find(|l| &l.name == name))
+            .or_else(|| self.layouts.first())
+    }
+}
+
+impl Default for ZonesSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            layouts: vec![
+                ZoneLayout {
+                    name: "Columns".to_string(),
+                    zones: vec![
+                        Zone::new(0.0, 0.0, 33.33, 100.0),
+                        Zone::new(33.33, 0.0, 33.34, 100.0),
+                        Zone::new(66.67, 0.0, 33.33, 100.0),
+                    ],
+                },
+                ZoneLayout {
+                    name: "Priority Grid".to_string(),
+                    zones: vec![
+                        Zone::new(0.0, 0.0, 25.0, 100.0),
+                        Zone::new(25.0, 0.0, 50.0, 100.0),
+                        Zone::new(75.0, 0.0, 25.0, 100.0),
+                    ],
+                },
+                ZoneLayout {
+                    name: "Grid".to_string(),
+                    zones: vec![
+                        Zone::new(0.0, 0.0, 50.0, 50.0),
+                        Zone::new(50.0, 0.0, 50.0, 50.0),
+                        Zone::new(0.0, 50.0, 50.0, 50.0),
+                        Zone::new(50.0, 50.0, 50.0, 50.0),
+                    ],
+                },
+            ],
+            by_monitor: HashMap::new(),
+            replace_snap_flyout: false,
+        }
+    }
+}
+
+// ============== Projection Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProjectionSettings {
+    /// hide the dock on monitors that are duplicated or projected (miracast)
+    pub hide_weg_on_projected: bool,
+    /// while any monitor is duplicated or projected, only show the toolbar on the primary monitor
+    pub toolbar_on_primary_only: bool,
+}
+
+// ============== Idle Settings ==============
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum IdleAction {
+    /// lowers the opacity of the dock/taskbar
+    DimWeg,
+    /// hides the dock/taskbar as if it was overlaped
+    AutoHideWeg,
+    /// advances the native wallpaper slideshow periodically
+    WallpaperSlideshow,
+    /// runs the rule command
+    RunCommand,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleRule {
+    /// seconds without user input before the action is triggered
+    pub after: u64,
+    pub action: IdleAction,
+    /// program to run followed by its arguments, only used by `RunCommand`
+    pub command: Option<Vec<String>>,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IdleSettings {
+    /// enable or disable the idle monitor
+    pub enabled: bool,
+    /// actions to trigger when the user is idle, reverted on the next input
+    pub rules: Vec<IdleRule>,
+    /// seconds between wallpapers while the slideshow action is active
+    pub slideshow_interval: u64,
+}
+
+impl Default for IdleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rules: vec![
+                IdleRule {
+                    after: 120,
+                    action: IdleAction::DimWeg,
+                    command: None,
+                },
+                IdleRule {
+                    after: 300,
+                    action: IdleAction::AutoHideWeg,
+                    command: None,
+                },
+            ],
+            slideshow_interval: 30,
+        }
+    }
+}
+
+// ============== Launcher Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum FileSearchBackend {
+    /// Everything when it is running, Windows Search otherwise
+    #[default]
+    Auto,
+    /// voidtools Everything, requires it to be installed and running
+    Everything,
+    /// files indexed by Windows Search
+    WindowsSearch,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LauncherSettings {
+    /// backend used to search files by name
+    pub file_search: FileSearchBackend,
+}
+
+// ============== OSD Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum OsdPosition {
+    Top,
+    #[default]
+    Bottom,
+    Center,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OsdSettings {
+    /// enable or disable the on-screen display
+    pub enabled: bool,
+    /// show the volume of the default output device when it is changed outside Seelen
+    pub volume: bool,
+    /// show the screen brightness when it is changed by hardware keys
+    pub brightness: bool,
+    /// show the keyboard layout when it is switched
+    pub keyboard_layout: bool,
+    pub position: OsdPosition,
+    /// milliseconds the osd stays visible after the last change
+    pub duration: u64,
+    /// intercept the volume keys so the changes use the steps below and only the Seelen osd
+    /// is shown instead of the Windows flyout
+    pub intercept_keys: bool,
+    /// percent changed by each press of the volume keys
+    pub volume_step: u32,
+    /// percent changed by each brightness step made by Seelen, as the mouse wheel actions
+    pub brightness_step: u32,
+    /// exponent of the volume curve by output device name or id, 1.0 is linear and higher
+    /// values give finer steps at low volumes, as needed by some headphones
+    pub volume_curves: HashMap<String, f32>,
+}
+
+impl Default for OsdSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            volume: true,
+            brightness: true,
+            keyboard_layout: true,
+            position: OsdPosition::Bottom,
+            duration: 1500,
+            intercept_keys: false,
+            volume_step: 2,
+            brightness_step: 5,
+            volume_curves: HashMap::new(),
+        }
+    }
+}
+
+// ============== Theme Schedule Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum ThemeScheduleMode {
+    /// follow the light/dark mode of the Windows system theme
+    #[default]
+    FollowSystem,
+    /// switch at the configured local times
+    Time,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ThemeScheduleSettings {
+    /// enable or disable the automatic switch between light and dark themes
+    pub enabled: bool,
+    pub mode: ThemeScheduleMode,
+    /// themes selected while light, same format as `selected_theme`
+    pub light_themes: Vec<String>,
+    /// themes selected while dark, same format as `selected_theme`
+    pub dark_themes: Vec<String>,
+    /// local time in `HH:MM` format when the light themes are selected on `Time` mode
+    pub light_start: String,
+    /// local time in `HH:MM` format when the dark themes are selected on `Time` mode
+    pub dark_start: String,
+    /// also switch the Windows apps theme on `Time` mode
+    pub sync_windows_apps: bool,
+}
+
+impl Default for ThemeScheduleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: ThemeScheduleMode::FollowSystem,
+            light_themes: vec!["default".to_string()],
+            dark_themes: vec!["default".to_string()],
+            light_start: "07:00".to_string(),
+            dark_start: "19:00".to_string(),
+            sync_windows_apps: false,
+        }
+    }
+}
+
+// ============== Desktop Icons Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DesktopIconsSettings {
+    /// render the desktop icons, always enabled when running as the shell
+    pub enabled: bool,
+    /// size of the icons in px
+    pub icon_size: u32,
+    /// size of the cells of the grid where the icons are placed in px
+    pub grid_size: u32,
+}
+
+impl Default for DesktopIconsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            icon_size: 48,
+            grid_size: 96,
+        }
+    }
+}
+
+// ============== Session Restore Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SessionRestoreSettings {
+    /// relaunch the apps running at logoff/shutdown on the next login
+    pub enabled: bool,
+    /// executable names never relaunched, e.g. `setup.exe`
+    pub exclude: Vec<String>,
+}
+
+// ============== Pen Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum PenAction {
+    /// let Windows handle the button (Windows Ink workspace)
+    #[default]
+    System,
+    /// opens the screen region capture
+    ScreenClip,
+    /// opens the start menu
+    StartMenu,
+    /// opens the workspaces overview
+    Overview,
+    /// runs the binding command, e.g. a notes app
+    RunCommand,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PenBinding {
+    pub action: PenAction,
+    /// program to run followed by its arguments, only used by `RunCommand`
+    pub command: Option<Vec<String>>,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PenSettings {
+    /// handle the pen top button instead of Windows
+    pub enabled: bool,
+    pub click: PenBinding,
+    pub double_click: PenBinding,
+    pub long_press: PenBinding,
+}
+
+impl Default for PenSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            click: PenBinding {
+                action: PenAction::ScreenClip,
+                command: None,
+            },
+            double_click: PenBinding::default(),
+            long_press: PenBinding {
+                action: PenAction::StartMenu,
+                command: None,
+            },
+        }
+    }
+}
+
+// ============== Mouse Buttons Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum MouseButtonAction {
+    /// let the app under the cursor handle the button
+    #[default]
+    None,
+    /// opens the start menu
+    StartMenu,
+    /// opens the workspaces overview
+    Overview,
+    /// opens the mini task manager
+    TaskManager,
+    SwitchToNextWorkspace,
+    SwitchToPreviousWorkspace,
+    /// runs the binding command
+    RunCommand,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MouseButtonBinding {
+    pub action: MouseButtonAction,
+    /// program to run followed by its arguments, only used by `RunCommand`
+    pub command: Option<Vec<String>>,
+}
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum BarWheelAction {
+    /// let the dock or toolbar handle the wheel
+    #[default]
+    None,
+    /// wheel up increases the volume and wheel down decreases it
+    Volume,
+    /// wheel up increases the brightness of the main monitor and wheel down decreases it
+    Brightness,
+    /// focuses the next or previous open app of the dock
+    SwitchApps,
+    SwitchWorkspaces,
+}
+
+/// The toolbar is split in three regions of the same width
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ToolbarWheelRegions {
+    pub left: BarWheelAction,
+    pub center: BarWheelAction,
+    pub right: BarWheelAction,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MouseButtonsSettings {
+    /// handle the extra mouse buttons and the wheel over the dock and the toolbar
+    pub enabled: bool,
+    /// back button (XButton1)
+    pub x1: MouseButtonBinding,
+    /// forward button (XButton2)
+    pub x2: MouseButtonBinding,
+    /// action executed when the wheel is used with the cursor over the dock
+    pub wheel_on_weg: BarWheelAction,
+    /// actions executed when the wheel is used with the cursor over the toolbar
+    pub wheel_on_toolbar: ToolbarWheelRegions,
+}
+
+impl Default for MouseButtonsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            x1: MouseButtonBinding::default(),
+            x2: MouseButtonBinding {
+                action: MouseButtonAction::StartMenu,
+                command: None,
+            },
+            wheel_on_weg: BarWheelAction::Volume,
+            wheel_on_toolbar: ToolbarWheelRegions {
+                left: BarWheelAction::SwitchWorkspaces,
+                center: BarWheelAction::None,
+                right: BarWheelAction::Volume,
+            },
+        }
+    }
+}
+
+// ============== Game Mode Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GameModeSettings {
+    /// suspend animations, polling and noisy events while a game is in foreground
+    pub enabled: bool,
+    /// treat any fullscreen app (exclusive or borderless) as a game
+    pub detect_fullscreen: bool,
+    /// executable names always treated as games, e.g. `eldenring.exe`
+    pub games: Vec<String>,
+}
+
+impl Default for GameModeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            detect_fullscreen: true,
+            games: Vec::new(),
+        }
+    }
+}
+
+// ============== Automation Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AutomationSettings {
+    /// enable or disable the evaluation of the rules
+    pub enabled: bool,
+    /// one rule per item with the format `<trigger> => <action>; <action>...`
+    ///
+    /// triggers: `at HH:MM`, `on ac`, `on battery`, `on monitor connected`, `on launch <exe>`
+    ///
+    /// actions: `theme <theme>, <theme>...`, `profile <name>`, `weg autohide on|off|toggle`,
+    /// `run <command>`
+    ///
+    /// example: `at 19:00 => theme default, dark; weg autohide on`
+    pub rules: Vec<String>,
+}
+
+// ============== Popup Filter Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PopupFilterSettings {
+    /// keep splash screens, overlays and other transient popups out of the dock and tiling
+    pub enabled: bool,
+    /// window classes always ignored, `*` matches any text, e.g. `*Splash*`
+    pub ignored_classes: Vec<String>,
+    /// borderless popups closed before this ms are counted as transient, apps that open
+    /// several of them get their next borderless popups ignored
+    pub short_lived_threshold: u64,
+}
+
+impl Default for PopupFilterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ignored_classes: vec![
+                "IME".to_string(),
+                "MSCTFIME UI".to_string(),
+                "CiceroUIWndFrame".to_string(),
+                "*SplashScreen*".to_string(),
+            ],
+            short_lived_threshold: 1500,
+        }
+    }
+}
+
+// ============== Remote API Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RemoteApiSettings {
+    /// serve the http and websocket api on localhost
+    pub enabled: bool,
+    pub port: u16,
+    /// origins of the web pages allowed to use the api, e.g. `http://localhost:3000`.
+    /// Clients that are not browsers don't send an origin and only need the token.
+    pub allowed_origins: Vec<String>,
+}
+
+impl Default for RemoteApiSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 5770,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+// ============== Presence Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PresenceSettings {
+    /// publish the presence state to the mqtt broker
+    pub enabled: bool,
+    /// hostname or ip of the mqtt broker
+    pub host: String,
+    pub port: u16,
+    /// connect using TLS, verifying the certificate of the broker with the system roots
+    pub tls: bool,
+    /// empty to connect without authentication. The password is read from `presence.password`
+    /// inside the app data folder, so it is not shared with the webviews as the settings are
+    pub username: String,
+    /// topics are published under this prefix, e.g. `seelen/presence`
+    pub topic: String,
+    /// seconds without input to report the user as idle
+    pub idle_after: u64,
+}
+
+impl Default for PresenceSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "localhost".to_string(),
+            port: 8883,
+            tls: true,
+            username: String::new(),
+            topic: "seelen".to_string(),
+            idle_after: 300,
+        }
+    }
+}
+
+// ============== Data Bridge Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DataBridgeSettings {
+    /// write the exports periodically
+    pub enabled: bool,
+    /// seconds between writes, files are only written if their content changed
+    pub interval: u64,
+    pub exports: Vec<DataExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExport {
+    /// absolute path, or relative to the `data_bridge` folder on the app data
+    pub path: String,
+    pub format: DataExportFormat,
+    /// data written on the file, all if empty
+    #[serde(default)]
+    pub data: Vec<DataBridgeField>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum DataExportFormat {
+    Json,
+    /// `[Variables]` section to be included on skins with `@Include`
+    Rainmeter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum DataBridgeField {
+    NowPlaying,
+    Workspaces,
+    System,
+}
+
+impl Default for DataBridgeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: 2,
+            exports: vec![
+                DataExport {
+                    path: "state.json".to_string(),
+                    format: DataExportFormat::Json,
+                    data: Vec::new(),
+                },
+                DataExport {
+                    path: "state.inc".to_string(),
+                    format: DataExportFormat::Rainmeter,
+                    data: Vec::new(),
+                },
+            ],
+        }
+    }
+}
+
+// ============== Lock Screen Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LockScreenSettings {
+    /// show the companion overlay before locking, if disabled the session is locked directly
+    pub enabled: bool,
+    pub clock: bool,
+    /// current media session with its thumbnail
+    pub media: bool,
+    /// milliseconds the overlay is shown before the session is locked
+    pub delay: u64,
+}
+
+impl Default for LockScreenSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            clock: true,
+            media: true,
+            delay: 1500,
+        }
+    }
+}
+
+// ============== Do Not Disturb Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum FocusAssistLevel {
+    /// only notifications of the priority list of Windows are shown
+    #[default]
+    PriorityOnly,
+    AlarmsOnly,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DoNotDisturbSettings {
+    /// enable or disable the do not disturb scheduler
+    pub enabled: bool,
+    /// local time in `HH:MM` format when the quiet hours start, empty to disable them
+    pub start: String,
+    /// local time in `HH:MM` format when the quiet hours end
+    pub end: String,
+    /// executable names that enable do not disturb while fullscreen, e.g. `POWERPNT.EXE`
+    pub fullscreen_apps: Vec<String>,
+    /// also enable it while any app is presenting
+    pub detect_presentations: bool,
+    pub level: FocusAssistLevel,
+    /// app names whose notifications are not silenced on the notification center
+    pub exceptions: Vec<String>,
+}
+
+impl Default for DoNotDisturbSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start: "22:00".to_string(),
+            end: "07:00".to_string(),
+            fullscreen_apps: vec!["POWERPNT.EXE".to_string()],
+            detect_presentations: true,
+            level: FocusAssistLevel::PriorityOnly,
+            exceptions: Vec::new(),
+        }
+    }
+}
+
+// ============== Conflicting Software Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum ConflictOverride {
+    /// mitigate the conflict only while it is detected
+    #[default]
+    Auto,
+    /// never mitigate the conflict, also silences its warning
+    Ignore,
+    /// always mitigate the conflict, even if not detected
+    Force,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConflictsSettings {
+    /// detect known conflicting software on startup
+    pub enabled: bool,
+    /// overrides by conflict id, e.g. `explorer-patcher: Ignore`. Conflicts with an override
+    /// are not warned anymore.
+    pub overrides: HashMap<String, ConflictOverride>,
+}
+
+impl Default for ConflictsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+// ============== Cache Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CacheSettings {
+    /// max size in MB of the extracted icons, the least recently used are removed first.
+    /// Custom icons placed by the user are never removed.
+    pub icons_max_size: u32,
+    /// max size in MB of the media thumbnails and window previews
+    pub thumbnails_max_size: u32,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self {
+            icons_max_size: 256,
+            thumbnails_max_size: 128,
+        }
+    }
+}
+
+// ============== Keyboard Layout Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct KeyboardLayoutSettings {
+    /// remember the keyboard layout of each window and restore it when the window is focused
+    pub per_window: bool,
+}
+
+// ============== Snippets Settings ==============
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Snippet {
+    /// typed text replaced by the snippet, e.g. `;sig`
+    pub abbreviation: String,
+    pub text: String,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SnippetsSettings {
+    /// expand the abbreviations while typing
+    pub enabled: bool,
+    pub snippets: Vec<Snippet>,
+    /// executable names where the abbreviations are not expanded, e.g. `code.exe`
+    pub excluded_apps: Vec<String>,
+}
+
+// ============== Window Titles Settings ==============
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TitleRule {
+    /// text to find, or a regular expression if `regex` is enabled
+    pub find: String,
+    /// replacement of the found text, `$1` references a capture group when using `regex`
+    pub replace: String,
+    pub regex: bool,
+    // cache
+    #[serde(skip)]
+    pub compiled: Option<Regex>,
+}
+
+impl TitleRule {
+    pub fn cache_regex(&mut self) {
+        self.compiled = match self.regex {
+            true => Regex::new(&self.find).ok(),
+            false => None,
+        };
+    }
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WindowTitlesSettings {
+    /// applied in order, e.g. find ` - Google Chrome` with an empty replacement
+    pub rules: Vec<TitleRule>,
+    /// titles with more characters are truncated with an ellipsis, 0 disables the limit
+    pub max_length: usize,
+}
+
+impl WindowTitlesSettings {
+    pub fn cache_regex(&mut self) {
+        self.rules.iter_mut().for_each(TitleRule::cache_regex);
+    }
+}
+
+// ============== App Usage Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AppUsageSettings {
+    /// record the foreground time of the apps, the data never leaves this device
+    pub enabled: bool,
+    /// days of history kept, older days are removed
+    pub retention_days: u32,
+}
+
+impl Default for AppUsageSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_days: 90,
+        }
+    }
+}
+
+// ============== Focus Sessions Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum FocusBlockMode {
+    /// the windows of the blocked apps are minimized once the grace period ends
+    #[default]
+    Minimize,
+    /// as `Minimize`, but after that the app can't be activated again until the session ends
+    Block,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FocusSession {
+    /// local time in `HH:MM` format
+    pub start: String,
+    /// local time in `HH:MM` format, sessions ending before they start cross midnight
+    pub end: String,
+    /// days of the week when the session starts, 0 is Sunday. Empty for every day.
+    pub days: Vec<u8>,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FocusSessionsSettings {
+    /// enable or disable the focus sessions scheduler
+    pub enabled: bool,
+    pub sessions: Vec<FocusSession>,
+    /// executable names blocked during the sessions, e.g. `steam.exe`
+    pub blocked_apps: Vec<String>,
+    pub mode: FocusBlockMode,
+    /// seconds a blocked app is tolerated after the warning toast
+    pub grace_period: u64,
+}
+
+impl Default for FocusSessionsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sessions: Vec::new(),
+            blocked_apps: Vec::new(),
+            mode: FocusBlockMode::Minimize,
+            grace_period: 10,
+        }
+    }
+}
+
+// ============== Brightness Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BrightnessSettings {
+    /// changing the brightness of a monitor applies the same percent to all of them,
+    /// including the steps of the brightness keys
+    pub linked: bool,
+}
+
+// ============== Audio Profiles Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AudioProfile {
+    pub name: String,
+    /// endpoint id of the output device set as default, the current one is kept if none
+    pub output: Option<String>,
+    /// endpoint id of the input device set as default, the current one is kept if none
+    pub input: Option<String>,
+    /// volume of the output device between 0 and 1
+    pub volume: Option<f32>,
+    /// volume of the input device between 0 and 1
+    pub input_volume: Option<f32>,
+}
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum AudioProfileTrigger {
+    #[default]
+    Connected,
+    Disconnected,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AudioProfileRule {
+    /// part of the name of an audio device or of the id of an usb device, case insensitive
+    pub device: String,
+    pub trigger: AudioProfileTrigger,
+    /// name of the profile applied when the device is connected or disconnected
+    pub profile: String,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AudioProfilesSettings {
+    pub profiles: Vec<AudioProfile>,
+    /// profiles applied automatically, the first matching rule wins
+    pub rules: Vec<AudioProfileRule>,
+}
+
+// ============== Presentation Mode Settings ==============
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PresentationModeSettings {
+    /// keep the system and the display awake
+    pub keep_awake: bool,
+    /// enable do not disturb
+    pub do_not_disturb: bool,
+    /// hide the dock unless hovered
+    pub hide_weg: bool,
+    /// disable the minimize and maximize animations of the windows
+    pub disable_animations: bool,
+    /// image set as wallpaper, the current one is kept if none
+    pub wallpaper: Option<String>,
+    /// settings profile applied, the previous settings are restored on exit
+    pub profile: Option<String>,
+}
+
+impl Default for PresentationModeSettings {
+    fn default() -> Self {
+        Self {
+            keep_awake: true,
+            do_not_disturb: true,
+            hide_weg: true,
+            disable_animations: true,
+            wallpaper: None,
+            profile: None,
+        }
+    }
+}
+
+// ============== Alerts Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum AlertKind {
+    /// charge of the battery while discharging
+    #[default]
+    Battery,
+    /// free space of a fixed drive
+    DiskFree,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AlertThreshold {
+    pub enabled: bool,
+    pub kind: AlertKind,
+    /// the alert is raised at or below this percentage
+    pub percent: u8,
+    /// drive letter like `C:` checked by disk alerts, all the fixed drives if none
+    pub drive: Option<String>,
+    /// minutes until an alert not acknowledged is raised again
+    pub snooze_minutes: u64,
+}
+
+impl Default for AlertThreshold {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            kind: AlertKind::Battery,
+            percent: 20,
+            drive: None,
+            snooze_minutes: 15,
+        }
+    }
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AlertsSettings {
+    pub enabled: bool,
+    /// only the lowest crossed threshold of each battery or drive is raised
+    pub thresholds: Vec<AlertThreshold>,
+}
+
+impl Default for AlertsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            thresholds: vec![
+                AlertThreshold::default(),
+                AlertThreshold {
+                    percent: 10,
+                    snooze_minutes: 5,
+                    ..Default::default()
+                },
+                AlertThreshold {
+                    kind: AlertKind::DiskFree,
+                    percent: 10,
+                    snooze_minutes: 240,
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+}
+
+// ============== Quiet Launch Settings ==============
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum QuietLaunchState {
+    /// minimized without being activated, shown on the dock
+    #[default]
+    Minimized,
+    /// hidden, for apps living on the tray
+    Hidden,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct QuietLaunchApp {
+    /// executable or shortcut to be launched, skipped if the executable is already running
+    pub path: String,
+    pub args: String,
+    /// index of the workspace where the windows are sent, if none the workspace bound on the
+    /// app configurations is used and if there is none the windows stay on the current one
+    pub workspace: Option<usize>,
+    pub state: QuietLaunchState,
+}
+
+#[serde_alias(SnakeCase)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct QuietLaunchSettings {
+    pub enabled: bool,
+    /// seconds to wait after the start of Seelen UI, so the apps don't compete with the shell
+    pub delay: u64,
+    /// seconds the new windows of each app are placed, windows shown later are left alone
+    pub timeout: u64,
+    pub apps: Vec<QuietLaunchApp>,
+}
+
+impl Default for QuietLaunchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            delay: 5,
+            timeout: 30,
+            apps: Vec::new(),
+        }
+    }
+}
+
 // ============== Settings by Monitor ==============
 
 #[serde_alias(SnakeCase)]
@@ -256,12 +1686,20 @@ pub struct Workspace {
     pub layout: String,
     pub padding: Option<f64>,
     pub gap: Option<f64>,
+    /// wallpaper set on the monitor while this workspace is active
+    pub wallpaper: Option<String>,
+    /// hex color exposed to the themes as `--config-workspace-accent-color`, only the
+    /// workspaces of the primary monitor are taken into account
+    pub accent: Option<String>,
 }
 
 #[serde_alias(SnakeCase)]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, rename_all = "camelCase")]
 pub struct Monitor {
+    /// stable device id of the monitor, entries without id are bound to the
+    /// connected monitors in order and then persisted
+    pub id: Option<String>,
     pub workspaces: Vec<Workspace>,
     pub work_area_offset: Option<Rect>,
 }
@@ -273,6 +1711,8 @@ impl Default for Workspace {
             layout: "BSP".to_string(),
             padding: None,
             gap: None,
+            wallpaper: None,
+            accent: None,
         }
     }
 }
@@ -280,6 +1720,7 @@ impl Default for Workspace {
 impl Default for Monitor {
     fn default() -> Self {
         Self {
+            id: None,
             workspaces: vec![Workspace::default()],
             work_area_offset: None,
         }
@@ -378,7 +1819,24 @@ define_struct_and_hashmap![
     send_to_workspace_6,
     send_to_workspace_7,
     send_to_workspace_8,
-    send_to_workspace_9
+    send_to_workspace_9,
+    toggle_overview,
+    toggle_topmost,
+    increase_opacity,
+    decrease_opacity,
+    toggle_shade,
+    resize_mode,
+    toggle_emoji_picker,
+    undo_last_action,
+    toggle_task_manager,
+    toggle_cursor_lock,
+    find_cursor,
+    send_to_monitor_left,
+    send_to_monitor_right,
+    send_workspace_to_next_monitor,
+    toggle_color_picker,
+    next_audio_profile,
+    toggle_mic_mute
 ];
 
 impl Default for AhkVarList {
@@ -430,6 +1888,23 @@ impl Default for AhkVarList {
             send_to_workspace_7: AhkVar::new("Win + Shift + 8", "#+8"),
             send_to_workspace_8: AhkVar::new("Win + Shift + 9", "#+9"),
             send_to_workspace_9: AhkVar::new("Win + Shift + 0", "#+0"),
+            toggle_overview: AhkVar::new("Win + Alt + Tab", "#!Tab"),
+            toggle_topmost: AhkVar::new("Win + Control + T", "#^t"),
+            increase_opacity: AhkVar::new("Win + Control + =", "#^="),
+            decrease_opacity: AhkVar::new("Win + Control + -", "#^-"),
+            toggle_shade: AhkVar::new("Win + Control + R", "#^r"),
+            resize_mode: AhkVar::new("Win + Control + M", "#^m"),
+            toggle_emoji_picker: AhkVar::new("Win + Alt + .", "#!."),
+            undo_last_action: AhkVar::new("Win + Control + Z", "#^z"),
+            toggle_task_manager: AhkVar::new("Control + Shift + Escape", "^+Esc"),
+            toggle_cursor_lock: AhkVar::new("Win + Alt + L", "#!l"),
+            find_cursor: AhkVar::new("Win + Alt + F", "#!f"),
+            send_to_monitor_left: AhkVar::new("Win + Shift + Left", "#+Left"),
+            send_to_monitor_right: AhkVar::new("Win + Shift + Right", "#+Right"),
+            send_workspace_to_next_monitor: AhkVar::new("Win + Alt + Shift + Right", "#!+Right"),
+            toggle_color_picker: AhkVar::new("Win + Shift + C", "#+c"),
+            next_audio_profile: AhkVar::new("Win + Alt + A", "#!a"),
+            toggle_mic_mute: AhkVar::new("Win + Alt + M", "#!m"),
         }
     }
 }
@@ -3,6 +3,26 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_alias::serde_alias;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowCorners {
+    /// let the system decide
+    Default,
+    Round,
+    RoundSmall,
+    Square,
+}
+
+/// Applied to the dock or the toolbar while a window of the app is focused
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusedAppBehavior {
+    /// hide it as when the window overlaps it, ignoring the hide mode
+    Hide,
+    /// reduce its opacity
+    Dim,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AppExtraFlag {
@@ -16,6 +36,10 @@ pub enum AppExtraFlag {
     Pinned,
     /// Hide this app on the dock/taskbar.
     Hidden,
+    /// Keep the windows of this app always on top of other windows.
+    Topmost,
+    /// Center the dialogs of this app, even if it is disabled globally.
+    CenterDialogs,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -145,6 +169,22 @@ pub struct AppConfig {
     /// extra specific options/settings for the app
     #[serde(default)]
     pub options: Vec<AppExtraFlag>,
+    /// opacity (alpha value from 0 to 255) to be applied to the windows of this app
+    #[serde(default)]
+    pub opacity: Option<u8>,
+    /// enables or removes the acrylic backdrop of the windows of this app,
+    /// only visible on apps that draw a transparent background
+    #[serde(default)]
+    pub acrylic: Option<bool>,
+    /// corners of the windows of this app (Windows 11 only)
+    #[serde(default)]
+    pub corners: Option<WindowCorners>,
+    /// behavior of the dock while a window of this app is focused
+    #[serde(default)]
+    pub weg_while_focused: Option<FocusedAppBehavior>,
+    /// behavior of the toolbar while a window of this app is focused
+    #[serde(default)]
+    pub toolbar_while_focused: Option<FocusedAppBehavior>,
     /// is this config bundled with seelen ui.
     #[serde(default)]
     pub is_bundled: bool,
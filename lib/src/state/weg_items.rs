@@ -7,6 +7,12 @@ pub struct PinnedWegItem {
     exe: String,
     /// command to open the app using explorer.exe (uwp apps starts with `shell:AppsFolder`)
     execution_path: String,
+    /// id of the workspace (virtual desktop) where the item is shown, all workspaces if none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace: Option<String>,
+    /// launch the app elevated, set after a successful elevated launch
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    run_as_admin: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -17,16 +23,46 @@ pub struct TemporalPinnedWegItem {
     execution_path: String,
 }
 
+/// User defined button, runs a command and/or emits an event for plugins when clicked
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CustomWegButton {
+    /// unique identifier of the button, also sent as payload of the emitted event
+    pub id: String,
+    /// name of the icon to show, from the icon set used by the UI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// text shown on hover
+    #[serde(default)]
+    pub tooltip: String,
+    /// command to run on click, executed using `cmd /C`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// name of the event to emit on click
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type")]
 pub enum WegItem {
     PinnedApp(PinnedWegItem),
     TemporalPin(TemporalPinnedWegItem),
     Separator,
+    /// flexible space that grows to fill the free space of its side
+    Spacer,
+    CustomButton(CustomWegButton),
     Media,
     StartMenu,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WegItemsSide {
+    Left,
+    Center,
+    Right,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct WegItems {
@@ -35,6 +71,149 @@ pub struct WegItems {
     right: Vec<WegItem>,
 }
 
+impl PinnedWegItem {
+    pub fn exe(&self) -> &str {
+        &self.exe
+    }
+
+    pub fn execution_path(&self) -> &str {
+        &self.execution_path
+    }
+
+    pub fn run_as_admin(&self) -> bool {
+        self.run_as_admin
+    }
+}
+
+impl WegItems {
+    /// pinned apps of all the sides, in order from left to right
+    pub fn pinned_apps(&self) -> Vec<&PinnedWegItem> {
+        self.left
+            .iter()
+            .chain(self.center.iter())
+            .chain(self.right.iter())
+            .filter_map(|item| match item {
+                WegItem::PinnedApp(app) => Some(app),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// number of pinned apps on all the sides
+    pub fn pinned_count(&self) -> usize {
+        self.left
+            .iter()
+            .chain(self.center.iter())
+            .chain(self.right.iter())
+            .filter(|item| matches!(item, WegItem::PinnedApp(_)))
+            .count()
+    }
+
+    /// execution paths of the pinned apps, folders are also pinned as apps
+    pub fn pinned_execution_paths(&self) -> Vec<&str> {
+        self.left
+            .iter()
+            .chain(self.center.iter())
+            .chain(self.right.iter())
+            .filter_map(|item| match item {
+                WegItem::PinnedApp(app) => Some(app.execution_path.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// pinned app launched by the execution path, if any
+    pub fn pinned_app(&self, execution_path: &str) -> Option<&PinnedWegItem> {
+        self.pinned_apps()
+            .into_iter()
+            .find(|app| app.execution_path == execution_path)
+    }
+
+    /// sets the elevation preference of the pinned apps with the given execution path
+    pub fn set_run_as_admin(&mut self, execution_path: &str, enabled: bool) -> bool {
+        let mut found = false;
+        for side in [
+            WegItemsSide::Left,
+            WegItemsSide::Center,
+            WegItemsSide::Right,
+        ] {
+            for item in self.side_mut(side) {
+                if let WegItem::PinnedApp(app) = item {
+                    if app.execution_path == execution_path {
+                        app.run_as_admin = enabled;
+                        found = true;
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    pub fn side(&self, side: WegItemsSide) -> &Vec<WegItem> {
+        match side {
+            WegItemsSide::Left => &self.left,
+            WegItemsSide::Center => &self.center,
+            WegItemsSide::Right => &self.right,
+        }
+    }
+
+    pub fn side_mut(&mut self, side: WegItemsSide) -> &mut Vec<WegItem> {
+        match side {
+            WegItemsSide::Left => &mut self.left,
+            WegItemsSide::Center => &mut self.center,
+            WegItemsSide::Right => &mut self.right,
+        }
+    }
+
+    /// adds the item at the end of the side
+    pub fn push(&mut self, side: WegItemsSide, item: WegItem) {
+        self.side_mut(side).push(item);
+    }
+
+    /// removes the item at the index of the side, if it exists
+    pub fn remove(&mut self, side: WegItemsSide, index: usize) -> Option<WegItem> {
+        let items = self.side_mut(side);
+        (index < items.len()).then(|| items.remove(index))
+    }
+
+    /// removes the pinned apps with the given execution path from all the sides
+    pub fn remove_pinned_app(&mut self, execution_path: &str) -> bool {
+        let mut removed = false;
+        for side in [
+            WegItemsSide::Left,
+            WegItemsSide::Center,
+            WegItemsSide::Right,
+        ] {
+            self.side_mut(side).retain(|item| {
+                let is_target = matches!(
+                    item,
+                    WegItem::PinnedApp(app) if app.execution_path == execution_path
+                );
+                removed |= is_target;
+                !is_target
+            });
+        }
+        removed
+    }
+
+    /// removes the custom button with the given id from all the sides
+    pub fn remove_custom_button(&mut self, id: &str) -> bool {
+        let mut removed = false;
+        for side in [
+            WegItemsSide::Left,
+            WegItemsSide::Center,
+            WegItemsSide::Right,
+        ] {
+            self.side_mut(side).retain(|item| {
+                let is_target = matches!(item, WegItem::CustomButton(button) if button.id == id);
+                removed |= is_target;
+                !is_target
+            });
+        }
+        removed
+    }
+}
+
 impl Default for WegItems {
     fn default() -> Self {
         Self {
@@ -42,6 +221,8 @@ impl Default for WegItems {
             center: vec![WegItem::PinnedApp(PinnedWegItem {
                 exe: "C:\\Windows\\explorer.exe".to_string(),
                 execution_path: "C:\\Windows\\explorer.exe".to_string(),
+                workspace: None,
+                run_as_admin: false,
             })],
             right: vec![WegItem::Media],
         }
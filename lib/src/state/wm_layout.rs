@@ -48,6 +48,9 @@ common_item! {
         /// window handles (HWND) in the node
         #[serde(default)]
         handles: Vec<isize>,
+        /// how the windows in the node are listed above the tile
+        #[serde(default)]
+        mode: WmStackMode,
     }
     struct WmFallbackNode {
         /// active window handle (HWND) in the node
@@ -55,6 +58,9 @@ common_item! {
         /// window handles (HWND) in the node
         #[serde(default)]
         handles: Vec<isize>,
+        /// how the windows in the node are listed above the tile
+        #[serde(default)]
+        mode: WmStackMode,
     }
 }
 
@@ -101,6 +107,15 @@ pub enum NodeSubtype {
     Permanent,
 }
 
+/// Only the active window of a stack is visible, the others are listed as tabs in a row
+/// (`Tabbed`) or as titles one below the other (`Stacked`).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+pub enum WmStackMode {
+    #[default]
+    Tabbed,
+    Stacked,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum NoFallbackBehavior {
     Float,
@@ -126,6 +141,7 @@ impl Default for WindowManagerLayout {
                 condition: None,
                 active: None,
                 handles: vec![],
+                mode: WmStackMode::default(),
             }),
             no_fallback_behavior: NoFallbackBehavior::Float,
         }
@@ -0,0 +1,159 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface PickedColor {
+  r: number;
+  g: number;
+  b: number;
+}
+
+interface ColorPickerSetup {
+  image: string;
+  history: PickedColor[];
+}
+
+/** pixels shown on each side of the loupe, odd so the picked pixel is centered */
+const LOUPE_PIXELS = 11;
+const LOUPE_ZOOM = 12;
+const LOUPE_SIZE = LOUPE_PIXELS * LOUPE_ZOOM;
+
+function toHex({ r, g, b }: PickedColor) {
+  return `#${[r, g, b].map((v) => v.toString(16).padStart(2, '0')).join('')}`.toUpperCase();
+}
+
+function close() {
+  invoke('color_picker_close').catch(console.error);
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+
+  const screen = document.createElement('img');
+  screen.className = 'color-picker-screen';
+  screen.draggable = false;
+
+  // full size copy of the capture to read the pixels
+  const source = document.createElement('canvas');
+  const sourceCtx = source.getContext('2d', { willReadFrequently: true })!;
+
+  const loupe = document.createElement('div');
+  loupe.className = 'color-picker-loupe';
+  loupe.style.display = 'none';
+
+  const zoomed = document.createElement('canvas');
+  zoomed.width = LOUPE_SIZE;
+  zoomed.height = LOUPE_SIZE;
+  const zoomedCtx = zoomed.getContext('2d')!;
+  zoomedCtx.imageSmoothingEnabled = false;
+
+  const label = document.createElement('div');
+  label.className = 'color-picker-label';
+  const swatch = document.createElement('div');
+  swatch.className = 'color-picker-swatch';
+  const text = document.createElement('span');
+  label.append(swatch, text);
+  loupe.append(zoomed, label);
+
+  const history = document.createElement('div');
+  history.className = 'color-picker-history';
+  history.addEventListener('mousemove', (e) => e.stopPropagation());
+  history.addEventListener('click', (e) => e.stopPropagation());
+
+  document.body.append(screen, loupe, history);
+
+  // cursor position in physical pixels
+  let x = 0;
+  let y = 0;
+
+  const render = () => {
+    const half = Math.floor(LOUPE_PIXELS / 2);
+    zoomedCtx.clearRect(0, 0, LOUPE_SIZE, LOUPE_SIZE);
+    zoomedCtx.drawImage(
+      source,
+      x - half,
+      y - half,
+      LOUPE_PIXELS,
+      LOUPE_PIXELS,
+      0,
+      0,
+      LOUPE_SIZE,
+      LOUPE_SIZE,
+    );
+    zoomedCtx.strokeStyle = 'white';
+    zoomedCtx.lineWidth = 2;
+    zoomedCtx.strokeRect(half * LOUPE_ZOOM, half * LOUPE_ZOOM, LOUPE_ZOOM, LOUPE_ZOOM);
+
+    const [r = 0, g = 0, b = 0] = sourceCtx.getImageData(x, y, 1, 1).data;
+    const hex = toHex({ r, g, b });
+    swatch.style.background = hex;
+    text.textContent = hex;
+  };
+
+  document.addEventListener('mousemove', (e) => {
+    const ratio = window.devicePixelRatio;
+    x = Math.floor(e.clientX * ratio);
+    y = Math.floor(e.clientY * ratio);
+
+    // the loupe is kept inside the window, flipped to the other side near the edges
+    const offset = 24;
+    const width = loupe.offsetWidth || LOUPE_SIZE;
+    const height = loupe.offsetHeight || LOUPE_SIZE;
+    const left = e.clientX + offset + width > window.innerWidth
+      ? e.clientX - offset - width
+      : e.clientX + offset;
+    const top = e.clientY + offset + height > window.innerHeight
+      ? e.clientY - offset - height
+      : e.clientY + offset;
+    loupe.style.left = `${left}px`;
+    loupe.style.top = `${top}px`;
+    loupe.style.display = 'flex';
+    render();
+  });
+
+  // click copies the hex value, shift + click the rgb one
+  document.addEventListener('click', (e) => {
+    const format = e.shiftKey ? 'Rgb' : 'Hex';
+    invoke('color_picker_pick', { x, y, format }).catch(console.error);
+  });
+
+  document.addEventListener('contextmenu', (e) => {
+    e.preventDefault();
+    close();
+  });
+
+  document.addEventListener('keydown', (e) => {
+    if (e.key === 'Escape') {
+      close();
+    }
+  });
+
+  await view.listen<ColorPickerSetup>('color-picker-setup', (event) => {
+    screen.onload = () => {
+      source.width = screen.naturalWidth;
+      source.height = screen.naturalHeight;
+      sourceCtx.drawImage(screen, 0, 0);
+    };
+    screen.src = event.payload.image;
+
+    history.replaceChildren(
+      ...event.payload.history.map((color) => {
+        const item = document.createElement('button');
+        const hex = toHex(color);
+        item.style.background = hex;
+        item.title = hex;
+        item.addEventListener('click', () => {
+          navigator.clipboard.writeText(hex).catch(console.error).finally(close);
+        });
+        return item;
+      }),
+    );
+  });
+
+  await view.emitTo(view.label, 'complete-setup');
+}
+
+Main();
@@ -0,0 +1,28 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+const RINGS = 3;
+
+function ping() {
+  document.body.replaceChildren();
+  for (let i = 0; i < RINGS; i++) {
+    const ring = document.createElement('div');
+    ring.className = 'ring';
+    ring.style.animationDelay = `${i * 200}ms`;
+    document.body.appendChild(ring);
+  }
+}
+
+async function Main() {
+  wrapConsole();
+
+  const webview = getCurrentWebviewWindow();
+  await webview.listen('cursor-sonar-ping', ping);
+
+  ping();
+  await webview.show();
+}
+
+Main();
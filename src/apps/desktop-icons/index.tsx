@@ -0,0 +1,199 @@
+import { UserSettingsLoader } from '../settings/modules/shared/store/storeApi';
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { FileChange } from '../shared/events';
+import { convertFileSrc, invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface DesktopIconPosition {
+  monitor: string;
+  column: number;
+  row: number;
+}
+
+interface DesktopItem {
+  path: string;
+  name: string;
+  isFolder: boolean;
+  icon: string | null;
+  position: DesktopIconPosition | null;
+}
+
+const TARGET = 'desktop-icons';
+const monitor = getCurrentWebviewWindow().label.slice(TARGET.length + 1);
+
+let gridSize = 96;
+let items: DesktopItem[] = [];
+const selected = new Set<string>();
+
+function rows() {
+  return Math.max(1, Math.floor(window.innerHeight / gridSize));
+}
+
+/** items without position take the first free cells, column by column as the Explorer does */
+function layout(): Map<string, [number, number]> {
+  const cells = new Map<string, [number, number]>();
+  const used = new Set<string>();
+  for (const item of items) {
+    if (item.position) {
+      cells.set(item.path, [item.position.column, item.position.row]);
+      used.add(`${item.position.column}:${item.position.row}`);
+    }
+  }
+  let next = 0;
+  for (const item of items) {
+    if (item.position) {
+      continue;
+    }
+    while (used.has(`${Math.floor(next / rows())}:${next % rows()}`)) {
+      next++;
+    }
+    cells.set(item.path, [Math.floor(next / rows()), next % rows()]);
+    next++;
+  }
+  return cells;
+}
+
+function isCellFree(column: number, row: number, except: string) {
+  return ![...layout().entries()].some(
+    ([path, [c, r]]) => path !== except && c === column && r === row,
+  );
+}
+
+function open(item: DesktopItem) {
+  invoke('desktop_icons_open', { path: item.path }).catch(console.error);
+}
+
+function render(root: HTMLElement) {
+  const cells = layout();
+  root.replaceChildren(
+    ...items.map((item) => {
+      const [column, row] = cells.get(item.path)!;
+      const element = document.createElement('div');
+      element.className = 'desktop-icon';
+      element.classList.toggle('desktop-icon-selected', selected.has(item.path));
+      element.style.left = `${column * gridSize}px`;
+      element.style.top = `${row * gridSize}px`;
+      element.title = item.path;
+
+      const icon = document.createElement('img');
+      icon.className = 'desktop-icon-image';
+      icon.draggable = false;
+      if (item.icon) {
+        icon.src = convertFileSrc(item.icon);
+      }
+
+      const label = document.createElement('span');
+      label.className = 'desktop-icon-label';
+      label.textContent = item.name;
+
+      element.append(icon, label);
+      element.addEventListener('dblclick', () => open(item));
+      element.addEventListener('pointerdown', (e) => onPointerDown(e, item, element, root));
+      return element;
+    }),
+  );
+}
+
+function onPointerDown(
+  e: PointerEvent,
+  item: DesktopItem,
+  element: HTMLElement,
+  root: HTMLElement,
+) {
+  if (e.button !== 0) {
+    return;
+  }
+  e.stopPropagation();
+  if (e.ctrlKey) {
+    selected.has(item.path) ? selected.delete(item.path) : selected.add(item.path);
+  } else if (!selected.has(item.path)) {
+    selected.clear();
+    selected.add(item.path);
+  }
+  root.querySelectorAll('.desktop-icon').forEach((el, idx) => {
+    el.classList.toggle('desktop-icon-selected', selected.has(items[idx]!.path));
+  });
+
+  const startX = e.clientX;
+  const startY = e.clientY;
+  const originLeft = element.offsetLeft;
+  const originTop = element.offsetTop;
+  let dragging = false;
+
+  const onMove = (move: PointerEvent) => {
+    const dx = move.clientX - startX;
+    const dy = move.clientY - startY;
+    if (!dragging && Math.hypot(dx, dy) < 4) {
+      return;
+    }
+    dragging = true;
+    element.classList.add('desktop-icon-dragging');
+    element.style.left = `${originLeft + dx}px`;
+    element.style.top = `${originTop + dy}px`;
+  };
+
+  const onUp = (up: PointerEvent) => {
+    window.removeEventListener('pointermove', onMove);
+    window.removeEventListener('pointerup', onUp);
+    element.classList.remove('desktop-icon-dragging');
+    if (!dragging) {
+      return;
+    }
+    const column = Math.max(0, Math.floor(up.clientX / gridSize));
+    const row = Math.min(rows() - 1, Math.max(0, Math.floor(up.clientY / gridSize)));
+    if (!isCellFree(column, row, item.path)) {
+      render(root);
+      return;
+    }
+    const position: DesktopIconPosition = { monitor, column, row };
+    item.position = position;
+    render(root);
+    invoke('desktop_icons_set_position', { path: item.path, position }).catch(console.error);
+  };
+
+  window.addEventListener('pointermove', onMove);
+  window.addEventListener('pointerup', onUp);
+}
+
+async function loadSettings() {
+  const { jsonSettings } = await new UserSettingsLoader().withThemes(false).load();
+  const settings = jsonSettings.desktopIcons;
+  gridSize = settings.gridSize ?? 96;
+  document.documentElement.style.setProperty('--config-icon-size', `${settings.iconSize ?? 48}px`);
+  document.documentElement.style.setProperty('--config-grid-size', `${gridSize}px`);
+}
+
+async function loadItems(root: HTMLElement) {
+  items = await invoke<DesktopItem[]>('desktop_icons_get_items', { monitor });
+  const paths = new Set(items.map((item) => item.path));
+  [...selected].filter((path) => !paths.has(path)).forEach((path) => selected.delete(path));
+  render(root);
+}
+
+async function Main() {
+  wrapConsole();
+
+  const root = document.createElement('div');
+  root.className = 'desktop-icons';
+  document.body.append(root);
+
+  document.body.addEventListener('pointerdown', () => {
+    selected.clear();
+    render(root);
+  });
+  window.addEventListener('resize', () => render(root));
+
+  await loadSettings();
+  await loadItems(root);
+
+  await listen('desktop-icons-changed', () => loadItems(root));
+  await listen(FileChange.Settings, async () => {
+    await loadSettings();
+    render(root);
+  });
+}
+
+Main();
@@ -0,0 +1,113 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface Emoji {
+  value: string;
+  name: string;
+  category: string;
+  supportsSkinTone: boolean;
+}
+
+const SKIN_TONES = ['Default', 'Light', 'MediumLight', 'Medium', 'MediumDark', 'Dark'];
+const SKIN_TONE_PREVIEW = ['✋', '✋🏻', '✋🏼', '✋🏽', '✋🏾', '✋🏿'];
+const COLUMNS = 8;
+
+let emojis: Emoji[] = [];
+let selected = 0;
+let skinTone = 0;
+
+function close() {
+  invoke('emoji_picker_close').catch(console.error);
+}
+
+function select(emoji: Emoji) {
+  invoke('emoji_picker_select', { value: emoji.value }).catch(console.error);
+}
+
+function render(grid: HTMLElement, footer: HTMLElement) {
+  grid.replaceChildren(
+    ...emojis.map((emoji, index) => {
+      const item = document.createElement('button');
+      item.className = 'emoji-picker-item';
+      if (index === selected) {
+        item.classList.add('emoji-picker-item-selected');
+      }
+      item.textContent = emoji.value;
+      item.title = emoji.name;
+      item.addEventListener('click', () => select(emoji));
+      return item;
+    }),
+  );
+  grid.children[selected]?.scrollIntoView({ block: 'nearest' });
+  footer.textContent = emojis[selected]?.name || '';
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+
+  const header = document.createElement('div');
+  header.className = 'emoji-picker-header';
+
+  const search = document.createElement('input');
+  search.className = 'emoji-picker-search';
+  search.placeholder = 'Search';
+
+  const tone = document.createElement('button');
+  tone.className = 'emoji-picker-tone';
+  tone.textContent = SKIN_TONE_PREVIEW[skinTone]!;
+
+  const grid = document.createElement('div');
+  grid.className = 'emoji-picker-grid';
+
+  const footer = document.createElement('div');
+  footer.className = 'emoji-picker-footer';
+
+  header.append(search, tone);
+  document.body.append(header, grid, footer);
+
+  async function update() {
+    emojis = await invoke<Emoji[]>('emoji_picker_search', {
+      query: search.value,
+      skinTone: SKIN_TONES[skinTone],
+    });
+    selected = 0;
+    render(grid, footer);
+  }
+
+  search.addEventListener('input', update);
+  tone.addEventListener('click', () => {
+    skinTone = (skinTone + 1) % SKIN_TONES.length;
+    tone.textContent = SKIN_TONE_PREVIEW[skinTone]!;
+    update();
+    search.focus();
+  });
+
+  document.addEventListener('keydown', (e) => {
+    const moves: Record<string, number> = {
+      ArrowLeft: -1,
+      ArrowRight: 1,
+      ArrowUp: -COLUMNS,
+      ArrowDown: COLUMNS,
+    };
+    if (e.key === 'Escape') {
+      close();
+    } else if (e.key === 'Enter' && emojis[selected]) {
+      select(emojis[selected]!);
+    } else if (e.key in moves) {
+      e.preventDefault();
+      selected = Math.min(Math.max(selected + moves[e.key]!, 0), emojis.length - 1);
+      render(grid, footer);
+    }
+  });
+
+  view.listen('tauri://blur', close);
+
+  await update();
+  search.focus();
+}
+
+Main();
@@ -0,0 +1,79 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { convertFileSrc } from '@tauri-apps/api/core';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface MediaPlayer {
+  title: string;
+  author: string;
+  thumbnail: string | null;
+}
+
+interface LockScreenContent {
+  clock: boolean;
+  media: MediaPlayer | null;
+}
+
+function renderClock(container: HTMLElement) {
+  const now = new Date();
+
+  const time = document.createElement('div');
+  time.className = 'lock-screen-time';
+  time.textContent = now.toLocaleTimeString(undefined, { hour: '2-digit', minute: '2-digit' });
+
+  const date = document.createElement('div');
+  date.className = 'lock-screen-date';
+  date.textContent = now.toLocaleDateString(undefined, {
+    weekday: 'long',
+    month: 'long',
+    day: 'numeric',
+  });
+
+  container.append(time, date);
+}
+
+function renderMedia(container: HTMLElement, media: MediaPlayer) {
+  const widget = document.createElement('div');
+  widget.className = 'lock-screen-media';
+
+  if (media.thumbnail) {
+    const thumbnail = document.createElement('img');
+    thumbnail.className = 'lock-screen-media-thumbnail';
+    thumbnail.src = convertFileSrc(media.thumbnail);
+    widget.append(thumbnail);
+  }
+
+  const info = document.createElement('div');
+  info.className = 'lock-screen-media-info';
+
+  const title = document.createElement('div');
+  title.className = 'lock-screen-media-title';
+  title.textContent = media.title;
+
+  const author = document.createElement('div');
+  author.className = 'lock-screen-media-author';
+  author.textContent = media.author;
+
+  info.append(title, author);
+  widget.append(info);
+  container.append(widget);
+}
+
+function render(content: LockScreenContent) {
+  document.body.replaceChildren();
+  if (content.clock) {
+    renderClock(document.body);
+  }
+  if (content.media) {
+    renderMedia(document.body, content.media);
+  }
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+  await view.listen<LockScreenContent>('lock-screen-show', ({ payload }) => render(payload));
+}
+
+Main();
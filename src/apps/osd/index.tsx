@@ -0,0 +1,71 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+type OsdEvent =
+  | { kind: 'volume'; level: number; muted: boolean }
+  | { kind: 'brightness'; level: number }
+  | { kind: 'keyboardLayout'; layout: { locale: string; displayName: string } }
+  | { kind: 'microphone'; muted: boolean };
+
+function volumeIcon(level: number, muted: boolean) {
+  if (muted || level === 0) {
+    return '🔇';
+  }
+  return level < 0.34 ? '🔈' : level < 0.67 ? '🔉' : '🔊';
+}
+
+function render(event: OsdEvent, icon: HTMLElement, label: HTMLElement, bar: HTMLElement) {
+  let percent: number | null = null;
+  switch (event.kind) {
+    case 'volume':
+      percent = Math.round(event.level * 100);
+      icon.textContent = volumeIcon(event.level, event.muted);
+      label.textContent = event.muted ? 'Muted' : `${percent}`;
+      break;
+    case 'brightness':
+      percent = event.level;
+      icon.textContent = '☀';
+      label.textContent = `${percent}`;
+      break;
+    case 'keyboardLayout':
+      icon.textContent = '⌨';
+      label.textContent = event.layout.displayName || event.layout.locale;
+      break;
+    case 'microphone':
+      icon.textContent = event.muted ? '🚫' : '🎙';
+      label.textContent = event.muted ? 'Microphone muted' : 'Microphone on';
+      break;
+  }
+  document.body.classList.toggle('osd-with-level', percent !== null);
+  bar.style.width = `${percent ?? 0}%`;
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+
+  const icon = document.createElement('div');
+  icon.className = 'osd-icon';
+
+  const content = document.createElement('div');
+  content.className = 'osd-content';
+
+  const label = document.createElement('div');
+  label.className = 'osd-label';
+
+  const track = document.createElement('div');
+  track.className = 'osd-track';
+
+  const bar = document.createElement('div');
+  bar.className = 'osd-bar';
+
+  track.append(bar);
+  content.append(label, track);
+  document.body.append(icon, content);
+
+  await view.listen<OsdEvent>('osd-show', ({ payload }) => render(payload, icon, label, bar));
+}
+
+Main();
@@ -0,0 +1,60 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface OverviewItem {
+  hwnd: number;
+  title: string;
+  exe: string;
+  rect: { left: number; top: number; right: number; bottom: number };
+}
+
+function close() {
+  invoke('overview_close').catch(console.error);
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+
+  await view.listen<OverviewItem[]>('overview-items', async (event) => {
+    // rects are in physical pixels
+    const ratio = window.devicePixelRatio;
+
+    for (const item of event.payload) {
+      const element = document.createElement('div');
+      element.className = 'overview-item';
+      element.style.left = `${item.rect.left / ratio}px`;
+      element.style.top = `${item.rect.top / ratio}px`;
+      element.style.width = `${(item.rect.right - item.rect.left) / ratio}px`;
+      element.style.height = `${(item.rect.bottom - item.rect.top) / ratio}px`;
+
+      const title = document.createElement('span');
+      title.className = 'overview-item-title';
+      title.textContent = item.title;
+      element.appendChild(title);
+
+      element.addEventListener('click', (e) => {
+        e.stopPropagation();
+        invoke('overview_focus', { hwnd: item.hwnd }).catch(console.error);
+      });
+      document.body.appendChild(element);
+    }
+
+    await view.show();
+    await view.setFocus();
+  });
+
+  document.body.addEventListener('click', close);
+  document.addEventListener('keydown', (e) => {
+    if (e.key === 'Escape') {
+      close();
+    }
+  });
+
+  await view.emitTo(view.label, 'complete-setup');
+}
+
+Main();
@@ -0,0 +1,24 @@
+import { Lang } from '../../shared/lang';
+import i18n from 'i18next';
+import yaml from 'js-yaml';
+
+i18n.init({
+  lng: 'en',
+  fallbackLng: 'en',
+  interpolation: {
+    escapeValue: false,
+  },
+  resources: {},
+});
+
+export async function loadTranslations() {
+  const translations: Partial<Record<Lang, { default: string }>> = {
+    en: await import('./translations/en.yml'),
+  };
+
+  for (const [key, value] of Object.entries(translations)) {
+    i18n.addResourceBundle(key, 'translation', yaml.load(value.default));
+  }
+}
+
+export default i18n;
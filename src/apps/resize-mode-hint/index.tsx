@@ -0,0 +1,33 @@
+import { UserSettingsLoader } from '../settings/modules/shared/store/storeApi';
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import i18n, { loadTranslations } from './i18n';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+const HINTS: [string, string][] = [
+  ['hint.arrows', 'hint.move'],
+  ['hint.shift_arrows', 'hint.resize'],
+  ['hint.enter', 'hint.confirm'],
+  ['hint.esc', 'hint.cancel'],
+];
+
+async function Main() {
+  wrapConsole();
+
+  let { jsonSettings } = await new UserSettingsLoader().withThemes(false).load();
+  await loadTranslations();
+  await i18n.changeLanguage(jsonSettings.language);
+
+  for (const [keys, action] of HINTS) {
+    const line = document.createElement('div');
+    const kbd = document.createElement('kbd');
+    kbd.textContent = i18n.t(keys);
+    line.append(kbd, ` ${i18n.t(action)}`);
+    document.body.appendChild(line);
+  }
+
+  await getCurrentWebviewWindow().show();
+}
+
+Main();
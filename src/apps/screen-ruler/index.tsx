@@ -0,0 +1,79 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface Rect {
+  left: number;
+  top: number;
+  right: number;
+  bottom: number;
+}
+
+interface Measurement {
+  x: number;
+  y: number;
+  hovered: Rect | null;
+  guides: Rect;
+}
+
+function element(className: string, style: Partial<CSSStyleDeclaration>, text?: string) {
+  const div = document.createElement('div');
+  div.className = className;
+  Object.assign(div.style, style);
+  if (text) {
+    div.textContent = text;
+  }
+  return div;
+}
+
+function render({ x, y, hovered, guides }: Measurement) {
+  // measurements are in physical pixels
+  const ratio = window.devicePixelRatio;
+  const px = (value: number) => `${value / ratio}px`;
+  const children: HTMLElement[] = [];
+
+  if (hovered) {
+    children.push(
+      element('ruler-hovered', {
+        left: px(hovered.left),
+        top: px(hovered.top),
+        width: px(hovered.right - hovered.left),
+        height: px(hovered.bottom - hovered.top),
+      }),
+      element(
+        'ruler-label',
+        { left: px((hovered.left + hovered.right) / 2), top: px(hovered.bottom) },
+        `${hovered.right - hovered.left} × ${hovered.bottom - hovered.top}`,
+      ),
+    );
+  }
+
+  children.push(
+    element('ruler-line ruler-line-horizontal', {
+      left: px(guides.left),
+      top: px(y),
+      width: px(guides.right - guides.left),
+    }),
+    element('ruler-line ruler-line-vertical', {
+      left: px(x),
+      top: px(guides.top),
+      height: px(guides.bottom - guides.top),
+    }),
+    element('ruler-label', { left: px(x), top: px(y - 14 * ratio) }, `${x}, ${y}`),
+    element('ruler-label', { left: px((guides.left + x) / 2), top: px(y) }, `${x - guides.left}`),
+    element('ruler-label', { left: px((x + guides.right) / 2), top: px(y) }, `${guides.right - x}`),
+    element('ruler-label', { left: px(x), top: px((guides.top + y) / 2) }, `${y - guides.top}`),
+    element('ruler-label', { left: px(x), top: px((y + guides.bottom) / 2) }, `${guides.bottom - y}`),
+  );
+
+  document.body.replaceChildren(...children);
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+  await view.listen<Measurement>('screen-ruler-measurement', (event) => render(event.payload));
+}
+
+Main();
@@ -4,6 +4,7 @@ import {
   LeafNode,
   NodeSubtype,
   NodeType,
+  StackMode,
   StackNode,
   VerticalBranchNode,
 } from '../../../shared/schemas/Layout';
@@ -12,7 +13,7 @@ import { evaluate } from 'mathjs';
 
 import { FocusAction } from '../shared/store/domain';
 import { HWND } from '../shared/utils/domain';
-import { BranchNode, Node, Reservation, Sizing } from './domain';
+import { BranchNode, CycleDirection, Node, Reservation, Sizing, SplitOrientation } from './domain';
 
 export function clearContainer(container: Node): void {
   switch (container.type) {
@@ -149,7 +150,7 @@ export class NodeImpl<T extends Node> {
       return !this.ref.handle;
     }
 
-    if (this.isFallback()) {
+    if (this.isFallback() || this.isStack()) {
       return this.ref.handles.length === 0;
     }
 
@@ -165,8 +166,8 @@ export class NodeImpl<T extends Node> {
       return !!this.ref.handle;
     }
 
-    if (this.isFallback()) {
-      // fallback nodes can not be fulled this allow infinite number of handles
+    if (this.isFallback() || this.isStack()) {
+      // stacks can not be fulled this allow infinite number of handles
       return false;
     }
 
@@ -188,7 +189,7 @@ export class NodeImpl<T extends Node> {
       return true;
     }
 
-    if (this.isFallback()) {
+    if (this.isFallback() || this.isStack()) {
       this.ref.handles.push(handle);
       this.ref.active = handle;
       return true;
@@ -209,7 +210,7 @@ export class NodeImpl<T extends Node> {
   }
 
   removeHandle(handle: number): boolean {
-    if (this.isFallback()) {
+    if (this.isFallback() || this.isStack()) {
       const index = this.ref.handles.indexOf(handle);
       if (index !== -1) {
         this.ref.handles.splice(index, 1);
@@ -248,6 +249,7 @@ export class NodeImpl<T extends Node> {
       ref.subtype = NodeSubtype.Temporal;
       ref.handles = [];
       ref.active = null;
+      ref.mode = StackMode.Tabbed;
 
       if (this.ref.handle) {
         ref.handles.push(this.ref.handle);
@@ -296,7 +298,7 @@ export class NodeImpl<T extends Node> {
 
     switch (reservation) {
       case Reservation.Stack: {
-        if (node.isFallback()) {
+        if (node.isFallback() || node.isStack()) {
           node.ref.handles.push(hwnd);
         }
         if (node.isLeaf()) {
@@ -331,12 +333,70 @@ export class NodeImpl<T extends Node> {
     }
   }
 
-  getNodeContaining(searched: HWND): LeafNode | FallbackNode | null {
+  /**
+   * Converts the split into the reservation that places the next window after the node
+   * containing the handle, so it is handled and indicated like a manual reservation.
+   */
+  splitAsReservation(handle: HWND, orientation: SplitOrientation): Reservation | null {
+    if (orientation === SplitOrientation.Horizontal) {
+      return Reservation.Right;
+    }
+
+    if (orientation === SplitOrientation.Vertical) {
+      return Reservation.Bottom;
+    }
+
+    const found = this.getNodeContaining(handle);
+    if (!found) {
+      console.error('Could not find node containing handle', handle);
+      return null;
+    }
+
+    const parent = this.trace(found).at(-2);
+    return parent?.type === NodeType.Horizontal ? Reservation.Bottom : Reservation.Right;
+  }
+
+  /** @returns the handle to focus, null if the window is not on a stack with other tabs */
+  cycleTab(handle: HWND, direction: CycleDirection): HWND | null {
+    const found = this.getNodeContaining(handle);
+    if (!found || found.type === NodeType.Leaf || found.handles.length < 2) {
+      return null;
+    }
+
+    const offset = direction === CycleDirection.Next ? 1 : -1;
+    const total = found.handles.length;
+    const idx = found.handles.indexOf(found.active ?? handle);
+    const next = found.handles[(idx + offset + total) % total]!;
+    found.active = next;
+    return next;
+  }
+
+  /** used to keep the tab strip in sync when a hidden tab is focused from outside */
+  activateTab(handle: HWND): void {
+    const found = this.getNodeContaining(handle);
+    if (found && found.type !== NodeType.Leaf) {
+      found.active = handle;
+    }
+  }
+
+  setStackMode(handle: HWND, mode: StackMode): void {
+    const found = this.getNodeContaining(handle);
+    if (!found) {
+      console.error('Could not find node containing handle', handle);
+      return;
+    }
+
+    const node = NodeImpl.from(found);
+    const stack = node.isLeaf() ? node.mutateToStacked() : node;
+    (stack.inner as FallbackNode | StackNode).mode = mode;
+  }
+
+  getNodeContaining(searched: HWND): LeafNode | FallbackNode | StackNode | null {
     if (this.isLeaf()) {
       return this.ref.handle === searched ? this.ref : null;
     }
 
-    if (this.isFallback()) {
+    if (this.isFallback() || this.isStack()) {
       return this.ref.handles.includes(searched) ? this.ref : null;
     }
 
@@ -358,7 +418,7 @@ export class NodeImpl<T extends Node> {
       result.push(this.ref);
     }
 
-    if (this.isFallback() && this.ref === to) {
+    if ((this.isFallback() || this.isStack()) && this.ref === to) {
       result.push(this.ref);
     }
 
@@ -383,6 +443,29 @@ export class NodeImpl<T extends Node> {
     }
   }
 
+  /** grow factors of the whole tree in depth-first order */
+  getGrowFactors(): number[] {
+    const factors = [this.ref.growFactor];
+    if (this.isBranch()) {
+      for (const child of this.ref.children) {
+        factors.push(...NodeImpl.from(child).getGrowFactors());
+      }
+    }
+    return factors;
+  }
+
+  /** inverse of `getGrowFactors`, returns the amount of factors consumed */
+  setGrowFactors(factors: number[], offset = 0): number {
+    let consumed = 1;
+    this.ref.growFactor = factors[offset] ?? 1;
+    if (this.isBranch()) {
+      for (const child of this.ref.children) {
+        consumed += NodeImpl.from(child).setGrowFactors(factors, offset + consumed);
+      }
+    }
+    return consumed;
+  }
+
   reIndexingGrowFactor() {
     if (this.isBranch()) {
       const noEmptyChildren = this.ref.children.filter((child) => !NodeImpl.from(child).isEmpty());
@@ -400,11 +483,12 @@ export class NodeImpl<T extends Node> {
     }
   }
 
-  updateGrowFactor(handle: HWND, axis: 'x' | 'y', action: Sizing) {
+  /** closest branch on the axis with other children, and its child containing the handle */
+  private getResizable(handle: HWND, axis: 'x' | 'y'): { parent: BranchNode; node: Node } | null {
     const result = this.getNodeContaining(handle);
     if (!result) {
       console.error('Could not find node containing handle', handle);
-      return;
+      return null;
     }
 
     const trace = this.trace(result);
@@ -426,12 +510,19 @@ export class NodeImpl<T extends Node> {
 
     if (idx === -1) {
       console.error('Can\'t resize root');
-      return;
+      return null;
     }
 
-    const parent = trace[idx] as BranchNode;
-    const nodeToResize = trace[idx + 1]!;
+    return { parent: trace[idx] as BranchNode, node: trace[idx + 1]! };
+  }
 
+  updateGrowFactor(handle: HWND, axis: 'x' | 'y', action: Sizing) {
+    const resizable = this.getResizable(handle, axis);
+    if (!resizable) {
+      return;
+    }
+
+    const { parent, node: nodeToResize } = resizable;
     const noEmptyChildren = parent.children.filter((child) => !NodeImpl.from(child).isEmpty());
 
     const total = noEmptyChildren.reduce((acc, child) => acc + child.growFactor, 0);
@@ -445,6 +536,23 @@ export class NodeImpl<T extends Node> {
     this.reIndexingGrowFactor();
   }
 
+  /** sets the grow factor so the container takes `ratio` (0 - 1) of its parent on the axis */
+  setGrowRatio(handle: HWND, axis: 'x' | 'y', ratio: number) {
+    const resizable = this.getResizable(handle, axis);
+    if (!resizable) {
+      return;
+    }
+
+    const { parent, node: nodeToResize } = resizable;
+    const others = parent.children
+      .filter((child) => child !== nodeToResize && !NodeImpl.from(child).isEmpty())
+      .reduce((acc, child) => acc + child.growFactor, 0);
+
+    const clamped = Math.min(Math.max(ratio, 0.1), 0.9);
+    nodeToResize.growFactor = (clamped * others) / (1 - clamped);
+    this.reIndexingGrowFactor();
+  }
+
   getLeafByPriority(): LeafNode | FallbackNode | StackNode | null {
     if (this.isLeaf()) {
       return this.ref.handle ? this.ref : null;
@@ -9,6 +9,18 @@ export enum Reservation {
   Float = 'Float',
 }
 
+export enum SplitOrientation {
+  Horizontal = 'Horizontal',
+  Vertical = 'Vertical',
+  /** alternates the orientation of the parent container */
+  Auto = 'Auto',
+}
+
+export enum CycleDirection {
+  Next = 'Next',
+  Previous = 'Previous',
+}
+
 export enum Sizing {
   Increase = 'Increase',
   Decrease = 'Decrease',
@@ -2,21 +2,40 @@ import { toPhysicalPixels } from '../../../../../shared';
 import { cx } from '../../../../../shared/styles';
 import { ReservedContainer } from './reserved';
 import { invoke } from '@tauri-apps/api/core';
-import { useCallback, useEffect, useRef } from 'react';
+import { CSSProperties, useCallback, useEffect, useRef } from 'react';
 import { useSelector } from 'react-redux';
 
 import { Selectors } from '../../../shared/store/app';
 
+import { SizeLimits } from '../../../shared/store/domain';
+
 interface Props {
   hwnd: number;
   growFactor?: number;
+  /** inactive tab of a stack */
+  hidden?: boolean;
+}
+
+/** limits are of the window frame in physical pixels, the leaf also includes the borders */
+function sizeLimitsStyle(limits: SizeLimits | undefined, border: number): CSSProperties {
+  if (!limits) {
+    return {};
+  }
+  const ratio = window.devicePixelRatio;
+  return {
+    minWidth: limits.min_width / ratio + border * 2,
+    minHeight: limits.min_height / ratio + border * 2,
+    maxWidth: limits.max_width / ratio + border * 2,
+    maxHeight: limits.max_height / ratio + border * 2,
+  };
 }
 
-export function LeafContainer({ hwnd, growFactor }: Props) {
+export function LeafContainer({ hwnd, growFactor, hidden }: Props) {
   const ref = useRef<HTMLDivElement>(null);
   const reservation = useSelector(Selectors.reservation);
   const activeWindow = useSelector(Selectors.activeWindow);
   const borderSettings = useSelector(Selectors.settings.border);
+  const sizeLimits = useSelector(Selectors.sizeLimits);
 
   const updateSize = useCallback(() => {
     if (!ref.current) {
@@ -41,18 +60,21 @@ export function LeafContainer({ hwnd, growFactor }: Props) {
   useEffect(updateSize);
 
   const isFocused = activeWindow === hwnd;
+  const border = borderSettings.enabled ? borderSettings.width + borderSettings.offset : 0;
   return (
     <div
       ref={ref}
       style={{
         flexGrow: growFactor,
+        ...sizeLimitsStyle(sizeLimits[hwnd], border),
       }}
       className={cx('wm-container', 'wm-leaf', {
         'wm-leaf-focused': isFocused,
         'wm-leaf-with-borders': borderSettings.enabled,
+        'wm-leaf-hidden': hidden,
       })}
     >
-      {!!reservation && isFocused && <ReservedContainer reservation={reservation} />}
+      {!!reservation && isFocused && !hidden && <ReservedContainer reservation={reservation} />}
     </div>
   );
 }
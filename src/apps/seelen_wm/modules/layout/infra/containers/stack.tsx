@@ -0,0 +1,47 @@
+import { FallbackNode, StackNode } from '../../../../../shared/schemas/Layout';
+import { cx } from '../../../../../shared/styles';
+import { LeafContainer } from './leaf';
+import { useSelector } from 'react-redux';
+
+import { Selectors } from '../../../shared/store/app';
+
+interface Props {
+  node: FallbackNode | StackNode;
+}
+
+export function StackContainer({ node }: Props) {
+  const { border } = useSelector(Selectors.settings);
+  const titles = useSelector(Selectors.titles);
+
+  return (
+    <div
+      style={{
+        flexGrow: node.growFactor,
+      }}
+      className={cx('wm-container', 'wm-stack', `wm-stack-${node.mode.toLowerCase()}`)}
+    >
+      {node.handles.length > 1 && (
+        <div className={cx('wm-stack-bar', {
+          'wm-stack-bar-with-borders': border.enabled,
+        })}>
+          {node.handles.map((handle) => (
+            <div
+              key={handle}
+              className={cx('wm-stack-bar-item', {
+                'wm-stack-bar-item-active': handle === node.active,
+              })}
+            >
+              {titles[handle] || handle}
+            </div>
+          ))}
+        </div>
+      )}
+      {/* inactive tabs share the tile with the active one, staying behind it */}
+      <div className="wm-stack-content">
+        {node.handles.map((handle) => (
+          <LeafContainer key={handle} hwnd={handle} hidden={handle !== node.active} />
+        ))}
+      </div>
+    </div>
+  );
+}
@@ -1,9 +1,10 @@
 import { cx } from '../../../../shared/styles';
-import { FallbackContainer } from './containers/fallback';
 import { LeafContainer } from './containers/leaf';
-import { useSelector } from 'react-redux';
+import { StackContainer } from './containers/stack';
+import { useEffect } from 'react';
+import { useDispatch, useSelector } from 'react-redux';
 
-import { SelectCurrentWorkspace, Selectors } from '../../shared/store/app';
+import { RootActions, SelectCurrentWorkspace, Selectors } from '../../shared/store/app';
 import { NodeImpl } from '../app';
 
 import { Node } from '../domain';
@@ -17,8 +18,8 @@ export function Container({ container }: { container: Node }) {
     return null;
   }
 
-  if (node.isFallback()) {
-    return <FallbackContainer node={node.inner} />;
+  if (node.isFallback() || node.isStack()) {
+    return <StackContainer node={node.inner} />;
   }
 
   if (node.isLeaf() && node.inner.handle) {
@@ -46,10 +47,51 @@ export function Container({ container }: { container: Node }) {
 export function Layout() {
   const workspace = useSelector(SelectCurrentWorkspace);
   const version = useSelector(Selectors.version);
+  const isTabletPosture = useSelector(Selectors.isTabletPosture);
+  const activeWorkspace = useSelector(Selectors.activeWorkspace);
+  const handlesByDesktop = useSelector(Selectors.handlesByDesktop);
+  const lastManagedActivated = useSelector(Selectors.lastManagedActivated);
+  const sizeLimits = useSelector(Selectors.sizeLimits);
+
+  const dispatch = useDispatch();
+
+  // minimum sizes take the space of the neighbors, when there is no space left the layout
+  // overflows and the window asking for the biggest area is floated
+  useEffect(() => {
+    const root = document.querySelector('#root > .wm-container');
+    if (!workspace || isTabletPosture || !root) {
+      return;
+    }
+    const overflows =
+      root.scrollWidth > root.clientWidth + 1 || root.scrollHeight > root.clientHeight + 1;
+    if (!overflows) {
+      return;
+    }
+    const node = NodeImpl.from(workspace.layout.structure);
+    const minArea = (hwnd: number) => {
+      const limits = sizeLimits[hwnd];
+      return limits ? limits.min_width * limits.min_height : 0;
+    };
+    const unfittable = (handlesByDesktop[activeWorkspace] || [])
+      .filter((hwnd) => minArea(hwnd) > 0 && !!node.getNodeContaining(hwnd))
+      .sort((a, b) => minArea(b) - minArea(a))[0];
+    if (unfittable) {
+      dispatch(RootActions.floatUnfittable(unfittable));
+    }
+  });
 
   if (!workspace) {
     return null;
   }
 
+  if (isTabletPosture) {
+    const handles = handlesByDesktop[activeWorkspace] || [];
+    const hwnd =
+      lastManagedActivated && handles.includes(lastManagedActivated)
+        ? lastManagedActivated
+        : handles[0];
+    return hwnd ? <LeafContainer key={hwnd} hwnd={hwnd} /> : null;
+  }
+
   return <Container key={version} container={workspace.layout.structure} />;
 }
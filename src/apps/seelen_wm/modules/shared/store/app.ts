@@ -1,6 +1,7 @@
 import { defaultLayout } from '../../../../../shared.interfaces';
 import { toPhysicalPixels } from '../../../../shared';
 import { parseAsCamel } from '../../../../shared/schemas';
+import { NodeSchema, StackMode } from '../../../../shared/schemas/Layout';
 import { WindowManagerSchema } from '../../../../shared/schemas/WindowManager';
 import { StateBuilder } from '../../../../shared/StateBuilder';
 import { createSlice, PayloadAction } from '@reduxjs/toolkit';
@@ -9,11 +10,13 @@ import { cloneDeep } from 'lodash';
 
 import { NodeImpl, reIndexContainer } from '../../layout/app';
 
-import { Reservation, Sizing } from '../../layout/domain';
-import { AddWindowPayload, DesktopId, FocusAction, RootState } from './domain';
+import { CycleDirection, Reservation, Sizing, SplitOrientation } from '../../layout/domain';
+import { HWND } from '../utils/domain';
+import { AddWindowPayload, DesktopId, FocusAction, NativeSnapPayload, RootState } from './domain';
 
 const initialState: RootState = {
   version: 0,
+  isTabletPosture: false,
   availableLayouts: [],
   workspaces: {},
   activeWorkspace: '' as DesktopId,
@@ -22,6 +25,9 @@ const initialState: RootState = {
   activeWindow: 0,
   lastManagedActivated: null,
   reservation: null,
+  sizingBackup: null,
+  titles: {},
+  sizeLimits: {},
   settings: parseAsCamel(WindowManagerSchema, {}),
   colors: {
     background: '#ffffff',
@@ -37,14 +43,32 @@ const initialState: RootState = {
   },
 };
 
+function setFloatingSize(hwnd: HWND, size: { width: number; height: number }) {
+  const top = toPhysicalPixels(window.screen.height / 2 - size.height / 2);
+  const left = toPhysicalPixels(window.screen.width / 2 - size.width / 2);
+  invoke('set_window_position', {
+    hwnd,
+    rect: {
+      top,
+      left,
+      right: left + toPhysicalPixels(size.width),
+      bottom: top + toPhysicalPixels(size.height),
+    },
+  });
+}
+
 export const RootSlice = createSlice({
   name: 'root',
   initialState,
   reducers: {
     ...StateBuilder.reducersFor(initialState),
     addWindow: (state, action: PayloadAction<AddWindowPayload>) => {
-      const { desktop_id, hwnd, as_floating } = action.payload;
+      const { desktop_id, hwnd, as_floating, title, size_limits } = action.payload;
 
+      state.titles[hwnd] = title;
+      if (size_limits) {
+        state.sizeLimits[hwnd] = size_limits;
+      }
       state.desktopByHandle[hwnd] = desktop_id;
       state.handlesByDesktop[desktop_id] ??= [];
 
@@ -66,24 +90,10 @@ export const RootSlice = createSlice({
 
       let successfullyAdded = false;
 
-      const setFloatingSize = () => {
-        const top = toPhysicalPixels(window.screen.height / 2 - state.settings.floating.height / 2);
-        const left = toPhysicalPixels(window.screen.width / 2 - state.settings.floating.width / 2);
-        invoke('set_window_position', {
-          hwnd,
-          rect: {
-            top,
-            left,
-            right: left + toPhysicalPixels(state.settings.floating.width),
-            bottom: top + toPhysicalPixels(state.settings.floating.height),
-          },
-        });
-      };
-
       if (state.reservation) {
         if (state.reservation === Reservation.Float) {
           invoke('bounce_handle', { hwnd });
-          setFloatingSize();
+          setFloatingSize(hwnd, state.settings.floating);
           successfullyAdded = true;
         } else if (state.lastManagedActivated) {
           successfullyAdded = node.concreteReservation(
@@ -93,7 +103,7 @@ export const RootSlice = createSlice({
           );
         }
       } else if (as_floating) {
-        setFloatingSize();
+        setFloatingSize(hwnd, state.settings.floating);
         successfullyAdded = true;
       } else {
         successfullyAdded = node.addHandle(hwnd);
@@ -114,7 +124,7 @@ export const RootSlice = createSlice({
             'Layout can\'t handle the window, FallbackNode and noFallbackBehavior are not defined in layout',
           );
         } else if (workspace.layout.noFallbackBehavior === 'Float') {
-          setFloatingSize();
+          setFloatingSize(hwnd, state.settings.floating);
         }
       }
     },
@@ -127,6 +137,8 @@ export const RootSlice = createSlice({
       }
 
       delete state.desktopByHandle[hwnd];
+      delete state.titles[hwnd];
+      delete state.sizeLimits[hwnd];
       const handlesInDesktop = state.handlesByDesktop[desktopId] || [];
       const idx = handlesInDesktop.indexOf(hwnd);
       if (idx != -1) {
@@ -142,6 +154,25 @@ export const RootSlice = createSlice({
         }
       }
     },
+    /** the window can't be shrunk to its tile, it is floated to not overlap its neighbors */
+    floatUnfittable(state, action: PayloadAction<HWND>) {
+      const hwnd = action.payload;
+      const desktopId = state.desktopByHandle[hwnd];
+      const workspace = desktopId && state.workspaces[desktopId];
+      if (!workspace) {
+        return;
+      }
+
+      const node = NodeImpl.from(workspace.layout.structure);
+      if (node.removeHandle(hwnd)) {
+        reIndexContainer(node.inner, state.handlesByDesktop[desktopId] || []);
+        invoke('wm_float_unfittable', { hwnd });
+        setFloatingSize(hwnd, state.settings.floating);
+      }
+      if (state.lastManagedActivated === hwnd) {
+        state.lastManagedActivated = null;
+      }
+    },
     forceUpdate(state) {
       state.version += 1;
     },
@@ -157,6 +188,69 @@ export const RootSlice = createSlice({
         };
       }
     },
+    /** the windows of the saved tree were already resolved to handles by the background */
+    applyLayoutTree(state, action: PayloadAction<unknown>) {
+      const workspace = state.workspaces[state.activeWorkspace];
+      const parsed = NodeSchema.safeParse(action.payload);
+      if (!workspace || !parsed.success) {
+        return console.error('Invalid layout tree', parsed.error);
+      }
+
+      const previous = NodeImpl.from(workspace.layout.structure);
+      const tiled = (state.handlesByDesktop[state.activeWorkspace] || []).filter(
+        (hwnd) => !!previous.getNodeContaining(hwnd),
+      );
+
+      workspace.layout.structure = parsed.data;
+      const node = NodeImpl.from(workspace.layout.structure);
+      // windows not present on the saved tree are added as new ones
+      for (const hwnd of tiled) {
+        if (!node.getNodeContaining(hwnd) && !node.addHandle(hwnd)) {
+          invoke('bounce_handle', { hwnd });
+        }
+      }
+    },
+    setTitle(state, action: PayloadAction<{ hwnd: HWND; title: string }>) {
+      if (state.desktopByHandle[action.payload.hwnd]) {
+        state.titles[action.payload.hwnd] = action.payload.title;
+      }
+    },
+    setStackMode(state, action: PayloadAction<StackMode>) {
+      const workspace = state.workspaces[state.activeWorkspace];
+      if (!workspace || !state.lastManagedActivated) {
+        return;
+      }
+      NodeImpl.from(workspace.layout.structure).setStackMode(
+        state.lastManagedActivated,
+        action.payload,
+      );
+    },
+    cycleTab(state, action: PayloadAction<CycleDirection>) {
+      const workspace = state.workspaces[state.activeWorkspace];
+      if (!workspace || !state.lastManagedActivated) {
+        return;
+      }
+      const node = NodeImpl.from(workspace.layout.structure);
+      const next = node.cycleTab(state.lastManagedActivated, action.payload);
+      if (next) {
+        invoke('request_focus', { hwnd: next });
+      }
+    },
+    activateTab(state, action: PayloadAction<HWND>) {
+      const desktopId = state.desktopByHandle[action.payload];
+      const workspace = desktopId && state.workspaces[desktopId];
+      if (workspace) {
+        NodeImpl.from(workspace.layout.structure).activateTab(action.payload);
+      }
+    },
+    setSplit(state, action: PayloadAction<SplitOrientation>) {
+      const workspace = state.workspaces[state.activeWorkspace];
+      if (!workspace || !state.lastManagedActivated) {
+        return;
+      }
+      const node = NodeImpl.from(workspace.layout.structure);
+      state.reservation = node.splitAsReservation(state.lastManagedActivated, action.payload);
+    },
     updateSizing(state, action: PayloadAction<{ axis: 'x' | 'y'; sizing: Sizing }>) {
       const { axis, sizing } = action.payload;
       if (state.lastManagedActivated) {
@@ -164,10 +258,43 @@ export const RootSlice = createSlice({
         node.updateGrowFactor(state.lastManagedActivated, axis, sizing);
       }
     },
+    /** the snapped size is adopted as the ratio of the tile, full sized axes are ignored */
+    adoptNativeSnap(state, action: PayloadAction<NativeSnapPayload>) {
+      const { hwnd, width, height } = action.payload;
+      const desktopId = state.desktopByHandle[hwnd];
+      const workspace = desktopId && state.workspaces[desktopId];
+      if (!workspace) {
+        return;
+      }
+      const node = NodeImpl.from(workspace.layout.structure);
+      if (!node.getNodeContaining(hwnd)) {
+        return;
+      }
+      if (width < 0.95) {
+        node.setGrowRatio(hwnd, 'x', width);
+      }
+      if (height < 0.95) {
+        node.setGrowRatio(hwnd, 'y', height);
+      }
+    },
     resetSizing(state) {
       const node = NodeImpl.from(state.workspaces[state.activeWorkspace]!.layout.structure);
+      state.sizingBackup = { workspace: state.activeWorkspace, factors: node.getGrowFactors() };
       node.resetGrowFactor();
     },
+    restoreSizing(state) {
+      const backup = state.sizingBackup;
+      const workspace = backup && state.workspaces[backup.workspace];
+      if (!backup || !workspace) {
+        return;
+      }
+      const node = NodeImpl.from(workspace.layout.structure);
+      // the layout changed since the reset so the factors no longer apply
+      if (node.getGrowFactors().length === backup.factors.length) {
+        node.setGrowFactors(backup.factors);
+      }
+      state.sizingBackup = null;
+    },
     focus(state, action: PayloadAction<FocusAction>) {
       const { workspaces, activeWorkspace } = state;
       const workspace = workspaces[activeWorkspace];
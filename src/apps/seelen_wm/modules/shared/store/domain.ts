@@ -44,14 +44,41 @@ export interface RootState extends IRootState<WindowManager> {
   /** last managed window activated */
   lastManagedActivated: HWND | null;
   reservation: Reservation | null;
+  /** grow factors before the last size reset, used to undo it */
+  sizingBackup: { workspace: DesktopId; factors: number[] } | null;
+  /** titles of the managed windows, shown on the tabs of stacks */
+  titles: Record<HWND, string>;
+  /** size limits of the managed windows, honored by the tiles */
+  sizeLimits: Record<HWND, SizeLimits>;
   handlesByDesktop: Record<DesktopId, HWND[]>;
   desktopByHandle: Record<HWND, DesktopId>;
   /** Prop to listen for app forced updates */
   version: number;
+  /** convertible device used as tablet, only the focused window is shown maximized */
+  isTabletPosture: boolean;
+}
+
+/** visible frame of the window in physical pixels */
+export interface SizeLimits {
+  min_width: number;
+  min_height: number;
+  max_width: number;
+  max_height: number;
+}
+
+/** window snapped by Windows, the values are fractions (0 - 1) of the monitor's work area */
+export interface NativeSnapPayload {
+  hwnd: HWND;
+  x: number;
+  y: number;
+  width: number;
+  height: number;
 }
 
 export interface AddWindowPayload {
   hwnd: HWND;
   desktop_id: DesktopId;
   as_floating: boolean;
+  title: string;
+  size_limits: SizeLimits | null;
 }
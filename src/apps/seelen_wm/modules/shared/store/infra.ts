@@ -1,14 +1,17 @@
 import { UserSettingsLoader } from '../../../../settings/modules/shared/store/storeApi';
-import { loadThemeCSS, setColorsAsCssVariables } from '../../../../shared';
+import { listenWorkspaceAccent, loadThemeCSS, setColorsAsCssVariables } from '../../../../shared';
 import { FileChange } from '../../../../shared/events';
+import { StackMode } from '../../../../shared/schemas/Layout';
 import { WindowManager } from '../../../../shared/schemas/WindowManager';
 import { configureStore } from '@reduxjs/toolkit';
+import { invoke } from '@tauri-apps/api/core';
 import { listen as listenGlobal } from '@tauri-apps/api/event';
 
 import { RootActions, RootSlice } from './app';
 
-import { Reservation, Sizing } from '../../layout/domain';
-import { AddWindowPayload, DesktopId, FocusAction, UIColors } from './domain';
+import { CycleDirection, Reservation, Sizing, SplitOrientation } from '../../layout/domain';
+import { HWND } from '../utils/domain';
+import { AddWindowPayload, DesktopId, FocusAction, NativeSnapPayload, UIColors } from './domain';
 
 export const store = configureStore({
   reducer: RootSlice.reducer,
@@ -16,6 +19,8 @@ export const store = configureStore({
 });
 
 export async function loadStore() {
+  const posture = await invoke<string>('get_posture').catch(() => 'Desktop');
+  store.dispatch(RootActions.setIsTabletPosture(posture === 'Tablet'));
   const userSettings = await new UserSettingsLoader().withLayouts().load();
   const settings = userSettings.jsonSettings.windowManager;
   store.dispatch(RootActions.setAvailableLayouts(userSettings.layouts));
@@ -37,6 +42,10 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.removeWindow(event.payload));
   });
 
+  await listenGlobal<string>('posture-changed', (event) => {
+    store.dispatch(RootActions.setIsTabletPosture(event.payload === 'Tablet'));
+  });
+
   await listenGlobal<void>('force-retiling', () => {
     store.dispatch(RootActions.forceUpdate());
   });
@@ -49,13 +58,46 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.setActiveWindow(event.payload));
     if (event.payload != 0) {
       store.dispatch(RootActions.setLastManagedActivated(event.payload));
+      store.dispatch(RootActions.activateTab(event.payload));
+    }
+  });
+
+  await listenGlobal<{ hwnd: HWND; title: string }>('update-window-title', (event) => {
+    store.dispatch(RootActions.setTitle(event.payload));
+  });
+
+  await listenGlobal<NativeSnapPayload>('native-snap', (event) => {
+    store.dispatch(RootActions.adoptNativeSnap(event.payload));
+  });
+
+  await listenGlobal<StackMode>('set-stack-mode', (event) => {
+    store.dispatch(RootActions.setStackMode(event.payload));
+  });
+
+  await listenGlobal<string>('export-layout', (event) => {
+    const { workspaces, activeWorkspace } = store.getState();
+    const structure = workspaces[activeWorkspace]?.layout.structure;
+    if (structure) {
+      invoke('wm_write_layout_tree', { path: event.payload, structure }).catch(console.error);
     }
   });
 
+  await listenGlobal<unknown>('apply-layout-tree', (event) => {
+    store.dispatch(RootActions.applyLayoutTree(event.payload));
+  });
+
+  await listenGlobal<CycleDirection>('cycle-tab', (event) => {
+    store.dispatch(RootActions.cycleTab(event.payload));
+  });
+
   await listenGlobal<Reservation | null>('set-reservation', (event) => {
     store.dispatch(RootActions.setReservation(event.payload));
   });
 
+  await listenGlobal<SplitOrientation>('set-split', (event) => {
+    store.dispatch(RootActions.setSplit(event.payload));
+  });
+
   await listenGlobal<Sizing>('update-width', (event) => {
     store.dispatch(RootActions.updateSizing({ axis: 'x', sizing: event.payload }));
   });
@@ -68,6 +110,10 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.resetSizing());
   });
 
+  await listenGlobal<void>('undo-reset-workspace-size', () => {
+    store.dispatch(RootActions.restoreSizing());
+  });
+
   await listenGlobal<FocusAction>('focus', (event) => {
     store.dispatch(RootActions.focus(event.payload));
   });
@@ -82,6 +128,8 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.setColors(event.payload));
   });
 
+  await listenWorkspaceAccent();
+
   await listenGlobal(FileChange.Themes, async () => {
     const userSettings = await new UserSettingsLoader().load();
     loadThemeCSS(userSettings);
@@ -1,6 +1,5 @@
 import { wrapConsole } from '../shared/ConsoleWrapper';
 import { invoke } from '@tauri-apps/api/core';
-import { PhysicalPosition, PhysicalSize } from '@tauri-apps/api/dpi';
 import { emitTo } from '@tauri-apps/api/event';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 
@@ -14,11 +13,16 @@ async function Main() {
   view.listen('init', () => {
     getCurrentWebviewWindow().show();
 
+    // the reveal delay and hover intent are handled by the background
     document.body.addEventListener('mouseenter', () => {
-      emitTo(main, 'mouseenter');
+      invoke('weg_hitbox_enter').catch(console.error);
       invoke('ensure_hitboxes_zorder').catch(console.error);
     });
 
+    document.body.addEventListener('mouseleave', () => {
+      invoke('weg_hitbox_leave').catch(console.error);
+    });
+
     async function onClick(e: MouseEvent | TouchEvent) {
       invoke('ensure_hitboxes_zorder').catch(console.error);
 
@@ -40,16 +44,15 @@ async function Main() {
 
     document.body.addEventListener('click', onClick);
     document.body.addEventListener('touchend', onClick);
-  });
 
-  view.listen('resize', (event) => {
-    const { width, height } = event.payload as any;
-    getCurrentWebviewWindow().setSize(new PhysicalSize(width, height));
-  });
+    // mouse events are not fired while dragging files, so drags are forwarded separately
+    document.body.addEventListener('dragover', (e) => {
+      emitTo(main, 'drag-over', { x: e.clientX, y: e.clientY });
+    });
 
-  view.listen('move', (event) => {
-    const { x, y } = event.payload as any;
-    getCurrentWebviewWindow().setPosition(new PhysicalPosition(x, y));
+    document.body.addEventListener('dragleave', () => {
+      emitTo(main, 'drag-leave');
+    });
   });
 
   view.listen('debug-hitbox', () => {
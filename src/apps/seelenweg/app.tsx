@@ -18,6 +18,7 @@ async function onMount() {
   await view.show();
   await view.emitTo(view.label, 'complete-setup');
   await emit('register-colors-events');
+  await emit('register-wallpaper-palette-events');
 }
 export function App() {
   const isDarkMode = useDarkMode();
@@ -1,7 +1,7 @@
-import { toPhysicalPixels } from '../shared';
+import { FocusedAppBehavior } from '../shared/schemas/AppsConfigurations';
 import { AppBarHideMode, SeelenWegSide } from '../shared/schemas/Seelenweg';
 import { debounce, TimeoutIdRef } from '../shared/Timing';
-import { emitTo } from '@tauri-apps/api/event';
+import { invoke } from '@tauri-apps/api/core';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 
 import { store } from './modules/shared/store/infra';
@@ -13,54 +13,49 @@ const root_container = document.getElementById('root')!;
 export const ExtraCallbacksOnLeave = new CallbacksManager();
 export const ExtraCallbacksOnActivate = new CallbacksManager();
 
-export const updateHitbox = debounce(async () => {
+export const updateHitbox = debounce(() => {
   const {
     isOverlaped,
+    focusedAppBehavior,
+    itemsOnLeft,
+    itemsOnCenter,
+    itemsOnRight,
     settings: { position, hideMode },
   } = store.getState();
-  const view = getCurrentWebviewWindow();
-  const hitboxTarget = view.label.replace('/', '-hitbox/');
-
-  const viewPosition = await view.innerPosition();
-  const viewSize = await view.innerSize();
 
   const isAutoHideOn =
-    (hideMode !== AppBarHideMode.Never && isOverlaped) || hideMode === AppBarHideMode.Always;
+    (hideMode !== AppBarHideMode.Never && isOverlaped) ||
+    hideMode === AppBarHideMode.Always ||
+    focusedAppBehavior === FocusedAppBehavior.Hide;
   const isHorizontal = position === SeelenWegSide.TOP || position === SeelenWegSide.BOTTOM;
 
-  const hiddenOffsetTop =
-    position === SeelenWegSide.TOP ? viewPosition.y : viewPosition.y + viewSize.height - 1;
-  const hiddenOffsetLeft =
-    position === SeelenWegSide.LEFT ? viewPosition.x : viewPosition.x + viewSize.width - 1;
-  emitTo(hitboxTarget, 'move', {
-    x:
-      isAutoHideOn && !isHorizontal
-        ? hiddenOffsetLeft
-        : viewPosition.x + toPhysicalPixels(root_container.offsetLeft),
-    y:
-      isAutoHideOn && isHorizontal
-        ? hiddenOffsetTop
-        : viewPosition.y + toPhysicalPixels(root_container.offsetTop),
-  });
-  emitTo(hitboxTarget, 'resize', {
-    width: isAutoHideOn && !isHorizontal ? 1 : toPhysicalPixels(root_container.offsetWidth),
-    height: isAutoHideOn && isHorizontal ? 1 : toPhysicalPixels(root_container.offsetHeight),
-  });
+  // logical pixels, the background scales them using the dpi of the dock's monitor
+  invoke('weg_set_hitbox', {
+    layout: {
+      offset: isHorizontal ? root_container.offsetLeft : root_container.offsetTop,
+      length: isHorizontal ? root_container.offsetWidth : root_container.offsetHeight,
+      autoHide: isAutoHideOn,
+      itemCount: itemsOnLeft.length + itemsOnCenter.length + itemsOnRight.length,
+    },
+  }).catch(console.error);
 }, 300);
 
 export function registerDocumentEvents() {
   const timeoutId: TimeoutIdRef = { current: null };
   const webview = getCurrentWebviewWindow();
 
-  const onMouseLeave = debounce(
-    () => {
-      webview.setIgnoreCursorEvents(true);
-      ExtraCallbacksOnLeave.execute();
-      updateHitbox();
-    },
-    200,
-    timeoutId,
-  );
+  // the delay is read on each call, so changes on settings are applied without reloading
+  const onMouseLeave = () => {
+    debounce(
+      () => {
+        webview.setIgnoreCursorEvents(true);
+        ExtraCallbacksOnLeave.execute();
+        updateHitbox();
+      },
+      store.getState().settings.hideDelay,
+      timeoutId,
+    )();
+  };
 
   const onMouseEnter = () => {
     if (timeoutId.current) {
@@ -82,10 +77,30 @@ export function registerDocumentEvents() {
   root_container.addEventListener('mouseenter', onMouseEnter);
   webview.listen('mouseenter', onMouseEnter); // listener for hitbox
 
+  // the work area changed, so the available space for the items could be different
+  window.addEventListener('resize', () => updateHitbox());
+
   webview.listen<{ x: number; y: number }>('click', (event) => {
     let element = document.elementFromPoint(event.payload.x, event.payload.y);
     if (element && 'click' in element && typeof element.click === 'function') {
       element.click();
     }
   });
+
+  // files dragged over the hitbox, the window of the hovered item is activated after a dwell
+  webview.listen<{ x: number; y: number }>('drag-over', (event) => {
+    let element = document.elementFromPoint(event.payload.x, event.payload.y);
+    let item = element?.closest<HTMLElement>('[data-drag-hwnd]');
+    let hwnd = item ? Number(item.dataset.dragHwnd) : null;
+    invoke('weg_drag_over', { hwnd }).catch(console.error);
+  });
+
+  webview.listen('drag-leave', () => {
+    invoke('weg_drag_leave').catch(console.error);
+  });
+
+  // emitted by the idle monitor
+  webview.listen<boolean>('weg-set-dimmed', (event) => {
+    document.body.classList.toggle('dimmed', event.payload);
+  });
 }
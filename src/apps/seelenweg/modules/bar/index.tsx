@@ -1,3 +1,4 @@
+import { FocusedAppBehavior } from '../../../shared/schemas/AppsConfigurations';
 import { AppBarHideMode, SeelenWegMode, SeelenWegSide } from '../../../shared/schemas/Seelenweg';
 import { SavedSeparatorItem } from '../../../shared/schemas/SeelenWegItems';
 import { cx } from '../../../shared/styles';
@@ -10,12 +11,14 @@ import { useTranslation } from 'react-i18next';
 import { useDispatch, useSelector } from 'react-redux';
 
 import { BackgroundByLayersV2 } from '../../components/BackgroundByLayers/infra';
+import { CustomButton } from '../item/infra/CustomButton';
+import { DockSeparator, DockSpacer } from '../item/infra/LayoutItems';
 import { MediaSession } from '../item/infra/MediaSession';
 import { StartMenu } from '../item/infra/StartMenu';
 import { UserApplication } from '../item/infra/UserApplication';
 import { useAppActivation, useAppBlur } from '../shared/hooks/infra';
 
-import { RootActions, Selectors } from '../shared/store/app';
+import { isVisibleOnWorkspace, RootActions, Selectors } from '../shared/store/app';
 
 import { SpecialItemType, SwItem } from '../shared/store/domain';
 
@@ -29,7 +32,16 @@ const Separator2: SavedSeparatorItem = {
   type: SpecialItemType.Separator,
 };
 
-function shouldBeHidden(hideMode: AppBarHideMode, isActive: boolean, isOverlaped: boolean) {
+function shouldBeHidden(
+  hideMode: AppBarHideMode,
+  isActive: boolean,
+  isOverlaped: boolean,
+  focusedAppBehavior: FocusedAppBehavior | null,
+) {
+  // set on the app config of the focused app, so it applies whatever the hide mode
+  if (focusedAppBehavior === FocusedAppBehavior.Hide) {
+    return !isActive;
+  }
   let shouldBeHidden = false;
   switch (hideMode) {
     case AppBarHideMode.Always:
@@ -47,10 +59,18 @@ function shouldBeHidden(hideMode: AppBarHideMode, isActive: boolean, isOverlaped
 export function SeelenWeg() {
   const settings = useSelector(Selectors.settings);
   const isOverlaped = useSelector(Selectors.isOverlaped);
+  const focusedAppBehavior = useSelector(Selectors.focusedAppBehavior);
+  const layoutConstraints = useSelector(Selectors.layoutConstraints);
+
+  const activeWorkspace = useSelector(Selectors.activeWorkspace);
+  const itemsOnLeft = useSelector(Selectors.itemsOnLeft);
+  const itemsOnCenter = useSelector(Selectors.itemsOnCenter);
+  const itemsOnRight = useSelector(Selectors.itemsOnRight);
 
-  const pinnedOnLeft = useSelector(Selectors.itemsOnLeft);
-  const pinnedOnCenter = useSelector(Selectors.itemsOnCenter);
-  const pinnedOnRight = useSelector(Selectors.itemsOnRight);
+  const isVisible = (item: SwItem) => isVisibleOnWorkspace(item, activeWorkspace);
+  const pinnedOnLeft = itemsOnLeft.filter(isVisible);
+  const pinnedOnCenter = itemsOnCenter.filter(isVisible);
+  const pinnedOnRight = itemsOnRight.filter(isVisible);
 
   const [isActive, setActive] = useState(false);
 
@@ -88,30 +108,33 @@ export function SeelenWeg() {
     [settings],
   );
 
-  const onReorderPinned = useCallback((apps: (SavedSeparatorItem | SwItem)[]) => {
-    let extractedPinned: SwItem[] = [];
+  const onReorderPinned = useCallback(
+    (apps: (SavedSeparatorItem | SwItem)[]) => {
+      // items of other workspaces are not rendered, they are kept at the end of their side
+      const hidden = (items: SwItem[]) => items.filter((item) => !isVisible(item));
+      let extractedPinned: SwItem[] = [];
+
+      apps.forEach((app) => {
+        if (app === Separator1) {
+          dispatch(RootActions.setItemsOnLeft([...extractedPinned, ...hidden(itemsOnLeft)]));
+          extractedPinned = [];
+          return;
+        }
+
+        if (app === Separator2) {
+          dispatch(RootActions.setItemsOnCenter([...extractedPinned, ...hidden(itemsOnCenter)]));
+          extractedPinned = [];
+          return;
+        }
 
-    apps.forEach((app) => {
-      if (app === Separator1) {
-        dispatch(RootActions.setItemsOnLeft(extractedPinned));
-        extractedPinned = [];
-        return;
-      }
-
-      if (app === Separator2) {
-        dispatch(RootActions.setItemsOnCenter(extractedPinned));
-        extractedPinned = [];
-        return;
-      }
-
-      if (app.type !== SpecialItemType.Separator) {
         extractedPinned.push(app);
-      }
-    });
+      });
 
-    dispatch(RootActions.setItemsOnRight(extractedPinned));
-    savePinnedItems();
-  }, []);
+      dispatch(RootActions.setItemsOnRight([...extractedPinned, ...hidden(itemsOnRight)]));
+      savePinnedItems();
+    },
+    [itemsOnLeft, itemsOnCenter, itemsOnRight, activeWorkspace],
+  );
 
   const isHorizontal =
     settings.position === SeelenWegSide.TOP || settings.position === SeelenWegSide.BOTTOM;
@@ -123,16 +146,24 @@ export function SeelenWeg() {
         values={[...pinnedOnLeft, Separator1, ...pinnedOnCenter, Separator2, ...pinnedOnRight]}
         onReorder={onReorderPinned}
         axis={isHorizontal ? 'x' : 'y'}
+        onWheel={(e) => {
+          // the mouse wheel only scrolls vertically by default
+          if (layoutConstraints.scrollable && isHorizontal) {
+            e.currentTarget.scrollLeft += e.deltaY;
+          }
+        }}
         className={cx('taskbar', settings.position.toLowerCase(), {
           horizontal: isHorizontal,
           vertical: !isHorizontal,
           'full-width': settings.mode === SeelenWegMode.FULL_WIDTH,
-          hidden: shouldBeHidden(settings.hideMode, isActive, isOverlaped),
+          'multi-row': layoutConstraints.rows > 1,
+          scrollable: layoutConstraints.scrollable,
+          hidden: shouldBeHidden(settings.hideMode, isActive, isOverlaped, focusedAppBehavior),
         })}
       >
         <BackgroundByLayersV2 prefix="taskbar" />
         {[
-          ...pinnedOnLeft.map(ItemByType),
+          ...pinnedOnLeft.map(itemByType('left')),
           <Reorder.Item
             as="div"
             key="separator1"
@@ -143,7 +174,7 @@ export function SeelenWeg() {
             drag={false}
             style={getSeparatorComplementarySize(pinnedOnLeft.length, pinnedOnCenter.length)}
           />,
-          ...pinnedOnCenter.map(ItemByType),
+          ...pinnedOnCenter.map(itemByType('center')),
           <Reorder.Item
             as="div"
             key="separator2"
@@ -154,14 +185,27 @@ export function SeelenWeg() {
             drag={false}
             style={getSeparatorComplementarySize(pinnedOnRight.length, pinnedOnCenter.length)}
           />,
-          ...pinnedOnRight.map(ItemByType),
+          ...pinnedOnRight.map(itemByType('right')),
         ]}
       </Reorder.Group>
     </WithContextMenu>
   );
 }
 
-function ItemByType(item: SwItem) {
+/** layout items don't have an identity, so their keys are based on their position */
+const itemByType = (side: string) => (item: SwItem, index: number) => {
+  if (item.type === SpecialItemType.Separator) {
+    return <DockSeparator key={`${side}-separator-${index}`} item={item} />;
+  }
+
+  if (item.type === SpecialItemType.Spacer) {
+    return <DockSpacer key={`${side}-spacer-${index}`} item={item} />;
+  }
+
+  if (item.type === SpecialItemType.CustomButton) {
+    return <CustomButton key={`custom-button-${item.id}`} item={item} />;
+  }
+
   if (item.type === SpecialItemType.PinnedApp || item.type === SpecialItemType.TemporalApp) {
     return <UserApplication key={item.exe || item.opens[0] || item.title} item={item} />;
   }
@@ -175,4 +219,4 @@ function ItemByType(item: SwItem) {
   }
 
   return null;
-}
+};
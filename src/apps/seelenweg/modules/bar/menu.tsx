@@ -1,8 +1,10 @@
 import { savePinnedItems } from '../shared/store/storeApi';
 import { invoke } from '@tauri-apps/api/core';
+import { ask } from '@tauri-apps/plugin-dialog';
 import { Menu, MenuProps, Popover } from 'antd';
 import { ItemType } from 'antd/es/menu/interface';
 import { TFunction } from 'i18next';
+import { MouseEvent } from 'react';
 
 import { BackgroundByLayersV2 } from '../../components/BackgroundByLayers/infra';
 import { store } from '../shared/store/infra';
@@ -27,6 +29,20 @@ export function getSeelenWegMenu(t: TFunction): ItemType[] {
         store.dispatch(RootActions.addStartModule());
       },
     },
+    {
+      key: 'add-separator',
+      label: t('taskbar_menu.separator'),
+      onClick() {
+        store.dispatch(RootActions.addSeparator());
+      },
+    },
+    {
+      key: 'add-spacer',
+      label: t('taskbar_menu.spacer'),
+      onClick() {
+        store.dispatch(RootActions.addSpacer());
+      },
+    },
     {
       key: 'settings',
       label: t('taskbar_menu.settings'),
@@ -37,7 +53,22 @@ export function getSeelenWegMenu(t: TFunction): ItemType[] {
   ];
 }
 
-export function getMenuForItem(t: TFunction, item: SwPinnedApp | SwTemporalApp): ItemType[] {
+/** common presets, old games usually stutter with too many cores or on the first one */
+function getAffinityOptions() {
+  const all = 2 ** navigator.hardwareConcurrency - 1;
+  return [
+    { key: 'all', mask: null },
+    { key: 'first', mask: 1 },
+    { key: 'except_first', mask: all - 1 },
+  ];
+}
+
+export function getMenuForItem(
+  t: TFunction,
+  item: SwPinnedApp | SwTemporalApp,
+  isHung = false,
+  isOnAllDesktops = false,
+): ItemType[] {
   const isPinned = isPinnedApp(item);
 
   const pin = (side: AppsSides) => {
@@ -58,6 +89,20 @@ export function getMenuForItem(t: TFunction, item: SwPinnedApp | SwTemporalApp):
         savePinnedItems();
       },
     });
+
+    const { activeWorkspace } = store.getState();
+    if (activeWorkspace) {
+      menu.push({
+        key: 'weg_pin_workspace',
+        label: item.workspace
+          ? t('app_menu.show_on_all_workspaces')
+          : t('app_menu.only_this_workspace'),
+        onClick: () => {
+          const workspace = item.workspace ? null : activeWorkspace;
+          store.dispatch(RootActions.setPinnedAppWorkspace({ app: item, workspace }));
+        },
+      });
+    }
   } else {
     menu.push({
       key: 'weg_pin_app',
@@ -115,8 +160,120 @@ export function getMenuForItem(t: TFunction, item: SwPinnedApp | SwTemporalApp):
     },
   );
 
+  if (item.execution_path.startsWith('shell:AppsFolder')) {
+    menu.push(
+      {
+        key: 'weg_app_settings',
+        label: t('app_menu.app_settings'),
+        onClick: () =>
+          invoke('weg_open_app_settings', { executionPath: item.execution_path }).catch(
+            console.error,
+          ),
+      },
+      {
+        key: 'weg_uninstall_app',
+        label: t('app_menu.uninstall'),
+        // confirmation is asked by the backend
+        onClick: () =>
+          invoke('weg_uninstall_app', { executionPath: item.execution_path }).catch(
+            console.error,
+          ),
+        danger: true,
+      },
+    );
+  }
+
+  if (isPinned) {
+    menu.push({
+      key: 'weg_always_runas',
+      label: item.run_as_admin ? t('app_menu.stop_run_as') : t('app_menu.always_run_as'),
+      onClick: () =>
+        invoke('weg_set_run_as_admin', {
+          executionPath: item.execution_path,
+          enabled: !item.run_as_admin,
+        }).catch(console.error),
+    });
+  }
+
+  menu.push(
+    {
+      key: 'weg_shell_menu',
+      label: t('app_menu.more_options'),
+      onClick: ({ domEvent }) => {
+        const { screenX, screenY } = domEvent as MouseEvent;
+        // uwp apps have no file to show the menu for, so the menu is shown for its exe
+        const path = item.execution_path.startsWith('shell:') ? item.exe : item.execution_path;
+        invoke('open_shell_context_menu', {
+          path,
+          x: Math.round(screenX * window.devicePixelRatio),
+          y: Math.round(screenY * window.devicePixelRatio),
+        });
+      },
+    },
+  );
+
   if (item.opens.length) {
     menu.push(
+      {
+        key: 'weg_toggle_topmost',
+        label: t('app_menu.toggle_topmost'),
+        onClick: () => {
+          item.opens.forEach((hwnd) => invoke('window_toggle_topmost', { hwnd }));
+        },
+      },
+      {
+        key: 'weg_pin_to_all_desktops',
+        label: isOnAllDesktops
+          ? t('app_menu.unpin_from_all_desktops')
+          : t('app_menu.pin_to_all_desktops'),
+        onClick: () => {
+          const { pinnedToAllDesktops } = store.getState();
+          item.opens
+            .filter((hwnd) => pinnedToAllDesktops.includes(hwnd) === isOnAllDesktops)
+            .forEach((hwnd) => invoke('window_pin_to_all_desktops', { hwnd }));
+        },
+      },
+      {
+        key: 'weg_send_to_monitor',
+        label: t('app_menu.send_to_monitor'),
+        children: ['left', 'right', 'up', 'down'].map((target) => ({
+          key: `weg_send_to_monitor_${target}`,
+          label: t(`app_menu.monitor_directions.${target}`),
+          onClick: () => {
+            item.opens.forEach((hwnd) => invoke('wm_send_to_monitor', { hwnd, target }));
+          },
+        })),
+      },
+      {
+        key: 'weg_opacity',
+        label: t('app_menu.opacity'),
+        children: [100, 90, 75, 50].map((percent) => ({
+          key: `weg_opacity_${percent}`,
+          label: `${percent}%`,
+          onClick: () => {
+            const alpha = Math.round((percent * 255) / 100);
+            item.opens.forEach((hwnd) => invoke('window_set_opacity', { hwnd, alpha }));
+          },
+        })),
+      },
+      {
+        key: 'weg_priority',
+        label: t('app_menu.priority'),
+        children: ['Idle', 'BelowNormal', 'Normal', 'AboveNormal', 'High'].map((level) => ({
+          key: `weg_priority_${level}`,
+          label: t(`app_menu.priority_levels.${level}`),
+          onClick: () => invoke('set_process_priority', { hwnd: item.opens[0], level }),
+        })),
+      },
+      {
+        key: 'weg_affinity',
+        label: t('app_menu.affinity'),
+        children: getAffinityOptions().map(({ key, mask }) => ({
+          key: `weg_affinity_${key}`,
+          label: t(`app_menu.affinity_options.${key}`),
+          onClick: () => invoke('set_process_affinity', { hwnd: item.opens[0], mask }),
+        })),
+      },
       {
         key: 'weg_copy_hwnd',
         label: t('app_menu.copy_handles'),
@@ -129,8 +286,9 @@ export function getMenuForItem(t: TFunction, item: SwPinnedApp | SwTemporalApp):
         key: 'weg_close_app',
         label: item.opens.length > 1 ? t('app_menu.close_multiple') : t('app_menu.close'),
         onClick() {
-          item.opens.forEach((hwnd) => {
-            invoke('weg_close_app', { hwnd });
+          invoke('weg_close_windows', {
+            hwnds: item.opens,
+            executionPath: item.execution_path,
           });
         },
         danger: true,
@@ -138,5 +296,23 @@ export function getMenuForItem(t: TFunction, item: SwPinnedApp | SwTemporalApp):
     );
   }
 
+  if (isHung) {
+    menu.push({
+      key: 'weg_end_task',
+      label: t('app_menu.end_task'),
+      async onClick() {
+        const name = item.exe.split('\\').pop();
+        const confirmed = await ask(t('app_menu.end_task_confirm', { name }), { kind: 'warning' });
+        if (confirmed) {
+          const { hungWindows } = store.getState();
+          item.opens
+            .filter((hwnd) => hungWindows.includes(hwnd))
+            .forEach((hwnd) => invoke('weg_kill_app', { hwnd }));
+        }
+      },
+      danger: true,
+    });
+  }
+
   return menu;
 }
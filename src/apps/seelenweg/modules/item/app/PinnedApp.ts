@@ -21,7 +21,9 @@ export class SwPinnedAppUtils {
       icon,
       exe: item.exe,
       execution_path: item.execution_path,
-      title: '',
+      workspace: item.workspace,
+      run_as_admin: item.run_as_admin,
+      title: await invoke<string>('get_app_display_name', { path: item.exe }).catch(() => ''),
       opens: [],
     };
   }
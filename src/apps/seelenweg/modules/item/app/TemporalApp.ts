@@ -25,7 +25,7 @@ export class SwTemporalAppUtils {
       icon: item.icon || '',
       exe: item.exe,
       execution_path: item.execution_path,
-      title: item.exe.split('\\').at(-1) || 'Unknown',
+      title: item.display_name || item.exe.split('\\').at(-1) || 'Unknown',
       opens: [item.hwnd],
     };
   }
@@ -0,0 +1,55 @@
+import { Icon, IconName, isValidIconName } from '../../../../shared/components/Icon';
+import { SavedCustomButton } from '../../../../shared/schemas/SeelenWegItems';
+import { WithContextMenu } from '../../../components/WithContextMenu';
+import { DraggableItem } from './DraggableItem';
+import { getMenuForItem } from './Menu';
+import { invoke } from '@tauri-apps/api/core';
+import { emit } from '@tauri-apps/api/event';
+import { Tooltip } from 'antd';
+import { motion } from 'framer-motion';
+import { useTranslation } from 'react-i18next';
+import { useSelector } from 'react-redux';
+
+import { BackgroundByLayersV2 } from '../../../components/BackgroundByLayers/infra';
+
+import { Selectors } from '../../shared/store/app';
+
+export function CustomButton({ item }: { item: SavedCustomButton }) {
+  const size = useSelector(Selectors.settings.size);
+
+  const { t } = useTranslation();
+
+  const iconName =
+    item.icon && isValidIconName(item.icon) ? (item.icon as IconName) : 'TbTerminal2';
+
+  const onClick = () => {
+    if (item.command) {
+      invoke('run', { program: 'cmd', args: ['/C', item.command] }).catch(console.error);
+    }
+    if (item.event) {
+      emit(item.event, item.id).catch(console.error);
+    }
+  };
+
+  return (
+    <DraggableItem item={item}>
+      <WithContextMenu items={getMenuForItem(t, item)}>
+        <Tooltip title={item.tooltip || null} showArrow={false}>
+          <motion.div
+            className="weg-item weg-custom-button"
+            initial={{ scale: 0 }}
+            animate={{ scale: 1 }}
+            style={{ height: size, aspectRatio: '1/1' }}
+            onClick={onClick}
+            onContextMenu={(e) => e.stopPropagation()}
+          >
+            <BackgroundByLayersV2 prefix="item" />
+            <div className="weg-item-icon">
+              <Icon iconName={iconName} />
+            </div>
+          </motion.div>
+        </Tooltip>
+      </WithContextMenu>
+    </DraggableItem>
+  );
+}
@@ -1,3 +1,4 @@
+import { cx } from '../../../../shared/styles';
 import { Reorder } from 'framer-motion';
 import { PropsWithChildren, useRef } from 'react';
 
@@ -5,16 +6,17 @@ import { SwItem } from '../../shared/store/domain';
 
 interface Props extends PropsWithChildren {
   item: SwItem;
+  className?: string;
 }
 
-export function DraggableItem({ children, item }: Props) {
+export function DraggableItem({ children, item, className }: Props) {
   const isDragging = useRef(false);
 
   return (
     <Reorder.Item
       as="div"
       value={item}
-      className="weg-item-drag-container"
+      className={cx('weg-item-drag-container', className)}
       onDragStart={() => {
         isDragging.current = true;
       }}
@@ -0,0 +1,29 @@
+import { SavedSeparatorItem, SavedSpacerItem } from '../../../../shared/schemas/SeelenWegItems';
+import { WithContextMenu } from '../../../components/WithContextMenu';
+import { DraggableItem } from './DraggableItem';
+import { getMenuForItem } from './Menu';
+import { useTranslation } from 'react-i18next';
+
+export function DockSeparator({ item }: { item: SavedSeparatorItem }) {
+  const { t } = useTranslation();
+
+  return (
+    <DraggableItem item={item}>
+      <WithContextMenu items={getMenuForItem(t, item)}>
+        <div className="weg-layout-separator" onContextMenu={(e) => e.stopPropagation()} />
+      </WithContextMenu>
+    </DraggableItem>
+  );
+}
+
+export function DockSpacer({ item }: { item: SavedSpacerItem }) {
+  const { t } = useTranslation();
+
+  return (
+    <DraggableItem item={item} className="weg-layout-spacer-container">
+      <WithContextMenu items={getMenuForItem(t, item)}>
+        <div className="weg-layout-spacer" onContextMenu={(e) => e.stopPropagation()} />
+      </WithContextMenu>
+    </DraggableItem>
+  );
+}
@@ -33,5 +33,33 @@ export function getMenuForItem(t: TFunction, item: SwItem): ItemType[] {
     ];
   }
 
+  if (item.type === SwItemType.Separator || item.type === SwItemType.Spacer) {
+    return [
+      {
+        key: 'remove',
+        label: t(
+          item.type === SwItemType.Separator
+            ? 'layout_item_menu.remove_separator'
+            : 'layout_item_menu.remove_spacer',
+        ),
+        onClick() {
+          store.dispatch(RootActions.removeItem(item));
+        },
+      },
+    ];
+  }
+
+  if (item.type === SwItemType.CustomButton) {
+    return [
+      {
+        key: 'remove',
+        label: t('layout_item_menu.remove_button'),
+        onClick() {
+          store.dispatch(RootActions.removeItem(item));
+        },
+      },
+    ];
+  }
+
   return [];
 }
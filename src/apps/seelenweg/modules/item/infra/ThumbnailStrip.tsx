@@ -0,0 +1,97 @@
+import { invoke } from '@tauri-apps/api/core';
+import { MouseEvent, useEffect, useRef } from 'react';
+import { useSelector } from 'react-redux';
+
+import { HWND, RootState, SwPinnedApp, SwTemporalApp } from '../../shared/store/domain';
+
+/** items with more windows than this are previewed with the scrubbable strip */
+export const SCRUB_THRESHOLD = 8;
+
+interface Props {
+  item: SwPinnedApp | SwTemporalApp;
+  /** windows of the item to show, can be filtered by a search */
+  hwnds: HWND[];
+}
+
+/**
+ * Live DWM thumbnails of the windows of a grouped item. Moving the cursor over the strip
+ * scrubs through the windows, only the slots fully visible have a thumbnail registered.
+ */
+export function ThumbnailStrip({ item, hwnds }: Props) {
+  const ref = useRef<HTMLDivElement>(null);
+  const frame = useRef<number | null>(null);
+  const openApps = useSelector((state: RootState) => state.openApps);
+
+  const sync = () => {
+    frame.current = null;
+    const container = ref.current;
+    if (!container) {
+      return;
+    }
+
+    const bounds = container.getBoundingClientRect();
+    const scale = window.devicePixelRatio;
+    const slots = hwnds.flatMap((hwnd, index) => {
+      const thumbnail = container.children[index]?.querySelector('.weg-scrub-slot-thumbnail');
+      const rect = thumbnail?.getBoundingClientRect();
+      // thumbnails are drawn over the webview by DWM and can't be clipped by the container
+      if (!rect || rect.left < bounds.left || rect.right > bounds.right) {
+        return [];
+      }
+      return [
+        {
+          hwnd,
+          rect: {
+            left: Math.round(rect.left * scale),
+            top: Math.round(rect.top * scale),
+            right: Math.round(rect.right * scale),
+            bottom: Math.round(rect.bottom * scale),
+          },
+        },
+      ];
+    });
+    invoke('weg_scrub_thumbnails', { slots }).catch(console.error);
+  };
+
+  const scheduleSync = () => {
+    if (frame.current === null) {
+      frame.current = requestAnimationFrame(sync);
+    }
+  };
+
+  useEffect(() => {
+    // wait for the popover opening animation to place the slots
+    const timeout = setTimeout(scheduleSync, 200);
+    return () => {
+      clearTimeout(timeout);
+      if (frame.current !== null) {
+        cancelAnimationFrame(frame.current);
+      }
+      invoke('weg_scrub_end').catch(console.error);
+    };
+  }, [hwnds]);
+
+  const onMouseMove = (e: MouseEvent<HTMLDivElement>) => {
+    const container = e.currentTarget;
+    const bounds = container.getBoundingClientRect();
+    const progress = (e.clientX - bounds.left) / bounds.width;
+    container.scrollLeft = progress * (container.scrollWidth - container.clientWidth);
+  };
+
+  return (
+    <div className="weg-scrub-strip" ref={ref} onMouseMove={onMouseMove} onScroll={scheduleSync}>
+      {hwnds.map((hwnd) => (
+        <div
+          key={hwnd}
+          className="weg-scrub-slot"
+          onClick={() =>
+            invoke('weg_toggle_window_state', { hwnd, exePath: item.execution_path })
+          }
+        >
+          <div className="weg-scrub-slot-title">{openApps[hwnd]?.title}</div>
+          <div className="weg-scrub-slot-thumbnail" />
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -1,7 +1,9 @@
+import { Icon } from '../../../../shared/components/Icon';
 import { cx } from '../../../../shared/styles';
 import { WithContextMenu } from '../../../components/WithContextMenu';
 import { getMenuForItem } from '../../bar/menu';
 import { DraggableItem } from './DraggableItem';
+import { SCRUB_THRESHOLD, ThumbnailStrip } from './ThumbnailStrip';
 import { UserApplicationPreview } from './UserApplicationPreview';
 import { invoke } from '@tauri-apps/api/core';
 import { Popover } from 'antd';
@@ -16,7 +18,20 @@ import { updatePreviews } from '../../shared/utils/infra';
 
 import { Selectors } from '../../shared/store/app';
 
-import { RootState, SwPinnedApp, SwTemporalApp } from '../../shared/store/domain';
+import { HWND, RootState, SwPinnedApp, SwTemporalApp } from '../../shared/store/domain';
+
+function formatBytes(bytes: number) {
+  const mb = bytes / 1024 / 1024;
+  return mb >= 1024 ? `${(mb / 1024).toFixed(1)}G` : `${mb.toFixed(0)}M`;
+}
+
+function formatRate(bytes: number) {
+  const kb = bytes / 1024;
+  return kb >= 1024 ? `${(kb / 1024).toFixed(1)}M/s` : `${kb.toFixed(0)}K/s`;
+}
+
+/** how long the item flashes after a download completes on a pinned folder */
+const FLASH_DURATION = 3000;
 
 interface Props {
   item: SwPinnedApp | SwTemporalApp;
@@ -27,8 +42,35 @@ export const UserApplication = memo(({ item }: Props) => {
   const isFocused = useSelector(
     (state: RootState) => state.focusedApp && item.opens.includes(state.focusedApp.hwnd),
   );
+  const showUsage = useSelector(Selectors.settings.resourceUsage);
+  const showNetwork = useSelector(Selectors.settings.networkUsage);
+  const usage = useSelector((state: RootState) => state.resourceUsage[item.exe]);
+  const overlayIcon = useSelector((state: RootState) =>
+    item.opens.map((hwnd) => state.overlayIcons[hwnd]).find(Boolean),
+  );
+  const isHung = useSelector((state: RootState) =>
+    item.opens.some((hwnd) => state.hungWindows.includes(hwnd)),
+  );
+  // only shown if all the windows of the app are suspended
+  const isSuspended = useSelector(
+    (state: RootState) =>
+      !!item.opens.length && item.opens.every((hwnd) => state.suspendedWindows.includes(hwnd)),
+  );
+  // pinned apps without windows but still running on the tray
+  const isInBackground = useSelector(
+    (state: RootState) =>
+      !item.opens.length && state.backgroundApps.includes(item.exe.toLowerCase()),
+  );
+  const isOnAllDesktops = useSelector((state: RootState) =>
+    item.opens.some((hwnd) => state.pinnedToAllDesktops.includes(hwnd)),
+  );
+  const folderBadge = useSelector((state: RootState) => state.folderBadges[item.execution_path]);
+
+  const [flashing, setFlashing] = useState(false);
 
   const [openPreview, setOpenPreview] = useState(false);
+  const [query, setQuery] = useState('');
+  const [matches, setMatches] = useState<HWND[] | null>(null);
 
   const { t } = useTranslation();
 
@@ -37,7 +79,16 @@ export const UserApplication = memo(({ item }: Props) => {
   });
 
   useEffect(() => {
-    if (openPreview) {
+    if (!folderBadge?.completed) {
+      return;
+    }
+    setFlashing(true);
+    const timeout = setTimeout(() => setFlashing(false), FLASH_DURATION);
+    return () => clearTimeout(timeout);
+  }, [folderBadge]);
+
+  useEffect(() => {
+    if (openPreview && item.opens.length <= SCRUB_THRESHOLD) {
       updatePreviews(item.opens);
     }
   }, [openPreview]);
@@ -48,9 +99,29 @@ export const UserApplication = memo(({ item }: Props) => {
     }
   }, [item]);
 
+  useEffect(() => {
+    if (!openPreview) {
+      setQuery('');
+    }
+  }, [openPreview]);
+
+  useEffect(() => {
+    if (!query.trim()) {
+      setMatches(null);
+      return;
+    }
+    invoke<{ hwnd: HWND }[]>('weg_search_windows', { query })
+      .then((results) =>
+        setMatches(results.map((r) => r.hwnd).filter((hwnd) => item.opens.includes(hwnd))),
+      )
+      .catch(console.error);
+  }, [query, item.opens]);
+
+  const visibleOpens = matches || item.opens;
+
   return (
     <DraggableItem item={item}>
-      <WithContextMenu items={getMenuForItem(t, item) || []}>
+      <WithContextMenu items={getMenuForItem(t, item, isHung, isOnAllDesktops) || []}>
         <Popover
           open={openPreview}
           mouseEnterDelay={0.4}
@@ -64,17 +135,39 @@ export const UserApplication = memo(({ item }: Props) => {
               onMouseMoveCapture={(e) => e.stopPropagation()}
               prefix="preview"
             >
-              {item.opens.map((hwnd) => (
-                <UserApplicationPreview key={hwnd} hwnd={hwnd} />
-              ))}
+              {item.opens.length > 1 && (
+                <input
+                  className="weg-item-preview-search"
+                  placeholder={t('app_menu.search_windows')}
+                  value={query}
+                  onChange={(e) => setQuery(e.target.value)}
+                  onKeyDown={(e) => e.key === 'Escape' && setQuery('')}
+                />
+              )}
+              <div className="weg-item-preview-list">
+                {item.opens.length > SCRUB_THRESHOLD ? (
+                  // the popover keeps its content mounted, thumbnails are released on unmount
+                  openPreview && <ThumbnailStrip item={item} hwnds={visibleOpens} />
+                ) : (
+                  visibleOpens.map((hwnd) => (
+                    <UserApplicationPreview key={hwnd} hwnd={hwnd} open={openPreview} />
+                  ))
+                )}
+              </div>
             </BackgroundByLayersV2>
           }
         >
           <motion.div
-            className="weg-item"
+            className={cx('weg-item', {
+              'weg-item-hung': isHung,
+              'weg-item-suspended': isSuspended,
+              'weg-item-flashing': flashing,
+            })}
             initial={{ scale: 0 }}
             animate={{ scale: 1 }}
             style={{ height: size, aspectRatio: '1/1' }}
+            aria-label={item.title}
+            data-drag-hwnd={item.opens[0]}
             onClick={() => {
               let hwnd = item.opens[0] || 0;
               invoke('weg_toggle_window_state', { hwnd, exePath: item.execution_path });
@@ -83,10 +176,39 @@ export const UserApplication = memo(({ item }: Props) => {
           >
             <BackgroundByLayersV2 prefix="item" />
             <img className="weg-item-icon" src={item.icon} draggable={false} />
+            {overlayIcon && (
+              <img className="weg-item-overlay-icon" src={overlayIcon} draggable={false} />
+            )}
+            {item.run_as_admin && (
+              <div className="weg-item-admin-badge">
+                <Icon iconName="MdOutlineShield" propsIcon={{ size: 10 }} />
+              </div>
+            )}
+            {!!folderBadge?.count && (
+              <div className="weg-item-folder-badge">
+                {folderBadge.count > 99 ? '99+' : folderBadge.count}
+              </div>
+            )}
+            {showUsage && usage && !!item.opens.length && (
+              <div
+                className="weg-item-usage"
+                title={
+                  showNetwork
+                    ? `${t('usage.network')}: ↓ ${formatRate(usage.netReceived)} ↑ ${formatRate(usage.netSent)}`
+                    : undefined
+                }
+              >
+                <span>{usage.cpu.toFixed(0)}%</span>
+                <span>{formatBytes(usage.memory)}</span>
+              </div>
+            )}
             <div
               className={cx('weg-item-open-sign', {
                 'weg-item-open-sign-active': !!item.opens.length,
                 'weg-item-open-sign-focused': isFocused,
+                'weg-item-open-sign-hung': isHung,
+                'weg-item-open-sign-all-desktops': isOnAllDesktops,
+                'weg-item-open-sign-background': isInBackground,
               })}
             />
           </motion.div>
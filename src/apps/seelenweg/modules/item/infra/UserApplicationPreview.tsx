@@ -2,7 +2,7 @@ import { Icon } from '../../../../shared/components/Icon';
 import { convertFileSrc, invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { Spin } from 'antd';
-import { MouseEvent, useEffect, useReducer, useState } from 'react';
+import { MouseEvent, useEffect, useReducer, useRef, useState } from 'react';
 import { useSelector } from 'react-redux';
 
 import { LAZY_CONSTANTS } from '../../shared/utils/infra';
@@ -13,14 +13,123 @@ import { HWND } from '../../shared/store/domain';
 
 interface PreviewProps {
   hwnd: HWND;
+  /** thumbnails drawn by DWM are only registered while the preview is shown */
+  open: boolean;
 }
 
-export const UserApplicationPreview = ({ hwnd }: PreviewProps) => {
+interface WegTab {
+  hwnd: HWND;
+  title: string;
+}
+
+interface PreviewCardProps {
+  title: string;
+  imageSrc: string | null;
+  onError: () => void;
+  onClick: () => void;
+  onClose: (e: MouseEvent) => void;
+}
+
+const PreviewCard = ({ title, imageSrc, onError, onClick, onClose }: PreviewCardProps) => {
+  return (
+    <div className="weg-item-preview" onClick={onClick}>
+      <div className="weg-item-preview-topbar">
+        <div className="weg-item-preview-title">{title}</div>
+        <div className="weg-item-preview-close" onClick={onClose}>
+          <Icon iconName="IoClose" />
+        </div>
+      </div>
+      <div className="weg-item-preview-image-container">
+        {imageSrc ? (
+          <img
+            className="weg-item-preview-image"
+            src={imageSrc + `?${new Date().getTime()}`}
+            onError={onError}
+          />
+        ) : (
+          <Spin className="weg-item-preview-spin" />
+        )}
+      </div>
+    </div>
+  );
+};
+
+/**
+ * Tabs are previewed with live DWM thumbnails drawn over the card, the app provides the
+ * bitmap of each tab on demand so these can't be captured as the windows are.
+ */
+const TabPreviews = ({ hwnd, tabs, open }: { hwnd: HWND; tabs: WegTab[]; open: boolean }) => {
+  const ref = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    if (!open) {
+      return;
+    }
+    const sync = () => {
+      const scale = window.devicePixelRatio;
+      const containers = ref.current?.querySelectorAll('.weg-item-preview-image-container') || [];
+      const slots = tabs.flatMap((tab, index) => {
+        const rect = containers[index]?.getBoundingClientRect();
+        if (!rect) {
+          return [];
+        }
+        return [
+          {
+            hwnd: tab.hwnd,
+            rect: {
+              left: Math.round(rect.left * scale),
+              top: Math.round(rect.top * scale),
+              right: Math.round(rect.right * scale),
+              bottom: Math.round(rect.bottom * scale),
+            },
+          },
+        ];
+      });
+      invoke('weg_tab_thumbnails', { owner: hwnd, slots }).catch(console.error);
+    };
+    // wait for the popover opening animation to place the cards
+    const timeout = setTimeout(sync, 200);
+    return () => {
+      clearTimeout(timeout);
+      invoke('weg_tab_thumbnails_end', { owner: hwnd }).catch(console.error);
+    };
+  }, [tabs, open]);
+
+  return (
+    <div className="weg-item-preview-tabs" ref={ref}>
+      {tabs.map((tab) => (
+        <div
+          key={tab.hwnd}
+          className="weg-item-preview"
+          onClick={() => invoke('weg_activate_tab', { hwnd, tab: tab.hwnd })}
+        >
+          <div className="weg-item-preview-topbar">
+            <div className="weg-item-preview-title">{tab.title}</div>
+            <div
+              className="weg-item-preview-close"
+              onClick={(e) => {
+                e.stopPropagation();
+                // apps close the tab when its proxy window receives WM_CLOSE
+                invoke('weg_close_app', { hwnd: tab.hwnd });
+              }}
+            >
+              <Icon iconName="IoClose" />
+            </div>
+          </div>
+          <div className="weg-item-preview-image-container" />
+        </div>
+      ))}
+    </div>
+  );
+};
+
+export const UserApplicationPreview = ({ hwnd, open }: PreviewProps) => {
   const app = useSelector(SelectOpenApp(hwnd));
 
   const imageUrl = convertFileSrc(`${LAZY_CONSTANTS.TEMP_FOLDER}${app?.hwnd || 0}.png`);
 
   const [imageSrc, setImageSrc] = useState<string | null>(imageUrl);
+  const [tabs, setTabs] = useState<WegTab[]>([]);
   const [_, forceUpdate] = useReducer((x) => x + 1, 0);
 
   useEffect(() => {
@@ -28,8 +137,12 @@ export const UserApplicationPreview = ({ hwnd }: PreviewProps) => {
       setImageSrc(imageUrl);
       forceUpdate();
     });
+    const unlistenTabs = listen<WegTab[]>(`weg-preview-tabs-${app?.hwnd || 0}`, (event) => {
+      setTabs(event.payload);
+    });
     return () => {
       unlisten.then((unlisten) => unlisten()).catch(console.error);
+      unlistenTabs.then((unlisten) => unlisten()).catch(console.error);
     };
   }, []);
 
@@ -42,30 +155,19 @@ export const UserApplicationPreview = ({ hwnd }: PreviewProps) => {
     return null;
   }
 
+  if (tabs.length > 0) {
+    return <TabPreviews hwnd={app.hwnd} tabs={tabs} open={open} />;
+  }
+
   return (
-    <div
-      className="weg-item-preview"
+    <PreviewCard
+      title={app.title}
+      imageSrc={imageSrc}
+      onError={() => setImageSrc(null)}
       onClick={() =>
         invoke('weg_toggle_window_state', { hwnd: app.hwnd || 0, exePath: app.execution_path })
       }
-    >
-      <div className="weg-item-preview-topbar">
-        <div className="weg-item-preview-title">{app.title}</div>
-        <div className="weg-item-preview-close" onClick={onClose}>
-          <Icon iconName="IoClose" />
-        </div>
-      </div>
-      <div className="weg-item-preview-image-container">
-        {imageSrc ? (
-          <img
-            className="weg-item-preview-image"
-            src={imageSrc + `?${new Date().getTime()}`}
-            onError={() => setImageSrc(null)}
-          />
-        ) : (
-          <Spin className="weg-item-preview-spin" />
-        )}
-      </div>
-    </div>
+      onClose={onClose}
+    />
   );
 };
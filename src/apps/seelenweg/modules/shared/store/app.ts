@@ -1,6 +1,6 @@
 import { StateBuilder } from '../../../../shared/StateBuilder';
 import { savePinnedItems } from './storeApi';
-import { createSlice, current, PayloadAction } from '@reduxjs/toolkit';
+import { createSlice, current, original, PayloadAction } from '@reduxjs/toolkit';
 
 import { SeelenWegSlice } from '../../bar/app';
 import { SwTemporalAppUtils } from '../../item/app/TemporalApp';
@@ -8,6 +8,7 @@ import { SwTemporalAppUtils } from '../../item/app/TemporalApp';
 import {
   AppFromBackground,
   AppsSides,
+  FolderBadge,
   HWND,
   RootState,
   SpecialItemType,
@@ -23,6 +24,7 @@ const initialState: RootState = {
   openApps: {},
   focusedApp: null,
   isOverlaped: false,
+  focusedAppBehavior: null,
   settings: SeelenWegSlice.getInitialState(),
   mediaSessions: [],
   colors: {
@@ -37,6 +39,20 @@ const initialState: RootState = {
     accent_lightest: '#000000',
     complement: null,
   },
+  activeWorkspace: null,
+  hungWindows: [],
+  suspendedWindows: [],
+  backgroundApps: [],
+  pinnedToAllDesktops: [],
+  resourceUsage: {},
+  overlayIcons: {},
+  folderBadges: {},
+  layoutConstraints: {
+    rows: 1,
+    itemsPerRow: Number.MAX_SAFE_INTEGER,
+    itemScale: 1,
+    scrollable: false,
+  },
 };
 
 function removeAppFromState(state: RootState, searched: SwPinnedApp | SwTemporalApp) {
@@ -75,10 +91,23 @@ export const RootSlice = createSlice({
   initialState,
   reducers: {
     ...StateBuilder.reducersFor(initialState),
+    setOverlayIcon(state, action: PayloadAction<{ hwnd: HWND; icon: string | null }>) {
+      const { hwnd, icon } = action.payload;
+      if (icon) {
+        state.overlayIcons[hwnd] = icon;
+      } else {
+        delete state.overlayIcons[hwnd];
+      }
+    },
+    setFolderBadge(state, action: PayloadAction<FolderBadge>) {
+      state.folderBadges[action.payload.path] = action.payload;
+    },
     unPin(state, action: PayloadAction<SwPinnedApp | SwTemporalApp>) {
       const found = findApp(state, action.payload);
       if (found) {
         found.type = SpecialItemType.TemporalApp;
+        found.workspace = null;
+        found.run_as_admin = false;
         if (found.opens.length === 0) {
           removeAppFromState(state, found);
         }
@@ -106,6 +135,16 @@ export const RootSlice = createSlice({
         default:
       }
     },
+    setPinnedAppWorkspace(
+      state,
+      action: PayloadAction<{ app: SwPinnedApp; workspace: string | null }>,
+    ) {
+      const found = findApp(state, action.payload.app);
+      if (found && found.type === SpecialItemType.PinnedApp) {
+        found.workspace = action.payload.workspace;
+      }
+      savePinnedItems(current(state));
+    },
     addMediaModule(state) {
       const all = [...state.itemsOnLeft, ...state.itemsOnCenter, ...state.itemsOnRight];
       if (!all.some((current) => current.type === SpecialItemType.Media)) {
@@ -138,6 +177,30 @@ export const RootSlice = createSlice({
       state.itemsOnRight = state.itemsOnRight.filter(filter);
       savePinnedItems(current(state));
     },
+    addSeparator(state) {
+      state.itemsOnCenter.push({
+        type: SpecialItemType.Separator,
+      });
+      savePinnedItems(current(state));
+    },
+    addSpacer(state) {
+      state.itemsOnCenter.push({
+        type: SpecialItemType.Spacer,
+      });
+      savePinnedItems(current(state));
+    },
+    /** removes the exact item, separators and spacers don't have any other identity */
+    removeItem(state, action: PayloadAction<SwItem>) {
+      const base = original(state)!;
+      for (const side of ['itemsOnLeft', 'itemsOnCenter', 'itemsOnRight'] as const) {
+        const index = base[side].indexOf(action.payload);
+        if (index !== -1) {
+          state[side].splice(index, 1);
+          break;
+        }
+      }
+      savePinnedItems(current(state));
+    },
     addOpenApp(state, action: PayloadAction<AppFromBackground>) {
       const app = action.payload;
 
@@ -154,6 +217,7 @@ export const RootSlice = createSlice({
           if (!pinedApp.opens.includes(app.hwnd)) {
             pinedApp.opens.push(app.hwnd);
           }
+          pinedApp.title = app.display_name || pinedApp.title;
 
           // update path to pinned apps normally changed on updates
           if (pinedApp.exe !== app.exe) {
@@ -198,6 +262,14 @@ export const isPinnedApp = (item: SwItem): item is SwPinnedApp => {
   return item.type === SpecialItemType.PinnedApp;
 };
 
+/** Pinned apps scoped to other workspace are hidden, unless they have open windows */
+export const isVisibleOnWorkspace = (item: SwItem, workspace: string | null) => {
+  if (item.type !== SpecialItemType.PinnedApp || !item.workspace || !workspace) {
+    return true;
+  }
+  return item.workspace === workspace || item.opens.length > 0;
+};
+
 export const isTemporalApp = (item: SwItem): item is SwTemporalApp => {
   return item.type === SpecialItemType.TemporalApp;
 };
@@ -1,10 +1,13 @@
 import { IRootState } from '../../../../../shared.interfaces';
 import { FocusedApp } from '../../../../shared/interfaces/common';
+import { FocusedAppBehavior } from '../../../../shared/schemas/AppsConfigurations';
 import { Seelenweg } from '../../../../shared/schemas/Seelenweg';
 import {
+  SavedCustomButton,
   SavedMediaItem,
   SavedPinnedApp,
   SavedSeparatorItem,
+  SavedSpacerItem,
   StartMenuItem,
   SwItemType as SpecialItemType,
 } from '../../../../shared/schemas/SeelenWegItems';
@@ -16,6 +19,8 @@ export { SpecialItemType };
 
 export interface AppFromBackground {
   title: string;
+  /** localized name of the executable */
+  display_name: string;
   exe: string;
   execution_path: string;
   icon: string;
@@ -56,7 +61,24 @@ export type SwTemporalApp = modify<
   }
 >;
 
-export type SwItem = SwPinnedApp | SwTemporalApp | SavedSeparatorItem | SavedMediaItem | StartMenuItem;
+export type SwItem =
+  | SwPinnedApp
+  | SwTemporalApp
+  | SavedSeparatorItem
+  | SavedSpacerItem
+  | SavedCustomButton
+  | SavedMediaItem
+  | StartMenuItem;
+
+export interface FolderBadge {
+  /** folder path as it was pinned */
+  path: string;
+  count: number;
+  /** name of the most recently modified item */
+  newest: string | null;
+  /** a new item finished arriving, e.g. a completed download */
+  completed: boolean;
+}
 
 export interface UIColors {
   background: string;
@@ -71,6 +93,31 @@ export interface UIColors {
   complement: string | null;
 }
 
+export interface WallpaperPalette {
+  wallpaper_primary: string;
+  wallpaper_secondary: string;
+  wallpaper_tertiary: string;
+}
+
+export interface ResourceUsage {
+  /** percentage of the whole system */
+  cpu: number;
+  /** bytes */
+  memory: number;
+  /** bytes per second, 0 if the network usage is not sampled */
+  netReceived: number;
+  netSent: number;
+}
+
+/** calculated by the background from the amount of items and the size of the monitor */
+export interface LayoutConstraints {
+  rows: number;
+  itemsPerRow: number;
+  /** factor applied to the item size when auto scale shrinks the items */
+  itemScale: number;
+  scrollable: boolean;
+}
+
 export interface RootState extends IRootState<Seelenweg> {
   itemsOnLeft: SwItem[];
   itemsOnCenter: SwItem[];
@@ -79,6 +126,23 @@ export interface RootState extends IRootState<Seelenweg> {
   // ----------------------
   focusedApp: FocusedApp | null;
   isOverlaped: boolean;
+  /** set on the app config of the focused app */
+  focusedAppBehavior: FocusedAppBehavior | null;
   mediaSessions: MediaSession[];
   colors: UIColors;
+  activeWorkspace: string | null;
+  hungWindows: HWND[];
+  /** suspended uwp windows, only tracked if they are shown on the dock */
+  suspendedWindows: HWND[];
+  /** lowercased exe paths of the pinned apps running only on the tray */
+  backgroundApps: string[];
+  /** windows shown on all the virtual desktops */
+  pinnedToAllDesktops: HWND[];
+  /** keyed by exe path */
+  resourceUsage: Record<string, ResourceUsage>;
+  /** png data urls set by apps as overlay of their taskbar button */
+  overlayIcons: Record<HWND, string>;
+  /** pinned folders, keyed by path */
+  folderBadges: Record<string, FolderBadge>;
+  layoutConstraints: LayoutConstraints;
 }
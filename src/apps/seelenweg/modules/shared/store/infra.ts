@@ -1,15 +1,22 @@
 import { UserSettingsLoader } from '../../../../settings/modules/shared/store/storeApi';
-import { loadThemeCSS, setColorsAsCssVariables } from '../../../../shared';
+import {
+  listenGameMode,
+  listenWorkspaceAccent,
+  loadThemeCSS,
+  setColorsAsCssVariables,
+} from '../../../../shared';
 import { FileChange, GlobalEvent } from '../../../../shared/events';
 import { FocusedApp } from '../../../../shared/interfaces/common';
+import { FocusedAppBehavior } from '../../../../shared/schemas/AppsConfigurations';
 import { Seelenweg, SeelenWegMode, SeelenWegSide } from '../../../../shared/schemas/Seelenweg';
 import { SwItemType, SwSavedItem } from '../../../../shared/schemas/SeelenWegItems';
 import { Theme } from '../../../../shared/schemas/Theme';
 import { updateHitbox } from '../../../events';
 import i18n from '../../../i18n';
-import { IsSavingPinnedItems, loadPinnedItems } from './storeApi';
+import { IsSavingPinnedItems, loadPinnedItems, savePinnedItems } from './storeApi';
 import { configureStore } from '@reduxjs/toolkit';
-import { listen as listenGlobal } from '@tauri-apps/api/event';
+import { invoke } from '@tauri-apps/api/core';
+import { emit, listen as listenGlobal } from '@tauri-apps/api/event';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 import { debounce } from 'lodash';
 
@@ -17,12 +24,56 @@ import { SwPinnedAppUtils } from '../../item/app/PinnedApp';
 import { SwTemporalAppUtils } from '../../item/app/TemporalApp';
 import { RootActions, RootSlice } from './app';
 
-import { AppFromBackground, HWND, MediaSession, SwItem, UIColors } from './domain';
+import {
+  AppFromBackground,
+  FolderBadge,
+  HWND,
+  LayoutConstraints,
+  MediaSession,
+  ResourceUsage,
+  SwItem,
+  UIColors,
+  WallpaperPalette,
+} from './domain';
 
 export const store = configureStore({
   reducer: RootSlice.reducer,
 });
 
+enum Posture {
+  Desktop = 'Desktop',
+  Tablet = 'Tablet',
+}
+
+/** icons and spacing are enlarged on convertible devices used as tablet */
+const TABLET_SCALE = 1.25;
+let currentPosture = Posture.Desktop;
+/** calculated by the background when auto scale shrinks the items to fit the monitor */
+let currentItemScale = 1;
+
+function scaleForPosture(settings: Seelenweg): Seelenweg {
+  if (currentPosture !== Posture.Tablet) {
+    return settings;
+  }
+  return {
+    ...settings,
+    size: Math.round(settings.size * TABLET_SCALE),
+    zoomSize: Math.round(settings.zoomSize * TABLET_SCALE),
+    spaceBetweenItems: Math.round(settings.spaceBetweenItems * TABLET_SCALE),
+  };
+}
+
+function scaleForLayout(settings: Seelenweg): Seelenweg {
+  if (currentItemScale === 1) {
+    return settings;
+  }
+  return {
+    ...settings,
+    size: Math.round(settings.size * currentItemScale),
+    zoomSize: Math.round(settings.zoomSize * currentItemScale),
+  };
+}
+
 async function cleanItems(items: AppFromBackground[]): Promise<AppFromBackground[]> {
   const result: AppFromBackground[] = [];
   for (const item of items) {
@@ -49,8 +100,9 @@ async function cleanSavedItems(items: SwSavedItem[]): Promise<SwItem[]> {
 export async function registerStoreEvents() {
   const view = getCurrentWebviewWindow();
   const updateHitboxIfNeeded = () => {
-    const { mode } = store.getState().settings;
-    if (mode === SeelenWegMode.MIN_CONTENT) {
+    const { mode, maxRows } = store.getState().settings;
+    // the amount of items is needed by the background to calculate the rows
+    if (mode === SeelenWegMode.MIN_CONTENT || maxRows > 1) {
       updateHitbox();
     }
   };
@@ -60,6 +112,35 @@ export async function registerStoreEvents() {
     updateHitbox();
   });
 
+  await view.listen<FocusedAppBehavior | null>('set-focused-app-behavior', (event) => {
+    store.dispatch(RootActions.setFocusedAppBehavior(event.payload));
+    document.body.classList.toggle('focus-dimmed', event.payload === FocusedAppBehavior.Dim);
+    updateHitbox();
+  });
+
+  await view.listen<LayoutConstraints>('weg-layout-constraints', async (event) => {
+    const current = store.getState().layoutConstraints;
+    const { rows, itemsPerRow, itemScale, scrollable } = event.payload;
+    if (
+      current.rows === rows &&
+      current.itemsPerRow === itemsPerRow &&
+      current.itemScale === itemScale &&
+      current.scrollable === scrollable
+    ) {
+      return;
+    }
+    const styles = document.documentElement.style;
+    styles.setProperty('--config-rows', `${rows}`);
+    styles.setProperty('--config-items-per-row', `${itemsPerRow}`);
+    store.dispatch(RootActions.setLayoutConstraints(event.payload));
+    if (currentItemScale !== itemScale) {
+      currentItemScale = itemScale;
+      await loadSettingsToStore();
+    }
+    // the dock length changes after wrapping the items
+    updateHitbox();
+  });
+
   await listenGlobal<AppFromBackground[]>('add-multiple-open-apps', async (event) => {
     const items = await cleanItems(event.payload);
     for (const item of items) {
@@ -94,15 +175,61 @@ export async function registerStoreEvents() {
     }
   });
 
+  await listenGlobal<string>('active-workspace-changed', (event) => {
+    store.dispatch(RootActions.setActiveWorkspace(event.payload));
+  });
+
+  await listenGlobal<HWND[]>('hung-windows', (event) => {
+    store.dispatch(RootActions.setHungWindows(event.payload));
+  });
+
+  await listenGlobal<HWND[]>('weg-suspended-windows', (event) => {
+    store.dispatch(RootActions.setSuspendedWindows(event.payload));
+  });
+
+  await listenGlobal<string[]>('weg-background-apps', (event) => {
+    store.dispatch(RootActions.setBackgroundApps(event.payload));
+  });
+
+  await listenGlobal<HWND[]>('pinned-to-all-desktops', (event) => {
+    store.dispatch(RootActions.setPinnedToAllDesktops(event.payload));
+  });
+
+  await listenGlobal<FolderBadge>('weg-folder-badge', (event) => {
+    store.dispatch(RootActions.setFolderBadge(event.payload));
+  });
+
+  // the session is ending, the pinned items can't wait for the debounce. The background
+  // waits for the answer of each dock before stopping the modules.
+  await listenGlobal('flush-pending-writes', async () => {
+    await savePinnedItems.flush()?.catch(console.error);
+    await emit('pending-writes-flushed', view.label);
+  });
+
+  await listenGlobal<{ hwnd: HWND; icon: string | null }>('weg-overlay-icon', (event) => {
+    store.dispatch(RootActions.setOverlayIcon(event.payload));
+  });
+
+  await listenGlobal<Record<string, ResourceUsage>>('weg-resource-usage', (event) => {
+    store.dispatch(RootActions.setResourceUsage(event.payload));
+  });
+
   await listenGlobal<MediaSession[]>('media-sessions', (event) => {
     store.dispatch(RootActions.setMediaSessions(event.payload));
   });
 
+  await listenGameMode();
+  await listenWorkspaceAccent();
+
   await listenGlobal<UIColors>('colors', (event) => {
     setColorsAsCssVariables(event.payload);
     store.dispatch(RootActions.setColors(event.payload));
   });
 
+  await listenGlobal<WallpaperPalette>('wallpaper-palette', (event) => {
+    setColorsAsCssVariables(event.payload);
+  });
+
   await listenGlobal<Theme[]>(FileChange.Themes, async () => {
     const userSettings = await new UserSettingsLoader().load();
     loadThemeCSS(userSettings);
@@ -143,6 +270,12 @@ export async function registerStoreEvents() {
     updateHitbox();
   });
 
+  await listenGlobal<Posture>('posture-changed', async (event) => {
+    currentPosture = event.payload;
+    await loadSettingsToStore();
+    updateHitbox();
+  });
+
   await view.emitTo(view.label, 'request-all-open-apps');
 }
 
@@ -155,6 +288,7 @@ function loadSettingsCSS(settings: Seelenweg) {
   styles.setProperty('--config-item-size', `${settings.size}px`);
   styles.setProperty('--config-item-zoom-size', `${settings.zoomSize}px`);
   styles.setProperty('--config-space-between-items', `${settings.spaceBetweenItems}px`);
+  styles.setProperty('--config-animation-duration', `${settings.animationDuration}ms`);
 
   switch (settings.position) {
     case SeelenWegSide.TOP:
@@ -183,16 +317,32 @@ function loadSettingsCSS(settings: Seelenweg) {
 async function loadSettingsToStore() {
   const userSettings = await new UserSettingsLoader().load();
   i18n.changeLanguage(userSettings.jsonSettings.language);
-  const settings = userSettings.jsonSettings.seelenweg;
+  const settings = scaleForLayout(scaleForPosture(userSettings.jsonSettings.seelenweg));
   store.dispatch(RootActions.setSettings(settings));
   loadSettingsCSS(settings);
   loadThemeCSS(userSettings);
 }
 
 export async function loadStore() {
+  currentPosture = await invoke<Posture>('get_posture').catch(() => Posture.Desktop);
   await loadSettingsToStore();
   const apps = await loadPinnedItems();
   store.dispatch(RootActions.setItemsOnLeft(await cleanSavedItems(apps.left)));
   store.dispatch(RootActions.setItemsOnCenter(await cleanSavedItems(apps.center)));
   store.dispatch(RootActions.setItemsOnRight(await cleanSavedItems(apps.right)));
+  const activeWorkspace = await invoke<string>('get_active_workspace').catch(() => null);
+  store.dispatch(RootActions.setActiveWorkspace(activeWorkspace));
+  store.dispatch(RootActions.setHungWindows(await invoke<HWND[]>('weg_get_hung_windows')));
+  const suspendedWindows = await invoke<HWND[]>('weg_get_suspended_windows');
+  store.dispatch(RootActions.setSuspendedWindows(suspendedWindows));
+  const backgroundApps = await invoke<string[]>('weg_get_background_apps');
+  store.dispatch(RootActions.setBackgroundApps(backgroundApps));
+  const pinnedToAllDesktops = await invoke<HWND[]>('window_get_pinned_to_all_desktops');
+  store.dispatch(RootActions.setPinnedToAllDesktops(pinnedToAllDesktops));
+  const overlayIcons = await invoke<Record<HWND, string>>('weg_get_overlay_icons');
+  store.dispatch(RootActions.setOverlayIcons(overlayIcons));
+  const folderBadges = await invoke<FolderBadge[]>('weg_get_folder_badges');
+  store.dispatch(
+    RootActions.setFolderBadges(Object.fromEntries(folderBadges.map((b) => [b.path, b]))),
+  );
 }
@@ -10,6 +10,7 @@ import { writeTextFile } from '@tauri-apps/plugin-fs';
 import yaml from 'js-yaml';
 import { debounce } from 'lodash';
 
+import { getGeneratedFilesPath } from '../utils/app';
 import { store } from './infra';
 
 import { RootState, SwItem } from './domain';
@@ -29,6 +30,8 @@ export const savePinnedItems = debounce(
             type: item.type,
             exe: item.exe,
             execution_path: item.execution_path,
+            workspace: item.workspace,
+            run_as_admin: item.run_as_admin,
           });
           break;
         default:
@@ -44,7 +47,7 @@ export const savePinnedItems = debounce(
       right: state.itemsOnRight.reduce(cb, []),
     };
 
-    const yaml_route = await path.join(await path.appDataDir(), 'seelenweg_items.yaml');
+    const yaml_route = await path.join(await getGeneratedFilesPath(), 'seelenweg_items.yaml');
     IsSavingPinnedItems.current = true;
     await writeTextFile(yaml_route, yaml.dump(data));
   },
@@ -1,4 +1,4 @@
-import { path } from '@tauri-apps/api';
+import { invoke } from '@tauri-apps/api/core';
 
 export function filenameFromPath(path: string): string {
   const parts = path.split('\\');
@@ -6,7 +6,7 @@ export function filenameFromPath(path: string): string {
 }
 
 export async function getGeneratedFilesPath(): Promise<string> {
-  return await path.appDataDir();
+  return await invoke<string>('get_data_dir');
 }
 
 export class CallbacksManager {
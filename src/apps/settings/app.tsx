@@ -7,13 +7,17 @@ import { Suspense, useEffect } from 'react';
 import { useSelector } from 'react-redux';
 
 import { AppsConfiguration } from './modules/appsConfigurations/infra/infra';
+import { AudioProfiles } from './modules/audioProfiles/infra';
 import { DeveloperTools } from './modules/developer/infra';
 import { FancyToolbarSettings } from './modules/fancyToolbar/infra';
 import { General } from './modules/general/main/infra';
 import { Information } from './modules/information/infrastructure';
 import { Monitors } from './modules/monitors/main/infra';
+import { ScreenTime } from './modules/screenTime/infra';
 import { SeelenWegSettings } from './modules/seelenweg/infra';
 import { Shortcuts } from './modules/shortcuts/infrastructure';
+import { StartUser } from './modules/StartUser/infra';
+import { StartupApps } from './modules/startup/infra';
 import { WindowManagerSettings } from './modules/WindowManager/main/infra';
 
 import { newSelectors } from './modules/shared/store/app/reducer';
@@ -23,6 +27,9 @@ const ComponentByRout: Record<Route, React.JSXElementConstructor<any>> = {
   [Route.GENERAL]: General,
   [Route.MONITORS]: Monitors,
   [Route.SHORTCUTS]: Shortcuts,
+  [Route.STARTUP_APPS]: StartupApps,
+  [Route.SCREEN_TIME]: ScreenTime,
+  [Route.AUDIO_PROFILES]: AudioProfiles,
   [Route.SPECIFIC_APPS]: AppsConfiguration,
   [Route.INFO]: Information,
   [Route.SEELEN_WEG]: SeelenWegSettings,
@@ -63,6 +70,7 @@ export function App() {
           <Component />
         </Suspense>
       </div>
+      <StartUser />
     </ConfigProvider>
   );
 }
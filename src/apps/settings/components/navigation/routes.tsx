@@ -9,6 +9,9 @@ export enum Route {
   MONITORS = 'monitors',
   SPECIFIC_APPS = 'specific_apps',
   SHORTCUTS = 'shortcuts',
+  STARTUP_APPS = 'startup_apps',
+  SCREEN_TIME = 'screen_time',
+  AUDIO_PROFILES = 'audio_profiles',
   DEVELOPER = 'developer',
   INFO = 'info',
 }
@@ -30,6 +33,9 @@ export const RouteIcons: Record<Route, React.ReactNode> = {
   [Route.SEELEN_WEG]: <Icon iconName="BiDockBottom" />,
   [Route.SPECIFIC_APPS]: <Icon iconName="IoIosApps" propsIcon={{ color: '#d71913' }} />,
   [Route.SHORTCUTS]: '🔡',
+  [Route.STARTUP_APPS]: <Icon iconName="MdRocketLaunch" />,
+  [Route.SCREEN_TIME]: <Icon iconName="MdOutlineTimer" />,
+  [Route.AUDIO_PROFILES]: <Icon iconName="MdOutlineHeadphones" />,
   [Route.INFO]: <Icon iconName="PiInfoFill" />,
   [Route.DEVELOPER]: <Icon iconName="PiCodeBold" />,
 };
\ No newline at end of file
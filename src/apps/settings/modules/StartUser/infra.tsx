@@ -1,35 +1,139 @@
-import i18n from '../../i18n';
-import { Modal } from 'antd';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Button, Modal, Spin, Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
 
-import { SaveStore, store } from '../shared/store/infra';
-import { startup } from '../shared/tauri/infra';
+import { LoadSettingsToStore, store } from '../shared/store/infra';
 
 import { RootActions } from '../shared/store/app/reducer';
 
 import cs from './index.module.css';
 
-export const StartUser = () => {
-  startup.enable();
-  store.dispatch(RootActions.setAutostart(true));
-
-  const modal = Modal.confirm({
-    title: i18n.t('start.title'),
-    className: cs.welcome,
-    content: (
-      <div>
-        <p>
-          {i18n.t('start.message')}
-        </p>
-        <b>{i18n.t('start.message_accent')}</b>
-      </div>
-    ),
-    okText: 'Continue',
-    onOk: () => {
-      SaveStore();
-      modal.destroy();
-    },
-    icon: <div className={cs.icon}>🎉</div>,
-    cancelButtonProps: { style: { display: 'none' } },
-    centered: true,
-  });
+type OnboardingStep = 'Checking' | 'AwaitingConsent' | 'Applying' | 'Completed';
+
+interface CapabilityCheck {
+  id: string;
+  status: 'Ok' | 'Warning' | 'Error';
+  message: string;
+}
+
+interface OnboardingChoices {
+  autostart: boolean;
+  dock: boolean;
+  toolbar: boolean;
+  windowManager: boolean;
+}
+
+interface OnboardingState {
+  step: OnboardingStep;
+  checks: CapabilityCheck[];
+  defaults: OnboardingChoices;
+}
+
+const StatusIcon: Record<CapabilityCheck['status'], string> = {
+  Ok: '✔️',
+  Warning: '⚠️',
+  Error: '❌',
 };
+
+/** First run flow, led by the backend. The dock and toolbar are only started after continuing. */
+export function StartUser() {
+  const [state, setState] = useState<OnboardingState | null>(null);
+  const [choices, setChoices] = useState<OnboardingChoices | null>(null);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<OnboardingState>('onboarding_get').then(setState).catch(console.error);
+    const unlisten = listen<OnboardingState>('onboarding-changed', (e) => setState(e.payload));
+    return () => {
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  useEffect(() => {
+    if (state?.step === 'AwaitingConsent' && !choices) {
+      setChoices(state.defaults);
+    }
+    if (state?.step === 'Completed' && choices) {
+      store.dispatch(RootActions.setAutostart(choices.autostart));
+      LoadSettingsToStore();
+    }
+  }, [state]);
+
+  if (!state || state.step === 'Completed') {
+    return null;
+  }
+
+  function onContinue(accepted: boolean) {
+    if (!choices) {
+      return;
+    }
+    const chosen = accepted
+      ? choices
+      : { ...choices, dock: false, toolbar: false, windowManager: false };
+    setChoices(chosen);
+    invoke('onboarding_complete', { choices: chosen }).catch(console.error);
+  }
+
+  const options: [keyof OnboardingChoices, string][] = [
+    ['dock', t('start.options.dock')],
+    ['toolbar', t('start.options.toolbar')],
+    ['windowManager', t('start.options.window_manager')],
+    ['autostart', t('start.options.autostart')],
+  ];
+
+  return (
+    <Modal
+      open
+      centered
+      closable={false}
+      className={cs.welcome}
+      title={
+        <>
+          <span className={cs.icon}>🎉</span> {t('start.title')}
+        </>
+      }
+      footer={
+        <>
+          <Button onClick={() => onContinue(false)} disabled={state.step !== 'AwaitingConsent'}>
+            {t('start.decline')}
+          </Button>
+          <Button
+            type="primary"
+            onClick={() => onContinue(true)}
+            loading={state.step === 'Applying'}
+            disabled={state.step !== 'AwaitingConsent'}
+          >
+            {t('start.continue')}
+          </Button>
+        </>
+      }
+    >
+      <p>{t('start.message')}</p>
+      <b>{t('start.message_accent')}</b>
+      {state.step === 'Checking' ? (
+        <Spin />
+      ) : (
+        <ul className={cs.checks}>
+          {state.checks.map((check) => (
+            <li key={check.id}>
+              {StatusIcon[check.status]} {check.message}
+            </li>
+          ))}
+        </ul>
+      )}
+      {choices &&
+        options.map(([key, label]) => (
+          <div key={key} className={cs.option}>
+            <span>{label}</span>
+            <Switch
+              value={choices[key]}
+              onChange={(value) => setChoices({ ...choices, [key]: value })}
+            />
+          </div>
+        ))}
+    </Modal>
+  );
+}
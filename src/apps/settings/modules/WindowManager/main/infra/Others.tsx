@@ -1,5 +1,5 @@
 import { SettingsGroup, SettingsOption } from '../../../../components/SettingsBox';
-import { InputNumber } from 'antd';
+import { Input, InputNumber, Switch } from 'antd';
 import { useTranslation } from 'react-i18next';
 import { useDispatch } from 'react-redux';
 
@@ -10,6 +10,8 @@ import { WManagerSettingsActions } from '../app';
 
 export const OthersConfigs = () => {
   const resizeDelta = useAppSelector(SeelenWmSelectors.resizeDelta);
+  const autoWorkspaceNames = useAppSelector(SeelenWmSelectors.autoWorkspaceNames);
+  const workspaceNameTemplate = useAppSelector(SeelenWmSelectors.workspaceNameTemplate);
 
   const dispatch = useDispatch();
   const { t } = useTranslation();
@@ -26,6 +28,25 @@ export const OthersConfigs = () => {
           <InputNumber value={resizeDelta} onChange={onChangeResizeDelta} min={1} max={40} />
         </SettingsOption>
       </SettingsGroup>
+      <SettingsGroup>
+        <SettingsOption>
+          <span>{t('wm.auto_workspace_names')}</span>
+          <Switch
+            checked={autoWorkspaceNames}
+            onChange={(value) => dispatch(WManagerSettingsActions.setAutoWorkspaceNames(value))}
+          />
+        </SettingsOption>
+        <SettingsOption>
+          <span>{t('wm.workspace_name_template')}</span>
+          <Input
+            value={workspaceNameTemplate}
+            disabled={!autoWorkspaceNames}
+            onChange={(e) =>
+              dispatch(WManagerSettingsActions.setWorkspaceNameTemplate(e.target.value))
+            }
+          />
+        </SettingsOption>
+      </SettingsGroup>
     </>
   );
 };
@@ -1,4 +1,5 @@
 import { VirtualDesktopStrategy } from '../../../../../shared/schemas/Settings';
+import { WmInterop } from '../../../../../shared/schemas/WindowManager';
 import { SettingsGroup, SettingsOption } from '../../../../components/SettingsBox';
 import { GlobalPaddings } from './GlobalPaddings';
 import { OthersConfigs } from './Others';
@@ -39,6 +40,10 @@ export function WindowManagerSettings() {
     dispatch(WManagerSettingsActions.setEnabled(value));
   };
 
+  const onSelectInterop = (value: WmInterop) => {
+    dispatch(WManagerSettingsActions.setInterop(value));
+  };
+
   const onSelectLayout = (value: string) => {
     dispatch(WManagerSettingsActions.setDefaultLayout(value));
   };
@@ -79,16 +84,39 @@ export function WindowManagerSettings() {
         </SettingsOption>
       </SettingsGroup>
 
+      <SettingsGroup>
+        <SettingsOption>
+          <div>
+            <b>{t('wm.interop.label')}</b>
+          </div>
+          <Select
+            style={{ width: '200px' }}
+            value={settings.interop}
+            options={[
+              { label: t('wm.interop.none'), value: WmInterop.None },
+              { label: 'komorebi', value: WmInterop.Komorebi },
+              { label: 'GlazeWM', value: WmInterop.GlazeWM },
+            ]}
+            onSelect={onSelectInterop}
+          />
+        </SettingsOption>
+        {settings.interop !== WmInterop.None && <p>{t('wm.interop.description')}</p>}
+      </SettingsGroup>
+
       <SettingsGroup>
         <SettingsOption>
           <div>
             <b>{t('wm.enable')}</b>
           </div>
-          <Switch checked={settings.enabled} onChange={onToggleEnable} />
+          <Switch
+            checked={settings.enabled}
+            onChange={onToggleEnable}
+            disabled={settings.interop !== WmInterop.None}
+          />
         </SettingsOption>
       </SettingsGroup>
 
-      <ConfigProvider componentDisabled={!settings.enabled}>
+      <ConfigProvider componentDisabled={!settings.enabled || settings.interop !== WmInterop.None}>
         <SettingsGroup>
           <SettingsOption>
             <div>
@@ -1,16 +1,24 @@
-import { IdWithIdentifier } from '../../../shared/schemas/AppsConfigurations';
+import { FocusedAppBehavior, IdWithIdentifier } from '../../../shared/schemas/AppsConfigurations';
 
 export enum WmApplicationOptions {
   Float = 'float',
   Unmanage = 'unmanage',
   ForceManage = 'force',
   Pinned = 'pinned',
+  CenterDialogs = 'center_dialogs',
 }
 
 export enum WegApplicationOptions {
   Hidden = 'hidden',
 }
 
+export enum WindowCorners {
+  Default = 'default',
+  Round = 'round',
+  RoundSmall = 'round_small',
+  Square = 'square',
+}
+
 export enum ApplicationIdentifier {
   Exe = 'Exe',
   Class = 'Class',
@@ -35,6 +43,14 @@ export interface AppConfiguration {
   identifier: IdWithIdentifier;
   isBundled: boolean;
   options: Array<WmApplicationOptions | WegApplicationOptions>;
+  /** alpha value from 0 to 255 */
+  opacity?: number | null;
+  acrylic?: boolean | null;
+  corners?: WindowCorners | null;
+  /** applied to the dock while the app is focused */
+  wegWhileFocused?: FocusedAppBehavior | null;
+  /** applied to the toolbar while the app is focused */
+  toolbarWhileFocused?: FocusedAppBehavior | null;
 }
 
 export interface AppConfigurationExtended extends AppConfiguration {
@@ -1,8 +1,9 @@
-import { IdWithIdentifier } from '../../../../shared/schemas/AppsConfigurations';
+import { FocusedAppBehavior, IdWithIdentifier } from '../../../../shared/schemas/AppsConfigurations';
 import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../../components/SettingsBox';
 import { Identifier } from './Identifier';
 import { createSelector } from '@reduxjs/toolkit';
-import { ConfigProvider, Input, Modal, Select, Switch } from 'antd';
+import { invoke } from '@tauri-apps/api/core';
+import { ConfigProvider, Input, InputNumber, Modal, Select, Switch } from 'antd';
 import React, { useEffect, useState } from 'react';
 import { useTranslation } from 'react-i18next';
 import { useSelector } from 'react-redux';
@@ -14,6 +15,7 @@ import {
   AppConfiguration,
   AppConfigurationExtended,
   WegApplicationOptions,
+  WindowCorners,
   WmApplicationOptions,
 } from '../domain';
 
@@ -42,6 +44,11 @@ export const EditAppModal = ({ idx, onCancel, onSave, isNew, open, readonlyApp }
   const isReadonly = !!readonlyApp;
 
   const [app, setApp] = useState(initialState);
+  const [capabilities, setCapabilities] = useState({ systemBackdrop: true, cornerPreference: true });
+
+  useEffect(() => {
+    invoke<typeof capabilities>('get_os_capabilities').then(setCapabilities).catch(console.error);
+  }, []);
 
   useEffect(() => {
     if (isNew && !open) {
@@ -68,6 +75,30 @@ export const EditAppModal = ({ idx, onCancel, onSave, isNew, open, readonlyApp }
     setApp({ ...app, options: checked ? [...app.options, option] : app.options.filter((o) => o !== option) });
   };
 
+  // opacity is stored as alpha but shown as percentage
+  const onChangeOpacity = (percent: number | null) =>
+    setApp({ ...app, opacity: percent == null ? null : Math.round((percent * 255) / 100) });
+  const onChangeAcrylic = (value: boolean | null) => setApp({ ...app, acrylic: value });
+  const onSelectCorners = (value: WindowCorners | null) => setApp({ ...app, corners: value });
+  const onSelectWegBehavior = (value: FocusedAppBehavior | null) =>
+    setApp({ ...app, wegWhileFocused: value });
+  const onSelectToolbarBehavior = (value: FocusedAppBehavior | null) =>
+    setApp({ ...app, toolbarWhileFocused: value });
+
+  const acrylicOptions = [
+    { label: t('apps_configurations.app.acrylic_enabled'), value: true },
+    { label: t('apps_configurations.app.acrylic_disabled'), value: false },
+  ];
+  const cornersOptions = Object.values(WindowCorners).map((value) => ({
+    label: t(`apps_configurations.app.corners_options.${value}`),
+    value,
+  }));
+
+  const focusedBehaviorOptions = Object.values(FocusedAppBehavior).map((value) => ({
+    label: t(`apps_configurations.app.while_focused_options.${value}`),
+    value,
+  }));
+
   const monitorsOptions = monitors.map((_, i) => ({ label: `Monitor ${i + 1}`, value: i }));
   const workspaceOptions =
     app.monitor != null && monitors[app.monitor]
@@ -153,6 +184,46 @@ export const EditAppModal = ({ idx, onCancel, onSave, isNew, open, readonlyApp }
           </SettingsSubGroup>
         </SettingsGroup>
 
+        <SettingsGroup>
+          <SettingsSubGroup label={t('apps_configurations.app.appearance')}>
+            <SettingsOption>
+              <span>{t('apps_configurations.app.opacity')}</span>
+              <InputNumber
+                value={app.opacity == null ? null : Math.round((app.opacity * 100) / 255)}
+                placeholder={t('apps_configurations.app.unchanged')}
+                min={20}
+                max={100}
+                addonAfter="%"
+                onChange={onChangeOpacity}
+              />
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('apps_configurations.app.acrylic')}</span>
+              <Select
+                value={app.acrylic ?? null}
+                placeholder={t('apps_configurations.app.unchanged')}
+                title={capabilities.systemBackdrop ? undefined : t('apps_configurations.app.unsupported')}
+                disabled={!capabilities.systemBackdrop}
+                allowClear
+                options={acrylicOptions}
+                onChange={onChangeAcrylic}
+              />
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('apps_configurations.app.corners')}</span>
+              <Select
+                value={app.corners ?? null}
+                placeholder={t('apps_configurations.app.unchanged')}
+                title={capabilities.cornerPreference ? undefined : t('apps_configurations.app.unsupported')}
+                disabled={!capabilities.cornerPreference}
+                allowClear
+                options={cornersOptions}
+                onChange={onSelectCorners}
+              />
+            </SettingsOption>
+          </SettingsSubGroup>
+        </SettingsGroup>
+
         <SettingsGroup>
           <SettingsSubGroup label={t('apps_configurations.app.wm_options_label')}>
             {Object.values(WmApplicationOptions).map((value, i) => (
@@ -170,6 +241,26 @@ export const EditAppModal = ({ idx, onCancel, onSave, isNew, open, readonlyApp }
                 <Switch value={app.options.includes(value)} onChange={onChangeOption.bind(this, value)} />
               </SettingsOption>
             ))}
+            <SettingsOption>
+              <span>{t('apps_configurations.app.weg_while_focused')}</span>
+              <Select
+                value={app.wegWhileFocused ?? null}
+                placeholder={t('apps_configurations.app.unchanged')}
+                allowClear
+                options={focusedBehaviorOptions}
+                onChange={onSelectWegBehavior}
+              />
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('apps_configurations.app.toolbar_while_focused')}</span>
+              <Select
+                value={app.toolbarWhileFocused ?? null}
+                placeholder={t('apps_configurations.app.unchanged')}
+                allowClear
+                options={focusedBehaviorOptions}
+                onChange={onSelectToolbarBehavior}
+              />
+            </SettingsOption>
           </SettingsSubGroup>
         </SettingsGroup>
       </ConfigProvider>
@@ -0,0 +1,206 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, Input, InputNumber, Select } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { useDispatch, useSelector } from 'react-redux';
+
+import { newSelectors, RootActions } from '../shared/store/app/reducer';
+
+interface AudioDeviceInfo {
+  id: string;
+  name: string;
+}
+
+interface AudioDevices {
+  inputs: AudioDeviceInfo[];
+  outputs: AudioDeviceInfo[];
+}
+
+interface AudioProfile {
+  name: string;
+  output: string | null;
+  input: string | null;
+  volume: number | null;
+  inputVolume: number | null;
+}
+
+interface AudioProfileRule {
+  device: string;
+  trigger: 'Connected' | 'Disconnected';
+  profile: string;
+}
+
+export function AudioProfiles() {
+  const [devices, setDevices] = useState<AudioDevices>({ inputs: [], outputs: [] });
+
+  const settings = useSelector(newSelectors.audioProfiles);
+  const profiles: AudioProfile[] = settings.profiles || [];
+  const rules: AudioProfileRule[] = settings.rules || [];
+
+  const dispatch = useDispatch();
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<AudioDevices>('audio_profiles_get_devices').then(setDevices).catch(console.error);
+  }, []);
+
+  const onChange = (changes: Record<string, any>) => {
+    dispatch(RootActions.setAudioProfiles({ ...settings, ...changes }));
+    dispatch(RootActions.setToBeSaved(true));
+  };
+
+  function updateProfile(idx: number, changes: Partial<AudioProfile>) {
+    const previous = profiles[idx]!;
+    const { name } = changes;
+    onChange({
+      profiles: profiles.map((profile, i) => (i === idx ? { ...profile, ...changes } : profile)),
+      // the rules follow the renamed profile
+      rules:
+        name === undefined ? rules : rules.map((rule) => (rule.profile === previous.name ? { ...rule, profile: name } : rule)),
+    });
+  }
+
+  function addProfile() {
+    const profile: AudioProfile = {
+      name: t('audio_profiles.new_profile', { number: profiles.length + 1 }),
+      output: null,
+      input: null,
+      volume: null,
+      inputVolume: null,
+    };
+    onChange({ profiles: [...profiles, profile] });
+  }
+
+  function updateRule(idx: number, changes: Partial<AudioProfileRule>) {
+    onChange({ rules: rules.map((rule, i) => (i === idx ? { ...rule, ...changes } : rule)) });
+  }
+
+  function addRule() {
+    const rule: AudioProfileRule = { device: '', trigger: 'Connected', profile: profiles[0]?.name || '' };
+    onChange({ rules: [...rules, rule] });
+  }
+
+  function onApply(name: string) {
+    invoke('audio_profiles_apply', { name }).catch(console.error);
+  }
+
+  const deviceOptions = (list: AudioDeviceInfo[]) => list.map((device) => ({ label: device.name, value: device.id }));
+
+  /** volumes are stored from 0 to 1 and shown as percent */
+  const toPercent = (volume: number | null) => (volume === null ? null : Math.round(volume * 100));
+  const fromPercent = (percent: number | null) => (percent === null ? null : percent / 100);
+
+  return (
+    <>
+      <SettingsGroup>
+        <SettingsOption>
+          <b>{t('audio_profiles.profiles')}</b>
+          <Button onClick={addProfile}>{t('audio_profiles.add_profile')}</Button>
+        </SettingsOption>
+        {profiles.length === 0 && <span>{t('audio_profiles.empty')}</span>}
+        {profiles.map((profile, idx) => (
+          <SettingsSubGroup
+            key={idx}
+            label={
+              <SettingsOption>
+                <Input
+                  style={{ width: '200px' }}
+                  value={profile.name}
+                  onChange={(e) => updateProfile(idx, { name: e.target.value })}
+                />
+                <div style={{ display: 'flex', gap: '4px' }}>
+                  <Button onClick={() => onApply(profile.name)}>{t('audio_profiles.apply')}</Button>
+                  <Button danger onClick={() => onChange({ profiles: profiles.filter((_, i) => i !== idx) })}>
+                    {t('delete')}
+                  </Button>
+                </div>
+              </SettingsOption>
+            }
+          >
+            <SettingsOption>
+              <span>{t('audio_profiles.output')}</span>
+              <Select
+                style={{ width: '200px' }}
+                allowClear
+                value={profile.output}
+                placeholder={t('audio_profiles.keep_current')}
+                options={deviceOptions(devices.outputs)}
+                onChange={(output) => updateProfile(idx, { output: output ?? null })}
+              />
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('audio_profiles.volume')}</span>
+              <InputNumber
+                min={0}
+                max={100}
+                value={toPercent(profile.volume)}
+                placeholder={t('audio_profiles.keep_current')}
+                onChange={(volume) => updateProfile(idx, { volume: fromPercent(volume) })}
+              />
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('audio_profiles.input')}</span>
+              <Select
+                style={{ width: '200px' }}
+                allowClear
+                value={profile.input}
+                placeholder={t('audio_profiles.keep_current')}
+                options={deviceOptions(devices.inputs)}
+                onChange={(input) => updateProfile(idx, { input: input ?? null })}
+              />
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('audio_profiles.input_volume')}</span>
+              <InputNumber
+                min={0}
+                max={100}
+                value={toPercent(profile.inputVolume)}
+                placeholder={t('audio_profiles.keep_current')}
+                onChange={(inputVolume) => updateProfile(idx, { inputVolume: fromPercent(inputVolume) })}
+              />
+            </SettingsOption>
+          </SettingsSubGroup>
+        ))}
+      </SettingsGroup>
+
+      <SettingsGroup>
+        <SettingsOption>
+          <b>{t('audio_profiles.rules')}</b>
+          <Button onClick={addRule} disabled={profiles.length === 0}>
+            {t('audio_profiles.add_rule')}
+          </Button>
+        </SettingsOption>
+        <span>{t('audio_profiles.rules_description')}</span>
+        {rules.map((rule, idx) => (
+          <SettingsOption key={idx}>
+            <Input
+              style={{ width: '160px' }}
+              value={rule.device}
+              placeholder={t('audio_profiles.device')}
+              onChange={(e) => updateRule(idx, { device: e.target.value })}
+            />
+            <Select
+              style={{ width: '120px' }}
+              value={rule.trigger}
+              options={[
+                { label: t('audio_profiles.connected'), value: 'Connected' },
+                { label: t('audio_profiles.disconnected'), value: 'Disconnected' },
+              ]}
+              onChange={(trigger) => updateRule(idx, { trigger })}
+            />
+            <Select
+              style={{ width: '140px' }}
+              value={rule.profile}
+              options={profiles.map((profile) => ({ label: profile.name, value: profile.name }))}
+              onChange={(profile) => updateRule(idx, { profile })}
+            />
+            <Button danger onClick={() => onChange({ rules: rules.filter((_, i) => i !== idx) })}>
+              {t('delete')}
+            </Button>
+          </SettingsOption>
+        ))}
+      </SettingsGroup>
+    </>
+  );
+}
@@ -0,0 +1,84 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, Table } from 'antd';
+import { ColumnsType } from 'antd/es/table';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+type ActionOutcome =
+  | { status: 'Success' }
+  | { status: 'Dispatched' }
+  | { status: 'Failed'; message: string };
+
+interface ActionRecord {
+  time: number;
+  source: 'Ipc' | 'Cli' | 'RemoteApi' | 'Automation';
+  caller: string;
+  command: string;
+  args: unknown;
+  outcome: ActionOutcome;
+}
+
+const columns: ColumnsType<ActionRecord> = [
+  {
+    title: 'time',
+    dataIndex: 'time',
+    width: 90,
+    render: (time: number) => new Date(time).toLocaleTimeString(),
+  },
+  {
+    title: 'source',
+    dataIndex: 'source',
+    width: 100,
+    render: (source: string, record) => `${source} (${record.caller})`,
+  },
+  {
+    title: 'command',
+    dataIndex: 'command',
+    render: (command: string, record) =>
+      record.args == null ? command : `${command} ${JSON.stringify(record.args)}`,
+  },
+  {
+    title: 'result',
+    dataIndex: 'outcome',
+    width: 100,
+    render: (outcome: ActionOutcome) =>
+      outcome.status === 'Failed' ? <span title={outcome.message}>Failed</span> : outcome.status,
+  },
+];
+
+export function ActionHistory() {
+  const [records, setRecords] = useState<ActionRecord[]>([]);
+  const [loading, setLoading] = useState(false);
+
+  const { t } = useTranslation();
+
+  function refresh() {
+    setLoading(true);
+    invoke<ActionRecord[]>('get_action_history', { limit: 500 })
+      .then(setRecords)
+      .catch(console.error)
+      .finally(() => setLoading(false));
+  }
+
+  useEffect(refresh, []);
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('devtools.actions.label')}>
+        <SettingsOption>
+          <span>{t('devtools.actions.description')}</span>
+          <Button onClick={refresh}>{t('devtools.actions.refresh')}</Button>
+        </SettingsOption>
+        <Table
+          size="small"
+          loading={loading}
+          dataSource={records}
+          columns={columns}
+          rowKey={(record) => `${record.time}-${record.command}-${record.caller}`}
+          pagination={{ pageSize: 20 }}
+        />
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
@@ -0,0 +1,74 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, InputNumber } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { useDispatch, useSelector } from 'react-redux';
+
+import { newSelectors, RootActions } from '../shared/store/app/reducer';
+
+type CacheKind = 'icons' | 'thumbnails';
+
+interface CacheStats {
+  kind: CacheKind;
+  files: number;
+  size: number;
+  maxSize: number;
+}
+
+const MB = 1024 * 1024;
+
+const maxSizeKeys: Record<CacheKind, string> = {
+  icons: 'iconsMaxSize',
+  thumbnails: 'thumbnailsMaxSize',
+};
+
+export function CacheStorage() {
+  const [stats, setStats] = useState<CacheStats[]>([]);
+
+  const cache = useSelector(newSelectors.cache);
+
+  const dispatch = useDispatch();
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<CacheStats[]>('cache_get_stats').then(setStats).catch(console.error);
+  }, []);
+
+  function onClear(kind: CacheKind) {
+    invoke<CacheStats[]>('cache_clear', { kind }).then(setStats).catch(console.error);
+  }
+
+  function onChangeMaxSize(kind: CacheKind, value: number | null) {
+    if (value != null) {
+      dispatch(RootActions.setCache({ ...cache, [maxSizeKeys[kind]]: value }));
+      dispatch(RootActions.setToBeSaved(true));
+    }
+  }
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('devtools.cache.label')}>
+        {stats.map((stat) => (
+          <SettingsOption key={stat.kind}>
+            <span>
+              {t(`devtools.cache.${stat.kind}`, {
+                files: stat.files,
+                size: (stat.size / MB).toFixed(1),
+              })}
+            </span>
+            <div>
+              <InputNumber
+                value={cache[maxSizeKeys[stat.kind]] ?? stat.maxSize / MB}
+                min={16}
+                addonAfter="MB"
+                onChange={(value) => onChangeMaxSize(stat.kind, value)}
+              />
+              <Button onClick={() => onClear(stat.kind)}>{t('devtools.cache.clear')}</Button>
+            </div>
+          </SettingsOption>
+        ))}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
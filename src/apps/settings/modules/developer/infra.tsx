@@ -5,10 +5,16 @@ import { Button, Switch } from 'antd';
 import { useTranslation } from 'react-i18next';
 import { useDispatch, useSelector } from 'react-redux';
 
-import { resolveDataPath } from '../shared/config/infra';
+import { getDataDir, resolveDataPath } from '../shared/config/infra';
 
 import { newSelectors, RootActions } from '../shared/store/app/reducer';
+import { ActionHistory } from './actions';
 import { LoadCustomConfigFile } from './app';
+import { CacheStorage } from './cache';
+import { WindowInspector } from './inspector';
+import { Permissions } from './permissions';
+import { ScreenRuler } from './ruler';
+import { ShellMode } from './shell';
 
 export function DeveloperTools() {
   const devTools = useSelector(newSelectors.devTools);
@@ -29,7 +35,7 @@ export function DeveloperTools() {
   }
 
   async function openDataFolder() {
-    invoke('open_file', { path: await path.appDataDir() });
+    invoke('open_file', { path: await getDataDir() });
   }
 
   return (
@@ -64,6 +70,13 @@ export function DeveloperTools() {
           <Button onClick={LoadCustomConfigFile}>{t('devtools.load')}</Button>
         </SettingsOption>
       </SettingsGroup>
+
+      <WindowInspector />
+      <ShellMode />
+      <Permissions />
+      <ActionHistory />
+      <CacheStorage />
+      <ScreenRuler />
     </>
   );
 }
@@ -0,0 +1,105 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Button } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+interface WindowInspection {
+  hwnd: number;
+  title: string;
+  class: string;
+  exe: string | null;
+  path: string | null;
+  aumid: string | null;
+  processId: number;
+  style: number;
+  exStyle: number;
+  styleNames: string[];
+  dpi: number;
+  cloaked: boolean;
+  visible: boolean;
+  iconic: boolean;
+  maximized: boolean;
+  rules: {
+    applied: string | null;
+    flags: string[];
+  };
+  appConfig: string;
+}
+
+export function WindowInspector() {
+  const [picking, setPicking] = useState(false);
+  const [report, setReport] = useState<WindowInspection | null>(null);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    const unlisteners = [
+      listen<boolean>('window-inspector-picking', (e) => setPicking(e.payload)),
+      listen<WindowInspection>('window-inspected', (e) => setReport(e.payload)),
+    ];
+    return () => {
+      unlisteners.forEach((promise) => promise.then((unlisten) => unlisten()));
+    };
+  }, []);
+
+  function onPick() {
+    invoke(picking ? 'inspector_stop_picking' : 'inspector_start_picking').catch(console.error);
+  }
+
+  function onCopy() {
+    if (report) {
+      navigator.clipboard.writeText(report.appConfig);
+    }
+  }
+
+  const hex = (value: number) => `0x${value.toString(16).toUpperCase()}`;
+  const rows: [string, React.ReactNode][] = report
+    ? [
+      ['hwnd', hex(report.hwnd)],
+      ['title', report.title],
+      ['class', report.class],
+      ['exe', report.exe || '-'],
+      ['path', report.path || '-'],
+      ['aumid', report.aumid || '-'],
+      ['pid', report.processId],
+      ['style', `${hex(report.style)} ${hex(report.exStyle)}`],
+      ['flags', report.styleNames.join(' ')],
+      ['dpi', report.dpi],
+      ['state', [
+        report.visible && 'visible',
+        report.cloaked && 'cloaked',
+        report.iconic && 'minimized',
+        report.maximized && 'maximized',
+      ].filter(Boolean).join(', ')],
+      ['config', report.rules.applied || '-'],
+      ['options', report.rules.flags.join(', ') || '-'],
+    ]
+    : [];
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('devtools.inspector.label')}>
+        <SettingsOption>
+          <span>{picking ? t('devtools.inspector.picking') : t('devtools.inspector.pick')}</span>
+          <Button onClick={onPick}>
+            {picking ? t('devtools.inspector.cancel') : t('devtools.inspector.start')}
+          </Button>
+        </SettingsOption>
+        {rows.map(([label, value]) => (
+          <SettingsOption key={label}>
+            <b>{label}</b>
+            <span>{value}</span>
+          </SettingsOption>
+        ))}
+        {report && (
+          <SettingsOption>
+            <span>{t('devtools.inspector.app_config')}</span>
+            <Button onClick={onCopy}>{t('devtools.inspector.copy')}</Button>
+          </SettingsOption>
+        )}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
@@ -0,0 +1,118 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Button, Input, Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+type Scope = 'read-state' | 'control-windows' | 'run-commands';
+const SCOPES: Scope[] = ['read-state', 'control-windows', 'run-commands'];
+
+interface ClientId {
+  kind: 'Webview' | 'Cli' | 'Token';
+  id: string;
+}
+
+interface PermissionsStore {
+  grants: { client: ClientId; allowed: Scope[]; denied: Scope[] }[];
+  tokens: { name: string; token: string; scopes: Scope[] }[];
+}
+
+function ScopeSwitches({ client, allowed }: { client: ClientId; allowed: Scope[] }) {
+  const { t } = useTranslation();
+
+  function onToggle(scope: Scope, value: boolean) {
+    const scopes = value ? [...allowed, scope] : allowed.filter((s) => s !== scope);
+    invoke('permissions_set_scopes', { client, scopes }).catch(console.error);
+  }
+
+  function onRevoke() {
+    invoke('permissions_revoke', { client }).catch(console.error);
+  }
+
+  return (
+    <>
+      {SCOPES.map((scope) => (
+        <SettingsOption key={scope}>
+          <span>{t(`devtools.permissions.scopes.${scope}`)}</span>
+          <Switch value={allowed.includes(scope)} onChange={(value) => onToggle(scope, value)} />
+        </SettingsOption>
+      ))}
+      <SettingsOption>
+        <span />
+        <Button danger onClick={onRevoke}>
+          {t('devtools.permissions.revoke')}
+        </Button>
+      </SettingsOption>
+    </>
+  );
+}
+
+export function Permissions() {
+  const [store, setStore] = useState<PermissionsStore>({ grants: [], tokens: [] });
+  const [tokenName, setTokenName] = useState('');
+  const [created, setCreated] = useState<string | null>(null);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<PermissionsStore>('permissions_get').then(setStore).catch(console.error);
+    const unlisten = listen<PermissionsStore>('permissions-changed', (e) => setStore(e.payload));
+    return () => {
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  function createToken() {
+    invoke<string>('permissions_create_token', { name: tokenName, scopes: ['read-state'] })
+      .then((token) => {
+        setCreated(token);
+        setTokenName('');
+      })
+      .catch(console.error);
+  }
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('devtools.permissions.label')}>
+        <SettingsOption>
+          <span>{t('devtools.permissions.description')}</span>
+        </SettingsOption>
+        {store.grants.map(({ client, allowed }) => (
+          <SettingsSubGroup
+            key={`${client.kind}-${client.id}`}
+            label={`${client.kind}: ${client.id}`}
+          >
+            <ScopeSwitches client={client} allowed={allowed} />
+          </SettingsSubGroup>
+        ))}
+        {store.tokens.map(({ name, scopes }) => (
+          <SettingsSubGroup
+            key={`token-${name}`}
+            label={`${t('devtools.permissions.token')}: ${name}`}
+          >
+            <ScopeSwitches client={{ kind: 'Token', id: name }} allowed={scopes} />
+          </SettingsSubGroup>
+        ))}
+        <SettingsOption>
+          <Input
+            value={tokenName}
+            placeholder={t('devtools.permissions.token_name')}
+            onChange={(e) => setTokenName(e.target.value)}
+          />
+          <Button onClick={createToken} disabled={!tokenName.trim()}>
+            {t('devtools.permissions.create_token')}
+          </Button>
+        </SettingsOption>
+        {created && (
+          <SettingsOption>
+            <span>{t('devtools.permissions.created')}</span>
+            <Button onClick={() => navigator.clipboard.writeText(created)}>
+              {t('devtools.inspector.copy')}
+            </Button>
+          </SettingsOption>
+        )}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
@@ -0,0 +1,80 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Button } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+interface Rect {
+  left: number;
+  top: number;
+  right: number;
+  bottom: number;
+}
+
+interface Measurement {
+  x: number;
+  y: number;
+  hovered: Rect | null;
+  guides: Rect;
+}
+
+export function ScreenRuler() {
+  const [open, setOpen] = useState(false);
+  const [measurement, setMeasurement] = useState<Measurement | null>(null);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<boolean>('screen_ruler_is_open').then(setOpen).catch(console.error);
+    const unlisteners = [
+      listen<boolean>('screen-ruler-open', (e) => setOpen(e.payload)),
+      listen<Measurement>('screen-ruler-measurement', (e) => setMeasurement(e.payload)),
+    ];
+    return () => {
+      unlisteners.forEach((promise) => promise.then((unlisten) => unlisten()));
+    };
+  }, []);
+
+  function onToggle() {
+    invoke('screen_ruler_toggle').catch(console.error);
+  }
+
+  const rect = (r: Rect) =>
+    `${r.left}, ${r.top} → ${r.right}, ${r.bottom} (${r.right - r.left} × ${r.bottom - r.top})`;
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('devtools.ruler.label')}>
+        <SettingsOption>
+          <span>{t('devtools.ruler.description')}</span>
+          <Button type={open ? 'primary' : 'default'} onClick={onToggle}>
+            {t('devtools.ruler.toggle')}
+          </Button>
+        </SettingsOption>
+        {open && measurement && (
+          <>
+            <SettingsOption>
+              <span>{t('devtools.ruler.cursor')}</span>
+              <code>{`${measurement.x}, ${measurement.y}`}</code>
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('devtools.ruler.hovered')}</span>
+              <code>{measurement.hovered ? rect(measurement.hovered) : '-'}</code>
+            </SettingsOption>
+            <SettingsOption>
+              <span>{t('devtools.ruler.guides')}</span>
+              <code>
+                {`← ${measurement.x - measurement.guides.left} → ${
+                  measurement.guides.right - measurement.x
+                } ↑ ${measurement.y - measurement.guides.top} ↓ ${
+                  measurement.guides.bottom - measurement.y
+                }`}
+              </code>
+            </SettingsOption>
+          </>
+        )}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
@@ -0,0 +1,40 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+export function ShellMode() {
+  const [registered, setRegistered] = useState<boolean | null>(null);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<boolean>('shell_is_registered').then(setRegistered).catch(console.error);
+  }, []);
+
+  function onToggle(value: boolean) {
+    invoke('shell_set_registered', { registered: value })
+      .then(() => setRegistered(value))
+      .catch(console.error);
+  }
+
+  function launchExplorer() {
+    invoke('shell_launch_explorer').catch(console.error);
+  }
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('devtools.shell.label')}>
+        <SettingsOption>
+          <span>{t('devtools.shell.register')}</span>
+          <Switch value={!!registered} loading={registered === null} onChange={onToggle} />
+        </SettingsOption>
+        <SettingsOption>
+          <span>{t('devtools.shell.explorer')}</span>
+          <Button onClick={launchExplorer}>{t('devtools.shell.launch')}</Button>
+        </SettingsOption>
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
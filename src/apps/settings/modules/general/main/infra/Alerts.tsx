@@ -0,0 +1,115 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../../../components/SettingsBox';
+import { Button, Input, InputNumber, Select, Switch } from 'antd';
+import { useTranslation } from 'react-i18next';
+import { useSelector } from 'react-redux';
+
+import { useAppDispatch } from '../../../shared/utils/infra';
+
+import { newSelectors, RootActions } from '../../../shared/store/app/reducer';
+
+type AlertKind = 'Battery' | 'DiskFree';
+
+interface AlertThreshold {
+  enabled: boolean;
+  kind: AlertKind;
+  percent: number;
+  drive: string | null;
+  snoozeMinutes: number;
+}
+
+export function Alerts() {
+  const alerts = useSelector(newSelectors.alerts);
+  const thresholds: AlertThreshold[] = alerts.thresholds || [];
+
+  const { t } = useTranslation();
+  const dispatch = useAppDispatch();
+
+  const onChange = (changes: Record<string, any>) => {
+    dispatch(RootActions.setAlerts({ ...alerts, ...changes }));
+    dispatch(RootActions.setToBeSaved(true));
+  };
+
+  function updateThreshold(idx: number, changes: Partial<AlertThreshold>) {
+    onChange({
+      thresholds: thresholds.map((threshold, i) => (i === idx ? { ...threshold, ...changes } : threshold)),
+    });
+  }
+
+  function addThreshold() {
+    const threshold: AlertThreshold = {
+      enabled: true,
+      kind: 'Battery',
+      percent: 20,
+      drive: null,
+      snoozeMinutes: 15,
+    };
+    onChange({ thresholds: [...thresholds, threshold] });
+  }
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup
+        label={
+          <SettingsOption>
+            <span>{t('general.alerts.enable')}</span>
+            <Switch checked={alerts.enabled ?? true} onChange={(enabled) => onChange({ enabled })} />
+          </SettingsOption>
+        }
+      >
+        <SettingsOption>
+          <span>{t('general.alerts.description')}</span>
+          <Button onClick={addThreshold}>{t('general.alerts.add')}</Button>
+        </SettingsOption>
+        {thresholds.map((threshold, idx) => (
+          <SettingsOption key={idx}>
+            <div style={{ display: 'flex', gap: '4px', alignItems: 'center' }}>
+              <Switch
+                size="small"
+                checked={threshold.enabled}
+                onChange={(enabled) => updateThreshold(idx, { enabled })}
+              />
+              <Select
+                style={{ width: '140px' }}
+                value={threshold.kind}
+                options={(['Battery', 'DiskFree'] as AlertKind[]).map((value) => ({
+                  value,
+                  label: t(`general.alerts.kind.${value}`),
+                }))}
+                onSelect={(kind) => updateThreshold(idx, { kind })}
+              />
+              {threshold.kind === 'DiskFree' && (
+                <Input
+                  style={{ width: '80px' }}
+                  placeholder={t('general.alerts.all_drives')}
+                  value={threshold.drive || ''}
+                  onChange={(e) => updateThreshold(idx, { drive: e.target.value || null })}
+                />
+              )}
+            </div>
+            <div style={{ display: 'flex', gap: '4px', alignItems: 'center' }}>
+              <InputNumber
+                style={{ width: '80px' }}
+                min={1}
+                max={100}
+                value={threshold.percent}
+                addonAfter="%"
+                onChange={(percent) => updateThreshold(idx, { percent: percent || 1 })}
+              />
+              <InputNumber
+                style={{ width: '110px' }}
+                min={1}
+                value={threshold.snoozeMinutes}
+                addonAfter={t('general.alerts.minutes')}
+                title={t('general.alerts.snooze')}
+                onChange={(snoozeMinutes) => updateThreshold(idx, { snoozeMinutes: snoozeMinutes || 1 })}
+              />
+              <Button danger onClick={() => onChange({ thresholds: thresholds.filter((_, i) => i !== idx) })}>
+                {t('delete')}
+              </Button>
+            </div>
+          </SettingsOption>
+        ))}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
@@ -0,0 +1,75 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, Select } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { useSelector } from 'react-redux';
+
+import { useAppDispatch } from '../../../shared/utils/infra';
+
+import { newSelectors, RootActions } from '../../../shared/store/app/reducer';
+
+type ConflictOverride = 'Auto' | 'Ignore' | 'Force';
+
+interface ConflictReport {
+  id: string;
+  name: string;
+  detected: boolean;
+  reason: string | null;
+  mitigations: string[];
+  override: ConflictOverride | null;
+  mitigated: boolean;
+}
+
+export function Conflicts() {
+  const [reports, setReports] = useState<ConflictReport[]>([]);
+
+  const conflicts = useSelector(newSelectors.conflicts);
+
+  const { t } = useTranslation();
+  const dispatch = useAppDispatch();
+
+  useEffect(() => {
+    invoke<ConflictReport[]>('get_conflicts').then(setReports).catch(console.error);
+  }, []);
+
+  function onRescan() {
+    invoke<ConflictReport[]>('conflicts_rescan').then(setReports).catch(console.error);
+  }
+
+  function onOverride(id: string, value: ConflictOverride) {
+    const overrides = { ...(conflicts.overrides || {}), [id]: value };
+    dispatch(RootActions.setConflicts({ ...conflicts, overrides }));
+    dispatch(RootActions.setToBeSaved(true));
+  }
+
+  return (
+    <SettingsGroup>
+      <SettingsSubGroup label={t('general.conflicts.label')}>
+        <SettingsOption>
+          <span>
+            {reports.length ? t('general.conflicts.description') : t('general.conflicts.none')}
+          </span>
+          <Button onClick={onRescan}>{t('general.conflicts.rescan')}</Button>
+        </SettingsOption>
+        {reports.map((report) => (
+          <SettingsOption key={report.id}>
+            <span title={report.reason || undefined}>
+              {report.name}
+              {report.mitigated && ` (${t('general.conflicts.mitigated')})`}
+            </span>
+            <Select
+              style={{ width: '120px' }}
+              value={conflicts.overrides?.[report.id] || report.override || 'Auto'}
+              options={(['Auto', 'Ignore', 'Force'] as ConflictOverride[]).map((value) => ({
+                value,
+                label: t(`general.conflicts.override.${value}`),
+              }))}
+              onSelect={(value) => onOverride(report.id, value)}
+            />
+          </SettingsOption>
+        ))}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
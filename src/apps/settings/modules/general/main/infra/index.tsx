@@ -1,6 +1,8 @@
 import { LanguageList } from '../../../../../shared/lang';
 import { SettingsGroup, SettingsOption } from '../../../../components/SettingsBox';
+import { Alerts } from './Alerts';
 import { Colors } from './Colors';
+import { Conflicts } from './Conflicts';
 import { Themes } from './Themes';
 import { Wallpaper } from './Wallpaper';
 import { Select, Switch } from 'antd';
@@ -11,7 +13,7 @@ import { useSelector } from 'react-redux';
 import { startup } from '../../../shared/tauri/infra';
 import { useAppDispatch } from '../../../shared/utils/infra';
 
-import { RootActions } from '../../../shared/store/app/reducer';
+import { newSelectors, RootActions } from '../../../shared/store/app/reducer';
 import { RootSelectors } from '../../../shared/store/app/selectors';
 
 export function General() {
@@ -19,6 +21,9 @@ export function General() {
 
   const autostartStatus = useSelector(RootSelectors.autostart);
   const language = useSelector(RootSelectors.language);
+  const renderingFallback = useSelector(RootSelectors.renderingFallback);
+  const minimalMode = useSelector(RootSelectors.minimalMode);
+  const keyboardLayout = useSelector(newSelectors.keyboardLayout);
 
   const { t } = useTranslation();
   const dispatch = useAppDispatch();
@@ -34,6 +39,11 @@ export function General() {
     dispatch(RootActions.setAutostart(value));
   };
 
+  const onPerWindowLayout = (value: boolean) => {
+    dispatch(RootActions.setKeyboardLayout({ ...keyboardLayout, perWindow: value }));
+    dispatch(RootActions.setToBeSaved(true));
+  };
+
   return (
     <>
       <SettingsGroup>
@@ -56,6 +66,24 @@ export function General() {
             onSelect={(value) => dispatch(RootActions.setLanguage(value))}
           />
         </SettingsOption>
+        <SettingsOption>
+          <span>{t('general.rendering_fallback')}</span>
+          <Switch
+            value={renderingFallback}
+            onChange={(value) => dispatch(RootActions.setRenderingFallback(value))}
+          />
+        </SettingsOption>
+        <SettingsOption>
+          <span>{t('general.minimal_mode')}</span>
+          <Switch
+            value={minimalMode}
+            onChange={(value) => dispatch(RootActions.setMinimalMode(value))}
+          />
+        </SettingsOption>
+        <SettingsOption>
+          <span>{t('general.per_window_layout')}</span>
+          <Switch value={!!keyboardLayout.perWindow} onChange={onPerWindowLayout} />
+        </SettingsOption>
       </SettingsGroup>
 
       <Colors />
@@ -70,6 +98,10 @@ export function General() {
         </div>
         <Themes />
       </SettingsGroup>
+
+      <Alerts />
+
+      <Conflicts />
     </>
   );
 }
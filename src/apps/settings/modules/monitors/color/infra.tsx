@@ -0,0 +1,74 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Select, Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+interface DisplayColor {
+  id: string;
+  monitor: string;
+  name: string;
+  hdrSupported: boolean;
+  hdrEnabled: boolean;
+  sdrWhiteLevel: number | null;
+  profiles: string[];
+  defaultProfile: string | null;
+}
+
+export function DisplayColors() {
+  const [displays, setDisplays] = useState<DisplayColor[]>([]);
+
+  const { t } = useTranslation();
+
+  function refresh() {
+    invoke<DisplayColor[]>('monitors_get_display_colors').then(setDisplays).catch(console.error);
+  }
+
+  useEffect(refresh, []);
+
+  function onToggleHdr(id: string, enabled: boolean) {
+    invoke('monitors_set_hdr', { id, enabled }).catch(console.error).finally(refresh);
+  }
+
+  function onChangeProfile(id: string, profile: string) {
+    invoke('monitors_set_color_profile', { id, profile }).catch(console.error).finally(refresh);
+  }
+
+  if (displays.length === 0) {
+    return null;
+  }
+
+  return (
+    <SettingsGroup>
+      {displays.map((display) => (
+        <SettingsSubGroup key={display.id} label={`${display.name || display.monitor} (${display.monitor})`}>
+          <SettingsOption>
+            <span>{t('display_color.hdr')}</span>
+            <Switch
+              value={display.hdrEnabled}
+              disabled={!display.hdrSupported}
+              onChange={(enabled) => onToggleHdr(display.id, enabled)}
+            />
+          </SettingsOption>
+          {display.sdrWhiteLevel !== null && (
+            <SettingsOption>
+              <span>{t('display_color.sdr_white_level')}</span>
+              <span>{t('display_color.nits', { nits: display.sdrWhiteLevel })}</span>
+            </SettingsOption>
+          )}
+          <SettingsOption>
+            <span>{t('display_color.profile')}</span>
+            <Select
+              style={{ width: '200px' }}
+              value={display.defaultProfile}
+              placeholder={t('display_color.no_profile')}
+              disabled={display.profiles.length === 0}
+              onChange={(profile) => onChangeProfile(display.id, profile)}
+              options={display.profiles.map((profile) => ({ label: profile, value: profile }))}
+            />
+          </SettingsOption>
+        </SettingsSubGroup>
+      ))}
+    </SettingsGroup>
+  );
+}
@@ -4,6 +4,7 @@ import { useState } from 'react';
 import { useDispatch } from 'react-redux';
 
 import { useAppSelector } from '../../shared/utils/infra';
+import { DisplayColors } from '../color/infra';
 import { LayoutExamples } from '../layouts/infra';
 import { WorkspaceConfig } from '../workspace/infra';
 import cs from './infra.module.css';
@@ -13,6 +14,11 @@ import { getMonitorSelector, RootSelectors, SeelenWmSelectors } from '../../shar
 import { defaultOnNull } from '../../shared/utils/app';
 import { MonitorsActions } from './app';
 
+/** The device id is a path like `\\?\DISPLAY#GSM5B7F#5&1a2b3c&0&UID4352#{guid}` */
+function shortMonitorId(id: string) {
+  return id.split('#')[1] || id;
+}
+
 export const MonitorConfig = ({ monitorIdx }: { monitorIdx: number }) => {
   const [newWorkspaceName, setNewWorkspaceName] = useState('');
   const monitor = useAppSelector(getMonitorSelector(monitorIdx));
@@ -74,7 +80,10 @@ export const MonitorConfig = ({ monitorIdx }: { monitorIdx: number }) => {
       </div>
       <SettingsGroup>
         <div>
-          <div className={cs.title}>Monitor {monitorIdx + 1}</div>
+          <div className={cs.title}>
+            Monitor {monitorIdx + 1}
+            {monitor.id && <span title={monitor.id}> ({shortMonitorId(monitor.id)})</span>}
+          </div>
           <Select
             className={cs.workspaceSelector}
             value={monitor.edditingWorkspace}
@@ -111,6 +120,7 @@ export function Monitors() {
       {monitors.map((_, index) => (
         <MonitorConfig key={index} monitorIdx={index} />
       ))}
+      <DisplayColors />
     </div>
   );
 }
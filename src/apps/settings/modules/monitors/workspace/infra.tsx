@@ -1,5 +1,5 @@
 import { SettingsOption } from '../../../components/SettingsBox';
-import { InputNumber, Select } from 'antd';
+import { ColorPicker, Input, InputNumber, Select } from 'antd';
 import { useDispatch } from 'react-redux';
 
 import { useAppSelector } from '../../shared/utils/infra';
@@ -37,6 +37,15 @@ export const WorkspaceConfig = ({ monitorIdx, workspaceIdx }: Props) => {
     dispatch(MonitorsActions.updateWorkspace({ monitorIdx, workspaceIdx, key: 'padding', value }));
   };
 
+  const onChangeWallpaper = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const value = event.target.value.trim() || null;
+    dispatch(MonitorsActions.updateWorkspace({ monitorIdx, workspaceIdx, key: 'wallpaper', value }));
+  };
+
+  const onChangeAccent = (value: string | null) => {
+    dispatch(MonitorsActions.updateWorkspace({ monitorIdx, workspaceIdx, key: 'accent', value }));
+  };
+
   return (
     <div className={cs.workspaceConfig}>
       <SettingsOption>
@@ -51,6 +60,20 @@ export const WorkspaceConfig = ({ monitorIdx, workspaceIdx }: Props) => {
         <span>layout</span>
         <Select value={workspace.layout as any} options={OptionsFromEnum(Layout)} onChange={onSelectLayout} />
       </SettingsOption>
+      <SettingsOption>
+        <span>wallpaper</span>
+        <Input value={workspace.wallpaper || ''} placeholder="C:\path\to\image.png" allowClear onChange={onChangeWallpaper} />
+      </SettingsOption>
+      <SettingsOption>
+        <span>accent</span>
+        <ColorPicker
+          value={workspace.accent}
+          disabledAlpha
+          allowClear
+          onChange={(color) => onChangeAccent(color.toHexString())}
+          onClear={() => onChangeAccent(null)}
+        />
+      </SettingsOption>
     </div>
   );
 };
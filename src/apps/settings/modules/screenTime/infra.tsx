@@ -0,0 +1,124 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, InputNumber, Progress, Select, Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { useDispatch, useSelector } from 'react-redux';
+
+import { newSelectors, RootActions } from '../shared/store/app/reducer';
+
+interface AppUsageSummary {
+  exe: string;
+  name: string;
+  seconds: number;
+  firstUsed: number;
+  lastUsed: number;
+}
+
+type Period = 'today' | 'week' | 'month';
+
+const PeriodDays: Record<Period, number> = {
+  today: 0,
+  week: 6,
+  month: 29,
+};
+
+/** local date as `YYYY-MM-DD`, the same format used by the tracker */
+function toDay(date: Date) {
+  const month = String(date.getMonth() + 1).padStart(2, '0');
+  const day = String(date.getDate()).padStart(2, '0');
+  return `${date.getFullYear()}-${month}-${day}`;
+}
+
+function formatDuration(seconds: number) {
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  return hours > 0 ? `${hours}h ${minutes}m` : `${minutes}m`;
+}
+
+export function ScreenTime() {
+  const [period, setPeriod] = useState<Period>('today');
+  const [usage, setUsage] = useState<AppUsageSummary[]>([]);
+
+  const settings = useSelector(newSelectors.appUsage);
+
+  const dispatch = useDispatch();
+  const { t } = useTranslation();
+
+  function refresh() {
+    const from = new Date();
+    from.setDate(from.getDate() - PeriodDays[period]);
+    invoke<AppUsageSummary[]>('app_usage_get', { from: toDay(from), to: toDay(new Date()) })
+      .then(setUsage)
+      .catch(console.error);
+  }
+
+  useEffect(refresh, [period]);
+
+  const onChange = (changes: Record<string, any>) => {
+    dispatch(RootActions.setAppUsage({ ...settings, ...changes }));
+    dispatch(RootActions.setToBeSaved(true));
+  };
+
+  function onClear() {
+    invoke('app_usage_clear').then(refresh).catch(console.error);
+  }
+
+  const total = usage.reduce((sum, app) => sum + app.seconds, 0);
+  const max = usage[0]?.seconds || 1;
+
+  return (
+    <>
+      <SettingsGroup>
+        <SettingsOption>
+          <span>{t('screen_time.enable')}</span>
+          <Switch value={!!settings.enabled} onChange={(enabled) => onChange({ enabled })} />
+        </SettingsOption>
+        <SettingsOption>
+          <span>{t('screen_time.retention_days')}</span>
+          <InputNumber
+            min={1}
+            value={settings.retentionDays ?? 90}
+            onChange={(retentionDays) => onChange({ retentionDays: retentionDays || 1 })}
+          />
+        </SettingsOption>
+        <SettingsOption>
+          <span>{t('screen_time.clear')}</span>
+          <Button onClick={onClear}>{t('screen_time.clear_button')}</Button>
+        </SettingsOption>
+      </SettingsGroup>
+
+      <SettingsGroup>
+        <SettingsOption>
+          <b>{t('screen_time.total', { time: formatDuration(total) })}</b>
+          <Select<Period>
+            style={{ width: '120px' }}
+            value={period}
+            onChange={setPeriod}
+            options={Object.keys(PeriodDays).map((key) => ({
+              label: t(`screen_time.periods.${key}`),
+              value: key as Period,
+            }))}
+          />
+        </SettingsOption>
+        <SettingsSubGroup label={t('screen_time.apps')}>
+          {usage.length === 0 && <span>{t('screen_time.empty')}</span>}
+          {usage.map((app) => (
+            <SettingsOption key={app.exe}>
+              <span title={app.exe}>{app.name}</span>
+              <div style={{ display: 'flex', gap: '8px', alignItems: 'center' }}>
+                <Progress
+                  style={{ width: '120px', margin: 0 }}
+                  percent={(app.seconds / max) * 100}
+                  showInfo={false}
+                  size="small"
+                />
+                <span>{formatDuration(app.seconds)}</span>
+              </div>
+            </SettingsOption>
+          ))}
+        </SettingsSubGroup>
+      </SettingsGroup>
+    </>
+  );
+}
@@ -76,6 +76,46 @@ export const SeelenWegSettings = () => {
         </SettingsSubGroup>
       </SettingsGroup>
 
+      <SettingsGroup>
+        <SettingsSubGroup label={t('weg.timing.label')}>
+          <SettingsOption>
+            <div>{t('weg.timing.reveal_delay')}</div>
+            <InputNumber
+              min={0}
+              value={settings.revealDelay}
+              disabled={settings.hideMode === AppBarHideMode.Never}
+              onChange={(value) => dispatch(SeelenWegActions.setRevealDelay(value || 0))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.timing.hover_intent_threshold')}</div>
+            <InputNumber
+              min={0}
+              value={settings.hoverIntentThreshold}
+              disabled={settings.hideMode === AppBarHideMode.Never || !settings.revealDelay}
+              onChange={(value) => dispatch(SeelenWegActions.setHoverIntentThreshold(value || 0))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.timing.hide_delay')}</div>
+            <InputNumber
+              min={0}
+              value={settings.hideDelay}
+              disabled={settings.hideMode === AppBarHideMode.Never}
+              onChange={(value) => dispatch(SeelenWegActions.setHideDelay(value || 0))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.timing.animation_duration')}</div>
+            <InputNumber
+              min={0}
+              value={settings.animationDuration}
+              onChange={(value) => dispatch(SeelenWegActions.setAnimationDuration(value || 0))}
+            />
+          </SettingsOption>
+        </SettingsSubGroup>
+      </SettingsGroup>
+
       <SettingsGroup>
         <SettingsSubGroup label={t('weg.items.label')}>
           <SettingsOption>
@@ -99,10 +139,73 @@ export const SeelenWegSettings = () => {
               onChange={(value) => dispatch(SeelenWegActions.setSpaceBetweenItems(value || 0))}
             />
           </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.max_rows')}</div>
+            <InputNumber
+              min={1}
+              max={5}
+              value={settings.maxRows}
+              onChange={(value) => dispatch(SeelenWegActions.setMaxRows(value || 1))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.auto_scale')}</div>
+            <Switch
+              checked={settings.autoScale}
+              onChange={(value) => dispatch(SeelenWegActions.setAutoScale(value))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.min_size')}</div>
+            <InputNumber
+              min={8}
+              value={settings.minSize}
+              disabled={!settings.autoScale}
+              onChange={(value) => dispatch(SeelenWegActions.setMinSize(value || 24))}
+            />
+          </SettingsOption>
           <SettingsOption>
             <div>{t('weg.items.visible_separators')}</div>
             <Switch checked={settings.visibleSeparators} onChange={(value) => dispatch(SeelenWegActions.setVisibleSeparators(value))} />
           </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.show_suspended_uwp')}</div>
+            <Switch
+              checked={settings.showSuspendedUwp}
+              onChange={(value) => dispatch(SeelenWegActions.setShowSuspendedUwp(value))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.show_background_apps')}</div>
+            <Switch
+              checked={settings.showBackgroundApps}
+              onChange={(value) => dispatch(SeelenWegActions.setShowBackgroundApps(value))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.resource_usage')}</div>
+            <Switch
+              checked={settings.resourceUsage}
+              onChange={(value) => dispatch(SeelenWegActions.setResourceUsage(value))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.resource_usage_interval')}</div>
+            <InputNumber
+              min={1}
+              value={settings.resourceUsageInterval}
+              disabled={!settings.resourceUsage}
+              onChange={(value) => dispatch(SeelenWegActions.setResourceUsageInterval(value || 1))}
+            />
+          </SettingsOption>
+          <SettingsOption>
+            <div>{t('weg.items.network_usage')}</div>
+            <Switch
+              checked={settings.networkUsage}
+              disabled={!settings.resourceUsage}
+              onChange={(value) => dispatch(SeelenWegActions.setNetworkUsage(value))}
+            />
+          </SettingsOption>
         </SettingsSubGroup>
       </SettingsGroup>
     </>
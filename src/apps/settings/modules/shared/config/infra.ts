@@ -1,9 +1,15 @@
 import { path } from '@tauri-apps/api';
+import { invoke } from '@tauri-apps/api/core';
 
 export const EnvConfig = {
   version: process.env.packageVersion,
 };
 
+/** app data folder, or the relocated one on portable mode */
+export async function getDataDir() {
+  return await invoke<string>('get_data_dir');
+}
+
 export async function resolveDataPath(...sub: string[]) {
-  return await path.join(await path.appDataDir(), ...sub);
+  return await path.join(await getDataDir(), ...sub);
 }
\ No newline at end of file
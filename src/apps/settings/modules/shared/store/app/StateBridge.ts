@@ -21,6 +21,11 @@ export const YamlToState_Apps = (yaml: anyObject[]): AppConfiguration[] => {
         identifier: parseAsCamel(IdWithIdentifierSchema, ymlApp.identifier),
         isBundled: ymlApp.is_bundled || false,
         options: ymlApp.options,
+        opacity: ymlApp.opacity ?? null,
+        acrylic: ymlApp.acrylic ?? null,
+        corners: ymlApp.corners ?? null,
+        wegWhileFocused: ymlApp.weg_while_focused ?? null,
+        toolbarWhileFocused: ymlApp.toolbar_while_focused ?? null,
       });
     }
   });
@@ -52,7 +57,42 @@ export const StateToJsonSettings = (state: RootState): ISettings => {
     'ahkEnabled',
     'ahkVariables',
     'devTools',
+    'renderingFallback',
+    'minimalMode',
     'language',
     'virtualDesktopStrategy',
+    'gestures',
+    'overview',
+    'windowControls',
+    'zones',
+    'projection',
+    'idle',
+    'launcher',
+    'osd',
+    'themeSchedule',
+    'desktopIcons',
+    'sessionRestore',
+    'pen',
+    'mouseButtons',
+    'gameMode',
+    'automation',
+    'popupFilter',
+    'remoteApi',
+    'presence',
+    'dataBridge',
+    'lockScreen',
+    'doNotDisturb',
+    'conflicts',
+    'cache',
+    'keyboardLayout',
+    'snippets',
+    'windowTitles',
+    'appUsage',
+    'focusSessions',
+    'brightness',
+    'audioProfiles',
+    'presentationMode',
+    'alerts',
+    'quietLaunch',
   ]);
 };
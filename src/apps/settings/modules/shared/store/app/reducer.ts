@@ -33,6 +33,8 @@ const initialState: RootState = {
   availablePlaceholders: [],
   selectedTheme: [],
   devTools: false,
+  renderingFallback: false,
+  minimalMode: false,
   language: navigator.language.split('-')[0] || 'en',
   colors: {
     background: '#ffffff',
@@ -48,6 +50,39 @@ const initialState: RootState = {
   },
   wallpaper: null,
   virtualDesktopStrategy: VirtualDesktopStrategy.Native,
+  gestures: {},
+  overview: {},
+  windowControls: {},
+  zones: {},
+  projection: {},
+  idle: {},
+  launcher: {},
+  osd: {},
+  themeSchedule: {},
+  desktopIcons: {},
+  sessionRestore: {},
+  pen: {},
+  mouseButtons: {},
+  gameMode: {},
+  automation: {},
+  popupFilter: {},
+  remoteApi: {},
+  presence: {},
+  dataBridge: {},
+  lockScreen: {},
+  doNotDisturb: {},
+  conflicts: {},
+  cache: {},
+  keyboardLayout: {},
+  snippets: {},
+  windowTitles: {},
+  appUsage: {},
+  focusSessions: {},
+  brightness: {},
+  audioProfiles: {},
+  presentationMode: {},
+  alerts: {},
+  quietLaunch: {},
 };
 
 export const RootSlice = createSlice({
@@ -85,6 +120,17 @@ export const RootSlice = createSlice({
       state.toBeSaved = true;
       state.devTools = action.payload;
     },
+    setRenderingFallback: (state, action: PayloadAction<boolean>) => {
+      state.toBeSaved = true;
+      state.toBeRestarted = true;
+      state.renderingFallback = action.payload;
+    },
+    setMinimalMode: (state, action: PayloadAction<boolean>) => {
+      state.toBeSaved = true;
+      // leaving it starts the shell, entering it needs a restart
+      state.toBeRestarted = state.toBeRestarted || action.payload;
+      state.minimalMode = action.payload;
+    },
     setSelectedTheme: (state, action: PayloadAction<RootState['selectedTheme']>) => {
       let themes = new Set(action.payload);
       if (!themes.has('default')) {
@@ -81,11 +81,6 @@ export const LoadSettingsToStore = async (customPath?: string) => {
   const newState = StaticSettingsToState(userSettings, currentState);
   newState.lastLoaded = cloneDeep(newState);
   store.dispatch(RootActions.setState(newState));
-
-  /* // !customPath => avoid start user on manual user loading file
-  if (!Object.keys(userSettings.jsonSettings).length && !customPath) {
-    StartUser();
-  } */
 };
 
 export const SaveStore = async () => {
@@ -7,6 +7,7 @@ import { useDispatch, useSelector } from 'react-redux';
 import { RootActions } from '../shared/store/app/reducer';
 import { RootSelectors } from '../shared/store/app/selectors';
 import { AhkVariablesActions, KeyCodeToAHK } from './app';
+import { Snippets } from './snippets';
 
 export function Shortcuts() {
   const ahkEnable = useSelector(RootSelectors.ahkEnabled);
@@ -58,6 +59,8 @@ export function Shortcuts() {
           })
         }
       </SettingsGroup>
+
+      <Snippets />
     </div>
   );
 }
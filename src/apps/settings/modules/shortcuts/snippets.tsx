@@ -0,0 +1,86 @@
+import { Icon } from '../../../shared/components/Icon';
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { Button, Input, Select, Switch } from 'antd';
+import { useTranslation } from 'react-i18next';
+import { useDispatch, useSelector } from 'react-redux';
+
+import { newSelectors, RootActions } from '../shared/store/app/reducer';
+
+interface Snippet {
+  abbreviation: string;
+  text: string;
+}
+
+export function Snippets() {
+  const settings = useSelector(newSelectors.snippets);
+  const snippets: Snippet[] = settings.snippets || [];
+
+  const dispatch = useDispatch();
+  const { t } = useTranslation();
+
+  const onChange = (changes: Record<string, any>) => {
+    dispatch(RootActions.setSnippets({ ...settings, ...changes }));
+    dispatch(RootActions.setToBeSaved(true));
+  };
+
+  const onChangeSnippet = (index: number, changes: Partial<Snippet>) => {
+    onChange({
+      snippets: snippets.map((snippet, i) => (i === index ? { ...snippet, ...changes } : snippet)),
+    });
+  };
+
+  const onAdd = () => {
+    onChange({ snippets: [...snippets, { abbreviation: '', text: '' }] });
+  };
+
+  const onRemove = (index: number) => {
+    onChange({ snippets: snippets.filter((_, i) => i !== index) });
+  };
+
+  return (
+    <SettingsGroup>
+      <SettingsOption>
+        <span>{t('shortcuts.snippets.enable')}</span>
+        <Switch value={!!settings.enabled} onChange={(enabled) => onChange({ enabled })} />
+      </SettingsOption>
+      <SettingsOption>
+        <span>{t('shortcuts.snippets.excluded_apps')}</span>
+        <Select
+          mode="tags"
+          style={{ width: '200px' }}
+          value={settings.excludedApps || []}
+          placeholder="code.exe"
+          onChange={(excludedApps) => onChange({ excludedApps })}
+        />
+      </SettingsOption>
+      <SettingsSubGroup label={t('shortcuts.snippets.label')}>
+        {snippets.map((snippet, index) => (
+          <SettingsOption key={index}>
+            <Input
+              style={{ width: '120px' }}
+              value={snippet.abbreviation}
+              placeholder={t('shortcuts.snippets.abbreviation')}
+              onChange={(e) => onChangeSnippet(index, { abbreviation: e.target.value })}
+            />
+            <div style={{ display: 'flex', gap: '4px' }}>
+              <Input.TextArea
+                autoSize
+                style={{ width: '260px' }}
+                value={snippet.text}
+                placeholder={t('shortcuts.snippets.text')}
+                onChange={(e) => onChangeSnippet(index, { text: e.target.value })}
+              />
+              <Button type="text" onClick={() => onRemove(index)}>
+                <Icon iconName="IoTrash" />
+              </Button>
+            </div>
+          </SettingsOption>
+        ))}
+        <SettingsOption>
+          <span />
+          <Button onClick={onAdd}>{t('shortcuts.snippets.add')}</Button>
+        </SettingsOption>
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
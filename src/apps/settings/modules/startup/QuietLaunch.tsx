@@ -0,0 +1,93 @@
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { Button, Input, InputNumber, Select, Switch } from 'antd';
+import { useTranslation } from 'react-i18next';
+import { useDispatch, useSelector } from 'react-redux';
+
+import { newSelectors, RootActions } from '../shared/store/app/reducer';
+
+interface QuietLaunchApp {
+  path: string;
+  args: string;
+  workspace: number | null;
+  state: 'Minimized' | 'Hidden';
+}
+
+export function QuietLaunch() {
+  const settings = useSelector(newSelectors.quietLaunch);
+  const apps: QuietLaunchApp[] = settings.apps || [];
+
+  const dispatch = useDispatch();
+  const { t } = useTranslation();
+
+  const onChange = (changes: Record<string, any>) => {
+    dispatch(RootActions.setQuietLaunch({ ...settings, ...changes }));
+    dispatch(RootActions.setToBeSaved(true));
+  };
+
+  function updateApp(idx: number, changes: Partial<QuietLaunchApp>) {
+    onChange({ apps: apps.map((app, i) => (i === idx ? { ...app, ...changes } : app)) });
+  }
+
+  function addApp() {
+    const app: QuietLaunchApp = { path: '', args: '', workspace: null, state: 'Minimized' };
+    onChange({ apps: [...apps, app] });
+  }
+
+  return (
+    <SettingsGroup>
+      <SettingsOption>
+        <b>{t('startup.quiet_launch.enable')}</b>
+        <Switch value={settings.enabled ?? true} onChange={(enabled) => onChange({ enabled })} />
+      </SettingsOption>
+      <span>{t('startup.quiet_launch.description')}</span>
+      <SettingsOption>
+        <span>{t('startup.quiet_launch.delay')}</span>
+        <InputNumber min={0} value={settings.delay ?? 5} onChange={(delay) => onChange({ delay: delay ?? 0 })} />
+      </SettingsOption>
+      <SettingsSubGroup
+        label={
+          <SettingsOption>
+            <span>{t('startup.quiet_launch.apps')}</span>
+            <Button onClick={addApp}>{t('startup.add')}</Button>
+          </SettingsOption>
+        }
+      >
+        {apps.map((app, idx) => (
+          <SettingsOption key={idx}>
+            <Input
+              style={{ width: '220px' }}
+              value={app.path}
+              placeholder={t('startup.quiet_launch.path')}
+              onChange={(e) => updateApp(idx, { path: e.target.value })}
+            />
+            <Input
+              style={{ width: '100px' }}
+              value={app.args}
+              placeholder={t('startup.quiet_launch.args')}
+              onChange={(e) => updateApp(idx, { args: e.target.value })}
+            />
+            {/* workspaces are shown starting from 1 */}
+            <InputNumber
+              min={1}
+              value={app.workspace === null ? null : app.workspace + 1}
+              placeholder={t('startup.quiet_launch.workspace')}
+              onChange={(workspace) => updateApp(idx, { workspace: workspace ? workspace - 1 : null })}
+            />
+            <Select
+              style={{ width: '120px' }}
+              value={app.state}
+              options={[
+                { label: t('startup.quiet_launch.minimized'), value: 'Minimized' },
+                { label: t('startup.quiet_launch.hidden'), value: 'Hidden' },
+              ]}
+              onChange={(state) => updateApp(idx, { state })}
+            />
+            <Button danger onClick={() => onChange({ apps: apps.filter((_, i) => i !== idx) })}>
+              {t('delete')}
+            </Button>
+          </SettingsOption>
+        ))}
+      </SettingsSubGroup>
+    </SettingsGroup>
+  );
+}
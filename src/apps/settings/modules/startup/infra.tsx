@@ -0,0 +1,112 @@
+import { Icon } from '../../../shared/components/Icon';
+import { SettingsGroup, SettingsOption, SettingsSubGroup } from '../../components/SettingsBox';
+import { invoke } from '@tauri-apps/api/core';
+import { Button, Input, Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+import { QuietLaunch } from './QuietLaunch';
+
+enum StartupSource {
+  UserRegistry = 'UserRegistry',
+  MachineRegistry = 'MachineRegistry',
+  MachineRegistry32 = 'MachineRegistry32',
+  UserFolder = 'UserFolder',
+  CommonFolder = 'CommonFolder',
+  Package = 'Package',
+}
+
+interface StartupEntry {
+  name: string;
+  command: string;
+  source: StartupSource;
+  enabled: boolean;
+}
+
+export function StartupApps() {
+  const [entries, setEntries] = useState<StartupEntry[]>([]);
+  const [newName, setNewName] = useState('');
+  const [newCommand, setNewCommand] = useState('');
+
+  const { t } = useTranslation();
+
+  function refresh() {
+    invoke<StartupEntry[]>('startup_get_apps').then(setEntries).catch(console.error);
+  }
+
+  useEffect(refresh, []);
+
+  function onToggle(entry: StartupEntry, enabled: boolean) {
+    invoke('startup_set_enabled', { source: entry.source, name: entry.name, enabled })
+      .then(refresh)
+      .catch(console.error);
+  }
+
+  function onRemove(entry: StartupEntry) {
+    invoke('startup_remove', { source: entry.source, name: entry.name })
+      .then(refresh)
+      .catch(console.error);
+  }
+
+  function onAdd() {
+    if (!newName || !newCommand) {
+      return;
+    }
+    invoke('startup_add', { name: newName, command: newCommand })
+      .then(() => {
+        setNewName('');
+        setNewCommand('');
+        refresh();
+      })
+      .catch(console.error);
+  }
+
+  return (
+    <>
+      {Object.values(StartupSource).map((source) => {
+        const items = entries.filter((entry) => entry.source === source);
+        if (items.length === 0) {
+          return null;
+        }
+        return (
+          <SettingsGroup key={source}>
+            <SettingsSubGroup label={t(`startup.sources.${source}`)}>
+              {items.map((entry) => (
+                <SettingsOption key={entry.name}>
+                  <span title={entry.command}>{entry.name}</span>
+                  <div>
+                    {source !== StartupSource.Package && (
+                      <Button type="text" onClick={() => onRemove(entry)}>
+                        <Icon iconName="IoTrash" />
+                      </Button>
+                    )}
+                    <Switch value={entry.enabled} onChange={(value) => onToggle(entry, value)} />
+                  </div>
+                </SettingsOption>
+              ))}
+            </SettingsSubGroup>
+          </SettingsGroup>
+        );
+      })}
+
+      <SettingsGroup>
+        <SettingsSubGroup label={t('startup.add')}>
+          <SettingsOption>
+            <span>{t('startup.name')}</span>
+            <Input value={newName} onChange={(e) => setNewName(e.target.value)} />
+          </SettingsOption>
+          <SettingsOption>
+            <span>{t('startup.command')}</span>
+            <Input value={newCommand} onChange={(e) => setNewCommand(e.target.value)} />
+          </SettingsOption>
+          <SettingsOption>
+            <span />
+            <Button onClick={onAdd}>{t('startup.add')}</Button>
+          </SettingsOption>
+        </SettingsSubGroup>
+      </SettingsGroup>
+
+      <QuietLaunch />
+    </>
+  );
+}
@@ -1,7 +1,9 @@
 import { UserSettings } from '../../shared.interfaces';
 import { Theme } from './schemas/Theme';
 import { path } from '@tauri-apps/api';
+import { invoke } from '@tauri-apps/api/core';
 import { PhysicalSize } from '@tauri-apps/api/dpi';
+import { listen as listenGlobal } from '@tauri-apps/api/event';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 
 export function getRootContainer() {
@@ -27,6 +29,27 @@ export const setWindowAsFullSize = () => {
   getCurrentWebviewWindow().setSize(new PhysicalSize(screenWidth, screenHeight));
 };
 
+/** animations are disabled while a game is in foreground to save cpu/gpu time */
+export async function listenGameMode() {
+  const toggle = (active: boolean) =>
+    document.documentElement.classList.toggle('game-mode', active);
+  toggle(await invoke<boolean>('game_mode_is_active'));
+  await listenGlobal<boolean>('game-mode-changed', (event) => toggle(event.payload));
+}
+
+/** accent of the active workspace, themes should fallback to the system accent when unset */
+export async function listenWorkspaceAccent() {
+  const apply = (accent: string | null) => {
+    if (accent) {
+      document.documentElement.style.setProperty('--config-workspace-accent-color', accent);
+    } else {
+      document.documentElement.style.removeProperty('--config-workspace-accent-color');
+    }
+  };
+  apply(await invoke<string | null>('get_workspace_accent'));
+  await listenGlobal<string | null>('workspace-accent', (event) => apply(event.payload));
+}
+
 export function setColorsAsCssVariables(colors: anyObject) {
   for (const [key, value] of Object.entries(colors)) {
     if (typeof value !== 'string') {
@@ -57,6 +80,12 @@ export function loadThemeCSS(config: Pick<UserSettings, 'themes' | 'jsonSettings
     themes = defaultTheme ? [defaultTheme] : [];
   }
 
+  // set by the user or by the background when the webviews fail to render
+  document.documentElement.classList.toggle(
+    'rendering-fallback',
+    config.jsonSettings.renderingFallback,
+  );
+
   const label = getCurrentWebviewWindow().label;
   let theme_key: keyof Theme['styles'] | null = null;
   if (label.startsWith('fancy-toolbar')) {
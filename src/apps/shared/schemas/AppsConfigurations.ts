@@ -7,6 +7,12 @@ export enum ApplicationIdentifier {
   Path = 'Path',
 }
 
+/** applied to the dock or the toolbar while a window of the app is focused */
+export enum FocusedAppBehavior {
+  Hide = 'hide',
+  Dim = 'dim',
+}
+
 export enum MatchingStrategy {
   Legacy = 'Legacy',
   Equals = 'Equals',
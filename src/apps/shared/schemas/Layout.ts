@@ -15,6 +15,11 @@ export enum NodeSubtype {
   Permanent = 'Permanent',
 }
 
+export enum StackMode {
+  Tabbed = 'Tabbed',
+  Stacked = 'Stacked',
+}
+
 export enum NoFallbackBehavior {
   Float = 'Float',
   Unmanaged = 'Unmanaged',
@@ -40,6 +45,7 @@ const StackNodeSchema = BaseNodeSchema.extend({
   type: z.literal(NodeType.Stack),
   active: hwndSchema.nullable().default(null),
   handles: z.array(hwndSchema).default([]),
+  mode: z.nativeEnum(StackMode).default(StackMode.Tabbed).describe('How the windows are listed'),
 });
 
 export type FallbackNode = z.infer<typeof FallbackNodeSchema>;
@@ -48,6 +54,7 @@ const FallbackNodeSchema = BaseNodeSchema.extend({
   subtype: z.literal(NodeSubtype.Permanent).default(NodeSubtype.Permanent),
   active: hwndSchema.nullable().default(null),
   handles: z.array(hwndSchema).default([]),
+  mode: z.nativeEnum(StackMode).default(StackMode.Tabbed).describe('How the windows are listed'),
 });
 
 export type LeafNode = z.infer<typeof LeafNodeSchema>;
@@ -7,16 +7,20 @@ export const WorkspaceSchema = z.object({
   layout: z.string().default('BSP'),
   padding: z.number().nonnegative().optional().nullable(),
   gap: z.number().nonnegative().optional().nullable(),
+  wallpaper: z.string().optional().nullable(),
+  accent: z.string().optional().nullable(),
 });
 
 type InnerMonitor = z.infer<typeof MonitorSchema>;
 export const MonitorSchema = z.object({
+  id: z.string().optional().nullable(),
   workspaces: z.array(WorkspaceSchema).min(1).default([WorkspaceSchema.parse({})]),
   work_area_offset: RectSchema.optional().nullable(),
   editing_workspace: z.number().nonnegative().default(0),
 });
 
 export interface Monitor {
+  id: InnerMonitor['id'];
   workAreaOffset: InnerMonitor['work_area_offset'];
   workspaces: InnerMonitor['workspaces'];
   edditingWorkspace: InnerMonitor['editing_workspace'];
@@ -15,6 +15,8 @@ export enum ToolbarModuleType {
   Tray = 'tray',
   Device = 'device',
   Notifications = 'notifications',
+  FileOperations = 'fileOperations',
+  Privacy = 'privacy',
 }
 
 export enum WorkspaceTMMode {
@@ -98,6 +100,16 @@ export const NotificationsTMSchema = BaseTMSchema.extend({
   type: z.literal(ToolbarModuleType.Notifications),
 });
 
+export type FileOperationsTM = z.infer<typeof FileOperationsTMSchema>;
+export const FileOperationsTMSchema = BaseTMSchema.extend({
+  type: z.literal(ToolbarModuleType.FileOperations),
+});
+
+export type PrivacyTM = z.infer<typeof PrivacyTMSchema>;
+export const PrivacyTMSchema = BaseTMSchema.extend({
+  type: z.literal(ToolbarModuleType.Privacy),
+});
+
 export type DeviceTM = z.infer<typeof DeviceTMSchema>;
 export const DeviceTMSchema = BaseTMSchema.extend({
   type: z.literal(ToolbarModuleType.Device),
@@ -126,6 +138,8 @@ export const ToolbarModuleSchema = z.union([
   MediaTMSchema,
   DeviceTMSchema,
   NotificationsTMSchema,
+  FileOperationsTMSchema,
+  PrivacyTMSchema,
 ]);
 
 type InnerPlaceholder = z.infer<typeof PlaceholderSchema>;
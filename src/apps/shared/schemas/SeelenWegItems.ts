@@ -4,6 +4,8 @@ export enum SwItemType {
   PinnedApp = 'PinnedApp',
   TemporalApp = 'TemporalPin',
   Separator = 'Separator',
+  Spacer = 'Spacer',
+  CustomButton = 'CustomButton',
   Media = 'Media',
   Start = 'StartMenu',
 }
@@ -15,6 +17,10 @@ const PinnedAppSchema = z.object({
   exe: z.string(),
   /** Path to execute the app using explorer.exe (uwp apps starts with `shell:AppsFolder`) */
   execution_path: z.string(),
+  /** Id of the workspace where the item is shown, all workspaces if not set */
+  workspace: z.string().nullish(),
+  /** Launch the app elevated, set after a successful elevated launch */
+  run_as_admin: z.boolean().optional(),
 });
 
 export type SavedSeparatorItem = z.infer<typeof SeparatorSchema>;
@@ -22,6 +28,25 @@ const SeparatorSchema = z.object({
   type: z.literal(SwItemType.Separator),
 });
 
+export type SavedSpacerItem = z.infer<typeof SpacerSchema>;
+const SpacerSchema = z.object({
+  type: z.literal(SwItemType.Spacer),
+});
+
+export type SavedCustomButton = z.infer<typeof CustomButtonSchema>;
+const CustomButtonSchema = z.object({
+  type: z.literal(SwItemType.CustomButton),
+  /** Unique identifier, also sent as payload of the emitted event */
+  id: z.string(),
+  /** Name of the icon to show */
+  icon: z.string().nullish(),
+  tooltip: z.string().default(''),
+  /** Command to run on click, executed using `cmd /C` */
+  command: z.string().nullish(),
+  /** Event to emit on click */
+  event: z.string().nullish(),
+});
+
 export type SavedMediaItem = z.infer<typeof MediaItemSchema>;
 const MediaItemSchema = z.object({
   type: z.literal(SwItemType.Media),
@@ -36,6 +61,8 @@ export type SwSavedItem = z.infer<typeof SwSavedItemSchema>;
 export const SwSavedItemSchema = z.union([
   PinnedAppSchema,
   SeparatorSchema,
+  SpacerSchema,
+  CustomButtonSchema,
   MediaItemSchema,
   StartMenuItemSchema,
 ]);
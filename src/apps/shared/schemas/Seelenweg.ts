@@ -29,6 +29,31 @@ export const SeelenWegSchema = z.object({
   margin: z.number().nonnegative().default(8).describe('Dock/Bar margin in pixels'),
   padding: z.number().nonnegative().default(8).describe('Dock/Bar padding in pixels'),
   space_between_items: z.number().nonnegative().default(8).describe('Space between items (gap) in pixels'),
+  force_show: z.array(z.string()).default([]).describe('Exe names always shown on the dock'),
+  resource_usage: z.boolean().default(false).describe('Show cpu and memory usage on dock items'),
+  resource_usage_interval: z.number().positive().default(2).describe('Sampling interval in seconds'),
+  network_usage: z.boolean().default(false).describe('Also sample the network throughput of dock items'),
+  reveal_delay: z.number().nonnegative().default(100).describe('Delay in ms to reveal the hidden dock'),
+  hide_delay: z.number().nonnegative().default(200).describe('Delay in ms to hide the dock'),
+  animation_duration: z.number().nonnegative().default(200).describe('Show/hide animation in ms'),
+  hover_intent_threshold: z
+    .number()
+    .nonnegative()
+    .default(10)
+    .describe('Max cursor movement in px between checks while revealing'),
+  show_suspended_uwp: z.boolean().default(false).describe('Show suspended uwp apps greyed out'),
+  show_background_apps: z
+    .boolean()
+    .default(false)
+    .describe('Show the running dot on pinned apps minimized to the tray'),
+  max_rows: z.number().int().positive().default(1).describe('Max rows used when items overflow'),
+  auto_scale: z.boolean().default(false).describe('Shrink the items when they overflow'),
+  min_size: z
+    .number()
+    .int()
+    .positive()
+    .default(24)
+    .describe('Smallest item size in px when shrunk'),
 });
 
 type inner = z.infer<typeof SeelenWegSchema> & {};
@@ -43,4 +68,17 @@ export interface Seelenweg {
   margin: inner['margin'];
   padding: inner['padding'];
   spaceBetweenItems: inner['space_between_items'];
+  forceShow: inner['force_show'];
+  resourceUsage: inner['resource_usage'];
+  resourceUsageInterval: inner['resource_usage_interval'];
+  networkUsage: inner['network_usage'];
+  revealDelay: inner['reveal_delay'];
+  hideDelay: inner['hide_delay'];
+  animationDuration: inner['animation_duration'];
+  hoverIntentThreshold: inner['hover_intent_threshold'];
+  showSuspendedUwp: inner['show_suspended_uwp'];
+  showBackgroundApps: inner['show_background_apps'];
+  maxRows: inner['max_rows'];
+  autoScale: inner['auto_scale'];
+  minSize: inner['min_size'];
 }
\ No newline at end of file
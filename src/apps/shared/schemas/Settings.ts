@@ -66,6 +66,26 @@ export const AhkVariablesSchema = z.object({
   send_to_workspace_7: AhkVarSchema.default({ fancy: 'Win + Shift + 8', ahk: '#+8' }),
   send_to_workspace_8: AhkVarSchema.default({ fancy: 'Win + Shift + 9', ahk: '#+9' }),
   send_to_workspace_9: AhkVarSchema.default({ fancy: 'Win + Shift + 0', ahk: '#+0' }),
+  toggle_overview: AhkVarSchema.default({ fancy: 'Win + Alt + Tab', ahk: '#!Tab' }),
+  toggle_topmost: AhkVarSchema.default({ fancy: 'Win + Control + T', ahk: '#^t' }),
+  increase_opacity: AhkVarSchema.default({ fancy: 'Win + Control + =', ahk: '#^=' }),
+  decrease_opacity: AhkVarSchema.default({ fancy: 'Win + Control + -', ahk: '#^-' }),
+  toggle_shade: AhkVarSchema.default({ fancy: 'Win + Control + R', ahk: '#^r' }),
+  resize_mode: AhkVarSchema.default({ fancy: 'Win + Control + M', ahk: '#^m' }),
+  toggle_emoji_picker: AhkVarSchema.default({ fancy: 'Win + Alt + .', ahk: '#!.' }),
+  undo_last_action: AhkVarSchema.default({ fancy: 'Win + Control + Z', ahk: '#^z' }),
+  toggle_task_manager: AhkVarSchema.default({ fancy: 'Control + Shift + Escape', ahk: '^+Esc' }),
+  toggle_cursor_lock: AhkVarSchema.default({ fancy: 'Win + Alt + L', ahk: '#!l' }),
+  find_cursor: AhkVarSchema.default({ fancy: 'Win + Alt + F', ahk: '#!f' }),
+  send_to_monitor_left: AhkVarSchema.default({ fancy: 'Win + Shift + Left', ahk: '#+Left' }),
+  send_to_monitor_right: AhkVarSchema.default({ fancy: 'Win + Shift + Right', ahk: '#+Right' }),
+  send_workspace_to_next_monitor: AhkVarSchema.default({
+    fancy: 'Win + Alt + Shift + Right',
+    ahk: '#!+Right',
+  }),
+  toggle_color_picker: AhkVarSchema.default({ fancy: 'Win + Shift + C', ahk: '#+c' }),
+  next_audio_profile: AhkVarSchema.default({ fancy: 'Win + Alt + A', ahk: '#!a' }),
+  toggle_mic_mute: AhkVarSchema.default({ fancy: 'Win + Alt + M', ahk: '#!m' }),
 });
 
 export enum VirtualDesktopStrategy {
@@ -94,6 +114,8 @@ export const SettingsSchema = z.object({
     })
     .default(['default']),
   dev_tools: z.boolean().default(false),
+  rendering_fallback: z.boolean().default(false),
+  minimal_mode: z.boolean().default(false),
   language: z
     .string()
     .nullable()
@@ -106,6 +128,40 @@ export const SettingsSchema = z.object({
   virtual_desktop_strategy: z
     .nativeEnum(VirtualDesktopStrategy)
     .default(VirtualDesktopStrategy.Native),
+  // sections without ui on the settings app, kept as loaded to not lose them on save
+  gestures: z.record(z.any()).default({}),
+  overview: z.record(z.any()).default({}),
+  window_controls: z.record(z.any()).default({}),
+  zones: z.record(z.any()).default({}),
+  projection: z.record(z.any()).default({}),
+  idle: z.record(z.any()).default({}),
+  launcher: z.record(z.any()).default({}),
+  osd: z.record(z.any()).default({}),
+  theme_schedule: z.record(z.any()).default({}),
+  desktop_icons: z.record(z.any()).default({}),
+  session_restore: z.record(z.any()).default({}),
+  pen: z.record(z.any()).default({}),
+  mouse_buttons: z.record(z.any()).default({}),
+  game_mode: z.record(z.any()).default({}),
+  automation: z.record(z.any()).default({}),
+  popup_filter: z.record(z.any()).default({}),
+  remote_api: z.record(z.any()).default({}),
+  presence: z.record(z.any()).default({}),
+  data_bridge: z.record(z.any()).default({}),
+  lock_screen: z.record(z.any()).default({}),
+  do_not_disturb: z.record(z.any()).default({}),
+  conflicts: z.record(z.any()).default({}),
+  cache: z.record(z.any()).default({}),
+  keyboard_layout: z.record(z.any()).default({}),
+  snippets: z.record(z.any()).default({}),
+  window_titles: z.record(z.any()).default({}),
+  app_usage: z.record(z.any()).default({}),
+  focus_sessions: z.record(z.any()).default({}),
+  brightness: z.record(z.any()).default({}),
+  audio_profiles: z.record(z.any()).default({}),
+  presentation_mode: z.record(z.any()).default({}),
+  alerts: z.record(z.any()).default({}),
+  quiet_launch: z.record(z.any()).default({}),
 });
 
 export interface ISettings {
@@ -117,6 +173,41 @@ export interface ISettings {
   ahkVariables: AhkVariables;
   selectedTheme: string[];
   devTools: boolean;
+  renderingFallback: boolean;
+  minimalMode: boolean;
   language: string;
   virtualDesktopStrategy: VirtualDesktopStrategy;
+  gestures: anyObject;
+  overview: anyObject;
+  windowControls: anyObject;
+  zones: anyObject;
+  projection: anyObject;
+  idle: anyObject;
+  launcher: anyObject;
+  osd: anyObject;
+  themeSchedule: anyObject;
+  desktopIcons: anyObject;
+  sessionRestore: anyObject;
+  pen: anyObject;
+  mouseButtons: anyObject;
+  gameMode: anyObject;
+  automation: anyObject;
+  popupFilter: anyObject;
+  remoteApi: anyObject;
+  presence: anyObject;
+  dataBridge: anyObject;
+  lockScreen: anyObject;
+  doNotDisturb: anyObject;
+  conflicts: anyObject;
+  cache: anyObject;
+  keyboardLayout: anyObject;
+  snippets: anyObject;
+  windowTitles: anyObject;
+  appUsage: anyObject;
+  focusSessions: anyObject;
+  brightness: anyObject;
+  audioProfiles: anyObject;
+  presentationMode: anyObject;
+  alerts: anyObject;
+  quietLaunch: anyObject;
 }
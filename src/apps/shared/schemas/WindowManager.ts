@@ -21,6 +21,12 @@ export const FloatingWindowSchema = z.object({
   height: z.number().positive().default(500),
 });
 
+export enum WmInterop {
+  None = 'None',
+  Komorebi = 'Komorebi',
+  GlazeWM = 'GlazeWM',
+}
+
 export const WindowManagerSchema = z.object({
   enabled: z.boolean().default(false),
   auto_stacking_by_category: z.boolean().default(true),
@@ -31,6 +37,15 @@ export const WindowManagerSchema = z.object({
   global_work_area_offset: RectSchema.default({}),
   floating: FloatingWindowSchema.default({}),
   default_layout: z.string().nullable().default(null),
+  auto_workspace_names: z.boolean().default(false).describe('Name workspaces after their main app'),
+  workspace_name_template: z
+    .string()
+    .default('{index}: {app}')
+    .describe('Supports {index}, {app} and {name}'),
+  interop: z
+    .nativeEnum(WmInterop)
+    .default(WmInterop.None)
+    .describe('External tiling window manager providing the workspaces'),
 });
 
 type inner = z.infer<typeof WindowManagerSchema> & {};
@@ -44,4 +59,7 @@ export interface WindowManager {
   globalWorkAreaOffset: inner['global_work_area_offset'];
   floating: inner['floating'];
   defaultLayout: inner['default_layout'];
+  autoWorkspaceNames: inner['auto_workspace_names'];
+  workspaceNameTemplate: inner['workspace_name_template'];
+  interop: inner['interop'];
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface TaskEntry {
+  pid: number;
+  name: string;
+  exe: string | null;
+  cpu: number;
+  memory: number;
+  /** bytes per second */
+  netReceived: number;
+  netSent: number;
+  window: number | null;
+  title: string | null;
+}
+
+const REFRESH_INTERVAL = 2000;
+
+let tasks: TaskEntry[] = [];
+let selected = 0;
+/** pid waiting for a second click to be ended */
+let pendingEnd: number | null = null;
+
+function close() {
+  invoke('task_manager_close').catch(console.error);
+}
+
+function activate(task: TaskEntry) {
+  if (task.window) {
+    invoke('task_manager_activate', { hwnd: task.window }).catch(console.error);
+  }
+}
+
+/**
+ * Asks for a second click/keypress instead of a dialog, as the dialog
+ * would steal the focus and close the window on blur.
+ */
+function endTask(task: TaskEntry) {
+  if (pendingEnd !== task.pid) {
+    pendingEnd = task.pid;
+    return;
+  }
+  pendingEnd = null;
+  invoke('task_manager_end_task', { pid: task.pid }).catch(console.error);
+}
+
+function formatBytes(bytes: number) {
+  const mb = bytes / 1024 / 1024;
+  return mb >= 1024 ? `${(mb / 1024).toFixed(1)} GB` : `${mb.toFixed(0)} MB`;
+}
+
+function formatRate(bytes: number) {
+  const kb = bytes / 1024;
+  return kb >= 1024 ? `${(kb / 1024).toFixed(1)} MB/s` : `${kb.toFixed(0)} KB/s`;
+}
+
+function render(list: HTMLElement, footer: HTMLElement) {
+  list.replaceChildren(
+    ...tasks.map((task, index) => {
+      const item = document.createElement('div');
+      item.className = 'task-manager-item';
+      item.classList.toggle('task-manager-item-selected', index === selected);
+      item.classList.toggle('task-manager-item-windowed', !!task.window);
+
+      const name = document.createElement('span');
+      name.className = 'task-manager-item-name';
+      name.textContent = task.title ? `${task.name} - ${task.title}` : task.name;
+      name.title = task.exe || task.name;
+      name.addEventListener('click', () => activate(task));
+
+      const cpu = document.createElement('span');
+      cpu.className = 'task-manager-item-usage';
+      cpu.textContent = `${task.cpu.toFixed(1)}%`;
+
+      const memory = document.createElement('span');
+      memory.className = 'task-manager-item-usage';
+      memory.textContent = formatBytes(task.memory);
+
+      const network = document.createElement('span');
+      network.className = 'task-manager-item-usage';
+      network.textContent = `${formatRate(task.netReceived + task.netSent)}`;
+      network.title = `↓ ${formatRate(task.netReceived)} ↑ ${formatRate(task.netSent)}`;
+
+      const end = document.createElement('button');
+      end.className = 'task-manager-end';
+      end.textContent = pendingEnd === task.pid ? '?' : '✕';
+      end.title = pendingEnd === task.pid ? 'Click again to end task' : 'End task';
+      end.addEventListener('click', () => {
+        endTask(task);
+        render(list, footer);
+      });
+
+      item.append(name, cpu, memory, network, end);
+      return item;
+    }),
+  );
+  list.children[selected]?.scrollIntoView({ block: 'nearest' });
+  footer.firstChild!.textContent = `${tasks.length} processes`;
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+
+  const search = document.createElement('input');
+  search.className = 'task-manager-search';
+  search.placeholder = 'Search';
+
+  const list = document.createElement('div');
+  list.className = 'task-manager-list';
+
+  const footer = document.createElement('div');
+  footer.className = 'task-manager-footer';
+
+  const system = document.createElement('button');
+  system.textContent = 'Open Windows Task Manager';
+  system.addEventListener('click', () => {
+    invoke('task_manager_open_system').catch(console.error);
+  });
+
+  footer.append(document.createElement('span'), system);
+  document.body.append(search, list, footer);
+
+  async function update() {
+    const selectedPid = tasks[selected]?.pid;
+    tasks = await invoke<TaskEntry[]>('task_manager_list', { query: search.value });
+    // keep the selection on the same process while the list is re-sorted by usage
+    selected = Math.max(
+      tasks.findIndex((task) => task.pid === selectedPid),
+      0,
+    );
+    render(list, footer);
+  }
+
+  search.addEventListener('input', () => {
+    selected = 0;
+    tasks = [];
+    update();
+  });
+
+  document.addEventListener('keydown', (e) => {
+    const task = tasks[selected];
+    if (e.key === 'Escape') {
+      close();
+    } else if (e.key === 'Enter' && task) {
+      activate(task);
+    } else if (e.key === 'Delete' && task) {
+      endTask(task);
+      render(list, footer);
+    } else if (e.key === 'ArrowUp' || e.key === 'ArrowDown') {
+      e.preventDefault();
+      const move = e.key === 'ArrowUp' ? -1 : 1;
+      selected = Math.min(Math.max(selected + move, 0), tasks.length - 1);
+      pendingEnd = null;
+      render(list, footer);
+    }
+  });
+
+  view.listen('tauri://blur', close);
+  setInterval(update, REFRESH_INTERVAL);
+
+  await update();
+  search.focus();
+}
+
+Main();
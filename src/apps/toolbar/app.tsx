@@ -8,6 +8,7 @@ import { ConfigProvider, theme } from 'antd';
 import { useEffect } from 'react';
 import { useSelector } from 'react-redux';
 
+import { registerDataSourcesEvents } from './modules/item/sources';
 import { ToolBar } from './modules/main/infra';
 
 import { Selectors } from './modules/shared/store/app';
@@ -16,6 +17,8 @@ async function onMount() {
   let view = getCurrentWebviewWindow();
   await emitTo(view.label.replace('/', '-hitbox/'), 'init');
   await emit('register-colors-events');
+  await emit('register-wallpaper-palette-events');
+  await registerDataSourcesEvents();
   await view.show();
 }
 
@@ -0,0 +1,56 @@
+import { FileOperationsTM } from '../../../shared/schemas/Placeholders';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useEffect, useState } from 'react';
+
+import { Item } from '../item/infra';
+
+interface FileOperation {
+  id: number;
+  kind: 'Copy' | 'Move';
+  sources: string[];
+  destination: string;
+  totalBytes: number;
+  doneBytes: number;
+  totalFiles: number;
+  doneFiles: number;
+  current: string | null;
+  status: 'Running' | 'Completed' | 'Failed' | 'Cancelled';
+  error: string | null;
+}
+
+interface Props {
+  module: FileOperationsTM;
+}
+
+export function FileOperationsModule({ module }: Props) {
+  const [operations, setOperations] = useState<FileOperation[]>([]);
+
+  useEffect(() => {
+    invoke<FileOperation[]>('file_operations_get').then(setOperations).catch(console.error);
+    const unlisten = listen<FileOperation[]>('file-operations-changed', (e) => {
+      setOperations(e.payload);
+    });
+    return () => {
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  if (!operations.length) {
+    return null;
+  }
+
+  const running = operations.filter((operation) => operation.status === 'Running');
+  const total = running.reduce((acc, operation) => acc + operation.totalBytes, 0);
+  const done = running.reduce((acc, operation) => acc + operation.doneBytes, 0);
+
+  return (
+    <Item
+      extraVars={{
+        operations,
+        progress: total ? done / total : running.length ? 0 : 1,
+      }}
+      module={module}
+    />
+  );
+}
@@ -1,4 +1,5 @@
 import { Icon } from '../../../../shared/components/Icon';
+import { cx } from '../../../../shared/styles';
 import { invoke } from '@tauri-apps/api/core';
 import { Button } from 'antd';
 import { AnimatePresence, motion } from 'framer-motion';
@@ -37,7 +38,9 @@ export function Notifications() {
         <AnimatePresence>
           {notifications.map((notification) => (
             <motion.div
-              className="notification"
+              className={cx('notification', {
+                'notification-silenced': notification.silenced,
+              })}
               key={notification.id}
               animate={{ x: '0%', opacity: 1 }}
               exit={{ x: '100%', opacity: 0 }}
@@ -45,7 +48,9 @@ export function Notifications() {
             >
               <div className="notification-header">
                 <div className="notification-header-info">
-                  <Icon iconName="TbNotification" />
+                  <Icon
+                    iconName={notification.silenced ? 'TbNotificationOff' : 'TbNotification'}
+                  />
                   <div>{notification.app_name}</div>
                   <span>-</span>
                   <div>
@@ -0,0 +1,83 @@
+import { invoke } from '@tauri-apps/api/core';
+import { Switch } from 'antd';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+import { BackgroundByLayersV2 } from '../../../seelenweg/components/BackgroundByLayers/infra';
+
+interface BatteryHealth {
+  designedCapacity: number;
+  fullChargedCapacity: number;
+  health: number;
+  cycleCount: number;
+  chemistry: string;
+}
+
+interface ChargeLimit {
+  vendor: string;
+  limit: number;
+}
+
+/** same as `ChargeLimit::DEFAULT_LIMIT` on the background */
+const DEFAULT_CHARGE_LIMIT = 80;
+
+export function BatteryPanel() {
+  const [health, setHealth] = useState<BatteryHealth[]>([]);
+  const [chargeLimit, setChargeLimit] = useState<ChargeLimit | null>(null);
+  const [changingLimit, setChangingLimit] = useState(false);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<BatteryHealth[]>('get_battery_health').then(setHealth).catch(console.error);
+    invoke<ChargeLimit | null>('get_charge_limit').then(setChargeLimit).catch(console.error);
+  }, []);
+
+  function toggleChargeLimit(limited: boolean) {
+    setChangingLimit(true);
+    invoke<ChargeLimit | null>('set_charge_limit', { limit: limited ? DEFAULT_CHARGE_LIMIT : 100 })
+      .then(setChargeLimit)
+      .catch(console.error)
+      .finally(() => setChangingLimit(false));
+  }
+
+  return (
+    <div className="battery-panel">
+      <BackgroundByLayersV2 prefix="battery-panel" />
+      <span className="battery-panel-title">{t('battery.health')}</span>
+      {health.map((battery, idx) => (
+        <div className="battery-panel-item" key={idx}>
+          <div className="battery-panel-row">
+            <span>{t('battery.health')}</span>
+            <span>{Math.round(battery.health)}%</span>
+          </div>
+          <div className="battery-panel-row">
+            <span>{t('battery.full_charge_capacity')}</span>
+            <span>{(battery.fullChargedCapacity / 1000).toFixed(1)} Wh</span>
+          </div>
+          <div className="battery-panel-row">
+            <span>{t('battery.design_capacity')}</span>
+            <span>{(battery.designedCapacity / 1000).toFixed(1)} Wh</span>
+          </div>
+          {!!battery.cycleCount && (
+            <div className="battery-panel-row">
+              <span>{t('battery.cycle_count')}</span>
+              <span>{battery.cycleCount}</span>
+            </div>
+          )}
+        </div>
+      ))}
+      {chargeLimit && (
+        <div className="battery-panel-row battery-panel-charge-limit">
+          <span>{t('battery.charge_limit', { limit: DEFAULT_CHARGE_LIMIT })}</span>
+          <Switch
+            size="small"
+            loading={changingLimit}
+            checked={chargeLimit.limit < 100}
+            onChange={toggleChargeLimit}
+          />
+        </div>
+      )}
+    </div>
+  );
+}
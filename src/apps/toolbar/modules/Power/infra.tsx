@@ -1,36 +1,65 @@
 import { PowerToolbarModule } from '../../../shared/schemas/Placeholders';
+import { createSelector } from '@reduxjs/toolkit';
+import { invoke } from '@tauri-apps/api/core';
 import { emit } from '@tauri-apps/api/event';
-import { useEffect } from 'react';
+import { Popover } from 'antd';
+import { useEffect, useState } from 'react';
 import { useSelector } from 'react-redux';
 
 import { Item } from '../item/infra';
+import { registerDataSource } from '../item/sources';
+import { useAppBlur } from '../shared/hooks/infra';
+import { BatteryPanel } from './BatteryPanel';
 
 import { Selectors } from '../shared/store/app';
 
+export const PowerDataSource = registerDataSource({
+  name: 'power',
+  registerEvent: 'register-power-events',
+  select: createSelector([Selectors.powerStatus, Selectors.batteries], (power, batteries) => ({
+    power,
+    batteries,
+    battery: batteries[0] || null,
+  })),
+  actions: {
+    lock: () => invoke('lock_screen'),
+    logOut: () => invoke('log_out'),
+    suspend: () => invoke('suspend'),
+    restart: () => invoke('restart'),
+    shutdown: () => invoke('shutdown'),
+  },
+});
+
 interface Props {
   module: PowerToolbarModule;
 }
 
 export function PowerModule({ module }: Props) {
-  const power = useSelector(Selectors.powerStatus);
-  const batteries = useSelector(Selectors.batteries);
+  const [openPanel, setOpenPanel] = useState(false);
+  const data = useSelector(PowerDataSource.select);
 
   useEffect(() => {
-    emit('register-power-events');
+    emit(PowerDataSource.registerEvent!);
   }, []);
 
-  if (!batteries.length) {
+  useAppBlur(() => {
+    setOpenPanel(false);
+  });
+
+  if (!data.batteries.length) {
     return null;
   }
 
   return (
-    <Item
-      extraVars={{
-        power,
-        batteries,
-        battery: batteries[0],
-      }}
-      module={module}
-    />
+    <Popover
+      open={openPanel}
+      trigger="click"
+      onOpenChange={setOpenPanel}
+      arrow={false}
+      destroyTooltipOnHide
+      content={<BatteryPanel />}
+    >
+      <Item extraVars={data} module={module} />
+    </Popover>
   );
 }
@@ -0,0 +1,99 @@
+import { PrivacyTM } from '../../../shared/schemas/Placeholders';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Popover } from 'antd';
+import moment from 'moment';
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+import { BackgroundByLayersV2 } from '../../../seelenweg/components/BackgroundByLayers/infra';
+import { Item } from '../item/infra';
+import { useAppBlur } from '../shared/hooks/infra';
+
+interface CapabilityUsage {
+  app: string;
+  name: string;
+  capability: 'Microphone' | 'Camera';
+  packaged: boolean;
+  since: number;
+}
+
+interface PrivacyState {
+  microphone: CapabilityUsage[];
+  camera: CapabilityUsage[];
+  microphoneMuted: boolean;
+}
+
+interface Props {
+  module: PrivacyTM;
+}
+
+export function PrivacyModule({ module }: Props) {
+  const [openPreview, setOpenPreview] = useState(false);
+  const [state, setState] = useState<PrivacyState>({ microphone: [], camera: [], microphoneMuted: false });
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    invoke<PrivacyState>('privacy_get_state').then(setState).catch(console.error);
+    const unlisten = listen<PrivacyState>('privacy-changed', (e) => {
+      setState(e.payload);
+    });
+    return () => {
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  useAppBlur(() => {
+    setOpenPreview(false);
+  });
+
+  const usages = [...state.camera, ...state.microphone];
+  if (!usages.length) {
+    return null;
+  }
+
+  const names = [...new Set(usages.map((usage) => usage.name))].join(', ');
+
+  return (
+    <Popover
+      open={openPreview}
+      trigger="click"
+      onOpenChange={setOpenPreview}
+      arrow={false}
+      content={
+        <div className="privacy-panel">
+          <BackgroundByLayersV2 prefix="privacy-panel" />
+          {usages.map((usage) => (
+            <div className="privacy-panel-item" key={`${usage.capability}-${usage.app}`}>
+              <span className="privacy-panel-app" title={usage.app}>
+                {usage.name}
+              </span>
+              <span className="privacy-panel-detail">
+                {t(`privacy.${usage.capability.toLowerCase()}`)} · {moment.unix(usage.since).fromNow()}
+              </span>
+            </div>
+          ))}
+          {!!state.microphone.length && (
+            <button
+              className="privacy-panel-button"
+              onClick={() => invoke('privacy_toggle_microphone_mute').catch(console.error)}
+            >
+              {state.microphoneMuted ? t('privacy.unmute') : t('privacy.mute')}
+            </button>
+          )}
+        </div>
+      }
+    >
+      <Item
+        extraVars={{
+          usingMicrophone: state.microphone.length > 0,
+          usingCamera: state.camera.length > 0,
+          microphoneMuted: state.microphoneMuted,
+          names,
+        }}
+        module={module}
+      />
+    </Popover>
+  );
+}
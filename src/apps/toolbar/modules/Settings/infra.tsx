@@ -1,7 +1,8 @@
 import { Icon } from '../../../shared/components/Icon';
 import { SettingsToolbarModule } from '../../../shared/schemas/Placeholders';
+import { cx } from '../../../shared/styles';
 import { invoke } from '@tauri-apps/api/core';
-import { emit } from '@tauri-apps/api/event';
+import { emit, listen } from '@tauri-apps/api/event';
 import { Popover, Slider, Tooltip } from 'antd';
 import React, { useEffect, useState } from 'react';
 import { useTranslation } from 'react-i18next';
@@ -20,19 +21,29 @@ interface Props {
   module: SettingsToolbarModule;
 }
 
-interface Brightness {
-  min: number;
-  max: number;
+interface MonitorBrightness {
+  id: string;
+  name: string;
   current: number;
+  max: number;
+}
+
+interface BrightnessState {
+  linked: boolean;
+  monitors: MonitorBrightness[];
+}
+
+interface KeepAwakeState {
+  active: boolean;
+  keepDisplayOn: boolean;
+  until: number | null;
 }
 
 export function SettingsModule({ module }: Props) {
   const [openPreview, setOpenPreview] = useState(false);
-  const [brightness, setBrightness] = useState<Brightness>({
-    min: 0,
-    max: 0,
-    current: 0,
-  });
+  const [brightness, setBrightness] = useState<BrightnessState>({ linked: false, monitors: [] });
+  const [keepAwake, setKeepAwake] = useState(false);
+  const [presenting, setPresenting] = useState(false);
 
   const defaultInput = useSelector((state: RootState) =>
     Selectors.mediaInputs(state).find((d) => d.is_default_multimedia),
@@ -48,14 +59,61 @@ export function SettingsModule({ module }: Props) {
   }, []);
 
   useEffect(() => {
-    invoke<Brightness>('get_main_monitor_brightness')
-      .then(setBrightness)
-      .catch(() => {
-        // TODO brightness is always failing
-        // console.error(e);
-      });
+    invoke<KeepAwakeState>('keep_awake_get_state').then((state) => setKeepAwake(state.active));
+    const unlisten = listen<KeepAwakeState>('keep-awake-changed', (e) => {
+      setKeepAwake(e.payload.active);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  useEffect(() => {
+    invoke<string | null>('mode_manager_get_active').then((mode) =>
+      setPresenting(mode === 'presentation'),
+    );
+    const unlisten = listen<string | null>('mode-changed', (e) => {
+      setPresenting(e.payload === 'presentation');
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  function refreshBrightness() {
+    invoke<BrightnessState>('brightness_get_state').then(setBrightness).catch(console.error);
+  }
+
+  useEffect(() => {
+    if (openPreview) {
+      refreshBrightness();
+    }
   }, [openPreview]);
 
+  function onBrightnessChange(id: string, value: number) {
+    const changed = brightness.monitors.find((monitor) => monitor.id === id);
+    if (!changed) {
+      return;
+    }
+    setBrightness({
+      ...brightness,
+      monitors: brightness.monitors.map((monitor) => {
+        if (monitor.id === id) {
+          return { ...monitor, current: value };
+        }
+        return brightness.linked
+          ? { ...monitor, current: Math.round((value / changed.max) * monitor.max) }
+          : monitor;
+      }),
+    });
+  }
+
+  function onToggleLinked() {
+    invoke('brightness_set_linked', { linked: !brightness.linked })
+      .then(() => setBrightness({ ...brightness, linked: !brightness.linked }))
+      .catch(console.error);
+  }
+
   useAppBlur(() => {
     setOpenPreview(false);
   });
@@ -114,20 +172,76 @@ export function SettingsModule({ module }: Props) {
             </div>
           )}
 
-          {brightness.max > 0 && (
-            <div className="fast-settings-item">
-              <Icon iconName="CiBrightnessUp" />
-              <Slider
-                value={brightness.current}
-                onChange={(value) => setBrightness({ ...brightness, current: value })}
-                min={brightness.min}
-                max={brightness.max}
-              />
-            </div>
+          {brightness.monitors.length > 0 && (
+            <span className="fast-settings-label">
+              {t('settings.brightness')}
+              {brightness.monitors.length > 1 && (
+                <Tooltip mouseLeaveDelay={0} arrow={false} title={t('settings.link_brightness')}>
+                  <button
+                    className={cx('fast-settings-label-button', {
+                      'fast-settings-label-button-active': brightness.linked,
+                    })}
+                    onClick={onToggleLinked}
+                  >
+                    <Icon iconName={brightness.linked ? 'BiLink' : 'BiUnlink'} />
+                  </button>
+                </Tooltip>
+              )}
+            </span>
           )}
 
+          {brightness.monitors.map((monitor) => (
+            <Tooltip
+              key={monitor.id}
+              mouseLeaveDelay={0}
+              arrow={false}
+              title={monitor.name}
+              placement="left"
+            >
+              <div className="fast-settings-item">
+                <Icon iconName="CiBrightnessUp" />
+                <Slider
+                  value={monitor.current}
+                  onChange={(value) => onBrightnessChange(monitor.id, value)}
+                  onChangeComplete={(value) => {
+                    invoke('brightness_set', { id: monitor.id, value })
+                      .catch(console.error)
+                      .finally(refreshBrightness);
+                  }}
+                  min={0}
+                  max={monitor.max}
+                />
+              </div>
+            </Tooltip>
+          ))}
+
           <span className="fast-settings-label">{t('settings.power')}</span>
           <div className="fast-settings-item fast-settings-power">
+            <Tooltip mouseLeaveDelay={0} arrow={false} title={t('settings.keep_awake')}>
+              <button
+                className={cx('fast-settings-item-button', {
+                  'fast-settings-item-button-active': keepAwake,
+                })}
+                onClick={() => invoke('keep_awake_toggle')}
+              >
+                <Icon iconName="BiCoffee" />
+              </button>
+            </Tooltip>
+            <Tooltip mouseLeaveDelay={0} arrow={false} title={t('settings.presentation_mode')}>
+              <button
+                className={cx('fast-settings-item-button', {
+                  'fast-settings-item-button-active': presenting,
+                })}
+                onClick={() => invoke('mode_manager_toggle', { mode: 'presentation' })}
+              >
+                <Icon iconName="BiSlideshow" />
+              </button>
+            </Tooltip>
+            <Tooltip mouseLeaveDelay={0} arrow={false} title={t('settings.lock')}>
+              <button className="fast-settings-item-button" onClick={() => invoke('lock_screen')}>
+                <Icon iconName="BiLock" />
+              </button>
+            </Tooltip>
             <Tooltip mouseLeaveDelay={0} arrow={false} title={t('settings.log_out')}>
               <button className="fast-settings-item-button" onClick={() => invoke('log_out')}>
                 <Icon iconName="BiLogOut" />
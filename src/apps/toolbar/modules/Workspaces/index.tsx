@@ -1,6 +1,6 @@
 import { WorkspacesTM, WorkspaceTMMode } from '../../../shared/schemas/Placeholders';
 import { cx } from '../../../shared/styles';
-import { invoke } from '@tauri-apps/api/core';
+import { convertFileSrc, invoke } from '@tauri-apps/api/core';
 import { Tooltip } from 'antd';
 import { Reorder } from 'framer-motion';
 import { useSelector } from 'react-redux';
@@ -14,6 +14,7 @@ interface Props {
 export function WorkspacesModule({ module }: Props) {
   const workspaces = useSelector(Selectors.workspaces);
   const activeWorkspace = useSelector(Selectors.activeWorkspace);
+  const autoNames = useSelector(Selectors.workspacesAutoNames);
 
   const { mode } = module;
 
@@ -42,12 +43,14 @@ export function WorkspacesModule({ module }: Props) {
   return (
     <Reorder.Item as="div" id={module.id} value={module} className="ft-bar-group">
       {workspaces.map((w, idx) => {
+        const autoName = autoNames.find((n) => n.id === w.id);
+        const name = autoName?.name || w.name || `Workspace ${idx + 1}`;
         return (
           <Tooltip
             arrow={false}
             mouseLeaveDelay={0}
             overlayClassName="ft-bar-item-tooltip"
-            title={w.name || `Workspace ${idx + 1}`}
+            title={name}
             key={w.id}
           >
             <div
@@ -59,9 +62,10 @@ export function WorkspacesModule({ module }: Props) {
               onClick={() => invoke('switch_workspace', { idx })}
             >
               <div className="ft-bar-item-content">
-                {mode === WorkspaceTMMode.Named
-                  ? `${w.name || `Workspace ${idx + 1}`}`
-                  : `${idx + 1}`}
+                {mode === WorkspaceTMMode.Named && autoName?.icon && (
+                  <img className="workspace-app-icon" src={convertFileSrc(autoName.icon)} />
+                )}
+                {mode === WorkspaceTMMode.Named ? name : `${idx + 1}`}
               </div>
             </div>
           </Tooltip>
@@ -76,6 +76,12 @@ const ActionsScope = {
   run(program: string, ...args: string[]) {
     invoke('run', { program, args }).catch(console.error);
   },
+  openTaskManager() {
+    invoke('task_manager_show').catch(console.error);
+  },
+  openColorPicker() {
+    invoke('color_picker_show').catch(console.error);
+  },
   copyClipboard(text: string) {
     navigator.clipboard.writeText(text);
   },
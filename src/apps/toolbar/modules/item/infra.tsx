@@ -8,12 +8,13 @@ import { cloneDeep } from 'lodash';
 import { evaluate, isResultSet } from 'mathjs';
 import React, { PropsWithChildren, useEffect, useRef } from 'react';
 import { useTranslation } from 'react-i18next';
-import { useSelector } from 'react-redux';
+import { shallowEqual, useSelector } from 'react-redux';
 
 import { LAZY_CONSTANTS } from '../shared/utils/infra';
 
 import { Selectors } from '../shared/store/app';
 import { performClick, safeEval, Scope } from './app';
+import { runDataSourceAction, selectDataSources } from './sources';
 
 interface Props extends PropsWithChildren {
   module: ToolbarModule;
@@ -165,6 +166,7 @@ export function Item(props: Props) {
 
   const [mounted, setMounted] = React.useState(false);
   const env = useSelector(Selectors.env);
+  const sources = useSelector(selectDataSources, shallowEqual);
 
   const { t } = useTranslation();
   const scope = useRef(new Scope());
@@ -187,6 +189,10 @@ export function Item(props: Props) {
   }
 
   scope.current.set('t', t);
+  scope.current.set('sources', sources);
+  scope.current.set('sourceAction', (name: string, action: string, ...args: any[]) => {
+    runDataSourceAction(sources, name, action, args);
+  });
   if (extraVars) {
     Object.keys(extraVars).forEach((key) => {
       scope.current.set(key, extraVars[key]);
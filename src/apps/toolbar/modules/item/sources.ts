@@ -0,0 +1,63 @@
+import { invoke } from '@tauri-apps/api/core';
+import { emit } from '@tauri-apps/api/event';
+
+import { RootState } from '../shared/store/domain';
+
+/**
+ * Named data readable by all the items as `sources.<name>`. The built-in modules implement
+ * it over the store, third-party sources are provided by the background on `dataSources`.
+ */
+export interface DataSource<T = any> {
+  name: string;
+  /** event emitted to the background to start receiving the data of the source */
+  registerEvent?: string;
+  /** should be memoized, it is evaluated on each store change */
+  select: (state: RootState) => T;
+  /** actions receive the current data of the source before the item arguments */
+  actions?: Record<string, (data: T, ...args: any[]) => unknown>;
+}
+
+const builtIn = new Map<string, DataSource>();
+
+export function registerDataSource<T>(source: DataSource<T>): DataSource<T> {
+  builtIn.set(source.name, source);
+  return source;
+}
+
+/** built-in sources override the ones of the background with the same name */
+export function selectDataSources(state: RootState): Record<string, any> {
+  const sources: Record<string, any> = { ...state.dataSources };
+  for (const source of builtIn.values()) {
+    sources[source.name] = source.select(state);
+  }
+  return sources;
+}
+
+export function runDataSourceAction(
+  sources: Record<string, any>,
+  name: string,
+  action: string,
+  args: any[],
+) {
+  const source = builtIn.get(name);
+  if (!source) {
+    invoke('data_source_action', { name, action, args }).catch(console.error);
+    return;
+  }
+
+  const handler = source.actions?.[action];
+  if (!handler) {
+    console.error(`Data source ${name} has no action named ${action}`);
+    return;
+  }
+  Promise.resolve(handler(sources[name], ...args)).catch(console.error);
+}
+
+export async function registerDataSourcesEvents() {
+  for (const source of builtIn.values()) {
+    if (source.registerEvent) {
+      await emit(source.registerEvent);
+    }
+  }
+  await emit('register-data-sources-events');
+}
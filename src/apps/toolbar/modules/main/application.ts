@@ -2,7 +2,7 @@ import {
   saveJsonSettings,
   UserSettingsLoader,
 } from '../../../settings/modules/shared/store/storeApi';
-import { path } from '@tauri-apps/api';
+import { resolveDataPath } from '../../../settings/modules/shared/config/infra';
 import { writeTextFile } from '@tauri-apps/plugin-fs';
 import yaml from 'js-yaml';
 import { cloneDeep, debounce } from 'lodash';
@@ -24,11 +24,7 @@ export const SavePlaceholderAsCustom = debounce(async () => {
   toBeSaved.info.description = 'Customized by me';
   toBeSaved.info.filename = 'custom.yml';
 
-  const filePath = await path.join(
-    await path.appDataDir(),
-    'placeholders',
-    toBeSaved.info.filename,
-  );
+  const filePath = await resolveDataPath('placeholders', toBeSaved.info.filename);
 
   await writeTextFile(filePath, yaml.dump(toBeSaved));
 
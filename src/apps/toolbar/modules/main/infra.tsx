@@ -3,6 +3,7 @@ import {
   ToolbarModule,
   ToolbarModuleType,
 } from '../../../shared/schemas/Placeholders';
+import { FocusedAppBehavior } from '../../../shared/schemas/AppsConfigurations';
 import { AppBarHideMode } from '../../../shared/schemas/Seelenweg';
 import { cx } from '../../../shared/styles';
 import { TrayModule } from '../Tray';
@@ -15,11 +16,13 @@ import { useDispatch, useSelector } from 'react-redux';
 import { BackgroundByLayersV2 } from '../../../seelenweg/components/BackgroundByLayers/infra';
 import { DateModule } from '../Date/infra';
 import { DeviceModule } from '../Device/infra';
+import { FileOperationsModule } from '../FileOperations/infra';
 import { GenericItem, Item } from '../item/infra';
 import { MediaModule } from '../media/infra/Module';
 import { NetworkModule } from '../network/infra/Module';
 import { NotificationsModule } from '../Notifications/infra/Module';
 import { PowerModule } from '../Power/infra';
+import { PrivacyModule } from '../Privacy/infra';
 import { SettingsModule } from '../Settings/infra';
 import { useAppActivation, useAppBlur } from '../shared/hooks/infra';
 
@@ -38,6 +41,8 @@ const modulesByType: Record<ToolbarModuleType, JSXElementConstructor<{ module: a
   [ToolbarModuleType.Media]: MediaModule,
   [ToolbarModuleType.Device]: DeviceModule,
   [ToolbarModuleType.Notifications]: NotificationsModule,
+  [ToolbarModuleType.FileOperations]: FileOperationsModule,
+  [ToolbarModuleType.Privacy]: PrivacyModule,
 };
 
 interface Props {
@@ -58,6 +63,7 @@ function componentByModule(module: ToolbarModule, idx: number) {
 export function ToolBar({ structure }: Props) {
   const [isActive, setActive] = useState(false);
   const isOverlaped = useSelector(Selectors.isOverlaped);
+  const focusedAppBehavior = useSelector(Selectors.focusedAppBehavior);
   const hideMode = useSelector(Selectors.settings.hideMode);
 
   const dispatch = useDispatch();
@@ -95,10 +101,12 @@ export function ToolBar({ structure }: Props) {
     [],
   );
 
+  // the behavior set on the app config of the focused app applies whatever the hide mode
   const shouldBeHidden =
     !isActive &&
-    hideMode !== AppBarHideMode.Never &&
-    (isOverlaped || hideMode === AppBarHideMode.Always);
+    (focusedAppBehavior === FocusedAppBehavior.Hide ||
+      hideMode === AppBarHideMode.Always ||
+      (hideMode !== AppBarHideMode.Never && isOverlaped));
 
   return (
     <Reorder.Group
@@ -1,44 +1,60 @@
 import { MediaTM } from '../../../../shared/schemas/Placeholders';
 import { WithMediaControls } from './MediaControls';
+import { createSelector } from '@reduxjs/toolkit';
+import { invoke } from '@tauri-apps/api/core';
 import { emit } from '@tauri-apps/api/event';
 import { useEffect } from 'react';
 import { useSelector } from 'react-redux';
 
 import { Item } from '../../item/infra';
+import { registerDataSource } from '../../item/sources';
 
 import { Selectors } from '../../shared/store/app';
+import { MediaChannelTransportData } from '../../shared/store/domain';
+
+/** actions are applied to the default session, ignored if there is none */
+function onSession(command: string) {
+  return ({ mediaSession }: { mediaSession: MediaChannelTransportData | null }) => {
+    if (mediaSession) {
+      return invoke(command, { id: mediaSession.id });
+    }
+    return undefined;
+  };
+}
+
+export const MediaDataSource = registerDataSource({
+  name: 'media',
+  registerEvent: 'register-media-events',
+  select: createSelector(
+    [Selectors.mediaOutputs, Selectors.mediaInputs, Selectors.mediaSessions],
+    (outputs, inputs, sessions) => {
+      const { volume = 0, muted: isMuted = true } =
+        outputs.find((d) => d.is_default_multimedia) || {};
+      const { volume: inputVolume = 0, muted: inputIsMuted = true } =
+        inputs.find((d) => d.is_default_multimedia) || {};
+      const mediaSession = sessions.find((d) => d.default) || null;
+      return { volume, isMuted, inputVolume, inputIsMuted, mediaSession };
+    },
+  ),
+  actions: {
+    togglePlayPause: onSession('media_toggle_play_pause'),
+    next: onSession('media_next'),
+    prev: onSession('media_prev'),
+  },
+});
 
 interface Props {
   module: MediaTM;
 }
 
 function MediaModuleItem({ module, ...rest }: Props) {
-  const { volume = 0, muted: isMuted = true } =
-    useSelector((state: any) =>
-      Selectors.mediaOutputs(state).find((d) => d.is_default_multimedia),
-    ) || {};
-
-  const { volume: inputVolume = 0, muted: inputIsMuted = true } =
-    useSelector((state: any) =>
-      Selectors.mediaInputs(state).find((d) => d.is_default_multimedia),
-    ) || {};
-
-  const mediaSession = useSelector((state: any) =>
-    Selectors.mediaSessions(state).find((d) => d.default),
-  ) || null;
-
-  return (
-    <Item
-      {...rest}
-      extraVars={{ volume, isMuted, inputVolume, inputIsMuted, mediaSession }}
-      module={module}
-    />
-  );
+  const data = useSelector(MediaDataSource.select);
+  return <Item {...rest} extraVars={data} module={module} />;
 }
 
 export function MediaModule({ module }: Props) {
   useEffect(() => {
-    emit('register-media-events');
+    emit(MediaDataSource.registerEvent!);
   }, []);
 
   return module.withMediaControls ? (
@@ -14,3 +14,8 @@ export interface WlanProfile {
   encryption: string;
   password: string;
 }
+
+export interface VpnProfile {
+  name: string;
+  state: 'disconnected' | 'connecting' | 'connected';
+}
@@ -1,40 +1,48 @@
 import { NetworkTM } from '../../../../shared/schemas/Placeholders';
 import { WithWlanSelector } from './WlanSelector';
+import { createSelector } from '@reduxjs/toolkit';
+import { invoke } from '@tauri-apps/api/core';
 import { emit } from '@tauri-apps/api/event';
 import { useEffect } from 'react';
 import { useSelector } from 'react-redux';
 
 import { Item } from '../../item/infra';
+import { registerDataSource } from '../../item/sources';
 
 import { Selectors } from '../../shared/store/app';
 
+export const NetworkDataSource = registerDataSource({
+  name: 'network',
+  registerEvent: 'register-network-events',
+  select: createSelector(
+    [Selectors.networkAdapters, Selectors.networkLocalIp, Selectors.online, Selectors.vpnProfiles],
+    (networkAdapters, defaultIp, online, vpnProfiles) => ({
+      online,
+      interfaces: networkAdapters,
+      usingInterface: networkAdapters.find((i) => i.ipv4 === defaultIp) || null,
+      vpnProfiles,
+      vpn: vpnProfiles.find((p) => p.state === 'connected') || null,
+    }),
+  ),
+  actions: {
+    wlanDisconnect: () => invoke('wlan_disconnect'),
+    vpnConnect: (_data, name: string) => invoke('vpn_connect', { name }),
+    vpnDisconnect: (_data, name: string) => invoke('vpn_disconnect', { name }),
+  },
+});
+
 interface Props {
   module: NetworkTM;
 }
 
 function NetworkModuleItem({ module, ...rest }: Props) {
-  const networkAdapters = useSelector(Selectors.networkAdapters);
-  const defaultIp = useSelector(Selectors.networkLocalIp);
-  const online = useSelector(Selectors.online);
-
-  const usingAdapter = networkAdapters.find((i) => i.ipv4 === defaultIp) || null;
-
-  return (
-    <Item
-      {...rest}
-      extraVars={{
-        online,
-        interfaces: networkAdapters,
-        usingInterface: usingAdapter,
-      }}
-      module={module}
-    />
-  );
+  const data = useSelector(NetworkDataSource.select);
+  return <Item {...rest} extraVars={data} module={module} />;
 }
 
 export function NetworkModule({ module }: Props) {
   useEffect(() => {
-    emit('register-network-events');
+    emit(NetworkDataSource.registerEvent!);
   }, []);
 
   return module.withWlanSelector ? (
@@ -4,11 +4,12 @@ import { Placeholder, ToolbarModule } from '../../../../shared/schemas/Placehold
 import { StateBuilder } from '../../../../shared/StateBuilder';
 import { createSelector, createSlice, PayloadAction } from '@reduxjs/toolkit';
 
-import { RootState } from './domain';
+import { DataSourceUpdate, RootState } from './domain';
 
 const initialState: RootState = {
   version: 0,
   isOverlaped: false,
+  focusedAppBehavior: null,
   focused: null,
   placeholder: null,
   settings: parseAsCamel(FancyToolbarSchema, {}),
@@ -25,11 +26,13 @@ const initialState: RootState = {
   batteries: [],
   workspaces: [],
   activeWorkspace: null,
+  workspacesAutoNames: [],
   systemTray: [],
   networkAdapters: [],
   networkLocalIp: null,
   online: false,
   wlanBssEntries: [],
+  vpnProfiles: [],
   mediaSessions: [],
   mediaOutputs: [],
   mediaInputs: [],
@@ -46,6 +49,7 @@ const initialState: RootState = {
     accent_lightest: '#000000',
     complement: null,
   },
+  dataSources: {},
 };
 
 export const RootSlice = createSlice({
@@ -72,6 +76,9 @@ export const RootSlice = createSlice({
         state.placeholder.right = action.payload;
       }
     },
+    setDataSource(state, action: PayloadAction<DataSourceUpdate>) {
+      state.dataSources[action.payload.name] = action.payload.value;
+    },
   },
 });
 
@@ -1,10 +1,11 @@
 import { IRootState } from '../../../../../shared.interfaces';
 import { FocusedApp } from '../../../../shared/interfaces/common';
+import { FocusedAppBehavior } from '../../../../shared/schemas/AppsConfigurations';
 import { FancyToolbar } from '../../../../shared/schemas/FancyToolbar';
 import { Placeholder } from '../../../../shared/schemas/Placeholders';
 import { SoftOpaque } from 'readable-types';
 
-import { WlanBssEntry } from '../../network/domain';
+import { VpnProfile, WlanBssEntry } from '../../network/domain';
 
 /** https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-system_power_status */
 export interface PowerStatus {
@@ -97,6 +98,7 @@ export interface AppNotification {
   app_logo: string | null;
   body: string[];
   date: number;
+  silenced: boolean;
 }
 
 export interface UIColors {
@@ -112,15 +114,36 @@ export interface UIColors {
   complement: string | null;
 }
 
+export interface WallpaperPalette {
+  wallpaper_primary: string;
+  wallpaper_secondary: string;
+  wallpaper_tertiary: string;
+}
+
 export type WorkspaceId = SoftOpaque<string, 'WorkspaceId'>;
 export interface Workspace {
   id: WorkspaceId;
   name: string | null;
 }
 
+/** name given by the background after the app with more windows on the workspace */
+export interface WorkspaceAutoName {
+  id: WorkspaceId;
+  name: string;
+  icon: string | null;
+}
+
+/** value published by a data source of the background */
+export interface DataSourceUpdate {
+  name: string;
+  value: unknown;
+}
+
 export interface RootState extends IRootState<FancyToolbar> {
   version: number;
   isOverlaped: boolean;
+  /** set on the app config of the focused app */
+  focusedAppBehavior: FocusedAppBehavior | null;
   focused: FocusedApp | null;
   placeholder: Placeholder | null;
   env: Record<string, string>;
@@ -128,14 +151,18 @@ export interface RootState extends IRootState<FancyToolbar> {
   batteries: Battery[];
   workspaces: Workspace[];
   activeWorkspace: WorkspaceId | null;
+  workspacesAutoNames: WorkspaceAutoName[];
   systemTray: TrayInfo[];
   networkAdapters: NetworkAdapter[];
   networkLocalIp: string | null;
   online: boolean;
   wlanBssEntries: WlanBssEntry[];
+  vpnProfiles: VpnProfile[];
   mediaSessions: MediaChannelTransportData[];
   mediaOutputs: MediaDevice[];
   mediaInputs: MediaDevice[];
   notifications: AppNotification[];
   colors: UIColors;
+  /** values of the data sources provided by the background, by name */
+  dataSources: Record<string, unknown>;
 }
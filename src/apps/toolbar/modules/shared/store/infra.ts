@@ -1,11 +1,18 @@
 import { UserSettings } from '../../../../../shared.interfaces';
 import { UserSettingsLoader } from '../../../../settings/modules/shared/store/storeApi';
-import { loadThemeCSS, setColorsAsCssVariables } from '../../../../shared';
+import {
+  listenGameMode,
+  listenWorkspaceAccent,
+  loadThemeCSS,
+  setColorsAsCssVariables,
+} from '../../../../shared';
 import { FileChange, GlobalEvent } from '../../../../shared/events';
 import { FocusedApp } from '../../../../shared/interfaces/common';
+import { FocusedAppBehavior } from '../../../../shared/schemas/AppsConfigurations';
 import { FancyToolbar } from '../../../../shared/schemas/FancyToolbar';
 import i18n from '../../../i18n';
 import { configureStore } from '@reduxjs/toolkit';
+import { invoke } from '@tauri-apps/api/core';
 import { listen as listenGlobal } from '@tauri-apps/api/event';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 import { debounce, throttle } from 'lodash';
@@ -13,17 +20,20 @@ import { debounce, throttle } from 'lodash';
 import { IsSavingCustom } from '../../main/application';
 import { RootActions, RootSlice } from './app';
 
-import { WlanBssEntry } from '../../network/domain';
+import { VpnProfile, WlanBssEntry } from '../../network/domain';
 import {
   AppNotification,
   Battery,
+  DataSourceUpdate,
   MediaChannelTransportData,
   MediaDevice,
   NetworkAdapter,
   PowerStatus,
   TrayInfo,
   UIColors,
+  WallpaperPalette,
   Workspace,
+  WorkspaceAutoName,
   WorkspaceId,
 } from './domain';
 
@@ -38,6 +48,11 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.setIsOverlaped(event.payload));
   });
 
+  await view.listen<FocusedAppBehavior | null>('set-focused-app-behavior', (event) => {
+    store.dispatch(RootActions.setFocusedAppBehavior(event.payload));
+    document.body.classList.toggle('focus-dimmed', event.payload === FocusedAppBehavior.Dim);
+  });
+
   const onFocusChanged = debounce((app: FocusedApp) => {
     store.dispatch(RootActions.setFocused(app));
   }, 200);
@@ -68,6 +83,16 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.setActiveWorkspace(event.payload));
   });
 
+  await listenGlobal<WorkspaceAutoName[]>('workspaces-auto-names', (event) => {
+    store.dispatch(RootActions.setWorkspacesAutoNames(event.payload));
+  });
+
+  await listenGlobal<DataSourceUpdate>('data-source-changed', (event) => {
+    store.dispatch(RootActions.setDataSource(event.payload));
+  });
+  // sources already started by other toolbars will not emit again until they change
+  store.dispatch(RootActions.setDataSources(await invoke<Record<string, unknown>>('data_sources_get')));
+
   await listenGlobal<TrayInfo[]>('tray-info', (event) => {
     store.dispatch(RootActions.setSystemTray(event.payload));
   });
@@ -107,11 +132,22 @@ export async function registerStoreEvents() {
     store.dispatch(RootActions.setWlanBssEntries(event.payload));
   });
 
+  await listenGlobal<VpnProfile[]>('vpn-profiles', (event) => {
+    store.dispatch(RootActions.setVpnProfiles(event.payload));
+  });
+
+  await listenGameMode();
+  await listenWorkspaceAccent();
+
   await listenGlobal<UIColors>('colors', (event) => {
     setColorsAsCssVariables(event.payload);
     store.dispatch(RootActions.setColors(event.payload));
   });
 
+  await listenGlobal<WallpaperPalette>('wallpaper-palette', (event) => {
+    setColorsAsCssVariables(event.payload);
+  });
+
   await listenGlobal(FileChange.Themes, async () => {
     const userSettings = await new UserSettingsLoader().load();
     loadThemeCSS(userSettings);
@@ -0,0 +1,37 @@
+import { wrapConsole } from '../shared/ConsoleWrapper';
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+import './index.css';
+
+interface ZonePickerState {
+  zones: { left: number; top: number; right: number; bottom: number }[];
+  hovered: number | null;
+}
+
+async function Main() {
+  wrapConsole();
+  const view = getCurrentWebviewWindow();
+
+  await view.listen<ZonePickerState>('zone-picker-state', (event) => {
+    // rects are in physical pixels
+    const ratio = window.devicePixelRatio;
+    const { zones, hovered } = event.payload;
+
+    document.body.replaceChildren(
+      ...zones.map((zone, idx) => {
+        const element = document.createElement('div');
+        element.className = idx === hovered ? 'zone zone-hovered' : 'zone';
+        element.style.left = `${zone.left / ratio}px`;
+        element.style.top = `${zone.top / ratio}px`;
+        element.style.width = `${(zone.right - zone.left) / ratio}px`;
+        element.style.height = `${(zone.bottom - zone.top) / ratio}px`;
+        element.textContent = `${idx + 1}`;
+        return element;
+      }),
+    );
+  });
+
+  await view.emitTo(view.label, 'complete-setup');
+}
+
+Main();
@@ -1,27 +1,77 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::Arc;
 
-use tauri::{Builder, Wry};
+use tauri::{ipc::Invoke, Builder, Wry};
 use tauri_plugin_shell::ShellExt;
 
 use crate::error_handler::Result;
 use crate::modules::input::Keyboard;
-use crate::modules::virtual_desk::get_vd_manager;
+use crate::modules::virtual_desk::{get_vd_manager, visuals::WorkspaceVisuals};
 use crate::seelen::{get_app_handle, Seelen, SEELEN};
+use crate::seelen_weg::app_names::AppNames;
 use crate::seelen_weg::handler::*;
 use crate::seelen_weg::icon_extractor::extract_and_save_icon;
 use crate::seelen_wm::handler::*;
 use crate::state::infrastructure::*;
 use crate::system::brightness::*;
-use crate::utils::is_virtual_desktop_supported as virtual_desktop_supported;
+use crate::utils::app_data_path;
 use crate::{log_error, trace_lock};
 
+use crate::modules::action_history::infrastructure::*;
+use crate::modules::alerts::infrastructure::*;
+use crate::modules::app_usage::infrastructure::*;
+use crate::modules::associations::infrastructure::*;
+use crate::modules::audio_profiles::infrastructure::*;
+use crate::modules::cache::infrastructure::*;
+use crate::modules::color_picker::infrastructure::*;
+use crate::modules::color_scheme::infrastructure::*;
+use crate::modules::conflicts::infrastructure::*;
+use crate::modules::context_menu::infrastructure::*;
+use crate::modules::cursor::infrastructure::*;
+use crate::modules::data_sources::infrastructure::*;
+use crate::modules::desktop_icons::infrastructure::*;
+use crate::modules::devices::infrastructure::*;
+use crate::modules::do_not_disturb::infrastructure::*;
+use crate::modules::emoji_picker::infrastructure::*;
+use crate::modules::event_recorder::infrastructure::*;
+use crate::modules::file_operations::infrastructure::*;
+use crate::modules::focus::infrastructure::*;
+use crate::modules::focus_sessions::infrastructure::*;
+use crate::modules::game_mode::infrastructure::*;
+use crate::modules::inspector::infrastructure::*;
+use crate::modules::keep_awake::infrastructure::*;
+use crate::modules::launcher::infrastructure::*;
+use crate::modules::lock_screen::infrastructure::*;
 use crate::modules::media::infrastructure::*;
+use crate::modules::metrics::infrastructure::*;
+use crate::modules::mode_manager::infrastructure::*;
+use crate::modules::monitors::infrastructure::*;
 use crate::modules::network::infrastructure::*;
+use crate::modules::notes::infrastructure::*;
 use crate::modules::notifications::infrastructure::*;
+use crate::modules::onboarding::infrastructure::*;
+use crate::modules::os_capabilities::{domain::OsCapabilities, infrastructure::*};
+use crate::modules::overview::infrastructure::*;
+use crate::modules::permissions::{infrastructure::*, InvokeHandler, Permissions};
+use crate::modules::posture::infrastructure::*;
 use crate::modules::power::infrastructure::*;
+use crate::modules::printing::infrastructure::*;
+use crate::modules::privacy::infrastructure::*;
+use crate::modules::process_controls::infrastructure::*;
+use crate::modules::quick_access::infrastructure::*;
+use crate::modules::recently_closed::infrastructure::*;
+use crate::modules::ruler::infrastructure::*;
+use crate::modules::shell::infrastructure::*;
+use crate::modules::startup::infrastructure::*;
+use crate::modules::storage::infrastructure::*;
+use crate::modules::task_manager::infrastructure::*;
+use crate::modules::touch_keyboard::infrastructure::*;
 use crate::modules::tray::infrastructure::*;
+use crate::modules::undo::infrastructure::*;
+use crate::modules::window_controls::infrastructure::*;
+use crate::modules::zones::infrastructure::*;
 
 #[tauri::command(async)]
 fn select_file_on_explorer(path: String) {
@@ -93,6 +143,16 @@ fn switch_workspace(idx: usize) -> Result<()> {
     get_vd_manager().switch_to(idx)
 }
 
+#[tauri::command(async)]
+fn get_active_workspace() -> Result<String> {
+    Ok(get_vd_manager().get_current()?.id())
+}
+
+#[tauri::command(async)]
+fn get_workspace_accent() -> Option<String> {
+    WorkspaceVisuals::accent()
+}
+
 #[tauri::command(async)]
 fn ensure_hitboxes_zorder() -> Result<()> {
     let seelen = trace_lock!(SEELEN);
@@ -117,13 +177,28 @@ fn get_icon(path: String) -> Option<PathBuf> {
     extract_and_save_icon(&get_app_handle(), &path).ok()
 }
 
+#[tauri::command(async)]
+fn get_app_display_name(path: String) -> String {
+    AppNames::get(&path)
+}
+
 #[tauri::command(async)]
 fn is_virtual_desktop_supported() -> bool {
-    virtual_desktop_supported()
+    OsCapabilities::get().native_virtual_desktops
+}
+
+#[tauri::command(async)]
+fn get_data_dir() -> PathBuf {
+    app_data_path(&get_app_handle())
 }
 
 pub fn register_invoke_handler(app_builder: Builder<Wry>) -> Builder<Wry> {
-    app_builder.invoke_handler(tauri::generate_handler![
+    let handler: InvokeHandler = Arc::new(commands());
+    app_builder.invoke_handler(move |invoke| Permissions::dispatch_invoke(invoke, &handler))
+}
+
+fn commands() -> impl Fn(Invoke<Wry>) -> bool + Send + Sync + 'static {
+    tauri::generate_handler![
         // General
         run,
         is_dev_mode,
@@ -134,18 +209,26 @@ pub fn register_invoke_handler(app_builder: Builder<Wry>) -> Builder<Wry> {
         get_user_envs,
         show_app_settings,
         switch_workspace,
+        get_active_workspace,
+        get_workspace_accent,
         ensure_hitboxes_zorder,
         send_keys,
         get_icon,
+        get_app_display_name,
+        get_data_dir,
         // Seelen Settings
         set_auto_start,
         get_auto_start_status,
         state_get_themes,
         state_get_placeholders,
         state_get_layouts,
+        state_save_layout,
+        state_delete_layout,
         state_get_weg_items,
         state_get_settings,
+        state_validate_config,
         state_get_specific_apps_configurations,
+        match_window_rules,
         state_get_wallpaper,
         state_set_wallpaper,
         // Media
@@ -158,19 +241,57 @@ pub fn register_invoke_handler(app_builder: Builder<Wry>) -> Builder<Wry> {
         // Brightness
         get_main_monitor_brightness,
         set_main_monitor_brightness,
+        brightness_get_state,
+        brightness_set,
+        brightness_set_linked,
         // Power
+        get_battery_health,
+        get_charge_limit,
+        set_charge_limit,
         log_out,
         suspend,
         restart,
         shutdown,
         // SeelenWeg
         weg_close_app,
+        weg_close_windows,
+        weg_get_hung_windows,
+        weg_get_suspended_windows,
+        weg_get_background_apps,
+        weg_kill_app,
+        weg_get_overlay_icons,
+        weg_get_folder_badges,
+        weg_search_windows,
+        weg_scrub_thumbnails,
+        weg_scrub_end,
+        weg_tab_thumbnails,
+        weg_tab_thumbnails_end,
+        weg_get_window_verdict,
+        weg_set_force_show,
         weg_toggle_window_state,
+        weg_set_run_as_admin,
+        weg_open_app_settings,
+        weg_uninstall_app,
         weg_request_update_previews,
+        weg_set_hitbox,
+        weg_hitbox_enter,
+        weg_hitbox_leave,
+        weg_drag_over,
+        weg_drag_leave,
+        weg_activate_tab,
         // Windows Manager
         set_window_position,
         bounce_handle,
+        wm_float_unfittable,
         request_focus,
+        wm_set_split,
+        wm_set_stack_mode,
+        wm_cycle_tab,
+        wm_export_layout,
+        wm_apply_layout,
+        wm_write_layout_tree,
+        wm_send_to_monitor,
+        wm_send_workspace_to_monitor,
         // tray icons
         temp_get_by_event_tray_info,
         on_click_tray_icon,
@@ -181,8 +302,195 @@ pub fn register_invoke_handler(app_builder: Builder<Wry>) -> Builder<Wry> {
         wlan_stop_scanning,
         wlan_connect,
         wlan_disconnect,
+        vpn_get_profiles,
+        vpn_connect,
+        vpn_disconnect,
         // notifications
         notifications_close,
         notifications_close_all,
-    ])
+        // overview
+        overview_focus,
+        overview_close,
+        // window controls
+        window_set_topmost,
+        window_toggle_topmost,
+        window_set_opacity,
+        window_toggle_shade,
+        window_pin_to_all_desktops,
+        window_get_pinned_to_all_desktops,
+        // zones
+        snap_to_zone,
+        zones_save_layout,
+        zones_delete_layout,
+        zones_set_monitor_layout,
+        zones_preview_layout,
+        zones_close_preview,
+        // startup apps
+        startup_get_apps,
+        startup_set_enabled,
+        startup_add,
+        startup_remove,
+        // file and protocol associations
+        associations_get_default,
+        associations_change_default,
+        associations_open_settings,
+        get_open_with_candidates,
+        open_with,
+        // quick access
+        quick_access_get_folders,
+        quick_access_get_recent_files,
+        // launcher
+        launcher_search,
+        launcher_refresh,
+        launcher_execute,
+        // emoji picker
+        emoji_picker_search,
+        emoji_picker_select,
+        emoji_picker_close,
+        // keep awake
+        keep_awake_get_state,
+        keep_awake_enable,
+        keep_awake_disable,
+        keep_awake_toggle,
+        // devices
+        get_removable_drives,
+        eject_drive,
+        // storage
+        get_storage_usage,
+        open_drive,
+        open_storage_settings,
+        // gpu
+        get_gpu_usage,
+        // printing
+        get_printers,
+        set_default_printer,
+        get_print_jobs,
+        pause_print_job,
+        resume_print_job,
+        cancel_print_job,
+        set_printer_paused,
+        // undo
+        undo_last_action,
+        // recently closed
+        reopen_last_closed,
+        reopen_closed,
+        get_recently_closed,
+        // cursor
+        cursor_is_locked,
+        cursor_toggle_lock,
+        cursor_find,
+        // window inspector
+        inspector_start_picking,
+        inspector_stop_picking,
+        // event recorder
+        event_recorder_start,
+        event_recorder_stop,
+        event_recorder_is_recording,
+        event_recorder_dump,
+        // shell
+        shell_is_registered,
+        shell_is_active,
+        shell_set_registered,
+        shell_launch_explorer,
+        // desktop icons
+        desktop_icons_get_items,
+        desktop_icons_set_position,
+        desktop_icons_open,
+        // file operations
+        file_operations_get,
+        file_operation_start,
+        file_operation_cancel,
+        // touch keyboard
+        touch_keyboard_show,
+        touch_keyboard_hide,
+        touch_keyboard_toggle,
+        touch_keyboard_is_visible,
+        // posture
+        get_posture,
+        // color scheme
+        get_color_scheme,
+        // lock screen
+        lock_screen,
+        // do not disturb
+        do_not_disturb_is_active,
+        // notes and reminders
+        notes_get,
+        notes_add,
+        notes_update,
+        notes_delete,
+        reminders_add,
+        reminders_update,
+        reminders_delete,
+        // game mode
+        game_mode_is_active,
+        // data sources
+        data_sources_get,
+        data_source_action,
+        // process controls
+        get_process_rule,
+        set_process_priority,
+        set_process_affinity,
+        // task manager
+        task_manager_show,
+        task_manager_list,
+        task_manager_end_task,
+        task_manager_activate,
+        task_manager_open_system,
+        task_manager_close,
+        // shell context menu
+        open_shell_context_menu,
+        // focus requests
+        get_focus_request_stats,
+        // action history
+        get_action_history,
+        // permissions
+        permissions_get,
+        permissions_set_scopes,
+        permissions_revoke,
+        permissions_create_token,
+        // onboarding
+        onboarding_get,
+        onboarding_complete,
+        // conflicting software
+        get_conflicts,
+        conflicts_rescan,
+        // os capabilities
+        get_os_capabilities,
+        // cache
+        cache_get_stats,
+        cache_clear,
+        // color picker
+        color_picker_show,
+        color_picker_pick,
+        color_picker_close,
+        color_picker_history,
+        // screen ruler
+        screen_ruler_toggle,
+        screen_ruler_is_open,
+        // app usage
+        app_usage_get,
+        app_usage_clear,
+        // focus sessions
+        focus_sessions_is_active,
+        // display color
+        monitors_get_display_colors,
+        monitors_set_hdr,
+        monitors_set_color_profile,
+        // audio profiles
+        audio_profiles_apply,
+        audio_profiles_get_active,
+        audio_profiles_get_devices,
+        // privacy
+        privacy_get_state,
+        privacy_toggle_microphone_mute,
+        // modes
+        mode_manager_get_active,
+        mode_manager_enter,
+        mode_manager_exit,
+        mode_manager_toggle,
+        // alerts
+        alerts_get_active,
+        alerts_snooze,
+        alerts_acknowledge,
+    ]
 }
@@ -5,19 +5,21 @@ use std::{
         atomic::{AtomicIsize, Ordering},
         Arc,
     },
+    thread::JoinHandle,
     time::{Duration, Instant},
 };
 
 use color_eyre::owo_colors::OwoColorize;
+use crossbeam_channel::{unbounded, Sender};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
 use serde::Serialize;
-use tauri::Emitter;
+use tauri::{Emitter, WebviewWindow};
 use windows::Win32::{
     Foundation::HWND,
     UI::{
-        Accessibility::{SetWinEventHook, HWINEVENTHOOK},
+        Accessibility::{SetWinEventHook, UnhookWinEvent, HWINEVENTHOOK},
         WindowsAndMessaging::{
             DispatchMessageW, GetMessageW, TranslateMessage, EVENT_MAX, EVENT_MIN, MSG,
         },
@@ -26,16 +28,37 @@ use windows::Win32::{
 
 use crate::{
     error_handler::Result,
+    host::Surface,
     log_error,
     modules::{
-        input::{domain::Point, Mouse},
-        virtual_desk::{get_vd_manager, VirtualDesktopEvent, VirtualDesktopManager},
+        app_usage::AppUsageTracker,
+        automation::Automation,
+        event_recorder::EventRecorder,
+        focus_sessions::FocusSessions,
+        game_mode::GameMode,
+        input::{domain::Point, layout_memory::LayoutMemory, Mouse},
+        overview::Overview,
+        popup_filter::PopupFilter,
+        process_controls::ProcessControls,
+        quiet_launch::QuietLaunch,
+        recently_closed::RecentlyClosed,
+        secure_desktop::SecureDesktop,
+        virtual_desk::{
+            get_vd_manager, naming::WorkspaceNames, visuals::WorkspaceVisuals, VirtualDesktopEvent,
+            VirtualDesktopManager,
+        },
+        watchdog::Watchdog,
+        window_controls::WindowControls,
+        window_memory::WindowMemory,
+        window_titles::WindowTitles,
+        zones::ZonesManager,
     },
     seelen::{get_app_handle, Seelen, SEELEN},
-    seelen_weg::SeelenWeg,
+    seelen_weg::{zorder::ZOrderGuardian, SeelenWeg},
+    seelen_wm::WindowManager,
     state::{application::FULL_STATE, domain::AppExtraFlag},
     trace_lock,
-    utils::{constants::IGNORE_FOCUS, spawn_named_thread},
+    utils::{constants::IGNORE_FOCUS, spawn_named_thread, throttle::emit_throttled},
     windows_api::{window::Window, WindowsApi},
     winevent::WinEvent,
 };
@@ -44,12 +67,32 @@ lazy_static! {
     pub static ref HOOK_MANAGER: Arc<Mutex<HookManager>> = Arc::new(Mutex::new(HookManager::new()));
     // Last active window omitting all the seelen apps
     pub static ref LAST_ACTIVE_NOT_SEELEN: AtomicIsize = AtomicIsize::new(WindowsApi::get_foreground_window().0);
+    static ref WINDOW_SHOWN_QUEUE: Sender<HWND> = spawn_window_shown_worker();
+}
+
+/// Windows shown are configured off the hook thread, one by one in the order they appeared
+fn spawn_window_shown_worker() -> Sender<HWND> {
+    let (sender, receiver) = unbounded::<HWND>();
+    log_error!(spawn_named_thread("Window Shown Worker", move || {
+        for origin in receiver {
+            log_error!(WindowControls::apply_app_config(origin));
+            log_error!(ProcessControls::apply_rule(origin));
+            Automation::on_window_shown(origin);
+        }
+    }));
+    sender
 }
 
 pub struct HookManager {
     skip: HashMap<isize, Vec<WinEvent>>,
 }
 
+/// Dock and window manager of a monitor
+pub type MonitorShell<'a, S = WebviewWindow> = (
+    Option<&'a mut SeelenWeg<S>>,
+    Option<&'a mut WindowManager<S>>,
+);
+
 #[derive(Serialize, Clone)]
 pub struct FocusedApp {
     hwnd: isize,
@@ -106,59 +149,116 @@ impl HookManager {
         // uncomment for debug
         // Self::_log_event(event, origin);
 
+        if event != WinEvent::ObjectLocationChange {
+            EventRecorder::record_win_event(event, origin);
+        }
+
         if self.should_skip(event, origin.0) {
             log::trace!("Skipping WinEvent::{:?}", event);
             self.skip_done(event, origin.0);
             return;
         }
 
+        SecureDesktop::on_win_event(event);
+        LayoutMemory::on_win_event(event, origin);
+
         let window = Window::from(origin);
         if event == WinEvent::SystemForeground && !window.is_seelen_window() {
             LAST_ACTIVE_NOT_SEELEN.store(origin.0, Ordering::Relaxed);
         }
 
+        if event == WinEvent::SystemForeground {
+            AppUsageTracker::on_foreground(origin);
+            FocusSessions::on_foreground(origin);
+        }
+
         if event == WinEvent::ObjectFocus || event == WinEvent::SystemForeground {
             let title = window.title();
             if IGNORE_FOCUS.contains(&title) {
                 log::trace!("Skipping WinEvent::{:?}", event);
                 return;
             }
-            log_error!(get_app_handle().emit(
+            log_error!(emit_throttled(
                 "global-focus-changed",
+                "global",
                 FocusedApp {
-                    title,
+                    title: WindowTitles::normalize(&title),
                     hwnd: origin.0,
                     name: window
                         .app_display_name()
                         .unwrap_or(String::from("Error on App Name")),
                     exe: window.exe().ok(),
                 },
+                Duration::from_millis(100),
             ));
         }
 
+        match event {
+            WinEvent::ObjectShow => {
+                // on the hook thread, so the dialog is moved before it is painted
+                log_error!(WindowControls::center_dialog(origin));
+                QuietLaunch::on_window_shown(origin);
+                log_error!(WINDOW_SHOWN_QUEUE.send(origin));
+            }
+            WinEvent::ObjectDestroy => WindowControls::forget(origin),
+            _ => {}
+        }
+        RecentlyClosed::on_win_event(event, origin);
+
+        if matches!(
+            event,
+            WinEvent::ObjectShow | WinEvent::ObjectHide | WinEvent::ObjectDestroy
+        ) {
+            WorkspaceNames::request_update();
+        }
+
         std::thread::spawn(move || {
             if let VirtualDesktopManager::Seelen(vd) = get_vd_manager().as_ref() {
                 log_error!(vd.on_win_event(event, origin));
             }
         });
 
-        if seelen.state().is_weg_enabled() {
-            log_error!(SeelenWeg::process_global_win_event(event, origin));
-        }
-
+        let weg_enabled = seelen.state().is_weg_enabled();
         for monitor in seelen.monitors_mut() {
             if let Some(toolbar) = monitor.toolbar_mut() {
                 log_error!(toolbar.process_win_event(event, origin));
             }
+        }
+
+        let shells = seelen.monitors_mut().iter_mut().map(|m| m.shell_mut());
+        let managed = Self::dispatch_to_shell(event, origin, weg_enabled, shells);
+        if weg_enabled {
+            ZOrderGuardian::on_win_event(event, origin);
+        }
+        log_error!(ZonesManager::process_win_event(event, origin, managed));
+        log_error!(WindowMemory::process_win_event(event, origin, managed));
+    }
+
+    /// Forwards the event to the dock and the window managers, returns true if the window
+    /// is managed by one of them after the event.
+    pub fn dispatch_to_shell<'a, S: Surface + 'a>(
+        event: WinEvent,
+        origin: HWND,
+        weg_enabled: bool,
+        monitors: impl IntoIterator<Item = MonitorShell<'a, S>>,
+    ) -> bool {
+        // before the dock and the wm so popups closing now are already counted as transient
+        PopupFilter::on_win_event(event, origin);
+        if weg_enabled {
+            log_error!(SeelenWeg::process_global_win_event(event, origin));
+        }
 
-            if let Some(weg) = monitor.weg_mut() {
+        let mut managed = false;
+        for (weg, wm) in monitors {
+            if let Some(weg) = weg {
                 log_error!(weg.process_individual_win_event(event, origin));
             }
-
-            if let Some(wm) = monitor.wm_mut() {
+            if let Some(wm) = wm {
                 log_error!(wm.process_win_event(event, origin));
+                managed |= wm.is_managed(origin);
             }
         }
+        managed
     }
 }
 
@@ -188,12 +288,16 @@ pub fn process_vd_event(event: VirtualDesktopEvent) -> Result<()> {
                 .map(|d| d.as_serializable())
                 .collect_vec();
             seelen.handle().emit("workspaces-changed", &desktops)?;
+            WorkspaceNames::request_update();
         }
 
         VirtualDesktopEvent::DesktopChanged { new, old: _ } => {
             seelen.handle().emit("active-workspace-changed", new.id())?;
+            let idx = get_vd_manager().get_current_idx()?;
+            std::thread::spawn(move || log_error!(WorkspaceVisuals::apply(idx)));
         }
         VirtualDesktopEvent::WindowChanged(window) => {
+            WorkspaceNames::request_update();
             let hwnd = HWND(window);
             if WindowsApi::is_window(hwnd) {
                 if let Some(config) = FULL_STATE.load().get_app_config_by_window(hwnd) {
@@ -266,7 +370,9 @@ pub extern "system" fn win_event_hook(
         Err(_) => return,
     };
 
-    if event == WinEvent::ObjectLocationChange && !location_delay_completed(hwnd) {
+    if event == WinEvent::ObjectLocationChange
+        && (GameMode::is_active() || !location_delay_completed(hwnd))
+    {
         return;
     }
 
@@ -280,46 +386,80 @@ pub extern "system" fn win_event_hook(
     }
 }
 
-pub fn register_win_hook() -> Result<()> {
-    log::trace!("Registering Windows and Virtual Desktop Hooks");
+const WIN_EVENT_HOOK: &str = "win-event-hook";
+const MOUSE_EVENT_HOOK: &str = "mouse-event-hook";
 
+fn spawn_win_event_hook(generation: u32) -> Result<JoinHandle<()>> {
     // let stack_size = 5 * 1024 * 1024; // 5 MB
     spawn_named_thread("WinEventHook", move || unsafe {
-        SetWinEventHook(EVENT_MIN, EVENT_MAX, None, Some(win_event_hook), 0, 0, 0);
+        let hook = SetWinEventHook(EVENT_MIN, EVENT_MAX, None, Some(win_event_hook), 0, 0, 0);
+        Watchdog::attach_message_loop(WIN_EVENT_HOOK, generation);
 
         let mut msg: MSG = MSG::default();
         loop {
             if !GetMessageW(&mut msg, HWND(0), 0, 0).as_bool() {
                 log::info!("windows event processing shutdown");
+                let _ = UnhookWinEvent(hook);
                 break;
             };
+            if !Watchdog::heartbeat(WIN_EVENT_HOOK, generation) {
+                // a new hook was registered while this one was hung
+                let _ = UnhookWinEvent(hook);
+                break;
+            }
             let _ = TranslateMessage(&msg);
             DispatchMessageW(&msg);
             std::thread::sleep(Duration::from_millis(10));
         }
-    })?;
-
-    let (sender, receiver) = std::sync::mpsc::channel::<VirtualDesktopEvent>();
-    get_vd_manager().listen_events(sender)?;
-    spawn_named_thread("VirtualDesktopEventHook", move || {
-        for event in receiver {
-            log_error!(process_vd_event(event))
-        }
-    })?;
+    })
+}
 
-    spawn_named_thread("MouseEventHook", || {
+fn spawn_mouse_event_hook(generation: u32) -> Result<JoinHandle<()>> {
+    spawn_named_thread("MouseEventHook", move || {
         let handle = get_app_handle();
         let mut last_pos = Point::default();
-        loop {
+        while Watchdog::heartbeat(MOUSE_EVENT_HOOK, generation) {
             if let Ok(pos) = Mouse::get_cursor_pos() {
                 if last_pos != pos {
                     let _ = handle.emit("global-mouse-move", &[pos.get_x(), pos.get_y()]);
+                    Overview::on_cursor_move(pos);
                     last_pos = pos;
                 }
             }
             std::thread::sleep(Duration::from_millis(66)); // 15 FPS
         }
+    })
+}
+
+pub fn register_win_hook() -> Result<()> {
+    log::trace!("Registering Windows and Virtual Desktop Hooks");
+
+    Watchdog::supervise(
+        WIN_EVENT_HOOK,
+        Duration::from_secs(10),
+        spawn_win_event_hook,
+    )?;
+
+    let (sender, receiver) = std::sync::mpsc::channel::<VirtualDesktopEvent>();
+    get_vd_manager().listen_events(sender)?;
+    spawn_named_thread("VirtualDesktopEventHook", move || {
+        for event in receiver {
+            log_error!(process_vd_event(event))
+        }
     })?;
 
+    Watchdog::supervise(
+        MOUSE_EVENT_HOOK,
+        Duration::from_secs(5),
+        spawn_mouse_event_hook,
+    )?;
+    Ok(())
+}
+
+/// Unhooks the windows events and stops the mouse polling, nothing is handled after this
+pub fn release_win_hook() -> Result<()> {
+    log::trace!("Releasing Windows Hooks");
+    Watchdog::release(WIN_EVENT_HOOK)?;
+    Watchdog::release(MOUSE_EVENT_HOOK)?;
     Ok(())
 }
@@ -0,0 +1,152 @@
+//! App side of the hook path: the state, the virtual desktops and the webviews. It is behind
+//! traits as the window queries are behind `WindowsBackend`, so the tests can drive the real
+//! dock and window manager without an app handle.
+
+use std::path::Path;
+
+use seelen_core::state::{AppExtraFlag, FocusedAppBehavior, PopupFilterSettings};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{Emitter, WebviewWindow, Wry};
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    modules::{virtual_desk::get_vd_manager, window_titles::normalize_with},
+    seelen::get_app_handle,
+    seelen_weg::{rules::WegVerdict, SeelenWeg, WegAppInfo},
+    state::application::FULL_STATE,
+    windows_api::window::Window,
+};
+
+pub trait ShellHost {
+    /// emits to all the webviews
+    fn emit(&self, event: &str, payload: Value) -> Result<()>;
+    fn popup_filter(&self) -> PopupFilterSettings;
+    /// applies the rewrite rules of the window titles
+    fn normalize_title(&self, title: &str) -> String;
+    fn weg_verdict(&self, window: &Window) -> WegVerdict;
+    /// `exe` is none if the path of the window could not be read
+    fn weg_app_info(&self, exe: Option<&Path>) -> WegAppInfo;
+    /// options of the app config matching the window
+    fn app_flags(&self, window: &Window) -> Vec<AppExtraFlag>;
+    fn weg_while_focused(&self, window: &Window) -> Option<FocusedAppBehavior>;
+    fn current_desktop(&self) -> Result<String>;
+    fn desktop_of(&self, window: &Window) -> Result<String>;
+    fn is_pinned_to_all_desktops(&self, window: &Window) -> bool;
+    fn uses_cloak(&self) -> bool;
+}
+
+pub struct AppHost;
+
+impl ShellHost for AppHost {
+    fn emit(&self, event: &str, payload: Value) -> Result<()> {
+        get_app_handle().emit(event, payload)?;
+        Ok(())
+    }
+
+    fn popup_filter(&self) -> PopupFilterSettings {
+        FULL_STATE.load().settings().popup_filter.clone()
+    }
+
+    fn normalize_title(&self, title: &str) -> String {
+        normalize_with(title, &FULL_STATE.load().settings().window_titles)
+    }
+
+    fn weg_verdict(&self, window: &Window) -> WegVerdict {
+        WegVerdict::evaluate(window.hwnd())
+    }
+
+    fn weg_app_info(&self, exe: Option<&Path>) -> WegAppInfo {
+        SeelenWeg::app_info(exe)
+    }
+
+    fn app_flags(&self, window: &Window) -> Vec<AppExtraFlag> {
+        FULL_STATE
+            .load()
+            .get_app_config_by_window(window.hwnd())
+            .map(|config| config.options.clone())
+            .unwrap_or_default()
+    }
+
+    fn weg_while_focused(&self, window: &Window) -> Option<FocusedAppBehavior> {
+        FULL_STATE
+            .load()
+            .get_app_config_by_window(window.hwnd())
+            .and_then(|config| config.weg_while_focused)
+    }
+
+    fn current_desktop(&self) -> Result<String> {
+        Ok(get_vd_manager().get_current()?.id())
+    }
+
+    fn desktop_of(&self, window: &Window) -> Result<String> {
+        Ok(get_vd_manager().get_by_window(window.hwnd().0)?.id())
+    }
+
+    fn is_pinned_to_all_desktops(&self, window: &Window) -> bool {
+        get_vd_manager()
+            .is_pinned_window(window.hwnd().0)
+            .unwrap_or(false)
+    }
+
+    fn uses_cloak(&self) -> bool {
+        get_vd_manager().uses_cloak()
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    static MOCK_HOST: std::cell::RefCell<Option<std::rc::Rc<dyn ShellHost>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Runs `cb` with the host of the current thread, always the app outside of tests
+pub fn with_host<T>(cb: impl FnOnce(&dyn ShellHost) -> T) -> T {
+    #[cfg(test)]
+    if let Some(mock) = MOCK_HOST.with(|mock| mock.borrow().clone()) {
+        return cb(mock.as_ref());
+    }
+    cb(&AppHost)
+}
+
+/// Replaces the host of the current thread, `None` restores the app one
+#[cfg(test)]
+pub fn set_thread_host(host: Option<std::rc::Rc<dyn ShellHost>>) {
+    MOCK_HOST.with(|mock| *mock.borrow_mut() = host);
+}
+
+/// Emits `payload` to all the webviews
+pub fn emit_global<S: Serialize>(event: &str, payload: S) -> Result<()> {
+    let payload = serde_json::to_value(payload)?;
+    with_host(|host| host.emit(event, payload))
+}
+
+/// Webview of the dock or the window manager, as used by their event handlers
+pub trait Surface {
+    fn name(&self) -> &str;
+    fn handle(&self) -> Result<HWND>;
+    /// emits only to this webview
+    fn emit_self<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<()>;
+    fn close(&self) -> Result<()>;
+}
+
+impl Surface for WebviewWindow<Wry> {
+    fn name(&self) -> &str {
+        self.label()
+    }
+
+    fn handle(&self) -> Result<HWND> {
+        Ok(HWND(self.hwnd()?.0))
+    }
+
+    fn emit_self<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<()> {
+        self.emit_to(self.label(), event, payload)?;
+        Ok(())
+    }
+
+    fn close(&self) -> Result<()> {
+        self.destroy()?;
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 mod error_handler;
 mod exposed;
 mod hook;
+mod host;
 mod modules;
 mod monitor;
 mod plugins;
@@ -13,6 +14,8 @@ mod seelen_weg;
 mod seelen_wm;
 mod state;
 mod system;
+#[cfg(test)]
+mod testing;
 mod tray;
 mod utils;
 mod windows_api;
@@ -25,17 +28,26 @@ use error_handler::Result;
 use exposed::register_invoke_handler;
 use itertools::Itertools;
 use modules::{
+    cache::Cache,
     cli::{
         application::{attach_console, is_just_getting_info, SEELEN_COMMAND_LINE},
         Client,
     },
+    onboarding::Onboarding,
+    os_capabilities::domain::OsCapabilities,
+    portable::Portable,
+    remote_api::RemoteApi,
+    rendering::RenderingProbe,
+    shell::ShellMode,
+    shutdown::Shutdown,
     tray::application::ensure_tray_overflow_creation,
 };
 use plugins::register_plugins;
 use seelen::{Seelen, SEELEN};
 use seelen_core::state::Settings;
+use state::application::FullState;
 use tray::try_register_tray_icon;
-use utils::PERFORMANCE_HELPER;
+use utils::{measure, PERFORMANCE_HELPER};
 use windows::Win32::Security::{SE_DEBUG_NAME, SE_SHUTDOWN_NAME};
 use windows_api::WindowsApi;
 
@@ -67,6 +79,10 @@ fn register_panic_hook() {
             cause.cyan(),
             string_location.purple()
         );
+        // panics on other threads are handled by the watchdog or are not fatal
+        if std::thread::current().name() == Some("main") {
+            ShellMode::recover();
+        }
     }));
 }
 
@@ -74,8 +90,12 @@ fn setup(app: &mut tauri::App<tauri::Wry>) -> Result<(), Box<dyn std::error::Err
     let version = env!("CARGO_PKG_VERSION");
     log::info!("───────────────────── Starting Seelen UI v{version} ─────────────────────");
     log::info!("Operating System: {}", os_info::get());
+    log::info!("OS Capabilities: {:?}", OsCapabilities::get());
     log::info!("Locate: {:?}", Settings::get_locale());
     log::info!("Elevated: {:?}", WindowsApi::is_elevated());
+    if let Some(dir) = Portable::data_dir() {
+        log::info!("Portable mode, data folder: {:?}", dir);
+    }
     Client::listen_tcp()?;
 
     log_error!(WindowsApi::enable_privilege(SE_SHUTDOWN_NAME));
@@ -86,6 +106,22 @@ fn setup(app: &mut tauri::App<tauri::Wry>) -> Result<(), Box<dyn std::error::Err
 
     let mut seelen = unsafe { SEELEN.make_guard_unchecked() };
     seelen.init(app.handle().clone())?;
+    RenderingProbe::apply_fallback();
+
+    if Seelen::is_safe_mode() {
+        Seelen::show_settings()?;
+        log_error!(try_register_tray_icon(app));
+        std::mem::forget(seelen);
+        return Ok(());
+    }
+
+    if Onboarding::is_first_run() {
+        // the dock and the rest of the shell are started once the user accepts the defaults
+        Onboarding::begin()?;
+        log_error!(try_register_tray_icon(app));
+        std::mem::forget(seelen);
+        return Ok(());
+    }
 
     if !tauri::is_dev() {
         let command = trace_lock!(SEELEN_COMMAND_LINE).clone();
@@ -96,7 +132,14 @@ fn setup(app: &mut tauri::App<tauri::Wry>) -> Result<(), Box<dyn std::error::Err
         Seelen::show_update_modal()?;
     }
 
-    seelen.start()?;
+    if Seelen::is_minimal_mode() {
+        // the shell is started later from the tray or by disabling the setting
+        log_error!(try_register_tray_icon(app));
+        std::mem::forget(seelen);
+        return Ok(());
+    }
+
+    measure("start", || seelen.start())?;
 
     log_error!(try_register_tray_icon(app));
     std::mem::forget(seelen);
@@ -113,7 +156,8 @@ fn app_callback(_: &tauri::AppHandle<tauri::Wry>, event: tauri::RunEvent) {
         }
         tauri::RunEvent::Exit => {
             log::info!("───────────────────── Exiting Seelen ─────────────────────");
-            trace_lock!(SEELEN).stop()
+            Shutdown::run(false);
+            ShellMode::recover();
         }
         _ => {}
     }
@@ -143,7 +187,18 @@ fn main() -> Result<()> {
     sys.refresh_processes();
     let already_running = sys.processes_by_name("seelen-ui.exe").collect_vec().len() > 1;
 
+    // commands with an output that should be printed on this console
+    let expects_reply = matches!(
+        matches.subcommand_name(),
+        Some("status")
+            | Some(FullState::CLI_IDENTIFIER)
+            | Some(RemoteApi::CLI_IDENTIFIER)
+            | Some(Cache::CLI_IDENTIFIER)
+    );
     if already_running {
+        if expects_reply {
+            return Client::print_reply();
+        }
         if let Ok(stream) = Client::connect_tcp() {
             let mut writer = BufWriter::new(stream);
 
@@ -156,6 +211,21 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if expects_reply {
+        attach_console()?;
+        println!("Seelen UI is not running.");
+        return Ok(());
+    }
+
+    Portable::init(&matches)?;
+    ShellMode::init();
+    if matches.get_flag("safe-mode") {
+        Seelen::enable_safe_mode();
+    }
+    if matches.get_flag("minimal") {
+        Seelen::enable_minimal_mode();
+    }
+
     let mut app_builder = tauri::Builder::default();
     app_builder = register_plugins(app_builder);
     app_builder = register_invoke_handler(app_builder);
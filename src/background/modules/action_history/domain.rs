@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionSource {
+    /// invoked by a webview (widgets, themes, plugins)
+    Ipc,
+    /// sent by another process using the command line
+    Cli,
+    RemoteApi,
+    Automation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "message")]
+pub enum ActionOutcome {
+    Success,
+    Failed(String),
+    /// ipc commands are resolved asynchronously so only the dispatch is known
+    Dispatched,
+}
+
+impl<T, E: std::fmt::Debug> From<&Result<T, E>> for ActionOutcome {
+    fn from(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self::Success,
+            Err(err) => Self::Failed(format!("{:?}", err)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionRecord {
+    /// unix timestamp in milliseconds
+    pub time: u64,
+    pub source: ActionSource,
+    /// webview label, remote client address or automation rule
+    pub caller: String,
+    pub command: String,
+    pub args: Value,
+    pub outcome: ActionOutcome,
+}
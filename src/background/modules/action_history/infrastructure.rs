@@ -0,0 +1,6 @@
+use super::{domain::ActionRecord, ActionHistory};
+
+#[tauri::command(async)]
+pub fn get_action_history(limit: Option<usize>) -> Vec<ActionRecord> {
+    ActionHistory::get(limit.unwrap_or(200))
+}
@@ -0,0 +1,165 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::VecDeque,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde_json::Value;
+use tauri::{
+    ipc::{InvokeBody, InvokeMessage},
+    Wry,
+};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, trace_lock, utils::app_log_path,
+};
+
+use domain::{ActionOutcome, ActionRecord, ActionSource};
+
+lazy_static! {
+    static ref HISTORY: Arc<Mutex<VecDeque<ActionRecord>>> =
+        Arc::new(Mutex::new(ActionHistory::load().unwrap_or_default()));
+}
+
+/// max amount of records kept in memory
+const CAPACITY: usize = 1_000;
+/// the log is rotated to `actions.1.log` when it reaches this size
+const MAX_LOG_SIZE: u64 = 1024 * 1024;
+
+/// ipc commands that only read state or are sent continuously by the ui,
+/// recording them would bury the actions in noise.
+fn is_passive_command(command: &str) -> bool {
+    command.starts_with("get_")
+        || command.starts_with("is_")
+        || command.ends_with("_get")
+        || command.contains("_get_")
+        || command.contains("_is_")
+        || command.starts_with("weg_hitbox_")
+        || command.starts_with("weg_drag_")
+        || command.starts_with("weg_scrub_")
+        || command.starts_with("weg_tab_thumbnails")
+        || command.ends_with("_search")
+        || matches!(
+            command,
+            "weg_set_hitbox"
+                | "weg_request_update_previews"
+                | "ensure_hitboxes_zorder"
+                | "match_window_rules"
+                | "state_validate_config"
+                | "task_manager_list"
+                | "event_recorder_dump"
+        )
+}
+
+/// Rotating log of the commands executed through ipc, cli, remote api and automations,
+/// so users can see what was done to their windows and by whom.
+pub struct ActionHistory;
+
+impl ActionHistory {
+    fn log_path() -> Result<PathBuf> {
+        let dir = app_log_path(&get_app_handle())?;
+        std::fs::create_dir_all(&dir)?;
+        Ok(dir.join("actions.log"))
+    }
+
+    /// loads the records of previous sessions, oldest first
+    fn load() -> Result<VecDeque<ActionRecord>> {
+        let path = Self::log_path()?;
+        let mut records = VecDeque::with_capacity(CAPACITY);
+        for path in [path.with_extension("1.log"), path] {
+            let Ok(file) = File::open(&path) else {
+                continue;
+            };
+            for line in BufReader::new(file).lines().map_while(|line| line.ok()) {
+                if let Ok(record) = serde_json::from_str(&line) {
+                    if records.len() >= CAPACITY {
+                        records.pop_front();
+                    }
+                    records.push_back(record);
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    fn persist(record: &ActionRecord) -> Result<()> {
+        let path = Self::log_path()?;
+        if std::fs::metadata(&path).is_ok_and(|meta| meta.len() >= MAX_LOG_SIZE) {
+            std::fs::rename(&path, path.with_extension("1.log"))?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+
+    pub fn record(
+        source: ActionSource,
+        caller: impl Into<String>,
+        command: impl Into<String>,
+        args: Value,
+        outcome: ActionOutcome,
+    ) {
+        let record = ActionRecord {
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            source,
+            caller: caller.into(),
+            command: command.into(),
+            args,
+            outcome,
+        };
+        log::trace!(target: "slu::actions", "{:?}", record);
+
+        let mut history = trace_lock!(HISTORY);
+        log_error!(Self::persist(&record));
+        if history.len() >= CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(record);
+    }
+
+    pub fn record_invoke(message: &InvokeMessage<Wry>) {
+        let command = message.command();
+        if is_passive_command(command) {
+            return;
+        }
+        let args = match message.payload() {
+            InvokeBody::Json(value) => value.clone(),
+            InvokeBody::Raw(bytes) => Value::String(format!("<{} bytes>", bytes.len())),
+        };
+        Self::record(
+            ActionSource::Ipc,
+            message.webview().label(),
+            command,
+            args,
+            ActionOutcome::Dispatched,
+        );
+    }
+
+    /// argv as received by the cli, including the executable
+    pub fn record_argv(source: ActionSource, caller: &str, argv: &[String], result: &Result<()>) {
+        let command = argv.get(1).cloned().unwrap_or_default();
+        let args = argv.iter().skip(2).cloned().collect::<Vec<_>>();
+        Self::record(source, caller, command, Value::from(args), result.into());
+    }
+
+    /// newest first
+    pub fn get(limit: usize) -> Vec<ActionRecord> {
+        trace_lock!(HISTORY)
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,25 @@
+use seelen_core::state::AlertKind;
+use serde::Serialize;
+
+/// Current value checked against the thresholds
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub kind: AlertKind,
+    /// `battery` or the drive letter, like `C:`
+    pub target: String,
+    pub percent: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alert {
+    /// `<threshold index>:<target>`, used to snooze or acknowledge it
+    pub id: String,
+    pub kind: AlertKind,
+    pub target: String,
+    /// battery charge or free space as of the last check
+    pub percent: f32,
+    pub threshold: u8,
+    /// acknowledged alerts are not raised again until the condition is gone
+    pub acknowledged: bool,
+}
@@ -0,0 +1,18 @@
+use crate::error_handler::Result;
+
+use super::{domain::Alert, AlertWatcher};
+
+#[tauri::command(async)]
+pub fn alerts_get_active() -> Vec<Alert> {
+    AlertWatcher::active()
+}
+
+#[tauri::command(async)]
+pub fn alerts_snooze(id: String) -> Result<()> {
+    AlertWatcher::snooze(&id)
+}
+
+#[tauri::command(async)]
+pub fn alerts_acknowledge(id: String) -> Result<()> {
+    AlertWatcher::acknowledge(&id)
+}
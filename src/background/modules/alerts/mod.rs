@@ -0,0 +1,279 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use domain::{Alert, Reading};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{AlertKind, AlertThreshold};
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::storage::StorageMonitor,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::{Toast, WindowsApi},
+};
+
+struct Tracked {
+    alert: Alert,
+    snooze: Duration,
+    /// when the alert is raised again, none once acknowledged
+    next: Option<Instant>,
+}
+
+lazy_static! {
+    /// alerts whose condition is still true, by id
+    static ref ALERTS: Mutex<HashMap<String, Tracked>> = Mutex::new(HashMap::new());
+}
+
+/// true while the watcher thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+fn same_drive(a: &str, b: &str) -> bool {
+    let letter = |drive: &str| drive.trim_end_matches(['\\', ':']).to_ascii_uppercase();
+    letter(a) == letter(b)
+}
+
+/// Index of the lowest enabled threshold crossed by the reading
+fn crossed(thresholds: &[AlertThreshold], reading: &Reading) -> Option<usize> {
+    thresholds
+        .iter()
+        .enumerate()
+        .filter(|(_, threshold)| {
+            threshold.enabled
+                && threshold.kind == reading.kind
+                && reading.percent <= threshold.percent as f32
+                && threshold
+                    .drive
+                    .as_ref()
+                    .map_or(true, |drive| same_drive(drive, &reading.target))
+        })
+        .min_by_key(|(_, threshold)| threshold.percent)
+        .map(|(idx, _)| idx)
+}
+
+/// Raises a toast with snooze and acknowledge buttons when the battery charge or the free
+/// space of a drive goes under one of the thresholds of `settings.alerts`. Alerts not
+/// acknowledged are raised again after the snooze time while their condition is true.
+pub struct AlertWatcher;
+
+impl AlertWatcher {
+    const INTERVAL: u64 = 60_000;
+
+    pub fn active() -> Vec<Alert> {
+        trace_lock!(ALERTS)
+            .values()
+            .map(|tracked| tracked.alert.clone())
+            .collect()
+    }
+
+    fn emit() -> Result<()> {
+        get_app_handle().emit("alerts-changed", Self::active())?;
+        Ok(())
+    }
+
+    fn readings() -> Vec<Reading> {
+        let mut readings = Vec::new();
+        if let Ok(power) = WindowsApi::get_system_power_status() {
+            // the percent is 255 if unknown and the flag 128 means there is no battery
+            let has_battery = power.BatteryFlag & 128 == 0 && power.BatteryLifePercent <= 100;
+            if has_battery && power.ACLineStatus == 0 {
+                readings.push(Reading {
+                    kind: AlertKind::Battery,
+                    target: "battery".to_string(),
+                    percent: power.BatteryLifePercent as f32,
+                });
+            }
+        }
+        for drive in StorageMonitor::drives() {
+            if drive.removable || drive.total == 0 {
+                continue;
+            }
+            readings.push(Reading {
+                kind: AlertKind::DiskFree,
+                percent: drive.free as f32 * 100.0 / drive.total as f32,
+                target: drive.letter,
+            });
+        }
+        readings
+    }
+
+    fn notify(alert: &Alert) -> Result<()> {
+        let (title, message) = match alert.kind {
+            AlertKind::Battery => (
+                "Low battery",
+                format!("{:.0}% of battery remaining", alert.percent),
+            ),
+            AlertKind::DiskFree => (
+                "Low disk space",
+                format!("{:.0}% of {} is free", alert.percent, alert.target),
+            ),
+        };
+        let snooze = format!("snooze:{}", alert.id);
+        let acknowledge = format!("acknowledge:{}", alert.id);
+        Toast::show_with_actions(
+            title,
+            &message,
+            &[
+                ("Snooze", snooze.as_str()),
+                ("Acknowledge", acknowledge.as_str()),
+            ],
+            |argument| match argument.split_once(':') {
+                Some(("snooze", id)) => log_error!(Self::snooze(id)),
+                Some(("acknowledge", id)) => log_error!(Self::acknowledge(id)),
+                _ => {}
+            },
+        )
+    }
+
+    fn tick() -> Result<()> {
+        let state = FULL_STATE.load();
+        let thresholds = &state.settings().alerts.thresholds;
+        let mut current = HashMap::new();
+        for reading in Self::readings() {
+            if let Some(idx) = crossed(thresholds, &reading) {
+                current.insert(format!("{}:{}", idx, reading.target), (idx, reading));
+            }
+        }
+
+        let now = Instant::now();
+        let mut raised = Vec::new();
+        let mut alerts = trace_lock!(ALERTS);
+        let previous = alerts.len();
+        // alerts are re-armed once their condition is gone
+        alerts.retain(|id, _| current.contains_key(id));
+        let mut changed = alerts.len() != previous;
+
+        for (id, (idx, reading)) in current {
+            let threshold = &thresholds[idx];
+            let snooze = Duration::from_secs(threshold.snooze_minutes * 60);
+            if let Some(tracked) = alerts.get_mut(&id) {
+                tracked.alert.percent = reading.percent;
+                if tracked.next.is_some_and(|next| next <= now) {
+                    tracked.next = Some(now + tracked.snooze);
+                    raised.push(tracked.alert.clone());
+                }
+                continue;
+            }
+            let alert = Alert {
+                id: id.clone(),
+                kind: reading.kind,
+                target: reading.target,
+                percent: reading.percent,
+                threshold: threshold.percent,
+                acknowledged: false,
+            };
+            raised.push(alert.clone());
+            alerts.insert(
+                id,
+                Tracked {
+                    alert,
+                    snooze,
+                    next: Some(now + snooze),
+                },
+            );
+            changed = true;
+        }
+        drop(alerts);
+
+        for alert in &raised {
+            log_error!(Self::notify(alert));
+        }
+        if changed {
+            Self::emit()?;
+        }
+        Ok(())
+    }
+
+    /// Raises the alert again after its snooze time if the condition is still true
+    pub fn snooze(id: &str) -> Result<()> {
+        let mut alerts = trace_lock!(ALERTS);
+        let tracked = alerts.get_mut(id).ok_or("Alert not found")?;
+        tracked.next = Some(Instant::now() + tracked.snooze);
+        Ok(())
+    }
+
+    /// The alert is not raised again until its condition is gone
+    pub fn acknowledge(id: &str) -> Result<()> {
+        {
+            let mut alerts = trace_lock!(ALERTS);
+            let tracked = alerts.get_mut(id).ok_or("Alert not found")?;
+            tracked.next = None;
+            tracked.alert.acknowledged = true;
+        }
+        Self::emit()
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting alert watcher");
+        spawn_named_thread("Alert Watcher", || {
+            while RUNNING.load(Ordering::Acquire) {
+                log_error!(Self::tick());
+                sleep_millis(Self::INTERVAL);
+            }
+            trace_lock!(ALERTS).clear();
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn threshold(kind: AlertKind, percent: u8, drive: Option<&str>) -> AlertThreshold {
+        AlertThreshold {
+            kind,
+            percent,
+            drive: drive.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn reading(kind: AlertKind, target: &str, percent: f32) -> Reading {
+        Reading {
+            kind,
+            target: target.to_string(),
+            percent,
+        }
+    }
+
+    #[test]
+    fn lowest_crossed_threshold_wins() {
+        let thresholds = vec![
+            threshold(AlertKind::Battery, 20, None),
+            threshold(AlertKind::Battery, 10, None),
+            threshold(AlertKind::DiskFree, 15, Some(r"D:\")),
+        ];
+        let battery = |percent| {
+            crossed(
+                &thresholds,
+                &reading(AlertKind::Battery, "battery", percent),
+            )
+        };
+        assert_eq!(battery(50.0), None);
+        assert_eq!(battery(20.0), Some(0));
+        assert_eq!(battery(8.0), Some(1));
+
+        let disk = |letter| crossed(&thresholds, &reading(AlertKind::DiskFree, letter, 5.0));
+        assert_eq!(disk("D:"), Some(2));
+        assert_eq!(disk("C:"), None);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Foreground time of an executable, the days are local dates as `YYYY-MM-DD`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AppUsage {
+    pub name: String,
+    /// seconds in the foreground by day
+    pub days: BTreeMap<String, u64>,
+    /// unix timestamps in seconds
+    pub first_used: u64,
+    pub last_used: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppUsageSummary {
+    pub exe: String,
+    pub name: String,
+    /// seconds in the foreground on the queried days
+    pub seconds: u64,
+    pub first_used: u64,
+    pub last_used: u64,
+}
+
+impl AppUsage {
+    /// Usage older than this weighs half on the launcher ranking
+    const HALF_LIFE_DAYS: f64 = 7.0;
+
+    pub fn add(&mut self, day: &str, seconds: u64, now: u64) {
+        *self.days.entry(day.to_string()).or_default() += seconds;
+        if self.first_used == 0 {
+            self.first_used = now;
+        }
+        self.last_used = now;
+    }
+
+    /// Seconds between both days, inclusive. ISO dates sort as strings.
+    pub fn seconds_between(&self, from: &str, to: &str) -> u64 {
+        self.days
+            .range(from.to_string()..=to.to_string())
+            .map(|(_, seconds)| seconds)
+            .sum()
+    }
+
+    /// Minutes in the foreground weighted by recency, `today` as returned by `day_number`
+    pub fn frecency(&self, today: i64) -> f64 {
+        self.days
+            .iter()
+            .filter_map(|(day, seconds)| {
+                let age = (today - day_number(day)?).max(0) as f64;
+                Some(*seconds as f64 / 60.0 * 0.5f64.powf(age / Self::HALF_LIFE_DAYS))
+            })
+            .sum()
+    }
+
+    /// Removes the days before `oldest`, returns true if the app has no usage left
+    pub fn prune(&mut self, oldest: i64) -> bool {
+        self.days
+            .retain(|day, _| day_number(day).is_some_and(|day| day >= oldest));
+        self.days.is_empty()
+    }
+}
+
+pub fn format_day(year: u16, month: u16, day: u16) -> String {
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Days since 1970-01-01 of a `YYYY-MM-DD` date
+pub fn day_number(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // proleptic gregorian calendar with the years starting in March, so the leap day is last
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146097 + day_of_era - 719468)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_numbers() {
+        assert_eq!(day_number("1970-01-01"), Some(0));
+        assert_eq!(day_number("2000-03-01"), Some(11017));
+        assert_eq!(day_number("2024-12-31"), Some(20088));
+        assert_eq!(day_number("2024-13-01"), None);
+        assert_eq!(day_number("today"), None);
+    }
+
+    #[test]
+    fn recent_usage_weighs_more() {
+        let mut usage = AppUsage::default();
+        usage.add("2024-01-08", 3600, 1);
+        usage.add("2024-01-01", 3600, 2);
+        let today = day_number("2024-01-08").unwrap();
+        assert_eq!(usage.frecency(today), 90.0);
+        assert_eq!(usage.first_used, 1);
+        assert_eq!(usage.last_used, 2);
+        assert_eq!(usage.seconds_between("2024-01-02", "2024-01-08"), 3600);
+
+        assert!(!usage.prune(today));
+        assert_eq!(usage.days.len(), 1);
+        assert!(usage.prune(today + 1));
+    }
+}
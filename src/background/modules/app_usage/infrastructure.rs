@@ -0,0 +1,13 @@
+use crate::error_handler::Result;
+
+use super::{domain::AppUsageSummary, AppUsageTracker};
+
+#[tauri::command(async)]
+pub fn app_usage_get(from: String, to: String) -> Vec<AppUsageSummary> {
+    AppUsageTracker::query(&from, &to)
+}
+
+#[tauri::command(async)]
+pub fn app_usage_clear() -> Result<()> {
+    AppUsageTracker::clear()
+}
@@ -0,0 +1,223 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use domain::{day_number, format_day, AppUsage, AppUsageSummary};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::{Foundation::HWND, System::SystemInformation::GetLocalTime};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::idle::IdleManager,
+    seelen::get_app_handle,
+    seelen_weg::{app_names::AppNames, SeelenWeg},
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{app_data_path, sleep_millis, spawn_named_thread},
+    windows_api::{window::Window, WindowsApi},
+};
+
+lazy_static! {
+    static ref APP_USAGE: Mutex<AppUsageTracker> = Mutex::new(AppUsageTracker::load());
+}
+
+/// true while the flush thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Opt-in record of the time each app spends in the foreground, kept by day on this device.
+/// It feeds the launcher ranking and the screen time page of the settings.
+pub struct AppUsageTracker {
+    /// keyed by exe path
+    apps: HashMap<String, AppUsage>,
+    /// foreground app and since when its time is not yet counted
+    current: Option<(String, Instant)>,
+    dirty: bool,
+}
+
+impl AppUsageTracker {
+    const FILENAME: &'static str = "app_usage.json";
+    /// seconds without input after which the foreground app is no longer counted
+    const IDLE_THRESHOLD: u64 = 300;
+    const FLUSH_INTERVAL: u64 = 60_000;
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn load() -> Self {
+        let apps = std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            apps,
+            current: None,
+            dirty: false,
+        }
+    }
+
+    fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let retention = FULL_STATE.load().settings().app_usage.retention_days as i64;
+        if let Some(today) = day_number(&today()) {
+            self.apps.retain(|_, usage| !usage.prune(today - retention));
+        }
+        std::fs::write(Self::path(), serde_json::to_string(&self.apps)?)?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Adds the pending time of the foreground app to today. When the user is idle, the time
+    /// without input is not counted.
+    fn flush(&mut self) {
+        let Some((exe, since)) = self.current.as_mut() else {
+            return;
+        };
+        let elapsed = since.elapsed().as_secs();
+        let idle = IdleManager::idle_seconds().unwrap_or(0);
+        let active = if idle >= Self::IDLE_THRESHOLD {
+            *since = Instant::now();
+            elapsed.saturating_sub(idle)
+        } else {
+            // the fraction of second left is counted on the next flush
+            *since += Duration::from_secs(elapsed);
+            elapsed
+        };
+        if active == 0 {
+            return;
+        }
+
+        let usage = self.apps.entry(exe.clone()).or_insert_with(|| AppUsage {
+            name: AppNames::get(exe),
+            ..Default::default()
+        });
+        usage.add(&today(), active, now());
+        self.dirty = true;
+    }
+
+    pub fn on_foreground(hwnd: HWND) {
+        if !FULL_STATE.load().is_app_usage_enabled() {
+            return;
+        }
+        let window = Window::from(hwnd);
+        // seelen windows, the desktop and other shell surfaces are not counted
+        let exe = match !window.is_seelen_window() && SeelenWeg::should_be_added(hwnd) {
+            true => window.exe().ok(),
+            false => None,
+        };
+
+        let mut tracker = trace_lock!(APP_USAGE);
+        tracker.flush();
+        tracker.current = exe.map(|exe| (exe.to_string_lossy().to_string(), Instant::now()));
+    }
+
+    /// Usage between both days (`YYYY-MM-DD`, inclusive), most used first
+    pub fn query(from: &str, to: &str) -> Vec<AppUsageSummary> {
+        let mut tracker = trace_lock!(APP_USAGE);
+        tracker.flush();
+        let mut summaries: Vec<AppUsageSummary> = tracker
+            .apps
+            .iter()
+            .filter_map(|(exe, usage)| {
+                let seconds = usage.seconds_between(from, to);
+                (seconds > 0).then(|| AppUsageSummary {
+                    exe: exe.clone(),
+                    name: usage.name.clone(),
+                    seconds,
+                    first_used: usage.first_used,
+                    last_used: usage.last_used,
+                })
+            })
+            .collect();
+        summaries.sort_by(|a, b| b.seconds.cmp(&a.seconds));
+        summaries
+    }
+
+    /// Score bonus for the launcher keyed by lowercased app name and exe filename without
+    /// extension, empty if the tracking is disabled
+    pub fn ranking() -> HashMap<String, u32> {
+        let mut ranking = HashMap::new();
+        let today = day_number(&today());
+        let (true, Some(today)) = (FULL_STATE.load().is_app_usage_enabled(), today) else {
+            return ranking;
+        };
+        for (exe, usage) in trace_lock!(APP_USAGE).apps.iter() {
+            // an hour today is worth about the same as a good match of the name
+            let bonus = ((usage.frecency(today).ln_1p() * 25.0) as u32).min(150);
+            if bonus == 0 {
+                continue;
+            }
+            let stem = Path::new(exe)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_lowercase());
+            for key in [Some(usage.name.to_lowercase()), stem]
+                .into_iter()
+                .flatten()
+            {
+                let entry = ranking.entry(key).or_insert(0);
+                *entry = bonus.max(*entry);
+            }
+        }
+        ranking
+    }
+
+    pub fn clear() -> Result<()> {
+        let mut tracker = trace_lock!(APP_USAGE);
+        tracker.apps.clear();
+        let path = Self::path();
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        tracker.dirty = false;
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        // the app focused before enabling the tracking is counted from now
+        Self::on_foreground(WindowsApi::get_foreground_window());
+        spawn_named_thread("App Usage Tracker", || {
+            while RUNNING.load(Ordering::Acquire) {
+                sleep_millis(Self::FLUSH_INTERVAL);
+                let mut tracker = trace_lock!(APP_USAGE);
+                tracker.flush();
+                log_error!(tracker.save());
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        if !RUNNING.swap(false, Ordering::AcqRel) {
+            return;
+        }
+        let mut tracker = trace_lock!(APP_USAGE);
+        tracker.flush();
+        tracker.current = None;
+        log_error!(tracker.save());
+    }
+}
+
+/// Local date as `YYYY-MM-DD`
+fn today() -> String {
+    let time = unsafe { GetLocalTime() };
+    format_day(time.wYear, time.wMonth, time.wDay)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+use crate::error_handler::Result;
+
+use super::{Association, AssociationsManager, OpenWithCandidate};
+
+#[tauri::command(async)]
+pub fn associations_get_default(target: String) -> Result<Association> {
+    AssociationsManager::get_default(&target)
+}
+
+#[tauri::command(async)]
+pub fn associations_change_default(target: String) -> Result<()> {
+    AssociationsManager::change_default(&target)
+}
+
+#[tauri::command(async)]
+pub fn associations_open_settings(target: String) -> Result<()> {
+    AssociationsManager::open_settings(&target)
+}
+
+#[tauri::command(async)]
+pub fn get_open_with_candidates(path: PathBuf) -> Result<Vec<OpenWithCandidate>> {
+    AssociationsManager::get_open_with_candidates(&path)
+}
+
+#[tauri::command(async)]
+pub fn open_with(path: PathBuf, handler: String) -> Result<()> {
+    AssociationsManager::open_with(&path, &handler)
+}
@@ -0,0 +1,214 @@
+pub mod infrastructure;
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use windows::{
+    core::{HSTRING, PCWSTR, PWSTR},
+    Win32::{
+        Foundation::HWND,
+        System::Com::{CoTaskMemFree, IDataObject},
+        UI::Shell::{
+            ApplicationAssociationRegistration, AssocQueryStringW, BHID_DataObject,
+            IApplicationAssociationRegistration, IAssocHandler, IShellItem, SHAssocEnumHandlers,
+            SHCreateItemFromParsingName, SHOpenWithDialog, AL_EFFECTIVE, ASSOCF_NONE, ASSOCSTR,
+            ASSOCSTR_EXECUTABLE, ASSOCSTR_FRIENDLYAPPNAME, ASSOC_FILTER_NONE, AT_FILEEXTENSION,
+            AT_URLPROTOCOL, OAIF_ALLOW_REGISTRATION, OAIF_FORCE_REGISTRATION, OAIF_REGISTER_EXT,
+            OPENASINFO,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result, seelen::get_app_handle,
+    seelen_weg::icon_extractor::extract_and_save_icon, windows_api::Com,
+};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Association {
+    /// file extension (with dot) or url protocol (without colon)
+    target: String,
+    prog_id: String,
+    app_name: Option<String>,
+    executable: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenWithCandidate {
+    /// path of the handler executable, used to identify it when opening
+    name: String,
+    ui_name: String,
+    icon: Option<PathBuf>,
+    /// recommended handlers are the ones registered for the extension or recently used with it
+    recommended: bool,
+}
+
+/// Effective file and protocol associations, the ones that Windows will use after
+/// applying the user choice, policies and machine defaults.
+pub struct AssociationsManager {}
+
+impl AssociationsManager {
+    fn is_extension(target: &str) -> bool {
+        target.starts_with('.')
+    }
+
+    fn query_string(kind: ASSOCSTR, target: &str) -> Option<String> {
+        let target = HSTRING::from(target);
+        let mut len = 0;
+        unsafe {
+            let _ = AssocQueryStringW(
+                ASSOCF_NONE,
+                kind,
+                &target,
+                PCWSTR::null(),
+                PWSTR::null(),
+                &mut len,
+            );
+        }
+        if len == 0 {
+            return None;
+        }
+        let mut buffer = vec![0u16; len as usize];
+        unsafe {
+            AssocQueryStringW(
+                ASSOCF_NONE,
+                kind,
+                &target,
+                PCWSTR::null(),
+                PWSTR(buffer.as_mut_ptr()),
+                &mut len,
+            )
+            .ok()
+            .ok()?;
+        }
+        String::from_utf16(&buffer[..(len as usize).saturating_sub(1)]).ok()
+    }
+
+    pub fn get_default(target: &str) -> Result<Association> {
+        let prog_id = Com::run_with_context(|| {
+            let registration: IApplicationAssociationRegistration =
+                Com::create_instance(&ApplicationAssociationRegistration)?;
+            let kind = if Self::is_extension(target) {
+                AT_FILEEXTENSION
+            } else {
+                AT_URLPROTOCOL
+            };
+            unsafe {
+                let value =
+                    registration.QueryCurrentDefault(&HSTRING::from(target), kind, AL_EFFECTIVE)?;
+                let prog_id = value.to_string();
+                CoTaskMemFree(Some(value.0 as _));
+                Ok(prog_id?)
+            }
+        })?;
+
+        Ok(Association {
+            target: target.to_string(),
+            prog_id,
+            app_name: Self::query_string(ASSOCSTR_FRIENDLYAPPNAME, target),
+            executable: Self::query_string(ASSOCSTR_EXECUTABLE, target),
+        })
+    }
+
+    /// Since Windows 8 the user choice is protected and can not be written by apps, so
+    /// extensions use the native "open with" dialog that is allowed to change it and
+    /// protocols are sent to the Settings page where the user can change them.
+    pub fn change_default(target: &str) -> Result<()> {
+        if !Self::is_extension(target) {
+            return Self::open_settings(target);
+        }
+
+        // the dialog only needs a file name to read the extension from
+        let file = HSTRING::from(format!("file{}", target));
+        let info = OPENASINFO {
+            pcszFile: PCWSTR(file.as_ptr()),
+            pcszClass: PCWSTR::null(),
+            oaifInFlags: OAIF_ALLOW_REGISTRATION | OAIF_REGISTER_EXT | OAIF_FORCE_REGISTRATION,
+        };
+        unsafe { SHOpenWithDialog(HWND(0), &info)? };
+        Ok(())
+    }
+
+    pub fn open_settings(target: &str) -> Result<()> {
+        let uri = if Self::is_extension(target) {
+            "ms-settings:defaultapps".to_string()
+        } else {
+            format!("ms-settings:defaultapps?registeredProtocol={}", target)
+        };
+        std::process::Command::new("explorer").arg(uri).spawn()?;
+        Ok(())
+    }
+
+    fn handlers(path: &Path) -> Result<Vec<IAssocHandler>> {
+        let extension = path
+            .extension()
+            .map(|ext| format!(".{}", ext.to_string_lossy()))
+            .ok_or("File has no extension")?;
+
+        let handlers =
+            unsafe { SHAssocEnumHandlers(&HSTRING::from(extension), ASSOC_FILTER_NONE)? };
+        let mut result = Vec::new();
+        loop {
+            let mut batch = [None];
+            let mut fetched = 0;
+            unsafe { handlers.Next(&mut batch, Some(&mut fetched)).ok()? };
+            match batch[0].take() {
+                Some(handler) if fetched > 0 => result.push(handler),
+                _ => break,
+            }
+        }
+        Ok(result)
+    }
+
+    /// Same list shown by the Explorer "Open with" submenu, recommended handlers first
+    pub fn get_open_with_candidates(path: &Path) -> Result<Vec<OpenWithCandidate>> {
+        Com::run_with_context(|| {
+            let mut candidates = Vec::new();
+            for handler in Self::handlers(path)? {
+                let (name, ui_name) = unsafe {
+                    let name = handler.GetName()?;
+                    let ui_name = handler.GetUIName()?;
+                    let result = (name.to_string(), ui_name.to_string());
+                    CoTaskMemFree(Some(name.0 as _));
+                    CoTaskMemFree(Some(ui_name.0 as _));
+                    result
+                };
+                let name = name?;
+                candidates.push(OpenWithCandidate {
+                    icon: extract_and_save_icon(&get_app_handle(), &name).ok(),
+                    name,
+                    ui_name: ui_name?,
+                    recommended: unsafe { handler.IsRecommended() }.is_ok(),
+                });
+            }
+            candidates.sort_by_key(|candidate| !candidate.recommended);
+            Ok(candidates)
+        })
+    }
+
+    pub fn open_with(path: &Path, handler_name: &str) -> Result<()> {
+        Com::run_with_context(|| {
+            for handler in Self::handlers(path)? {
+                let name = unsafe {
+                    let name = handler.GetName()?;
+                    let result = name.to_string();
+                    CoTaskMemFree(Some(name.0 as _));
+                    result
+                }?;
+
+                if name.eq_ignore_ascii_case(handler_name) {
+                    unsafe {
+                        let item: IShellItem =
+                            SHCreateItemFromParsingName(&HSTRING::from(path.as_os_str()), None)?;
+                        let data: IDataObject = item.BindToHandler(None, &BHID_DataObject)?;
+                        handler.Invoke(&data)?;
+                    }
+                    return Ok(());
+                }
+            }
+            Err(format!("No handler {} for {}", handler_name, path.display()).into())
+        })
+    }
+}
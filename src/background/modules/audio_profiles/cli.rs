@@ -0,0 +1,32 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::AudioProfiles;
+
+get_subcommands![
+    /** Applies the audio profile with the given name */
+    Apply(name: String => "Name of the profile."),
+    /** Applies the profile after the last applied one */
+    Next,
+];
+
+impl AudioProfiles {
+    pub const CLI_IDENTIFIER: &'static str = "audio-profile";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Switches between the audio profiles")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Apply(name) => Self::apply(&name, true)?,
+            SubCommand::Next => Self::next()?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::error_handler::Result;
+
+use super::{domain::AudioDevices, AudioProfiles};
+
+#[tauri::command(async)]
+pub fn audio_profiles_apply(name: String) -> Result<()> {
+    AudioProfiles::apply(&name, false)
+}
+
+#[tauri::command(async)]
+pub fn audio_profiles_get_active() -> Option<String> {
+    AudioProfiles::active()
+}
+
+#[tauri::command(async)]
+pub fn audio_profiles_get_devices() -> AudioDevices {
+    AudioProfiles::devices()
+}
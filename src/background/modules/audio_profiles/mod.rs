@@ -0,0 +1,222 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::time::{Duration, Instant};
+
+use domain::{AudioDeviceInfo, AudioDevices};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{AudioProfile, AudioProfileRule, AudioProfileTrigger};
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::media::{
+        application::MEDIA_MANAGER,
+        domain::{Device, SEELEN_AUDIO_CONTEXT},
+    },
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::Toast,
+};
+
+lazy_static! {
+    /// name of the last applied profile, `next` continues from it
+    static ref ACTIVE: Mutex<Option<String>> = Mutex::new(None);
+    /// last rule triggered, windows reports the same arrival more than once
+    static ref LAST_TRIGGER: Mutex<Option<(String, AudioProfileTrigger, Instant)>> =
+        Mutex::new(None);
+}
+
+/// First rule of the list matching the device, `device` is an audio device name or an usb id
+fn rule_for<'a>(
+    rules: &'a [AudioProfileRule],
+    device: &str,
+    trigger: AudioProfileTrigger,
+) -> Option<&'a AudioProfileRule> {
+    let device = device.to_lowercase();
+    rules.iter().find(|rule| {
+        rule.trigger == trigger
+            && !rule.device.is_empty()
+            && device.contains(&rule.device.to_lowercase())
+    })
+}
+
+/// Named combinations of default input/output devices and volumes, applied from the settings,
+/// the `audio-profile` cli (used by the shortcut) or automatically when a device matching one
+/// of the rules is connected or disconnected.
+pub struct AudioProfiles;
+
+impl AudioProfiles {
+    const DEBOUNCE: Duration = Duration::from_secs(3);
+    /// a new endpoint can't be set as default right after its arrival
+    const ARRIVAL_DELAY: u64 = 1000;
+
+    fn profile(name: &str) -> Result<AudioProfile> {
+        FULL_STATE
+            .load()
+            .settings()
+            .audio_profiles
+            .profiles
+            .iter()
+            .find(|profile| profile.name == name)
+            .cloned()
+            .ok_or_else(|| format!("Audio profile not found: {name}").into())
+    }
+
+    /// Sets the devices of the profile as default for all the roles and then its volumes.
+    /// `notify` shows a toast, used when the profile is not applied from the settings.
+    pub fn apply(name: &str, notify: bool) -> Result<()> {
+        let profile = Self::profile(name)?;
+        {
+            let mut manager = trace_lock!(MEDIA_MANAGER);
+            for id in [&profile.output, &profile.input].into_iter().flatten() {
+                for role in ["console", "multimedia", "communications"] {
+                    manager.set_default_device(id, role)?;
+                }
+            }
+
+            let default_of = |devices: &[Device]| {
+                devices
+                    .iter()
+                    .find(|device| device.is_default_multimedia)
+                    .map(|device| device.id.clone())
+            };
+            let output = profile
+                .output
+                .clone()
+                .or_else(|| default_of(manager.outputs()));
+            let input = profile
+                .input
+                .clone()
+                .or_else(|| default_of(manager.inputs()));
+
+            let endpoints = manager.devices_audio_endpoint();
+            for (id, volume) in [(output, profile.volume), (input, profile.input_volume)] {
+                let (Some(id), Some(volume)) = (id, volume) else {
+                    continue;
+                };
+                if let Some((endpoint, _)) = endpoints.get(&id) {
+                    let volume = volume.clamp(0.0, 1.0);
+                    unsafe { endpoint.SetMasterVolumeLevelScalar(volume, &SEELEN_AUDIO_CONTEXT)? };
+                }
+            }
+        }
+
+        *trace_lock!(ACTIVE) = Some(profile.name.clone());
+        get_app_handle().emit("audio-profile-changed", &profile.name)?;
+        if notify {
+            log_error!(Toast::show_titled("Audio profile", &profile.name));
+        }
+        Ok(())
+    }
+
+    /// Applies the profile after the last applied one, or the first of the list
+    pub fn next() -> Result<()> {
+        let state = FULL_STATE.load();
+        let profiles = &state.settings().audio_profiles.profiles;
+        if profiles.is_empty() {
+            return Err("There are no audio profiles".into());
+        }
+        let active = trace_lock!(ACTIVE).clone();
+        let next = active
+            .and_then(|name| profiles.iter().position(|profile| profile.name == name))
+            .map_or(0, |idx| (idx + 1) % profiles.len());
+        Self::apply(&profiles[next].name, true)
+    }
+
+    pub fn active() -> Option<String> {
+        trace_lock!(ACTIVE).clone()
+    }
+
+    /// Called by the media manager with the name of the audio endpoints and by the device
+    /// watcher with the id of the usb devices. This can be called while the media manager is
+    /// locked, so the profile is applied on another thread.
+    pub fn on_device_change(device: &str, connected: bool) {
+        let trigger = match connected {
+            true => AudioProfileTrigger::Connected,
+            false => AudioProfileTrigger::Disconnected,
+        };
+        let state = FULL_STATE.load();
+        let Some(rule) = rule_for(&state.settings().audio_profiles.rules, device, trigger) else {
+            return;
+        };
+
+        {
+            let mut last = trace_lock!(LAST_TRIGGER);
+            if let Some((device, last_trigger, at)) = last.as_ref() {
+                let repeated = *device == rule.device && *last_trigger == trigger;
+                if repeated && at.elapsed() < Self::DEBOUNCE {
+                    return;
+                }
+            }
+            *last = Some((rule.device.clone(), trigger, Instant::now()));
+        }
+
+        log::trace!("Audio profile rule matched: {} -> {}", device, rule.profile);
+        let profile = rule.profile.clone();
+        log_error!(spawn_named_thread("Audio Profile", move || {
+            if connected {
+                sleep_millis(Self::ARRIVAL_DELAY);
+            }
+            log_error!(Self::apply(&profile, true));
+        }));
+    }
+
+    pub fn devices() -> AudioDevices {
+        let manager = trace_lock!(MEDIA_MANAGER);
+        let info = |devices: &[Device]| {
+            devices
+                .iter()
+                .map(|device| AudioDeviceInfo {
+                    id: device.id.clone(),
+                    name: device.name.clone(),
+                })
+                .collect()
+        };
+        AudioDevices {
+            inputs: info(manager.inputs()),
+            outputs: info(manager.outputs()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(device: &str, trigger: AudioProfileTrigger, profile: &str) -> AudioProfileRule {
+        AudioProfileRule {
+            device: device.to_string(),
+            trigger,
+            profile: profile.to_string(),
+        }
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules = vec![
+            rule("", AudioProfileTrigger::Connected, "empty"),
+            rule("headphones", AudioProfileTrigger::Disconnected, "speakers"),
+            rule("Headphones", AudioProfileTrigger::Connected, "music"),
+            rule("headphones", AudioProfileTrigger::Connected, "calls"),
+        ];
+        let matched = |device, trigger| rule_for(&rules, device, trigger).map(|r| &*r.profile);
+        assert_eq!(
+            matched("Headphones (WH-1000XM4)", AudioProfileTrigger::Connected),
+            Some("music")
+        );
+        assert_eq!(
+            matched("headphones (WH-1000XM4)", AudioProfileTrigger::Disconnected),
+            Some("speakers")
+        );
+        assert_eq!(
+            matched("Speakers (Realtek)", AudioProfileTrigger::Connected),
+            None
+        );
+    }
+}
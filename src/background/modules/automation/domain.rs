@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use crate::{error_handler::AppError, modules::theme_schedule::parse_time};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    /// minutes since midnight, local time
+    At(u32),
+    OnAc,
+    OnBattery,
+    OnMonitorConnected,
+    /// exe filename, compared case insensitive
+    OnLaunch(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Switch {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Theme(Vec<String>),
+    Profile(String),
+    WegAutohide(Switch),
+    Run(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub source: String,
+    pub trigger: Trigger,
+    pub actions: Vec<Action>,
+}
+
+/// splits the first word of the input, returning it lowercased and the trimmed rest
+fn split_keyword(input: &str) -> (String, &str) {
+    let input = input.trim();
+    match input.split_once(char::is_whitespace) {
+        Some((keyword, rest)) => (keyword.to_lowercase(), rest.trim()),
+        None => (input.to_lowercase(), ""),
+    }
+}
+
+impl FromStr for Trigger {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (keyword, rest) = split_keyword(s);
+        let trigger = match keyword.as_str() {
+            "at" => Trigger::At(parse_time(rest).ok_or(format!("Invalid time: {rest}"))?),
+            "on" => {
+                let (event, arg) = split_keyword(rest);
+                match event.as_str() {
+                    "ac" => Trigger::OnAc,
+                    "battery" => Trigger::OnBattery,
+                    "monitor" if arg.eq_ignore_ascii_case("connected") => {
+                        Trigger::OnMonitorConnected
+                    }
+                    "launch" if !arg.is_empty() => Trigger::OnLaunch(arg.to_string()),
+                    _ => return Err(format!("Unknown event: {rest}").into()),
+                }
+            }
+            _ => return Err(format!("Unknown trigger: {s}").into()),
+        };
+        Ok(trigger)
+    }
+}
+
+impl FromStr for Switch {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "on" => Ok(Switch::On),
+            "off" => Ok(Switch::Off),
+            "toggle" => Ok(Switch::Toggle),
+            _ => Err(format!("Expected on, off or toggle, got: {s}").into()),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (keyword, rest) = split_keyword(s);
+        if rest.is_empty() {
+            return Err(format!("Missing argument on action: {s}").into());
+        }
+        let action = match keyword.as_str() {
+            "theme" => Action::Theme(
+                rest.split(',')
+                    .map(|theme| theme.trim().to_string())
+                    .filter(|theme| !theme.is_empty())
+                    .collect(),
+            ),
+            "profile" => Action::Profile(rest.to_string()),
+            "weg" => {
+                let (option, value) = split_keyword(rest);
+                if option != "autohide" {
+                    return Err(format!("Unknown weg option: {option}").into());
+                }
+                Action::WegAutohide(value.parse()?)
+            }
+            "run" => Action::Run(rest.to_string()),
+            _ => return Err(format!("Unknown action: {s}").into()),
+        };
+        Ok(action)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (trigger, actions) = s.split_once("=>").ok_or("Missing `=>` on rule")?;
+        let actions = actions
+            .split(';')
+            .filter(|action| !action.trim().is_empty())
+            .map(Action::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if actions.is_empty() {
+            return Err("Rule without actions".into());
+        }
+        Ok(Rule {
+            source: s.trim().to_string(),
+            trigger: trigger.parse()?,
+            actions,
+        })
+    }
+}
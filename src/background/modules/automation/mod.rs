@@ -0,0 +1,215 @@
+pub mod domain;
+
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use domain::{Action, Rule, Switch, Trigger};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::HideMode;
+use tauri_plugin_shell::ShellExt;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        action_history::{domain::ActionSource, ActionHistory},
+        monitors::MonitorManagerEvent,
+        theme_schedule::ThemeScheduler,
+    },
+    seelen::get_app_handle,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref RULES: Arc<Mutex<Vec<Rule>>> = Arc::new(Mutex::new(Vec::new()));
+    /// processes that already fired their launch rules
+    static ref LAUNCHED: Arc<Mutex<HashSet<u32>>> = Arc::new(Mutex::new(HashSet::new()));
+}
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Evaluates the user rules declared on `settings.automation`, each rule runs its
+/// actions when the trigger happens, not while the condition is true, so manual
+/// changes made after a rule fired are kept.
+pub struct Automation;
+
+impl Automation {
+    /// ticks between each clean up of the launched processes
+    const PRUNE_INTERVAL: u32 = 60;
+
+    fn load_rules() {
+        let sources = FULL_STATE.load().settings().automation.rules.clone();
+        let mut rules = Vec::new();
+        for source in sources.iter().filter(|source| !source.trim().is_empty()) {
+            match source.parse::<Rule>() {
+                Ok(rule) => rules.push(rule),
+                Err(err) => log::warn!("Invalid automation rule `{source}`: {err}"),
+            }
+        }
+        *trace_lock!(RULES) = rules;
+    }
+
+    /// `None` if the power source is unknown
+    fn is_on_ac() -> Option<bool> {
+        match WindowsApi::get_system_power_status().ok()?.ACLineStatus {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    fn fire(trigger: &Trigger) {
+        if !RUNNING.load(Ordering::Acquire) {
+            return;
+        }
+        let rules = trace_lock!(RULES)
+            .iter()
+            .filter(|rule| &rule.trigger == trigger)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for rule in rules {
+            log::trace!("Running automation rule: {}", rule.source);
+            for action in rule.actions {
+                let command = format!("{:?}", action);
+                let result = Self::execute(action);
+                ActionHistory::record(
+                    ActionSource::Automation,
+                    rule.source.as_str(),
+                    command,
+                    serde_json::Value::Null,
+                    (&result).into(),
+                );
+                log_error!(result);
+            }
+        }
+    }
+
+    fn set_weg_autohide(switch: Switch) -> Result<()> {
+        let mut state = FULL_STATE.load().cloned();
+        let current = state.settings().seelenweg.hide_mode;
+        let mode = match switch {
+            Switch::On => HideMode::Always,
+            Switch::Off => HideMode::Never,
+            Switch::Toggle if current == HideMode::Never => HideMode::Always,
+            Switch::Toggle => HideMode::Never,
+        };
+        state.set_weg_hide_mode(mode)?;
+        Ok(())
+    }
+
+    fn execute(action: Action) -> Result<()> {
+        match action {
+            Action::Theme(themes) => {
+                FULL_STATE.load().cloned().select_themes(themes)?;
+            }
+            Action::Profile(name) => {
+                FULL_STATE.load().cloned().apply_settings_profile(&name)?;
+            }
+            Action::WegAutohide(switch) => Self::set_weg_autohide(switch)?,
+            Action::Run(command) => {
+                tauri::async_runtime::spawn(async move {
+                    log_error!(
+                        get_app_handle()
+                            .shell()
+                            .command("cmd")
+                            .args(["/C", &command])
+                            .status()
+                            .await
+                    );
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn on_monitor_event(event: MonitorManagerEvent) {
+        if let MonitorManagerEvent::Added(..) = event {
+            std::thread::spawn(|| Self::fire(&Trigger::OnMonitorConnected));
+        }
+    }
+
+    /// Fires the launch rules of the app the first time one of its windows is shown
+    pub fn on_window_shown(hwnd: HWND) {
+        if !RUNNING.load(Ordering::Acquire) || !SeelenWeg::should_be_added(hwnd) {
+            return;
+        }
+        let Ok(exe) = WindowsApi::exe(hwnd) else {
+            return;
+        };
+        let (process_id, _) = WindowsApi::window_thread_process_id(hwnd);
+        if !trace_lock!(LAUNCHED).insert(process_id) {
+            return;
+        }
+
+        let launch_rules = trace_lock!(RULES)
+            .iter()
+            .filter_map(|rule| match &rule.trigger {
+                Trigger::OnLaunch(target) if target.eq_ignore_ascii_case(&exe) => {
+                    Some(rule.trigger.clone())
+                }
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        for trigger in launch_rules {
+            Self::fire(&trigger);
+        }
+    }
+
+    /// Starts the scheduler or reloads the rules if it is already running
+    pub fn start() -> Result<()> {
+        Self::load_rules();
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting automation scheduler");
+
+        spawn_named_thread("Automation", || {
+            let mut last_minute = ThemeScheduler::local_minutes();
+            let mut last_on_ac = Self::is_on_ac();
+            let mut ticks = 0;
+            while RUNNING.load(Ordering::Acquire) {
+                sleep_millis(1000);
+                ticks += 1;
+
+                let minute = ThemeScheduler::local_minutes();
+                if minute != last_minute {
+                    last_minute = minute;
+                    Self::fire(&Trigger::At(minute));
+                }
+
+                let on_ac = Self::is_on_ac();
+                if on_ac != last_on_ac {
+                    last_on_ac = on_ac;
+                    match on_ac {
+                        Some(true) => Self::fire(&Trigger::OnAc),
+                        Some(false) => Self::fire(&Trigger::OnBattery),
+                        None => {}
+                    }
+                }
+
+                if ticks % Self::PRUNE_INTERVAL == 0 {
+                    trace_lock!(LAUNCHED).retain(|pid| WindowsApi::is_process_running(*pid));
+                }
+            }
+            trace_lock!(LAUNCHED).clear();
+            log::trace!("Automation scheduler stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
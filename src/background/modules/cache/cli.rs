@@ -0,0 +1,51 @@
+use clap::Command;
+use itertools::Itertools;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::Cache;
+
+get_subcommands![
+    /** Prints the files and size used by the icons and thumbnails caches */
+    Stats,
+    /** Removes the generated icons and thumbnails, custom icons are kept */
+    Clear,
+];
+
+impl Cache {
+    pub const CLI_IDENTIFIER: &'static str = "cache";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Generated icons and thumbnails")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    /// Returns the text to be printed on the console of the caller
+    pub fn process(matches: &clap::ArgMatches) -> Result<String> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Stats => Ok(Self::stats()
+                .iter()
+                .map(|stats| {
+                    format!(
+                        "{}: {} files, {:.1} MB of {} MB",
+                        stats.kind.id(),
+                        stats.files,
+                        stats.size as f64 / Self::MB as f64,
+                        stats.max_size / Self::MB
+                    )
+                })
+                .join("\n")),
+            SubCommand::Clear => {
+                let (files, size) = Self::clear(None)?;
+                Ok(format!(
+                    "Removed {} files ({:.1} MB).",
+                    files,
+                    size as f64 / Self::MB as f64
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CacheKind {
+    /// icons extracted from executables, packaged apps and the shell
+    Icons,
+    /// media thumbnails and dock window previews
+    Thumbnails,
+}
+
+impl CacheKind {
+    pub const ALL: [CacheKind; 2] = [CacheKind::Icons, CacheKind::Thumbnails];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            CacheKind::Icons => "icons",
+            CacheKind::Thumbnails => "thumbnails",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub size: u64,
+    /// modification time of the file when it was generated, a different one means that
+    /// the user replaced it by a custom file, which should not be removed.
+    pub modified: u64,
+    pub last_used: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    pub kind: CacheKind,
+    pub files: usize,
+    /// in bytes
+    pub size: u64,
+    /// in bytes
+    pub max_size: u64,
+}
@@ -0,0 +1,17 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{CacheKind, CacheStats},
+    Cache,
+};
+
+#[tauri::command(async)]
+pub fn cache_get_stats() -> Vec<CacheStats> {
+    Cache::stats()
+}
+
+#[tauri::command(async)]
+pub fn cache_clear(kind: Option<CacheKind>) -> Result<Vec<CacheStats>> {
+    Cache::clear(kind)?;
+    Ok(Cache::stats())
+}
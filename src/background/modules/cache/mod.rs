@@ -0,0 +1,248 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use domain::{CacheEntry, CacheKind, CacheStats};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, seelen_weg::app_names::AppNames,
+    state::application::FULL_STATE, trace_lock, utils::app_cache_path,
+};
+
+lazy_static! {
+    static ref CACHE_INDEX: Mutex<CacheIndex> = Mutex::new(CacheIndex::load());
+}
+
+struct CacheIndex {
+    entries: HashMap<CacheKind, HashMap<PathBuf, CacheEntry>>,
+    dirty: bool,
+    last_save: Instant,
+}
+
+impl CacheIndex {
+    fn path() -> Result<PathBuf> {
+        Ok(app_cache_path(&get_app_handle())?.join(Cache::INDEX_FILENAME))
+    }
+
+    /// entries of files removed or replaced by the user since the last session are dropped
+    fn load() -> Self {
+        let mut entries: HashMap<CacheKind, HashMap<PathBuf, CacheEntry>> = Self::path()
+            .and_then(|path| Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?))
+            .unwrap_or_default();
+        for kind_entries in entries.values_mut() {
+            kind_entries.retain(|path, entry| modified_secs(path) == Some(entry.modified));
+        }
+        Self {
+            entries,
+            dirty: false,
+            last_save: Instant::now(),
+        }
+    }
+
+    fn save(&mut self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(&self.entries)?)?;
+        self.dirty = false;
+        self.last_save = Instant::now();
+        Ok(())
+    }
+
+    /// usage changes are saved at most once per interval, they are not worth a write per hit
+    fn save_if_due(&mut self) {
+        if self.dirty && self.last_save.elapsed() > Cache::SAVE_INTERVAL {
+            log_error!(self.save());
+        }
+    }
+
+    fn size(&self, kind: CacheKind) -> u64 {
+        self.entries
+            .get(&kind)
+            .map_or(0, |entries| entries.values().map(|entry| entry.size).sum())
+    }
+
+    /// Removes the least recently used files until the cache is under the limits
+    fn evict(&mut self, kind: CacheKind, max_size: u64) {
+        let Some(entries) = self.entries.get_mut(&kind) else {
+            return;
+        };
+
+        let mut size: u64 = entries.values().map(|entry| entry.size).sum();
+        if size <= max_size && entries.len() <= Cache::MAX_ENTRIES {
+            return;
+        }
+
+        // evict a bit more than needed to not evict on each new file
+        let target_size = max_size / 10 * 9;
+        let target_len = Cache::MAX_ENTRIES / 10 * 9;
+        let by_usage = entries
+            .iter()
+            .sorted_by_key(|(_, entry)| entry.last_used)
+            .map(|(path, entry)| (path.clone(), entry.size))
+            .collect_vec();
+
+        let mut evicted = 0;
+        for (path, entry_size) in by_usage {
+            if size <= target_size && entries.len() <= target_len {
+                break;
+            }
+            if remove_file(&path).is_ok() {
+                entries.remove(&path);
+                size = size.saturating_sub(entry_size);
+                evicted += 1;
+            }
+        }
+
+        log::debug!("Evicted {} files from the {} cache", evicted, kind.id());
+        self.dirty = true;
+        log_error!(self.save());
+    }
+}
+
+/// Bounded disk cache for the generated icons and thumbnails. The files stay where they
+/// always were, this only keeps an index of the generated ones to remove the least recently
+/// used when the limits set on the settings are exceeded.
+pub struct Cache;
+
+impl Cache {
+    const INDEX_FILENAME: &'static str = "cache_index.json";
+    const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+    /// limit of tracked files, so the index itself does not grow unbounded
+    const MAX_ENTRIES: usize = 10_000;
+    pub const MB: u64 = 1024 * 1024;
+
+    fn max_size(kind: CacheKind) -> u64 {
+        let state = FULL_STATE.load();
+        let settings = &state.settings().cache;
+        let max_size = match kind {
+            CacheKind::Icons => settings.icons_max_size,
+            CacheKind::Thumbnails => settings.thumbnails_max_size,
+        };
+        max_size as u64 * Self::MB
+    }
+
+    /// Folder for the thumbnails generated by Seelen, like the media ones
+    pub fn thumbnails_dir() -> Result<PathBuf> {
+        let dir = app_cache_path(&get_app_handle())?.join("thumbnails");
+        if !dir.exists() {
+            std::fs::create_dir_all(&dir)?;
+        }
+        Ok(dir)
+    }
+
+    /// Registers a file just generated by Seelen, evicting others if the limits are exceeded
+    pub fn record(kind: CacheKind, path: &Path) {
+        let (Ok(metadata), Some(modified)) = (path.metadata(), modified_secs(path)) else {
+            return;
+        };
+
+        let mut index = trace_lock!(CACHE_INDEX);
+        index.entries.entry(kind).or_default().insert(
+            path.to_path_buf(),
+            CacheEntry {
+                size: metadata.len(),
+                modified,
+                last_used: now_secs(),
+            },
+        );
+        index.dirty = true;
+
+        let max_size = Self::max_size(kind);
+        let len = index.entries.get(&kind).map_or(0, |entries| entries.len());
+        if index.size(kind) > max_size || len > Self::MAX_ENTRIES {
+            index.evict(kind, max_size);
+        } else {
+            index.save_if_due();
+        }
+    }
+
+    /// Marks a cached file as used, so it is evicted after the not used ones
+    pub fn touch(kind: CacheKind, path: &Path) {
+        let mut index = trace_lock!(CACHE_INDEX);
+        if let Some(entry) = index
+            .entries
+            .get_mut(&kind)
+            .and_then(|entries| entries.get_mut(path))
+        {
+            entry.last_used = now_secs();
+            index.dirty = true;
+            index.save_if_due();
+        }
+    }
+
+    pub fn stats() -> Vec<CacheStats> {
+        let index = trace_lock!(CACHE_INDEX);
+        CacheKind::ALL
+            .iter()
+            .map(|kind| CacheStats {
+                kind: *kind,
+                files: index.entries.get(kind).map_or(0, |entries| entries.len()),
+                size: index.size(*kind),
+                max_size: Self::max_size(*kind),
+            })
+            .collect()
+    }
+
+    /// Removes the generated files of `kind` or all of them, returns the amount of
+    /// files and bytes removed. Custom icons are not tracked so they are kept.
+    pub fn clear(kind: Option<CacheKind>) -> Result<(usize, u64)> {
+        let mut files = 0;
+        let mut size = 0;
+        {
+            let mut index = trace_lock!(CACHE_INDEX);
+            for (entry_kind, entries) in index.entries.iter_mut() {
+                if kind.is_some_and(|kind| kind != *entry_kind) {
+                    continue;
+                }
+                entries.retain(|path, entry| {
+                    if remove_file(path).is_ok() {
+                        files += 1;
+                        size += entry.size;
+                        return false;
+                    }
+                    true
+                });
+            }
+            index.save()?;
+        }
+
+        // icons and app names are resolved again on demand
+        if kind.map_or(true, |kind| kind == CacheKind::Icons) {
+            AppNames::clear()?;
+            get_app_handle().emit("icons-changed", ())?;
+        }
+        log::info!("Cleared {} cached files ({} bytes)", files, size);
+        Ok((files, size))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+fn modified_secs(path: &Path) -> Option<u64> {
+    let modified = path.metadata().ok()?.modified().ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_secs())
+}
+
+/// already removed files are also considered removed
+fn remove_file(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
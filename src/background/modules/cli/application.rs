@@ -9,11 +9,33 @@ use parking_lot::Mutex;
 use windows::Win32::System::Console::{AttachConsole, FreeConsole, ATTACH_PARENT_PROCESS};
 
 use crate::error_handler::Result;
+use crate::modules::audio_profiles::AudioProfiles;
+use crate::modules::cache::Cache;
+use crate::modules::cli::schema::JsonSchemas;
+use crate::modules::color_picker::ColorPicker;
+use crate::modules::cursor::CursorManager;
+use crate::modules::emoji_picker::EmojiPicker;
+use crate::modules::event_recorder::EventRecorder;
+use crate::modules::keep_awake::KeepAwake;
+use crate::modules::lock_screen::LockScreen;
+use crate::modules::mode_manager::ModeManager;
+use crate::modules::overview::Overview;
+use crate::modules::privacy::PrivacyWatcher;
+use crate::modules::recently_closed::RecentlyClosed;
+use crate::modules::remote_api::RemoteApi;
+use crate::modules::ruler::ScreenRuler;
+use crate::modules::session_restore::SessionRestore;
+use crate::modules::shell::ShellMode;
+use crate::modules::startup::StartupManager;
+use crate::modules::task_manager::TaskManager;
+use crate::modules::undo::Undo;
+use crate::modules::window_controls::WindowControls;
+use crate::modules::zones::ZonesManager;
 use crate::seelen::{Seelen, SEELEN};
 use crate::seelen_bar::FancyToolbar;
 use crate::seelen_weg::SeelenWeg;
 use crate::seelen_wm::WindowManager;
-use crate::state::application::FULL_STATE;
+use crate::state::application::{FullState, FULL_STATE};
 use crate::trace_lock;
 
 #[macro_export]
@@ -86,6 +108,20 @@ lazy_static! {
                     .long("silent")
                     .action(ArgAction::SetTrue)
                     .help("Start only background processes."),
+                Arg::new("safe-mode")
+                    .long("safe-mode")
+                    .action(ArgAction::SetTrue)
+                    .help("Start only the settings window, useful to recover from a broken config."),
+                Arg::new("minimal")
+                    .long("minimal")
+                    .action(ArgAction::SetTrue)
+                    .help("Start only the tray icon and the settings window, the rest can be started later from the tray."),
+                Arg::new("data-dir")
+                    .long("data-dir")
+                    .value_name("PATH")
+                    .value_parser(clap::value_parser!(PathBuf))
+                    .action(ArgAction::Set)
+                    .help("Stores settings, themes, icon cache and logs on the given folder (portable mode)."),
                 Arg::new("verbose")
                     .short('V')
                     .long("verbose")
@@ -104,9 +140,40 @@ lazy_static! {
             ])
             .subcommands([
                 Command::new("settings").about("Opens the Seelen settings gui."),
+                Command::new("status")
+                    .about("Prints the health of the running modules.")
+                    .arg(
+                        Arg::new("timings")
+                            .long("timings")
+                            .action(ArgAction::SetTrue)
+                            .help("Prints the time taken by each startup stage instead."),
+                    ),
+                FullState::get_cli(),
                 FancyToolbar::get_cli(),
                 WindowManager::get_cli(),
                 SeelenWeg::get_cli(),
+                Overview::get_cli(),
+                WindowControls::get_cli(),
+                ZonesManager::get_cli(),
+                StartupManager::get_cli(),
+                EmojiPicker::get_cli(),
+                KeepAwake::get_cli(),
+                Undo::get_cli(),
+                EventRecorder::get_cli(),
+                ShellMode::get_cli(),
+                JsonSchemas::get_cli(),
+                SessionRestore::get_cli(),
+                TaskManager::get_cli(),
+                RemoteApi::get_cli(),
+                RecentlyClosed::get_cli(),
+                CursorManager::get_cli(),
+                LockScreen::get_cli(),
+                Cache::get_cli(),
+                ColorPicker::get_cli(),
+                ScreenRuler::get_cli(),
+                AudioProfiles::get_cli(),
+                PrivacyWatcher::get_cli(),
+                ModeManager::get_cli(),
             ])
     ));
 }
@@ -141,6 +208,17 @@ pub fn is_just_getting_info(matches: &clap::ArgMatches) -> Result<bool> {
         r = true;
     }
 
+    // handled by this process as it is used to recover when the running instance is broken
+    if let Some((ShellMode::CLI_IDENTIFIER, matches)) = matches.subcommand() {
+        ShellMode::process(matches)?;
+        r = true;
+    }
+
+    if let Some((JsonSchemas::CLI_IDENTIFIER, matches)) = matches.subcommand() {
+        JsonSchemas::process(matches)?;
+        r = true;
+    }
+
     Ok(r)
 }
 
@@ -198,12 +276,61 @@ pub fn handle_cli_events(matches: &clap::ArgMatches) -> Result<()> {
                 }
             }
             SeelenWeg::CLI_IDENTIFIER => {
-                let mut seelen = trace_lock!(SEELEN);
-                for monitor in seelen.monitors_mut() {
-                    if let Some(weg) = monitor.weg_mut() {
-                        weg.process(matches)?;
-                    }
-                }
+                SeelenWeg::process_cli(matches)?;
+            }
+            Overview::CLI_IDENTIFIER => {
+                Overview::process(matches)?;
+            }
+            WindowControls::CLI_IDENTIFIER => {
+                WindowControls::process(matches)?;
+            }
+            ZonesManager::CLI_IDENTIFIER => {
+                ZonesManager::process(matches)?;
+            }
+            StartupManager::CLI_IDENTIFIER => {
+                StartupManager::process(matches)?;
+            }
+            EmojiPicker::CLI_IDENTIFIER => {
+                EmojiPicker::process(matches)?;
+            }
+            KeepAwake::CLI_IDENTIFIER => {
+                KeepAwake::process(matches)?;
+            }
+            Undo::CLI_IDENTIFIER => {
+                Undo::process(matches)?;
+            }
+            EventRecorder::CLI_IDENTIFIER => {
+                EventRecorder::process(matches)?;
+            }
+            SessionRestore::CLI_IDENTIFIER => {
+                SessionRestore::process(matches)?;
+            }
+            TaskManager::CLI_IDENTIFIER => {
+                TaskManager::process(matches)?;
+            }
+            RecentlyClosed::CLI_IDENTIFIER => {
+                RecentlyClosed::process(matches)?;
+            }
+            CursorManager::CLI_IDENTIFIER => {
+                CursorManager::process(matches)?;
+            }
+            LockScreen::CLI_IDENTIFIER => {
+                LockScreen::process(matches)?;
+            }
+            ColorPicker::CLI_IDENTIFIER => {
+                ColorPicker::process(matches)?;
+            }
+            ScreenRuler::CLI_IDENTIFIER => {
+                ScreenRuler::process(matches)?;
+            }
+            AudioProfiles::CLI_IDENTIFIER => {
+                AudioProfiles::process(matches)?;
+            }
+            PrivacyWatcher::CLI_IDENTIFIER => {
+                PrivacyWatcher::process(matches)?;
+            }
+            ModeManager::CLI_IDENTIFIER => {
+                ModeManager::process(matches)?;
             }
             _ => {}
         }
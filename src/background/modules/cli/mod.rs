@@ -1,21 +1,88 @@
 pub mod application;
 pub mod domain;
+pub mod schema;
 
 use std::{
     fs,
-    io::{BufReader, Read},
-    net::{TcpListener, TcpStream},
+    io::{BufReader, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream},
 };
 
-use application::{handle_cli_events, SEELEN_COMMAND_LINE};
+use application::{attach_console, detach_console, handle_cli_events, SEELEN_COMMAND_LINE};
+use itertools::Itertools;
 
-use crate::{error_handler::Result, log_error, trace_lock, utils::spawn_named_thread};
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        action_history::{domain::ActionSource, ActionHistory},
+        cache::Cache,
+        permissions::{
+            domain::{ClientId, Scope},
+            Permissions,
+        },
+        remote_api::RemoteApi,
+        watchdog::Watchdog,
+    },
+    state::application::FullState,
+    trace_lock,
+    utils::{spawn_named_thread, PERFORMANCE_HELPER},
+};
 
 pub struct Client;
 impl Client {
     // const BUFFER_SIZE: usize = 5 * 1024 * 1024; // 5 MB
 
+    /// Commands with an output are answered through the same stream instead of
+    /// being processed on background, the caller prints the reply.
+    pub fn reply(matches: &clap::ArgMatches) -> Option<String> {
+        match matches.subcommand()? {
+            ("status", matches) if matches.get_flag("timings") => {
+                let spans = trace_lock!(PERFORMANCE_HELPER).spans();
+                if spans.is_empty() {
+                    return Some("Seelen UI is still starting.".to_string());
+                }
+                Some(
+                    spans
+                        .iter()
+                        .map(|span| {
+                            format!(
+                                "{:<16} at {:>6}ms took {:>6}ms",
+                                span.name,
+                                span.start.as_millis(),
+                                span.duration.as_millis()
+                            )
+                        })
+                        .join("\n"),
+                )
+            }
+            ("status", _) => {
+                let report = Watchdog::report();
+                if report.is_empty() {
+                    return Some("No modules are being supervised yet.".to_string());
+                }
+                Some(report.iter().join("\n"))
+            }
+            (FullState::CLI_IDENTIFIER, matches) => match FullState::process(matches) {
+                Ok(output) => Some(output),
+                Err(err) => Some(format!("{:?}", err)),
+            },
+            (RemoteApi::CLI_IDENTIFIER, matches) => match RemoteApi::process(matches) {
+                Ok(output) => Some(output),
+                Err(err) => Some(format!("{:?}", err)),
+            },
+            (Cache::CLI_IDENTIFIER, matches) => match Cache::process(matches) {
+                Ok(output) => Some(output),
+                Err(err) => Some(format!("{:?}", err)),
+            },
+            _ => None,
+        }
+    }
+
     fn handle_message(stream: TcpStream) {
+        // identified before reading, as the caller closes the connection after sending
+        let client = Permissions::cli_client(&stream)
+            .unwrap_or_else(|| ClientId::Cli("unknown process".to_string()));
         let mut reader = BufReader::new(stream);
         let mut buffer = vec![];
         match reader.read_to_end(&mut buffer) {
@@ -24,9 +91,32 @@ impl Client {
                 match serde_json::from_str::<Vec<String>>(&message) {
                     Ok(argv) => {
                         log::trace!(target: "slu::cli", "{}", argv[1..].join(" "));
+                        let command = trace_lock!(SEELEN_COMMAND_LINE).clone();
+                        let matches = match command.try_get_matches_from(&argv) {
+                            Ok(matches) => matches,
+                            Err(err) => {
+                                log_error!(reader.get_mut().write_all(err.to_string().as_bytes()));
+                                return;
+                            }
+                        };
+                        let scope = Scope::of_cli(matches.subcommand_name());
+                        if !Permissions::is_trusted_cli(&client)
+                            && !Permissions::request(&client, scope)
+                        {
+                            let reply =
+                                format!("Permission denied: {} scope required.", scope.id());
+                            log_error!(reader.get_mut().write_all(reply.as_bytes()));
+                            return;
+                        }
+                        if let Some(reply) = Self::reply(&matches) {
+                            log_error!(reader.get_mut().write_all(reply.as_bytes()));
+                            return;
+                        }
                         std::thread::spawn(move || {
-                            let command = trace_lock!(SEELEN_COMMAND_LINE).clone();
-                            log_error!(handle_cli_events(&command.get_matches_from(argv)));
+                            let result = handle_cli_events(&matches);
+                            let caller = client.to_string();
+                            ActionHistory::record_argv(ActionSource::Cli, &caller, &argv, &result);
+                            log_error!(result);
                         });
                     }
                     Err(e) => {
@@ -68,4 +158,20 @@ impl Client {
         let port = fs::read_to_string(std::env::temp_dir().join("slu_tcp_socket"))?;
         Ok(TcpStream::connect(format!("127.0.0.1:{}", port))?)
     }
+
+    /// Sends the args to the running instance and prints its reply
+    pub fn print_reply() -> Result<()> {
+        let mut stream = Self::connect_tcp()?;
+        let args = std::env::args().collect_vec();
+        stream.write_all(serde_json::to_string(&args)?.as_bytes())?;
+        stream.shutdown(Shutdown::Write)?;
+
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply)?;
+
+        attach_console()?;
+        println!("{}", reply);
+        detach_console()?;
+        Ok(())
+    }
 }
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, Command};
+use seelen_core::schemas::{schema_json, SCHEMA_NAMES};
+
+use crate::error_handler::Result;
+
+use super::application::{attach_console, detach_console};
+
+/// JSON Schemas of the user editable files, generated from the `seelen_core` types
+pub struct JsonSchemas;
+
+impl JsonSchemas {
+    pub const CLI_IDENTIFIER: &'static str = "schema";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Prints the JSON Schema of settings, app configs, themes and other user files.")
+            .long_about(
+                "Prints the JSON Schema of settings, app configs, themes and other user files, \
+                useful for validation and autocomplete on editors. \
+                This command doesn't need Seelen UI to be running.",
+            )
+            .args([
+                Arg::new("name")
+                    .help("Schema to print.")
+                    .value_parser(SCHEMA_NAMES)
+                    .action(ArgAction::Set)
+                    .required_unless_present("out-dir"),
+                Arg::new("out-dir")
+                    .long("out-dir")
+                    .value_name("PATH")
+                    .help("Writes the schemas as `<name>.schema.json` files on the given folder.")
+                    .value_parser(clap::value_parser!(PathBuf))
+                    .action(ArgAction::Set),
+            ])
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let names = match matches.get_one::<String>("name") {
+            Some(name) => vec![name.as_str()],
+            None => SCHEMA_NAMES.to_vec(),
+        };
+
+        let mut schemas = Vec::new();
+        for name in names {
+            let schema = schema_json(name).ok_or(format!("Could not generate schema {name}"))?;
+            schemas.push((name, schema));
+        }
+
+        match matches.get_one::<PathBuf>("out-dir") {
+            Some(dir) => {
+                std::fs::create_dir_all(dir)?;
+                for (name, schema) in schemas {
+                    std::fs::write(dir.join(format!("{name}.schema.json")), schema)?;
+                }
+            }
+            None => {
+                attach_console()?;
+                for (_, schema) in schemas {
+                    println!("{schema}");
+                }
+                detach_console()?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::ColorPicker;
+
+get_subcommands![
+    /** Opens the color picker if it is closed, otherwise closes it */
+    Toggle,
+    /** Opens the color picker over the monitor under the cursor */
+    Show,
+    /** Closes the color picker */
+    Hide,
+];
+
+impl ColorPicker {
+    pub const CLI_IDENTIFIER: &'static str = "color-picker";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Screen color picker")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Toggle => Self::toggle()?,
+            SubCommand::Show => Self::show()?,
+            SubCommand::Hide => Self::hide(),
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// `#RRGGBB`
+    #[default]
+    Hex,
+    /// `rgb(r, g, b)`
+    Rgb,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct PickedColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl PickedColor {
+    pub fn format(&self, format: ColorFormat) -> String {
+        match format {
+            ColorFormat::Hex => format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b),
+            ColorFormat::Rgb => format!("rgb({}, {}, {})", self.r, self.g, self.b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorPickerSetup {
+    /// capture of the monitor as a png data url, in physical pixels
+    pub image: String,
+    /// latest picked colors, newest first
+    pub history: Vec<PickedColor>,
+}
@@ -0,0 +1,26 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{ColorFormat, PickedColor},
+    ColorPicker,
+};
+
+#[tauri::command(async)]
+pub fn color_picker_show() -> Result<()> {
+    ColorPicker::show()
+}
+
+#[tauri::command(async)]
+pub fn color_picker_pick(x: u32, y: u32, format: Option<ColorFormat>) -> Result<PickedColor> {
+    ColorPicker::pick(x, y, format.unwrap_or_default())
+}
+
+#[tauri::command(async)]
+pub fn color_picker_close() {
+    ColorPicker::hide();
+}
+
+#[tauri::command(async)]
+pub fn color_picker_history() -> Vec<PickedColor> {
+    ColorPicker::history()
+}
@@ -0,0 +1,173 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{collections::VecDeque, io::Cursor, sync::Arc};
+
+use base64::Engine;
+use domain::{ColorFormat, ColorPickerSetup, PickedColor};
+use image::{ImageFormat, RgbaImage};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::{Emitter, Listener, WebviewWindow, Wry};
+use win_screenshot::capture::capture_display;
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{
+        GetSystemMetrics, HWND_TOPMOST, SM_XVIRTUALSCREEN, SM_YVIRTUALSCREEN, SWP_NOACTIVATE,
+    },
+};
+
+use crate::{
+    error_handler::Result, log_error, modules::input::Mouse, trace_lock,
+    utils::overlay::OverlayBuilder, windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref COLOR_PICKER: Arc<Mutex<Option<ColorPicker>>> = Arc::new(Mutex::new(None));
+    static ref HISTORY: Mutex<VecDeque<PickedColor>> = Mutex::new(VecDeque::new());
+}
+
+/// Overlay shown over the monitor under the cursor with a magnified loupe, clicking copies the
+/// color of the pixel under the cursor to the clipboard.
+pub struct ColorPicker {
+    window: WebviewWindow<Wry>,
+    /// capture of the monitor taken on open, the picked colors are read from it so the loupe
+    /// and the copied color always match
+    capture: RgbaImage,
+}
+
+impl Drop for ColorPicker {
+    fn drop(&mut self) {
+        log::trace!("Closing color picker");
+        log_error!(self.window.destroy());
+    }
+}
+
+impl ColorPicker {
+    const TARGET: &'static str = "color-picker";
+    const MAX_HISTORY: usize = 12;
+
+    /// Captures the monitor area of the screen, in physical pixels
+    fn capture_monitor(rect: &RECT) -> Result<RgbaImage> {
+        let buf =
+            capture_display().map_err(|err| format!("Failed to capture screen: {:?}", err))?;
+        let screen = RgbaImage::from_raw(buf.width, buf.height, buf.pixels)
+            .ok_or("Invalid screen capture")?;
+        // the capture starts at the top left corner of the virtual screen
+        let (x, y) = unsafe {
+            (
+                GetSystemMetrics(SM_XVIRTUALSCREEN),
+                GetSystemMetrics(SM_YVIRTUALSCREEN),
+            )
+        };
+        let monitor = image::imageops::crop_imm(
+            &screen,
+            (rect.left - x).max(0) as u32,
+            (rect.top - y).max(0) as u32,
+            (rect.right - rect.left) as u32,
+            (rect.bottom - rect.top) as u32,
+        );
+        Ok(monitor.to_image())
+    }
+
+    fn create_window(rect: &RECT) -> Result<WebviewWindow<Wry>> {
+        let window = OverlayBuilder::new(
+            Self::TARGET,
+            "color-picker/index.html",
+            "Seelen Color Picker",
+        )
+        .build()?;
+
+        WindowsApi::set_position(
+            HWND(window.hwnd()?.0),
+            Some(HWND_TOPMOST),
+            rect,
+            SWP_NOACTIVATE,
+        )?;
+        Ok(window)
+    }
+
+    fn new() -> Result<Self> {
+        let cursor = Mouse::get_cursor_pos().unwrap_or_default();
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        let rect = WindowsApi::monitor_rect(monitor)?;
+        // captured before creating the overlay so it is not part of the capture
+        let capture = Self::capture_monitor(&rect)?;
+        let window = Self::create_window(&rect)?;
+
+        window.once("complete-setup", move |_| {
+            if let Some(picker) = trace_lock!(COLOR_PICKER).as_ref() {
+                log_error!(picker.emit_setup());
+            }
+        });
+
+        window.show()?;
+        window.set_focus()?;
+        Ok(Self { window, capture })
+    }
+
+    fn emit_setup(&self) -> Result<()> {
+        let mut png = Vec::new();
+        self.capture
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+        let setup = ColorPickerSetup {
+            image: format!(
+                "data:image/png;base64,{}",
+                base64::engine::general_purpose::STANDARD.encode(png)
+            ),
+            history: Self::history(),
+        };
+        self.window
+            .emit_to(self.window.label(), "color-picker-setup", setup)?;
+        Ok(())
+    }
+
+    pub fn show() -> Result<()> {
+        let mut picker = trace_lock!(COLOR_PICKER);
+        if picker.is_none() {
+            *picker = Some(Self::new()?);
+        }
+        Ok(())
+    }
+
+    pub fn hide() {
+        trace_lock!(COLOR_PICKER).take();
+    }
+
+    pub fn toggle() -> Result<()> {
+        let is_open = trace_lock!(COLOR_PICKER).is_some();
+        if is_open {
+            Self::hide();
+            Ok(())
+        } else {
+            Self::show()
+        }
+    }
+
+    /// Closes the picker and copies the color of the pixel at `x`, `y` (physical pixels
+    /// relative to the monitor) to the clipboard
+    pub fn pick(x: u32, y: u32, format: ColorFormat) -> Result<PickedColor> {
+        let picker = trace_lock!(COLOR_PICKER)
+            .take()
+            .ok_or("Color picker is not open")?;
+        let x = x.min(picker.capture.width().saturating_sub(1));
+        let y = y.min(picker.capture.height().saturating_sub(1));
+        let [r, g, b, _] = picker.capture.get_pixel(x, y).0;
+        drop(picker);
+
+        let color = PickedColor { r, g, b };
+        WindowsApi::set_clipboard_text(&color.format(format))?;
+
+        let mut history = trace_lock!(HISTORY);
+        history.retain(|c| *c != color);
+        history.push_front(color);
+        history.truncate(Self::MAX_HISTORY);
+        Ok(color)
+    }
+
+    /// Latest picked colors, newest first
+    pub fn history() -> Vec<PickedColor> {
+        trace_lock!(HISTORY).iter().copied().collect()
+    }
+}
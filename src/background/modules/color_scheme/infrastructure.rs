@@ -0,0 +1,6 @@
+use super::ColorScheme;
+
+#[tauri::command(async)]
+pub fn get_color_scheme() -> ColorScheme {
+    ColorScheme::current()
+}
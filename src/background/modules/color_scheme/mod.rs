@@ -0,0 +1,179 @@
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::Emitter;
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            PostMessageW, PostQuitMessage, RegisterClassW, TranslateMessage, MSG, WINDOW_EX_STYLE,
+            WINDOW_STYLE, WM_CLOSE, WM_DESTROY, WM_SETTINGCHANGE, WM_SYSCOLORCHANGE,
+            WM_THEMECHANGED, WNDCLASSW,
+        },
+    },
+};
+use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle,
+    seelen_weg::icon_extractor::refresh_packaged_apps_icons, trace_lock, utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref LAST_SCHEME: Mutex<Option<ColorScheme>> = Mutex::new(None);
+}
+
+/// hidden window receiving the setting changes, 0 if the watcher is not running
+static WATCHER_HWND: AtomicIsize = AtomicIsize::new(0);
+
+const PERSONALIZE_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorScheme {
+    /// dark mode of the apps
+    pub apps_dark: bool,
+    /// dark mode of the taskbar, start menu and other shell surfaces
+    pub system_dark: bool,
+    pub high_contrast: bool,
+}
+
+impl ColorScheme {
+    pub fn current() -> Self {
+        let key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey(PERSONALIZE_KEY)
+            .ok();
+        let is_dark = |name: &str| {
+            key.as_ref()
+                .and_then(|key| key.get_value::<u32, _>(name).ok())
+                .is_some_and(|light| light == 0)
+        };
+        Self {
+            apps_dark: is_dark("AppsUseLightTheme"),
+            system_dark: is_dark("SystemUsesLightTheme"),
+            high_contrast: WindowsApi::is_high_contrast().unwrap_or(false),
+        }
+    }
+}
+
+/// Emits `color-scheme-changed` when the dark mode or the high contrast is toggled.
+/// Windows broadcasts several messages for a single change, so only real changes are emitted.
+pub struct ColorSchemeWatcher;
+
+impl ColorSchemeWatcher {
+    fn is_color_set_change(l_param: LPARAM) -> bool {
+        if l_param.0 == 0 {
+            return false;
+        }
+        let area = unsafe { PCWSTR(l_param.0 as *const u16).to_string() };
+        area.is_ok_and(|area| area == "ImmersiveColorSet")
+    }
+
+    fn notify() {
+        let scheme = ColorScheme::current();
+        let previous = trace_lock!(LAST_SCHEME).replace(scheme);
+        if previous == Some(scheme) {
+            return;
+        }
+        log::trace!("Color scheme changed to {:?}", scheme);
+        log_error!(get_app_handle().emit("color-scheme-changed", scheme));
+
+        // packaged apps have icon variants for light and dark surfaces
+        if previous.is_some_and(|previous| previous.system_dark != scheme.system_dark) {
+            std::thread::spawn(move || {
+                log_error!(refresh_packaged_apps_icons(
+                    &get_app_handle(),
+                    !scheme.system_dark
+                ))
+            });
+        }
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_SETTINGCHANGE if Self::is_color_set_change(l_param) => {
+                Self::notify();
+                LRESULT(0)
+            }
+            WM_THEMECHANGED | WM_SYSCOLORCHANGE => {
+                Self::notify();
+                LRESULT(0)
+            }
+            WM_CLOSE => {
+                log_error!(DestroyWindow(hwnd));
+                LRESULT(0)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if WATCHER_HWND.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Starting color scheme watcher");
+        *trace_lock!(LAST_SCHEME) = Some(ColorScheme::current());
+
+        let class_name = w!("SeelenColorSchemeWatcher");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        spawn_named_thread("Color Scheme Watcher", move || unsafe {
+            // setting changes are only broadcasted to top-level windows
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+            WATCHER_HWND.store(hwnd.0, Ordering::Release);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            WATCHER_HWND.store(0, Ordering::Release);
+            log::trace!("Color scheme watcher stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let hwnd = WATCHER_HWND.load(Ordering::Acquire);
+        if hwnd != 0 {
+            log_error!(unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) });
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use seelen_core::state::ConflictOverride;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detection {
+    /// executable name of a running process, compared case insensitive
+    Process(&'static str),
+    /// lowercase text contained on the path of a dll loaded by the explorer
+    ExplorerModule(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Mitigation {
+    /// the native taskbar is not hidden by the dock, taskbar replacements fight back
+    KeepTaskbar,
+    /// the seelen tiling is not started, two window managers fight over the same windows
+    SkipTiling,
+}
+
+pub struct KnownConflict {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub detections: &'static [Detection],
+    /// empty if the user is only warned
+    pub mitigations: &'static [Mitigation],
+}
+
+pub const KNOWN_CONFLICTS: &[KnownConflict] = &[
+    KnownConflict {
+        id: "explorer-patcher",
+        name: "ExplorerPatcher",
+        detections: &[Detection::ExplorerModule("explorerpatcher")],
+        mitigations: &[Mitigation::KeepTaskbar],
+    },
+    KnownConflict {
+        id: "start-all-back",
+        name: "StartAllBack",
+        detections: &[Detection::ExplorerModule("startallback")],
+        mitigations: &[Mitigation::KeepTaskbar],
+    },
+    KnownConflict {
+        id: "start11",
+        name: "Start11",
+        detections: &[
+            Detection::Process("start11srv.exe"),
+            Detection::ExplorerModule("start11"),
+        ],
+        mitigations: &[Mitigation::KeepTaskbar],
+    },
+    KnownConflict {
+        id: "rounded-tb",
+        name: "RoundedTB",
+        detections: &[Detection::Process("roundedtb.exe")],
+        mitigations: &[Mitigation::KeepTaskbar],
+    },
+    KnownConflict {
+        id: "translucent-tb",
+        name: "TranslucentTB",
+        detections: &[Detection::Process("translucenttb.exe")],
+        mitigations: &[],
+    },
+    KnownConflict {
+        id: "windhawk",
+        name: "Windhawk",
+        detections: &[Detection::Process("windhawk.exe")],
+        mitigations: &[],
+    },
+    KnownConflict {
+        id: "rocket-dock",
+        name: "RocketDock",
+        detections: &[Detection::Process("rocketdock.exe")],
+        mitigations: &[],
+    },
+    KnownConflict {
+        id: "winstep-nexus",
+        name: "Winstep Nexus",
+        detections: &[Detection::Process("nexus.exe")],
+        mitigations: &[],
+    },
+    KnownConflict {
+        id: "object-dock",
+        name: "ObjectDock",
+        detections: &[Detection::Process("objectdock.exe")],
+        mitigations: &[],
+    },
+    KnownConflict {
+        id: "komorebi",
+        name: "komorebi",
+        detections: &[Detection::Process("komorebi.exe")],
+        mitigations: &[Mitigation::SkipTiling],
+    },
+    KnownConflict {
+        id: "glazewm",
+        name: "GlazeWM",
+        detections: &[Detection::Process("glazewm.exe")],
+        mitigations: &[Mitigation::SkipTiling],
+    },
+    KnownConflict {
+        id: "fancywm",
+        name: "FancyWM",
+        detections: &[Detection::Process("fancywm.exe")],
+        mitigations: &[Mitigation::SkipTiling],
+    },
+    KnownConflict {
+        id: "workspacer",
+        name: "workspacer",
+        detections: &[Detection::Process("workspacer.exe")],
+        mitigations: &[Mitigation::SkipTiling],
+    },
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictReport {
+    pub id: String,
+    pub name: String,
+    /// false if only listed because of a `Force` override
+    pub detected: bool,
+    /// how it was detected, e.g. the dll path
+    pub reason: Option<String>,
+    pub mitigations: Vec<Mitigation>,
+    #[serde(rename = "override")]
+    pub override_by_user: Option<ConflictOverride>,
+    /// the mitigations are applied
+    pub mitigated: bool,
+}
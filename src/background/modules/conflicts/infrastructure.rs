@@ -0,0 +1,12 @@
+use super::{domain::ConflictReport, ConflictDetector};
+
+#[tauri::command(async)]
+pub fn get_conflicts() -> Vec<ConflictReport> {
+    ConflictDetector::report()
+}
+
+#[tauri::command(async)]
+pub fn conflicts_rescan() -> Vec<ConflictReport> {
+    ConflictDetector::detect();
+    ConflictDetector::report()
+}
@@ -0,0 +1,158 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{collections::HashMap, sync::Arc};
+
+use domain::{ConflictReport, Detection, KnownConflict, Mitigation, KNOWN_CONFLICTS};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{ConflictOverride, ConflictsSettings, WmInterop};
+use sysinfo::System;
+
+use crate::{
+    log_error,
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::{Toast, WindowsApi},
+};
+
+lazy_static! {
+    /// detected conflicts by id, with the reason of the detection
+    static ref DETECTED: Arc<Mutex<HashMap<&'static str, String>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+}
+
+/// Detects known tools that conflict with the dock or the tiling by their processes or the
+/// dlls injected on the explorer, and adjusts the strategies to not fight with them.
+/// Each conflict can be overridden on the settings, which also silences its warning.
+pub struct ConflictDetector;
+
+impl ConflictDetector {
+    /// Returns the detected conflicts by id, with the reason of the detection
+    pub fn scan() -> HashMap<&'static str, String> {
+        let mut sys = System::new();
+        sys.refresh_processes();
+
+        let mut explorer_modules = Vec::new();
+        for process in sys.processes().values() {
+            if process.name().eq_ignore_ascii_case("explorer.exe") {
+                if let Ok(paths) = WindowsApi::process_module_paths(process.pid().as_u32()) {
+                    explorer_modules.extend(paths.into_iter().map(|path| path.to_lowercase()));
+                }
+            }
+        }
+
+        let mut found = HashMap::new();
+        for conflict in KNOWN_CONFLICTS {
+            let reason = conflict
+                .detections
+                .iter()
+                .find_map(|detection| match detection {
+                    Detection::Process(exe) => sys
+                        .processes()
+                        .values()
+                        .find(|process| process.name().eq_ignore_ascii_case(exe))
+                        .map(|process| format!("{} is running", process.name())),
+                    Detection::ExplorerModule(pattern) => explorer_modules
+                        .iter()
+                        .find(|path| path.contains(pattern))
+                        .map(|path| format!("{} is loaded on the explorer", path)),
+                });
+            if let Some(reason) = reason {
+                found.insert(conflict.id, reason);
+            }
+        }
+        found
+    }
+
+    /// Scans again and warns about the new conflicts without an override
+    pub fn detect() {
+        let state = FULL_STATE.load();
+        let settings = state.settings();
+        if !settings.conflicts.enabled {
+            trace_lock!(DETECTED).clear();
+            return;
+        }
+
+        let mut found = Self::scan();
+        // the external window manager is expected to be running on interop mode
+        if settings.window_manager.interop != WmInterop::None {
+            found.retain(|id, _| {
+                Self::known(id).is_some_and(|c| !c.mitigations.contains(&Mitigation::SkipTiling))
+            });
+        }
+
+        let mut detected = trace_lock!(DETECTED);
+        for (id, reason) in &found {
+            if detected.contains_key(id) || settings.conflicts.overrides.contains_key(*id) {
+                continue;
+            }
+            if let Some(conflict) = Self::known(id) {
+                log::warn!(
+                    "Conflicting software detected: {} ({})",
+                    conflict.name,
+                    reason
+                );
+                Self::warn(conflict);
+            }
+        }
+        *detected = found;
+    }
+
+    fn known(id: &str) -> Option<&'static KnownConflict> {
+        KNOWN_CONFLICTS.iter().find(|conflict| conflict.id == id)
+    }
+
+    fn warn(conflict: &KnownConflict) {
+        let adjustment = match conflict.mitigations.first() {
+            Some(Mitigation::KeepTaskbar) => "The Windows taskbar will not be hidden by the dock.",
+            Some(Mitigation::SkipTiling) => "The tiling window manager will not be started.",
+            None => "Some features could not work as expected.",
+        };
+        let message = format!(
+            "{} can conflict with Seelen UI. {} This can be overridden on the settings.",
+            conflict.name, adjustment
+        );
+        log_error!(Toast::show_titled(
+            "Conflicting software detected",
+            &message
+        ));
+    }
+
+    fn applies(conflict: &KnownConflict, settings: &ConflictsSettings, detected: bool) -> bool {
+        match settings.overrides.get(conflict.id) {
+            Some(ConflictOverride::Ignore) => false,
+            Some(ConflictOverride::Force) => true,
+            _ => detected && settings.enabled,
+        }
+    }
+
+    pub fn is_mitigated(settings: &ConflictsSettings, mitigation: Mitigation) -> bool {
+        let detected = trace_lock!(DETECTED);
+        KNOWN_CONFLICTS
+            .iter()
+            .filter(|conflict| conflict.mitigations.contains(&mitigation))
+            .any(|conflict| Self::applies(conflict, settings, detected.contains_key(conflict.id)))
+    }
+
+    /// Detected conflicts and the ones with an override
+    pub fn report() -> Vec<ConflictReport> {
+        let state = FULL_STATE.load();
+        let settings = &state.settings().conflicts;
+        let detected = trace_lock!(DETECTED);
+        KNOWN_CONFLICTS
+            .iter()
+            .filter(|c| detected.contains_key(c.id) || settings.overrides.contains_key(c.id))
+            .map(|conflict| ConflictReport {
+                id: conflict.id.to_string(),
+                name: conflict.name.to_string(),
+                detected: detected.contains_key(conflict.id),
+                reason: detected.get(conflict.id).cloned(),
+                mitigations: conflict.mitigations.to_vec(),
+                override_by_user: settings.overrides.get(conflict.id).copied(),
+                mitigated: !conflict.mitigations.is_empty()
+                    && Self::applies(conflict, settings, detected.contains_key(conflict.id)),
+            })
+            .collect()
+    }
+}
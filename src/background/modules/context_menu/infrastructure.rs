@@ -0,0 +1,10 @@
+use std::path::PathBuf;
+
+use crate::error_handler::Result;
+
+use super::ShellContextMenu;
+
+#[tauri::command(async)]
+pub fn open_shell_context_menu(path: PathBuf, x: i32, y: i32) -> Result<()> {
+    ShellContextMenu::show(path, x, y)
+}
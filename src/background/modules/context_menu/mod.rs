@@ -0,0 +1,179 @@
+pub mod infrastructure;
+
+use std::{cell::RefCell, path::PathBuf};
+
+use windows::{
+    core::{w, Interface, HSTRING, PCSTR, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, POINT, WPARAM},
+        UI::{
+            Shell::{
+                BHID_SFUIObject, IContextMenu, IContextMenu3, IShellItem,
+                SHCreateItemFromParsingName, CMF_NORMAL, CMIC_MASK_PTINVOKE, CMIC_MASK_UNICODE,
+                CMINVOKECOMMANDINFO, CMINVOKECOMMANDINFOEX,
+            },
+            WindowsAndMessaging::{
+                CreatePopupMenu, CreateWindowExW, DefWindowProcW, DestroyMenu, DestroyWindow,
+                RegisterClassW, SetForegroundWindow, TrackPopupMenuEx, HMENU, HWND_MESSAGE,
+                SW_SHOWNORMAL, TPM_RETURNCMD, TPM_RIGHTBUTTON, WINDOW_EX_STYLE, WINDOW_STYLE,
+                WM_DRAWITEM, WM_INITMENUPOPUP, WM_MEASUREITEM, WM_MENUCHAR, WM_MENUSELECT,
+                WNDCLASSW,
+            },
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    utils::spawn_named_thread,
+    windows_api::{Com, WindowsApi},
+};
+
+thread_local! {
+    /// menu being shown on this thread, owner draw messages are forwarded to it
+    static ACTIVE_MENU: RefCell<Option<IContextMenu3>> = RefCell::new(None);
+}
+
+/// The Explorer context menu of a file or folder, including the shell extensions
+/// registered by other apps (7-Zip, Git, antivirus, etc).
+pub struct ShellContextMenu;
+
+impl ShellContextMenu {
+    const FIRST_COMMAND: u32 = 1;
+    const LAST_COMMAND: u32 = 0x7FFF;
+
+    /// Submenus of extensions (like "Send to") are filled and drawn on demand
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        if matches!(
+            msg,
+            WM_INITMENUPOPUP | WM_DRAWITEM | WM_MEASUREITEM | WM_MENUCHAR | WM_MENUSELECT
+        ) {
+            let handled = ACTIVE_MENU.with(|menu| {
+                let menu = menu.borrow();
+                let menu = menu.as_ref()?;
+                let mut result = LRESULT(0);
+                menu.HandleMenuMsg2(msg, w_param, l_param, Some(&mut result as *mut _))
+                    .ok()
+                    .map(|_| result)
+            });
+            if let Some(result) = handled {
+                return result;
+            }
+        }
+        DefWindowProcW(hwnd, msg, w_param, l_param)
+    }
+
+    /// Message-only window owning the menu, it receives the messages of the menu
+    fn create_owner() -> Result<HWND> {
+        let class_name = w!("SeelenShellContextMenu");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe {
+            RegisterClassW(&wnd_class);
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                None,
+                h_module,
+                None,
+            );
+            if hwnd.0 == 0 {
+                return Err("Failed to create context menu owner".into());
+            }
+            Ok(hwnd)
+        }
+    }
+
+    unsafe fn invoke(menu: &IContextMenu, owner: HWND, command: u32, point: POINT) -> Result<()> {
+        // the verb is the offset of the command, passed as a resource id
+        let verb = (command - Self::FIRST_COMMAND) as usize;
+        let info = CMINVOKECOMMANDINFOEX {
+            cbSize: std::mem::size_of::<CMINVOKECOMMANDINFOEX>() as u32,
+            fMask: CMIC_MASK_UNICODE | CMIC_MASK_PTINVOKE,
+            hwnd: owner,
+            lpVerb: PCSTR(verb as *const u8),
+            lpVerbW: PCWSTR(verb as *const u16),
+            nShow: SW_SHOWNORMAL.0,
+            ptInvoke: point,
+            ..Default::default()
+        };
+        menu.InvokeCommand(&info as *const _ as *const CMINVOKECOMMANDINFO)?;
+        Ok(())
+    }
+
+    unsafe fn track(menu: &IContextMenu, hmenu: HMENU, point: POINT) -> Result<()> {
+        menu.QueryContextMenu(
+            hmenu,
+            0,
+            Self::FIRST_COMMAND,
+            Self::LAST_COMMAND,
+            CMF_NORMAL,
+        )?;
+
+        let owner = Self::create_owner()?;
+        ACTIVE_MENU.with(|active| *active.borrow_mut() = menu.cast::<IContextMenu3>().ok());
+        // the menu is only dismissed by clicking outside if its owner is on foreground
+        let _ = SetForegroundWindow(owner);
+        let command = TrackPopupMenuEx(
+            hmenu,
+            (TPM_RETURNCMD | TPM_RIGHTBUTTON).0,
+            point.x,
+            point.y,
+            owner,
+            None,
+        )
+        .0 as u32;
+        ACTIVE_MENU.with(|active| active.borrow_mut().take());
+
+        // 0 if the menu was dismissed without selecting anything
+        let result = if command >= Self::FIRST_COMMAND {
+            Self::invoke(menu, owner, command, point)
+        } else {
+            Ok(())
+        };
+        DestroyWindow(owner)?;
+        result
+    }
+
+    fn show_blocking(path: PathBuf, point: POINT) -> Result<()> {
+        Com::run_with_context(|| unsafe {
+            let item: IShellItem =
+                SHCreateItemFromParsingName(&HSTRING::from(path.as_os_str()), None)?;
+            let menu: IContextMenu = item.BindToHandler(None, &BHID_SFUIObject)?;
+            let hmenu = CreatePopupMenu()?;
+            let result = Self::track(&menu, hmenu, point);
+            DestroyMenu(hmenu)?;
+            result
+        })
+    }
+
+    /// Shows the menu at `x`, `y` in physical screen coordinates. The menu runs on its own
+    /// thread as it needs a single threaded apartment and blocks until it is closed.
+    pub fn show(path: PathBuf, x: i32, y: i32) -> Result<()> {
+        if !path.exists() {
+            return Err(format!("{} does not exist", path.display()).into());
+        }
+        spawn_named_thread("Shell Context Menu", move || {
+            log_error!(Self::show_blocking(path, POINT { x, y }));
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::CursorManager;
+
+get_subcommands![
+    /** Confines the cursor to the monitor of the focused window */
+    Lock,
+    /** Releases the cursor */
+    Unlock,
+    /** Locks the cursor if it is free, otherwise releases it */
+    ToggleLock,
+    /** Shows a sonar around the cursor to find it */
+    Find,
+];
+
+impl CursorManager {
+    pub const CLI_IDENTIFIER: &'static str = "cursor";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Cursor lock and locator")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Lock => Self::lock()?,
+            SubCommand::Unlock => Self::unlock()?,
+            SubCommand::ToggleLock => Self::toggle_lock()?,
+            SubCommand::Find => Self::find()?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::error_handler::Result;
+
+use super::CursorManager;
+
+#[tauri::command(async)]
+pub fn cursor_is_locked() -> bool {
+    CursorManager::is_locked()
+}
+
+#[tauri::command(async)]
+pub fn cursor_toggle_lock() -> Result<()> {
+    CursorManager::toggle_lock()
+}
+
+#[tauri::command(async)]
+pub fn cursor_find() -> Result<()> {
+    CursorManager::find()
+}
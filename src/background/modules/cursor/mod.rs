@@ -0,0 +1,149 @@
+pub mod cli;
+pub mod infrastructure;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::{Emitter, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{ClipCursor, HWND_TOPMOST, SWP_NOACTIVATE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::Mouse,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{overlay::OverlayBuilder, sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref SONAR: Arc<Mutex<Option<WebviewWindow<Wry>>>> = Arc::new(Mutex::new(None));
+}
+
+/// Incremented on each lock/unlock, the lock thread exits when it changes
+static LOCK_GENERATION: AtomicU32 = AtomicU32::new(0);
+/// Incremented on each sonar, so only the last one closes the overlay
+static SONAR_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+/// Cursor helpers: confine it to a monitor and locate it with a sonar overlay.
+pub struct CursorManager;
+
+impl CursorManager {
+    const SONAR_LABEL: &'static str = "cursor-sonar";
+    const SONAR_SIZE: f32 = 300.0;
+    /// should match the duration of the animation on the overlay
+    const SONAR_DURATION: u64 = 1200;
+    /// the system releases the clip on focus changes and UAC prompts, so it is reapplied
+    const LOCK_INTERVAL: u64 = 250;
+
+    pub fn is_locked() -> bool {
+        LOCK_GENERATION.load(Ordering::Acquire) % 2 == 1
+    }
+
+    /// Confines the cursor to the monitor of the foreground window until unlocked.
+    pub fn lock() -> Result<()> {
+        if Self::is_locked() {
+            return Ok(());
+        }
+
+        let monitor = WindowsApi::monitor_from_window(WindowsApi::get_foreground_window());
+        let rect = WindowsApi::monitor_rect(monitor)?;
+        let generation = LOCK_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+
+        spawn_named_thread("Cursor Lock", move || {
+            while LOCK_GENERATION.load(Ordering::Acquire) == generation {
+                // fails while the secure desktop is shown, so it is just retried
+                if let Err(err) = unsafe { ClipCursor(Some(&rect)) } {
+                    log::trace!("Failed to clip the cursor: {:?}", err);
+                }
+                sleep_millis(Self::LOCK_INTERVAL);
+            }
+            log_error!(unsafe { ClipCursor(None) });
+        })?;
+
+        get_app_handle().emit("cursor-lock-changed", true)?;
+        Ok(())
+    }
+
+    pub fn unlock() -> Result<()> {
+        if !Self::is_locked() {
+            return Ok(());
+        }
+        LOCK_GENERATION.fetch_add(1, Ordering::AcqRel);
+        get_app_handle().emit("cursor-lock-changed", false)?;
+        Ok(())
+    }
+
+    pub fn toggle_lock() -> Result<()> {
+        if Self::is_locked() {
+            Self::unlock()
+        } else {
+            Self::lock()
+        }
+    }
+
+    fn create_sonar() -> Result<WebviewWindow<Wry>> {
+        OverlayBuilder::new(
+            Self::SONAR_LABEL,
+            "cursor-sonar/index.html",
+            "Seelen Cursor Sonar",
+        )
+        .unfocused()
+        .click_through()
+        .build()
+    }
+
+    /// Shows an animated sonar centered on the cursor.
+    pub fn find() -> Result<()> {
+        let cursor = Mouse::get_cursor_pos()?;
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        let scale = WindowsApi::get_device_pixel_ratio(monitor)?;
+        let size = (Self::SONAR_SIZE * scale) as i32;
+        let left = cursor.get_x() - size / 2;
+        let top = cursor.get_y() - size / 2;
+
+        let generation = SONAR_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+        {
+            let mut sonar = trace_lock!(SONAR);
+            // a new overlay plays the animation once loaded, an existing one needs a ping
+            let created = sonar.is_none();
+            let window = match sonar.as_ref() {
+                Some(window) => window,
+                None => sonar.insert(Self::create_sonar()?),
+            };
+            WindowsApi::set_position(
+                HWND(window.hwnd()?.0),
+                Some(HWND_TOPMOST),
+                &RECT {
+                    left,
+                    top,
+                    right: left + size,
+                    bottom: top + size,
+                },
+                SWP_NOACTIVATE,
+            )?;
+            if !created {
+                window.emit("cursor-sonar-ping", ())?;
+            }
+        }
+
+        spawn_named_thread("Cursor Sonar", move || {
+            sleep_millis(Self::SONAR_DURATION);
+            if SONAR_GENERATION.load(Ordering::Acquire) != generation {
+                return;
+            }
+            if let Some(window) = trace_lock!(SONAR).take() {
+                log_error!(window.destroy());
+            }
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use serde::Serialize;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        media::application::MEDIA_MANAGER,
+        metrics::{Metrics, SystemUsage},
+        virtual_desk::get_vd_manager,
+    },
+    seelen::get_app_handle,
+    state::{
+        application::FULL_STATE,
+        domain::{DataBridgeField, DataExport, DataExportFormat},
+    },
+    trace_lock,
+    utils::{app_data_path, sleep_millis, spawn_named_thread},
+};
+
+/// true while the exporter thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NowPlaying {
+    title: String,
+    artist: String,
+    playing: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Workspaces {
+    /// 1-based, as shown to the user
+    active: usize,
+    active_name: String,
+    names: Vec<String>,
+}
+
+/// State shared by all the exports, each export picks the fields it wants.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Snapshot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    now_playing: Option<NowPlaying>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspaces: Option<Workspaces>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<SystemUsage>,
+}
+
+impl Snapshot {
+    fn take(fields: &[DataBridgeField]) -> Self {
+        let mut snapshot = Self::default();
+        if fields.contains(&DataBridgeField::NowPlaying) {
+            let manager = trace_lock!(MEDIA_MANAGER);
+            let players = manager.playing();
+            // the default player is the one controlled by the media keys
+            let player = players.iter().find(|p| p.default).or(players.first());
+            snapshot.now_playing = player.map(|p| NowPlaying {
+                title: p.title.clone(),
+                artist: p.author.clone(),
+                playing: p.playing,
+            });
+        }
+        if fields.contains(&DataBridgeField::Workspaces) {
+            snapshot.workspaces = Self::workspaces().ok();
+        }
+        if fields.contains(&DataBridgeField::System) {
+            Metrics::refresh();
+            snapshot.system = Some(Metrics::system());
+        }
+        snapshot
+    }
+
+    fn workspaces() -> Result<Workspaces> {
+        let vd = get_vd_manager();
+        let names: Vec<String> = vd
+            .get_all()?
+            .iter()
+            .enumerate()
+            .map(|(idx, desktop)| desktop.name().unwrap_or_else(|| format!("{}", idx + 1)))
+            .collect();
+        let active = vd.get_current_idx()?;
+        Ok(Workspaces {
+            active: active + 1,
+            active_name: names.get(active).cloned().unwrap_or_default(),
+            names,
+        })
+    }
+
+    /// copy with only the given fields, all if empty
+    fn only(&self, fields: &[DataBridgeField]) -> Self {
+        let wants = |field| fields.is_empty() || fields.contains(&field);
+        Self {
+            now_playing: self
+                .now_playing
+                .clone()
+                .filter(|_| wants(DataBridgeField::NowPlaying)),
+            workspaces: self
+                .workspaces
+                .clone()
+                .filter(|_| wants(DataBridgeField::Workspaces)),
+            system: self.system.filter(|_| wants(DataBridgeField::System)),
+        }
+    }
+
+    /// Variables to be used on skins with `@Include` and `DynamicVariables=1`. Missing
+    /// data is written as empty/zero so the skins don't keep showing stale values.
+    fn to_rainmeter(&self, fields: &[DataBridgeField]) -> String {
+        let wants = |field| fields.is_empty() || fields.contains(&field);
+        // values are single line, rainmeter has no escaping
+        let clean = |value: &str| value.replace(['\r', '\n'], " ");
+
+        let mut lines = vec!["[Variables]".to_string()];
+        if wants(DataBridgeField::NowPlaying) {
+            let player = self.now_playing.as_ref();
+            let title = player.map(|p| clean(&p.title)).unwrap_or_default();
+            let artist = player.map(|p| clean(&p.artist)).unwrap_or_default();
+            let state = player.is_some_and(|p| p.playing) as u8;
+            lines.push(format!("NowPlayingTitle={title}"));
+            lines.push(format!("NowPlayingArtist={artist}"));
+            lines.push(format!("NowPlayingState={state}"));
+        }
+        if wants(DataBridgeField::Workspaces) {
+            let workspaces = self.workspaces.as_ref();
+            let active = workspaces.map(|w| w.active).unwrap_or(0);
+            let name = workspaces
+                .map(|w| clean(&w.active_name))
+                .unwrap_or_default();
+            let count = workspaces.map(|w| w.names.len()).unwrap_or(0);
+            lines.push(format!("WorkspaceActive={active}"));
+            lines.push(format!("WorkspaceName={name}"));
+            lines.push(format!("WorkspaceCount={count}"));
+            for (idx, name) in workspaces.iter().flat_map(|w| w.names.iter()).enumerate() {
+                lines.push(format!("Workspace{}Name={}", idx + 1, clean(name)));
+            }
+        }
+        if wants(DataBridgeField::System) {
+            let system = self.system.as_ref();
+            let cpu = system.map(|s| s.cpu).unwrap_or(0.0);
+            let used = system.map(|s| s.used_memory).unwrap_or(0);
+            let total = system.map(|s| s.total_memory).unwrap_or(0);
+            let memory = if total > 0 {
+                used as f64 * 100.0 / total as f64
+            } else {
+                0.0
+            };
+            lines.push(format!("CpuUsage={cpu:.1}"));
+            lines.push(format!("MemoryUsage={memory:.1}"));
+            lines.push(format!("MemoryUsed={used}"));
+            lines.push(format!("MemoryTotal={total}"));
+        }
+        lines.push(String::new());
+        lines.join("\r\n")
+    }
+}
+
+/// Periodically writes parts of the state to files that desktop widget tools like
+/// Rainmeter or YASB can read, as configured on the `dataBridge` settings.
+pub struct DataBridge;
+
+impl DataBridge {
+    fn resolve(path: &str) -> PathBuf {
+        let path = PathBuf::from(path);
+        if path.is_absolute() {
+            return path;
+        }
+        app_data_path(&get_app_handle())
+            .join("data_bridge")
+            .join(path)
+    }
+
+    fn render(export: &DataExport, snapshot: &Snapshot) -> Result<String> {
+        let snapshot = snapshot.only(&export.data);
+        Ok(match export.format {
+            DataExportFormat::Json => serde_json::to_string_pretty(&snapshot)?,
+            DataExportFormat::Rainmeter => snapshot.to_rainmeter(&export.data),
+        })
+    }
+
+    /// Writes to a temporary file first so readers never get a partial file.
+    fn write(path: &PathBuf, format: DataExportFormat, contents: &str) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut temp = path.clone().into_os_string();
+        temp.push(".tmp");
+        // rainmeter only reads non-ascii characters from UTF-16 LE files with BOM
+        let bytes: Vec<u8> = match format {
+            DataExportFormat::Json => contents.as_bytes().to_vec(),
+            DataExportFormat::Rainmeter => {
+                let mut bytes = vec![0xFF, 0xFE];
+                bytes.extend(contents.encode_utf16().flat_map(|unit| unit.to_le_bytes()));
+                bytes
+            }
+        };
+        std::fs::write(&temp, bytes)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    fn export_all(last_written: &mut HashMap<PathBuf, String>) -> Result<()> {
+        let settings = FULL_STATE.load().settings().data_bridge.clone();
+        // empty field lists mean every field
+        let mut fields: Vec<DataBridgeField> = Vec::new();
+        for export in &settings.exports {
+            if export.data.is_empty() {
+                fields = vec![
+                    DataBridgeField::NowPlaying,
+                    DataBridgeField::Workspaces,
+                    DataBridgeField::System,
+                ];
+                break;
+            }
+            fields.extend(export.data.iter().copied());
+        }
+        let snapshot = Snapshot::take(&fields);
+
+        for export in &settings.exports {
+            let path = Self::resolve(&export.path);
+            let contents = Self::render(export, &snapshot)?;
+            if last_written.get(&path) == Some(&contents) {
+                continue;
+            }
+            Self::write(&path, export.format, &contents)?;
+            last_written.insert(path, contents);
+        }
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting data bridge");
+
+        spawn_named_thread("Data Bridge", || {
+            let mut last_written = HashMap::new();
+            while RUNNING.load(Ordering::Acquire) {
+                log_error!(Self::export_all(&mut last_written));
+                let interval = FULL_STATE.load().settings().data_bridge.interval.max(1);
+                sleep_millis(interval * 1000);
+            }
+            log::trace!("Data bridge stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    utils::{app_cache_path, sleep_millis},
+};
+
+use super::{DataSource, DataSources, PollLoop};
+
+/// Definition of a json data source, as written on `data_sources/*.yml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchDefinition {
+    name: String,
+    /// requested with GET, the response should be json
+    url: String,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    /// seconds between requests
+    #[serde(default = "FetchDefinition::default_interval")]
+    interval: u64,
+    /// fields of the value, each one is a dotted path on the response as `current.temp`
+    /// or a template as `{{current.temp}}°C`. The whole response is used if empty.
+    #[serde(default)]
+    fields: HashMap<String, String>,
+}
+
+impl FetchDefinition {
+    fn default_interval() -> u64 {
+        600
+    }
+}
+
+/// Last response, kept on disk to show something while offline or after a restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResponse {
+    fetched_at: u64,
+    response: Value,
+}
+
+pub struct FetchDataSource {
+    definition: FetchDefinition,
+    poll: PollLoop,
+}
+
+impl FetchDataSource {
+    /// a hung server would keep the poll loop waiting, even after the source is stopped
+    const TIMEOUT: Duration = Duration::from_secs(30);
+
+    pub fn new(definition: FetchDefinition) -> Self {
+        Self {
+            definition,
+            poll: PollLoop::default(),
+        }
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    fn cache_path(name: &str) -> Result<PathBuf> {
+        let filename: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        Ok(app_cache_path(&get_app_handle())?
+            .join("data_sources")
+            .join(format!("{}.json", filename)))
+    }
+
+    fn load_cached(name: &str) -> Option<CachedResponse> {
+        let contents = std::fs::read_to_string(Self::cache_path(name).ok()?).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn save_cached(name: &str, cached: &CachedResponse) -> Result<()> {
+        let path = Self::cache_path(name)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(cached)?)?;
+        Ok(())
+    }
+
+    /// Resolves paths like `list.0.name`, numbers index arrays
+    fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+        path.split('.')
+            .filter(|key| !key.is_empty())
+            .try_fold(value, |value, key| match value {
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?),
+                _ => value.get(key),
+            })
+    }
+
+    /// Replaces the `{{path}}` placeholders, missing paths are replaced by an empty string
+    fn render(template: &str, response: &Value) -> String {
+        let mut result = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start..].find("}}") else {
+                break;
+            };
+            result.push_str(&rest[..start]);
+            let path = rest[start + 2..start + end].trim();
+            match Self::lookup(response, path) {
+                Some(Value::String(text)) => result.push_str(text),
+                Some(Value::Null) | None => {}
+                Some(other) => result.push_str(&other.to_string()),
+            }
+            rest = &rest[start + end + 2..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    fn output(definition: &FetchDefinition, response: &Value) -> Value {
+        if definition.fields.is_empty() {
+            return response.clone();
+        }
+        let fields = definition
+            .fields
+            .iter()
+            .map(|(key, field)| {
+                let value = if field.contains("{{") {
+                    Value::String(Self::render(field, response))
+                } else {
+                    Self::lookup(response, field)
+                        .cloned()
+                        .unwrap_or(Value::Null)
+                };
+                (key.clone(), value)
+            })
+            .collect();
+        Value::Object(fields)
+    }
+
+    fn fetch(definition: &FetchDefinition) -> Result<Value> {
+        log::trace!("Fetching data source {}", definition.name);
+        let response = tauri::async_runtime::block_on(async {
+            let client = tauri_plugin_http::reqwest::Client::builder()
+                .timeout(Self::TIMEOUT)
+                .build()?;
+            let mut request = client.get(&definition.url);
+            for (key, value) in &definition.headers {
+                request = request.header(key, value);
+            }
+            request
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<Value>()
+                .await
+        })?;
+        Ok(response)
+    }
+
+    fn refresh(definition: &FetchDefinition) -> Result<()> {
+        let cached = CachedResponse {
+            fetched_at: Self::now(),
+            response: Self::fetch(definition)?,
+        };
+        log_error!(Self::save_cached(&definition.name, &cached));
+        DataSources::update(&definition.name, Self::output(definition, &cached.response))
+    }
+}
+
+impl DataSource for FetchDataSource {
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn start(&self) -> Result<()> {
+        let definition = self.definition.clone();
+        let interval = definition.interval.max(1);
+        self.poll.spawn(&self.definition.name, move |token| {
+            // the cached response is shown even if outdated, until a new one is fetched
+            if let Some(cached) = Self::load_cached(&definition.name) {
+                let output = Self::output(&definition, &cached.response);
+                log_error!(DataSources::update(&definition.name, output));
+                let age = Self::now().saturating_sub(cached.fetched_at);
+                if age < interval {
+                    sleep_millis((interval - age).saturating_mul(1000));
+                }
+            }
+            while token.is_current() {
+                log_error!(Self::refresh(&definition));
+                sleep_millis(interval.saturating_mul(1000));
+            }
+        })
+    }
+
+    fn stop(&self) {
+        self.poll.stop();
+    }
+
+    fn action(&self, action: &str, _args: &[Value]) -> Result<()> {
+        match action {
+            "refresh" => Self::refresh(&self.definition),
+            _ => Err(format!("{} has no action named {}", self.name(), action).into()),
+        }
+    }
+}
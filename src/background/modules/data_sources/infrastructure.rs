@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::error_handler::Result;
+
+use super::DataSources;
+
+#[tauri::command(async)]
+pub fn data_sources_get() -> HashMap<String, Value> {
+    DataSources::values()
+}
+
+#[tauri::command(async)]
+pub fn data_source_action(name: String, action: String, args: Vec<Value>) -> Result<()> {
+    DataSources::action(&name, &action, &args)
+}
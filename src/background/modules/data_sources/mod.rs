@@ -0,0 +1,228 @@
+mod fetch;
+pub mod infrastructure;
+mod script;
+mod world_clock;
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{app_data_path, spawn_named_thread},
+};
+
+use fetch::{FetchDataSource, FetchDefinition};
+use script::{ScriptDataSource, ScriptDefinition};
+use world_clock::{WorldClockDataSource, WorldClockDefinition};
+
+/// Provider of a named value the toolbar items can read as `sources.<name>`,
+/// loaded from the `data_sources` folder of the user data.
+pub trait DataSource: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Starts producing values, each new one should be published with `DataSources::update`.
+    fn start(&self) -> Result<()>;
+
+    /// Stops producing values, the source can be started again later.
+    fn stop(&self);
+
+    /// Called by the items through `sourceAction(name, action, ...args)`
+    fn action(&self, action: &str, _args: &[Value]) -> Result<()> {
+        Err(format!("{} has no action named {}", self.name(), action).into())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataSourceUpdate<'a> {
+    name: &'a str,
+    value: &'a Value,
+}
+
+/// Definition of a user source, as written on `data_sources/*.yml`. The kind is taken from
+/// its fields: `command` for scripts, `url` for json requests and `clocks` for world clocks.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum SourceDefinition {
+    Script(ScriptDefinition),
+    Fetch(FetchDefinition),
+    WorldClock(WorldClockDefinition),
+}
+
+impl SourceDefinition {
+    fn into_source(self) -> Arc<dyn DataSource> {
+        match self {
+            Self::Script(definition) => Arc::new(ScriptDataSource::new(definition)),
+            Self::Fetch(definition) => Arc::new(FetchDataSource::new(definition)),
+            Self::WorldClock(definition) => Arc::new(WorldClockDataSource::new(definition)),
+        }
+    }
+}
+
+/// Running state of a source polling on its own thread
+#[derive(Default)]
+struct PollLoop {
+    running: AtomicBool,
+    generation: Arc<AtomicU32>,
+}
+
+/// Owned by the polling thread, invalidated when the loop is stopped
+struct PollToken {
+    generation: Arc<AtomicU32>,
+    value: u32,
+}
+
+impl PollToken {
+    /// False once the loop was stopped, even if it was started again afterwards
+    fn is_current(&self) -> bool {
+        self.generation.load(Ordering::Acquire) == self.value
+    }
+}
+
+impl PollLoop {
+    /// Runs `poll` on a new thread, does nothing if the loop is already running.
+    /// `poll` should exit as soon as the token is not current anymore.
+    fn spawn<F>(&self, name: &str, poll: F) -> Result<()>
+    where
+        F: FnOnce(PollToken) + Send + 'static,
+    {
+        if self.running.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        let token = PollToken {
+            generation: self.generation.clone(),
+            value: self.generation.load(Ordering::Acquire),
+        };
+        if let Err(err) = spawn_named_thread(&format!("Data Source {}", name), move || poll(token))
+        {
+            self.running.store(false, Ordering::Release);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn stop(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        self.running.store(false, Ordering::Release);
+    }
+}
+
+lazy_static! {
+    static ref SOURCES: Mutex<HashMap<String, Arc<dyn DataSource>>> = Mutex::new(HashMap::new());
+    static ref VALUES: Mutex<HashMap<String, Value>> = Mutex::new(HashMap::new());
+}
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+pub struct DataSources;
+
+impl DataSources {
+    const FOLDER: &str = "data_sources";
+
+    fn load(path: &Path) -> Result<Arc<dyn DataSource>> {
+        let definition: SourceDefinition = serde_yaml::from_str(&std::fs::read_to_string(path)?)?;
+        Ok(definition.into_source())
+    }
+
+    /// invalid files are logged and skipped to not break the other sources
+    fn load_user_sources() -> Vec<Arc<dyn DataSource>> {
+        let folder = app_data_path(&get_app_handle()).join(Self::FOLDER);
+        let Ok(entries) = std::fs::read_dir(folder) else {
+            return Vec::new();
+        };
+
+        let mut sources = Vec::new();
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let is_yaml = path
+                .extension()
+                .is_some_and(|ext| ext == "yml" || ext == "yaml");
+            if !is_yaml {
+                continue;
+            }
+            match Self::load(&path) {
+                Ok(source) => sources.push(source),
+                Err(err) => log::error!("Failed to load data source {:?}: {:?}", path, err),
+            }
+        }
+        sources
+    }
+
+    pub fn update(name: &str, value: Value) -> Result<()> {
+        let mut values = trace_lock!(VALUES);
+        if values.get(name) == Some(&value) {
+            return Ok(());
+        }
+        get_app_handle().emit(
+            "data-source-changed",
+            DataSourceUpdate {
+                name,
+                value: &value,
+            },
+        )?;
+        values.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    pub fn values() -> HashMap<String, Value> {
+        trace_lock!(VALUES).clone()
+    }
+
+    pub fn action(name: &str, action: &str, args: &[Value]) -> Result<()> {
+        let source = trace_lock!(SOURCES)
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("data source {} not found", name))?;
+        source.action(action, args)
+    }
+
+    /// Loads the user sources and starts them, calls after the first one have no effect
+    /// until `stop` is called.
+    pub fn start() -> Result<()> {
+        let mut sources = trace_lock!(SOURCES);
+        if STARTED.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting data sources");
+
+        for source in Self::load_user_sources() {
+            let name = source.name().to_string();
+            if sources.insert(name.clone(), source).is_some() {
+                log::warn!(
+                    "Data source {} is defined twice, the last one is used",
+                    name
+                );
+            }
+        }
+        for source in sources.values() {
+            log_error!(source.start());
+        }
+        Ok(())
+    }
+
+    /// Stops and unloads the sources, the files are read again on the next `start`
+    pub fn stop() {
+        let mut sources = trace_lock!(SOURCES);
+        if !STARTED.swap(false, Ordering::AcqRel) {
+            return;
+        }
+        log::trace!("Stopping data sources");
+        for source in sources.values() {
+            source.stop();
+        }
+        sources.clear();
+    }
+}
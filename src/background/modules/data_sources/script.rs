@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+use tauri_plugin_shell::ShellExt;
+
+use crate::{error_handler::Result, log_error, seelen::get_app_handle, utils::sleep_millis};
+
+use super::{DataSource, DataSources, PollLoop};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScriptCommand {
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+/// Definition of a script data source, as written on `data_sources/*.yml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptDefinition {
+    name: String,
+    /// the standard output is parsed as json, if it fails it is used as plain text
+    #[serde(flatten)]
+    source: ScriptCommand,
+    /// seconds between runs of the command
+    #[serde(default = "ScriptDefinition::default_interval")]
+    interval: u64,
+    /// commands runnable from the items, the action arguments are appended to the command ones
+    #[serde(default)]
+    actions: HashMap<String, ScriptCommand>,
+}
+
+impl ScriptDefinition {
+    fn default_interval() -> u64 {
+        60
+    }
+}
+
+pub struct ScriptDataSource {
+    definition: ScriptDefinition,
+    poll: PollLoop,
+}
+
+impl ScriptDataSource {
+    pub fn new(definition: ScriptDefinition) -> Self {
+        Self {
+            definition,
+            poll: PollLoop::default(),
+        }
+    }
+
+    fn execute(command: &ScriptCommand, extra_args: &[String]) -> Result<String> {
+        let output = tauri::async_runtime::block_on(
+            get_app_handle()
+                .shell()
+                .command(&command.command)
+                .args(&command.args)
+                .args(extra_args)
+                .output(),
+        )?;
+        if !output.status.success() {
+            return Err(output.into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn refresh(definition: &ScriptDefinition) -> Result<()> {
+        let stdout = Self::execute(&definition.source, &[])?;
+        let value = serde_json::from_str(&stdout).unwrap_or(Value::String(stdout));
+        DataSources::update(&definition.name, value)
+    }
+}
+
+impl DataSource for ScriptDataSource {
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn start(&self) -> Result<()> {
+        let definition = self.definition.clone();
+        let interval = definition.interval.max(1).saturating_mul(1000);
+        self.poll.spawn(&self.definition.name, move |token| {
+            while token.is_current() {
+                log_error!(Self::refresh(&definition));
+                sleep_millis(interval);
+            }
+        })
+    }
+
+    fn stop(&self) {
+        self.poll.stop();
+    }
+
+    fn action(&self, action: &str, args: &[Value]) -> Result<()> {
+        let command = self
+            .definition
+            .actions
+            .get(action)
+            .ok_or_else(|| format!("{} has no action named {}", self.name(), action))?;
+        let args: Vec<String> = args
+            .iter()
+            .map(|arg| match arg {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        Self::execute(command, &args)?;
+        // actions usually change the state of the source
+        Self::refresh(&self.definition)
+    }
+}
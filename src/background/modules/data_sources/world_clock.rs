@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use windows::Win32::{
+    Foundation::{FILETIME, SYSTEMTIME},
+    System::{
+        SystemInformation::GetSystemTime,
+        Time::{
+            EnumDynamicTimeZoneInformation, GetTimeZoneInformationForYear, SystemTimeToFileTime,
+            SystemTimeToTzSpecificLocalTimeEx, DYNAMIC_TIME_ZONE_INFORMATION,
+            TIME_ZONE_INFORMATION,
+        },
+    },
+};
+
+use crate::{error_handler::Result, log_error, utils::sleep_millis};
+
+use super::{DataSource, DataSources, PollLoop};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClockDefinition {
+    label: String,
+    /// windows time zone id, ex: `Tokyo Standard Time`, listed by `tzutil /l`
+    time_zone: String,
+}
+
+/// Definition of a world clock data source, as written on `data_sources/*.yml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldClockDefinition {
+    name: String,
+    clocks: Vec<ClockDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorldClock {
+    label: String,
+    time_zone: String,
+    /// local time of the zone as `YYYY-MM-DDTHH:MM:SS`
+    date_time: String,
+    /// minutes ahead of UTC, daylight saving time included
+    utc_offset: i64,
+    is_dst: bool,
+}
+
+pub struct WorldClockDataSource {
+    definition: WorldClockDefinition,
+    poll: PollLoop,
+}
+
+impl WorldClockDataSource {
+    pub fn new(definition: WorldClockDefinition) -> Self {
+        Self {
+            definition,
+            poll: PollLoop::default(),
+        }
+    }
+
+    fn key_name(zone: &DYNAMIC_TIME_ZONE_INFORMATION) -> String {
+        String::from_utf16_lossy(&zone.TimeZoneKeyName)
+            .trim_end_matches('\0')
+            .to_string()
+    }
+
+    /// The dynamic information includes the dst rules of each year, unlike the static one
+    fn find_zone(id: &str) -> Option<DYNAMIC_TIME_ZONE_INFORMATION> {
+        let mut idx = 0;
+        loop {
+            let mut zone = DYNAMIC_TIME_ZONE_INFORMATION::default();
+            // returns ERROR_NO_MORE_ITEMS after the last zone
+            if unsafe { EnumDynamicTimeZoneInformation(idx, &mut zone) } != 0 {
+                return None;
+            }
+            if Self::key_name(&zone).eq_ignore_ascii_case(id) {
+                return Some(zone);
+            }
+            idx += 1;
+        }
+    }
+
+    fn minutes(time: &SYSTEMTIME) -> Result<i64> {
+        let mut file_time = FILETIME::default();
+        unsafe { SystemTimeToFileTime(time, &mut file_time)? };
+        let ticks = ((file_time.dwHighDateTime as i64) << 32) | file_time.dwLowDateTime as i64;
+        // filetime ticks are 100 nanoseconds
+        Ok(ticks / 10_000_000 / 60)
+    }
+
+    fn clock(definition: &ClockDefinition, utc: &SYSTEMTIME) -> Result<WorldClock> {
+        let zone = Self::find_zone(&definition.time_zone)
+            .ok_or_else(|| format!("Unknown time zone: {}", definition.time_zone))?;
+
+        let mut local = SYSTEMTIME::default();
+        unsafe { SystemTimeToTzSpecificLocalTimeEx(Some(&zone), utc, &mut local)? };
+        let utc_offset = Self::minutes(&local)? - Self::minutes(utc)?;
+
+        // biases of the current year, they can change between years on dynamic zones
+        let mut year = TIME_ZONE_INFORMATION::default();
+        unsafe { GetTimeZoneInformationForYear(local.wYear, Some(&zone), &mut year)? };
+        let is_dst =
+            year.DaylightBias != 0 && utc_offset == -(year.Bias as i64 + year.DaylightBias as i64);
+
+        Ok(WorldClock {
+            label: definition.label.clone(),
+            time_zone: Self::key_name(&zone),
+            date_time: format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                local.wYear, local.wMonth, local.wDay, local.wHour, local.wMinute, local.wSecond
+            ),
+            utc_offset,
+            is_dst,
+        })
+    }
+
+    fn refresh(definition: &WorldClockDefinition) -> Result<()> {
+        let utc = unsafe { GetSystemTime() };
+        let mut clocks = Vec::with_capacity(definition.clocks.len());
+        for clock in &definition.clocks {
+            match Self::clock(clock, &utc) {
+                Ok(clock) => clocks.push(clock),
+                Err(err) => log::error!("Invalid world clock {}: {}", clock.label, err),
+            }
+        }
+        DataSources::update(&definition.name, serde_json::to_value(clocks)?)
+    }
+}
+
+impl DataSource for WorldClockDataSource {
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn start(&self) -> Result<()> {
+        let definition = self.definition.clone();
+        self.poll.spawn(&self.definition.name, move |token| {
+            while token.is_current() {
+                log_error!(Self::refresh(&definition));
+                // wakes up at the start of the next minute
+                let now = unsafe { GetSystemTime() };
+                let elapsed = now.wSecond as u64 * 1000 + now.wMilliseconds as u64;
+                sleep_millis(60_000u64.saturating_sub(elapsed).max(1000));
+            }
+        })
+    }
+
+    fn stop(&self) {
+        self.poll.stop();
+    }
+}
@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Cell of the grid of a monitor where an icon was placed by the user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DesktopIconPosition {
+    pub monitor: String,
+    pub column: u32,
+    pub row: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DesktopItem {
+    pub path: PathBuf,
+    /// file name, without extension for shortcuts as the Explorer does
+    pub name: String,
+    pub is_folder: bool,
+    pub icon: Option<PathBuf>,
+    /// None if the item was never moved, the webview places it on the first free cell
+    pub position: Option<DesktopIconPosition>,
+}
@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use crate::{error_handler::Result, windows_api::WindowsApi};
+
+use super::{
+    domain::{DesktopIconPosition, DesktopItem},
+    DesktopIcons,
+};
+
+#[tauri::command(async)]
+pub fn desktop_icons_get_items(monitor: String) -> Result<Vec<DesktopItem>> {
+    DesktopIcons::items(&monitor)
+}
+
+#[tauri::command(async)]
+pub fn desktop_icons_set_position(path: PathBuf, position: DesktopIconPosition) -> Result<()> {
+    DesktopIcons::set_item_position(&path, position)
+}
+
+#[tauri::command(async)]
+pub fn desktop_icons_open(path: String) -> Result<()> {
+    WindowsApi::shell_open(&path)
+}
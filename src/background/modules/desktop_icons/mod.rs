@@ -0,0 +1,228 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::HashMap,
+    os::windows::fs::MetadataExt,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use domain::{DesktopIconPosition, DesktopItem};
+use lazy_static::lazy_static;
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{ReadDirectoryChangesWatcher, RecursiveMode, Watcher},
+    DebounceEventResult, Debouncer, FileIdMap,
+};
+use parking_lot::Mutex;
+use tauri::{Emitter, Manager, WebviewWindow, WindowEvent, Wry};
+use windows::Win32::{
+    Foundation::HWND,
+    Graphics::Gdi::HMONITOR,
+    Storage::FileSystem::{FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_SYSTEM},
+    UI::WindowsAndMessaging::{HWND_BOTTOM, SWP_NOACTIVATE, SW_SHOWNOACTIVATE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::{get_app_handle, SEELEN},
+    seelen_weg::icon_extractor::extract_and_save_shell_icon,
+    trace_lock,
+    utils::{app_data_path, overlay::OverlayBuilder},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref POSITIONS: Arc<Mutex<HashMap<String, DesktopIconPosition>>> = Arc::new(Mutex::new(
+        DesktopIcons::load_positions().unwrap_or_default()
+    ));
+    static ref WATCHER: Arc<Mutex<Option<Debouncer<ReadDirectoryChangesWatcher, FileIdMap>>>> =
+        Arc::new(Mutex::new(None));
+}
+
+/// amount of desktop icons windows alive, the folder watcher lives while there is one
+static INSTANCES: AtomicUsize = AtomicUsize::new(0);
+
+/// Desktop icons rendered by Seelen UI, one window per monitor placed at the bottom of
+/// the z-order. Needed when running as the shell, but can also be used alongside explorer.
+pub struct DesktopIcons {
+    window: WebviewWindow<Wry>,
+}
+
+impl Drop for DesktopIcons {
+    fn drop(&mut self) {
+        log_error!(self.window.destroy());
+        if INSTANCES.fetch_sub(1, Ordering::SeqCst) == 1 {
+            trace_lock!(WATCHER).take();
+        }
+    }
+}
+
+impl DesktopIcons {
+    const TARGET: &'static str = "desktop-icons";
+    const POSITIONS_FILE: &'static str = "desktop_icons.yml";
+
+    pub fn new(postfix: &str) -> Result<Self> {
+        let window = Self::create_window(postfix)?;
+        if INSTANCES.fetch_add(1, Ordering::SeqCst) == 0 {
+            log_error!(Self::start_watcher());
+        }
+        Ok(Self { window })
+    }
+
+    fn create_window(postfix: &str) -> Result<WebviewWindow<Wry>> {
+        let window = OverlayBuilder::new(
+            &format!("{}/{}", Self::TARGET, postfix),
+            "desktop-icons/index.html",
+            "Seelen Desktop",
+        )
+        .not_topmost()
+        .build()?;
+
+        // clicking the icons activates the window, it should stay below any other window
+        let hwnd = HWND(window.hwnd()?.0);
+        window.on_window_event(move |event| {
+            if let WindowEvent::Focused(true) = event {
+                log_error!(WindowsApi::bring_to(hwnd, HWND_BOTTOM));
+            }
+        });
+        Ok(window)
+    }
+
+    pub fn set_position(&self, monitor: HMONITOR) -> Result<()> {
+        let hwnd = HWND(self.window.hwnd()?.0);
+        let rect = WindowsApi::monitor_rect(monitor)?;
+        WindowsApi::set_position(hwnd, Some(HWND_BOTTOM), &rect, SWP_NOACTIVATE)?;
+        WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+        Ok(())
+    }
+
+    fn folders() -> Vec<PathBuf> {
+        let mut folders = Vec::new();
+        if let Ok(user) = get_app_handle().path().desktop_dir() {
+            folders.push(user);
+        }
+        if let Ok(public) = std::env::var("PUBLIC") {
+            folders.push(PathBuf::from(public).join("Desktop"));
+        }
+        folders
+    }
+
+    fn is_hidden(path: &Path) -> bool {
+        let hidden = FILE_ATTRIBUTE_HIDDEN.0 | FILE_ATTRIBUTE_SYSTEM.0;
+        path.metadata()
+            .is_ok_and(|meta| meta.file_attributes() & hidden != 0)
+    }
+
+    /// Items of the user and public desktop folders shown on the monitor, items without
+    /// position or placed on a disconnected monitor are shown on the primary monitor.
+    pub fn items(monitor: &str) -> Result<Vec<DesktopItem>> {
+        let handle = get_app_handle();
+        let positions = trace_lock!(POSITIONS).clone();
+        let primary = WindowsApi::monitor_name(WindowsApi::primary_monitor())?;
+        let connected = trace_lock!(SEELEN)
+            .monitors()
+            .iter()
+            .map(|m| m.name().clone())
+            .collect::<Vec<String>>();
+
+        let mut items = Vec::new();
+        for folder in Self::folders() {
+            let Ok(entries) = std::fs::read_dir(&folder) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if Self::is_hidden(&path) {
+                    continue;
+                }
+
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                let position = positions
+                    .get(&file_name)
+                    .filter(|p| connected.contains(&p.monitor))
+                    .cloned();
+                let shown_here = match &position {
+                    Some(position) => position.monitor == monitor,
+                    None => primary == monitor,
+                };
+                if !shown_here {
+                    continue;
+                }
+
+                let is_shortcut = path
+                    .extension()
+                    .is_some_and(|ext| ext == "lnk" || ext == "url");
+                let name = match is_shortcut {
+                    true => path
+                        .file_stem()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string(),
+                    false => file_name,
+                };
+
+                items.push(DesktopItem {
+                    icon: extract_and_save_shell_icon(&handle, &path).ok(),
+                    is_folder: path.is_dir(),
+                    name,
+                    path,
+                    position,
+                });
+            }
+        }
+        items.sort_by_key(|item| (!item.is_folder, item.name.to_lowercase()));
+        Ok(items)
+    }
+
+    fn positions_path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::POSITIONS_FILE)
+    }
+
+    fn load_positions() -> Result<HashMap<String, DesktopIconPosition>> {
+        let path = Self::positions_path();
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+        Ok(serde_yaml::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Stores the cell where the user dropped the item, keyed by file name
+    pub fn set_item_position(path: &Path, position: DesktopIconPosition) -> Result<()> {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let mut positions = trace_lock!(POSITIONS);
+        positions.insert(file_name.to_string(), position);
+        std::fs::write(Self::positions_path(), serde_yaml::to_string(&*positions)?)?;
+        drop(positions);
+        get_app_handle().emit("desktop-icons-changed", ())?;
+        Ok(())
+    }
+
+    fn start_watcher() -> Result<()> {
+        let mut debouncer = new_debouncer(
+            Duration::from_millis(300),
+            None,
+            |result: DebounceEventResult| match result {
+                Ok(_) => log_error!(get_app_handle().emit("desktop-icons-changed", ())),
+                Err(errors) => errors
+                    .iter()
+                    .for_each(|e| log::error!("Desktop Watcher Error: {:?}", e)),
+            },
+        )?;
+        for folder in Self::folders() {
+            if folder.exists() {
+                debouncer
+                    .watcher()
+                    .watch(&folder, RecursiveMode::NonRecursive)?;
+            }
+        }
+        *trace_lock!(WATCHER) = Some(debouncer);
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovableDrive {
+    /// drive letter with colon, ex: `E:`
+    pub letter: String,
+    pub label: String,
+    /// true for usb hard drives, reported as fixed drives by windows
+    pub is_usb_disk: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DeviceChange {
+    /// a storage volume was mounted, ex: usb stick or sd card
+    VolumeArrival {
+        letter: String,
+    },
+    VolumeRemoval {
+        letter: String,
+    },
+    /// a usb device was connected, `id` is its device interface path
+    UsbArrival {
+        id: String,
+    },
+    UsbRemoval {
+        id: String,
+    },
+}
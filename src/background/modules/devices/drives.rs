@@ -0,0 +1,175 @@
+use std::ffi::c_void;
+
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::{CloseHandle, BOOLEAN, GENERIC_READ, GENERIC_WRITE, HANDLE},
+        Storage::FileSystem::{
+            CreateFileW, GetDriveTypeW, GetLogicalDrives, GetVolumeInformationW,
+            FILE_FLAGS_AND_ATTRIBUTES, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+        },
+        System::{
+            Ioctl::{
+                BusTypeUsb, PropertyStandardQuery, StorageDeviceProperty, FSCTL_DISMOUNT_VOLUME,
+                FSCTL_LOCK_VOLUME, IOCTL_STORAGE_EJECT_MEDIA, IOCTL_STORAGE_MEDIA_REMOVAL,
+                IOCTL_STORAGE_QUERY_PROPERTY, PREVENT_MEDIA_REMOVAL, STORAGE_DEVICE_DESCRIPTOR,
+                STORAGE_PROPERTY_QUERY,
+            },
+            WindowsProgramming::{DRIVE_FIXED, DRIVE_REMOVABLE},
+            IO::DeviceIoControl,
+        },
+    },
+};
+
+use crate::{error_handler::Result, utils::sleep_millis};
+
+use super::domain::RemovableDrive;
+
+/// Volume handle closed on drop
+struct Volume(HANDLE);
+
+impl Volume {
+    fn open(letter: &str, access: u32) -> Result<Self> {
+        let path = HSTRING::from(format!(r"\\.\{}", letter));
+        let handle = unsafe {
+            CreateFileW(
+                &path,
+                access,
+                FILE_SHARE_READ | FILE_SHARE_WRITE,
+                None,
+                OPEN_EXISTING,
+                FILE_FLAGS_AND_ATTRIBUTES(0),
+                None,
+            )?
+        };
+        Ok(Self(handle))
+    }
+
+    fn control(&self, code: u32, input: Option<(*const c_void, u32)>) -> Result<()> {
+        let (in_buffer, in_size) = match input {
+            Some((buffer, size)) => (Some(buffer), size),
+            None => (None, 0),
+        };
+        let mut returned = 0;
+        unsafe {
+            DeviceIoControl(
+                self.0,
+                code,
+                in_buffer,
+                in_size,
+                None,
+                0,
+                Some(&mut returned),
+                None,
+            )?
+        };
+        Ok(())
+    }
+
+    fn is_usb(&self) -> bool {
+        let query = STORAGE_PROPERTY_QUERY {
+            PropertyId: StorageDeviceProperty,
+            QueryType: PropertyStandardQuery,
+            ..Default::default()
+        };
+        let mut descriptor = STORAGE_DEVICE_DESCRIPTOR::default();
+        let mut returned = 0;
+        let result = unsafe {
+            DeviceIoControl(
+                self.0,
+                IOCTL_STORAGE_QUERY_PROPERTY,
+                Some(&query as *const _ as *const c_void),
+                std::mem::size_of::<STORAGE_PROPERTY_QUERY>() as u32,
+                Some(&mut descriptor as *mut _ as *mut c_void),
+                std::mem::size_of::<STORAGE_DEVICE_DESCRIPTOR>() as u32,
+                Some(&mut returned),
+                None,
+            )
+        };
+        result.is_ok() && descriptor.BusType == BusTypeUsb
+    }
+}
+
+impl Drop for Volume {
+    fn drop(&mut self) {
+        let _ = unsafe { CloseHandle(self.0) };
+    }
+}
+
+fn drive_label(root: &HSTRING) -> String {
+    let mut label = [0u16; 261];
+    match unsafe { GetVolumeInformationW(root, Some(&mut label), None, None, None, None) } {
+        Ok(_) => String::from_utf16_lossy(&label)
+            .trim_end_matches('\0')
+            .to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Mounted drives that can be safely removed: removable media and usb hard drives
+pub fn removable_drives() -> Vec<RemovableDrive> {
+    let mask = unsafe { GetLogicalDrives() };
+    let mut drives = Vec::new();
+    for (idx, letter) in ('A'..='Z').enumerate() {
+        if mask & (1 << idx) == 0 {
+            continue;
+        }
+        let letter = format!("{}:", letter);
+        let root = HSTRING::from(format!(r"{}\", letter));
+        let is_usb_disk = match unsafe { GetDriveTypeW(&root) } {
+            DRIVE_REMOVABLE => false,
+            DRIVE_FIXED => match Volume::open(&letter, 0) {
+                Ok(volume) if volume.is_usb() => true,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        drives.push(RemovableDrive {
+            label: drive_label(&root),
+            letter,
+            is_usb_disk,
+        });
+    }
+    drives
+}
+
+/// Flushes, dismounts and ejects the volume, same as the "Eject" option of the explorer.
+/// `letter` can be given as `E`, `E:` or `E:\`.
+pub fn eject_drive(letter: &str) -> Result<()> {
+    let letter = match letter.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => format!("{}:", c.to_ascii_uppercase()),
+        _ => return Err(format!("Invalid drive letter: {}", letter).into()),
+    };
+    if !removable_drives().iter().any(|d| d.letter == letter) {
+        return Err(format!("{} is not a removable drive", letter).into());
+    }
+
+    let volume = Volume::open(&letter, (GENERIC_READ | GENERIC_WRITE).0)?;
+
+    // the lock fails while other processes have open files on the volume
+    let mut attempts = 0;
+    loop {
+        match volume.control(FSCTL_LOCK_VOLUME, None) {
+            Ok(()) => break,
+            Err(err) if attempts >= 10 => return Err(err),
+            Err(_) => {
+                attempts += 1;
+                sleep_millis(300);
+            }
+        }
+    }
+
+    volume.control(FSCTL_DISMOUNT_VOLUME, None)?;
+    let prevent = PREVENT_MEDIA_REMOVAL {
+        PreventMediaRemoval: BOOLEAN(0),
+    };
+    volume.control(
+        IOCTL_STORAGE_MEDIA_REMOVAL,
+        Some((
+            &prevent as *const _ as *const c_void,
+            std::mem::size_of::<PREVENT_MEDIA_REMOVAL>() as u32,
+        )),
+    )?;
+    volume.control(IOCTL_STORAGE_EJECT_MEDIA, None)?;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use crate::error_handler::Result;
+
+use super::domain::RemovableDrive;
+
+#[tauri::command(async)]
+pub fn get_removable_drives() -> Vec<RemovableDrive> {
+    super::removable_drives()
+}
+
+#[tauri::command(async)]
+pub fn eject_drive(letter: String) -> Result<()> {
+    super::eject_drive(&letter)
+}
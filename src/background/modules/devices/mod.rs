@@ -0,0 +1,160 @@
+pub mod domain;
+mod drives;
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use domain::DeviceChange;
+use tauri::Emitter;
+use widestring::U16CStr;
+use windows::{
+    core::w,
+    Win32::{
+        Devices::Usb::GUID_DEVINTERFACE_USB_DEVICE,
+        Foundation::{HANDLE, HWND, LPARAM, LRESULT, WPARAM},
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, PostQuitMessage,
+            RegisterClassW, RegisterDeviceNotificationW, TranslateMessage, DBT_DEVICEARRIVAL,
+            DBT_DEVICEREMOVECOMPLETE, DBT_DEVTYP_DEVICEINTERFACE, DBT_DEVTYP_VOLUME,
+            DEVICE_NOTIFY_WINDOW_HANDLE, DEV_BROADCAST_DEVICEINTERFACE_W, DEV_BROADCAST_HDR,
+            DEV_BROADCAST_VOLUME, MSG, WINDOW_EX_STYLE, WINDOW_STYLE, WM_DESTROY, WM_DEVICECHANGE,
+            WNDCLASSW,
+        },
+    },
+};
+
+pub use drives::{eject_drive, removable_drives};
+
+use crate::{
+    error_handler::Result, log_error, modules::audio_profiles::AudioProfiles,
+    seelen::get_app_handle, utils::spawn_named_thread, windows_api::WindowsApi,
+};
+
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Emits the arrival and removal of storage volumes and usb devices
+pub struct DeviceWatcher;
+
+impl DeviceWatcher {
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_DEVICECHANGE => {
+                let event = w_param.0 as u32;
+                if (event == DBT_DEVICEARRIVAL || event == DBT_DEVICEREMOVECOMPLETE)
+                    && l_param.0 != 0
+                {
+                    let arrival = event == DBT_DEVICEARRIVAL;
+                    let header = &*(l_param.0 as *const DEV_BROADCAST_HDR);
+                    log_error!(Self::on_device_change(header, arrival));
+                }
+                LRESULT(1)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    unsafe fn on_device_change(header: &DEV_BROADCAST_HDR, arrival: bool) -> Result<()> {
+        let mut changes = Vec::new();
+        if header.dbch_devicetype == DBT_DEVTYP_VOLUME {
+            let volume = &*(header as *const _ as *const DEV_BROADCAST_VOLUME);
+            for (idx, letter) in ('A'..='Z').enumerate() {
+                if volume.dbcv_unitmask & (1 << idx) == 0 {
+                    continue;
+                }
+                let letter = format!("{}:", letter);
+                changes.push(if arrival {
+                    DeviceChange::VolumeArrival { letter }
+                } else {
+                    DeviceChange::VolumeRemoval { letter }
+                });
+            }
+        } else if header.dbch_devicetype == DBT_DEVTYP_DEVICEINTERFACE {
+            let interface = &*(header as *const _ as *const DEV_BROADCAST_DEVICEINTERFACE_W);
+            let id = U16CStr::from_ptr_str(interface.dbcc_name.as_ptr()).to_string_lossy();
+            AudioProfiles::on_device_change(&id, arrival);
+            changes.push(if arrival {
+                DeviceChange::UsbArrival { id }
+            } else {
+                DeviceChange::UsbRemoval { id }
+            });
+        }
+
+        let handle = get_app_handle();
+        for change in &changes {
+            handle.emit("device-changed", change)?;
+        }
+        if header.dbch_devicetype == DBT_DEVTYP_VOLUME {
+            Self::emit_removable_drives()?;
+        }
+        Ok(())
+    }
+
+    pub fn emit_removable_drives() -> Result<()> {
+        get_app_handle().emit("removable-drives", removable_drives())?;
+        Ok(())
+    }
+
+    pub fn register_device_events() -> Result<()> {
+        if REGISTERED.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Registering device events");
+
+        let class_name = w!("SeelenDeviceWatcher");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        // volume changes are broadcasted to all top-level windows,
+        // device interfaces need to be registered
+        spawn_named_thread("Device Watcher", move || unsafe {
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                w!("Seelen Device Watcher"),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+
+            let filter = DEV_BROADCAST_DEVICEINTERFACE_W {
+                dbcc_size: std::mem::size_of::<DEV_BROADCAST_DEVICEINTERFACE_W>() as u32,
+                dbcc_devicetype: DBT_DEVTYP_DEVICEINTERFACE,
+                dbcc_classguid: GUID_DEVINTERFACE_USB_DEVICE,
+                ..Default::default()
+            };
+            log_error!(RegisterDeviceNotificationW(
+                HANDLE(hwnd.0),
+                &filter as *const _ as *const std::ffi::c_void,
+                DEVICE_NOTIFY_WINDOW_HANDLE,
+            ));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, hwnd, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        })?;
+        Ok(())
+    }
+}
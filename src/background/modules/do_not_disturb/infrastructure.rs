@@ -0,0 +1,6 @@
+use super::DoNotDisturb;
+
+#[tauri::command(async)]
+pub fn do_not_disturb_is_active() -> bool {
+    DoNotDisturb::is_active()
+}
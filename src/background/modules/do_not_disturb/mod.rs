@@ -0,0 +1,175 @@
+pub mod infrastructure;
+
+use std::{
+    ffi::c_void,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use seelen_core::state::{DoNotDisturbSettings, FocusAssistLevel};
+use tauri::Emitter;
+use windows::Win32::UI::Shell::{SHQueryUserNotificationState, QUNS_PRESENTATION_MODE};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::theme_schedule::{parse_time, ThemeScheduler},
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+/// true while the scheduler thread is running
+static SCHEDULER_RUNNING: AtomicBool = AtomicBool::new(false);
+/// true while do not disturb was enabled by the scheduler
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+/// true while another module keeps do not disturb enabled, like the presentation mode
+static FORCED: AtomicBool = AtomicBool::new(false);
+
+/// `WNF_SHEL_QUIETHOURS_ACTIVE_PROFILE_CHANGED`, undocumented state read by the shell to
+/// know the focus assist profile: 0 off, 1 priority only, 2 alarms only.
+const WNF_QUIET_HOURS_PROFILE: u64 = 0x0d83_063e_a3bf_1c75;
+
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtUpdateWnfStateData(
+        state_name: *const u64,
+        buffer: *const c_void,
+        length: u32,
+        type_id: *const c_void,
+        explicit_scope: *const c_void,
+        matching_change_stamp: u32,
+        check_stamp: u32,
+    ) -> i32;
+}
+
+/// `now`, `start` and `end` are minutes since midnight
+fn is_quiet_at(now: u32, start: u32, end: u32) -> bool {
+    if start <= end {
+        now >= start && now < end
+    } else {
+        // quiet hours cross midnight
+        now >= start || now < end
+    }
+}
+
+/// Enables focus assist during the quiet hours or while presenting, and marks the
+/// notifications received meanwhile as silenced unless their app is an exception.
+pub struct DoNotDisturb;
+
+impl DoNotDisturb {
+    const INTERVAL: u64 = 2000;
+
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::Acquire)
+    }
+
+    /// true if a notification of `app_name` should be silenced right now
+    pub fn is_silenced(app_name: &str) -> bool {
+        Self::is_active()
+            && !FULL_STATE
+                .load()
+                .settings()
+                .do_not_disturb
+                .exceptions
+                .iter()
+                .any(|app| app.eq_ignore_ascii_case(app_name))
+    }
+
+    fn in_quiet_hours(settings: &DoNotDisturbSettings) -> bool {
+        match (parse_time(&settings.start), parse_time(&settings.end)) {
+            (Some(start), Some(end)) => is_quiet_at(ThemeScheduler::local_minutes(), start, end),
+            _ => false,
+        }
+    }
+
+    fn is_presenting(settings: &DoNotDisturbSettings) -> bool {
+        let foreground = WindowsApi::get_foreground_window();
+        let is_listed = WindowsApi::exe(foreground).is_ok_and(|exe| {
+            settings
+                .fullscreen_apps
+                .iter()
+                .any(|app| app.eq_ignore_ascii_case(&exe))
+        });
+        if is_listed && WindowsApi::is_fullscreen(foreground).unwrap_or(false) {
+            return true;
+        }
+
+        settings.detect_presentations
+            && unsafe { SHQueryUserNotificationState() }
+                .is_ok_and(|state| state == QUNS_PRESENTATION_MODE)
+    }
+
+    fn should_be_active() -> bool {
+        if FORCED.load(Ordering::Acquire) {
+            return true;
+        }
+        let state = FULL_STATE.load();
+        let settings = &state.settings().do_not_disturb;
+        Self::in_quiet_hours(settings) || Self::is_presenting(settings)
+    }
+
+    fn set_focus_assist(level: Option<FocusAssistLevel>) -> Result<()> {
+        let profile: u32 = match level {
+            None => 0,
+            Some(FocusAssistLevel::PriorityOnly) => 1,
+            Some(FocusAssistLevel::AlarmsOnly) => 2,
+        };
+        let status = unsafe {
+            NtUpdateWnfStateData(
+                &WNF_QUIET_HOURS_PROFILE,
+                &profile as *const u32 as *const c_void,
+                std::mem::size_of::<u32>() as u32,
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+                0,
+            )
+        };
+        if status != 0 {
+            return Err(format!("Failed to update focus assist: NTSTATUS {:#x}", status).into());
+        }
+        Ok(())
+    }
+
+    fn set_active(active: bool) {
+        if ACTIVE.swap(active, Ordering::AcqRel) == active {
+            return;
+        }
+        log::info!(
+            "Do not disturb {}",
+            if active { "enabled" } else { "disabled" }
+        );
+        let level = FULL_STATE.load().settings().do_not_disturb.level;
+        log_error!(Self::set_focus_assist(active.then_some(level)));
+        log_error!(get_app_handle().emit("do-not-disturb-changed", active));
+    }
+
+    /// Keeps do not disturb enabled until unforced, even if the scheduler is not running
+    pub fn set_forced(forced: bool) {
+        FORCED.store(forced, Ordering::Release);
+        let scheduled = SCHEDULER_RUNNING.load(Ordering::Acquire) && Self::should_be_active();
+        Self::set_active(forced || scheduled);
+    }
+
+    pub fn start() -> Result<()> {
+        if SCHEDULER_RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting do not disturb scheduler");
+
+        spawn_named_thread("Do Not Disturb", || {
+            while SCHEDULER_RUNNING.load(Ordering::Acquire) {
+                Self::set_active(Self::should_be_active());
+                sleep_millis(Self::INTERVAL);
+            }
+            Self::set_active(FORCED.load(Ordering::Acquire));
+            log::trace!("Do not disturb scheduler stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        SCHEDULER_RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,68 @@
+use lazy_static::lazy_static;
+
+use super::domain::{EmojiCategory, EmojiEntry};
+
+/// (symbol, name, keywords) of non emoji characters hard to type on most keyboards
+const TYPOGRAPHY: &[(&str, &str, &str)] = &[
+    ("→", "rightwards arrow", "arrow right"),
+    ("←", "leftwards arrow", "arrow left"),
+    ("↑", "upwards arrow", "arrow up"),
+    ("↓", "downwards arrow", "arrow down"),
+    ("⇒", "rightwards double arrow", "implies"),
+    ("•", "bullet", "dot list"),
+    ("…", "horizontal ellipsis", "dots"),
+    ("—", "em dash", "dash"),
+    ("–", "en dash", "dash range"),
+    ("«", "left guillemet", "quote"),
+    ("»", "right guillemet", "quote"),
+    ("©", "copyright sign", "copyright"),
+    ("®", "registered sign", "registered trademark"),
+    ("™", "trade mark sign", "trademark tm"),
+    ("°", "degree sign", "degree temperature"),
+    ("§", "section sign", "section law"),
+    ("¶", "pilcrow", "paragraph"),
+    ("€", "euro sign", "currency money"),
+    ("£", "pound sign", "currency money"),
+    ("¥", "yen sign", "currency money"),
+    ("¢", "cent sign", "currency money"),
+    ("±", "plus-minus sign", "math"),
+    ("×", "multiplication sign", "math times"),
+    ("÷", "division sign", "math divide"),
+    ("≈", "almost equal to", "math approx"),
+    ("≠", "not equal to", "math"),
+    ("≤", "less-than or equal to", "math"),
+    ("≥", "greater-than or equal to", "math"),
+    ("∞", "infinity", "math"),
+    ("√", "square root", "math"),
+    ("π", "greek small letter pi", "math pi"),
+    ("Ω", "greek capital letter omega", "ohm"),
+    ("µ", "micro sign", "micro mu"),
+    ("✓", "check mark", "tick done"),
+    ("✗", "ballot x", "cross"),
+    ("★", "black star", "star"),
+    ("♥", "black heart suit", "heart"),
+    ("♪", "eighth note", "music"),
+    ("¯\\_(ツ)_/¯", "shrug", "kaomoji idk"),
+];
+
+lazy_static! {
+    /// Every fully qualified emoji of the Unicode data followed by the typographic symbols
+    pub static ref EMOJIS: Vec<EmojiEntry> = ::emojis::iter()
+        .filter_map(EmojiEntry::from_unicode)
+        .chain(TYPOGRAPHY.iter().map(|&(value, name, keywords)| EmojiEntry {
+            value,
+            name,
+            keywords: keywords.split_whitespace().collect(),
+            category: EmojiCategory::Typography,
+            emoji: None,
+        }))
+        .collect();
+}
+
+/// Entry of `value`, skin tone variants resolve to their base emoji
+pub fn find(value: &str) -> Option<&'static EmojiEntry> {
+    let base = ::emojis::get(value)
+        .and_then(|emoji| emoji.with_skin_tone(::emojis::SkinTone::Default))
+        .map_or(value, |emoji| emoji.as_str());
+    EMOJIS.iter().find(|entry| entry.value == base)
+}
@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::EmojiPicker;
+
+get_subcommands![
+    /** Opens the emoji picker if it is closed, otherwise closes it */
+    Toggle,
+    /** Opens the emoji picker next to the cursor */
+    Show,
+    /** Closes the emoji picker */
+    Hide,
+];
+
+impl EmojiPicker {
+    pub const CLI_IDENTIFIER: &'static str = "emoji-picker";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Emoji and symbol picker")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Toggle => Self::toggle()?,
+            SubCommand::Show => Self::show()?,
+            SubCommand::Hide => Self::hide(),
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum EmojiCategory {
+    Smileys,
+    People,
+    Nature,
+    Food,
+    Activities,
+    Travel,
+    Objects,
+    Symbols,
+    Flags,
+    /// non emoji characters hard to type on most keyboards
+    Typography,
+}
+
+pub struct EmojiEntry {
+    pub value: &'static str,
+    pub name: &'static str,
+    /// shortcodes or extra search terms
+    pub keywords: Vec<&'static str>,
+    pub category: EmojiCategory,
+    /// source of the Unicode data, used to get the skin tone variants
+    pub emoji: Option<&'static emojis::Emoji>,
+}
+
+impl EmojiEntry {
+    /// `None` for components (skin tones, hair styles) that are not meant to be typed alone
+    pub fn from_unicode(emoji: &'static emojis::Emoji) -> Option<Self> {
+        let category = match emoji.group() {
+            emojis::Group::SmileysAndEmotion => EmojiCategory::Smileys,
+            emojis::Group::PeopleAndBody => EmojiCategory::People,
+            emojis::Group::AnimalsAndNature => EmojiCategory::Nature,
+            emojis::Group::FoodAndDrink => EmojiCategory::Food,
+            emojis::Group::Activities => EmojiCategory::Activities,
+            emojis::Group::TravelAndPlaces => EmojiCategory::Travel,
+            emojis::Group::Objects => EmojiCategory::Objects,
+            emojis::Group::Symbols => EmojiCategory::Symbols,
+            emojis::Group::Flags => EmojiCategory::Flags,
+            emojis::Group::Component => return None,
+        };
+        Some(Self {
+            value: emoji.as_str(),
+            name: emoji.name(),
+            keywords: emoji.shortcodes().collect(),
+            category,
+            emoji: Some(emoji),
+        })
+    }
+
+    pub fn supports_skin_tone(&self) -> bool {
+        self.emoji.is_some_and(|emoji| emoji.skin_tones().is_some())
+    }
+
+    /// Variant of the entry with `tone`, the entry itself if it has no skin tones
+    pub fn with_skin_tone(&self, tone: SkinTone) -> &'static str {
+        self.emoji
+            .and_then(|emoji| emoji.with_skin_tone(tone.into()))
+            .map_or(self.value, |emoji| emoji.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SkinTone {
+    #[default]
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl From<SkinTone> for emojis::SkinTone {
+    fn from(tone: SkinTone) -> Self {
+        match tone {
+            SkinTone::Default => emojis::SkinTone::Default,
+            SkinTone::Light => emojis::SkinTone::Light,
+            SkinTone::MediumLight => emojis::SkinTone::MediumLight,
+            SkinTone::Medium => emojis::SkinTone::Medium,
+            SkinTone::MediumDark => emojis::SkinTone::MediumDark,
+            SkinTone::Dark => emojis::SkinTone::Dark,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Emoji {
+    pub value: String,
+    pub name: &'static str,
+    pub category: EmojiCategory,
+    pub supports_skin_tone: bool,
+}
@@ -0,0 +1,21 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{Emoji, SkinTone},
+    EmojiPicker,
+};
+
+#[tauri::command(async)]
+pub fn emoji_picker_search(query: String, skin_tone: Option<SkinTone>) -> Vec<Emoji> {
+    EmojiPicker::search(&query, skin_tone.unwrap_or_default())
+}
+
+#[tauri::command(async)]
+pub fn emoji_picker_select(value: String) -> Result<()> {
+    EmojiPicker::select(value)
+}
+
+#[tauri::command(async)]
+pub fn emoji_picker_close() {
+    EmojiPicker::hide();
+}
@@ -0,0 +1,180 @@
+mod catalog;
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{collections::VecDeque, sync::Arc};
+
+use catalog::EMOJIS;
+use domain::{Emoji, EmojiEntry, SkinTone};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::{WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SWP_NOACTIVATE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        input::{Keyboard, Mouse},
+        launcher::domain::match_score,
+    },
+    trace_lock,
+    utils::{overlay::OverlayBuilder, sleep_millis},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref EMOJI_PICKER: Arc<Mutex<Option<EmojiPicker>>> = Arc::new(Mutex::new(None));
+    static ref RECENTLY_USED: Mutex<VecDeque<&'static str>> = Mutex::new(VecDeque::new());
+}
+
+pub struct EmojiPicker {
+    window: WebviewWindow<Wry>,
+    /// window that had the focus when the picker was opened, receives the selection
+    target: HWND,
+}
+
+impl Drop for EmojiPicker {
+    fn drop(&mut self) {
+        log::trace!("Closing emoji picker");
+        log_error!(self.window.destroy());
+    }
+}
+
+impl EmojiPicker {
+    const TARGET: &'static str = "emoji-picker";
+    const WIDTH: i32 = 360;
+    const HEIGHT: i32 = 420;
+    const MAX_RECENTLY_USED: usize = 24;
+
+    /// Places the picker next to the cursor, kept inside the monitor
+    fn rect_near_cursor() -> Result<RECT> {
+        let cursor = Mouse::get_cursor_pos().unwrap_or_default();
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+        let scale = WindowsApi::get_device_pixel_ratio(monitor)?;
+        let width = (Self::WIDTH as f32 * scale) as i32;
+        let height = (Self::HEIGHT as f32 * scale) as i32;
+
+        let left = cursor
+            .get_x()
+            .min(monitor_rect.right - width)
+            .max(monitor_rect.left);
+        let top = cursor
+            .get_y()
+            .min(monitor_rect.bottom - height)
+            .max(monitor_rect.top);
+        Ok(RECT {
+            left,
+            top,
+            right: left + width,
+            bottom: top + height,
+        })
+    }
+
+    fn new() -> Result<Self> {
+        let target = WindowsApi::get_foreground_window();
+        let window = OverlayBuilder::new(
+            Self::TARGET,
+            "emoji-picker/index.html",
+            "Seelen Emoji Picker",
+        )
+        .build()?;
+        WindowsApi::set_position(
+            HWND(window.hwnd()?.0),
+            Some(HWND_TOPMOST),
+            &Self::rect_near_cursor()?,
+            SWP_NOACTIVATE,
+        )?;
+        window.show()?;
+        window.set_focus()?;
+        Ok(Self { window, target })
+    }
+
+    pub fn show() -> Result<()> {
+        let mut picker = trace_lock!(EMOJI_PICKER);
+        if picker.is_none() {
+            *picker = Some(Self::new()?);
+        }
+        Ok(())
+    }
+
+    pub fn hide() {
+        trace_lock!(EMOJI_PICKER).take();
+    }
+
+    pub fn toggle() -> Result<()> {
+        let is_open = trace_lock!(EMOJI_PICKER).is_some();
+        if is_open {
+            Self::hide();
+            Ok(())
+        } else {
+            Self::show()
+        }
+    }
+
+    /// Closes the picker and types `value` on the window that was focused before opening it
+    pub fn select(value: String) -> Result<()> {
+        let target = match trace_lock!(EMOJI_PICKER).take() {
+            Some(picker) => picker.target,
+            None => return Ok(()),
+        };
+
+        if let Some(entry) = catalog::find(&value) {
+            let mut recently_used = trace_lock!(RECENTLY_USED);
+            recently_used.retain(|v| *v != entry.value);
+            recently_used.push_front(entry.value);
+            recently_used.truncate(Self::MAX_RECENTLY_USED);
+        }
+
+        if WindowsApi::is_window(target) {
+            WindowsApi::force_set_foreground(target)?;
+            // give time to the target to process the activation before typing
+            sleep_millis(50);
+        }
+        Keyboard::new().send_text(&value)
+    }
+
+    /// Emojis matching `query` by name or keywords, recently used first if `query` is empty
+    pub fn search(query: &str, skin_tone: SkinTone) -> Vec<Emoji> {
+        let to_emoji = |entry: &EmojiEntry| Emoji {
+            value: entry.with_skin_tone(skin_tone).to_string(),
+            name: entry.name,
+            category: entry.category,
+            supports_skin_tone: entry.supports_skin_tone(),
+        };
+
+        if query.trim().is_empty() {
+            let recently_used = trace_lock!(RECENTLY_USED);
+            let recent = recently_used
+                .iter()
+                .filter_map(|value| EMOJIS.iter().find(|e| e.value == *value));
+            let others = EMOJIS.iter().filter(|e| !recently_used.contains(&e.value));
+            return recent.chain(others).map(to_emoji).collect();
+        }
+
+        let mut matches: Vec<(u32, &EmojiEntry)> = EMOJIS
+            .iter()
+            .filter_map(|entry| {
+                let score = match_score(query, entry.name).or_else(|| {
+                    entry
+                        .keywords
+                        .iter()
+                        .filter_map(|keyword| match_score(query, keyword))
+                        .max()
+                        .map(|score| score / 2)
+                })?;
+                Some((score, entry))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0));
+        matches
+            .into_iter()
+            .map(|(_, entry)| to_emoji(entry))
+            .collect()
+    }
+}
@@ -0,0 +1,42 @@
+use std::process::Command as Process;
+
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::EventRecorder;
+
+get_subcommands![
+    /** Starts recording the win events and module decisions, clearing the previous records */
+    Start,
+    /** Stops the recording, the records are kept until the next start */
+    Stop,
+    /** Writes the records to the logs folder and selects the file on the explorer */
+    Dump,
+];
+
+impl EventRecorder {
+    pub const CLI_IDENTIFIER: &'static str = "recorder";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Event trace recorder to diagnose bugs")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Start => Self::start(),
+            SubCommand::Stop => Self::stop(),
+            SubCommand::Dump => {
+                let path = Self::dump()?;
+                Process::new("explorer")
+                    .args(["/select,", &path.to_string_lossy()])
+                    .spawn()?;
+            }
+        };
+        Ok(())
+    }
+}
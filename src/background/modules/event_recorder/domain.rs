@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::windows_api::window::Window;
+
+/// State of a window when an event was received, enough to reproduce the dock rules and the
+/// window manager decisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowSnapshot {
+    pub title: String,
+    pub class: String,
+    pub exe: Option<String>,
+    pub parent: isize,
+    pub visible: bool,
+    pub styles: u32,
+    pub ex_styles: u32,
+    /// left, top, right, bottom
+    pub rect: [i32; 4],
+    pub suspended: bool,
+    #[serde(default)]
+    pub minimized: bool,
+    #[serde(default)]
+    pub maximized: bool,
+    /// snapped by Windows
+    #[serde(default)]
+    pub arranged: bool,
+    #[serde(default)]
+    pub cloaked: bool,
+    /// handle of the monitor, 0 on traces of older versions
+    #[serde(default)]
+    pub monitor: isize,
+    /// only captured for application frames, as their creator decides if they are shown
+    #[serde(default)]
+    pub children: Vec<(isize, WindowSnapshot)>,
+}
+
+impl WindowSnapshot {
+    pub fn capture(window: &Window) -> Self {
+        let rect = window.rect();
+        let children = if window.is_frame().unwrap_or(false) {
+            window
+                .children()
+                .unwrap_or_default()
+                .iter()
+                .map(|child| (child.hwnd().0, Self::capture(child)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            title: window.title(),
+            class: window.class(),
+            exe: window
+                .exe()
+                .ok()
+                .map(|exe| exe.to_string_lossy().to_string()),
+            parent: window.parent().map_or(0, |parent| parent.hwnd().0),
+            visible: window.is_visible(),
+            styles: window.styles().0,
+            ex_styles: window.ex_styles().0,
+            rect: [rect.left, rect.top, rect.right, rect.bottom],
+            suspended: window.is_uwp_suspended(),
+            minimized: window.is_minimized(),
+            maximized: window.is_maximized(),
+            arranged: window.is_arranged(),
+            cloaked: window.is_cloaked().unwrap_or(false),
+            monitor: window.monitor().0,
+            children,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceEvent {
+    /// milliseconds since the recording started
+    pub time: u128,
+    /// debug name of the win event, as `ObjectShow`
+    pub event: String,
+    pub hwnd: isize,
+    pub window: WindowSnapshot,
+}
+
+/// Structured version of the recording, replayed against the mocked windows api on tests
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trace {
+    pub version: String,
+    pub events: Vec<TraceEvent>,
+    /// handles on the dock when the trace was dumped
+    pub dock: Vec<isize>,
+    /// handles managed by the window managers when the trace was dumped
+    #[serde(default)]
+    pub managed: Vec<isize>,
+}
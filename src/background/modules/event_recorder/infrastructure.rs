@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use crate::error_handler::Result;
+
+use super::EventRecorder;
+
+#[tauri::command(async)]
+pub fn event_recorder_start() {
+    EventRecorder::start();
+}
+
+#[tauri::command(async)]
+pub fn event_recorder_stop() {
+    EventRecorder::stop();
+}
+
+#[tauri::command(async)]
+pub fn event_recorder_is_recording() -> bool {
+    EventRecorder::is_recording()
+}
+
+#[tauri::command(async)]
+pub fn event_recorder_dump() -> Result<PathBuf> {
+    EventRecorder::dump()
+}
@@ -0,0 +1,148 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::VecDeque,
+    io::Write,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use domain::{Trace, TraceEvent, WindowSnapshot};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    seelen::{get_app_handle, SEELEN},
+    seelen_weg::SeelenWeg,
+    trace_lock,
+    utils::app_log_path,
+    windows_api::window::Window,
+    winevent::WinEvent,
+};
+
+lazy_static! {
+    static ref RECORDS: Arc<Mutex<VecDeque<Record>>> =
+        Arc::new(Mutex::new(VecDeque::with_capacity(CAPACITY)));
+    static ref EVENTS: Arc<Mutex<VecDeque<TraceEvent>>> =
+        Arc::new(Mutex::new(VecDeque::with_capacity(CAPACITY)));
+    static ref STARTED_AT: Arc<Mutex<Instant>> = Arc::new(Mutex::new(Instant::now()));
+}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// max amount of records and events, the oldest ones are dropped first
+const CAPACITY: usize = 20_000;
+
+struct Record {
+    /// milliseconds since the recording started
+    time: u128,
+    source: &'static str,
+    message: String,
+}
+
+/// Opt-in ring buffer of the win events received and the decisions taken by the modules,
+/// dumped to a file to be attached on bug reports.
+pub struct EventRecorder;
+
+impl EventRecorder {
+    pub fn is_recording() -> bool {
+        RECORDING.load(Ordering::Relaxed)
+    }
+
+    pub fn start() {
+        trace_lock!(RECORDS).clear();
+        trace_lock!(EVENTS).clear();
+        *trace_lock!(STARTED_AT) = Instant::now();
+        RECORDING.store(true, Ordering::Relaxed);
+        log::info!("Event recorder started");
+    }
+
+    pub fn stop() {
+        RECORDING.store(false, Ordering::Relaxed);
+        log::info!("Event recorder stopped");
+    }
+
+    /// The message is only built while recording
+    pub fn record<F: FnOnce() -> String>(source: &'static str, message: F) {
+        if !Self::is_recording() {
+            return;
+        }
+        let record = Record {
+            time: trace_lock!(STARTED_AT).elapsed().as_millis(),
+            source,
+            message: message(),
+        };
+        let mut records = trace_lock!(RECORDS);
+        if records.len() >= CAPACITY {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Records the event and a snapshot of the window, so the trace can be replayed
+    pub fn record_win_event(event: WinEvent, origin: HWND) {
+        if !Self::is_recording() {
+            return;
+        }
+        let window = Window::from(origin);
+        Self::record("win-event", || format!("{:?} {:?}", event, window));
+
+        let trace_event = TraceEvent {
+            time: trace_lock!(STARTED_AT).elapsed().as_millis(),
+            event: format!("{:?}", event),
+            hwnd: origin.0,
+            window: WindowSnapshot::capture(&window),
+        };
+        let mut events = trace_lock!(EVENTS);
+        if events.len() >= CAPACITY {
+            events.pop_front();
+        }
+        events.push_back(trace_event);
+    }
+
+    /// Writes the recorded events to a new file in the logs folder, returns its path.
+    /// The replayable trace is written next to it with the same name and json extension.
+    pub fn dump() -> Result<PathBuf> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let dir = app_log_path(&get_app_handle())?;
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("event-trace-{}.log", timestamp));
+
+        let mut file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+        writeln!(file, "Seelen UI v{}", env!("CARGO_PKG_VERSION"))?;
+        writeln!(file, "Operating System: {}", os_info::get())?;
+        for record in trace_lock!(RECORDS).iter() {
+            writeln!(
+                file,
+                "[{:>10}ms] [{}] {}",
+                record.time, record.source, record.message
+            )?;
+        }
+        file.flush()?;
+
+        let trace = Trace {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            events: trace_lock!(EVENTS).iter().cloned().collect(),
+            dock: SeelenWeg::open_handles(),
+            managed: trace_lock!(SEELEN)
+                .monitors()
+                .iter()
+                .filter_map(|monitor| monitor.wm().as_ref())
+                .flat_map(|wm| wm.handles())
+                .collect(),
+        };
+        std::fs::write(path.with_extension("json"), serde_json::to_string(&trace)?)?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FileOperationKind {
+    Copy,
+    Move,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FileOperationStatus {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileOperation {
+    pub id: u32,
+    pub kind: FileOperationKind,
+    pub sources: Vec<PathBuf>,
+    pub destination: PathBuf,
+    pub total_bytes: u64,
+    pub done_bytes: u64,
+    pub total_files: u32,
+    pub done_files: u32,
+    /// file being copied right now
+    pub current: Option<PathBuf>,
+    pub status: FileOperationStatus,
+    pub error: Option<String>,
+}
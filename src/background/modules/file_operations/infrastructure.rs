@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use crate::error_handler::Result;
+
+use super::{
+    domain::{FileOperation, FileOperationKind},
+    FileOperations,
+};
+
+#[tauri::command(async)]
+pub fn file_operations_get() -> Vec<FileOperation> {
+    FileOperations::all()
+}
+
+#[tauri::command(async)]
+pub fn file_operation_start(
+    kind: FileOperationKind,
+    sources: Vec<PathBuf>,
+    destination: PathBuf,
+) -> Result<u32> {
+    FileOperations::start(kind, sources, destination)
+}
+
+#[tauri::command(async)]
+pub fn file_operation_cancel(id: u32) {
+    FileOperations::cancel(id);
+}
@@ -0,0 +1,214 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use domain::{FileOperation, FileOperationKind, FileOperationStatus};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+
+use crate::{
+    error_handler::Result,
+    log_error, trace_lock,
+    utils::{spawn_named_thread, throttle::emit_throttled},
+};
+
+lazy_static! {
+    static ref OPERATIONS: Arc<Mutex<Vec<FileOperation>>> = Arc::new(Mutex::new(Vec::new()));
+    static ref CANCEL_FLAGS: Arc<Mutex<HashMap<u32, Arc<AtomicBool>>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+}
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+const EMIT_INTERVAL: Duration = Duration::from_millis(200);
+/// finished operations are kept for a while so the toolbar can show the result
+const KEEP_FINISHED: Duration = Duration::from_secs(5);
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// (source file, target file, size in bytes)
+type PlannedCopy = (PathBuf, PathBuf, u64);
+
+/// Copy engine for the operations started by Seelen UI, the progress is emitted as
+/// `file-operations-changed` so it can be shown on the toolbar. Operations of the Explorer
+/// or other apps run inside their own process and can't be observed from here.
+pub struct FileOperations;
+
+impl FileOperations {
+    pub fn all() -> Vec<FileOperation> {
+        trace_lock!(OPERATIONS).clone()
+    }
+
+    fn emit() {
+        log_error!(emit_throttled(
+            "file-operations-changed",
+            "all",
+            Self::all(),
+            EMIT_INTERVAL
+        ));
+    }
+
+    fn update<F: FnOnce(&mut FileOperation)>(id: u32, f: F) {
+        if let Some(operation) = trace_lock!(OPERATIONS).iter_mut().find(|op| op.id == id) {
+            f(operation);
+        }
+        Self::emit();
+    }
+
+    /// Starts copying or moving the sources into the destination folder, returns the id
+    /// of the operation. Existing files on the destination are never overwritten.
+    pub fn start(
+        kind: FileOperationKind,
+        sources: Vec<PathBuf>,
+        destination: PathBuf,
+    ) -> Result<u32> {
+        if !destination.is_dir() {
+            return Err(format!("{} is not a folder", destination.display()).into());
+        }
+        for source in &sources {
+            let name = source.file_name().ok_or("Invalid source path")?;
+            if destination.join(name).exists() {
+                return Err(format!("{:?} already exists on the destination", name).into());
+            }
+        }
+
+        let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        trace_lock!(CANCEL_FLAGS).insert(id, cancelled.clone());
+        trace_lock!(OPERATIONS).push(FileOperation {
+            id,
+            kind,
+            sources: sources.clone(),
+            destination: destination.clone(),
+            total_bytes: 0,
+            done_bytes: 0,
+            total_files: 0,
+            done_files: 0,
+            current: None,
+            status: FileOperationStatus::Running,
+            error: None,
+        });
+        Self::emit();
+
+        spawn_named_thread("File Operation", move || {
+            let result = Self::run(id, kind, &sources, &destination, &cancelled);
+            let status = match &result {
+                Ok(()) if cancelled.load(Ordering::SeqCst) => FileOperationStatus::Cancelled,
+                Ok(()) => FileOperationStatus::Completed,
+                Err(_) => FileOperationStatus::Failed,
+            };
+            Self::update(id, |operation| {
+                operation.status = status;
+                operation.current = None;
+                operation.error = result.err().map(|err| err.to_string());
+            });
+            trace_lock!(CANCEL_FLAGS).remove(&id);
+
+            std::thread::sleep(KEEP_FINISHED);
+            trace_lock!(OPERATIONS).retain(|operation| operation.id != id);
+            Self::emit();
+        })?;
+        Ok(id)
+    }
+
+    pub fn cancel(id: u32) {
+        if let Some(flag) = trace_lock!(CANCEL_FLAGS).get(&id) {
+            flag.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn run(
+        id: u32,
+        kind: FileOperationKind,
+        sources: &[PathBuf],
+        destination: &Path,
+        cancelled: &AtomicBool,
+    ) -> Result<()> {
+        let mut pending = Vec::new();
+        for source in sources {
+            let target = destination.join(source.file_name().unwrap_or_default());
+            // moves on the same volume are just a rename
+            if kind == FileOperationKind::Move && std::fs::rename(source, &target).is_ok() {
+                continue;
+            }
+            pending.push((source.clone(), target));
+        }
+
+        let mut files = Vec::new();
+        for (source, target) in &pending {
+            Self::plan(source, target, &mut files)?;
+        }
+        Self::update(id, |operation| {
+            operation.total_bytes = files.iter().map(|(_, _, size)| size).sum();
+            operation.total_files = files.len() as u32;
+        });
+
+        for (from, to, _) in &files {
+            if cancelled.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+            Self::update(id, |operation| operation.current = Some(from.clone()));
+            Self::copy_file(id, from, to, cancelled)?;
+            Self::update(id, |operation| operation.done_files += 1);
+        }
+
+        if kind == FileOperationKind::Move && !cancelled.load(Ordering::SeqCst) {
+            for (source, _) in &pending {
+                if source.is_dir() {
+                    std::fs::remove_dir_all(source)?;
+                } else {
+                    std::fs::remove_file(source)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Flattens the tree into the list of files to copy, creating the folders on the target
+    fn plan(source: &Path, target: &Path, files: &mut Vec<PlannedCopy>) -> Result<()> {
+        if source.is_dir() {
+            std::fs::create_dir_all(target)?;
+            for entry in std::fs::read_dir(source)?.flatten() {
+                Self::plan(&entry.path(), &target.join(entry.file_name()), files)?;
+            }
+        } else {
+            let size = source.metadata()?.len();
+            files.push((source.to_path_buf(), target.to_path_buf(), size));
+        }
+        Ok(())
+    }
+
+    fn copy_file(id: u32, from: &Path, to: &Path, cancelled: &AtomicBool) -> Result<()> {
+        let mut reader = File::open(from)?;
+        let mut writer = File::create(to)?;
+        let mut buffer = vec![0; CHUNK_SIZE];
+        loop {
+            if cancelled.load(Ordering::SeqCst) {
+                drop(writer);
+                std::fs::remove_file(to)?;
+                return Ok(());
+            }
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            writer.write_all(&buffer[..read])?;
+            Self::update(id, |operation| operation.done_bytes += read as u64);
+        }
+        // keep the modification date as the Explorer does
+        if let Ok(modified) = reader.metadata().and_then(|meta| meta.modified()) {
+            log_error!(writer.set_modified(modified));
+        }
+        Ok(())
+    }
+}
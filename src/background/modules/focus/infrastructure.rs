@@ -0,0 +1,8 @@
+use std::collections::HashMap;
+
+use super::{FocusPath, FocusRequest};
+
+#[tauri::command(async)]
+pub fn get_focus_request_stats() -> HashMap<FocusPath, u64> {
+    FocusRequest::stats()
+}
@@ -0,0 +1,144 @@
+pub mod infrastructure;
+
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::Serialize;
+use windows::Win32::{
+    Foundation::HWND,
+    System::Threading::{AttachThreadInput, GetCurrentThreadId},
+    UI::WindowsAndMessaging::{AllowSetForegroundWindow, BringWindowToTop, SetForegroundWindow},
+};
+
+use crate::{error_handler::Result, log_error, windows_api::WindowsApi};
+
+/// Workaround that moved the focus, ordered from the least to the most intrusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum FocusPath {
+    Direct,
+    AllowSetForeground,
+    AttachThreadInput,
+    MinimizeRestore,
+    Failed,
+}
+
+impl FocusPath {
+    const ALL: [FocusPath; 5] = [
+        FocusPath::Direct,
+        FocusPath::AllowSetForeground,
+        FocusPath::AttachThreadInput,
+        FocusPath::MinimizeRestore,
+        FocusPath::Failed,
+    ];
+}
+
+/// times each path was the one that succeeded, indexed as `FocusPath::ALL`
+static STATS: [AtomicU64; 5] = [
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+];
+
+/// Moves the focus to a window despite the foreground lock rules of Windows, that ignore
+/// `SetForegroundWindow` when the caller didn't receive the last input event, as happens
+/// when the click on the dock is processed by the webview before reaching the background.
+pub struct FocusRequest;
+
+impl FocusRequest {
+    fn is_focused(hwnd: HWND) -> bool {
+        WindowsApi::get_foreground_window() == hwnd
+    }
+
+    fn direct(hwnd: HWND) -> bool {
+        unsafe { SetForegroundWindow(hwnd).as_bool() && Self::is_focused(hwnd) }
+    }
+
+    /// the foreground process can grant the right to the target process
+    fn allow_set_foreground(hwnd: HWND) -> bool {
+        let (process_id, _) = WindowsApi::window_thread_process_id(hwnd);
+        unsafe {
+            if AllowSetForegroundWindow(process_id).is_err() {
+                return false;
+            }
+        }
+        Self::direct(hwnd)
+    }
+
+    /// sharing the input state with the foreground thread makes the call come from it
+    fn attach_thread_input(hwnd: HWND) -> bool {
+        let (_, foreground_thread) =
+            WindowsApi::window_thread_process_id(WindowsApi::get_foreground_window());
+        let current_thread = unsafe { GetCurrentThreadId() };
+        if foreground_thread == 0 || foreground_thread == current_thread {
+            return false;
+        }
+
+        unsafe {
+            if !AttachThreadInput(current_thread, foreground_thread, true).as_bool() {
+                return false;
+            }
+            let _ = BringWindowToTop(hwnd);
+            let focused = Self::direct(hwnd);
+            let _ = AttachThreadInput(current_thread, foreground_thread, false);
+            focused
+        }
+    }
+
+    fn minimize_restore(hwnd: HWND) -> bool {
+        WindowsApi::force_set_foreground(hwnd).is_ok() && Self::is_focused(hwnd)
+    }
+
+    fn record(hwnd: HWND, path: FocusPath) {
+        let index = FocusPath::ALL
+            .iter()
+            .position(|p| *p == path)
+            .unwrap_or_default();
+        STATS[index].fetch_add(1, Ordering::Relaxed);
+        log::debug!("Focus request for {:?} resolved by {:?}", hwnd, path);
+    }
+
+    /// Tries each workaround until the window is on the foreground
+    pub fn request(hwnd: HWND) -> Result<FocusPath> {
+        if !WindowsApi::is_window(hwnd) {
+            return Err("Invalid window".into());
+        }
+        if Self::is_focused(hwnd) {
+            return Ok(FocusPath::Direct);
+        }
+
+        let path = if Self::direct(hwnd) {
+            FocusPath::Direct
+        } else if Self::allow_set_foreground(hwnd) {
+            FocusPath::AllowSetForeground
+        } else if Self::attach_thread_input(hwnd) {
+            FocusPath::AttachThreadInput
+        } else if Self::minimize_restore(hwnd) {
+            FocusPath::MinimizeRestore
+        } else {
+            FocusPath::Failed
+        };
+
+        Self::record(hwnd, path);
+        match path {
+            FocusPath::Failed => Err("Could not move the focus to the window".into()),
+            path => Ok(path),
+        }
+    }
+
+    pub fn async_request(hwnd: HWND) {
+        std::thread::spawn(move || log_error!(Self::request(hwnd)));
+    }
+
+    /// How many requests were resolved by each path since startup
+    pub fn stats() -> HashMap<FocusPath, u64> {
+        FocusPath::ALL
+            .iter()
+            .zip(STATS.iter())
+            .map(|(path, count)| (*path, count.load(Ordering::Relaxed)))
+            .collect()
+    }
+}
@@ -0,0 +1,6 @@
+use super::FocusSessions;
+
+#[tauri::command(async)]
+pub fn focus_sessions_is_active() -> bool {
+    FocusSessions::is_active()
+}
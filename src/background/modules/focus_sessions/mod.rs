@@ -0,0 +1,206 @@
+pub mod infrastructure;
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{FocusBlockMode, FocusSession};
+use tauri::Emitter;
+use windows::Win32::{Foundation::HWND, System::SystemInformation::GetLocalTime};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::theme_schedule::parse_time,
+    seelen::get_app_handle,
+    seelen_weg::app_names::AppNames,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::{Toast, WindowsApi},
+};
+
+/// true while the scheduler thread is running
+static SCHEDULER_RUNNING: AtomicBool = AtomicBool::new(false);
+/// true while a session is in progress
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    /// windows of blocked apps and when their grace period ends
+    static ref PENDING: Mutex<HashMap<isize, Instant>> = Mutex::new(HashMap::new());
+    /// lowercased executable names already minimized on the current session
+    static ref ENFORCED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// `weekday` is 0 for Sunday and `now` are minutes since midnight
+fn is_in_session(session: &FocusSession, weekday: u8, now: u32) -> bool {
+    let (Some(start), Some(end)) = (parse_time(&session.start), parse_time(&session.end)) else {
+        return false;
+    };
+    let starts_on = |day: u8| session.days.is_empty() || session.days.contains(&day);
+    if start <= end {
+        starts_on(weekday) && now >= start && now < end
+    } else {
+        // the part after midnight belongs to the session started the day before
+        (starts_on(weekday) && now >= start) || (starts_on((weekday + 6) % 7) && now < end)
+    }
+}
+
+/// Keeps the distracting apps away during the scheduled focus sessions. Activating a blocked
+/// app shows a warning toast and its window is minimized once the grace period ends, on block
+/// mode the next activations of the same app are refused right away.
+pub struct FocusSessions;
+
+impl FocusSessions {
+    const INTERVAL: u64 = 1000;
+
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::Acquire)
+    }
+
+    fn should_be_active() -> bool {
+        let time = unsafe { GetLocalTime() };
+        let now = time.wHour as u32 * 60 + time.wMinute as u32;
+        FULL_STATE
+            .load()
+            .settings()
+            .focus_sessions
+            .sessions
+            .iter()
+            .any(|session| is_in_session(session, time.wDayOfWeek as u8, now))
+    }
+
+    pub fn on_foreground(hwnd: HWND) {
+        if !Self::is_active() {
+            return;
+        }
+        let Ok(exe) = WindowsApi::exe(hwnd) else {
+            return;
+        };
+        let state = FULL_STATE.load();
+        let settings = &state.settings().focus_sessions;
+        if !settings
+            .blocked_apps
+            .iter()
+            .any(|app| app.eq_ignore_ascii_case(&exe))
+        {
+            return;
+        }
+
+        if settings.mode == FocusBlockMode::Block
+            && trace_lock!(ENFORCED).contains(&exe.to_lowercase())
+        {
+            log_error!(WindowsApi::minimize_window(hwnd));
+            return;
+        }
+
+        let mut pending = trace_lock!(PENDING);
+        if pending.contains_key(&hwnd.0) {
+            return;
+        }
+        let grace = settings.grace_period;
+        pending.insert(hwnd.0, Instant::now() + Duration::from_secs(grace));
+        drop(pending);
+
+        let name = WindowsApi::exe_path(hwnd).map_or(exe, |path| AppNames::get(&path));
+        log_error!(Toast::show_titled(
+            "Focus session",
+            &format!("{name} is blocked during the session, it will be minimized in {grace}s"),
+        ));
+    }
+
+    /// Minimizes the windows whose grace period ended
+    fn enforce() {
+        let now = Instant::now();
+        let mut due = Vec::new();
+        trace_lock!(PENDING).retain(|hwnd, deadline| {
+            let expired = *deadline <= now;
+            if expired {
+                due.push(HWND(*hwnd));
+            }
+            !expired
+        });
+
+        for hwnd in due {
+            if !WindowsApi::is_window(hwnd) {
+                continue;
+            }
+            if let Ok(exe) = WindowsApi::exe(hwnd) {
+                trace_lock!(ENFORCED).insert(exe.to_lowercase());
+            }
+            if !WindowsApi::is_iconic(hwnd) {
+                log_error!(WindowsApi::minimize_window(hwnd));
+            }
+        }
+    }
+
+    fn set_active(active: bool) {
+        if ACTIVE.swap(active, Ordering::AcqRel) == active {
+            return;
+        }
+        log::info!("Focus session {}", if active { "started" } else { "ended" });
+        trace_lock!(PENDING).clear();
+        trace_lock!(ENFORCED).clear();
+        if active {
+            // the blocked app could be focused before the session starts
+            Self::on_foreground(WindowsApi::get_foreground_window());
+        }
+        log_error!(get_app_handle().emit("focus-session-changed", active));
+    }
+
+    pub fn start() -> Result<()> {
+        if SCHEDULER_RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting focus sessions scheduler");
+
+        spawn_named_thread("Focus Sessions", || {
+            while SCHEDULER_RUNNING.load(Ordering::Acquire) {
+                Self::set_active(Self::should_be_active());
+                Self::enforce();
+                sleep_millis(Self::INTERVAL);
+            }
+            Self::set_active(false);
+            log::trace!("Focus sessions scheduler stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        SCHEDULER_RUNNING.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(start: &str, end: &str, days: &[u8]) -> FocusSession {
+        FocusSession {
+            start: start.to_string(),
+            end: end.to_string(),
+            days: days.to_vec(),
+        }
+    }
+
+    #[test]
+    fn sessions_apply_on_their_days() {
+        let work = session("09:00", "17:00", &[1, 2, 3, 4, 5]);
+        assert!(is_in_session(&work, 1, 10 * 60));
+        assert!(!is_in_session(&work, 0, 10 * 60));
+        assert!(!is_in_session(&work, 1, 17 * 60));
+    }
+
+    #[test]
+    fn sessions_crossing_midnight_belong_to_their_start_day() {
+        let night = session("22:00", "02:00", &[5]);
+        assert!(is_in_session(&night, 5, 23 * 60));
+        assert!(is_in_session(&night, 6, 60));
+        assert!(!is_in_session(&night, 5, 60));
+        assert!(is_in_session(&session("22:00", "02:00", &[]), 0, 60));
+    }
+}
@@ -0,0 +1,6 @@
+use super::GameMode;
+
+#[tauri::command(async)]
+pub fn game_mode_is_active() -> bool {
+    GameMode::is_active()
+}
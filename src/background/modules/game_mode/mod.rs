@@ -0,0 +1,84 @@
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::Emitter;
+use windows::Win32::UI::Shell::{
+    SHQueryUserNotificationState, QUNS_BUSY, QUNS_RUNNING_D3D_FULL_SCREEN,
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+/// true while the game detection thread is running
+static DETECTOR_RUNNING: AtomicBool = AtomicBool::new(false);
+/// true while a game is in foreground
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Detects games in foreground to reduce the work done in background while they run.
+/// Modules check `GameMode::is_active` to skip polling and noisy events, and the webviews
+/// disable their animations on `game-mode-changed`.
+pub struct GameMode;
+
+impl GameMode {
+    const INTERVAL: u64 = 2000;
+
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::Acquire)
+    }
+
+    fn is_game_running() -> bool {
+        let state = FULL_STATE.load();
+        let settings = &state.settings().game_mode;
+
+        let foreground = WindowsApi::get_foreground_window();
+        let is_listed = WindowsApi::exe(foreground).is_ok_and(|exe| {
+            settings
+                .games
+                .iter()
+                .any(|game| game.eq_ignore_ascii_case(&exe))
+        });
+        if is_listed {
+            return true;
+        }
+
+        // QUNS_BUSY is reported for borderless fullscreen apps and presentations
+        settings.detect_fullscreen
+            && unsafe { SHQueryUserNotificationState() }
+                .is_ok_and(|state| state == QUNS_RUNNING_D3D_FULL_SCREEN || state == QUNS_BUSY)
+    }
+
+    fn set_active(active: bool) {
+        if ACTIVE.swap(active, Ordering::AcqRel) != active {
+            log::info!("Game mode {}", if active { "started" } else { "ended" });
+            log_error!(get_app_handle().emit("game-mode-changed", active));
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if DETECTOR_RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting game mode detection");
+
+        spawn_named_thread("Game Mode", || {
+            while DETECTOR_RUNNING.load(Ordering::Acquire) {
+                Self::set_active(Self::is_game_running());
+                sleep_millis(Self::INTERVAL);
+            }
+            Self::set_active(false);
+            log::trace!("Game mode detection stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        DETECTOR_RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,101 @@
+use seelen_core::state::SeelenWegSide;
+use windows::Win32::Foundation::RECT;
+
+use crate::modules::input::domain::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+    /// swipe starting on the edge where the dock lives, going to the center of the screen
+    EdgeSwipe,
+    ThreeFingersSwipe(SwipeDirection),
+}
+
+/// Tracks a single touch contact to recognize swipes from the screen edges.
+#[derive(Debug, Default)]
+pub struct EdgeSwipeTracker {
+    start: Option<Point>,
+    recognized: bool,
+}
+
+impl EdgeSwipeTracker {
+    fn is_on_edge(point: &Point, monitor: &RECT, side: SeelenWegSide, edge_size: i32) -> bool {
+        match side {
+            SeelenWegSide::Bottom => point.get_y() >= monitor.bottom - edge_size,
+            SeelenWegSide::Top => point.get_y() <= monitor.top + edge_size,
+            SeelenWegSide::Left => point.get_x() <= monitor.left + edge_size,
+            SeelenWegSide::Right => point.get_x() >= monitor.right - edge_size,
+        }
+    }
+
+    pub fn down(&mut self, point: Point, monitor: &RECT, side: SeelenWegSide, edge_size: i32) {
+        self.recognized = false;
+        self.start = Self::is_on_edge(&point, monitor, side, edge_size).then_some(point);
+    }
+
+    /// returns a gesture only once per contact, the first time the threshold is exceeded
+    pub fn moved(&mut self, point: Point, side: SeelenWegSide, threshold: i32) -> Option<Gesture> {
+        let start = self.start?;
+        if self.recognized {
+            return None;
+        }
+
+        let distance = match side {
+            SeelenWegSide::Bottom => start.get_y() - point.get_y(),
+            SeelenWegSide::Top => point.get_y() - start.get_y(),
+            SeelenWegSide::Left => point.get_x() - start.get_x(),
+            SeelenWegSide::Right => start.get_x() - point.get_x(),
+        };
+
+        if distance >= threshold {
+            self.recognized = true;
+            return Some(Gesture::EdgeSwipe);
+        }
+        None
+    }
+
+    pub fn up(&mut self) {
+        self.start = None;
+        self.recognized = false;
+    }
+}
+
+/// Tracks precision touchpad reports to recognize horizontal three fingers swipes.
+#[derive(Debug, Default)]
+pub struct TouchpadSwipeTracker {
+    start_x: Option<i32>,
+    recognized: bool,
+}
+
+impl TouchpadSwipeTracker {
+    /// `x` is the position of the first contact in touchpad logical units
+    pub fn report(&mut self, contacts: u32, x: i32, threshold: i32) -> Option<Gesture> {
+        if contacts != 3 {
+            self.start_x = None;
+            self.recognized = false;
+            return None;
+        }
+
+        let start_x = *self.start_x.get_or_insert(x);
+        if self.recognized {
+            return None;
+        }
+
+        let delta = x - start_x;
+        if delta.abs() < threshold {
+            return None;
+        }
+
+        self.recognized = true;
+        Some(Gesture::ThreeFingersSwipe(if delta < 0 {
+            SwipeDirection::Left
+        } else {
+            SwipeDirection::Right
+        }))
+    }
+}
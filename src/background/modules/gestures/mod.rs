@@ -0,0 +1,333 @@
+pub mod domain;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use domain::{EdgeSwipeTracker, Gesture, SwipeDirection, TouchpadSwipeTracker};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::GestureAction;
+use windows::{
+    core::PCWSTR,
+    Win32::{
+        Devices::HumanInterfaceDevice::{HidP_GetUsageValue, HidP_Input, PHIDP_PREPARSED_DATA},
+        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+        Graphics::Gdi::HMONITOR,
+        System::Threading::GetCurrentThreadId,
+        UI::{
+            Input::{
+                GetRawInputData, GetRawInputDeviceInfoW, RegisterRawInputDevices, HRAWINPUT,
+                RAWINPUT, RAWINPUTDEVICE, RAWINPUTHEADER, RIDEV_INPUTSINK, RIDI_PREPARSEDDATA,
+                RID_INPUT, RIM_TYPEHID,
+            },
+            WindowsAndMessaging::{
+                CallNextHookEx, CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW,
+                GetMessageW, PostThreadMessageW, RegisterClassW, SetWindowsHookExW,
+                TranslateMessage, UnhookWindowsHookEx, HC_ACTION, HHOOK, HWND_MESSAGE, MSG,
+                MSLLHOOKSTRUCT, WH_MOUSE_LL, WINDOW_EX_STYLE, WINDOW_STYLE, WM_INPUT,
+                WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MOUSEMOVE, WM_QUIT, WNDCLASSW,
+            },
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{input::domain::Point, secure_desktop::SecureDesktop, virtual_desk::get_vd_manager},
+    seelen::SEELEN,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref GESTURES_MANAGER: Arc<Mutex<GesturesManager>> =
+        Arc::new(Mutex::new(GesturesManager::default()));
+}
+
+/// Thread id of the gestures message loop, 0 if not running
+static GESTURES_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Mouse events synthesized from touch or pen input carry this signature on `dwExtraInfo`
+const MI_WP_SIGNATURE: usize = 0xFF515700;
+const MI_WP_SIGNATURE_MASK: usize = 0xFFFFFF00;
+
+const HID_USAGE_PAGE_DIGITIZER: u16 = 0x0D;
+const HID_USAGE_DIGITIZER_TOUCH_PAD: u16 = 0x05;
+const HID_USAGE_DIGITIZER_CONTACT_COUNT: u16 = 0x54;
+const HID_USAGE_PAGE_GENERIC: u16 = 0x01;
+const HID_USAGE_GENERIC_X: u16 = 0x30;
+
+#[derive(Debug, Default)]
+pub struct GesturesManager {
+    edge: EdgeSwipeTracker,
+    touchpad: TouchpadSwipeTracker,
+    touch_monitor: Option<HMONITOR>,
+}
+
+impl GesturesManager {
+    fn on_touch(&mut self, msg: u32, point: Point) {
+        let state = FULL_STATE.load();
+        let settings = &state.settings().gestures;
+        let side = state.settings().seelenweg.position;
+
+        match msg {
+            WM_LBUTTONDOWN => {
+                let monitor = WindowsApi::monitor_from_point(point.into());
+                if let Ok(rect) = WindowsApi::monitor_rect(monitor) {
+                    self.edge
+                        .down(point, &rect, side, settings.edge_size as i32);
+                    self.touch_monitor = Some(monitor);
+                }
+            }
+            WM_MOUSEMOVE => {
+                if let Some(gesture) = self
+                    .edge
+                    .moved(point, side, settings.swipe_threshold as i32)
+                {
+                    Self::dispatch(gesture, self.touch_monitor);
+                }
+            }
+            WM_LBUTTONUP => {
+                self.edge.up();
+                self.touch_monitor = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn on_touchpad_report(&mut self, contacts: u32, x: i32) {
+        let threshold = FULL_STATE.load().settings().gestures.swipe_threshold as i32;
+        if let Some(gesture) = self.touchpad.report(contacts, x, threshold) {
+            Self::dispatch(gesture, None);
+        }
+    }
+
+    /// actions are executed outside of the hook thread to avoid blocking the input queue
+    fn dispatch(gesture: Gesture, monitor: Option<HMONITOR>) {
+        let settings = FULL_STATE.load().settings().gestures.clone();
+        let action = match gesture {
+            Gesture::EdgeSwipe => settings.edge_swipe,
+            Gesture::ThreeFingersSwipe(SwipeDirection::Left) => settings.three_fingers_swipe_left,
+            Gesture::ThreeFingersSwipe(SwipeDirection::Right) => settings.three_fingers_swipe_right,
+        };
+        log::trace!("Gesture {:?} => {:?}", gesture, action);
+        std::thread::spawn(move || log_error!(Self::execute(action, monitor)));
+    }
+
+    fn execute(action: GestureAction, monitor: Option<HMONITOR>) -> Result<()> {
+        match action {
+            GestureAction::None => {}
+            GestureAction::RevealWeg => {
+                let mut seelen = trace_lock!(SEELEN);
+                for m in seelen.monitors_mut() {
+                    if monitor.is_some_and(|id| id != *m.handle()) {
+                        continue;
+                    }
+                    if let Some(weg) = m.weg_mut() {
+                        weg.set_overlaped_status(false)?;
+                    }
+                }
+            }
+            GestureAction::SwitchToNextWorkspace | GestureAction::SwitchToPreviousWorkspace => {
+                let vd = get_vd_manager();
+                let len = vd.get_all()?.len();
+                if len == 0 {
+                    return Ok(());
+                }
+                let current = vd.get_current_idx()?;
+                let next = if action == GestureAction::SwitchToNextWorkspace {
+                    (current + 1) % len
+                } else {
+                    (current + len - 1) % len
+                };
+                vd.switch_to(next)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "system" fn mouse_hook_proc(code: i32, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 && !SecureDesktop::is_active() {
+        let data = &*(l_param.0 as *const MSLLHOOKSTRUCT);
+        if data.dwExtraInfo & MI_WP_SIGNATURE_MASK == MI_WP_SIGNATURE {
+            if let Some(mut manager) = GESTURES_MANAGER.try_lock() {
+                manager.on_touch(w_param.0 as u32, Point::from(data.pt));
+            }
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
+
+/// Reads the contacts count and the x position of the first contact
+/// from a precision touchpad report
+unsafe fn read_touchpad_report(l_param: LPARAM) -> Result<Option<(u32, i32)>> {
+    let handle = HRAWINPUT(l_param.0);
+    let header_size = std::mem::size_of::<RAWINPUTHEADER>() as u32;
+
+    let mut size = 0;
+    GetRawInputData(handle, RID_INPUT, None, &mut size, header_size);
+    let mut buffer = vec![0u8; size as usize];
+    if GetRawInputData(
+        handle,
+        RID_INPUT,
+        Some(buffer.as_mut_ptr() as _),
+        &mut size,
+        header_size,
+    ) != size
+    {
+        return Err("Failed to read raw input data".into());
+    }
+
+    let raw = &*(buffer.as_ptr() as *const RAWINPUT);
+    if raw.header.dwType != RIM_TYPEHID.0 {
+        return Ok(None);
+    }
+
+    let mut size = 0;
+    GetRawInputDeviceInfoW(raw.header.hDevice, RIDI_PREPARSEDDATA, None, &mut size);
+    let mut preparsed = vec![0u8; size as usize];
+    GetRawInputDeviceInfoW(
+        raw.header.hDevice,
+        RIDI_PREPARSEDDATA,
+        Some(preparsed.as_mut_ptr() as _),
+        &mut size,
+    );
+    let preparsed = PHIDP_PREPARSED_DATA(preparsed.as_ptr() as isize);
+
+    let hid = &raw.data.hid;
+    let report = std::slice::from_raw_parts(
+        hid.bRawData.as_ptr(),
+        (hid.dwSizeHid * hid.dwCount) as usize,
+    );
+
+    let mut contacts = 0;
+    HidP_GetUsageValue(
+        HidP_Input,
+        HID_USAGE_PAGE_DIGITIZER,
+        0,
+        HID_USAGE_DIGITIZER_CONTACT_COUNT,
+        &mut contacts,
+        preparsed,
+        report,
+    )
+    .ok()?;
+
+    // each finger is reported on its own link collection, the first one starts at 1
+    let mut x = 0;
+    HidP_GetUsageValue(
+        HidP_Input,
+        HID_USAGE_PAGE_GENERIC,
+        1,
+        HID_USAGE_GENERIC_X,
+        &mut x,
+        preparsed,
+        report,
+    )
+    .ok()?;
+
+    Ok(Some((contacts, x as i32)))
+}
+
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    msg: u32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if msg == WM_INPUT {
+        match read_touchpad_report(l_param) {
+            Ok(Some((contacts, x))) => {
+                trace_lock!(GESTURES_MANAGER).on_touchpad_report(contacts, x)
+            }
+            Ok(None) => {}
+            Err(err) => log::error!("{:?}", err),
+        }
+    }
+    DefWindowProcW(hwnd, msg, w_param, l_param)
+}
+
+impl GesturesManager {
+    pub fn start() -> Result<()> {
+        if GESTURES_THREAD_ID.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Registering touch and touchpad gestures");
+
+        let wide_class: Vec<u16> = "SeelenGesturesManager"
+            .encode_utf16()
+            .chain(Some(0))
+            .collect();
+        let h_module = WindowsApi::module_handle_w()?;
+
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: PCWSTR(wide_class.as_ptr()),
+            ..Default::default()
+        };
+
+        unsafe {
+            RegisterClassW(&wnd_class);
+        }
+
+        spawn_named_thread("Gestures Manager", move || unsafe {
+            GESTURES_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+
+            let hook =
+                SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook_proc), HINSTANCE(h_module.0), 0);
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                PCWSTR(wide_class.as_ptr()),
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                None,
+                h_module,
+                None,
+            );
+
+            let touchpad = RAWINPUTDEVICE {
+                usUsagePage: HID_USAGE_PAGE_DIGITIZER,
+                usUsage: HID_USAGE_DIGITIZER_TOUCH_PAD,
+                dwFlags: RIDEV_INPUTSINK,
+                hwndTarget: hwnd,
+            };
+            log_error!(RegisterRawInputDevices(
+                &[touchpad],
+                std::mem::size_of::<RAWINPUTDEVICE>() as u32
+            ));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            log_error!(DestroyWindow(hwnd));
+            GESTURES_THREAD_ID.store(0, Ordering::Release);
+            log::trace!("Gestures Manager stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() -> Result<()> {
+        let thread_id = GESTURES_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0))? };
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+/// Keeps track of the idle rules already triggered, each rule is triggered once
+/// when the idle time reaches its threshold and reverted on the next user input.
+#[derive(Debug, Default)]
+pub struct IdleTracker {
+    last_idle_secs: u64,
+    active: Vec<usize>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct IdleChanges {
+    pub triggered: Vec<usize>,
+    pub reverted: Vec<usize>,
+}
+
+impl IdleTracker {
+    pub fn is_idle(&self) -> bool {
+        !self.active.is_empty()
+    }
+
+    /// `thresholds` are the seconds of each rule, returned values are indexes of that slice
+    pub fn update(&mut self, idle_secs: u64, thresholds: &[u64]) -> IdleChanges {
+        let mut changes = IdleChanges::default();
+
+        // idle time only goes down when the user interacts with the system
+        if idle_secs < self.last_idle_secs {
+            changes.reverted = std::mem::take(&mut self.active);
+        }
+        self.last_idle_secs = idle_secs;
+
+        for (idx, threshold) in thresholds.iter().enumerate() {
+            if idle_secs >= *threshold && !self.active.contains(&idx) {
+                self.active.push(idx);
+                changes.triggered.push(idx);
+            }
+        }
+        changes
+    }
+
+    /// forgets the active rules, used when the rules are changed
+    pub fn reset(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.active)
+    }
+}
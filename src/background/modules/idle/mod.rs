@@ -0,0 +1,201 @@
+pub mod domain;
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use domain::IdleTracker;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{IdleAction, IdleRule};
+use serde::Serialize;
+use tauri::Emitter;
+use tauri_plugin_shell::ShellExt;
+use windows::{
+    core::PCWSTR,
+    Win32::{
+        System::SystemInformation::GetTickCount,
+        UI::{
+            Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO},
+            Shell::{DesktopWallpaper, IDesktopWallpaper, DSD_FORWARD},
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::{get_app_handle, SEELEN},
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::Com,
+};
+
+lazy_static! {
+    static ref IDLE_MANAGER: Arc<Mutex<IdleManager>> = Arc::new(Mutex::new(IdleManager::default()));
+}
+
+/// true while the idle monitor thread is running
+static IDLE_MONITOR_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleState {
+    idle: bool,
+    idle_seconds: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct IdleManager {
+    tracker: IdleTracker,
+    /// last time the wallpaper was advanced, `Some` while the slideshow action is active
+    slideshow: Option<Instant>,
+}
+
+impl IdleManager {
+    /// Time since the last keyboard or mouse input on the session
+    pub fn idle_seconds() -> Result<u64> {
+        let mut info = LASTINPUTINFO {
+            cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+            ..Default::default()
+        };
+        unsafe { GetLastInputInfo(&mut info).ok()? };
+        // both are tick counts, wrapping_sub handles the 49.7 days overflow
+        let elapsed = unsafe { GetTickCount() }.wrapping_sub(info.dwTime);
+        Ok(elapsed as u64 / 1000)
+    }
+
+    fn tick(&mut self) -> Result<()> {
+        let settings = FULL_STATE.load().settings().idle.clone();
+        let idle_seconds = Self::idle_seconds()?;
+
+        let was_idle = self.tracker.is_idle();
+        let thresholds: Vec<u64> = settings.rules.iter().map(|r| r.after).collect();
+        let changes = self.tracker.update(idle_seconds, &thresholds);
+
+        for idx in changes.reverted {
+            if let Some(rule) = settings.rules.get(idx) {
+                log_error!(self.revert(rule));
+            }
+        }
+        for idx in changes.triggered {
+            if let Some(rule) = settings.rules.get(idx) {
+                log::trace!("Idle for {}s, triggering {:?}", idle_seconds, rule.action);
+                log_error!(self.trigger(rule));
+            }
+        }
+
+        if let Some(last) = self.slideshow {
+            if last.elapsed().as_secs() >= settings.slideshow_interval {
+                self.slideshow = Some(Instant::now());
+                log_error!(Self::advance_wallpaper());
+            }
+        }
+
+        if was_idle != self.tracker.is_idle() {
+            get_app_handle().emit(
+                "idle-state-changed",
+                IdleState {
+                    idle: self.tracker.is_idle(),
+                    idle_seconds,
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    fn trigger(&mut self, rule: &IdleRule) -> Result<()> {
+        match rule.action {
+            IdleAction::DimWeg => {
+                get_app_handle().emit("weg-set-dimmed", true)?;
+            }
+            IdleAction::AutoHideWeg => Self::set_weg_overlaped(true)?,
+            IdleAction::WallpaperSlideshow => {
+                self.slideshow = Some(Instant::now());
+                Self::advance_wallpaper()?;
+            }
+            IdleAction::RunCommand => {
+                let (program, args) = match rule.command.as_deref() {
+                    Some([program, args @ ..]) => (program.clone(), args.to_vec()),
+                    _ => return Err("Idle rule RunCommand without command".into()),
+                };
+                tauri::async_runtime::spawn(async move {
+                    log_error!(
+                        get_app_handle()
+                            .shell()
+                            .command(program)
+                            .args(args)
+                            .status()
+                            .await
+                    );
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn revert(&mut self, rule: &IdleRule) -> Result<()> {
+        match rule.action {
+            IdleAction::DimWeg => {
+                get_app_handle().emit("weg-set-dimmed", false)?;
+            }
+            IdleAction::AutoHideWeg => Self::set_weg_overlaped(false)?,
+            IdleAction::WallpaperSlideshow => self.slideshow = None,
+            IdleAction::RunCommand => {}
+        }
+        Ok(())
+    }
+
+    fn set_weg_overlaped(overlaped: bool) -> Result<()> {
+        let mut seelen = trace_lock!(SEELEN);
+        for monitor in seelen.monitors_mut() {
+            if let Some(weg) = monitor.weg_mut() {
+                weg.set_overlaped_status(overlaped)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn advance_wallpaper() -> Result<()> {
+        Com::run_with_context(|| {
+            let wallpaper: IDesktopWallpaper = Com::create_instance(&DesktopWallpaper)?;
+            unsafe { wallpaper.AdvanceSlideshow(PCWSTR::null(), DSD_FORWARD)? };
+            Ok(())
+        })
+    }
+}
+
+impl IdleManager {
+    pub fn start() -> Result<()> {
+        if IDLE_MONITOR_RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting idle monitor");
+
+        spawn_named_thread("Idle Monitor", || {
+            while IDLE_MONITOR_RUNNING.load(Ordering::Acquire) {
+                log_error!(trace_lock!(IDLE_MANAGER).tick());
+                sleep_millis(1000);
+            }
+
+            let mut manager = trace_lock!(IDLE_MANAGER);
+            let rules = FULL_STATE.load().settings().idle.rules.clone();
+            for idx in manager.tracker.reset() {
+                if let Some(rule) = rules.get(idx) {
+                    log_error!(manager.revert(rule));
+                }
+            }
+            log::trace!("Idle monitor stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        IDLE_MONITOR_RUNNING.store(false, Ordering::Release);
+    }
+}
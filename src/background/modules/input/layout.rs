@@ -0,0 +1,70 @@
+use serde::Serialize;
+use windows::{
+    core::PCWSTR,
+    Win32::{
+        Foundation::HWND,
+        Globalization::{GetLocaleInfoEx, LCIDToLocaleName, LOCALE_SLOCALIZEDDISPLAYNAME},
+        UI::{
+            Input::KeyboardAndMouse::{GetKeyboardLayout, HKL},
+            WindowsAndMessaging::GetWindowThreadProcessId,
+        },
+    },
+};
+
+use crate::windows_api::WindowsApi;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyboardLayout {
+    /// locale of the layout language, like `en-US`
+    pub locale: String,
+    /// localized display name, like `English (United States)`
+    pub display_name: String,
+}
+
+impl KeyboardLayout {
+    /// Layout of the thread owning the foreground window, layouts are per thread
+    pub fn foreground_hkl() -> HKL {
+        Self::hkl_of(WindowsApi::get_foreground_window())
+    }
+
+    /// Layout of the thread owning the window
+    pub fn hkl_of(hwnd: HWND) -> HKL {
+        unsafe {
+            let thread_id = GetWindowThreadProcessId(hwnd, None);
+            GetKeyboardLayout(thread_id)
+        }
+    }
+
+    pub fn from_hkl(hkl: HKL) -> Option<Self> {
+        // the low word of the handle is the language identifier
+        let lang_id = (hkl.0 as usize & 0xFFFF) as u32;
+
+        let mut locale = [0u16; 85];
+        let len = unsafe { LCIDToLocaleName(lang_id, Some(&mut locale), 0) };
+        if len <= 0 {
+            return None;
+        }
+
+        let mut display_name = [0u16; 256];
+        let display_len = unsafe {
+            GetLocaleInfoEx(
+                PCWSTR(locale.as_ptr()),
+                LOCALE_SLOCALIZEDDISPLAYNAME,
+                Some(&mut display_name),
+            )
+        };
+
+        // lengths include the null terminator
+        let locale = String::from_utf16_lossy(&locale[..len as usize - 1]);
+        let display_name = if display_len > 0 {
+            String::from_utf16_lossy(&display_name[..display_len as usize - 1])
+        } else {
+            locale.clone()
+        };
+        Some(Self {
+            locale,
+            display_name,
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicIsize, Ordering},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::{
+    Foundation::{HWND, LPARAM, WPARAM},
+    UI::WindowsAndMessaging::{PostMessageW, WM_INPUTLANGCHANGEREQUEST},
+};
+
+use crate::{
+    log_error,
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::{window::Window, WindowsApi},
+    winevent::WinEvent,
+};
+
+use super::layout::KeyboardLayout;
+
+lazy_static! {
+    /// last keyboard layout used on each window, by handle
+    static ref LAYOUTS: Mutex<HashMap<isize, isize>> = Mutex::new(HashMap::new());
+}
+
+/// last focused window that is not a Seelen window
+static LAST_FOCUSED: AtomicIsize = AtomicIsize::new(0);
+
+/// Remembers the keyboard layout of each window and restores it when the window is focused
+/// again, as Windows only keeps a single layout for all the apps by default.
+pub struct LayoutMemory;
+
+impl LayoutMemory {
+    pub fn on_win_event(event: WinEvent, origin: HWND) {
+        match event {
+            WinEvent::SystemForeground => {
+                if !FULL_STATE.load().settings().keyboard_layout.per_window {
+                    return;
+                }
+                if Window::from(origin).is_seelen_window() {
+                    return;
+                }
+                let previous = HWND(LAST_FOCUSED.swap(origin.0, Ordering::Relaxed));
+                if previous != origin && WindowsApi::is_window(previous) {
+                    Self::save(previous);
+                }
+                Self::restore(origin);
+            }
+            WinEvent::ObjectDestroy => {
+                trace_lock!(LAYOUTS).remove(&origin.0);
+            }
+            _ => {}
+        }
+    }
+
+    fn save(hwnd: HWND) {
+        let hkl = KeyboardLayout::hkl_of(hwnd);
+        if hkl.0 != 0 {
+            trace_lock!(LAYOUTS).insert(hwnd.0, hkl.0);
+        }
+    }
+
+    fn restore(hwnd: HWND) {
+        let Some(hkl) = trace_lock!(LAYOUTS).get(&hwnd.0).copied() else {
+            return;
+        };
+        if KeyboardLayout::hkl_of(hwnd).0 != hkl {
+            log_error!(unsafe {
+                PostMessageW(hwnd, WM_INPUTLANGCHANGEREQUEST, WPARAM(0), LPARAM(hkl))
+            });
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod domain;
+pub mod layout;
+pub mod layout_memory;
 
 use std::cmp::max;
 use std::cmp::min;
@@ -30,6 +32,7 @@ const VIRTUAL_KEYS: phf::Map<&'static str, VIRTUAL_KEY> = phf_map! {
     "PRIOR" => VK_PRIOR, "PAGE_UP" => VK_PRIOR, "NEXT" => VK_NEXT, "PAGE_DOWN" => VK_NEXT, "HOME" => VK_HOME, "END" => VK_END,
     "LEFT" => VK_LEFT, "UP" => VK_UP, "RIGHT" => VK_RIGHT, "DOWN" => VK_DOWN, "PRINT" => VK_PRINT,
     "INSERT" => VK_INSERT, "DELETE" => VK_DELETE,
+    "VOLUME_UP" => VK_VOLUME_UP, "VOLUME_DOWN" => VK_VOLUME_DOWN, "VOLUME_MUTE" => VK_VOLUME_MUTE,
     "F1" => VK_F1, "F2" => VK_F2, "F3" => VK_F3, "F4" => VK_F4, "F5" => VK_F5, "F6" => VK_F6, "F7" => VK_F7, "F8" => VK_F8, "F9" => VK_F9, "F10" => VK_F10,
     "F11" => VK_F11, "F12" => VK_F12, "F13" => VK_F13, "F14" => VK_F14, "F15" => VK_F15, "F16" => VK_F16, "F17" => VK_F17, "F18" => VK_F18, "F19" => VK_F19,
     "F20" => VK_F20, "F21" => VK_F21, "F22" => VK_F22, "F23" => VK_F23, "F24" => VK_F24,
@@ -467,6 +470,45 @@ impl Keyboard {
         }
     }
 
+    /// Types `text` as unicode characters, ignoring the keyboard layout and special sequences.
+    ///
+    /// Characters outside the BMP (like emojis) are sent as their surrogate pairs.
+    pub fn send_text(&self, text: &str) -> Result<()> {
+        let mut inputs = Vec::new();
+        for unit in text.encode_utf16() {
+            for flags in [KEYEVENTF_UNICODE, KEYEVENTF_UNICODE | KEYEVENTF_KEYUP] {
+                inputs.push(INPUT {
+                    r#type: INPUT_KEYBOARD,
+                    Anonymous: INPUT_0 {
+                        ki: KEYBDINPUT {
+                            wVk: VIRTUAL_KEY(0),
+                            wScan: unit,
+                            dwFlags: flags,
+                            time: 0,
+                            dwExtraInfo: 0,
+                        },
+                    },
+                });
+            }
+        }
+        self.send_keyboard(&inputs)
+    }
+
+    /// Taps an unassigned key, so releasing the Win key after a swallowed shortcut does not
+    /// open the start menu.
+    pub fn send_menu_mask(&self) -> Result<()> {
+        let mask = VIRTUAL_KEY(0xE8);
+        self.send_keyboard(&[
+            Input::create_virtual_key(mask, KEYEVENTF_KEYDOWN),
+            Input::create_virtual_key(mask, KEYEVENTF_KEYUP),
+        ])
+    }
+
+    /// Check if the key is currently held down.
+    pub fn is_pressed(key: VIRTUAL_KEY) -> bool {
+        unsafe { GetAsyncKeyState(key.0 as i32) as u16 & 0x8000 != 0 }
+    }
+
     // fn send_keyboard(&self, input: &Input) -> Result<()> {
     //     let input_keys = input.create_inputs()?;
     //     if self.interval == 0 {
@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+use crate::state::application::RuleMatchReport;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowInspection {
+    pub hwnd: isize,
+    pub title: String,
+    pub class: String,
+    pub exe: Option<String>,
+    pub path: Option<String>,
+    /// explicit AppUserModelID of the window, if any
+    pub aumid: Option<String>,
+    pub process_id: u32,
+    pub style: u32,
+    pub ex_style: u32,
+    /// names of the known flags present on `style` and `ex_style`
+    pub style_names: Vec<String>,
+    pub dpi: u32,
+    pub cloaked: bool,
+    pub visible: bool,
+    pub iconic: bool,
+    pub maximized: bool,
+    pub rules: RuleMatchReport,
+    /// ready to paste entry for `applications.yml` matching this window
+    pub app_config: String,
+}
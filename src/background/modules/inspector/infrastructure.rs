@@ -0,0 +1,13 @@
+use crate::error_handler::Result;
+
+use super::Inspector;
+
+#[tauri::command(async)]
+pub fn inspector_start_picking() -> Result<()> {
+    Inspector::start_picking()
+}
+
+#[tauri::command(async)]
+pub fn inspector_stop_picking() -> Result<()> {
+    Inspector::stop_picking()
+}
@@ -0,0 +1,209 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use domain::WindowInspection;
+use seelen_core::state::{AppConfig, AppIdentifier, AppIdentifierType, MatchingStrategy};
+use tauri::Emitter;
+use windows::Win32::{
+    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::WindowsAndMessaging::{
+        CallNextHookEx, DispatchMessageW, GetAncestor, GetMessageW, PostThreadMessageW,
+        SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx, WindowFromPoint, GA_ROOT,
+        HC_ACTION, HHOOK, MSG, MSLLHOOKSTRUCT, WH_MOUSE_LL, WINDOW_EX_STYLE, WINDOW_STYLE,
+        WM_LBUTTONDOWN, WM_LBUTTONUP, WM_QUIT, WM_RBUTTONDOWN, WM_RBUTTONUP, WS_CAPTION, WS_CHILD,
+        WS_DISABLED, WS_EX_APPWINDOW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW,
+        WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_MAXIMIZEBOX, WS_MINIMIZEBOX, WS_POPUP, WS_SYSMENU,
+        WS_THICKFRAME, WS_VISIBLE,
+    },
+};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, state::application::FULL_STATE,
+    utils::spawn_named_thread, windows_api::WindowsApi,
+};
+
+/// Thread id of the mouse hook loop, 0 if not picking
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+const KNOWN_STYLES: [(WINDOW_STYLE, &str); 9] = [
+    (WS_VISIBLE, "WS_VISIBLE"),
+    (WS_DISABLED, "WS_DISABLED"),
+    (WS_CHILD, "WS_CHILD"),
+    (WS_POPUP, "WS_POPUP"),
+    (WS_CAPTION, "WS_CAPTION"),
+    (WS_SYSMENU, "WS_SYSMENU"),
+    (WS_THICKFRAME, "WS_THICKFRAME"),
+    (WS_MINIMIZEBOX, "WS_MINIMIZEBOX"),
+    (WS_MAXIMIZEBOX, "WS_MAXIMIZEBOX"),
+];
+
+const KNOWN_EX_STYLES: [(WINDOW_EX_STYLE, &str); 6] = [
+    (WS_EX_TOPMOST, "WS_EX_TOPMOST"),
+    (WS_EX_TOOLWINDOW, "WS_EX_TOOLWINDOW"),
+    (WS_EX_APPWINDOW, "WS_EX_APPWINDOW"),
+    (WS_EX_NOACTIVATE, "WS_EX_NOACTIVATE"),
+    (WS_EX_LAYERED, "WS_EX_LAYERED"),
+    (WS_EX_TRANSPARENT, "WS_EX_TRANSPARENT"),
+];
+
+/// Developer tool to get information about any window by clicking it,
+/// the report is emitted as `window-inspected`.
+pub struct Inspector;
+
+impl Inspector {
+    pub fn is_picking() -> bool {
+        HOOK_THREAD_ID.load(Ordering::Acquire) != 0
+    }
+
+    /// The next left click is captured and the window under the cursor inspected,
+    /// a right click cancels the picking.
+    pub fn start_picking() -> Result<()> {
+        if Self::is_picking() {
+            return Ok(());
+        }
+
+        let h_module = WindowsApi::module_handle_w()?;
+        spawn_named_thread("Window Inspector Mouse Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            log_error!(get_app_handle().emit("window-inspector-picking", true));
+            let hook =
+                SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook_proc), HINSTANCE(h_module.0), 0);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+            log_error!(get_app_handle().emit("window-inspector-picking", false));
+        })?;
+        Ok(())
+    }
+
+    pub fn stop_picking() -> Result<()> {
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0))? };
+        }
+        Ok(())
+    }
+
+    pub fn inspect(hwnd: HWND) -> Result<WindowInspection> {
+        let state = FULL_STATE.load();
+        let style = WindowsApi::get_styles(hwnd);
+        let ex_style = WindowsApi::get_ex_styles(hwnd);
+
+        let mut style_names: Vec<String> = KNOWN_STYLES
+            .iter()
+            .filter(|(flag, _)| style.contains(*flag))
+            .map(|(_, name)| name.to_string())
+            .collect();
+        style_names.extend(
+            KNOWN_EX_STYLES
+                .iter()
+                .filter(|(flag, _)| ex_style.contains(*flag))
+                .map(|(_, name)| name.to_string()),
+        );
+
+        let rules = match state.match_rules_by_window(hwnd) {
+            Ok(rules) => rules,
+            // windows of elevated or protected processes can't be queried
+            Err(_) => state.match_rules(
+                &WindowsApi::get_window_text(hwnd),
+                &WindowsApi::get_class(hwnd).unwrap_or_default(),
+                "",
+                "",
+            ),
+        };
+
+        let exe = WindowsApi::exe(hwnd).ok();
+        Ok(WindowInspection {
+            hwnd: hwnd.0,
+            title: rules.title.clone(),
+            class: rules.class.clone(),
+            path: WindowsApi::exe_path(hwnd).ok(),
+            aumid: WindowsApi::get_window_app_user_model_id(hwnd)
+                .ok()
+                .filter(|id| !id.is_empty()),
+            process_id: WindowsApi::window_thread_process_id(hwnd).0,
+            style: style.0,
+            ex_style: ex_style.0,
+            style_names,
+            dpi: WindowsApi::get_window_dpi(hwnd),
+            cloaked: WindowsApi::is_cloaked(hwnd).unwrap_or(false),
+            visible: WindowsApi::is_window_visible(hwnd),
+            iconic: WindowsApi::is_iconic(hwnd),
+            maximized: WindowsApi::is_maximized(hwnd),
+            app_config: Self::app_config_snippet(&rules.class, exe.as_deref())?,
+            exe,
+            rules,
+        })
+    }
+
+    /// applications.yml entry identifying the window by exe or by class as fallback
+    fn app_config_snippet(class: &str, exe: Option<&str>) -> Result<String> {
+        let (id, kind) = match exe {
+            Some(exe) => (exe.to_string(), AppIdentifierType::Exe),
+            None => (class.to_string(), AppIdentifierType::Class),
+        };
+        let config = AppConfig {
+            name: id.trim_end_matches(".exe").to_string(),
+            category: None,
+            bound_monitor: None,
+            bound_workspace: None,
+            identifier: AppIdentifier {
+                id,
+                kind,
+                matching_strategy: MatchingStrategy::Equals,
+                negation: false,
+                and: Vec::new(),
+                or: Vec::new(),
+                regex: None,
+            },
+            options: Vec::new(),
+            opacity: None,
+            acrylic: None,
+            corners: None,
+            weg_while_focused: None,
+            toolbar_while_focused: None,
+            is_bundled: false,
+        };
+        Ok(serde_yaml::to_string(&vec![config])?)
+    }
+
+    fn on_pick(hwnd: HWND) {
+        log_error!(Self::stop_picking());
+        match Self::inspect(hwnd) {
+            Ok(report) => log_error!(get_app_handle().emit("window-inspected", report)),
+            Err(err) => log::error!("Failed to inspect window: {:?}", err),
+        }
+    }
+}
+
+unsafe extern "system" fn mouse_hook_proc(code: i32, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        let data = &*(l_param.0 as *const MSLLHOOKSTRUCT);
+        match w_param.0 as u32 {
+            WM_LBUTTONDOWN => {
+                let hwnd = GetAncestor(WindowFromPoint(data.pt), GA_ROOT);
+                std::thread::spawn(move || Inspector::on_pick(hwnd));
+                return LRESULT(1);
+            }
+            WM_RBUTTONDOWN => {
+                std::thread::spawn(|| log_error!(Inspector::stop_picking()));
+                return LRESULT(1);
+            }
+            // the button down was swallowed so the up should be too
+            WM_LBUTTONUP | WM_RBUTTONUP => return LRESULT(1),
+            _ => {}
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
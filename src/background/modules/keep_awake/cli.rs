@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::KeepAwake;
+
+get_subcommands![
+    /** Keeps the system and the display awake until disabled */
+    Enable,
+    /** Keeps the system and the display awake for the given minutes */
+    For(minutes: u64 => "Minutes to keep the system awake."),
+    /** Allows the system to sleep again */
+    Disable,
+    /** Enables keep awake if it is off, otherwise disables it */
+    Toggle,
+];
+
+impl KeepAwake {
+    pub const CLI_IDENTIFIER: &'static str = "keep-awake";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Prevents the system from sleeping")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Enable => Self::enable(None, true)?,
+            SubCommand::For(minutes) => {
+                Self::enable(Some(Duration::from_secs(minutes * 60)), true)?
+            }
+            SubCommand::Disable => Self::disable()?,
+            SubCommand::Toggle => Self::toggle()?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAwakeState {
+    /// true while the system is prevented from sleeping
+    pub active: bool,
+    /// true if the display is also kept on
+    pub keep_display_on: bool,
+    /// unix timestamp in milliseconds when the keep awake ends, `None` if indefinite
+    pub until: Option<u64>,
+}
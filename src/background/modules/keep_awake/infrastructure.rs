@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use crate::error_handler::Result;
+
+use super::{domain::KeepAwakeState, KeepAwake};
+
+#[tauri::command(async)]
+pub fn keep_awake_get_state() -> KeepAwakeState {
+    KeepAwake::state()
+}
+
+#[tauri::command(async)]
+pub fn keep_awake_enable(minutes: Option<u64>, keep_display_on: Option<bool>) -> Result<()> {
+    let duration = minutes.map(|minutes| Duration::from_secs(minutes * 60));
+    KeepAwake::enable(duration, keep_display_on.unwrap_or(true))
+}
+
+#[tauri::command(async)]
+pub fn keep_awake_disable() -> Result<()> {
+    KeepAwake::disable()
+}
+
+#[tauri::command(async)]
+pub fn keep_awake_toggle() -> Result<()> {
+    KeepAwake::toggle()
+}
@@ -0,0 +1,108 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use domain::KeepAwakeState;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use windows::Win32::System::Power::{
+    SetThreadExecutionState, ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED,
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+lazy_static! {
+    static ref KEEP_AWAKE_STATE: Arc<Mutex<KeepAwakeState>> =
+        Arc::new(Mutex::new(KeepAwakeState::default()));
+}
+
+/// Incremented on each enable/disable, the keep awake thread exits when it changes
+static KEEP_AWAKE_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+/// Prevents the system (and optionally the display) from sleeping.
+///
+/// The execution state is set per thread, so a dedicated thread holds it
+/// while keep awake is active and resets it when finished.
+pub struct KeepAwake;
+
+impl KeepAwake {
+    pub fn state() -> KeepAwakeState {
+        trace_lock!(KEEP_AWAKE_STATE).clone()
+    }
+
+    fn set_state(state: KeepAwakeState) -> Result<()> {
+        *trace_lock!(KEEP_AWAKE_STATE) = state.clone();
+        get_app_handle().emit("keep-awake-changed", state)?;
+        Ok(())
+    }
+
+    /// Keeps the system awake for `duration` or until disabled if `None`
+    pub fn enable(duration: Option<Duration>, keep_display_on: bool) -> Result<()> {
+        let generation = KEEP_AWAKE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        let deadline = duration.map(|duration| Instant::now() + duration);
+
+        let mut flags = ES_CONTINUOUS | ES_SYSTEM_REQUIRED;
+        if keep_display_on {
+            flags |= ES_DISPLAY_REQUIRED;
+        }
+
+        spawn_named_thread("Keep Awake", move || {
+            if unsafe { SetThreadExecutionState(flags) }.0 == 0 {
+                log::error!("Failed to set the thread execution state");
+            }
+
+            while KEEP_AWAKE_GENERATION.load(Ordering::SeqCst) == generation
+                && deadline.map_or(true, |deadline| Instant::now() < deadline)
+            {
+                sleep_millis(1000);
+            }
+
+            unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+            // expired, nobody else changed the state meanwhile
+            if KEEP_AWAKE_GENERATION.load(Ordering::SeqCst) == generation {
+                log_error!(Self::set_state(KeepAwakeState::default()));
+            }
+        })?;
+
+        let until = duration.map(|duration| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            (now + duration).as_millis() as u64
+        });
+        Self::set_state(KeepAwakeState {
+            active: true,
+            keep_display_on,
+            until,
+        })
+    }
+
+    pub fn disable() -> Result<()> {
+        KEEP_AWAKE_GENERATION.fetch_add(1, Ordering::SeqCst);
+        Self::set_state(KeepAwakeState::default())
+    }
+
+    /// Toggles an indefinite keep awake, keeping the display on
+    pub fn toggle() -> Result<()> {
+        if Self::state().active {
+            Self::disable()
+        } else {
+            Self::enable(None, true)
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum LauncherAction {
+    /// file, folder, shortcut or uri opened by the shell
+    Open { target: String },
+    /// copies the text to the clipboard
+    Copy { text: String },
+    /// brings an open window to the foreground
+    Focus { hwnd: isize },
+    /// relaunches a recently closed app
+    Reopen { id: u32 },
+    /// saves the text as a quick note
+    AddNote { text: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherItem {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub icon: Option<PathBuf>,
+    pub action: LauncherAction,
+    /// higher is better, used to merge the results of all the providers
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherResults {
+    /// id returned by `launcher_search`, results of older searches should be ignored
+    pub search_id: u32,
+    pub provider: &'static str,
+    pub items: Vec<LauncherItem>,
+}
+
+/// Scores how well `text` matches `query`, `None` if it doesn't match at all.
+/// Exact > prefix > word start > substring > subsequence of word initials.
+pub fn match_score(query: &str, text: &str) -> Option<u32> {
+    let query = query.trim().to_lowercase();
+    let text = text.to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    if text == query {
+        return Some(1000);
+    }
+    if text.starts_with(&query) {
+        return Some(800 - text.len().min(100) as u32);
+    }
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.iter().any(|w| w.starts_with(&query)) {
+        return Some(600 - text.len().min(100) as u32);
+    }
+    if text.contains(&query) {
+        return Some(400 - text.len().min(100) as u32);
+    }
+    let initials: String = words.iter().filter_map(|w| w.chars().next()).collect();
+    if initials.starts_with(&query) {
+        return Some(300);
+    }
+    None
+}
+
+/// Percent-encodes a query to be used on a url
+pub fn encode_uri_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,18 @@
+use crate::error_handler::Result;
+
+use super::{domain::LauncherAction, Launcher, LAUNCHER};
+
+#[tauri::command(async)]
+pub fn launcher_search(query: String) -> Result<u32> {
+    LAUNCHER.search(query)
+}
+
+#[tauri::command(async)]
+pub fn launcher_refresh() {
+    LAUNCHER.refresh();
+}
+
+#[tauri::command(async)]
+pub fn launcher_execute(action: LauncherAction) -> Result<()> {
+    Launcher::execute(action)
+}
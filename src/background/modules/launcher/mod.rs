@@ -0,0 +1,123 @@
+pub mod domain;
+pub mod infrastructure;
+pub mod providers;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use domain::{LauncherAction, LauncherResults};
+use lazy_static::lazy_static;
+use providers::{
+    AppsProvider, CalculatorProvider, FilesProvider, NotesProvider, RecentlyClosedProvider,
+    SearchProvider, SettingsProvider, WebProvider, WindowsProvider,
+};
+use tauri::Emitter;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{notes::Notes, recently_closed::RecentlyClosed},
+    seelen::get_app_handle,
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    pub static ref LAUNCHER: Launcher = Launcher::new();
+}
+
+pub struct Launcher {
+    providers: Vec<Arc<dyn SearchProvider>>,
+    last_search: AtomicU32,
+}
+
+impl Launcher {
+    const RESULTS_EVENT: &'static str = "launcher-results";
+    const RESULTS_BY_PROVIDER: usize = 10;
+
+    fn new() -> Self {
+        Self {
+            providers: vec![
+                Arc::new(CalculatorProvider),
+                Arc::new(WindowsProvider),
+                Arc::new(RecentlyClosedProvider),
+                Arc::new(AppsProvider::default()),
+                Arc::new(SettingsProvider),
+                Arc::new(NotesProvider),
+                Arc::new(FilesProvider),
+                Arc::new(WebProvider),
+            ],
+            last_search: AtomicU32::new(0),
+        }
+    }
+
+    pub fn refresh(&self) {
+        for provider in &self.providers {
+            provider.refresh();
+        }
+    }
+
+    pub fn warm_up(&self) {
+        for provider in &self.providers {
+            provider.warm_up();
+        }
+    }
+
+    /// Runs the query on every provider in parallel, each one emits its results as soon
+    /// as they are ready so slow providers don't delay the others.
+    /// Returns the id of this search, results of previous searches are discarded.
+    pub fn search(&'static self, query: String) -> Result<u32> {
+        let search_id = self.last_search.fetch_add(1, Ordering::SeqCst) + 1;
+        let query = Arc::new(query);
+        for provider in &self.providers {
+            let provider = provider.clone();
+            let query = query.clone();
+            spawn_named_thread("Launcher Search", move || {
+                let items = match provider.search(&query, Self::RESULTS_BY_PROVIDER) {
+                    Ok(items) => items,
+                    Err(err) => {
+                        log::warn!("Launcher provider {} failed: {}", provider.id(), err);
+                        return;
+                    }
+                };
+                // the user kept typing, this result is already outdated
+                if self.last_search.load(Ordering::SeqCst) != search_id {
+                    return;
+                }
+                log_error!(get_app_handle().emit(
+                    Self::RESULTS_EVENT,
+                    LauncherResults {
+                        search_id,
+                        provider: provider.id(),
+                        items,
+                    }
+                ));
+            })?;
+        }
+        Ok(search_id)
+    }
+
+    pub fn execute(action: LauncherAction) -> Result<()> {
+        match action {
+            LauncherAction::Open { target } => {
+                std::process::Command::new("explorer").arg(target).spawn()?;
+            }
+            LauncherAction::Copy { text } => WindowsApi::set_clipboard_text(&text)?,
+            LauncherAction::Focus { hwnd } => {
+                let hwnd = HWND(hwnd);
+                if WindowsApi::is_iconic(hwnd) {
+                    WindowsApi::restore_window(hwnd)?;
+                }
+                WindowsApi::force_set_foreground(hwnd)?;
+            }
+            LauncherAction::Reopen { id } => RecentlyClosed::reopen(id)?,
+            LauncherAction::AddNote { text } => {
+                Notes::add_note(text)?;
+            }
+        }
+        Ok(())
+    }
+}
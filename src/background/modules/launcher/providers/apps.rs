@@ -0,0 +1,122 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use parking_lot::Mutex;
+
+use crate::{
+    error_handler::Result,
+    modules::{
+        app_usage::AppUsageTracker,
+        launcher::domain::{match_score, LauncherAction, LauncherItem},
+    },
+    seelen::get_app_handle,
+    seelen_weg::icon_extractor::extract_and_save_shell_icon,
+    trace_lock,
+};
+
+use super::SearchProvider;
+
+const START_MENU_PROGRAMS: &str = r"Microsoft\Windows\Start Menu\Programs";
+
+/// Shortcuts of the start menu (current user and shared), the same list shown on "All apps"
+#[derive(Default)]
+pub struct AppsProvider {
+    shortcuts: Mutex<Option<Vec<PathBuf>>>,
+}
+
+impl AppsProvider {
+    fn roots() -> Vec<PathBuf> {
+        ["APPDATA", "PROGRAMDATA"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .map(|dir| PathBuf::from(dir).join(START_MENU_PROGRAMS))
+            .collect()
+    }
+
+    fn collect(dir: &Path, shortcuts: &mut Vec<PathBuf>) {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                Self::collect(&path, shortcuts);
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("lnk") || ext == "url")
+            {
+                shortcuts.push(path);
+            }
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<PathBuf> {
+        trace_lock!(self.shortcuts)
+            .get_or_insert_with(|| {
+                let mut shortcuts = Vec::new();
+                for root in Self::roots() {
+                    Self::collect(&root, &mut shortcuts);
+                }
+                shortcuts
+            })
+            .clone()
+    }
+}
+
+impl SearchProvider for AppsProvider {
+    fn id(&self) -> &'static str {
+        "apps"
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>> {
+        let ranking = AppUsageTracker::ranking();
+        let mut matches: Vec<(u32, PathBuf)> = self
+            .shortcuts()
+            .into_iter()
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_string_lossy().to_string();
+                let usage = ranking.get(&name.to_lowercase()).copied().unwrap_or(0);
+                // apps are usually what the user is looking for
+                Some((match_score(query, &name)? + 100 + usage, path))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0));
+        // same app installed for the user and for all the users
+        let mut seen = HashSet::new();
+        matches.retain(|(_, path)| seen.insert(path.file_name().map(|name| name.to_owned())));
+
+        let handle = get_app_handle();
+        Ok(matches
+            .into_iter()
+            .take(limit)
+            .map(|(score, path)| LauncherItem {
+                title: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+                subtitle: None,
+                icon: extract_and_save_shell_icon(&handle, &path).ok(),
+                action: LauncherAction::Open {
+                    target: path.to_string_lossy().to_string(),
+                },
+                score,
+            })
+            .collect())
+    }
+
+    fn refresh(&self) {
+        trace_lock!(self.shortcuts).take();
+    }
+
+    /// the shortcuts are listed again on each open, but their icons stay cached
+    fn warm_up(&self) {
+        let handle = get_app_handle();
+        for path in self.shortcuts() {
+            let _ = extract_and_save_shell_icon(&handle, &path);
+        }
+    }
+}
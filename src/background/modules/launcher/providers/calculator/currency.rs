@@ -0,0 +1,102 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::{error_handler::Result, seelen::get_app_handle, trace_lock, utils::app_cache_path};
+
+const RATES_URL: &str = "https://open.er-api.com/v6/latest/USD";
+const MAX_AGE: Duration = Duration::from_secs(12 * 60 * 60);
+const RETRY_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+lazy_static! {
+    static ref RATES: Mutex<Option<CurrencyRates>> = Mutex::new(None);
+    static ref LAST_FETCH_ATTEMPT: Mutex<Option<Instant>> = Mutex::new(None);
+}
+
+/// Exchange rates with USD as base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CurrencyRates {
+    /// unix timestamp in seconds
+    fetched_at: u64,
+    rates: HashMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+struct RatesResponse {
+    rates: HashMap<String, f64>,
+}
+
+impl CurrencyRates {
+    fn cache_path() -> Result<PathBuf> {
+        Ok(app_cache_path(&get_app_handle())?.join("currency_rates.json"))
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    fn is_outdated(&self) -> bool {
+        Self::now().saturating_sub(self.fetched_at) > MAX_AGE.as_secs()
+    }
+
+    fn load_cached() -> Option<Self> {
+        let contents = std::fs::read_to_string(Self::cache_path().ok()?).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn fetch() -> Result<Self> {
+        log::trace!("Fetching currency rates");
+        let response: RatesResponse = tauri::async_runtime::block_on(async {
+            tauri_plugin_http::reqwest::get(RATES_URL)
+                .await?
+                .json::<RatesResponse>()
+                .await
+        })?;
+        let rates = Self {
+            fetched_at: Self::now(),
+            rates: response.rates,
+        };
+        let path = Self::cache_path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(&rates)?)?;
+        Ok(rates)
+    }
+}
+
+pub fn is_currency(code: &str) -> bool {
+    code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Converts between currency codes using rates cached on disk, refreshed twice a day.
+/// Outdated rates are used if they can't be refreshed (offline).
+pub fn convert(value: f64, from: &str, to: &str) -> Option<f64> {
+    let mut guard = trace_lock!(RATES);
+    if guard.is_none() {
+        *guard = CurrencyRates::load_cached();
+    }
+    let mut last_attempt = trace_lock!(LAST_FETCH_ATTEMPT);
+    let can_retry = last_attempt.map_or(true, |last| last.elapsed() > RETRY_INTERVAL);
+    if can_retry && guard.as_ref().map_or(true, |rates| rates.is_outdated()) {
+        *last_attempt = Some(Instant::now());
+        match CurrencyRates::fetch() {
+            Ok(rates) => *guard = Some(rates),
+            Err(err) => log::warn!("Failed to fetch currency rates: {}", err),
+        }
+    }
+
+    let rates = &guard.as_ref()?.rates;
+    let from_rate = rates.get(&from.to_uppercase())?;
+    let to_rate = rates.get(&to.to_uppercase())?;
+    Some(value / from_rate * to_rate)
+}
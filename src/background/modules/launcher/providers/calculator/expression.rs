@@ -0,0 +1,299 @@
+use std::{iter::Peekable, str::Chars};
+
+/// Longer inputs are not evaluated, nobody types those in the launcher
+const MAX_INPUT_LEN: usize = 256;
+/// Max nesting of parentheses, functions and signs, the parser is recursive
+const MAX_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    Open,
+    Close,
+}
+
+fn tokenize(input: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars: Peekable<Chars> = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            ' ' | '_' => {
+                chars.next();
+            }
+            '0'..='9' | '.' => {
+                let mut number = String::new();
+                while let Some(&c) = chars.peek() {
+                    match c {
+                        '0'..='9' | '.' => number.push(c),
+                        // thousands separator
+                        ',' => {}
+                        _ => break,
+                    }
+                    chars.next();
+                }
+                tokens.push(Token::Number(number.parse().ok()?));
+            }
+            'a'..='z' | 'A'..='Z' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    ident.push(c.to_ascii_lowercase());
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            '+' | '-' | '*' | '/' | '%' | '^' => {
+                tokens.push(Token::Op(c));
+                chars.next();
+            }
+            '×' => {
+                tokens.push(Token::Op('*'));
+                chars.next();
+            }
+            '÷' => {
+                tokens.push(Token::Op('/'));
+                chars.next();
+            }
+            '(' => {
+                tokens.push(Token::Open);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                chars.next();
+            }
+            _ => return None,
+        }
+    }
+    Some(tokens)
+}
+
+/// Recursive descent parser over the tokens, precedence from lower to higher:
+/// `+ -`, `* / %`, unary `-`, `^` (right associative), functions and parentheses.
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.peek().cloned() {
+            self.next();
+            let rhs = self.term()?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Some(value)
+    }
+
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        while let Some(Token::Op(op @ ('*' | '/' | '%'))) = self.peek().cloned() {
+            self.next();
+            let rhs = self.unary()?;
+            value = match op {
+                '*' => value * rhs,
+                '/' => value / rhs,
+                _ => value % rhs,
+            };
+        }
+        Some(value)
+    }
+
+    fn unary(&mut self) -> Option<f64> {
+        // every nested rule goes through here, so this is enough to bound the recursion
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let value = match self.peek() {
+            Some(Token::Op('-')) => {
+                self.next();
+                Some(-self.unary()?)
+            }
+            Some(Token::Op('+')) => {
+                self.next();
+                self.unary()
+            }
+            _ => self.power(),
+        };
+        self.depth -= 1;
+        value
+    }
+
+    fn power(&mut self) -> Option<f64> {
+        let base = self.atom()?;
+        if let Some(Token::Op('^')) = self.peek() {
+            self.next();
+            return Some(base.powf(self.unary()?));
+        }
+        Some(base)
+    }
+
+    fn atom(&mut self) -> Option<f64> {
+        match self.next()? {
+            Token::Number(value) => Some(value),
+            Token::Open => {
+                let value = self.expression()?;
+                match self.next()? {
+                    Token::Close => Some(value),
+                    _ => None,
+                }
+            }
+            Token::Ident(name) => match name.as_str() {
+                "pi" => Some(std::f64::consts::PI),
+                "e" => Some(std::f64::consts::E),
+                _ => {
+                    let arg = match self.peek() {
+                        Some(Token::Open) => self.atom()?,
+                        _ => self.unary()?,
+                    };
+                    Self::function(&name, arg)
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn function(name: &str, arg: f64) -> Option<f64> {
+        Some(match name {
+            "sqrt" => arg.sqrt(),
+            "abs" => arg.abs(),
+            "sin" => arg.sin(),
+            "cos" => arg.cos(),
+            "tan" => arg.tan(),
+            "ln" => arg.ln(),
+            "log" => arg.log10(),
+            "round" => arg.round(),
+            "floor" => arg.floor(),
+            "ceil" => arg.ceil(),
+            _ => return None,
+        })
+    }
+}
+
+/// Evaluates a math expression, `None` if it is not a valid one
+pub fn evaluate(input: &str) -> Option<f64> {
+    if input.len() > MAX_INPUT_LEN {
+        return None;
+    }
+    let tokens = tokenize(input)?;
+    // a lone number is not worth showing as a result
+    if tokens.len() < 2 {
+        return None;
+    }
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.expression()?;
+    if parser.pos != parser.tokens.len() || !value.is_finite() {
+        return None;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizer() {
+        let cases = [
+            (
+                "1 + 2",
+                vec![Token::Number(1.0), Token::Op('+'), Token::Number(2.0)],
+            ),
+            ("1,000.5", vec![Token::Number(1000.5)]),
+            (
+                "3×4÷2",
+                vec![
+                    Token::Number(3.0),
+                    Token::Op('*'),
+                    Token::Number(4.0),
+                    Token::Op('/'),
+                    Token::Number(2.0),
+                ],
+            ),
+            (
+                "SQRT(9)",
+                vec![
+                    Token::Ident("sqrt".to_string()),
+                    Token::Open,
+                    Token::Number(9.0),
+                    Token::Close,
+                ],
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(tokenize(input), Some(expected), "{}", input);
+        }
+
+        for input in ["1 $ 2", "1..2", "2 = 2"] {
+            assert_eq!(tokenize(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn precedence() {
+        let cases = [
+            ("1 + 2 * 3", 7.0),
+            ("(1 + 2) * 3", 9.0),
+            ("10 - 4 - 3", 3.0),
+            ("16 / 4 / 2", 2.0),
+            ("10 % 4 * 2", 4.0),
+            ("2 ^ 3 ^ 2", 512.0),
+            ("-2 ^ 2", -4.0),
+            ("2 ^ -1", 0.5),
+            ("--3 + 1", 4.0),
+            ("sqrt 16 + 1", 5.0),
+            ("sqrt(16) * 2", 8.0),
+            ("abs(-3) + round(2.6)", 6.0),
+            ("2 * pi", std::f64::consts::TAU),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(evaluate(input), Some(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        for input in [
+            "",
+            "42",
+            "1 +",
+            "(1 + 2",
+            "1 + 2)",
+            "foo(2)",
+            "1 / 0",
+            "ln(-1) + 1",
+        ] {
+            assert_eq!(evaluate(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn nesting_is_bounded() {
+        let nested = |depth: usize| format!("{}1{} + 1", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(evaluate(&nested(10)), Some(2.0));
+        assert_eq!(evaluate(&nested(MAX_DEPTH)), None);
+        assert_eq!(evaluate(&format!("{}1", "-".repeat(100))), None);
+        assert_eq!(evaluate(&"1+".repeat(MAX_INPUT_LEN)), None);
+    }
+}
@@ -0,0 +1,130 @@
+mod currency;
+mod expression;
+mod units;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    error_handler::Result,
+    modules::launcher::domain::{LauncherAction, LauncherItem},
+};
+
+use super::SearchProvider;
+
+lazy_static! {
+    /// `<amount> <unit> to|in <unit>`, the amount can be an expression
+    static ref CONVERSION: Regex =
+        Regex::new(r"(?i)^\s*(.+?)\s*([a-z°/]+[23]?)\s+(?:to|in|as)\s+([a-z°/]+[23]?)\s*$")
+            .expect("Invalid regex");
+}
+
+/// Inline results for math expressions and unit/currency conversions
+pub struct CalculatorProvider;
+
+impl CalculatorProvider {
+    /// results go above everything else, the user was clearly not searching an app
+    const SCORE: u32 = 2000;
+
+    fn format(value: f64) -> String {
+        let formatted = format!("{:.10}", value);
+        let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+        match trimmed {
+            "-0" => "0".to_string(),
+            _ => trimmed.to_string(),
+        }
+    }
+
+    fn amount(input: &str) -> Option<f64> {
+        input
+            .replace(',', "")
+            .parse()
+            .ok()
+            .or_else(|| expression::evaluate(input))
+    }
+
+    fn conversion(query: &str) -> Option<(String, String)> {
+        let captures = CONVERSION.captures(query)?;
+        let amount = Self::amount(&captures[1])?;
+        let (from, to) = (&captures[2], &captures[3]);
+
+        if let Some((value, symbol)) = units::convert(amount, from, to) {
+            let result = Self::format(value);
+            let title = format!("{} {}", result, symbol);
+            return Some((title, result));
+        }
+
+        if currency::is_currency(from) && currency::is_currency(to) {
+            let value = currency::convert(amount, from, to)?;
+            let result = format!("{:.2}", value);
+            let title = format!("{} {}", result, to.to_uppercase());
+            return Some((title, result));
+        }
+        None
+    }
+}
+
+impl SearchProvider for CalculatorProvider {
+    fn id(&self) -> &'static str {
+        "calculator"
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<LauncherItem>> {
+        let query = query.trim().trim_start_matches('=').trim();
+        let result = match expression::evaluate(query) {
+            Some(value) => Some((Self::format(value), Self::format(value))),
+            None => Self::conversion(query),
+        };
+
+        Ok(result
+            .map(|(title, copy)| LauncherItem {
+                title,
+                subtitle: Some(query.to_string()),
+                icon: None,
+                action: LauncherAction::Copy { text: copy },
+                score: Self::SCORE,
+            })
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        let cases = [
+            (2.0, "2"),
+            (100.0, "100"),
+            (-1.5, "-1.5"),
+            (0.1 + 0.2, "0.3"),
+            (1.0 / 3.0, "0.3333333333"),
+            (-0.0, "0"),
+            (-1e-12, "0"),
+            (1234567.0, "1234567"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(CalculatorProvider::format(value), expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn unit_conversion_queries() {
+        let cases = [
+            ("5 km to m", "5000 m", "5000"),
+            ("2 + 3 km in m", "5000 m", "5000"),
+            ("1,500 m as km", "1.5 km", "1.5"),
+            ("100 c to f", "212 °F", "212"),
+        ];
+        for (query, title, copy) in cases {
+            assert_eq!(
+                CalculatorProvider::conversion(query),
+                Some((title.to_string(), copy.to_string())),
+                "{}",
+                query
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+    Length,
+    Mass,
+    Volume,
+    Area,
+    Time,
+    Speed,
+    Data,
+    Temperature,
+}
+
+/// (aliases, dimension, factor to the base unit of the dimension)
+const UNITS: &[(&[&str], Dimension, f64)] = &[
+    (
+        &["mm", "millimeter", "millimeters"],
+        Dimension::Length,
+        0.001,
+    ),
+    (
+        &["cm", "centimeter", "centimeters"],
+        Dimension::Length,
+        0.01,
+    ),
+    (&["m", "meter", "meters"], Dimension::Length, 1.0),
+    (
+        &["km", "kilometer", "kilometers"],
+        Dimension::Length,
+        1000.0,
+    ),
+    (&["in", "inch", "inches"], Dimension::Length, 0.0254),
+    (&["ft", "foot", "feet"], Dimension::Length, 0.3048),
+    (&["yd", "yard", "yards"], Dimension::Length, 0.9144),
+    (&["mi", "mile", "miles"], Dimension::Length, 1609.344),
+    (&["nmi"], Dimension::Length, 1852.0),
+    (&["mg", "milligram", "milligrams"], Dimension::Mass, 0.001),
+    (&["g", "gram", "grams"], Dimension::Mass, 1.0),
+    (&["kg", "kilogram", "kilograms"], Dimension::Mass, 1000.0),
+    (&["t", "tonne", "tonnes"], Dimension::Mass, 1_000_000.0),
+    (&["oz", "ounce", "ounces"], Dimension::Mass, 28.349523125),
+    (
+        &["lb", "lbs", "pound", "pounds"],
+        Dimension::Mass,
+        453.59237,
+    ),
+    (
+        &["ml", "milliliter", "milliliters"],
+        Dimension::Volume,
+        0.001,
+    ),
+    (&["l", "liter", "liters"], Dimension::Volume, 1.0),
+    (
+        &["gal", "gallon", "gallons"],
+        Dimension::Volume,
+        3.785411784,
+    ),
+    (&["qt", "quart", "quarts"], Dimension::Volume, 0.946352946),
+    (&["cup", "cups"], Dimension::Volume, 0.2365882365),
+    (&["floz"], Dimension::Volume, 0.0295735295625),
+    (&["m2", "sqm"], Dimension::Area, 1.0),
+    (&["km2", "sqkm"], Dimension::Area, 1_000_000.0),
+    (&["ft2", "sqft"], Dimension::Area, 0.09290304),
+    (&["ha", "hectare", "hectares"], Dimension::Area, 10_000.0),
+    (&["acre", "acres"], Dimension::Area, 4046.8564224),
+    (
+        &["ms", "millisecond", "milliseconds"],
+        Dimension::Time,
+        0.001,
+    ),
+    (&["s", "sec", "second", "seconds"], Dimension::Time, 1.0),
+    (&["min", "minute", "minutes"], Dimension::Time, 60.0),
+    (&["h", "hr", "hour", "hours"], Dimension::Time, 3600.0),
+    (&["day", "days"], Dimension::Time, 86400.0),
+    (&["week", "weeks"], Dimension::Time, 604800.0),
+    (&["kmh", "kph", "km/h"], Dimension::Speed, 1.0 / 3.6),
+    (&["mps", "m/s"], Dimension::Speed, 1.0),
+    (&["mph"], Dimension::Speed, 0.44704),
+    (&["kn", "knot", "knots"], Dimension::Speed, 0.514444),
+    (&["b", "byte", "bytes"], Dimension::Data, 1.0),
+    (&["kb", "kilobyte", "kilobytes"], Dimension::Data, 1e3),
+    (&["mb", "megabyte", "megabytes"], Dimension::Data, 1e6),
+    (&["gb", "gigabyte", "gigabytes"], Dimension::Data, 1e9),
+    (&["tb", "terabyte", "terabytes"], Dimension::Data, 1e12),
+    (&["kib", "kibibyte"], Dimension::Data, 1024.0),
+    (&["mib", "mebibyte"], Dimension::Data, 1048576.0),
+    (&["gib", "gibibyte"], Dimension::Data, 1073741824.0),
+    (&["c", "°c", "celsius"], Dimension::Temperature, 0.0),
+    (&["f", "°f", "fahrenheit"], Dimension::Temperature, 0.0),
+    (&["k", "kelvin"], Dimension::Temperature, 0.0),
+];
+
+fn find(unit: &str) -> Option<(&'static str, Dimension, f64)> {
+    let unit = unit.to_lowercase();
+    UNITS
+        .iter()
+        .find(|(aliases, _, _)| aliases.contains(&unit.as_str()))
+        .map(|(aliases, dimension, factor)| (aliases[0], *dimension, *factor))
+}
+
+fn to_kelvin(value: f64, unit: &str) -> f64 {
+    match unit {
+        "c" => value + 273.15,
+        "f" => (value - 32.0) * 5.0 / 9.0 + 273.15,
+        _ => value,
+    }
+}
+
+fn from_kelvin(value: f64, unit: &str) -> f64 {
+    match unit {
+        "c" => value - 273.15,
+        "f" => (value - 273.15) * 9.0 / 5.0 + 32.0,
+        _ => value,
+    }
+}
+
+/// Converts between units of the same dimension, returns the value and the canonical
+/// symbol of the target unit.
+pub fn convert(value: f64, from: &str, to: &str) -> Option<(f64, &'static str)> {
+    let (from, from_dimension, from_factor) = find(from)?;
+    let (to, to_dimension, to_factor) = find(to)?;
+    if from_dimension != to_dimension {
+        return None;
+    }
+    if from_dimension == Dimension::Temperature {
+        let symbol = match to {
+            "c" => "°C",
+            "f" => "°F",
+            _ => "K",
+        };
+        return Some((from_kelvin(to_kelvin(value, from), to), symbol));
+    }
+    Some((value * from_factor / to_factor, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let cases = [
+            (1.0, "km", "m", 1000.0, "m"),
+            (1.0, "mi", "km", 1.609344, "km"),
+            (2.0, "lbs", "kg", 0.90718474, "kg"),
+            (90.0, "min", "h", 1.5, "h"),
+            (1.0, "GiB", "mib", 1024.0, "mib"),
+            (100.0, "c", "f", 212.0, "°F"),
+            (32.0, "°f", "celsius", 0.0, "°C"),
+            (0.0, "c", "kelvin", 273.15, "K"),
+        ];
+        for (value, from, to, expected, symbol) in cases {
+            let (result, result_symbol) = convert(value, from, to).unwrap();
+            assert!(
+                (result - expected).abs() < 1e-9,
+                "{} {} to {}",
+                value,
+                from,
+                to
+            );
+            assert_eq!(result_symbol, symbol);
+        }
+    }
+
+    #[test]
+    fn incompatible_units() {
+        for (from, to) in [("km", "kg"), ("c", "m"), ("foo", "m"), ("m", "bar")] {
+            assert_eq!(convert(1.0, from, to), None, "{} to {}", from, to);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    cell::RefCell,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use color_eyre::eyre::eyre;
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        System::DataExchange::COPYDATASTRUCT,
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, FindWindowW,
+            PeekMessageW, RegisterClassW, SendMessageW, TranslateMessage, HWND_MESSAGE, MSG,
+            PM_REMOVE, WINDOW_EX_STYLE, WINDOW_STYLE, WM_COPYDATA, WNDCLASSW,
+        },
+    },
+};
+
+use crate::{error_handler::Result, utils::sleep_millis, windows_api::WindowsApi};
+
+/// Window that Everything creates to receive IPC requests
+const IPC_WINDOW_CLASS: PCWSTR = w!("EVERYTHING_TASKBAR_NOTIFICATION");
+const IPC_COPYDATA_QUERY2W: usize = 18;
+const REQUEST_FULL_PATH_AND_NAME: u32 = 0x00000004;
+const SORT_DATE_RECENTLY_CHANGED_DESCENDING: u32 = 14;
+/// arbitrary id used to recognize the reply
+const REPLY_ID: usize = 0x534c55;
+
+const REPLY_WINDOW_CLASS: PCWSTR = w!("SeelenEverythingReply");
+const REPLY_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// `EVERYTHING_IPC_QUERY2`, followed by the null terminated search string
+#[repr(C)]
+#[allow(dead_code)]
+struct IpcQuery2 {
+    reply_hwnd: u32,
+    reply_copydata_message: u32,
+    search_flags: u32,
+    offset: u32,
+    max_results: u32,
+    request_flags: u32,
+    sort_type: u32,
+}
+
+/// `EVERYTHING_IPC_LIST2` is 5 u32 (totitems, numitems, offset, request_flags, sort_type)
+/// followed by `numitems` `EVERYTHING_IPC_ITEM2` of 2 u32 (flags, data_offset).
+/// The data offset is relative to the start of the list.
+const LIST2_NUMITEMS_OFFSET: usize = 4;
+const LIST2_SIZE: usize = 20;
+const ITEM2_DATA_OFFSET: usize = 4;
+const ITEM2_SIZE: usize = 8;
+
+thread_local! {
+    static REPLY: RefCell<Option<Vec<PathBuf>>> = const { RefCell::new(None) };
+}
+
+/// Client of the voidtools Everything IPC, the reply is sent back to a message-only window
+/// owned by the calling thread so it can be used from any thread.
+pub struct Everything;
+
+impl Everything {
+    pub fn is_running() -> bool {
+        Self::ipc_window().is_some()
+    }
+
+    fn ipc_window() -> Option<HWND> {
+        let hwnd = unsafe { FindWindowW(IPC_WINDOW_CLASS, PCWSTR::null()) };
+        (hwnd.0 != 0).then_some(hwnd)
+    }
+
+    unsafe extern "system" fn reply_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_COPYDATA {
+            let data = &*(l_param.0 as *const COPYDATASTRUCT);
+            if data.dwData == REPLY_ID {
+                let bytes = std::slice::from_raw_parts(data.lpData as *const u8, data.cbData as _);
+                REPLY.with(|reply| *reply.borrow_mut() = Some(Self::parse_reply(bytes)));
+                return LRESULT(1);
+            }
+        }
+        DefWindowProcW(hwnd, msg, w_param, l_param)
+    }
+
+    fn parse_reply(bytes: &[u8]) -> Vec<PathBuf> {
+        let read_u32 = |offset: usize| -> Option<u32> {
+            let slice = bytes.get(offset..offset + 4)?;
+            Some(u32::from_ne_bytes(slice.try_into().ok()?))
+        };
+
+        let mut paths = Vec::new();
+        let count = read_u32(LIST2_NUMITEMS_OFFSET).unwrap_or(0) as usize;
+        for index in 0..count {
+            let item = LIST2_SIZE + index * ITEM2_SIZE;
+            let data = match read_u32(item + ITEM2_DATA_OFFSET) {
+                Some(data) => data as usize,
+                None => break,
+            };
+            // full path is stored as its length in chars followed by the chars (no terminator)
+            let len = read_u32(data).unwrap_or(0) as usize;
+            let chars: Vec<u16> = match bytes.get(data + 4..data + 4 + len * 2) {
+                Some(chars) => chars
+                    .chunks_exact(2)
+                    .map(|c| u16::from_ne_bytes([c[0], c[1]]))
+                    .collect(),
+                None => break,
+            };
+            paths.push(PathBuf::from(String::from_utf16_lossy(&chars)));
+        }
+        paths
+    }
+
+    pub fn search(query: &str, limit: usize) -> Result<Vec<PathBuf>> {
+        let everything = Self::ipc_window().ok_or("Everything is not running")?;
+
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::reply_proc),
+            hInstance: h_module.into(),
+            lpszClassName: REPLY_WINDOW_CLASS,
+            ..Default::default()
+        };
+
+        let reply_hwnd = unsafe {
+            // fails if already registered by a previous search
+            RegisterClassW(&wnd_class);
+            CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                REPLY_WINDOW_CLASS,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                None,
+                h_module,
+                None,
+            )
+        };
+        if reply_hwnd.0 == 0 {
+            return Err(eyre!("Failed to create the Everything reply window").into());
+        }
+
+        let result = Self::query(everything, reply_hwnd, query, limit);
+        unsafe { DestroyWindow(reply_hwnd)? };
+        result
+    }
+
+    fn query(
+        everything: HWND,
+        reply_hwnd: HWND,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<PathBuf>> {
+        let header = IpcQuery2 {
+            // window handles are 32 bits wide even on 64 bits processes
+            reply_hwnd: reply_hwnd.0 as u32,
+            reply_copydata_message: REPLY_ID as u32,
+            search_flags: 0,
+            offset: 0,
+            max_results: limit as u32,
+            request_flags: REQUEST_FULL_PATH_AND_NAME,
+            sort_type: SORT_DATE_RECENTLY_CHANGED_DESCENDING,
+        };
+
+        let header_bytes = unsafe {
+            std::slice::from_raw_parts(
+                &header as *const IpcQuery2 as *const u8,
+                std::mem::size_of::<IpcQuery2>(),
+            )
+        };
+        let mut payload = header_bytes.to_vec();
+        for c in query.encode_utf16().chain(Some(0)) {
+            payload.extend_from_slice(&c.to_ne_bytes());
+        }
+
+        let data = COPYDATASTRUCT {
+            dwData: IPC_COPYDATA_QUERY2W,
+            cbData: payload.len() as u32,
+            lpData: payload.as_mut_ptr() as _,
+        };
+
+        REPLY.with(|reply| reply.borrow_mut().take());
+        let accepted = unsafe {
+            SendMessageW(
+                everything,
+                WM_COPYDATA,
+                WPARAM(reply_hwnd.0 as usize),
+                LPARAM(&data as *const COPYDATASTRUCT as isize),
+            )
+        };
+        if accepted.0 == 0 {
+            return Err("Everything rejected the search request".into());
+        }
+
+        let started = Instant::now();
+        while started.elapsed() < REPLY_TIMEOUT {
+            let mut msg = MSG::default();
+            unsafe {
+                while PeekMessageW(&mut msg, reply_hwnd, 0, 0, PM_REMOVE).as_bool() {
+                    let _ = TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
+                }
+            }
+            if let Some(paths) = REPLY.with(|reply| reply.borrow_mut().take()) {
+                return Ok(paths);
+            }
+            sleep_millis(10);
+        }
+        Err("Everything search timed out".into())
+    }
+}
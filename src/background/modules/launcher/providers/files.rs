@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use seelen_core::state::FileSearchBackend;
+
+use crate::{
+    error_handler::Result,
+    modules::launcher::domain::{match_score, LauncherAction, LauncherItem},
+    seelen::get_app_handle,
+    seelen_weg::icon_extractor::extract_and_save_shell_icon,
+    state::application::FULL_STATE,
+    utils::pwsh::PwshScript,
+};
+
+use super::{everything::Everything, SearchProvider};
+
+/// Files and folders by name, using Everything when available or Windows Search
+pub struct FilesProvider;
+
+impl FilesProvider {
+    /// shorter queries match almost everything on the index
+    const MIN_QUERY_LEN: usize = 3;
+
+    fn query_index(query: &str, limit: usize) -> Result<Vec<PathBuf>> {
+        let mut script = PwshScript::new(include_str!("search_index.ps1"));
+        script.with_args([query.to_string(), limit.to_string()]);
+        let output = tauri::async_runtime::block_on(script.execute())?;
+        if output.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&output)?)
+    }
+
+    fn find(query: &str, limit: usize) -> Result<Vec<PathBuf>> {
+        let backend = FULL_STATE.load().settings().launcher.file_search;
+        match backend {
+            FileSearchBackend::Everything => Everything::search(query, limit),
+            FileSearchBackend::WindowsSearch => Self::query_index(query, limit),
+            FileSearchBackend::Auto => {
+                if Everything::is_running() {
+                    match Everything::search(query, limit) {
+                        Ok(paths) => return Ok(paths),
+                        Err(err) => log::warn!("Everything search failed: {}", err),
+                    }
+                }
+                Self::query_index(query, limit)
+            }
+        }
+    }
+}
+
+impl SearchProvider for FilesProvider {
+    fn id(&self) -> &'static str {
+        "files"
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>> {
+        let query = query.trim();
+        if query.chars().count() < Self::MIN_QUERY_LEN {
+            return Ok(Vec::new());
+        }
+
+        let handle = get_app_handle();
+        Ok(Self::find(query, limit)?
+            .into_iter()
+            .map(|path| {
+                let name = path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                LauncherItem {
+                    // files rank below apps and settings with the same name
+                    score: match_score(query, &name).unwrap_or(100) / 2,
+                    subtitle: path
+                        .parent()
+                        .map(|parent| parent.to_string_lossy().to_string()),
+                    icon: extract_and_save_shell_icon(&handle, &path).ok(),
+                    action: LauncherAction::Open {
+                        target: path.to_string_lossy().to_string(),
+                    },
+                    title: name,
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,36 @@
+mod apps;
+mod calculator;
+mod everything;
+mod files;
+mod notes;
+mod recently_closed;
+mod settings;
+mod web;
+mod windows;
+
+pub use apps::AppsProvider;
+pub use calculator::CalculatorProvider;
+pub use files::FilesProvider;
+pub use notes::NotesProvider;
+pub use recently_closed::RecentlyClosedProvider;
+pub use settings::SettingsProvider;
+pub use web::WebProvider;
+pub use windows::WindowsProvider;
+
+use crate::error_handler::Result;
+
+use super::domain::LauncherItem;
+
+pub trait SearchProvider: Send + Sync {
+    /// unique name of the provider, sent along with its results
+    fn id(&self) -> &'static str;
+
+    /// Returns the matching items sorted by score, called on its own thread for each search
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>>;
+
+    /// Drops any cached data, called each time the launcher is opened
+    fn refresh(&self) {}
+
+    /// Prepares any slow data ahead of the first search, called once after startup
+    fn warm_up(&self) {}
+}
@@ -0,0 +1,69 @@
+use crate::{
+    error_handler::Result,
+    modules::{
+        launcher::domain::{match_score, LauncherAction, LauncherItem},
+        notes::Notes,
+    },
+};
+
+use super::SearchProvider;
+
+/// Searches the quick notes and reminders, `note <text>` adds a new note
+pub struct NotesProvider;
+
+impl NotesProvider {
+    const PREFIX: &'static str = "note ";
+}
+
+impl SearchProvider for NotesProvider {
+    fn id(&self) -> &'static str {
+        "notes"
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>> {
+        let mut items = Vec::new();
+        if let Some(text) = query.strip_prefix(Self::PREFIX) {
+            if !text.trim().is_empty() {
+                items.push(LauncherItem {
+                    title: text.trim().to_string(),
+                    subtitle: Some("Add note".to_string()),
+                    icon: None,
+                    action: LauncherAction::AddNote {
+                        text: text.trim().to_string(),
+                    },
+                    score: 1000,
+                });
+            }
+        }
+
+        let store = Notes::get();
+        for note in store.notes {
+            if let Some(score) = match_score(query, &note.text) {
+                items.push(LauncherItem {
+                    title: note.text.clone(),
+                    subtitle: Some("Copy note".to_string()),
+                    icon: None,
+                    action: LauncherAction::Copy { text: note.text },
+                    score,
+                });
+            }
+        }
+        for reminder in store.reminders.into_iter().filter(|r| !r.done) {
+            if let Some(score) = match_score(query, &reminder.text) {
+                items.push(LauncherItem {
+                    title: reminder.text.clone(),
+                    subtitle: Some("Copy reminder".to_string()),
+                    icon: None,
+                    action: LauncherAction::Copy {
+                        text: reminder.text,
+                    },
+                    score,
+                });
+            }
+        }
+
+        items.sort_by(|a, b| b.score.cmp(&a.score));
+        items.truncate(limit);
+        Ok(items)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    error_handler::Result,
+    modules::{
+        launcher::domain::{match_score, LauncherAction, LauncherItem},
+        recently_closed::RecentlyClosed,
+    },
+    seelen::get_app_handle,
+    seelen_weg::{app_names::AppNames, icon_extractor::extract_and_save_shell_icon},
+};
+
+use super::SearchProvider;
+
+/// "Recently closed" section, shown with an empty query or filtered by title and app name
+pub struct RecentlyClosedProvider;
+
+impl SearchProvider for RecentlyClosedProvider {
+    fn id(&self) -> &'static str {
+        "recently-closed"
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>> {
+        let handle = get_app_handle();
+        let closed = RecentlyClosed::list();
+        let count = closed.len() as u32;
+        let mut items: Vec<LauncherItem> = closed
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, window)| {
+                let exe = window.path.file_name()?.to_string_lossy().to_string();
+                let name = AppNames::get(&window.path.to_string_lossy());
+                // without query the most recent ones go first
+                let score = if query.trim().is_empty() {
+                    count - idx as u32
+                } else {
+                    match_score(query, &window.title)
+                        .max(match_score(query, &name))
+                        .max(match_score(query, &exe))?
+                };
+                Some(LauncherItem {
+                    title: window.title,
+                    subtitle: Some(format!("Reopen {name}")),
+                    icon: extract_and_save_shell_icon(&handle, &window.path).ok(),
+                    action: LauncherAction::Reopen { id: window.id },
+                    score,
+                })
+            })
+            .collect();
+        items.sort_by(|a, b| b.score.cmp(&a.score));
+        items.truncate(limit);
+        Ok(items)
+    }
+}
@@ -0,0 +1,20 @@
+param([string]$Query, [int]$Limit)
+
+$connection = New-Object -ComObject ADODB.Connection
+$recordset = New-Object -ComObject ADODB.Recordset
+$connection.Open("Provider=Search.CollatorDSO;Extended Properties='Application=Windows';")
+
+$escaped = $Query.Replace("'", "''").Replace("[", "[[]").Replace("%", "[%]").Replace("_", "[_]")
+$sql = "SELECT TOP $Limit System.ItemPathDisplay FROM SYSTEMINDEX " +
+  "WHERE System.FileName LIKE '%$escaped%' ORDER BY System.DateModified DESC"
+$recordset.Open($sql, $connection)
+
+$paths = @()
+while (-not $recordset.EOF) {
+  $paths += $recordset.Fields.Item("System.ItemPathDisplay").Value
+  $recordset.MoveNext()
+}
+
+$recordset.Close()
+$connection.Close()
+ConvertTo-Json -InputObject $paths -Compress
@@ -0,0 +1,226 @@
+use crate::{
+    error_handler::Result,
+    modules::launcher::domain::{match_score, LauncherAction, LauncherItem},
+};
+
+use super::SearchProvider;
+
+/// (title, keywords, ms-settings uri)
+const SETTINGS_PAGES: &[(&str, &str, &str)] = &[
+    (
+        "Display",
+        "screen resolution scale brightness",
+        "ms-settings:display",
+    ),
+    ("Night light", "blue light warm", "ms-settings:nightlight"),
+    (
+        "Sound",
+        "audio volume speakers microphone",
+        "ms-settings:sound",
+    ),
+    ("Notifications", "alerts focus", "ms-settings:notifications"),
+    (
+        "Focus assist",
+        "do not disturb quiet hours",
+        "ms-settings:quiethours",
+    ),
+    (
+        "Power & sleep",
+        "battery energy shutdown",
+        "ms-settings:powersleep",
+    ),
+    (
+        "Battery",
+        "battery saver energy",
+        "ms-settings:batterysaver",
+    ),
+    ("Storage", "disk space cleanup", "ms-settings:storagesense"),
+    (
+        "Multitasking",
+        "snap windows virtual desktops",
+        "ms-settings:multitasking",
+    ),
+    ("Clipboard", "copy paste history", "ms-settings:clipboard"),
+    (
+        "About",
+        "system info pc name specifications",
+        "ms-settings:about",
+    ),
+    (
+        "Bluetooth & devices",
+        "bluetooth pair devices",
+        "ms-settings:bluetooth",
+    ),
+    (
+        "Printers & scanners",
+        "printer scanner",
+        "ms-settings:printers",
+    ),
+    (
+        "Mouse",
+        "cursor pointer scroll",
+        "ms-settings:mousetouchpad",
+    ),
+    (
+        "Touchpad",
+        "gestures trackpad",
+        "ms-settings:devices-touchpad",
+    ),
+    ("Typing", "keyboard autocorrect", "ms-settings:typing"),
+    ("Pen & Windows Ink", "stylus pen", "ms-settings:pen"),
+    ("AutoPlay", "removable media", "ms-settings:autoplay"),
+    (
+        "Network & internet",
+        "network internet status",
+        "ms-settings:network-status",
+    ),
+    ("Wi-Fi", "wifi wireless network", "ms-settings:network-wifi"),
+    ("Ethernet", "wired network", "ms-settings:network-ethernet"),
+    ("VPN", "virtual private network", "ms-settings:network-vpn"),
+    ("Proxy", "network proxy", "ms-settings:network-proxy"),
+    (
+        "Airplane mode",
+        "flight mode",
+        "ms-settings:network-airplanemode",
+    ),
+    (
+        "Mobile hotspot",
+        "share internet tethering",
+        "ms-settings:network-mobilehotspot",
+    ),
+    (
+        "Background",
+        "wallpaper desktop picture",
+        "ms-settings:personalization-background",
+    ),
+    (
+        "Colors",
+        "accent dark mode light mode theme",
+        "ms-settings:colors",
+    ),
+    ("Themes", "theme personalization", "ms-settings:themes"),
+    ("Lock screen", "lockscreen", "ms-settings:lockscreen"),
+    ("Fonts", "typeface", "ms-settings:fonts"),
+    ("Taskbar", "taskbar tray", "ms-settings:taskbar"),
+    (
+        "Installed apps",
+        "uninstall programs apps features",
+        "ms-settings:appsfeatures",
+    ),
+    (
+        "Default apps",
+        "file associations open with",
+        "ms-settings:defaultapps",
+    ),
+    (
+        "Startup apps",
+        "startup autostart",
+        "ms-settings:startupapps",
+    ),
+    (
+        "Optional features",
+        "features capabilities",
+        "ms-settings:optionalfeatures",
+    ),
+    (
+        "Your info",
+        "account profile picture",
+        "ms-settings:yourinfo",
+    ),
+    (
+        "Sign-in options",
+        "password pin windows hello",
+        "ms-settings:signinoptions",
+    ),
+    ("Date & time", "clock timezone", "ms-settings:dateandtime"),
+    (
+        "Language & region",
+        "language region locale",
+        "ms-settings:regionlanguage",
+    ),
+    (
+        "Gaming",
+        "game mode game bar",
+        "ms-settings:gaming-gamemode",
+    ),
+    (
+        "Accessibility",
+        "ease of access",
+        "ms-settings:easeofaccess",
+    ),
+    (
+        "Text size",
+        "font size accessibility",
+        "ms-settings:easeofaccess-display",
+    ),
+    (
+        "High contrast",
+        "contrast themes accessibility",
+        "ms-settings:easeofaccess-highcontrast",
+    ),
+    (
+        "Magnifier",
+        "zoom accessibility",
+        "ms-settings:easeofaccess-magnifier",
+    ),
+    (
+        "Privacy & security",
+        "privacy permissions",
+        "ms-settings:privacy",
+    ),
+    ("Location", "gps privacy", "ms-settings:privacy-location"),
+    ("Camera", "webcam privacy", "ms-settings:privacy-webcam"),
+    (
+        "Microphone",
+        "mic privacy",
+        "ms-settings:privacy-microphone",
+    ),
+    (
+        "Windows Security",
+        "antivirus defender firewall",
+        "ms-settings:windowsdefender",
+    ),
+    (
+        "Windows Update",
+        "updates upgrade",
+        "ms-settings:windowsupdate",
+    ),
+    ("Recovery", "reset restore", "ms-settings:recovery"),
+    (
+        "Activation",
+        "license product key",
+        "ms-settings:activation",
+    ),
+];
+
+/// Pages of the Windows Settings app, matched by title or keywords
+pub struct SettingsProvider;
+
+impl SearchProvider for SettingsProvider {
+    fn id(&self) -> &'static str {
+        "settings"
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>> {
+        let mut items: Vec<LauncherItem> = SETTINGS_PAGES
+            .iter()
+            .filter_map(|(title, keywords, uri)| {
+                // keywords are a weaker match than the title
+                let score = match_score(query, title)
+                    .or_else(|| match_score(query, keywords).map(|score| score / 2))?;
+                Some(LauncherItem {
+                    title: title.to_string(),
+                    subtitle: Some("Settings".to_string()),
+                    icon: None,
+                    action: LauncherAction::Open {
+                        target: uri.to_string(),
+                    },
+                    score,
+                })
+            })
+            .collect();
+        items.sort_by(|a, b| b.score.cmp(&a.score));
+        items.truncate(limit);
+        Ok(items)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    error_handler::Result,
+    modules::launcher::domain::{encode_uri_component, LauncherAction, LauncherItem},
+};
+
+use super::SearchProvider;
+
+const SEARCH_URL: &str = "https://www.bing.com/search?q=";
+
+/// Fallback shown at the end of the results to search the query on the web
+pub struct WebProvider;
+
+impl SearchProvider for WebProvider {
+    fn id(&self) -> &'static str {
+        "web"
+    }
+
+    fn search(&self, query: &str, _limit: usize) -> Result<Vec<LauncherItem>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(vec![LauncherItem {
+            title: format!("Search \"{}\" on the web", query),
+            subtitle: None,
+            icon: None,
+            action: LauncherAction::Open {
+                target: format!("{}{}", SEARCH_URL, encode_uri_component(query)),
+            },
+            score: 0,
+        }])
+    }
+}
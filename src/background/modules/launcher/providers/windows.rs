@@ -0,0 +1,46 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    error_handler::Result,
+    modules::{
+        app_usage::AppUsageTracker,
+        launcher::domain::{LauncherAction, LauncherItem},
+    },
+    seelen_weg::SeelenWeg,
+};
+
+use super::SearchProvider;
+
+/// "Switch to" results, open windows matched by title or executable
+pub struct WindowsProvider;
+
+impl SearchProvider for WindowsProvider {
+    fn id(&self) -> &'static str {
+        "windows"
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<LauncherItem>> {
+        let ranking = AppUsageTracker::ranking();
+        let mut windows = SeelenWeg::search_windows(query);
+        for window in &mut windows {
+            let stem = Path::new(&window.exe)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            window.score += ranking.get(&stem).copied().unwrap_or(0);
+        }
+        windows.sort_by(|a, b| b.score.cmp(&a.score));
+
+        Ok(windows
+            .into_iter()
+            .take(limit)
+            .map(|window| LauncherItem {
+                title: window.title,
+                subtitle: Some("Switch to".to_string()),
+                icon: Some(PathBuf::from(window.icon)),
+                action: LauncherAction::Focus { hwnd: window.hwnd },
+                score: window.score,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,29 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::LockScreen;
+
+get_subcommands![
+    /** Shows the lock screen widgets if enabled and locks the session */
+    Lock,
+];
+
+impl LockScreen {
+    pub const CLI_IDENTIFIER: &'static str = "lock-screen";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Lock screen companion widgets")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Lock => Self::lock()?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+use crate::error_handler::Result;
+
+use super::LockScreen;
+
+#[tauri::command(async)]
+pub fn lock_screen() -> Result<()> {
+    LockScreen::lock()
+}
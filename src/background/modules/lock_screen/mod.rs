@@ -0,0 +1,157 @@
+pub mod cli;
+pub mod infrastructure;
+mod watcher;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{Emitter, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::HWND,
+    System::Shutdown::LockWorkStation,
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SWP_NOACTIVATE},
+};
+
+pub use watcher::LockScreenWatcher;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        input::Mouse,
+        media::{application::MEDIA_MANAGER, domain::MediaPlayer},
+    },
+    seelen::{get_app_handle, SEELEN},
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{overlay::OverlayBuilder, sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref OVERLAY: Arc<Mutex<Option<WebviewWindow<Wry>>>> = Arc::new(Mutex::new(None));
+}
+
+/// Incremented on each lock request, only the last one locks the session
+static LOCK_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LockScreenContent {
+    clock: bool,
+    media: Option<MediaPlayer>,
+}
+
+/// Companion overlay shown with the clock and the current media before locking the session,
+/// the overlay is destroyed once the session is locked.
+pub struct LockScreen;
+
+impl LockScreen {
+    const TARGET: &'static str = "lock-screen";
+    /// time given to the system to show the shell windows again after unlocking
+    const RESTORE_DELAY: u64 = 1000;
+
+    fn create_window() -> Result<WebviewWindow<Wry>> {
+        OverlayBuilder::new(Self::TARGET, "lock-screen/index.html", "Seelen Lock Screen")
+            .unfocused()
+            .click_through()
+            .build()
+    }
+
+    fn content() -> LockScreenContent {
+        let settings = FULL_STATE.load().settings().lock_screen.clone();
+        let media = if settings.media {
+            let manager = trace_lock!(MEDIA_MANAGER);
+            let playing = manager.playing();
+            playing
+                .iter()
+                .find(|player| player.default)
+                .or_else(|| playing.first())
+                .cloned()
+        } else {
+            None
+        };
+        LockScreenContent {
+            clock: settings.clock,
+            media,
+        }
+    }
+
+    /// Shows the overlay on the monitor under the cursor and locks the session after the
+    /// configured delay, or locks directly if the overlay is disabled.
+    pub fn lock() -> Result<()> {
+        let settings = FULL_STATE.load().settings().lock_screen.clone();
+        if !settings.enabled {
+            unsafe { LockWorkStation()? };
+            return Ok(());
+        }
+
+        let generation = LOCK_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+        {
+            let mut overlay = trace_lock!(OVERLAY);
+            let window = match overlay.as_ref() {
+                Some(window) => window,
+                None => overlay.insert(Self::create_window()?),
+            };
+
+            let cursor = Mouse::get_cursor_pos().unwrap_or_default();
+            let monitor = WindowsApi::monitor_from_point(cursor.into());
+            let rect = WindowsApi::monitor_rect(monitor)?;
+            WindowsApi::set_position(
+                HWND(window.hwnd()?.0),
+                Some(HWND_TOPMOST),
+                &rect,
+                SWP_NOACTIVATE,
+            )?;
+            window.emit_to(Self::TARGET, "lock-screen-show", Self::content())?;
+            window.show()?;
+        }
+
+        spawn_named_thread("Lock Screen", move || {
+            sleep_millis(settings.delay);
+            if LOCK_GENERATION.load(Ordering::Acquire) == generation {
+                // the overlay is destroyed by the watcher once the session is locked
+                if let Err(err) = unsafe { LockWorkStation() } {
+                    log::error!("Failed to lock the session: {:?}", err);
+                    Self::destroy();
+                }
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn destroy() {
+        if let Some(window) = trace_lock!(OVERLAY).take() {
+            log_error!(window.destroy());
+        }
+    }
+
+    fn on_session_lock() -> Result<()> {
+        Self::destroy();
+        get_app_handle().emit("session-lock-changed", true)?;
+        Ok(())
+    }
+
+    /// Windows sometimes leaves the shell windows hidden after unlocking the session
+    fn on_session_unlock() -> Result<()> {
+        get_app_handle().emit("session-lock-changed", false)?;
+        spawn_named_thread("Lock Screen Restore", || {
+            sleep_millis(Self::RESTORE_DELAY);
+            let seelen = trace_lock!(SEELEN);
+            for monitor in seelen.monitors() {
+                if let Some(toolbar) = monitor.toolbar() {
+                    log_error!(toolbar.restore_visibility());
+                }
+                if let Some(weg) = monitor.weg() {
+                    log_error!(weg.restore_visibility());
+                }
+            }
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        System::RemoteDesktop::{
+            WTSRegisterSessionNotification, WTSUnRegisterSessionNotification,
+            NOTIFY_FOR_THIS_SESSION,
+        },
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            PostMessageW, PostQuitMessage, RegisterClassW, TranslateMessage, MSG, WINDOW_EX_STYLE,
+            WINDOW_STYLE, WM_CLOSE, WM_DESTROY, WM_WTSSESSION_CHANGE, WNDCLASSW, WTS_SESSION_LOCK,
+            WTS_SESSION_UNLOCK,
+        },
+    },
+};
+
+use crate::{error_handler::Result, log_error, utils::spawn_named_thread, windows_api::WindowsApi};
+
+use super::LockScreen;
+
+/// hidden window registered for session notifications, 0 if the watcher is not running
+static WATCHER_HWND: AtomicIsize = AtomicIsize::new(0);
+
+/// Tears down the lock screen overlay once the session is locked and restores the shell
+/// windows after unlocking it.
+pub struct LockScreenWatcher;
+
+impl LockScreenWatcher {
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_WTSSESSION_CHANGE => {
+                match w_param.0 as u32 {
+                    WTS_SESSION_LOCK => log_error!(LockScreen::on_session_lock()),
+                    WTS_SESSION_UNLOCK => log_error!(LockScreen::on_session_unlock()),
+                    _ => {}
+                }
+                LRESULT(0)
+            }
+            WM_CLOSE => {
+                log_error!(WTSUnRegisterSessionNotification(hwnd));
+                log_error!(DestroyWindow(hwnd));
+                LRESULT(0)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if WATCHER_HWND.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Starting lock screen watcher");
+
+        let class_name = w!("SeelenLockScreenWatcher");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        spawn_named_thread("Lock Screen Watcher", move || unsafe {
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+            if let Err(err) = WTSRegisterSessionNotification(hwnd, NOTIFY_FOR_THIS_SESSION) {
+                log::error!("Failed to register session notifications: {:?}", err);
+                log_error!(DestroyWindow(hwnd));
+                return;
+            }
+            WATCHER_HWND.store(hwnd.0, Ordering::Release);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            WATCHER_HWND.store(0, Ordering::Release);
+            log::trace!("Lock screen watcher stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let hwnd = WATCHER_HWND.load(Ordering::Acquire);
+        if hwnd != 0 {
+            log_error!(unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) });
+        }
+        LockScreen::destroy();
+    }
+}
@@ -35,6 +35,10 @@ use windows_core::Interface;
 use crate::{
     error_handler::Result,
     log_error,
+    modules::{
+        audio_profiles::AudioProfiles,
+        osd::{domain::OsdEvent, Osd},
+    },
     seelen::get_app_handle,
     seelen_weg::icon_extractor::extract_and_save_icon,
     trace_lock,
@@ -42,7 +46,9 @@ use crate::{
     windows_api::{Com, WindowsApi},
 };
 
-use super::domain::{Device, DeviceChannel, IPolicyConfig, MediaPlayer, PolicyConfig};
+use super::domain::{
+    Device, DeviceChannel, IPolicyConfig, MediaPlayer, PolicyConfig, SEELEN_AUDIO_CONTEXT,
+};
 
 lazy_static! {
     pub static ref MEDIA_MANAGER: Arc<Mutex<MediaManager>> = Arc::new(Mutex::new(
@@ -66,6 +72,8 @@ enum MediaEvent {
         device_id: String,
         volume: f32,
         muted: bool,
+        /// false if the change was made by Seelen
+        external: bool,
     },
     MediaPlayerAdded(GlobalSystemMediaTransportControlsSession),
     MediaPlayerRemoved(String),
@@ -212,6 +220,7 @@ impl IAudioEndpointVolumeCallback_Impl for MediaDeviceEventHandler {
                 device_id: self.device_id.clone(),
                 volume: data.fMasterVolume,
                 muted: data.bMuted.as_bool(),
+                external: data.guidEventContext != SEELEN_AUDIO_CONTEXT,
             });
         }
         Ok(())
@@ -642,9 +651,15 @@ impl MediaManager {
             MediaEvent::DeviceAdded(device_id) => {
                 if let Some(device) = self.get_raw_device(&device_id) {
                     unsafe { self.load_device(&device)? };
+                    if let Some(loaded) = self.device_mut(&device_id) {
+                        AudioProfiles::on_device_change(&loaded.name, true);
+                    }
                 }
             }
             MediaEvent::DeviceRemoved(device_id) => {
+                if let Some(removed) = self.device_mut(&device_id) {
+                    AudioProfiles::on_device_change(&removed.name, false);
+                }
                 self.release_device(&device_id)?;
             }
             MediaEvent::DefaultDeviceChanged {
@@ -670,10 +685,19 @@ impl MediaManager {
                 device_id,
                 volume,
                 muted,
+                external,
             } => {
                 if let Some(device) = self.device_mut(&device_id) {
                     device.volume = volume;
                     device.muted = muted;
+                    if external && device.is_default_multimedia {
+                        std::thread::spawn(move || {
+                            log_error!(Osd::show(OsdEvent::Volume {
+                                level: volume,
+                                muted,
+                            }))
+                        });
+                    }
                 }
             }
             MediaEvent::MediaPlayerAdded(session) => {
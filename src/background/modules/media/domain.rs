@@ -12,7 +12,7 @@ use windows::{
     },
 };
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MediaPlayer {
     pub id: String,
     pub title: String,
@@ -45,6 +45,10 @@ pub struct Device {
     pub muted: bool,
 }
 
+/// Event context sent on volume changes made by Seelen, used to tell them apart from
+/// the changes made by hardware keys or other apps.
+pub const SEELEN_AUDIO_CONTEXT: GUID = GUID::from_u128(0x5ee1e2a7_0a5d_4c1e_9f3b_1d0c5e0f5e1e);
+
 /* Windows IPolicyConfig UNDOCUMENTED INTERFACE */
 #[allow(non_upper_case_globals)]
 pub const PolicyConfig: GUID = GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
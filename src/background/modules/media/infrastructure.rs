@@ -1,13 +1,12 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::Emitter;
-use windows::core::GUID;
 
 use crate::{
     error_handler::Result, modules::media::application::MEDIA_MANAGER, seelen::get_app_handle,
     trace_lock,
 };
 
-use super::domain::{Device, MediaPlayer};
+use super::domain::{Device, MediaPlayer, SEELEN_AUDIO_CONTEXT};
 
 fn emit_media_sessions(playing: &Vec<MediaPlayer>) {
     let app = get_app_handle();
@@ -89,7 +88,7 @@ pub fn media_toggle_mute(id: String, _session_id: Option<String>) -> Result<()>
     let endpoints = manager.devices_audio_endpoint();
     if let Some((endpoint, _)) = endpoints.get(&id) {
         unsafe {
-            endpoint.SetMute(!endpoint.GetMute()?.as_bool(), &GUID::zeroed())?;
+            endpoint.SetMute(!endpoint.GetMute()?.as_bool(), &SEELEN_AUDIO_CONTEXT)?;
         }
     }
     Ok(())
@@ -101,7 +100,7 @@ pub fn set_volume_level(id: String, _session_id: Option<String>, level: f32) ->
     let endpoints = manager.devices_audio_endpoint();
     if let Some((endpoint, _)) = endpoints.get(&id) {
         unsafe {
-            endpoint.SetMasterVolumeLevelScalar(level, &GUID::zeroed())?;
+            endpoint.SetMasterVolumeLevelScalar(level, &SEELEN_AUDIO_CONTEXT)?;
         }
     }
     Ok(())
@@ -1,3 +1,3 @@
-mod application;
-mod domain;
+pub mod application;
+pub mod domain;
 pub mod infrastructure;
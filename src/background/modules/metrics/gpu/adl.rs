@@ -0,0 +1,89 @@
+use std::{ffi::c_void, sync::OnceLock};
+
+use windows::{
+    core::{s, w},
+    Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW},
+};
+
+type MallocCallback = unsafe extern "system" fn(i32) -> *mut c_void;
+type MainControlCreate = unsafe extern "system" fn(MallocCallback, i32, *mut *mut c_void) -> i32;
+type NumberOfAdaptersGet = unsafe extern "system" fn(*mut c_void, *mut i32) -> i32;
+type AdapterActiveGet = unsafe extern "system" fn(*mut c_void, i32, *mut i32) -> i32;
+type TemperatureGet = unsafe extern "system" fn(*mut c_void, i32, i32, *mut i32) -> i32;
+
+const ADL_OK: i32 = 0;
+/// edge temperature, same as the one shown by the amd software
+const ADL_TEMPERATURE_EDGE: i32 = 1;
+
+struct Adl {
+    context: *mut c_void,
+    adapter: i32,
+    temperature_get: TemperatureGet,
+}
+
+// the context is only used from the gpu monitor thread
+unsafe impl Send for Adl {}
+unsafe impl Sync for Adl {}
+
+static ADL: OnceLock<Option<Adl>> = OnceLock::new();
+
+/// adl allocates the buffers it returns using this callback, they live as long as the app
+unsafe extern "system" fn adl_malloc(size: i32) -> *mut c_void {
+    let mut buffer = Vec::<u8>::with_capacity(size.max(0) as usize);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr.cast()
+}
+
+impl Adl {
+    /// Loads the adl library shipped with the amd drivers, using the first active adapter
+    unsafe fn load() -> Option<Self> {
+        let library = LoadLibraryW(w!("atiadlxx.dll")).ok()?;
+        let create: MainControlCreate =
+            std::mem::transmute(GetProcAddress(library, s!("ADL2_Main_Control_Create"))?);
+        let number_of_adapters: NumberOfAdaptersGet = std::mem::transmute(GetProcAddress(
+            library,
+            s!("ADL2_Adapter_NumberOfAdapters_Get"),
+        )?);
+        let active_get: AdapterActiveGet =
+            std::mem::transmute(GetProcAddress(library, s!("ADL2_Adapter_Active_Get"))?);
+        let temperature_get: TemperatureGet = std::mem::transmute(GetProcAddress(
+            library,
+            s!("ADL2_OverdriveN_Temperature_Get"),
+        )?);
+
+        let mut context = std::ptr::null_mut();
+        if create(adl_malloc, 1, &mut context) != ADL_OK {
+            return None;
+        }
+        let mut count = 0;
+        if number_of_adapters(context, &mut count) != ADL_OK {
+            return None;
+        }
+        let adapter = (0..count).find(|idx| {
+            let mut active = 0;
+            active_get(context, *idx, &mut active) == ADL_OK && active != 0
+        })?;
+
+        Some(Self {
+            context,
+            adapter,
+            temperature_get,
+        })
+    }
+}
+
+/// Temperature of the first active amd gpu
+pub fn temperature() -> Option<f32> {
+    let adl = ADL.get_or_init(|| unsafe { Adl::load() }).as_ref()?;
+    let mut millidegrees = 0;
+    let status = unsafe {
+        (adl.temperature_get)(
+            adl.context,
+            adl.adapter,
+            ADL_TEMPERATURE_EDGE,
+            &mut millidegrees,
+        )
+    };
+    (status == ADL_OK).then(|| millidegrees as f32 / 1000.0)
+}
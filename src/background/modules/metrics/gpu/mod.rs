@@ -0,0 +1,100 @@
+#[cfg(feature = "adl")]
+mod adl;
+#[cfg(feature = "nvml")]
+mod nvml;
+#[cfg(feature = "gpu_metrics")]
+mod sampler;
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+#[cfg(feature = "gpu_metrics")]
+use tauri::Emitter;
+
+use crate::{error_handler::Result, trace_lock};
+
+#[cfg(feature = "gpu_metrics")]
+use crate::{
+    log_error,
+    modules::game_mode::GameMode,
+    seelen::get_app_handle,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref LAST: Arc<Mutex<Vec<GpuUsage>>> = Arc::new(Mutex::new(Vec::new()));
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuUsage {
+    pub name: String,
+    /// pci vendor id, ex: 0x10DE for NVIDIA
+    pub vendor_id: u32,
+    /// busiest engine percentage, 0-100, same as the task manager
+    pub utilization: f32,
+    /// dedicated memory in bytes
+    pub memory_used: u64,
+    pub memory_total: u64,
+    /// celsius, only available with the `nvml`/`adl` features on supported cards
+    pub temperature: Option<f32>,
+}
+
+/// Samples the usage of the gpus, emitting `gpu-usage` as a list of [`GpuUsage`].
+/// The sampler is only compiled with the `gpu_metrics` feature, without it no gpus are reported.
+pub struct GpuMonitor;
+
+impl GpuMonitor {
+    /// Gpus as of the last sample
+    pub fn last() -> Vec<GpuUsage> {
+        trace_lock!(LAST).clone()
+    }
+
+    #[cfg(feature = "gpu_metrics")]
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting gpu monitor");
+        spawn_named_thread("Gpu Monitor", || {
+            // pdh counters are rates, so the query is kept alive between samples
+            let mut sampler = match sampler::GpuSampler::new() {
+                Ok(sampler) => sampler,
+                Err(err) => {
+                    log::error!("Failed to start the gpu sampler: {}", err);
+                    RUNNING.store(false, Ordering::Release);
+                    return;
+                }
+            };
+            while RUNNING.load(Ordering::Acquire) {
+                if !GameMode::is_active() {
+                    match sampler.sample() {
+                        Ok(gpus) => {
+                            *trace_lock!(LAST) = gpus.clone();
+                            log_error!(get_app_handle().emit("gpu-usage", gpus));
+                        }
+                        Err(err) => log::error!("Failed to sample the gpus: {}", err),
+                    }
+                }
+                sleep_millis(2000);
+            }
+        })?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "gpu_metrics"))]
+    pub fn start() -> Result<()> {
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
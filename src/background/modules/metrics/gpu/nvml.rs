@@ -0,0 +1,23 @@
+use std::sync::OnceLock;
+
+use nvml_wrapper::{enum_wrappers::device::TemperatureSensor, Nvml};
+
+/// nvml is shipped with the nvidia drivers, `None` if it could not be loaded
+static NVML: OnceLock<Option<Nvml>> = OnceLock::new();
+
+/// Temperature of the nvidia gpu with the given name, as reported by DXGI
+pub fn temperature(name: &str) -> Option<f32> {
+    let nvml = NVML.get_or_init(|| Nvml::init().ok()).as_ref()?;
+    for idx in 0..nvml.device_count().ok()? {
+        let Ok(device) = nvml.device_by_index(idx) else {
+            continue;
+        };
+        if device.name().is_ok_and(|device_name| device_name == name) {
+            return device
+                .temperature(TemperatureSensor::Gpu)
+                .ok()
+                .map(|t| t as f32);
+        }
+    }
+    None
+}
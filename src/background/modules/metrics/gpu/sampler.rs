@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Graphics::Dxgi::{
+            CreateDXGIFactory1, IDXGIFactory1, DXGI_ADAPTER_FLAG_SOFTWARE, DXGI_ERROR_NOT_FOUND,
+        },
+        System::Performance::{
+            PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData,
+            PdhGetFormattedCounterArrayW, PdhOpenQueryW, PDH_FMT_COUNTERVALUE_ITEM_W,
+            PDH_FMT_DOUBLE,
+        },
+    },
+};
+
+use crate::error_handler::Result;
+
+use super::GpuUsage;
+
+const PDH_MORE_DATA: u32 = 0x800007D2;
+#[cfg(feature = "nvml")]
+const VENDOR_NVIDIA: u32 = 0x10DE;
+#[cfg(feature = "adl")]
+const VENDOR_AMD: u32 = 0x1002;
+
+struct Adapter {
+    luid: u64,
+    name: String,
+    vendor_id: u32,
+    memory_total: u64,
+}
+
+/// Gpu adapters from DXGI and their usage from the `GPU Engine` and `GPU Adapter Memory`
+/// performance counters, matched by the adapter luid.
+pub struct GpuSampler {
+    query: isize,
+    engines: isize,
+    memory: isize,
+    adapters: Vec<Adapter>,
+}
+
+impl GpuSampler {
+    pub fn new() -> Result<Self> {
+        let mut sampler = Self {
+            query: 0,
+            engines: 0,
+            memory: 0,
+            adapters: Self::adapters()?,
+        };
+        unsafe {
+            Self::check(PdhOpenQueryW(PCWSTR::null(), 0, &mut sampler.query))?;
+            Self::check(PdhAddEnglishCounterW(
+                sampler.query,
+                w!(r"\GPU Engine(*)\Utilization Percentage"),
+                0,
+                &mut sampler.engines,
+            ))?;
+            Self::check(PdhAddEnglishCounterW(
+                sampler.query,
+                w!(r"\GPU Adapter Memory(*)\Dedicated Usage"),
+                0,
+                &mut sampler.memory,
+            ))?;
+            // rates need a first collection to compare with
+            Self::check(PdhCollectQueryData(sampler.query))?;
+        }
+        Ok(sampler)
+    }
+
+    fn check(status: u32) -> Result<()> {
+        match status {
+            0 => Ok(()),
+            status => Err(format!("PDH error: 0x{:X}", status).into()),
+        }
+    }
+
+    fn adapters() -> Result<Vec<Adapter>> {
+        let factory: IDXGIFactory1 = unsafe { CreateDXGIFactory1()? };
+        let mut adapters = Vec::new();
+        let mut idx = 0;
+        loop {
+            let adapter = match unsafe { factory.EnumAdapters1(idx) } {
+                Ok(adapter) => adapter,
+                Err(err) if err.code() == DXGI_ERROR_NOT_FOUND => break,
+                Err(err) => return Err(err.into()),
+            };
+            idx += 1;
+            let desc = unsafe { adapter.GetDesc1()? };
+            // skip the microsoft basic render driver
+            if desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32 != 0 {
+                continue;
+            }
+            adapters.push(Adapter {
+                luid: ((desc.AdapterLuid.HighPart as u64) << 32) | desc.AdapterLuid.LowPart as u64,
+                name: String::from_utf16_lossy(&desc.Description)
+                    .trim_end_matches('\0')
+                    .to_string(),
+                vendor_id: desc.VendorId,
+                memory_total: desc.DedicatedVideoMemory as u64,
+            });
+        }
+        Ok(adapters)
+    }
+
+    /// Parses the luid from instance names like `pid_123_luid_0x00000000_0x0000D1B5_phys_0_...`
+    fn luid_of(instance: &str) -> Option<u64> {
+        let rest = &instance[instance.find("luid_0x")? + 7..];
+        let high = u32::from_str_radix(rest.get(..8)?, 16).ok()?;
+        let low = u32::from_str_radix(rest.get(11..19)?, 16).ok()?;
+        Some(((high as u64) << 32) | low as u64)
+    }
+
+    /// Values of a wildcard counter keyed by instance name
+    fn values(counter: isize) -> Result<Vec<(String, f64)>> {
+        let mut size = 0;
+        let mut count = 0;
+        let status = unsafe {
+            PdhGetFormattedCounterArrayW(counter, PDH_FMT_DOUBLE, &mut size, &mut count, None)
+        };
+        if status != PDH_MORE_DATA {
+            Self::check(status)?;
+            return Ok(Vec::new());
+        }
+
+        // the buffer also holds the instance names after the items
+        let item_size = std::mem::size_of::<PDH_FMT_COUNTERVALUE_ITEM_W>();
+        let mut buffer: Vec<PDH_FMT_COUNTERVALUE_ITEM_W> =
+            Vec::with_capacity((size as usize).div_ceil(item_size));
+        unsafe {
+            Self::check(PdhGetFormattedCounterArrayW(
+                counter,
+                PDH_FMT_DOUBLE,
+                &mut size,
+                &mut count,
+                Some(buffer.as_mut_ptr()),
+            ))?;
+            buffer.set_len(count as usize);
+        }
+
+        let mut values = Vec::with_capacity(buffer.len());
+        for item in &buffer {
+            // instances of ended processes report an invalid status
+            if item.FmtValue.CStatus != 0 {
+                continue;
+            }
+            let name = unsafe { item.szName.to_string() }.unwrap_or_default();
+            values.push((name, unsafe { item.FmtValue.Anonymous.doubleValue }));
+        }
+        Ok(values)
+    }
+
+    pub fn sample(&mut self) -> Result<Vec<GpuUsage>> {
+        Self::check(unsafe { PdhCollectQueryData(self.query) })?;
+
+        // the utilization of each engine type is the sum of all the processes using it
+        let mut engines: HashMap<(u64, String), f64> = HashMap::new();
+        for (instance, value) in Self::values(self.engines)? {
+            let (Some(luid), Some(idx)) = (Self::luid_of(&instance), instance.find("engtype_"))
+            else {
+                continue;
+            };
+            let engine = instance[idx..].to_string();
+            *engines.entry((luid, engine)).or_default() += value;
+        }
+
+        let mut memory: HashMap<u64, f64> = HashMap::new();
+        for (instance, value) in Self::values(self.memory)? {
+            if let Some(luid) = Self::luid_of(&instance) {
+                *memory.entry(luid).or_default() += value;
+            }
+        }
+
+        let mut gpus = Vec::with_capacity(self.adapters.len());
+        for adapter in &self.adapters {
+            let utilization = engines
+                .iter()
+                .filter(|((luid, _), _)| *luid == adapter.luid)
+                .map(|(_, value)| *value)
+                .fold(0.0, f64::max);
+
+            #[allow(unused_mut)]
+            let mut temperature = None;
+            #[cfg(feature = "nvml")]
+            if adapter.vendor_id == VENDOR_NVIDIA {
+                temperature = super::nvml::temperature(&adapter.name);
+            }
+            #[cfg(feature = "adl")]
+            if adapter.vendor_id == VENDOR_AMD {
+                temperature = super::adl::temperature();
+            }
+
+            gpus.push(GpuUsage {
+                name: adapter.name.clone(),
+                vendor_id: adapter.vendor_id,
+                utilization: utilization.min(100.0) as f32,
+                memory_used: memory.get(&adapter.luid).copied().unwrap_or_default() as u64,
+                memory_total: adapter.memory_total,
+                temperature,
+            });
+        }
+        Ok(gpus)
+    }
+}
+
+impl Drop for GpuSampler {
+    fn drop(&mut self) {
+        unsafe { PdhCloseQuery(self.query) };
+    }
+}
@@ -0,0 +1,6 @@
+use super::gpu::{GpuMonitor, GpuUsage};
+
+#[tauri::command(async)]
+pub fn get_gpu_usage() -> Vec<GpuUsage> {
+    GpuMonitor::last()
+}
@@ -0,0 +1,216 @@
+pub mod gpu;
+pub mod infrastructure;
+pub mod network;
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use lazy_static::lazy_static;
+use network::NetworkSampler;
+use parking_lot::Mutex;
+use serde::Serialize;
+use sysinfo::{Pid, System};
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::game_mode::GameMode,
+    seelen::get_app_handle,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+lazy_static! {
+    /// cpu usage is calculated as the difference between two refreshes,
+    /// so the same instance has to be kept between samples.
+    static ref SYSTEM: Arc<Mutex<System>> = Arc::new(Mutex::new(System::new()));
+    static ref NETWORK: Arc<Mutex<NetworkSampler>> =
+        Arc::new(Mutex::new(NetworkSampler::default()));
+}
+
+/// true while the dock usage sampler is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceUsage {
+    /// percentage of the whole system, 0-100
+    pub cpu: f32,
+    /// resident memory in bytes
+    pub memory: u64,
+    /// tcp bytes per second, 0 unless the network usage is refreshed
+    pub net_received: u64,
+    pub net_sent: u64,
+}
+
+impl std::ops::AddAssign for ResourceUsage {
+    fn add_assign(&mut self, rhs: Self) {
+        self.cpu += rhs.cpu;
+        self.memory += rhs.memory;
+        self.net_received += rhs.net_received;
+        self.net_sent += rhs.net_sent;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemUsage {
+    /// percentage, 0-100
+    pub cpu: f32,
+    /// bytes
+    pub used_memory: u64,
+    pub total_memory: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessSample {
+    pub pid: u32,
+    pub name: String,
+    pub exe: Option<PathBuf>,
+    #[serde(flatten)]
+    pub usage: ResourceUsage,
+}
+
+pub struct Metrics;
+
+impl Metrics {
+    /// Refreshes the process list and the cpu counters
+    pub fn refresh() {
+        let mut sys = trace_lock!(SYSTEM);
+        sys.refresh_cpu();
+        sys.refresh_memory();
+        sys.refresh_processes();
+    }
+
+    /// Refreshes the throughput of the tcp connections of each process, it is kept apart
+    /// from `refresh` as it is only needed when the network usage is shown
+    pub fn refresh_network() {
+        trace_lock!(NETWORK).refresh();
+    }
+
+    /// Usage of the whole system as of the last refresh
+    pub fn system() -> SystemUsage {
+        let sys = trace_lock!(SYSTEM);
+        SystemUsage {
+            cpu: sys.global_cpu_info().cpu_usage(),
+            used_memory: sys.used_memory(),
+            total_memory: sys.total_memory(),
+        }
+    }
+
+    fn usage_of(
+        sys: &System,
+        network: &NetworkSampler,
+        pid: Pid,
+        process: &sysinfo::Process,
+    ) -> ResourceUsage {
+        // sysinfo reports cpu usage per core, 100% meaning a full core
+        let cores = sys.cpus().len().max(1) as f32;
+        let network = network.rate_of(pid.as_u32());
+        ResourceUsage {
+            cpu: process.cpu_usage() / cores,
+            memory: process.memory(),
+            net_received: network.received,
+            net_sent: network.sent,
+        }
+    }
+
+    /// Usage of every running process as of the last refresh
+    pub fn processes() -> Vec<ProcessSample> {
+        let sys = trace_lock!(SYSTEM);
+        let network = trace_lock!(NETWORK);
+        sys.processes()
+            .iter()
+            .map(|(pid, process)| ProcessSample {
+                pid: pid.as_u32(),
+                name: process.name().to_string(),
+                exe: process.exe().map(|exe| exe.to_path_buf()),
+                usage: Self::usage_of(&sys, &network, *pid, process),
+            })
+            .collect()
+    }
+
+    /// Aggregates the usage of every process tree whose root is one of `exes`, processes
+    /// are attributed to the nearest ancestor (themselves included) running a listed exe.
+    /// Keys of the result are the given exes as they were received.
+    pub fn usage_by_exe(exes: &[String]) -> HashMap<String, ResourceUsage> {
+        let sys = trace_lock!(SYSTEM);
+        let network = trace_lock!(NETWORK);
+        let by_lowercase: HashMap<String, &String> =
+            exes.iter().map(|exe| (exe.to_lowercase(), exe)).collect();
+
+        let owner_of = |pid: Pid| -> Option<&String> {
+            let mut current = sys.process(pid);
+            // parents can be recycled pids forming cycles, so the walk is bounded
+            for _ in 0..32 {
+                let process = current?;
+                let exe = process.exe().map(|p| p.to_string_lossy().to_lowercase());
+                if let Some(owner) = exe.and_then(|exe| by_lowercase.get(&exe).copied()) {
+                    return Some(owner);
+                }
+                current = process.parent().and_then(|parent| sys.process(parent));
+            }
+            None
+        };
+
+        let mut result = HashMap::new();
+        for (pid, process) in sys.processes() {
+            if let Some(owner) = owner_of(*pid) {
+                *result.entry(owner.clone()).or_default() +=
+                    Self::usage_of(&sys, &network, *pid, process);
+            }
+        }
+        result
+    }
+}
+
+/// Samples the usage of the apps open on the dock, emitting `weg-resource-usage`
+/// as a map of exe path to [`ResourceUsage`].
+pub struct ResourceMonitor;
+
+impl ResourceMonitor {
+    fn tick() -> Result<()> {
+        Metrics::refresh();
+        if FULL_STATE.load().settings().seelenweg.network_usage {
+            Metrics::refresh_network();
+        }
+        let usage = Metrics::usage_by_exe(&SeelenWeg::open_exes());
+        get_app_handle().emit("weg-resource-usage", usage)?;
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting dock resource monitor");
+        spawn_named_thread("Resource Monitor", || {
+            while RUNNING.load(Ordering::Acquire) {
+                if !GameMode::is_active() {
+                    log_error!(Self::tick());
+                }
+                let interval = FULL_STATE
+                    .load()
+                    .settings()
+                    .seelenweg
+                    .resource_usage_interval;
+                sleep_millis(interval.max(1) * 1000);
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
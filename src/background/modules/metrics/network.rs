@@ -0,0 +1,246 @@
+use std::{collections::HashMap, ffi::c_void, time::Instant};
+
+use windows::Win32::{
+    Foundation::{BOOL, BOOLEAN, NO_ERROR},
+    NetworkManagement::IpHelper::{
+        GetExtendedTcpTable, GetPerTcp6ConnectionEStats, GetPerTcpConnectionEStats,
+        SetPerTcp6ConnectionEStats, SetPerTcpConnectionEStats, TCP_ESTATS_DATA_ROD_v0,
+        TCP_ESTATS_DATA_RW_v0, TcpConnectionEstatsData, MIB_TCP6ROW, MIB_TCP6ROW_OWNER_PID,
+        MIB_TCP6TABLE_OWNER_PID, MIB_TCPROW_LH, MIB_TCPROW_OWNER_PID, MIB_TCPTABLE_OWNER_PID,
+        MIB_TCP_STATE, TCP_TABLE_OWNER_PID_CONNECTIONS,
+    },
+    Networking::WinSock::{AF_INET, AF_INET6, IN6_ADDR, IN6_ADDR_0},
+};
+
+/// Local and remote endpoints, identifies a connection between samples
+type ConnectionKey = ([u8; 16], u32, [u8; 16], u32);
+
+/// Bytes per second
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NetworkRate {
+    pub received: u64,
+    pub sent: u64,
+}
+
+/// Bytes transferred by a tcp connection since its statistics collection was enabled
+struct ConnectionTotals {
+    pid: u32,
+    received: u64,
+    sent: u64,
+}
+
+/// Samples the throughput of the tcp connections of each process, using the extended
+/// statistics of the tcp stack. Enabling the collection of these statistics needs Seelen UI
+/// to run as administrator, without it all the rates are 0.
+#[derive(Default)]
+pub struct NetworkSampler {
+    last_totals: HashMap<ConnectionKey, (u64, u64)>,
+    last_sample: Option<Instant>,
+    rates: HashMap<u32, NetworkRate>,
+}
+
+impl NetworkSampler {
+    pub fn rate_of(&self, pid: u32) -> NetworkRate {
+        self.rates.get(&pid).copied().unwrap_or_default()
+    }
+
+    pub fn refresh(&mut self) {
+        let mut connections = HashMap::new();
+        tcp4_connections(&mut connections);
+        tcp6_connections(&mut connections);
+
+        let now = Instant::now();
+        let elapsed = self
+            .last_sample
+            .map(|last| now.duration_since(last).as_secs_f64())
+            .unwrap_or(0.0);
+
+        self.rates.clear();
+        if elapsed > 0.0 {
+            for (key, totals) in &connections {
+                // new connections are only counted from their second sample
+                let Some((last_received, last_sent)) = self.last_totals.get(key) else {
+                    continue;
+                };
+                let received = totals.received.saturating_sub(*last_received);
+                let sent = totals.sent.saturating_sub(*last_sent);
+                let rate = self.rates.entry(totals.pid).or_default();
+                rate.received += (received as f64 / elapsed) as u64;
+                rate.sent += (sent as f64 / elapsed) as u64;
+            }
+        }
+
+        self.last_totals = connections
+            .into_iter()
+            .map(|(key, totals)| (key, (totals.received, totals.sent)))
+            .collect();
+        self.last_sample = Some(now);
+    }
+}
+
+/// Reads a table of `GetExtendedTcpTable`, returned as a u64 buffer to keep its alignment
+fn tcp_table(family: u32) -> Option<Vec<u64>> {
+    let mut size = 0;
+    unsafe {
+        GetExtendedTcpTable(
+            None,
+            &mut size,
+            BOOL(0),
+            family,
+            TCP_TABLE_OWNER_PID_CONNECTIONS,
+            0,
+        )
+    };
+    if size == 0 {
+        return None;
+    }
+    // connections can be opened between both calls, so some margin is added
+    size += 1024;
+    let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+    let result = unsafe {
+        GetExtendedTcpTable(
+            Some(buffer.as_mut_ptr() as *mut c_void),
+            &mut size,
+            BOOL(0),
+            family,
+            TCP_TABLE_OWNER_PID_CONNECTIONS,
+            0,
+        )
+    };
+    (result == NO_ERROR.0).then_some(buffer)
+}
+
+/// Process owning the local end of an ipv4 connection with the given port
+pub fn tcp_port_owner(port: u16) -> Option<u32> {
+    let buffer = tcp_table(AF_INET.0 as u32)?;
+    let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID) };
+    let rows: &[MIB_TCPROW_OWNER_PID] =
+        unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+    // ports are stored on network byte order on the lower 16 bits
+    rows.iter()
+        .find(|entry| u16::from_be(entry.dwLocalPort as u16) == port)
+        .map(|entry| entry.dwOwningPid)
+}
+
+fn as_bytes<T>(value: &mut T) -> &mut [u8] {
+    unsafe { std::slice::from_raw_parts_mut(value as *mut T as *mut u8, std::mem::size_of::<T>()) }
+}
+
+fn tcp4_connections(connections: &mut HashMap<ConnectionKey, ConnectionTotals>) {
+    let Some(buffer) = tcp_table(AF_INET.0 as u32) else {
+        return;
+    };
+    let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID) };
+    let rows: &[MIB_TCPROW_OWNER_PID] =
+        unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+
+    for entry in rows {
+        let mut row = MIB_TCPROW_LH {
+            dwLocalAddr: entry.dwLocalAddr,
+            dwLocalPort: entry.dwLocalPort,
+            dwRemoteAddr: entry.dwRemoteAddr,
+            dwRemotePort: entry.dwRemotePort,
+            ..Default::default()
+        };
+        row.Anonymous.State = MIB_TCP_STATE(entry.dwState as i32);
+
+        let mut rw = TCP_ESTATS_DATA_RW_v0 {
+            EnableCollection: BOOLEAN(1),
+        };
+        let mut rod = TCP_ESTATS_DATA_ROD_v0::default();
+        unsafe {
+            // already enabled connections keep their counters
+            SetPerTcpConnectionEStats(&row, TcpConnectionEstatsData, as_bytes(&mut rw), 0, 0);
+            if GetPerTcpConnectionEStats(
+                &row,
+                TcpConnectionEstatsData,
+                None,
+                0,
+                None,
+                0,
+                Some(as_bytes(&mut rod)),
+                0,
+            ) != NO_ERROR.0
+            {
+                continue;
+            }
+        }
+
+        let mut local = [0u8; 16];
+        local[..4].copy_from_slice(&entry.dwLocalAddr.to_ne_bytes());
+        let mut remote = [0u8; 16];
+        remote[..4].copy_from_slice(&entry.dwRemoteAddr.to_ne_bytes());
+        connections.insert(
+            (local, entry.dwLocalPort, remote, entry.dwRemotePort),
+            ConnectionTotals {
+                pid: entry.dwOwningPid,
+                received: rod.DataBytesIn,
+                sent: rod.DataBytesOut,
+            },
+        );
+    }
+}
+
+fn tcp6_connections(connections: &mut HashMap<ConnectionKey, ConnectionTotals>) {
+    let Some(buffer) = tcp_table(AF_INET6.0 as u32) else {
+        return;
+    };
+    let table = unsafe { &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID) };
+    let rows: &[MIB_TCP6ROW_OWNER_PID] =
+        unsafe { std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize) };
+
+    for entry in rows {
+        let row = MIB_TCP6ROW {
+            State: MIB_TCP_STATE(entry.dwState as i32),
+            LocalAddr: IN6_ADDR {
+                u: IN6_ADDR_0 {
+                    Byte: entry.ucLocalAddr,
+                },
+            },
+            dwLocalScopeId: entry.dwLocalScopeId,
+            dwLocalPort: entry.dwLocalPort,
+            RemoteAddr: IN6_ADDR {
+                u: IN6_ADDR_0 {
+                    Byte: entry.ucRemoteAddr,
+                },
+            },
+            dwRemoteScopeId: entry.dwRemoteScopeId,
+            dwRemotePort: entry.dwRemotePort,
+        };
+
+        let mut rw = TCP_ESTATS_DATA_RW_v0 {
+            EnableCollection: BOOLEAN(1),
+        };
+        let mut rod = TCP_ESTATS_DATA_ROD_v0::default();
+        unsafe {
+            SetPerTcp6ConnectionEStats(&row, TcpConnectionEstatsData, as_bytes(&mut rw), 0, 0);
+            if GetPerTcp6ConnectionEStats(
+                &row,
+                TcpConnectionEstatsData,
+                None,
+                0,
+                None,
+                0,
+                Some(as_bytes(&mut rod)),
+                0,
+            ) != NO_ERROR.0
+            {
+                continue;
+            }
+        }
+
+        connections.insert(
+            (
+                entry.ucLocalAddr,
+                entry.dwLocalPort,
+                entry.ucRemoteAddr,
+                entry.dwRemotePort,
+            ),
+            ConnectionTotals {
+                pid: entry.dwOwningPid,
+                received: rod.DataBytesIn,
+                sent: rod.DataBytesOut,
+            },
+        );
+    }
+}
@@ -1,11 +1,79 @@
+pub mod action_history;
+pub mod alerts;
+pub mod app_usage;
+pub mod associations;
+pub mod audio_profiles;
+pub mod automation;
+pub mod cache;
 pub mod cli;
+pub mod color_picker;
+pub mod color_scheme;
+pub mod conflicts;
+pub mod context_menu;
+pub mod cursor;
+pub mod data_bridge;
+pub mod data_sources;
+pub mod desktop_icons;
+pub mod devices;
+pub mod do_not_disturb;
+pub mod emoji_picker;
+pub mod event_recorder;
+pub mod file_operations;
+pub mod focus;
+pub mod focus_sessions;
+pub mod game_mode;
+pub mod gestures;
+pub mod idle;
 pub mod input;
+pub mod inspector;
+pub mod keep_awake;
+pub mod launcher;
+pub mod lock_screen;
 pub mod media;
+pub mod metrics;
+pub mod mode_manager;
 pub mod monitors;
+pub mod mouse_buttons;
 pub mod network;
+pub mod notes;
 pub mod notifications;
+pub mod onboarding;
+pub mod os_capabilities;
+pub mod osd;
+pub mod overview;
+pub mod pen;
+pub mod permissions;
+pub mod popup_filter;
+pub mod portable;
+pub mod posture;
 pub mod power;
+pub mod presence;
+pub mod printing;
+pub mod privacy;
+pub mod process_controls;
+pub mod quick_access;
+pub mod quiet_launch;
+pub mod recently_closed;
+pub mod remote_api;
+pub mod rendering;
+pub mod ruler;
+pub mod secure_desktop;
+pub mod session_restore;
+pub mod shell;
+pub mod shutdown;
+pub mod snippets;
+pub mod startup;
+pub mod storage;
 pub mod system_settings;
+pub mod task_manager;
+pub mod theme_schedule;
+pub mod touch_keyboard;
 pub mod tray;
+pub mod undo;
 pub mod uwp;
 pub mod virtual_desk;
+pub mod watchdog;
+pub mod window_controls;
+pub mod window_memory;
+pub mod window_titles;
+pub mod zones;
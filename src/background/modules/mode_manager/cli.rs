@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::{domain::Mode, ModeManager};
+
+get_subcommands![
+    /** Enables the mode, ending the current one */
+    Enter(mode: Mode => "The mode to enable."),
+    /** Ends the current mode, restoring what it changed */
+    Exit,
+    /** Enables the mode if it is not the current one, otherwise ends it */
+    Toggle(mode: Mode => "The mode to toggle."),
+];
+
+impl ModeManager {
+    pub const CLI_IDENTIFIER: &'static str = "mode";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Switches several features at once, like the presentation mode")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Enter(mode) => Self::enter(mode)?,
+            SubCommand::Exit => Self::exit()?,
+            SubCommand::Toggle(mode) => Self::toggle(mode)?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// keep awake, do not disturb, hidden dock and no window animations
+    Presentation,
+}
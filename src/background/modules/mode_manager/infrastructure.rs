@@ -0,0 +1,23 @@
+use crate::error_handler::Result;
+
+use super::{domain::Mode, ModeManager};
+
+#[tauri::command(async)]
+pub fn mode_manager_get_active() -> Option<Mode> {
+    ModeManager::active()
+}
+
+#[tauri::command(async)]
+pub fn mode_manager_enter(mode: Mode) -> Result<()> {
+    ModeManager::enter(mode)
+}
+
+#[tauri::command(async)]
+pub fn mode_manager_exit() -> Result<()> {
+    ModeManager::exit()
+}
+
+#[tauri::command(async)]
+pub fn mode_manager_toggle(mode: Mode) -> Result<()> {
+    ModeManager::toggle(mode)
+}
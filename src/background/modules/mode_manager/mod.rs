@@ -0,0 +1,181 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use domain::Mode;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{HideMode, Settings};
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{do_not_disturb::DoNotDisturb, keep_awake::KeepAwake},
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    /// active mode and what has to be restored when it ends
+    static ref ACTIVE: Mutex<Option<(Mode, Restore)>> = Mutex::new(None);
+}
+
+/// true while a mode keeps the window animations disabled
+static ANIMATIONS_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Changes made on enter, only these are reverted on exit so the ones already in place
+/// (like a keep awake enabled by the user) are kept.
+#[derive(Debug, Default)]
+struct Restore {
+    keep_awake: bool,
+    do_not_disturb: bool,
+    animations: bool,
+    wallpaper: Option<PathBuf>,
+    hide_mode: Option<HideMode>,
+    /// settings before applying the profile, the hide mode of the dock is included
+    settings: Option<Box<Settings>>,
+}
+
+/// Switches several features at once for an activity, like presenting, and restores them
+/// when the mode ends. Only one mode can be active, entering another one ends the current.
+pub struct ModeManager;
+
+impl ModeManager {
+    pub fn active() -> Option<Mode> {
+        trace_lock!(ACTIVE).as_ref().map(|(mode, _)| *mode)
+    }
+
+    /// Checked by `WindowsApi::set_minimize_animation` to not enable them meanwhile
+    pub fn animations_disabled() -> bool {
+        ANIMATIONS_DISABLED.load(Ordering::Acquire)
+    }
+
+    fn emit(mode: Option<Mode>) -> Result<()> {
+        get_app_handle().emit("mode-changed", mode)?;
+        Ok(())
+    }
+
+    pub fn enter(mode: Mode) -> Result<()> {
+        let mut active = trace_lock!(ACTIVE);
+        if active.as_ref().is_some_and(|(current, _)| *current == mode) {
+            return Ok(());
+        }
+        if let Some((_, restore)) = active.take() {
+            Self::restore(restore);
+        }
+        log::info!("Entering {:?} mode", mode);
+        let restore = match mode {
+            Mode::Presentation => Self::enter_presentation(),
+        };
+        *active = Some((mode, restore));
+        drop(active);
+        Self::emit(Some(mode))
+    }
+
+    pub fn exit() -> Result<()> {
+        let Some((mode, restore)) = trace_lock!(ACTIVE).take() else {
+            return Ok(());
+        };
+        log::info!("Exiting {:?} mode", mode);
+        Self::restore(restore);
+        Self::emit(None)
+    }
+
+    pub fn toggle(mode: Mode) -> Result<()> {
+        if Self::active() == Some(mode) {
+            Self::exit()
+        } else {
+            Self::enter(mode)
+        }
+    }
+
+    /// Each step is independent, a failed one is logged and not restored later
+    fn enter_presentation() -> Restore {
+        let state = FULL_STATE.load();
+        let settings = state.settings().presentation_mode.clone();
+        let mut restore = Restore::default();
+
+        if settings.keep_awake && !KeepAwake::state().active {
+            let result = KeepAwake::enable(None, true);
+            restore.keep_awake = result.is_ok();
+            log_error!(result);
+        }
+
+        if settings.do_not_disturb {
+            DoNotDisturb::set_forced(true);
+            restore.do_not_disturb = true;
+        }
+
+        let animated = WindowsApi::get_min_animation_info().is_ok_and(|info| info.iMinAnimate != 0);
+        if settings.disable_animations && animated {
+            ANIMATIONS_DISABLED.store(true, Ordering::Release);
+            let result = WindowsApi::set_minimize_animation(false);
+            restore.animations = true;
+            log_error!(result);
+        }
+
+        if let Some(wallpaper) = settings.wallpaper {
+            let previous = WindowsApi::get_wallpaper();
+            let result = WindowsApi::set_wallpaper(wallpaper);
+            if result.is_ok() {
+                restore.wallpaper = previous.ok();
+            }
+            log_error!(result);
+        }
+
+        let mut state = state.cloned();
+        let previous = state.settings().clone();
+        if let Some(profile) = &settings.profile {
+            let result = state.apply_settings_profile(profile);
+            if result.is_ok() {
+                restore.settings = Some(Box::new(previous.clone()));
+            }
+            log_error!(result);
+        }
+        if settings.hide_weg {
+            match state.set_weg_hide_mode(HideMode::Always) {
+                Ok(changed) => {
+                    restore.hide_mode = changed.then_some(previous.seelenweg.hide_mode);
+                }
+                Err(err) => log::error!("{:?}", err),
+            }
+        }
+
+        restore
+    }
+
+    fn restore(restore: Restore) {
+        if let Some(settings) = restore.settings {
+            log_error!(FULL_STATE.load().cloned().replace_settings(*settings));
+        } else if let Some(mode) = restore.hide_mode {
+            log_error!(FULL_STATE.load().cloned().set_weg_hide_mode(mode));
+        }
+
+        if let Some(wallpaper) = restore.wallpaper {
+            log_error!(WindowsApi::set_wallpaper(
+                wallpaper.to_string_lossy().to_string()
+            ));
+        }
+
+        if restore.animations {
+            ANIMATIONS_DISABLED.store(false, Ordering::Release);
+            log_error!(WindowsApi::set_minimize_animation(true));
+        }
+
+        if restore.do_not_disturb {
+            DoNotDisturb::set_forced(false);
+        }
+
+        if restore.keep_awake {
+            log_error!(KeepAwake::disable());
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use color_eyre::eyre::eyre;
+use serde::Serialize;
+use widestring::U16CStr;
+use windows::{
+    core::{HSTRING, PWSTR},
+    Win32::{
+        Devices::Display::{
+            DisplayConfigGetDeviceInfo, DisplayConfigSetDeviceInfo,
+            DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO,
+            DISPLAYCONFIG_DEVICE_INFO_GET_SDR_WHITE_LEVEL,
+            DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME, DISPLAYCONFIG_DEVICE_INFO_HEADER,
+            DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE, DISPLAYCONFIG_DEVICE_INFO_TYPE,
+            DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO, DISPLAYCONFIG_PATH_INFO,
+            DISPLAYCONFIG_SDR_WHITE_LEVEL, DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE,
+            DISPLAYCONFIG_TARGET_DEVICE_NAME,
+        },
+        Foundation::{LocalFree, BOOL, HLOCAL},
+        UI::ColorSystem::{
+            ColorProfileAddDisplayAssociation, ColorProfileGetDisplayDefault,
+            ColorProfileGetDisplayList, CPST_NONE, CPT_ICC,
+            WCS_PROFILE_MANAGEMENT_SCOPE_CURRENT_USER,
+        },
+    },
+};
+
+use crate::error_handler::Result;
+
+use super::projection::{active_paths, source_name};
+
+/// HDR state and color profiles of a physical display, as shown on the display settings
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayColor {
+    /// device path of the display, does not change between reconnections
+    pub id: String,
+    /// name of the monitor where the display is shown, like `DISPLAY1`
+    pub monitor: String,
+    pub name: String,
+    pub hdr_supported: bool,
+    pub hdr_enabled: bool,
+    /// brightness of the SDR content while HDR is enabled, in nits
+    pub sdr_white_level: Option<u32>,
+    /// file names of the ICC profiles associated to the display
+    pub profiles: Vec<String>,
+    pub default_profile: Option<String>,
+}
+
+impl DisplayColor {
+    pub fn all() -> Result<Vec<Self>> {
+        let mut displays = Vec::new();
+        for path in active_paths()? {
+            let (id, name) = target_name(&path)?;
+            let (hdr_supported, hdr_enabled) = advanced_color(&path)?;
+            displays.push(Self {
+                id,
+                monitor: source_name(&path)?,
+                name,
+                hdr_supported,
+                hdr_enabled,
+                sdr_white_level: hdr_enabled.then(|| sdr_white_level(&path).ok()).flatten(),
+                profiles: profiles(&path).unwrap_or_default(),
+                default_profile: default_profile(&path).ok(),
+            });
+        }
+        Ok(displays)
+    }
+
+    fn path(id: &str) -> Result<DISPLAYCONFIG_PATH_INFO> {
+        for path in active_paths()? {
+            if target_name(&path)?.0 == id {
+                return Ok(path);
+            }
+        }
+        Err(format!("Display not found: {id}").into())
+    }
+
+    /// Turns HDR (advanced color) on or off, like the toggle of the Windows display settings
+    pub fn set_hdr(id: &str, enabled: bool) -> Result<()> {
+        let path = Self::path(id)?;
+        let (supported, _) = advanced_color(&path)?;
+        if !supported {
+            return Err(format!("HDR is not supported by the display {id}").into());
+        }
+        let mut state = DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE {
+            header: header::<DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE>(
+                &path,
+                DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+            ),
+            ..Default::default()
+        };
+        // enableAdvancedColor is the first bit
+        state.Anonymous.value = enabled as u32;
+        let code = unsafe { DisplayConfigSetDeviceInfo(&state.header) };
+        if code != 0 {
+            return Err(eyre!("Failed to set the advanced color state: {}", code).into());
+        }
+        Ok(())
+    }
+
+    /// Sets the default ICC profile of the display, the profile has to be installed on the
+    /// color directory of the system. It is associated to the display if it was not.
+    pub fn set_profile(id: &str, profile: &str) -> Result<()> {
+        let path = Self::path(id)?;
+        let (_, hdr_enabled) = advanced_color(&path)?;
+        unsafe {
+            ColorProfileAddDisplayAssociation(
+                WCS_PROFILE_MANAGEMENT_SCOPE_CURRENT_USER,
+                &HSTRING::from(profile),
+                path.targetInfo.adapterId,
+                path.sourceInfo.id,
+                BOOL::from(true),
+                // profiles for HDR are kept apart from the SDR ones
+                BOOL::from(hdr_enabled),
+            )?
+        };
+        Ok(())
+    }
+}
+
+fn header<T>(
+    path: &DISPLAYCONFIG_PATH_INFO,
+    r#type: DISPLAYCONFIG_DEVICE_INFO_TYPE,
+) -> DISPLAYCONFIG_DEVICE_INFO_HEADER {
+    DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type,
+        size: std::mem::size_of::<T>() as u32,
+        adapterId: path.targetInfo.adapterId,
+        id: path.targetInfo.id,
+    }
+}
+
+fn get_device_info(header: &mut DISPLAYCONFIG_DEVICE_INFO_HEADER) -> Result<()> {
+    let code = unsafe { DisplayConfigGetDeviceInfo(header) };
+    if code != 0 {
+        return Err(eyre!("Failed to get display info {:?}: {}", header.r#type, code).into());
+    }
+    Ok(())
+}
+
+/// Device path and friendly name of the display
+fn target_name(path: &DISPLAYCONFIG_PATH_INFO) -> Result<(String, String)> {
+    let mut target = DISPLAYCONFIG_TARGET_DEVICE_NAME {
+        header: header::<DISPLAYCONFIG_TARGET_DEVICE_NAME>(
+            path,
+            DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        ),
+        ..Default::default()
+    };
+    get_device_info(&mut target.header)?;
+    let id = U16CStr::from_slice_truncate(&target.monitorDevicePath)
+        .map_err(|_| "device path was not a valid u16 c string")?
+        .to_string_lossy();
+    let name = U16CStr::from_slice_truncate(&target.monitorFriendlyDeviceName)
+        .map_err(|_| "display name was not a valid u16 c string")?
+        .to_string_lossy();
+    Ok((id, name))
+}
+
+/// (supported, enabled)
+fn advanced_color(path: &DISPLAYCONFIG_PATH_INFO) -> Result<(bool, bool)> {
+    let mut info = DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO {
+        header: header::<DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO>(
+            path,
+            DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO,
+        ),
+        ..Default::default()
+    };
+    get_device_info(&mut info.header)?;
+    // bitfield: advancedColorSupported, advancedColorEnabled, wideColorEnforced, ...
+    let bits = unsafe { info.Anonymous.value };
+    Ok((bits & 0b1 != 0, bits & 0b10 != 0))
+}
+
+fn sdr_white_level(path: &DISPLAYCONFIG_PATH_INFO) -> Result<u32> {
+    let mut level = DISPLAYCONFIG_SDR_WHITE_LEVEL {
+        header: header::<DISPLAYCONFIG_SDR_WHITE_LEVEL>(
+            path,
+            DISPLAYCONFIG_DEVICE_INFO_GET_SDR_WHITE_LEVEL,
+        ),
+        ..Default::default()
+    };
+    get_device_info(&mut level.header)?;
+    // the level is a multiplier of 80 nits, where 1000 means 1.0
+    Ok(level.SDRWhiteLevel * 80 / 1000)
+}
+
+fn default_profile(path: &DISPLAYCONFIG_PATH_INFO) -> Result<String> {
+    let name = unsafe {
+        ColorProfileGetDisplayDefault(
+            WCS_PROFILE_MANAGEMENT_SCOPE_CURRENT_USER,
+            path.targetInfo.adapterId,
+            path.sourceInfo.id,
+            CPT_ICC,
+            CPST_NONE,
+        )?
+    };
+    let profile = unsafe { name.to_string() };
+    let _ = unsafe { LocalFree(HLOCAL(name.0 as _)) };
+    Ok(profile?)
+}
+
+fn profiles(path: &DISPLAYCONFIG_PATH_INFO) -> Result<Vec<String>> {
+    let mut list: *mut PWSTR = std::ptr::null_mut();
+    let mut count = 0;
+    unsafe {
+        ColorProfileGetDisplayList(
+            WCS_PROFILE_MANAGEMENT_SCOPE_CURRENT_USER,
+            path.targetInfo.adapterId,
+            path.sourceInfo.id,
+            &mut list,
+            &mut count,
+        )?
+    };
+    if list.is_null() {
+        return Ok(Vec::new());
+    }
+    let profiles = unsafe { std::slice::from_raw_parts(list, count as usize) }
+        .iter()
+        .filter_map(|name| unsafe { name.to_string() }.ok())
+        .collect();
+    // only the list is freed, as documented for ColorProfileGetDisplayList
+    let _ = unsafe { LocalFree(HLOCAL(list as _)) };
+    Ok(profiles)
+}
@@ -0,0 +1,18 @@
+use crate::error_handler::Result;
+
+use super::color::DisplayColor;
+
+#[tauri::command(async)]
+pub fn monitors_get_display_colors() -> Result<Vec<DisplayColor>> {
+    DisplayColor::all()
+}
+
+#[tauri::command(async)]
+pub fn monitors_set_hdr(id: String, enabled: bool) -> Result<()> {
+    DisplayColor::set_hdr(&id, enabled)
+}
+
+#[tauri::command(async)]
+pub fn monitors_set_color_profile(id: String, profile: String) -> Result<()> {
+    DisplayColor::set_profile(&id, &profile)
+}
@@ -1,34 +1,46 @@
+pub mod color;
+pub mod infrastructure;
+pub mod projection;
+pub mod theme;
+
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use windows::{
     core::PCWSTR,
     Win32::{
         Devices::Display::GUID_DEVINTERFACE_MONITOR,
-        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        Foundation::{HWND, LPARAM, LRESULT, RECT, WPARAM},
         Graphics::Gdi::HMONITOR,
         UI::WindowsAndMessaging::{
             CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW,
             RegisterDeviceNotificationW, TranslateMessage, DBT_DEVTYP_DEVICEINTERFACE,
-            DEVICE_NOTIFY_WINDOW_HANDLE, DEV_BROADCAST_DEVICEINTERFACE_W, MSG, WINDOW_EX_STYLE,
-            WINDOW_STYLE, WM_DEVICECHANGE, WM_DISPLAYCHANGE, WM_SETTINGCHANGE, WNDCLASSW,
+            DEVICE_NOTIFY_WINDOW_HANDLE, DEV_BROADCAST_DEVICEINTERFACE_W, MSG, SWP_NOACTIVATE,
+            SWP_NOZORDER, WINDOW_EX_STYLE, WINDOW_STYLE, WM_DEVICECHANGE, WM_DISPLAYCHANGE,
+            WM_SETTINGCHANGE, WNDCLASSW,
         },
     },
 };
 
 use crate::{
     error_handler::Result,
-    log_error, trace_lock,
+    log_error,
+    seelen_weg::SeelenWeg,
+    trace_lock,
     utils::spawn_named_thread,
-    windows_api::{MonitorEnumerator, WindowsApi},
+    windows_api::{MonitorEnumerator, WindowEnumerator, WindowsApi},
 };
 
+use projection::DisplayProjection;
+
 lazy_static! {
     pub static ref MONITOR_MANAGER: Arc<Mutex<MonitorManager>> = Arc::new(Mutex::new(
         MonitorManager::new().expect("Failed to create monitor manager")
     ));
 }
 
+/// Monitors are identified by their stable id (see `WindowsApi::monitor_stable_id`), the
+/// names can be swapped when the monitors are connected again in a different order.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MonitorManagerEvent {
     Added(String, HMONITOR),
@@ -40,7 +52,12 @@ type OnMonitorsChange = Box<dyn Fn(MonitorManagerEvent) + Send + Sync>;
 
 pub struct MonitorManager {
     hwnd: isize,
+    /// connected monitors by stable id
     pub monitors: Vec<(String, HMONITOR)>,
+    /// rect and scale factor by monitor id, used to detect resolution and dpi changes
+    geometry: HashMap<String, (RECT, f32)>,
+    /// duplicated/projected monitors, switching to duplicate mode can keep the same monitors
+    projection: DisplayProjection,
     callbacks: Vec<OnMonitorsChange>,
 }
 
@@ -68,28 +85,41 @@ impl MonitorManager {
                         Err(_) => return LRESULT(0),
                     };
 
-                    for (name, id) in &new_list {
-                        match old_list.iter().position(|x| x.0 == *name) {
+                    let projection = DisplayProjection::current().unwrap_or_default();
+                    let mut updated = Vec::new();
+                    let mut added = Vec::new();
+                    for (id, handle) in &new_list {
+                        match old_list.iter().position(|x| x.0 == *id) {
                             Some(idx) => {
-                                let (_, old_id) = old_list.remove(idx);
-                                if old_id != *id {
-                                    manager.notify_changes(MonitorManagerEvent::Updated(
-                                        name.clone(),
-                                        *id,
-                                    ));
+                                let (_, old_handle) = old_list.remove(idx);
+                                let geometry = Self::get_geometry(*handle);
+                                let name = WindowsApi::monitor_name(*handle).unwrap_or_default();
+                                let projection_changed = manager.projection.is_projected(&name)
+                                    != projection.is_projected(&name);
+                                if old_handle != *handle
+                                    || manager.geometry.get(id) != geometry.as_ref()
+                                    || projection_changed
+                                {
+                                    updated.push((id.clone(), *handle));
                                 }
                             }
-                            None => {
-                                manager
-                                    .notify_changes(MonitorManagerEvent::Added(name.clone(), *id));
-                            }
+                            None => added.push((id.clone(), *handle)),
                         }
                     }
 
-                    for (name, id) in old_list {
-                        manager.notify_changes(MonitorManagerEvent::Removed(name, id));
+                    // removed first, so the names released can be taken by the others
+                    for (id, handle) in old_list {
+                        manager.notify_changes(MonitorManagerEvent::Removed(id, handle));
+                    }
+                    for (id, handle) in updated {
+                        manager.notify_changes(MonitorManagerEvent::Updated(id, handle));
+                    }
+                    for (id, handle) in added {
+                        manager.notify_changes(MonitorManagerEvent::Added(id, handle));
                     }
 
+                    manager.geometry = Self::get_geometries(&new_list);
+                    manager.projection = projection;
                     manager.monitors = new_list.into_iter().collect();
                     LRESULT(0)
                 }
@@ -163,17 +193,33 @@ impl MonitorManager {
             }
         })?;
 
+        let monitors = Self::get_monitors()?;
         Ok(Self {
             hwnd: hwnd_receiver.recv()?.0,
             callbacks: Vec::new(),
-            monitors: Self::get_monitors()?,
+            geometry: Self::get_geometries(&monitors),
+            projection: DisplayProjection::current().unwrap_or_default(),
+            monitors,
         })
     }
 
+    fn get_geometry(id: HMONITOR) -> Option<(RECT, f32)> {
+        let rect = WindowsApi::monitor_rect(id).ok()?;
+        let scale = WindowsApi::get_device_pixel_ratio(id).ok()?;
+        Some((rect, scale))
+    }
+
+    fn get_geometries(monitors: &[(String, HMONITOR)]) -> HashMap<String, (RECT, f32)> {
+        monitors
+            .iter()
+            .filter_map(|(id, handle)| Some((id.clone(), Self::get_geometry(*handle)?)))
+            .collect()
+    }
+
     fn get_monitors() -> Result<Vec<(String, HMONITOR)>> {
         let mut monitors = Vec::new();
         for m in MonitorEnumerator::new_refreshed()? {
-            monitors.push((WindowsApi::monitor_name(m)?, m));
+            monitors.push((WindowsApi::monitor_stable_id(m)?, m));
         }
         Ok(monitors)
     }
@@ -185,9 +231,63 @@ impl MonitorManager {
         self.callbacks.push(Box::new(callback));
     }
 
+    /// removes the callbacks registered by `listen_changes`, they are registered again on start
+    pub fn clear_listeners(&mut self) {
+        self.callbacks.clear();
+    }
+
     pub fn notify_changes(&self, event: MonitorManagerEvent) {
         for callback in &self.callbacks {
             callback(event.clone());
         }
     }
+
+    /// Moves the windows left outside of all the connected monitors (usually after
+    /// disconnecting one) to the primary monitor, keeping their size when possible.
+    pub fn migrate_orphaned_windows() -> Result<()> {
+        let mut monitors = Vec::new();
+        for m in MonitorEnumerator::new_refreshed()? {
+            monitors.push(WindowsApi::monitor_rect(m)?);
+        }
+
+        let mut orphans = Vec::new();
+        WindowEnumerator::new().for_each(|hwnd| {
+            if WindowsApi::is_iconic(hwnd) || !SeelenWeg::should_be_added(hwnd) {
+                return;
+            }
+            let rect = WindowsApi::get_window_rect(hwnd);
+            let visible = monitors.iter().any(|m| {
+                rect.left < m.right
+                    && rect.right > m.left
+                    && rect.top < m.bottom
+                    && rect.bottom > m.top
+            });
+            if !visible {
+                orphans.push((hwnd, rect));
+            }
+        })?;
+
+        let work_area = WindowsApi::monitor_info(WindowsApi::primary_monitor())?
+            .monitorInfo
+            .rcWork;
+        for (hwnd, rect) in orphans {
+            log::trace!("Migrating orphaned window {:?}", hwnd);
+            let width = (rect.right - rect.left).min(work_area.right - work_area.left);
+            let height = (rect.bottom - rect.top).min(work_area.bottom - work_area.top);
+            let left = work_area.left + (work_area.right - work_area.left - width) / 2;
+            let top = work_area.top + (work_area.bottom - work_area.top - height) / 2;
+            log_error!(WindowsApi::set_position(
+                hwnd,
+                None,
+                &RECT {
+                    left,
+                    top,
+                    right: left + width,
+                    bottom: top + height,
+                },
+                SWP_NOACTIVATE | SWP_NOZORDER,
+            ));
+        }
+        Ok(())
+    }
 }
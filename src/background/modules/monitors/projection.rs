@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::eyre;
+use widestring::U16CStr;
+use windows::Win32::Devices::Display::{
+    DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+    DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_MIRACAST, DISPLAYCONFIG_PATH_INFO,
+    DISPLAYCONFIG_SOURCE_DEVICE_NAME, QDC_ONLY_ACTIVE_PATHS,
+};
+
+use crate::error_handler::Result;
+
+/// Monitors (by name) whose output is duplicated on more than one display
+/// or sent to a wireless projector, read from the active display paths.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisplayProjection {
+    projected: HashSet<String>,
+}
+
+impl DisplayProjection {
+    pub fn current() -> Result<Self> {
+        let paths = active_paths()?;
+
+        // on duplicate mode all the targets share the same source
+        let mut targets_by_source: HashMap<(u32, i32, u32), (usize, bool)> = HashMap::new();
+        for path in &paths {
+            let source = &path.sourceInfo;
+            let key = (
+                source.adapterId.LowPart,
+                source.adapterId.HighPart,
+                source.id,
+            );
+            let entry = targets_by_source.entry(key).or_default();
+            entry.0 += 1;
+            entry.1 |= path.targetInfo.outputTechnology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_MIRACAST;
+        }
+
+        let mut projected = HashSet::new();
+        for path in &paths {
+            let source = &path.sourceInfo;
+            let key = (
+                source.adapterId.LowPart,
+                source.adapterId.HighPart,
+                source.id,
+            );
+            if let Some((targets, miracast)) = targets_by_source.get(&key) {
+                if *targets > 1 || *miracast {
+                    projected.insert(source_name(path)?);
+                }
+            }
+        }
+        Ok(Self { projected })
+    }
+
+    pub fn is_projected(&self, monitor_name: &str) -> bool {
+        self.projected.contains(monitor_name)
+    }
+
+    pub fn is_projecting(&self) -> bool {
+        !self.projected.is_empty()
+    }
+}
+
+/// Active display paths, one by target (physical display) connected to a source
+pub(super) fn active_paths() -> Result<Vec<DISPLAYCONFIG_PATH_INFO>> {
+    let mut path_count = 0;
+    let mut mode_count = 0;
+    unsafe {
+        GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count).ok()?
+    };
+
+    let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+    let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+    unsafe {
+        QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut path_count,
+            paths.as_mut_ptr(),
+            &mut mode_count,
+            modes.as_mut_ptr(),
+            None,
+        )
+        .ok()?
+    };
+    paths.truncate(path_count as usize);
+    Ok(paths)
+}
+
+/// GDI device name of the path source, same as `WindowsApi::monitor_name`
+pub(super) fn source_name(path: &DISPLAYCONFIG_PATH_INFO) -> Result<String> {
+    let mut source_name = DISPLAYCONFIG_SOURCE_DEVICE_NAME {
+        header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+            r#type: DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+            size: std::mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32,
+            adapterId: path.sourceInfo.adapterId,
+            id: path.sourceInfo.id,
+        },
+        ..Default::default()
+    };
+    let code = unsafe { DisplayConfigGetDeviceInfo(&mut source_name.header) };
+    if code != 0 {
+        return Err(eyre!("Failed to get display source name: {}", code).into());
+    }
+    Ok(U16CStr::from_slice_truncate(&source_name.viewGdiDeviceName)
+        .map_err(|_| "source name was not a valid u16 c string")?
+        .to_string_lossy()
+        .trim_start_matches(r"\\.\")
+        .to_string())
+}
@@ -0,0 +1,146 @@
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::Graphics::Gdi::HMONITOR;
+
+use crate::{
+    error_handler::Result, log_error, modules::system_settings::application::SYSTEM_SETTINGS,
+    seelen::SEELEN, trace_lock, utils::spawn_named_thread, windows_api::WindowsApi,
+};
+
+lazy_static! {
+    /// last accent color of the system, updated from the colors change event
+    static ref ACCENT: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// Values of a monitor exposed to the themes as css variables on the root element of the dock
+/// and toolbar webviews of that monitor. They are injected on creation (before the first paint)
+/// and updated on monitor, dpi and accent changes, so themes don't need to poll commands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorThemeVars {
+    /// monitor name like `DISPLAY1`, also set as the `data-monitor` attribute of the root
+    pub name: String,
+    pub scale: f32,
+    /// work area size in css pixels (already divided by the scale)
+    pub work_width: i32,
+    pub work_height: i32,
+    pub accent: Option<String>,
+}
+
+impl MonitorThemeVars {
+    pub fn new(hmonitor: HMONITOR) -> Result<Self> {
+        let rc_work = WindowsApi::monitor_info(hmonitor)?.monitorInfo.rcWork;
+        let scale = WindowsApi::get_device_pixel_ratio(hmonitor)?;
+        Ok(Self {
+            name: WindowsApi::monitor_name(hmonitor)?,
+            scale,
+            work_width: ((rc_work.right - rc_work.left) as f32 / scale).round() as i32,
+            work_height: ((rc_work.bottom - rc_work.top) as f32 / scale).round() as i32,
+            accent: Self::system_accent(),
+        })
+    }
+
+    fn system_accent() -> Option<String> {
+        if let Some(accent) = trace_lock!(ACCENT).clone() {
+            return Some(accent);
+        }
+        // not locked together, the colors event locks them in the inverse order
+        trace_lock!(SYSTEM_SETTINGS)
+            .get_colors()
+            .ok()
+            .map(|colors| colors.accent)
+    }
+
+    /// (name, value), a none value removes the variable
+    fn css_vars(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("--monitor-name", Some(format!("{:?}", self.name))),
+            ("--monitor-scale", Some(self.scale.to_string())),
+            (
+                "--monitor-work-width",
+                Some(format!("{}px", self.work_width)),
+            ),
+            (
+                "--monitor-work-height",
+                Some(format!("{}px", self.work_height)),
+            ),
+            (
+                "--monitor-accent-color",
+                self.accent
+                    .as_ref()
+                    .map(|accent| accent.chars().take(7).collect::<String>()),
+            ),
+        ]
+    }
+
+    /// Script applying the variables, valid as initialization script where the root element
+    /// may not exist yet, and to be evaluated on already loaded webviews.
+    pub fn script(&self) -> String {
+        let vars: serde_json::Map<String, serde_json::Value> = self
+            .css_vars()
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.into()))
+            .collect();
+        let vars = serde_json::Value::Object(vars);
+        let name = serde_json::Value::String(self.name.clone());
+        format!(
+            r#"(() => {{
+  const vars = {vars};
+  const apply = () => {{
+    const root = document.documentElement;
+    root.dataset.monitor = {name};
+    for (const [key, value] of Object.entries(vars)) {{
+      if (value === null) root.style.removeProperty(key);
+      else root.style.setProperty(key, value);
+    }}
+  }};
+  if (document.documentElement) apply();
+  else document.addEventListener('DOMContentLoaded', apply, {{ once: true }});
+}})();"#
+        )
+    }
+
+    /// Called from the colors event, the webviews of all the monitors are updated
+    pub fn on_accent_changed(accent: &str) {
+        {
+            let mut current = trace_lock!(ACCENT);
+            if current.as_deref() == Some(accent) {
+                return;
+            }
+            *current = Some(accent.to_string());
+        }
+        // the colors event is dispatched while the system settings are locked
+        log_error!(spawn_named_thread("Monitor Theme Vars", || {
+            for monitor in trace_lock!(SEELEN).monitors() {
+                log_error!(monitor.update_theme_vars());
+            }
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_vars_are_ready_to_use() {
+        let vars = MonitorThemeVars {
+            name: "DISPLAY1".to_string(),
+            scale: 1.5,
+            work_width: 1280,
+            work_height: 680,
+            accent: Some("#0078d4ff".to_string()),
+        };
+        let css = vars.css_vars();
+        assert_eq!(css[0].1.as_deref(), Some("\"DISPLAY1\""));
+        assert_eq!(css[1].1.as_deref(), Some("1.5"));
+        assert_eq!(css[2].1.as_deref(), Some("1280px"));
+        assert_eq!(css[4].1.as_deref(), Some("#0078d4"));
+
+        let vars = MonitorThemeVars {
+            accent: None,
+            ..vars
+        };
+        assert_eq!(vars.css_vars()[4].1, None);
+        assert!(vars.script().contains(r#""--monitor-accent-color":null"#));
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use seelen_core::state::{BarWheelAction, MouseButtonAction, MouseButtonBinding};
+use tauri_plugin_shell::ShellExt;
+use windows::Win32::{
+    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, POINT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::WindowsAndMessaging::{
+        CallNextHookEx, DispatchMessageW, GetAncestor, GetMessageW, InternalGetWindowText,
+        PostThreadMessageW, SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx,
+        WindowFromPoint, GA_ROOT, HC_ACTION, HHOOK, MSG, MSLLHOOKSTRUCT, SW_RESTORE, WH_MOUSE_LL,
+        WM_MOUSEWHEEL, WM_QUIT, WM_XBUTTONDOWN, WM_XBUTTONUP, XBUTTON1,
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    hook::LAST_ACTIVE_NOT_SEELEN,
+    log_error,
+    modules::{
+        input::Keyboard, osd::OsdKeys, overview::Overview, secure_desktop::SecureDesktop,
+        task_manager::TaskManager, virtual_desk::get_vd_manager,
+    },
+    seelen::get_app_handle,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    utils::spawn_named_thread,
+    windows_api::{window::Window, WindowsApi},
+};
+
+/// Thread id of the mouse hook loop, 0 if the handler is not running
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WheelDirection {
+    Up,
+    Down,
+}
+
+/// Maps the extra mouse buttons and the wheel over the dock and the toolbar to shell actions.
+/// Events without an assigned action are passed through to the app under the cursor.
+///
+/// The wheel is intercepted before it reaches the webviews, so it works even if they are
+/// not focused or are ignoring the cursor on their empty areas.
+pub struct MouseButtonsHandler;
+
+impl MouseButtonsHandler {
+    pub fn is_running() -> bool {
+        HOOK_THREAD_ID.load(Ordering::Acquire) != 0
+    }
+
+    pub fn start() -> Result<()> {
+        if Self::is_running() {
+            return Ok(());
+        }
+        log::trace!("Starting mouse buttons handler");
+        let h_module = WindowsApi::module_handle_w()?;
+        spawn_named_thread("Mouse Buttons Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            let hook =
+                SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook_proc), HINSTANCE(h_module.0), 0);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            log_error!(unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) });
+        }
+    }
+
+    fn binding_for(x_button: u16) -> Option<MouseButtonBinding> {
+        let state = FULL_STATE.load();
+        let settings = &state.settings().mouse_buttons;
+        let binding = if x_button == XBUTTON1 {
+            &settings.x1
+        } else {
+            &settings.x2
+        };
+        (binding.action != MouseButtonAction::None).then(|| binding.clone())
+    }
+
+    fn wheel_action(point: POINT) -> Option<BarWheelAction> {
+        let root = unsafe { GetAncestor(WindowFromPoint(point), GA_ROOT) };
+        let own = WindowsApi::window_thread_process_id(root).1 == WindowsApi::current_process_id();
+
+        let state = FULL_STATE.load();
+        let settings = state.settings();
+        let action = if own && Self::is_weg(root) {
+            settings.mouse_buttons.wheel_on_weg
+        } else if own || Window::from(root).is_desktop() {
+            // empty areas of the toolbar ignore the cursor, so the desktop is under it
+            if !settings.fancy_toolbar.enabled {
+                return None;
+            }
+            let monitor = WindowsApi::monitor_from_point(point);
+            let rect = WindowsApi::monitor_rect(monitor).ok()?;
+            let dpi = WindowsApi::get_device_pixel_ratio(monitor).ok()?;
+            let height = (settings.fancy_toolbar.height as f32 * dpi) as i32;
+            if point.y < rect.top || point.y >= rect.top + height {
+                return None;
+            }
+
+            let regions = &settings.mouse_buttons.wheel_on_toolbar;
+            let third = (rect.right - rect.left) / 3;
+            match point.x - rect.left {
+                x if x < third => regions.left,
+                x if x < third * 2 => regions.center,
+                _ => regions.right,
+            }
+        } else {
+            return None;
+        };
+        (action != BarWheelAction::None).then_some(action)
+    }
+
+    /// `InternalGetWindowText` is used as it doesn't send messages, so the hook is not blocked
+    fn is_weg(hwnd: HWND) -> bool {
+        let mut text = [0u16; 32];
+        let len = unsafe { InternalGetWindowText(hwnd, &mut text) } as usize;
+        String::from_utf16_lossy(&text[..len]).starts_with("SeelenWeg")
+    }
+
+    fn run(binding: &MouseButtonBinding) -> Result<()> {
+        match binding.action {
+            MouseButtonAction::None => {}
+            MouseButtonAction::StartMenu => Keyboard::new().send_keys("{win}")?,
+            MouseButtonAction::Overview => Overview::toggle()?,
+            MouseButtonAction::TaskManager => TaskManager::toggle()?,
+            MouseButtonAction::SwitchToNextWorkspace => Self::switch_workspace(1)?,
+            MouseButtonAction::SwitchToPreviousWorkspace => Self::switch_workspace(-1)?,
+            MouseButtonAction::RunCommand => {
+                let (program, args) = match binding.command.as_deref() {
+                    Some([program, args @ ..]) => (program.clone(), args.to_vec()),
+                    _ => return Err("Mouse binding RunCommand without command".into()),
+                };
+                tauri::async_runtime::spawn(async move {
+                    log_error!(
+                        get_app_handle()
+                            .shell()
+                            .command(program)
+                            .args(args)
+                            .status()
+                            .await
+                    );
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn run_wheel(action: BarWheelAction, direction: WheelDirection) -> Result<()> {
+        let step = if direction == WheelDirection::Up {
+            -1
+        } else {
+            1
+        };
+        match action {
+            BarWheelAction::None => {}
+            BarWheelAction::Volume => Keyboard::new().send_keys(match direction {
+                WheelDirection::Up => "{volume_up}",
+                WheelDirection::Down => "{volume_down}",
+            })?,
+            BarWheelAction::Brightness => OsdKeys::step_brightness(-step as i32)?,
+            BarWheelAction::SwitchApps => Self::switch_app(step)?,
+            BarWheelAction::SwitchWorkspaces => Self::switch_workspace(step)?,
+        }
+        Ok(())
+    }
+
+    fn switch_workspace(step: isize) -> Result<()> {
+        let vd = get_vd_manager();
+        let len = vd.get_all()?.len() as isize;
+        if len == 0 {
+            return Ok(());
+        }
+        let current = vd.get_current_idx()? as isize;
+        vd.switch_to((current + step).rem_euclid(len) as usize)?;
+        Ok(())
+    }
+
+    /// focuses the open app of the dock next to the last active one
+    fn switch_app(step: isize) -> Result<()> {
+        let handles = SeelenWeg::open_handles();
+        if handles.is_empty() {
+            return Ok(());
+        }
+
+        let len = handles.len() as isize;
+        let active = LAST_ACTIVE_NOT_SEELEN.load(Ordering::Acquire);
+        let next = match handles.iter().position(|hwnd| *hwnd == active) {
+            Some(idx) => (idx as isize + step).rem_euclid(len) as usize,
+            None => 0,
+        };
+
+        let hwnd = HWND(handles[next]);
+        if WindowsApi::is_iconic(hwnd) {
+            WindowsApi::show_window(hwnd, SW_RESTORE)?;
+        }
+        WindowsApi::async_force_set_foreground(hwnd);
+        Ok(())
+    }
+}
+
+unsafe extern "system" fn mouse_hook_proc(code: i32, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 && !SecureDesktop::is_active() {
+        let data = &*(l_param.0 as *const MSLLHOOKSTRUCT);
+        // high word of mouseData holds the button or the wheel delta
+        let high_word = (data.mouseData >> 16) as u16;
+
+        // the hook must return fast, the actions run outside of it
+        match w_param.0 as u32 {
+            WM_XBUTTONDOWN => {
+                if let Some(binding) = MouseButtonsHandler::binding_for(high_word) {
+                    std::thread::spawn(move || log_error!(MouseButtonsHandler::run(&binding)));
+                    return LRESULT(1);
+                }
+            }
+            // the release of a handled button is also swallowed to not trigger back/forward
+            WM_XBUTTONUP => {
+                if MouseButtonsHandler::binding_for(high_word).is_some() {
+                    return LRESULT(1);
+                }
+            }
+            WM_MOUSEWHEEL => {
+                if let Some(action) = MouseButtonsHandler::wheel_action(data.pt) {
+                    let direction = if high_word as i16 > 0 {
+                        WheelDirection::Up
+                    } else {
+                        WheelDirection::Down
+                    };
+                    std::thread::spawn(move || {
+                        log_error!(MouseButtonsHandler::run_wheel(action, direction))
+                    });
+                    return LRESULT(1);
+                }
+            }
+            _ => {}
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
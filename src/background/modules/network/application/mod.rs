@@ -1,4 +1,5 @@
 pub mod scanner;
+pub mod vpn;
 
 use std::{env::temp_dir, net::UdpSocket};
 
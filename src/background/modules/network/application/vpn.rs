@@ -0,0 +1,235 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use widestring::U16CStr;
+use windows::{
+    core::PCWSTR,
+    Win32::{
+        Foundation::{BOOL, INVALID_HANDLE_VALUE},
+        NetworkManagement::Rras::{
+            RASCN_Connection, RASCN_Disconnection, RASCS_Connected, RasConnectionNotificationW,
+            RasDialW, RasEnumConnectionsW, RasEnumEntriesW, RasGetConnectStatusW,
+            RasGetEntryDialParamsW, RasHangUpW, HRASCONN, RASCONNSTATUSW, RASCONNW, RASDIALPARAMSW,
+            RASENTRYNAMEW,
+        },
+        System::Threading::{CreateEventW, WaitForSingleObject, INFINITE},
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::network::domain::{VpnConnectionState, VpnProfile},
+    seelen::get_app_handle,
+    trace_lock,
+    utils::spawn_named_thread,
+};
+
+const ERROR_BUFFER_TOO_SMALL: u32 = 603;
+
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    /// profiles being dialed by seelen, the ras api only reports finished connections
+    static ref DIALING: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+fn ras_error(code: u32) -> crate::error_handler::AppError {
+    format!("RAS error code {}", code).into()
+}
+
+fn wide_to_string(value: &[u16]) -> String {
+    U16CStr::from_slice_truncate(value)
+        .map(|s| s.to_string_lossy())
+        .unwrap_or_default()
+}
+
+/// VPN profiles are read from the RAS phonebooks, this includes the profiles created
+/// from the Windows settings app. `VpnManagementAgent` is not used as it requires the
+/// restricted `networkingVpnProvider` capability.
+pub struct VpnManager;
+
+impl VpnManager {
+    fn entries() -> Result<Vec<String>> {
+        let entry_size = std::mem::size_of::<RASENTRYNAMEW>() as u32;
+        let mut entries = vec![RASENTRYNAMEW {
+            dwSize: entry_size,
+            ..Default::default()
+        }];
+        let mut bytes = entry_size;
+        let mut count = 0;
+
+        let mut code = unsafe {
+            RasEnumEntriesW(
+                PCWSTR::null(),
+                PCWSTR::null(),
+                entries.as_mut_ptr(),
+                &mut bytes,
+                &mut count,
+            )
+        };
+        if code == ERROR_BUFFER_TOO_SMALL {
+            let len = (bytes / entry_size) as usize;
+            entries = vec![RASENTRYNAMEW::default(); len];
+            for entry in &mut entries {
+                entry.dwSize = entry_size;
+            }
+            code = unsafe {
+                RasEnumEntriesW(
+                    PCWSTR::null(),
+                    PCWSTR::null(),
+                    entries.as_mut_ptr(),
+                    &mut bytes,
+                    &mut count,
+                )
+            };
+        }
+        if code != 0 {
+            return Err(ras_error(code));
+        }
+
+        Ok(entries
+            .iter()
+            .take(count as usize)
+            .map(|entry| wide_to_string(&entry.szEntryName))
+            .collect())
+    }
+
+    /// Active connections by entry name
+    fn connections() -> Result<HashMap<String, HRASCONN>> {
+        let conn_size = std::mem::size_of::<RASCONNW>() as u32;
+        let mut connections = vec![RASCONNW {
+            dwSize: conn_size,
+            ..Default::default()
+        }];
+        let mut bytes = conn_size;
+        let mut count = 0;
+
+        let mut code =
+            unsafe { RasEnumConnectionsW(connections.as_mut_ptr(), &mut bytes, &mut count) };
+        if code == ERROR_BUFFER_TOO_SMALL {
+            let len = (bytes / conn_size) as usize;
+            connections = vec![RASCONNW::default(); len];
+            for connection in &mut connections {
+                connection.dwSize = conn_size;
+            }
+            code = unsafe { RasEnumConnectionsW(connections.as_mut_ptr(), &mut bytes, &mut count) };
+        }
+        if code != 0 {
+            return Err(ras_error(code));
+        }
+
+        Ok(connections
+            .iter()
+            .take(count as usize)
+            .map(|conn| (wide_to_string(&conn.szEntryName), conn.hrasconn))
+            .collect())
+    }
+
+    fn is_connected(handle: HRASCONN) -> bool {
+        let mut status = RASCONNSTATUSW {
+            dwSize: std::mem::size_of::<RASCONNSTATUSW>() as u32,
+            ..Default::default()
+        };
+        let code = unsafe { RasGetConnectStatusW(handle, &mut status) };
+        code == 0 && status.rasconnstate == RASCS_Connected
+    }
+
+    pub fn profiles() -> Result<Vec<VpnProfile>> {
+        let connections = Self::connections()?;
+        let dialing = trace_lock!(DIALING).clone();
+        Ok(Self::entries()?
+            .into_iter()
+            .map(|name| {
+                let state = match connections.get(&name) {
+                    Some(handle) if Self::is_connected(*handle) => VpnConnectionState::Connected,
+                    Some(_) => VpnConnectionState::Connecting,
+                    None if dialing.contains(&name) => VpnConnectionState::Connecting,
+                    None => VpnConnectionState::Disconnected,
+                };
+                VpnProfile { name, state }
+            })
+            .collect())
+    }
+
+    pub fn emit_profiles() -> Result<()> {
+        get_app_handle().emit("vpn-profiles", Self::profiles()?)?;
+        Ok(())
+    }
+
+    /// Dials the profile with its saved credentials, blocks until connected or failed
+    pub fn connect(name: &str) -> Result<()> {
+        let mut params = RASDIALPARAMSW {
+            dwSize: std::mem::size_of::<RASDIALPARAMSW>() as u32,
+            ..Default::default()
+        };
+        let wide_name: Vec<u16> = name.encode_utf16().collect();
+        if wide_name.len() >= params.szEntryName.len() {
+            return Err(format!("Invalid VPN profile name: {}", name).into());
+        }
+        params.szEntryName[..wide_name.len()].copy_from_slice(&wide_name);
+
+        let mut has_password = BOOL(0);
+        let code =
+            unsafe { RasGetEntryDialParamsW(PCWSTR::null(), &mut params, &mut has_password) };
+        if code != 0 {
+            return Err(ras_error(code));
+        }
+
+        trace_lock!(DIALING).push(name.to_string());
+        log_error!(Self::emit_profiles());
+
+        let mut handle = HRASCONN::default();
+        let code = unsafe { RasDialW(None, PCWSTR::null(), &params, 0, None, &mut handle) };
+        trace_lock!(DIALING).retain(|dialing| dialing != name);
+        if code != 0 {
+            if !handle.is_invalid() {
+                unsafe { RasHangUpW(handle) };
+            }
+            log_error!(Self::emit_profiles());
+            return Err(ras_error(code));
+        }
+        Ok(())
+    }
+
+    pub fn disconnect(name: &str) -> Result<()> {
+        if let Some(handle) = Self::connections()?.get(name) {
+            let code = unsafe { RasHangUpW(*handle) };
+            if code != 0 {
+                return Err(ras_error(code));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn register_events() -> Result<()> {
+        if REGISTERED.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Registering vpn events");
+
+        let event = unsafe { CreateEventW(None, false, false, PCWSTR::null())? };
+        // INVALID_HANDLE_VALUE registers the notifications for all the connections
+        let code = unsafe {
+            RasConnectionNotificationW(
+                HRASCONN(INVALID_HANDLE_VALUE.0),
+                event,
+                RASCN_Connection | RASCN_Disconnection,
+            )
+        };
+        if code != 0 {
+            return Err(ras_error(code));
+        }
+
+        spawn_named_thread("VPN Watcher", move || loop {
+            unsafe { WaitForSingleObject(event, INFINITE) };
+            log_error!(Self::emit_profiles());
+        })?;
+        Ok(())
+    }
+}
@@ -169,3 +169,18 @@ pub struct WlanBssEntry {
     /// true if the interface is connected to this network and is using this channel frequency
     pub connected_channel: bool,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VpnConnectionState {
+    Disconnected,
+    Connecting,
+    Connected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VpnProfile {
+    pub name: String,
+    pub state: VpnConnectionState,
+}
@@ -12,8 +12,8 @@ use crate::{
 };
 
 use super::{
-    application::{get_local_ip_address, NetworkManager},
-    domain::{NetworkAdapter, WlanProfile},
+    application::{get_local_ip_address, vpn::VpnManager, NetworkManager},
+    domain::{NetworkAdapter, VpnProfile, WlanProfile},
 };
 
 fn emit_networks(ip: String, adapters: Vec<NetworkAdapter>, has_internet: bool) {
@@ -40,6 +40,7 @@ pub fn register_network_events() -> Result<()> {
                 emit_networks(ip, adapters, has_internet_ipv4 || has_internet_ipv6);
             }
         });
+        log_error!(VpnManager::register_events());
     }
 
     std::thread::spawn(|| -> Result<()> {
@@ -57,6 +58,7 @@ pub fn register_network_events() -> Result<()> {
             })?;
             emit_networks(ip, adapters, has_internet);
         }
+        log_error!(VpnManager::emit_profiles());
         Ok(())
     });
 
@@ -133,3 +135,18 @@ pub async fn wlan_disconnect() -> Result<()> {
         Err(output.into())
     }
 }
+
+#[tauri::command(async)]
+pub fn vpn_get_profiles() -> Result<Vec<VpnProfile>> {
+    VpnManager::profiles()
+}
+
+#[tauri::command(async)]
+pub fn vpn_connect(name: String) -> Result<()> {
+    VpnManager::connect(&name)
+}
+
+#[tauri::command(async)]
+pub fn vpn_disconnect(name: String) -> Result<()> {
+    VpnManager::disconnect(&name)
+}
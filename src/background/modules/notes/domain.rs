@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Note {
+    pub id: u32,
+    pub text: String,
+    /// unix timestamps in seconds
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReminderRepeat {
+    #[default]
+    Never,
+    Daily,
+    Weekly,
+}
+
+impl ReminderRepeat {
+    /// seconds between repetitions
+    pub fn period(&self) -> Option<u64> {
+        match self {
+            Self::Never => None,
+            Self::Daily => Some(24 * 60 * 60),
+            Self::Weekly => Some(7 * 24 * 60 * 60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    pub id: u32,
+    pub text: String,
+    /// unix timestamp in seconds of the next notification
+    pub due_at: u64,
+    #[serde(default)]
+    pub repeat: ReminderRepeat,
+    /// set once a non repeating reminder was notified, kept until deleted
+    #[serde(default)]
+    pub done: bool,
+}
+
+/// Contents of `notes.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NotesStore {
+    pub notes: Vec<Note>,
+    pub reminders: Vec<Reminder>,
+    pub next_id: u32,
+}
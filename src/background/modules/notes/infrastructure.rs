@@ -0,0 +1,50 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{Note, NotesStore, Reminder, ReminderRepeat},
+    Notes,
+};
+
+#[tauri::command(async)]
+pub fn notes_get() -> NotesStore {
+    Notes::get()
+}
+
+#[tauri::command(async)]
+pub fn notes_add(text: String) -> Result<Note> {
+    Notes::add_note(text)
+}
+
+#[tauri::command(async)]
+pub fn notes_update(id: u32, text: String) -> Result<()> {
+    Notes::update_note(id, text)
+}
+
+#[tauri::command(async)]
+pub fn notes_delete(id: u32) -> Result<()> {
+    Notes::delete_note(id)
+}
+
+#[tauri::command(async)]
+pub fn reminders_add(
+    text: String,
+    due_at: u64,
+    repeat: Option<ReminderRepeat>,
+) -> Result<Reminder> {
+    Notes::add_reminder(text, due_at, repeat.unwrap_or_default())
+}
+
+#[tauri::command(async)]
+pub fn reminders_update(
+    id: u32,
+    text: String,
+    due_at: u64,
+    repeat: Option<ReminderRepeat>,
+) -> Result<()> {
+    Notes::update_reminder(id, text, due_at, repeat.unwrap_or_default())
+}
+
+#[tauri::command(async)]
+pub fn reminders_delete(id: u32) -> Result<()> {
+    Notes::delete_reminder(id)
+}
@@ -0,0 +1,208 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use domain::{Note, NotesStore, Reminder, ReminderRepeat};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{app_data_path, sleep_millis, spawn_named_thread},
+    windows_api::Toast,
+};
+
+lazy_static! {
+    static ref STORE: Arc<Mutex<NotesStore>> = Arc::new(Mutex::new(Notes::load()));
+}
+
+static SCHEDULER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Quick notes and timed reminders, persisted on `notes.json` of the user data.
+/// Each change is emitted as `notes-changed` with the whole [`NotesStore`].
+pub struct Notes;
+
+impl Notes {
+    const FILENAME: &'static str = "notes.json";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn load() -> NotesStore {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    /// Applies the change, saves the store and notifies the widgets
+    fn modify<T>(f: impl FnOnce(&mut NotesStore) -> Result<T>) -> Result<T> {
+        let mut store = trace_lock!(STORE);
+        let result = f(&mut store)?;
+        std::fs::write(Self::path(), serde_json::to_string_pretty(&*store)?)?;
+        get_app_handle().emit("notes-changed", &*store)?;
+        Ok(result)
+    }
+
+    fn next_id(store: &mut NotesStore) -> u32 {
+        store.next_id += 1;
+        store.next_id
+    }
+
+    pub fn get() -> NotesStore {
+        trace_lock!(STORE).clone()
+    }
+
+    pub fn add_note(text: String) -> Result<Note> {
+        Self::modify(|store| {
+            let now = Self::now();
+            let note = Note {
+                id: Self::next_id(store),
+                text,
+                created_at: now,
+                updated_at: now,
+            };
+            store.notes.push(note.clone());
+            Ok(note)
+        })
+    }
+
+    pub fn update_note(id: u32, text: String) -> Result<()> {
+        Self::modify(|store| {
+            let note = store
+                .notes
+                .iter_mut()
+                .find(|note| note.id == id)
+                .ok_or_else(|| format!("Note {} not found", id))?;
+            note.text = text;
+            note.updated_at = Self::now();
+            Ok(())
+        })
+    }
+
+    pub fn delete_note(id: u32) -> Result<()> {
+        Self::modify(|store| {
+            store.notes.retain(|note| note.id != id);
+            Ok(())
+        })
+    }
+
+    pub fn add_reminder(text: String, due_at: u64, repeat: ReminderRepeat) -> Result<Reminder> {
+        Self::modify(|store| {
+            let reminder = Reminder {
+                id: Self::next_id(store),
+                text,
+                due_at,
+                repeat,
+                done: false,
+            };
+            store.reminders.push(reminder.clone());
+            Ok(reminder)
+        })
+    }
+
+    pub fn update_reminder(
+        id: u32,
+        text: String,
+        due_at: u64,
+        repeat: ReminderRepeat,
+    ) -> Result<()> {
+        Self::modify(|store| {
+            let reminder = store
+                .reminders
+                .iter_mut()
+                .find(|reminder| reminder.id == id)
+                .ok_or_else(|| format!("Reminder {} not found", id))?;
+            reminder.text = text;
+            reminder.due_at = due_at;
+            reminder.repeat = repeat;
+            // rescheduling a notified reminder reactivates it
+            if due_at > Self::now() {
+                reminder.done = false;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn delete_reminder(id: u32) -> Result<()> {
+        Self::modify(|store| {
+            store.reminders.retain(|reminder| reminder.id != id);
+            Ok(())
+        })
+    }
+
+    /// Notifies the due reminders, repeating ones are moved to their next occurrence.
+    /// Occurrences missed while the app was closed are notified only once.
+    fn fire_due() -> Result<()> {
+        let now = Self::now();
+        let is_due = |r: &Reminder| !r.done && r.due_at <= now;
+        if !trace_lock!(STORE).reminders.iter().any(is_due) {
+            return Ok(());
+        }
+
+        let fired = Self::modify(|store| {
+            let mut fired = Vec::new();
+            for reminder in store.reminders.iter_mut().filter(|r| is_due(r)) {
+                fired.push(reminder.text.clone());
+                match reminder.repeat.period() {
+                    Some(period) => {
+                        let missed = (now - reminder.due_at) / period + 1;
+                        reminder.due_at += missed * period;
+                    }
+                    None => reminder.done = true,
+                }
+            }
+            Ok(fired)
+        })?;
+
+        for text in fired {
+            Toast::show_titled("Reminder", &text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the reminders every few seconds, so changes of the system clock are respected
+pub struct ReminderScheduler;
+
+impl ReminderScheduler {
+    const INTERVAL: u64 = 10_000;
+
+    pub fn start() -> Result<()> {
+        if SCHEDULER_RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting reminder scheduler");
+        spawn_named_thread("Reminder Scheduler", || {
+            while SCHEDULER_RUNNING.load(Ordering::Acquire) {
+                log_error!(Notes::fire_due());
+                sleep_millis(Self::INTERVAL);
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        SCHEDULER_RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -19,7 +19,10 @@ use windows::{
     },
 };
 
-use crate::{error_handler::Result, log_error, utils::spawn_named_thread};
+use crate::{
+    error_handler::Result, log_error, modules::do_not_disturb::DoNotDisturb,
+    utils::spawn_named_thread,
+};
 
 lazy_static! {
     pub static ref NOTIFICATION_MANAGER: Arc<Mutex<NotificationManager>> = Arc::new(Mutex::new(
@@ -36,6 +39,8 @@ pub struct AppNotification {
     app_logo: Option<PathBuf>,
     body: Vec<String>,
     date: i64,
+    /// received while do not disturb was active and its app is not an exception
+    silenced: bool,
 }
 
 enum NotificationEvent {
@@ -211,10 +216,12 @@ impl NotificationManager {
             body.push(text.Text()?.to_string());
         }
 
+        let app_name = display_info.DisplayName()?.to_string();
         self.notifications.push(AppNotification {
             id: u_notification.Id()?,
             app_logo: None,
-            app_name: display_info.DisplayName()?.to_string(),
+            silenced: DoNotDisturb::is_silenced(&app_name),
+            app_name,
             app_description: display_info.Description()?.to_string(),
             body,
             date: u_notification.CreationTime()?.UniversalTime,
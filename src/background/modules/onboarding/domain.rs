@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Onboarding steps, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnboardingStep {
+    /// capability checks are running
+    Checking,
+    /// waiting for the user to accept or change the defaults
+    AwaitingConsent,
+    /// the choices are being applied
+    Applying,
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilityCheck {
+    pub id: String,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl CapabilityCheck {
+    pub fn new(id: &str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Defaults chosen by the user, declining the takeover is choosing all the modules disabled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingChoices {
+    pub autostart: bool,
+    pub dock: bool,
+    pub toolbar: bool,
+    pub window_manager: bool,
+}
+
+impl Default for OnboardingChoices {
+    fn default() -> Self {
+        Self {
+            autostart: true,
+            dock: true,
+            toolbar: true,
+            window_manager: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingState {
+    pub step: OnboardingStep,
+    pub checks: Vec<CapabilityCheck>,
+    /// suggested choices, adjusted by the checks (e.g. conflicting taskbar replacements)
+    pub defaults: OnboardingChoices,
+}
+
+/// Saved on `onboarding.json` of the user data once completed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingRecord {
+    /// unix timestamp in seconds
+    pub completed_at: u64,
+    /// version of Seelen UI that completed the onboarding
+    pub version: String,
+    pub choices: Option<OnboardingChoices>,
+}
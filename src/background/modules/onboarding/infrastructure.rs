@@ -0,0 +1,16 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{OnboardingChoices, OnboardingState},
+    Onboarding,
+};
+
+#[tauri::command(async)]
+pub fn onboarding_get() -> OnboardingState {
+    Onboarding::state()
+}
+
+#[tauri::command(async)]
+pub fn onboarding_complete(choices: OnboardingChoices) -> Result<()> {
+    Onboarding::complete(choices)
+}
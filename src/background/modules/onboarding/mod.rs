@@ -0,0 +1,248 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use domain::{
+    CapabilityCheck, CheckStatus, OnboardingChoices, OnboardingRecord, OnboardingState,
+    OnboardingStep,
+};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::conflicts::{
+        domain::{Mitigation, KNOWN_CONFLICTS},
+        ConflictDetector,
+    },
+    seelen::{get_app_handle, Seelen, SEELEN},
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{app_data_path, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref STATE: Arc<Mutex<OnboardingState>> = Arc::new(Mutex::new(OnboardingState {
+        step: OnboardingStep::Checking,
+        checks: Vec::new(),
+        defaults: OnboardingChoices::default(),
+    }));
+}
+
+/// Set while the onboarding is not completed, the shell is not taken over meanwhile
+static PENDING: AtomicBool = AtomicBool::new(false);
+
+/// Oldest WebView2 runtime major version tested
+const MIN_WEBVIEW_VERSION: u32 = 110;
+
+/// First run flow: capability checks, user consent on the defaults and then the takeover of
+/// the shell. Until completed only the settings window is shown, the dock, toolbar and
+/// window manager are started once the choices are applied.
+pub struct Onboarding;
+
+impl Onboarding {
+    const FILENAME: &'static str = "onboarding.json";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    fn save_record(choices: Option<OnboardingChoices>) -> Result<()> {
+        let record = OnboardingRecord {
+            completed_at: Self::now(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            choices,
+        };
+        std::fs::write(Self::path(), serde_json::to_string_pretty(&record)?)?;
+        Ok(())
+    }
+
+    /// Users that already have settings from versions without onboarding are not asked,
+    /// their completion is recorded instead.
+    pub fn is_first_run() -> bool {
+        if Self::path().exists() {
+            return false;
+        }
+        if FULL_STATE.load().settings_path().exists() {
+            log_error!(Self::save_record(None));
+            return false;
+        }
+        true
+    }
+
+    pub fn is_pending() -> bool {
+        PENDING.load(Ordering::Acquire)
+    }
+
+    pub fn state() -> OnboardingState {
+        trace_lock!(STATE).clone()
+    }
+
+    fn set_step(step: OnboardingStep) {
+        let mut state = trace_lock!(STATE);
+        state.step = step;
+        log::trace!("Onboarding step: {:?}", step);
+        log_error!(get_app_handle().emit("onboarding-changed", &*state));
+    }
+
+    /// Shows the settings window, which leads the onboarding, and runs the checks
+    pub fn begin() -> Result<()> {
+        log::info!("First run, starting onboarding");
+        PENDING.store(true, Ordering::Release);
+        Self::set_step(OnboardingStep::Checking);
+        Seelen::show_settings()?;
+        spawn_named_thread("Onboarding Checks", || {
+            let checks = Self::run_checks();
+            {
+                let mut state = trace_lock!(STATE);
+                state.defaults = Self::suggested_defaults(&checks);
+                state.checks = checks;
+            }
+            Self::set_step(OnboardingStep::AwaitingConsent);
+        })?;
+        Ok(())
+    }
+
+    fn run_checks() -> Vec<CapabilityCheck> {
+        let mut checks = vec![
+            Self::check_webview(),
+            Self::check_autostart(),
+            Self::check_elevation(),
+        ];
+        checks.extend(Self::check_conflicts());
+        checks
+    }
+
+    fn check_webview() -> CapabilityCheck {
+        match tauri::webview_version() {
+            Ok(version) => {
+                let major = version
+                    .split('.')
+                    .next()
+                    .and_then(|major| major.parse::<u32>().ok())
+                    .unwrap_or(0);
+                if major >= MIN_WEBVIEW_VERSION {
+                    CapabilityCheck::new("webview2", CheckStatus::Ok, format!("WebView2 {version}"))
+                } else {
+                    CapabilityCheck::new(
+                        "webview2",
+                        CheckStatus::Warning,
+                        format!("WebView2 {version} is outdated, some widgets could not render"),
+                    )
+                }
+            }
+            Err(err) => CapabilityCheck::new(
+                "webview2",
+                CheckStatus::Error,
+                format!("WebView2 runtime not found: {err}"),
+            ),
+        }
+    }
+
+    fn check_autostart() -> CapabilityCheck {
+        match tauri::async_runtime::block_on(Seelen::is_auto_start_enabled()) {
+            Ok(true) => CapabilityCheck::new("autostart", CheckStatus::Ok, "Runs on startup"),
+            Ok(false) => CapabilityCheck::new(
+                "autostart",
+                CheckStatus::Warning,
+                "Not running on startup yet",
+            ),
+            Err(err) => CapabilityCheck::new(
+                "autostart",
+                CheckStatus::Error,
+                format!("The startup task could not be read: {:?}", err),
+            ),
+        }
+    }
+
+    /// Windows of elevated apps can't be moved or hidden from a non elevated process
+    fn check_elevation() -> CapabilityCheck {
+        match WindowsApi::is_elevated() {
+            Ok(true) => CapabilityCheck::new("elevation", CheckStatus::Ok, "Running as admin"),
+            _ => CapabilityCheck::new(
+                "elevation",
+                CheckStatus::Warning,
+                "Apps running as admin can't be managed unless Seelen UI also runs as admin",
+            ),
+        }
+    }
+
+    fn check_conflicts() -> Vec<CapabilityCheck> {
+        let found = ConflictDetector::scan();
+        if found.is_empty() {
+            return vec![CapabilityCheck::new(
+                "conflicts",
+                CheckStatus::Ok,
+                "No conflicting software found",
+            )];
+        }
+        KNOWN_CONFLICTS
+            .iter()
+            .filter(|conflict| found.contains_key(conflict.id))
+            .map(|conflict| {
+                CapabilityCheck::new(
+                    &format!("conflict-{}", conflict.id),
+                    CheckStatus::Warning,
+                    format!(
+                        "{} is running and can conflict with Seelen UI",
+                        conflict.name
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// The dock is not suggested along other taskbar replacements
+    fn suggested_defaults(checks: &[CapabilityCheck]) -> OnboardingChoices {
+        let replaced_taskbar = KNOWN_CONFLICTS
+            .iter()
+            .filter(|conflict| conflict.mitigations.contains(&Mitigation::KeepTaskbar))
+            .any(|conflict| {
+                let id = format!("conflict-{}", conflict.id);
+                checks.iter().any(|check| check.id == id)
+            });
+        OnboardingChoices {
+            dock: !replaced_taskbar,
+            ..Default::default()
+        }
+    }
+
+    /// Applies the choices, records the completion and takes over the shell
+    pub fn complete(choices: OnboardingChoices) -> Result<()> {
+        if trace_lock!(STATE).step != OnboardingStep::AwaitingConsent {
+            return Err("The onboarding is not waiting for consent".into());
+        }
+        Self::set_step(OnboardingStep::Applying);
+        log::info!("Onboarding choices: {:?}", choices);
+
+        let mut state = FULL_STATE.load().cloned();
+        state.set_modules_enabled(choices.dock, choices.toolbar, choices.window_manager)?;
+        state.store();
+        log_error!(tauri::async_runtime::block_on(Seelen::set_auto_start(
+            choices.autostart
+        )));
+        Self::save_record(Some(choices))?;
+
+        PENDING.store(false, Ordering::Release);
+        Self::set_step(OnboardingStep::Completed);
+        trace_lock!(SEELEN).start()
+    }
+}
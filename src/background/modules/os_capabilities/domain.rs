@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Architecture {
+    X86,
+    X64,
+    Arm64,
+    Unknown,
+}
+
+impl Architecture {
+    /// Architecture this binary was compiled for, it differs from the native one when running
+    /// under emulation (x64 build on an ARM64 device).
+    pub fn current_process() -> Self {
+        match std::env::consts::ARCH {
+            "x86" => Self::X86,
+            "x86_64" => Self::X64,
+            "aarch64" => Self::Arm64,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Features of the running OS, each one is resolved once at startup so the modules
+/// can gate their calls and fallback instead of failing when the api is missing.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OsCapabilities {
+    pub build: u32,
+    pub architecture: Architecture,
+    /// the process runs under emulation, e.g. the x64 build on ARM64
+    pub emulated: bool,
+    /// DWMWA_WINDOW_CORNER_PREFERENCE, Windows 11
+    pub corner_preference: bool,
+    /// DWMWA_SYSTEMBACKDROP_TYPE (mica, acrylic), Windows 11 22H2
+    pub system_backdrop: bool,
+    /// undocumented virtual desktop COM interfaces used by the native strategy
+    pub native_virtual_desktops: bool,
+    /// the tray overflow is a XAML island instead of a toolbar window
+    pub xaml_tray_overflow: bool,
+}
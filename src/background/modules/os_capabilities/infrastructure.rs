@@ -0,0 +1,6 @@
+use super::domain::OsCapabilities;
+
+#[tauri::command(async)]
+pub fn get_os_capabilities() -> OsCapabilities {
+    OsCapabilities::get().clone()
+}
@@ -0,0 +1,65 @@
+pub mod domain;
+pub mod infrastructure;
+
+use domain::{Architecture, OsCapabilities};
+use lazy_static::lazy_static;
+use windows::Win32::System::SystemInformation::{
+    GetNativeSystemInfo, PROCESSOR_ARCHITECTURE_AMD64, PROCESSOR_ARCHITECTURE_ARM64,
+    PROCESSOR_ARCHITECTURE_INTEL, SYSTEM_INFO,
+};
+
+lazy_static! {
+    static ref OS_CAPABILITIES: OsCapabilities = OsCapabilities::detect();
+}
+
+impl OsCapabilities {
+    const WIN10_FIRST_BUILD: u32 = 10240;
+    const WIN11_FIRST_BUILD: u32 = 22000;
+    /// 22H2, first build with the documented system backdrop attribute
+    const SYSTEM_BACKDROP_BUILD: u32 = 22621;
+    /// 24H2 changed the virtual desktop interfaces again
+    const VIRTUAL_DESKTOP_MAX_BUILD: u32 = 26000;
+
+    pub fn get() -> &'static Self {
+        &OS_CAPABILITIES
+    }
+
+    fn native_architecture() -> Architecture {
+        let mut info = SYSTEM_INFO::default();
+        unsafe { GetNativeSystemInfo(&mut info) };
+        match unsafe { info.Anonymous.Anonymous.wProcessorArchitecture } {
+            PROCESSOR_ARCHITECTURE_INTEL => Architecture::X86,
+            PROCESSOR_ARCHITECTURE_AMD64 => Architecture::X64,
+            PROCESSOR_ARCHITECTURE_ARM64 => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+
+    fn detect() -> Self {
+        let build = match os_info::get().version() {
+            os_info::Version::Semantic(_, _, build) => *build as u32,
+            _ => 0,
+        };
+        let architecture = Self::native_architecture();
+        let process_architecture = Architecture::current_process();
+
+        Self {
+            build,
+            architecture,
+            emulated: architecture != Architecture::Unknown && process_architecture != architecture,
+            corner_preference: build >= Self::WIN11_FIRST_BUILD,
+            system_backdrop: build >= Self::SYSTEM_BACKDROP_BUILD,
+            native_virtual_desktops: (Self::WIN10_FIRST_BUILD..Self::VIRTUAL_DESKTOP_MAX_BUILD)
+                .contains(&build),
+            xaml_tray_overflow: build >= Self::WIN11_FIRST_BUILD,
+        }
+    }
+
+    pub fn is_windows_10(&self) -> bool {
+        (Self::WIN10_FIRST_BUILD..Self::WIN11_FIRST_BUILD).contains(&self.build)
+    }
+
+    pub fn is_windows_11(&self) -> bool {
+        self.build >= Self::WIN11_FIRST_BUILD
+    }
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+use crate::modules::input::layout::KeyboardLayout;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum OsdEvent {
+    /// volume of the default output device, from 0.0 to 1.0
+    Volume {
+        level: f32,
+        muted: bool,
+    },
+    /// brightness of the built-in display, from 0 to 100
+    Brightness {
+        level: u32,
+    },
+    KeyboardLayout {
+        layout: KeyboardLayout,
+    },
+    /// mute state of the default microphone
+    Microphone {
+        muted: bool,
+    },
+}
@@ -0,0 +1,196 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crossbeam_channel::{unbounded, Sender};
+use lazy_static::lazy_static;
+use windows::Win32::{
+    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::{
+        Input::KeyboardAndMouse::{VIRTUAL_KEY, VK_VOLUME_DOWN, VK_VOLUME_MUTE, VK_VOLUME_UP},
+        WindowsAndMessaging::{
+            CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+            TranslateMessage, UnhookWindowsHookEx, HC_ACTION, HHOOK, KBDLLHOOKSTRUCT, MSG,
+            WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        media::{application::MEDIA_MANAGER, domain::SEELEN_AUDIO_CONTEXT},
+        secure_desktop::SecureDesktop,
+    },
+    state::application::FULL_STATE,
+    system::brightness::{change_main_monitor_brightness, get_main_monitor_brightness},
+    trace_lock,
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+use super::{domain::OsdEvent, Osd};
+
+/// Thread id of the keyboard hook loop, 0 if the interception is not running
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+lazy_static! {
+    static ref KEYS_QUEUE: Sender<VIRTUAL_KEY> = spawn_keys_worker();
+}
+
+/// The hook must return fast, the key presses are applied by this worker in order
+fn spawn_keys_worker() -> Sender<VIRTUAL_KEY> {
+    let (sender, receiver) = unbounded::<VIRTUAL_KEY>();
+    log_error!(spawn_named_thread("OSD Keys Worker", move || {
+        for key in receiver {
+            log_error!(OsdKeys::on_key_down(key));
+        }
+    }));
+    sender
+}
+
+/// Intercepts the volume keys so the changes use the steps and curves of the settings and
+/// only the Seelen OSD is shown. Brightness keys are handled by the firmware on most devices
+/// and never reach the hook, their changes are still shown by the watcher.
+pub struct OsdKeys;
+
+impl OsdKeys {
+    pub fn is_running() -> bool {
+        HOOK_THREAD_ID.load(Ordering::Acquire) != 0
+    }
+
+    pub fn start() -> Result<()> {
+        if Self::is_running() {
+            return Ok(());
+        }
+        log::trace!("Starting OSD keys interception");
+        let h_module = WindowsApi::module_handle_w()?;
+        spawn_named_thread("OSD Keyboard Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            let hook = SetWindowsHookExW(
+                WH_KEYBOARD_LL,
+                Some(keyboard_hook_proc),
+                HINSTANCE(h_module.0),
+                0,
+            );
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            log_error!(unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) });
+        }
+    }
+
+    fn on_key_down(key: VIRTUAL_KEY) -> Result<()> {
+        match key {
+            VK_VOLUME_UP => Self::step_volume(1),
+            VK_VOLUME_DOWN => Self::step_volume(-1),
+            VK_VOLUME_MUTE => Self::toggle_mute(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Position of `level` on the curve, steps are linear on the position
+    fn to_position(level: f32, exponent: f32) -> f32 {
+        level.clamp(0.0, 1.0).powf(1.0 / exponent)
+    }
+
+    fn to_level(position: f32, exponent: f32) -> f32 {
+        position.clamp(0.0, 1.0).powf(exponent)
+    }
+
+    /// Changes the volume of the default output device by one step, `direction` is 1 or -1
+    pub fn step_volume(direction: i32) -> Result<()> {
+        let settings = FULL_STATE.load().settings().osd.clone();
+        let manager = trace_lock!(MEDIA_MANAGER);
+        let Some(device) = manager.outputs().iter().find(|d| d.is_default_multimedia) else {
+            return Ok(());
+        };
+        let Some((endpoint, _)) = manager.devices_audio_endpoint().get(&device.id) else {
+            return Ok(());
+        };
+
+        let exponent = settings
+            .volume_curves
+            .get(&device.name)
+            .or_else(|| settings.volume_curves.get(&device.id))
+            .copied()
+            .unwrap_or(1.0)
+            .max(0.1);
+        let step = settings.volume_step as f32 / 100.0 * direction as f32;
+
+        let (level, muted) = unsafe {
+            let current = endpoint.GetMasterVolumeLevelScalar()?;
+            let level = Self::to_level(Self::to_position(current, exponent) + step, exponent);
+            endpoint.SetMasterVolumeLevelScalar(level, &SEELEN_AUDIO_CONTEXT)?;
+            // as Windows does, raising the volume unmutes the device
+            let mut muted = endpoint.GetMute()?.as_bool();
+            if muted && direction > 0 {
+                endpoint.SetMute(false, &SEELEN_AUDIO_CONTEXT)?;
+                muted = false;
+            }
+            (level, muted)
+        };
+        drop(manager);
+        Osd::show(OsdEvent::Volume { level, muted })
+    }
+
+    pub fn toggle_mute() -> Result<()> {
+        let manager = trace_lock!(MEDIA_MANAGER);
+        let Some(device) = manager.outputs().iter().find(|d| d.is_default_multimedia) else {
+            return Ok(());
+        };
+        let Some((endpoint, _)) = manager.devices_audio_endpoint().get(&device.id) else {
+            return Ok(());
+        };
+        let (level, muted) = unsafe {
+            let muted = !endpoint.GetMute()?.as_bool();
+            endpoint.SetMute(muted, &SEELEN_AUDIO_CONTEXT)?;
+            (endpoint.GetMasterVolumeLevelScalar()?, muted)
+        };
+        drop(manager);
+        Osd::show(OsdEvent::Volume { level, muted })
+    }
+
+    /// Changes the brightness of the main monitor by one step, `direction` is 1 or -1
+    pub fn step_brightness(direction: i32) -> Result<()> {
+        let step = FULL_STATE.load().settings().osd.brightness_step as i32;
+        change_main_monitor_brightness(step * direction)?;
+        let level = get_main_monitor_brightness()?.percent();
+        Osd::show(OsdEvent::Brightness { level })
+    }
+}
+
+unsafe extern "system" fn keyboard_hook_proc(
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION as i32 && !SecureDesktop::is_active() {
+        let data = &*(l_param.0 as *const KBDLLHOOKSTRUCT);
+        let key = VIRTUAL_KEY(data.vkCode as u16);
+        if matches!(key, VK_VOLUME_UP | VK_VOLUME_DOWN | VK_VOLUME_MUTE) {
+            let msg = w_param.0 as u32;
+            if msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN {
+                log_error!(KEYS_QUEUE.send(key));
+            }
+            // the key up is also swallowed, the Windows flyout would be shown otherwise
+            return LRESULT(1);
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
@@ -0,0 +1,141 @@
+pub mod domain;
+mod keys;
+mod watcher;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use domain::OsdEvent;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::OsdPosition;
+use tauri::{Emitter, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SWP_NOACTIVATE, SW_HIDE, SW_SHOWNOACTIVATE},
+};
+
+pub use keys::OsdKeys;
+pub use watcher::OsdWatcher;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::Mouse,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{overlay::OverlayBuilder, sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref OSD: Arc<Mutex<Option<Osd>>> = Arc::new(Mutex::new(None));
+}
+
+/// Incremented on each show, the hide timer only hides the osd if no other value was shown
+static OSD_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+/// Themed overlay flashed when the volume, brightness or keyboard layout changes.
+/// The window is created once and only hidden between uses.
+pub struct Osd {
+    window: WebviewWindow<Wry>,
+}
+
+impl Osd {
+    const TARGET: &'static str = "osd";
+    const WIDTH: i32 = 260;
+    const HEIGHT: i32 = 64;
+    const MARGIN: i32 = 80;
+
+    fn create_window() -> Result<WebviewWindow<Wry>> {
+        // the osd is never interactive
+        OverlayBuilder::new(Self::TARGET, "osd/index.html", "Seelen OSD")
+            .unfocused()
+            .click_through()
+            .build()
+    }
+
+    fn rect(position: OsdPosition) -> Result<RECT> {
+        let cursor = Mouse::get_cursor_pos().unwrap_or_default();
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        let area = WindowsApi::monitor_rect(monitor)?;
+        let scale = WindowsApi::get_device_pixel_ratio(monitor)?;
+        let width = (Self::WIDTH as f32 * scale) as i32;
+        let height = (Self::HEIGHT as f32 * scale) as i32;
+        let margin = (Self::MARGIN as f32 * scale) as i32;
+
+        let left = area.left + (area.right - area.left - width) / 2;
+        let top = match position {
+            OsdPosition::Top => area.top + margin,
+            OsdPosition::Bottom => area.bottom - margin - height,
+            OsdPosition::Center => area.top + (area.bottom - area.top - height) / 2,
+        };
+        Ok(RECT {
+            left,
+            top,
+            right: left + width,
+            bottom: top + height,
+        })
+    }
+
+    fn hwnd(&self) -> Result<HWND> {
+        Ok(HWND(self.window.hwnd()?.0))
+    }
+
+    /// Shows the event on the monitor under the cursor without stealing the focus
+    pub fn show(event: OsdEvent) -> Result<()> {
+        let settings = FULL_STATE.load().settings().osd.clone();
+        if !settings.enabled {
+            return Ok(());
+        }
+        let enabled_for_event = match event {
+            OsdEvent::Volume { .. } => settings.volume,
+            OsdEvent::Brightness { .. } => settings.brightness,
+            OsdEvent::KeyboardLayout { .. } => settings.keyboard_layout,
+            // the microphone mute is shown as part of the volume changes
+            OsdEvent::Microphone { .. } => settings.volume,
+        };
+        if !enabled_for_event {
+            return Ok(());
+        }
+
+        let mut osd = trace_lock!(OSD);
+        let osd = match osd.as_ref() {
+            Some(osd) => osd,
+            None => osd.insert(Self {
+                window: Self::create_window()?,
+            }),
+        };
+
+        let hwnd = osd.hwnd()?;
+        let rect = Self::rect(settings.position)?;
+        WindowsApi::set_position(hwnd, Some(HWND_TOPMOST), &rect, SWP_NOACTIVATE)?;
+        osd.window.emit_to(Self::TARGET, "osd-show", &event)?;
+        WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+
+        let generation = OSD_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        spawn_named_thread("OSD Timeout", move || {
+            sleep_millis(settings.duration);
+            if OSD_GENERATION.load(Ordering::SeqCst) == generation {
+                Self::hide();
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn hide() {
+        if let Some(osd) = trace_lock!(OSD).as_ref() {
+            if let Ok(hwnd) = osd.hwnd() {
+                log_error!(WindowsApi::show_window_async(hwnd, SW_HIDE));
+            }
+        }
+    }
+
+    pub fn destroy() {
+        if let Some(osd) = trace_lock!(OSD).take() {
+            log_error!(osd.window.destroy());
+        }
+    }
+}
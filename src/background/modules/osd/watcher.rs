@@ -0,0 +1,170 @@
+use std::sync::atomic::{AtomicIsize, AtomicU32, Ordering};
+
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Foundation::{HANDLE, HWND, LPARAM, LRESULT, WPARAM},
+        System::{
+            Power::{
+                RegisterPowerSettingNotification, UnregisterPowerSettingNotification,
+                POWERBROADCAST_SETTING,
+            },
+            SystemServices::GUID_VIDEO_CURRENT_MONITOR_BRIGHTNESS,
+        },
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            KillTimer, PostMessageW, PostQuitMessage, RegisterClassW, SetTimer, TranslateMessage,
+            DEVICE_NOTIFY_WINDOW_HANDLE, MSG, PBT_POWERSETTINGCHANGE, WINDOW_EX_STYLE,
+            WINDOW_STYLE, WM_CLOSE, WM_DESTROY, WM_POWERBROADCAST, WM_TIMER, WNDCLASSW,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{input::layout::KeyboardLayout, media::application::MEDIA_MANAGER},
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+use super::{domain::OsdEvent, Osd};
+
+/// hidden window receiving the notifications, 0 if the watcher is not running
+static WATCHER_HWND: AtomicIsize = AtomicIsize::new(0);
+/// last known brightness, `u32::MAX` until the initial value is received
+static LAST_BRIGHTNESS: AtomicU32 = AtomicU32::new(u32::MAX);
+static LAST_LAYOUT: AtomicIsize = AtomicIsize::new(0);
+static LAST_LAYOUT_WINDOW: AtomicIsize = AtomicIsize::new(0);
+
+/// Watches the changes that have no event on their own modules:
+/// brightness (power setting notification) and keyboard layout (polled on a timer).
+pub struct OsdWatcher;
+
+impl OsdWatcher {
+    const LAYOUT_TIMER_ID: usize = 1;
+    const LAYOUT_INTERVAL: u32 = 250;
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_POWERBROADCAST if w_param.0 as u32 == PBT_POWERSETTINGCHANGE => {
+                let setting = &*(l_param.0 as *const POWERBROADCAST_SETTING);
+                if setting.PowerSetting == GUID_VIDEO_CURRENT_MONITOR_BRIGHTNESS
+                    && setting.DataLength as usize >= std::mem::size_of::<u32>()
+                {
+                    let level = (setting.Data.as_ptr() as *const u32).read_unaligned();
+                    Self::on_brightness(level);
+                }
+                LRESULT(1)
+            }
+            WM_TIMER if w_param.0 == Self::LAYOUT_TIMER_ID => {
+                Self::check_layout();
+                LRESULT(0)
+            }
+            WM_CLOSE => {
+                log_error!(DestroyWindow(hwnd));
+                LRESULT(0)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    fn on_brightness(level: u32) {
+        let last = LAST_BRIGHTNESS.swap(level, Ordering::SeqCst);
+        // the first notification is sent on registration with the current value
+        if last != u32::MAX && last != level {
+            log_error!(Osd::show(OsdEvent::Brightness { level }));
+        }
+    }
+
+    /// Only switches on the same window are shown, focusing a window
+    /// that uses another layout is not a layout change.
+    fn check_layout() {
+        let hwnd = WindowsApi::get_foreground_window();
+        let hkl = KeyboardLayout::foreground_hkl();
+        let last_window = LAST_LAYOUT_WINDOW.swap(hwnd.0, Ordering::SeqCst);
+        let last_layout = LAST_LAYOUT.swap(hkl.0, Ordering::SeqCst);
+        if last_window == hwnd.0 && last_layout != 0 && last_layout != hkl.0 {
+            if let Some(layout) = KeyboardLayout::from_hkl(hkl) {
+                log_error!(Osd::show(OsdEvent::KeyboardLayout { layout }));
+            }
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if WATCHER_HWND.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Starting OSD watcher");
+
+        // volume changes are reported by the media manager callbacks
+        std::thread::spawn(|| lazy_static::initialize(&MEDIA_MANAGER));
+
+        let class_name = w!("SeelenOsdWatcher");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        spawn_named_thread("OSD Watcher", move || unsafe {
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+            WATCHER_HWND.store(hwnd.0, Ordering::Release);
+
+            let notification = RegisterPowerSettingNotification(
+                HANDLE(hwnd.0),
+                &GUID_VIDEO_CURRENT_MONITOR_BRIGHTNESS,
+                DEVICE_NOTIFY_WINDOW_HANDLE,
+            );
+            SetTimer(hwnd, Self::LAYOUT_TIMER_ID, Self::LAYOUT_INTERVAL, None);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            let _ = KillTimer(hwnd, Self::LAYOUT_TIMER_ID);
+            if let Ok(notification) = notification {
+                log_error!(UnregisterPowerSettingNotification(notification));
+            }
+            WATCHER_HWND.store(0, Ordering::Release);
+            LAST_BRIGHTNESS.store(u32::MAX, Ordering::Release);
+            LAST_LAYOUT.store(0, Ordering::Release);
+            log::trace!("OSD watcher stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let hwnd = WATCHER_HWND.load(Ordering::Acquire);
+        if hwnd != 0 {
+            log_error!(unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) });
+        }
+    }
+}
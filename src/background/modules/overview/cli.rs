@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::Overview;
+
+get_subcommands![
+    /** Opens the overview if it is closed, otherwise closes it */
+    Toggle,
+    /** Opens the overview on the monitor where the cursor is */
+    Show,
+    /** Closes the overview */
+    Hide,
+];
+
+impl Overview {
+    pub const CLI_IDENTIFIER: &'static str = "overview";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Windows overview (exposé)")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Toggle => Self::toggle()?,
+            SubCommand::Show => Self::show()?,
+            SubCommand::Hide => Self::hide(),
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use seelen_core::rect::Rect;
+use serde::Serialize;
+use windows::Win32::Foundation::{RECT, SIZE};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverviewItem {
+    pub hwnd: isize,
+    pub title: String,
+    pub exe: String,
+    /// position of the thumbnail relative to the overview window in physical pixels
+    pub rect: Rect,
+}
+
+/// Splits `area` in a grid of at least `count` cells, trying to keep them as squared as possible.
+pub fn grid_cells(count: usize, area: &RECT, gap: i32) -> Vec<RECT> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let width = area.right - area.left;
+    let height = area.bottom - area.top;
+
+    let cols = (count as f64 * width as f64 / height.max(1) as f64)
+        .sqrt()
+        .ceil()
+        .clamp(1.0, count as f64) as i32;
+    let rows = (count as i32 + cols - 1) / cols;
+
+    let cell_width = (width - gap * (cols + 1)) / cols;
+    let cell_height = (height - gap * (rows + 1)) / rows;
+
+    let mut cells = Vec::with_capacity(count);
+    for idx in 0..count as i32 {
+        let row = idx / cols;
+        let col = idx % cols;
+
+        // center the last row if it is not complete
+        let items_in_row = if row == rows - 1 {
+            count as i32 - row * cols
+        } else {
+            cols
+        };
+        let row_offset = (cols - items_in_row) * (cell_width + gap) / 2;
+
+        let left = area.left + gap + row_offset + col * (cell_width + gap);
+        let top = area.top + gap + row * (cell_height + gap);
+        cells.push(RECT {
+            left,
+            top,
+            right: left + cell_width,
+            bottom: top + cell_height,
+        });
+    }
+    cells
+}
+
+/// Returns the biggest rect with the aspect ratio of `source` that fits centered in `cell`.
+pub fn fit_in_cell(source: SIZE, cell: &RECT) -> RECT {
+    let cell_width = cell.right - cell.left;
+    let cell_height = cell.bottom - cell.top;
+    if source.cx <= 0 || source.cy <= 0 {
+        return *cell;
+    }
+
+    let scale = (cell_width as f64 / source.cx as f64)
+        .min(cell_height as f64 / source.cy as f64)
+        .min(1.0);
+    let width = (source.cx as f64 * scale) as i32;
+    let height = (source.cy as f64 * scale) as i32;
+
+    let left = cell.left + (cell_width - width) / 2;
+    let top = cell.top + (cell_height - height) / 2;
+    RECT {
+        left,
+        top,
+        right: left + width,
+        bottom: top + height,
+    }
+}
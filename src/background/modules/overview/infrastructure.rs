@@ -0,0 +1,15 @@
+use windows::Win32::Foundation::HWND;
+
+use crate::error_handler::Result;
+
+use super::Overview;
+
+#[tauri::command(async)]
+pub fn overview_focus(hwnd: isize) -> Result<()> {
+    Overview::focus(HWND(hwnd))
+}
+
+#[tauri::command(async)]
+pub fn overview_close() {
+    Overview::hide();
+}
@@ -0,0 +1,190 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use domain::{fit_in_cell, grid_cells, OverviewItem};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::{Emitter, Listener, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SWP_NOACTIVATE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::{domain::Point, Mouse},
+    seelen::get_app_handle,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::overlay::OverlayBuilder,
+    windows_api::{window::Window, DwmThumbnail, WindowEnumerator, WindowsApi},
+};
+
+lazy_static! {
+    pub static ref OVERVIEW: Arc<Mutex<Option<Overview>>> = Arc::new(Mutex::new(None));
+    static ref HOT_CORNER: Mutex<HotCorner> = Mutex::new(HotCorner::default());
+}
+
+pub struct Overview {
+    window: WebviewWindow<Wry>,
+    thumbnails: Vec<DwmThumbnail>,
+}
+
+impl Drop for Overview {
+    fn drop(&mut self) {
+        log::trace!("Closing overview");
+        self.thumbnails.clear();
+        log_error!(self.window.destroy());
+    }
+}
+
+impl Overview {
+    const TARGET: &'static str = "overview";
+    const GAP: i32 = 40;
+
+    /// windows on the current workspace that would be shown on the dock
+    fn get_windows() -> Result<Vec<HWND>> {
+        let mut windows = Vec::new();
+        WindowEnumerator::new().for_each(|hwnd| {
+            if SeelenWeg::should_be_added(hwnd) && !WindowsApi::is_cloaked(hwnd).unwrap_or(true) {
+                windows.push(hwnd);
+            }
+        })?;
+        Ok(windows)
+    }
+
+    fn new(monitor: HMONITOR) -> Result<Self> {
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+        let window =
+            OverlayBuilder::new(Self::TARGET, "overview/index.html", "Seelen Overview").build()?;
+        let hwnd = HWND(window.hwnd()?.0);
+        WindowsApi::set_position(hwnd, Some(HWND_TOPMOST), &monitor_rect, SWP_NOACTIVATE)?;
+
+        let sources = Self::get_windows()?;
+        let area = RECT {
+            left: 0,
+            top: 0,
+            right: monitor_rect.right - monitor_rect.left,
+            bottom: monitor_rect.bottom - monitor_rect.top,
+        };
+
+        let mut thumbnails = Vec::new();
+        let mut items = Vec::new();
+        for (source, cell) in sources
+            .iter()
+            .zip(grid_cells(sources.len(), &area, Self::GAP))
+        {
+            let thumbnail = match DwmThumbnail::new(hwnd, *source) {
+                Ok(thumbnail) => thumbnail,
+                Err(err) => {
+                    log::warn!("Failed to register thumbnail for {:?}: {:?}", source, err);
+                    continue;
+                }
+            };
+            let rect = fit_in_cell(thumbnail.source_size()?, &cell);
+            thumbnail.show(rect, 255)?;
+
+            let window = Window::from(*source);
+            items.push(OverviewItem {
+                hwnd: source.0,
+                title: window.title(),
+                exe: WindowsApi::exe(*source).unwrap_or_default(),
+                rect: rect.into(),
+            });
+            thumbnails.push(thumbnail);
+        }
+
+        let label = window.label().to_string();
+        window.once("complete-setup", move |_| {
+            let handle = get_app_handle();
+            log_error!(handle.emit_to(&label, "overview-items", &items));
+        });
+
+        Ok(Self { window, thumbnails })
+    }
+
+    pub fn show() -> Result<()> {
+        let mut overview = trace_lock!(OVERVIEW);
+        if overview.is_none() {
+            let cursor = Mouse::get_cursor_pos().unwrap_or_default();
+            let monitor = WindowsApi::monitor_from_point(cursor.into());
+            *overview = Some(Self::new(monitor)?);
+        }
+        Ok(())
+    }
+
+    pub fn hide() {
+        trace_lock!(OVERVIEW).take();
+    }
+
+    pub fn toggle() -> Result<()> {
+        let is_open = trace_lock!(OVERVIEW).is_some();
+        if is_open {
+            Self::hide();
+            Ok(())
+        } else {
+            Self::show()
+        }
+    }
+
+    pub fn focus(hwnd: HWND) -> Result<()> {
+        Self::hide();
+        if WindowsApi::is_iconic(hwnd) {
+            WindowsApi::restore_window(hwnd)?;
+        }
+        WindowsApi::force_set_foreground(hwnd)
+    }
+
+    /// Should be called on each cursor move, opens the overview if the cursor
+    /// stays on the top left corner of any monitor.
+    pub fn on_cursor_move(pos: Point) {
+        let settings = &FULL_STATE.load().settings().overview;
+        if !settings.hot_corner {
+            return;
+        }
+        let delay = Duration::from_millis(settings.hot_corner_delay);
+        if trace_lock!(HOT_CORNER).update(pos, delay) {
+            std::thread::spawn(|| log_error!(Self::toggle()));
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct HotCorner {
+    entered_at: Option<Instant>,
+    triggered: bool,
+}
+
+impl HotCorner {
+    const SIZE: i32 = 2;
+
+    /// returns true once per corner visit, when the cursor stays there longer than `delay`
+    fn update(&mut self, pos: Point, delay: Duration) -> bool {
+        let monitor = WindowsApi::monitor_from_point(pos.into());
+        let on_corner = WindowsApi::monitor_rect(monitor).is_ok_and(|rect| {
+            pos.get_x() < rect.left + Self::SIZE && pos.get_y() < rect.top + Self::SIZE
+        });
+
+        if !on_corner {
+            self.entered_at = None;
+            self.triggered = false;
+            return false;
+        }
+
+        let entered_at = *self.entered_at.get_or_insert_with(Instant::now);
+        if !self.triggered && entered_at.elapsed() >= delay {
+            self.triggered = true;
+            return true;
+        }
+        false
+    }
+}
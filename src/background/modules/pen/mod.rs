@@ -0,0 +1,138 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use seelen_core::state::{PenAction, PenBinding};
+use tauri_plugin_shell::ShellExt;
+use windows::Win32::{
+    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::{
+        Input::KeyboardAndMouse::{VIRTUAL_KEY, VK_F18, VK_F19, VK_F20, VK_LWIN, VK_RWIN},
+        WindowsAndMessaging::{
+            CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+            TranslateMessage, UnhookWindowsHookEx, HC_ACTION, HHOOK, KBDLLHOOKSTRUCT, MSG,
+            WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{input::Keyboard, overview::Overview, secure_desktop::SecureDesktop},
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+/// Thread id of the keyboard hook loop, 0 if the handler is not running
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+/// The pen top button is paired as a bluetooth keyboard that sends Win + F20 on click,
+/// Win + F19 on double click and Win + F18 on long press, Windows opens the Ink workspace
+/// on these shortcuts. The handler intercepts them to run the configured Seelen actions.
+pub struct PenButtonHandler;
+
+impl PenButtonHandler {
+    pub fn is_running() -> bool {
+        HOOK_THREAD_ID.load(Ordering::Acquire) != 0
+    }
+
+    pub fn start() -> Result<()> {
+        if Self::is_running() {
+            return Ok(());
+        }
+        log::trace!("Starting pen button handler");
+        let h_module = WindowsApi::module_handle_w()?;
+        spawn_named_thread("Pen Keyboard Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            let hook = SetWindowsHookExW(
+                WH_KEYBOARD_LL,
+                Some(keyboard_hook_proc),
+                HINSTANCE(h_module.0),
+                0,
+            );
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            log_error!(unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) });
+        }
+    }
+
+    fn binding_for(key: VIRTUAL_KEY) -> Option<PenBinding> {
+        let state = FULL_STATE.load();
+        let settings = &state.settings().pen;
+        let binding = match key {
+            VK_F20 => &settings.click,
+            VK_F19 => &settings.double_click,
+            VK_F18 => &settings.long_press,
+            _ => return None,
+        };
+        (binding.action != PenAction::System).then(|| binding.clone())
+    }
+
+    fn run(binding: &PenBinding) -> Result<()> {
+        // the win key is still down, pressing another key avoids the start menu on its release
+        Keyboard::new().send_keys("{ctrl}")?;
+        match binding.action {
+            PenAction::System => {}
+            PenAction::ScreenClip => WindowsApi::shell_open("ms-screenclip:")?,
+            PenAction::StartMenu => Keyboard::new().send_keys("{win}")?,
+            PenAction::Overview => Overview::show()?,
+            PenAction::RunCommand => {
+                let (program, args) = match binding.command.as_deref() {
+                    Some([program, args @ ..]) => (program.clone(), args.to_vec()),
+                    _ => return Err("Pen binding RunCommand without command".into()),
+                };
+                tauri::async_runtime::spawn(async move {
+                    log_error!(
+                        get_app_handle()
+                            .shell()
+                            .command(program)
+                            .args(args)
+                            .status()
+                            .await
+                    );
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "system" fn keyboard_hook_proc(
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION as i32 && !SecureDesktop::is_active() {
+        let data = &*(l_param.0 as *const KBDLLHOOKSTRUCT);
+        let key = VIRTUAL_KEY(data.vkCode as u16);
+        let msg = w_param.0 as u32;
+        let win_pressed = Keyboard::is_pressed(VK_LWIN) || Keyboard::is_pressed(VK_RWIN);
+
+        if (msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN) && win_pressed {
+            if let Some(binding) = PenButtonHandler::binding_for(key) {
+                // the hook must return fast, the action runs outside of it
+                std::thread::spawn(move || log_error!(PenButtonHandler::run(&binding)));
+                return LRESULT(1);
+            }
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
@@ -0,0 +1,410 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Scope {
+    /// read settings, windows, metrics and any other state
+    ReadState,
+    /// focus, move, hide or close windows, workspaces and the dock/toolbar
+    ControlWindows,
+    /// launch programs, send input, change settings and power actions
+    RunCommands,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 3] = [Scope::ReadState, Scope::ControlWindows, Scope::RunCommands];
+
+    /// same as serialized
+    pub fn id(&self) -> &'static str {
+        match self {
+            Scope::ReadState => "read-state",
+            Scope::ControlWindows => "control-windows",
+            Scope::RunCommands => "run-commands",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Scope::ReadState => "read the state of your desktop",
+            Scope::ControlWindows => "control your windows and workspaces",
+            Scope::RunCommands => "run programs and commands",
+        }
+    }
+
+    /// Scope needed by an ipc command, `None` if it can only be used by the windows of Seelen
+    pub fn of_invoke(command: &str) -> Option<Scope> {
+        INVOKE_SCOPES
+            .iter()
+            .find(|(name, _)| *name == command)
+            .map(|(_, scope)| *scope)
+    }
+
+    /// Scope needed by a cli call, by the name of the subcommand as parsed by clap
+    pub fn of_cli(subcommand: Option<&str>) -> Scope {
+        match subcommand {
+            Some("status" | "config") => Scope::ReadState,
+            Some(
+                "manager" | "weg" | "toolbar" | "overview" | "window" | "zones" | "cursor" | "undo"
+                | "recently-closed" | "task-manager",
+            ) => Scope::ControlWindows,
+            _ => Scope::RunCommands,
+        }
+    }
+}
+
+/// Ipc commands available for third party webviews and the scope they need. Commands not
+/// listed here can only be used by the windows of Seelen, like the ones changing the settings,
+/// the permissions or reading and writing any file of the user.
+const INVOKE_SCOPES: &[(&str, Scope)] = &[
+    // General
+    ("run", Scope::RunCommands),
+    ("is_dev_mode", Scope::ReadState),
+    ("open_file", Scope::RunCommands),
+    ("run_as_admin", Scope::RunCommands),
+    ("select_file_on_explorer", Scope::RunCommands),
+    ("is_virtual_desktop_supported", Scope::ReadState),
+    ("show_app_settings", Scope::RunCommands),
+    ("switch_workspace", Scope::ControlWindows),
+    ("get_active_workspace", Scope::ReadState),
+    ("get_workspace_accent", Scope::ReadState),
+    ("ensure_hitboxes_zorder", Scope::RunCommands),
+    ("send_keys", Scope::RunCommands),
+    ("get_icon", Scope::ReadState),
+    ("get_app_display_name", Scope::ReadState),
+    ("get_data_dir", Scope::ReadState),
+    // Seelen Settings
+    ("set_auto_start", Scope::RunCommands),
+    ("get_auto_start_status", Scope::ReadState),
+    ("state_get_themes", Scope::ReadState),
+    ("state_get_placeholders", Scope::ReadState),
+    ("state_get_layouts", Scope::ReadState),
+    ("state_get_weg_items", Scope::ReadState),
+    ("state_get_settings", Scope::ReadState),
+    ("state_validate_config", Scope::RunCommands),
+    ("state_get_specific_apps_configurations", Scope::ReadState),
+    ("match_window_rules", Scope::RunCommands),
+    ("state_get_wallpaper", Scope::ReadState),
+    // Media
+    ("media_prev", Scope::RunCommands),
+    ("media_toggle_play_pause", Scope::RunCommands),
+    ("media_next", Scope::RunCommands),
+    ("set_volume_level", Scope::RunCommands),
+    ("media_toggle_mute", Scope::RunCommands),
+    ("media_set_default_device", Scope::RunCommands),
+    // Brightness
+    ("get_main_monitor_brightness", Scope::ReadState),
+    ("set_main_monitor_brightness", Scope::RunCommands),
+    ("brightness_get_state", Scope::ReadState),
+    ("brightness_set", Scope::RunCommands),
+    ("brightness_set_linked", Scope::RunCommands),
+    // Power
+    ("get_battery_health", Scope::ReadState),
+    ("get_charge_limit", Scope::ReadState),
+    ("set_charge_limit", Scope::RunCommands),
+    ("log_out", Scope::RunCommands),
+    ("suspend", Scope::RunCommands),
+    ("restart", Scope::RunCommands),
+    ("shutdown", Scope::RunCommands),
+    // SeelenWeg
+    ("weg_close_app", Scope::ControlWindows),
+    ("weg_close_windows", Scope::ControlWindows),
+    ("weg_get_hung_windows", Scope::ReadState),
+    ("weg_get_suspended_windows", Scope::ReadState),
+    ("weg_get_background_apps", Scope::ReadState),
+    ("weg_kill_app", Scope::RunCommands),
+    ("weg_get_overlay_icons", Scope::ReadState),
+    ("weg_get_folder_badges", Scope::ReadState),
+    ("weg_search_windows", Scope::ControlWindows),
+    ("weg_scrub_thumbnails", Scope::ControlWindows),
+    ("weg_scrub_end", Scope::ControlWindows),
+    ("weg_tab_thumbnails", Scope::ControlWindows),
+    ("weg_tab_thumbnails_end", Scope::ControlWindows),
+    ("weg_get_window_verdict", Scope::ReadState),
+    ("weg_set_force_show", Scope::ControlWindows),
+    ("weg_toggle_window_state", Scope::RunCommands),
+    ("weg_set_run_as_admin", Scope::RunCommands),
+    ("weg_open_app_settings", Scope::ControlWindows),
+    ("weg_uninstall_app", Scope::RunCommands),
+    ("weg_request_update_previews", Scope::ControlWindows),
+    ("weg_set_hitbox", Scope::ControlWindows),
+    ("weg_hitbox_enter", Scope::ControlWindows),
+    ("weg_hitbox_leave", Scope::ControlWindows),
+    ("weg_drag_over", Scope::ControlWindows),
+    ("weg_drag_leave", Scope::ControlWindows),
+    ("weg_activate_tab", Scope::ControlWindows),
+    // Windows Manager
+    ("set_window_position", Scope::ControlWindows),
+    ("bounce_handle", Scope::ControlWindows),
+    ("wm_float_unfittable", Scope::ControlWindows),
+    ("request_focus", Scope::ControlWindows),
+    ("wm_set_split", Scope::ControlWindows),
+    ("wm_set_stack_mode", Scope::ControlWindows),
+    ("wm_cycle_tab", Scope::ControlWindows),
+    ("wm_send_to_monitor", Scope::ControlWindows),
+    ("wm_send_workspace_to_monitor", Scope::ControlWindows),
+    // tray icons
+    ("temp_get_by_event_tray_info", Scope::ReadState),
+    ("on_click_tray_icon", Scope::RunCommands),
+    ("on_context_menu_tray_icon", Scope::RunCommands),
+    // network
+    ("wlan_get_profiles", Scope::ReadState),
+    ("wlan_start_scanning", Scope::RunCommands),
+    ("wlan_stop_scanning", Scope::RunCommands),
+    ("wlan_connect", Scope::RunCommands),
+    ("wlan_disconnect", Scope::RunCommands),
+    ("vpn_get_profiles", Scope::ReadState),
+    ("vpn_connect", Scope::RunCommands),
+    ("vpn_disconnect", Scope::RunCommands),
+    // notifications
+    ("notifications_close", Scope::RunCommands),
+    ("notifications_close_all", Scope::RunCommands),
+    // overview
+    ("overview_focus", Scope::ControlWindows),
+    ("overview_close", Scope::ControlWindows),
+    // window controls
+    ("window_set_topmost", Scope::ControlWindows),
+    ("window_toggle_topmost", Scope::ControlWindows),
+    ("window_set_opacity", Scope::ControlWindows),
+    ("window_toggle_shade", Scope::ControlWindows),
+    ("window_pin_to_all_desktops", Scope::ControlWindows),
+    ("window_get_pinned_to_all_desktops", Scope::ReadState),
+    // zones
+    ("snap_to_zone", Scope::ControlWindows),
+    ("zones_set_monitor_layout", Scope::RunCommands),
+    ("zones_preview_layout", Scope::RunCommands),
+    ("zones_close_preview", Scope::RunCommands),
+    // startup apps
+    ("startup_get_apps", Scope::ReadState),
+    ("startup_set_enabled", Scope::RunCommands),
+    ("startup_add", Scope::RunCommands),
+    ("startup_remove", Scope::RunCommands),
+    // file and protocol associations
+    ("associations_get_default", Scope::ReadState),
+    ("associations_change_default", Scope::RunCommands),
+    ("associations_open_settings", Scope::RunCommands),
+    ("get_open_with_candidates", Scope::ReadState),
+    ("open_with", Scope::RunCommands),
+    // quick access
+    ("quick_access_get_folders", Scope::ReadState),
+    ("quick_access_get_recent_files", Scope::ReadState),
+    // launcher
+    ("launcher_search", Scope::ReadState),
+    ("launcher_refresh", Scope::RunCommands),
+    ("launcher_execute", Scope::RunCommands),
+    // emoji picker
+    ("emoji_picker_search", Scope::ReadState),
+    ("emoji_picker_select", Scope::RunCommands),
+    ("emoji_picker_close", Scope::RunCommands),
+    // keep awake
+    ("keep_awake_get_state", Scope::ReadState),
+    ("keep_awake_enable", Scope::RunCommands),
+    ("keep_awake_disable", Scope::RunCommands),
+    ("keep_awake_toggle", Scope::RunCommands),
+    // devices
+    ("get_removable_drives", Scope::ReadState),
+    ("eject_drive", Scope::RunCommands),
+    // storage
+    ("get_storage_usage", Scope::ReadState),
+    ("open_drive", Scope::RunCommands),
+    ("open_storage_settings", Scope::RunCommands),
+    // gpu
+    ("get_gpu_usage", Scope::ReadState),
+    // printing
+    ("get_printers", Scope::ReadState),
+    ("set_default_printer", Scope::RunCommands),
+    ("get_print_jobs", Scope::ReadState),
+    ("pause_print_job", Scope::RunCommands),
+    ("resume_print_job", Scope::RunCommands),
+    ("cancel_print_job", Scope::RunCommands),
+    ("set_printer_paused", Scope::RunCommands),
+    // undo
+    ("undo_last_action", Scope::ControlWindows),
+    // recently closed
+    ("reopen_last_closed", Scope::ControlWindows),
+    ("reopen_closed", Scope::ControlWindows),
+    ("get_recently_closed", Scope::ReadState),
+    // cursor
+    ("cursor_is_locked", Scope::ReadState),
+    ("cursor_toggle_lock", Scope::ControlWindows),
+    ("cursor_find", Scope::ControlWindows),
+    // window inspector
+    ("inspector_start_picking", Scope::RunCommands),
+    ("inspector_stop_picking", Scope::RunCommands),
+    // event recorder
+    ("event_recorder_start", Scope::RunCommands),
+    ("event_recorder_stop", Scope::RunCommands),
+    ("event_recorder_is_recording", Scope::ReadState),
+    ("event_recorder_dump", Scope::RunCommands),
+    // shell
+    ("shell_is_registered", Scope::ReadState),
+    ("shell_is_active", Scope::ReadState),
+    ("shell_set_registered", Scope::RunCommands),
+    ("shell_launch_explorer", Scope::RunCommands),
+    // desktop icons
+    ("desktop_icons_get_items", Scope::ReadState),
+    ("desktop_icons_set_position", Scope::RunCommands),
+    ("desktop_icons_open", Scope::RunCommands),
+    // file operations
+    ("file_operations_get", Scope::ReadState),
+    ("file_operation_start", Scope::RunCommands),
+    ("file_operation_cancel", Scope::RunCommands),
+    // touch keyboard
+    ("touch_keyboard_show", Scope::RunCommands),
+    ("touch_keyboard_hide", Scope::RunCommands),
+    ("touch_keyboard_toggle", Scope::RunCommands),
+    ("touch_keyboard_is_visible", Scope::ReadState),
+    // posture
+    ("get_posture", Scope::ReadState),
+    // color scheme
+    ("get_color_scheme", Scope::ReadState),
+    // lock screen
+    ("lock_screen", Scope::RunCommands),
+    // do not disturb
+    ("do_not_disturb_is_active", Scope::ReadState),
+    // notes and reminders
+    ("notes_get", Scope::ReadState),
+    ("notes_add", Scope::RunCommands),
+    ("notes_update", Scope::RunCommands),
+    ("notes_delete", Scope::RunCommands),
+    ("reminders_add", Scope::RunCommands),
+    ("reminders_update", Scope::RunCommands),
+    ("reminders_delete", Scope::RunCommands),
+    // game mode
+    ("game_mode_is_active", Scope::ReadState),
+    // data sources
+    ("data_sources_get", Scope::ReadState),
+    ("data_source_action", Scope::RunCommands),
+    // process controls
+    ("get_process_rule", Scope::ReadState),
+    ("set_process_priority", Scope::RunCommands),
+    ("set_process_affinity", Scope::RunCommands),
+    // task manager
+    ("task_manager_show", Scope::ControlWindows),
+    ("task_manager_list", Scope::ControlWindows),
+    ("task_manager_end_task", Scope::RunCommands),
+    ("task_manager_activate", Scope::ControlWindows),
+    ("task_manager_open_system", Scope::ControlWindows),
+    ("task_manager_close", Scope::ControlWindows),
+    // shell context menu
+    ("open_shell_context_menu", Scope::RunCommands),
+    // focus requests
+    ("get_focus_request_stats", Scope::ReadState),
+    // action history
+    ("get_action_history", Scope::ReadState),
+    // onboarding
+    ("onboarding_get", Scope::ReadState),
+    ("onboarding_complete", Scope::RunCommands),
+    // conflicting software
+    ("get_conflicts", Scope::ReadState),
+    ("conflicts_rescan", Scope::RunCommands),
+    // os capabilities
+    ("get_os_capabilities", Scope::ReadState),
+    // cache
+    ("cache_get_stats", Scope::ReadState),
+    ("cache_clear", Scope::RunCommands),
+    // color picker
+    ("color_picker_show", Scope::RunCommands),
+    ("color_picker_pick", Scope::RunCommands),
+    ("color_picker_close", Scope::RunCommands),
+    ("color_picker_history", Scope::ReadState),
+    // screen ruler
+    ("screen_ruler_toggle", Scope::RunCommands),
+    ("screen_ruler_is_open", Scope::ReadState),
+    // app usage
+    ("app_usage_get", Scope::ReadState),
+    ("app_usage_clear", Scope::RunCommands),
+    // focus sessions
+    ("focus_sessions_is_active", Scope::ReadState),
+    // display color
+    ("monitors_get_display_colors", Scope::ReadState),
+    ("monitors_set_hdr", Scope::RunCommands),
+    ("monitors_set_color_profile", Scope::RunCommands),
+    // audio profiles
+    ("audio_profiles_apply", Scope::RunCommands),
+    ("audio_profiles_get_active", Scope::ReadState),
+    ("audio_profiles_get_devices", Scope::ReadState),
+    // privacy
+    ("privacy_get_state", Scope::ReadState),
+    ("privacy_toggle_microphone_mute", Scope::RunCommands),
+    // modes
+    ("mode_manager_get_active", Scope::ReadState),
+    ("mode_manager_enter", Scope::RunCommands),
+    ("mode_manager_exit", Scope::RunCommands),
+    ("mode_manager_toggle", Scope::RunCommands),
+    // alerts
+    ("alerts_get_active", Scope::ReadState),
+    ("alerts_snooze", Scope::RunCommands),
+    ("alerts_acknowledge", Scope::RunCommands),
+];
+
+/// Arguments of ipc commands that third party webviews can't send, as they would go beyond
+/// the scope of the command, e.g. reading any file of the user with `state_get_settings`.
+const DENIED_ARGUMENTS: [(&str, &str); 1] = [("state_get_settings", "path")];
+
+/// Name of the first denied argument present on the args of the ipc command
+pub fn denied_argument(command: &str, args: &serde_json::Value) -> Option<&'static str> {
+    DENIED_ARGUMENTS
+        .iter()
+        .find(|(denied_command, arg)| {
+            *denied_command == command && args.get(arg).is_some_and(|value| !value.is_null())
+        })
+        .map(|(_, arg)| *arg)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "id")]
+pub enum ClientId {
+    /// label of a webview not created by Seelen
+    Webview(String),
+    /// executable that called the cli, usually a script host
+    Cli(String),
+    /// name of a remote api token
+    Token(String),
+}
+
+impl Display for ClientId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientId::Webview(label) => write!(f, "The widget \"{}\"", label),
+            ClientId::Cli(exe) => write!(f, "{}", exe),
+            ClientId::Token(name) => write!(f, "The remote api token \"{}\"", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Grant {
+    pub client: ClientId,
+    pub allowed: Vec<Scope>,
+    /// scopes refused on the consent prompt, they are not asked again
+    pub denied: Vec<Scope>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiToken {
+    pub name: String,
+    pub token: String,
+    pub scopes: Vec<Scope>,
+}
+
+/// Compares all the bytes even after a mismatch, so the time taken does not reveal how
+/// much of a guessed token is right
+pub fn tokens_equal(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y));
+    std::hint::black_box(diff) == 0
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PermissionsStore {
+    pub grants: Vec<Grant>,
+    pub tokens: Vec<ApiToken>,
+}
@@ -0,0 +1,26 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{ClientId, PermissionsStore, Scope},
+    Permissions,
+};
+
+#[tauri::command(async)]
+pub fn permissions_get() -> PermissionsStore {
+    Permissions::get()
+}
+
+#[tauri::command(async)]
+pub fn permissions_set_scopes(client: ClientId, scopes: Vec<Scope>) -> Result<()> {
+    Permissions::set_scopes(client, scopes)
+}
+
+#[tauri::command(async)]
+pub fn permissions_revoke(client: ClientId) -> Result<()> {
+    Permissions::revoke(client)
+}
+
+#[tauri::command(async)]
+pub fn permissions_create_token(name: String, scopes: Vec<Scope>) -> Result<String> {
+    Permissions::create_token(name, scopes)
+}
@@ -0,0 +1,331 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{net::TcpStream, path::PathBuf, sync::Arc};
+
+use domain::{denied_argument, tokens_equal, ApiToken, ClientId, Grant, PermissionsStore, Scope};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use sysinfo::{Pid, System};
+use tauri::{
+    ipc::{Invoke, InvokeBody},
+    Emitter, Wry,
+};
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::{
+        Foundation::HWND,
+        UI::WindowsAndMessaging::{
+            MessageBoxW, IDYES, MB_ICONQUESTION, MB_SETFOREGROUND, MB_TOPMOST, MB_YESNO,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{action_history::ActionHistory, metrics::network::tcp_port_owner},
+    seelen::get_app_handle,
+    trace_lock,
+    utils::app_data_path,
+};
+
+lazy_static! {
+    static ref STORE: Arc<Mutex<PermissionsStore>> = Arc::new(Mutex::new(Permissions::load()));
+    /// only one consent prompt is shown at a time
+    static ref PROMPT: Arc<Mutex<()>> = Arc::new(Mutex::new(()));
+}
+
+/// Labels (before the monitor postfix) of the webviews created by Seelen, these are trusted.
+const FIRST_PARTY_WEBVIEWS: [&str; 18] = [
+    "settings",
+    "updater",
+    "fancy-toolbar",
+    "window-manager",
+    "seelenweg",
+    "seelenweg-hitbox",
+    "overview",
+    "lock-screen",
+    "task-manager",
+    "osd",
+    "emoji-picker",
+    "color-picker",
+    "screen-ruler",
+    "zone-picker",
+    "zone-preview",
+    "desktop-icons",
+    "cursor-sonar",
+    "resize-mode-hint",
+];
+
+pub type InvokeHandler = Arc<dyn Fn(Invoke<Wry>) -> bool + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Allowed,
+    Denied,
+    /// the user has not been asked yet
+    Ask,
+}
+
+/// Scopes granted to the clients that are not part of Seelen: third party webviews, scripts
+/// calling the cli and remote api tokens. Unknown clients are asked for consent on their first
+/// use of each scope, the answers are saved on `permissions.json` of the user data.
+pub struct Permissions;
+
+impl Permissions {
+    const FILENAME: &'static str = "permissions.json";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn load() -> PermissionsStore {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Applies the change, saves the store and notifies the settings
+    fn modify<T>(f: impl FnOnce(&mut PermissionsStore) -> Result<T>) -> Result<T> {
+        let mut store = trace_lock!(STORE);
+        let result = f(&mut store)?;
+        std::fs::write(Self::path(), serde_json::to_string_pretty(&*store)?)?;
+        get_app_handle().emit("permissions-changed", &*store)?;
+        Ok(result)
+    }
+
+    pub fn get() -> PermissionsStore {
+        trace_lock!(STORE).clone()
+    }
+
+    pub fn is_first_party_webview(label: &str) -> bool {
+        let root = label.split('/').next().unwrap_or(label);
+        FIRST_PARTY_WEBVIEWS.contains(&root)
+    }
+
+    pub fn check(client: &ClientId, scope: Scope) -> Decision {
+        let store = trace_lock!(STORE);
+        if let ClientId::Token(name) = client {
+            return match store
+                .tokens
+                .iter()
+                .any(|t| &t.name == name && t.scopes.contains(&scope))
+            {
+                true => Decision::Allowed,
+                false => Decision::Denied,
+            };
+        }
+        match store.grants.iter().find(|grant| &grant.client == client) {
+            Some(grant) if grant.allowed.contains(&scope) => Decision::Allowed,
+            Some(grant) if grant.denied.contains(&scope) => Decision::Denied,
+            _ => Decision::Ask,
+        }
+    }
+
+    /// Checks the scope asking the user for consent if needed, blocks while the prompt is open.
+    pub fn request(client: &ClientId, scope: Scope) -> bool {
+        match Self::check(client, scope) {
+            Decision::Allowed => return true,
+            Decision::Denied => return false,
+            Decision::Ask => {}
+        }
+
+        let _prompt = trace_lock!(PROMPT);
+        // the same client could have been answered while waiting for the prompt
+        match Self::check(client, scope) {
+            Decision::Allowed => return true,
+            Decision::Denied => return false,
+            Decision::Ask => {}
+        }
+
+        let allowed = Self::prompt(client, scope);
+        log_error!(Self::modify(|store| {
+            let index = match store
+                .grants
+                .iter()
+                .position(|grant| &grant.client == client)
+            {
+                Some(index) => index,
+                None => {
+                    store.grants.push(Grant {
+                        client: client.clone(),
+                        allowed: Vec::new(),
+                        denied: Vec::new(),
+                    });
+                    store.grants.len() - 1
+                }
+            };
+            let grant = &mut store.grants[index];
+            match allowed {
+                true => grant.allowed.push(scope),
+                false => grant.denied.push(scope),
+            }
+            Ok(())
+        }));
+        allowed
+    }
+
+    fn prompt(client: &ClientId, scope: Scope) -> bool {
+        log::info!("Asking consent for {} to {}", client, scope.description());
+        let text = HSTRING::from(format!(
+            "{} wants to {}.\n\nAllow it? This can be changed later on the developer settings.",
+            client,
+            scope.description()
+        ));
+        let answer = unsafe {
+            MessageBoxW(
+                HWND(0),
+                PCWSTR(text.as_ptr()),
+                windows::core::w!("Seelen UI - Permission request"),
+                MB_YESNO | MB_ICONQUESTION | MB_SETFOREGROUND | MB_TOPMOST,
+            )
+        };
+        answer == IDYES
+    }
+
+    /// Ipc dispatch layer, commands of third party webviews are only run if their scope is granted.
+    /// The consent prompt is shown out of the ipc thread, the command is resolved after it.
+    pub fn dispatch_invoke(invoke: Invoke<Wry>, handler: &InvokeHandler) -> bool {
+        let label = invoke.message.webview().label().to_string();
+        if Self::is_first_party_webview(&label) {
+            ActionHistory::record_invoke(&invoke.message);
+            return handler(invoke);
+        }
+
+        let command = invoke.message.command().to_string();
+        let client = ClientId::Webview(label);
+        let Some(scope) = Scope::of_invoke(&command) else {
+            invoke
+                .resolver
+                .reject(format!("{} is not available for widgets", command));
+            return true;
+        };
+        if let InvokeBody::Json(args) = invoke.message.payload() {
+            if let Some(arg) = denied_argument(&command, args) {
+                invoke.resolver.reject(format!(
+                    "the {} argument of {} is not available for widgets",
+                    arg, command
+                ));
+                return true;
+            }
+        }
+
+        match Self::check(&client, scope) {
+            Decision::Allowed => {
+                ActionHistory::record_invoke(&invoke.message);
+                handler(invoke)
+            }
+            Decision::Denied => {
+                invoke
+                    .resolver
+                    .reject(format!("{} has not the {} scope", client, scope.id()));
+                true
+            }
+            Decision::Ask => {
+                let handler = handler.clone();
+                std::thread::spawn(move || {
+                    if Self::request(&client, scope) {
+                        ActionHistory::record_invoke(&invoke.message);
+                        handler(invoke);
+                    } else {
+                        let error = format!("{} has not the {} scope", client, scope.id());
+                        invoke.resolver.reject(error);
+                    }
+                });
+                true
+            }
+        }
+    }
+
+    /// Identifies the process that connected to the cli socket. Calls made through the Seelen
+    /// executable are identified by its parent, usually the script host that ran the command.
+    pub fn cli_client(stream: &TcpStream) -> Option<ClientId> {
+        let port = stream.peer_addr().ok()?.port();
+        let pid = Pid::from_u32(tcp_port_owner(port)?);
+
+        let mut sys = System::new();
+        sys.refresh_process(pid);
+        let process = sys.process(pid)?;
+        let mut exe = process.exe()?.to_path_buf();
+
+        if std::env::current_exe().is_ok_and(|current| current == exe) {
+            let parent = process.parent()?;
+            sys.refresh_process(parent);
+            exe = sys.process(parent)?.exe()?.to_path_buf();
+        }
+        Some(ClientId::Cli(exe.to_string_lossy().to_string()))
+    }
+
+    /// Seelen itself and the explorer (start menu, jump lists and file associations) act on
+    /// behalf of the user so they are not asked. The full path is compared, as any program can
+    /// be named `explorer.exe` or be placed on the installation folder.
+    pub fn is_trusted_cli(client: &ClientId) -> bool {
+        let ClientId::Cli(exe) = client else {
+            return false;
+        };
+        let is_same = |path: PathBuf| path.to_string_lossy().eq_ignore_ascii_case(exe);
+        let is_explorer = std::env::var("WINDIR")
+            .is_ok_and(|windir| is_same(PathBuf::from(windir).join("explorer.exe")));
+        let is_seelen = std::env::current_exe().is_ok_and(is_same);
+        is_explorer || is_seelen
+    }
+
+    /// Scopes of a remote api token created on the permissions, `None` if the token is unknown
+    pub fn token_scopes(token: &str) -> Option<Vec<Scope>> {
+        trace_lock!(STORE)
+            .tokens
+            .iter()
+            .find(|t| tokens_equal(&t.token, token))
+            .map(|t| t.scopes.clone())
+    }
+
+    pub fn set_scopes(client: ClientId, allowed: Vec<Scope>) -> Result<()> {
+        Self::modify(|store| {
+            if let ClientId::Token(name) = &client {
+                let token = store.tokens.iter_mut().find(|t| &t.name == name);
+                token.ok_or("Token not found")?.scopes = allowed;
+                return Ok(());
+            }
+            let denied = Scope::ALL
+                .into_iter()
+                .filter(|scope| !allowed.contains(scope))
+                .collect();
+            store.grants.retain(|grant| grant.client != client);
+            store.grants.push(Grant {
+                client,
+                allowed,
+                denied,
+            });
+            Ok(())
+        })
+    }
+
+    /// Forgets the answers of the client, it will be asked again on its next use
+    pub fn revoke(client: ClientId) -> Result<()> {
+        Self::modify(|store| {
+            match &client {
+                ClientId::Token(name) => store.tokens.retain(|t| &t.name != name),
+                _ => store.grants.retain(|grant| grant.client != client),
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the generated token
+    pub fn create_token(name: String, scopes: Vec<Scope>) -> Result<String> {
+        Self::modify(|store| {
+            if name.trim().is_empty() || store.tokens.iter().any(|t| t.name == name) {
+                return Err("The token name is empty or already used".into());
+            }
+            let token = uuid::Uuid::new_v4().simple().to_string();
+            store.tokens.push(ApiToken {
+                name,
+                token: token.clone(),
+                scopes,
+            });
+            Ok(token)
+        })
+    }
+}
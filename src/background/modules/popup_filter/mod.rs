@@ -0,0 +1,207 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::PopupFilterSettings;
+use windows::Win32::{
+    Foundation::HWND,
+    UI::WindowsAndMessaging::{WS_CAPTION, WS_POPUP},
+};
+
+use crate::{host::with_host, trace_lock, windows_api::window::Window, winevent::WinEvent};
+
+/// transient popups needed in the window to flag their app
+const TRANSIENT_COUNT: usize = 3;
+const TRANSIENT_WINDOW: Duration = Duration::from_secs(30);
+/// how long the app keeps flagged after its last transient popup
+const FLAGGED_FOR: Duration = Duration::from_secs(300);
+
+lazy_static! {
+    /// borderless popups currently shown, by handle
+    static ref SHOWN_AT: Mutex<HashMap<isize, (Instant, String)>> = Mutex::new(HashMap::new());
+    /// recent transient popups, by exe path
+    static ref TRANSIENTS: Mutex<HashMap<String, VecDeque<Instant>>> = Mutex::new(HashMap::new());
+}
+
+/// Keeps splash screens, game overlays, IME candidates and similar short lived windows out
+/// of the dock and the window manager. Apps are flagged after opening and closing several
+/// borderless popups in a short period, their next borderless popups are ignored.
+pub struct PopupFilter;
+
+impl PopupFilter {
+    fn is_borderless_popup(window: &Window) -> bool {
+        let styles = window.styles();
+        styles.contains(WS_POPUP) && !styles.contains(WS_CAPTION)
+    }
+
+    fn exe(window: &Window) -> Option<String> {
+        window
+            .exe()
+            .ok()
+            .map(|exe| exe.to_string_lossy().to_string())
+    }
+
+    /// case insensitive, `*` matches any amount of characters
+    fn wildcard_match(pattern: &str, text: &str) -> bool {
+        let pattern = pattern.to_lowercase();
+        let text = text.to_lowercase();
+        let parts: Vec<&str> = pattern.split('*').collect();
+        if parts.len() == 1 {
+            return pattern == text;
+        }
+
+        let (first, last) = (parts[0], parts[parts.len() - 1]);
+        if !text.starts_with(first) || !text[first.len()..].ends_with(last) {
+            return false;
+        }
+        let mut rest = &text[first.len()..text.len() - last.len()];
+        for part in &parts[1..parts.len() - 1] {
+            match rest.find(part) {
+                Some(idx) => rest = &rest[idx + part.len()..],
+                None => return false,
+            }
+        }
+        true
+    }
+
+    pub fn on_win_event(event: WinEvent, origin: HWND) {
+        let settings = with_host(|host| host.popup_filter());
+        Self::process_event(event, &Window::from(origin), &settings);
+    }
+
+    pub fn process_event(event: WinEvent, window: &Window, settings: &PopupFilterSettings) {
+        if !settings.enabled {
+            return;
+        }
+
+        match event {
+            WinEvent::ObjectShow => {
+                if Self::is_borderless_popup(window) {
+                    if let Some(exe) = Self::exe(window) {
+                        trace_lock!(SHOWN_AT).insert(window.hwnd().0, (Instant::now(), exe));
+                    }
+                }
+            }
+            WinEvent::ObjectHide | WinEvent::ObjectDestroy => {
+                let Some((shown_at, exe)) = trace_lock!(SHOWN_AT).remove(&window.hwnd().0) else {
+                    return;
+                };
+                if shown_at.elapsed() < Duration::from_millis(settings.short_lived_threshold) {
+                    let mut transients = trace_lock!(TRANSIENTS);
+                    let recent = transients.entry(exe).or_default();
+                    recent.push_back(Instant::now());
+                    while recent.len() > TRANSIENT_COUNT {
+                        recent.pop_front();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn has_ignored_class(window: &Window, settings: &PopupFilterSettings) -> bool {
+        if !settings.enabled {
+            return false;
+        }
+        let class = window.class();
+        settings
+            .ignored_classes
+            .iter()
+            .any(|pattern| Self::wildcard_match(pattern, &class))
+    }
+
+    pub fn is_zero_size(window: &Window, settings: &PopupFilterSettings) -> bool {
+        if !settings.enabled {
+            return false;
+        }
+        let rect = window.rect();
+        rect.right - rect.left <= 1 || rect.bottom - rect.top <= 1
+    }
+
+    pub fn is_transient_popup(window: &Window, settings: &PopupFilterSettings) -> bool {
+        if !settings.enabled || !Self::is_borderless_popup(window) {
+            return false;
+        }
+        let Some(exe) = Self::exe(window) else {
+            return false;
+        };
+
+        let mut transients = trace_lock!(TRANSIENTS);
+        transients.retain(|_, recent| recent.back().is_some_and(|t| t.elapsed() < FLAGGED_FOR));
+        transients.get(&exe).is_some_and(|recent| {
+            recent.len() >= TRANSIENT_COUNT
+                && recent[recent.len() - 1].duration_since(recent[0]) < TRANSIENT_WINDOW
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use windows::Win32::Foundation::RECT;
+
+    use super::*;
+    use crate::{
+        seelen_weg::rules::WegRule,
+        testing::{MockWindow, Simulation},
+    };
+
+    #[test]
+    fn wildcard_patterns() {
+        assert!(PopupFilter::wildcard_match(
+            "*SplashScreen*",
+            "GameSplashScreenWnd"
+        ));
+        assert!(PopupFilter::wildcard_match("ime", "IME"));
+        assert!(!PopupFilter::wildcard_match("IME", "IMEWnd"));
+    }
+
+    #[test]
+    fn transient_popups_are_flagged() {
+        let mut sim = Simulation::new();
+        let splash = || MockWindow::popup("C:\\Games\\launcher.exe").title("Loading");
+        for _ in 0..TRANSIENT_COUNT {
+            let hwnd = sim.open(splash());
+            sim.close(hwnd);
+        }
+
+        let hwnd = sim.open(splash());
+        assert_eq!(sim.verdict(hwnd).rejected_by, Some(WegRule::TransientPopup));
+        assert!(sim.dock().is_empty());
+
+        // windows with title bar of the same app are not affected
+        let main = sim.open(MockWindow::app("C:\\Games\\launcher.exe", "Launcher"));
+        assert!(sim.dock().contains(&main.0));
+    }
+
+    #[test]
+    fn long_lived_popups_are_not_flagged() {
+        let mut sim = Simulation::new();
+        sim.host
+            .settings
+            .borrow_mut()
+            .popup_filter
+            .short_lived_threshold = 0;
+        let popup = || MockWindow::popup("C:\\Apps\\widget.exe").title("Widget");
+        for _ in 0..TRANSIENT_COUNT {
+            let hwnd = sim.open(popup());
+            sim.close(hwnd);
+        }
+        let hwnd = sim.open(popup());
+        assert!(sim.dock().contains(&hwnd.0));
+    }
+
+    #[test]
+    fn ignored_classes_and_zero_size() {
+        let mut sim = Simulation::new();
+        let ime = sim.open(MockWindow::app("C:\\Apps\\ime.exe", "Candidates").class("IME"));
+        let empty = sim.open(MockWindow::app("C:\\Apps\\empty.exe", "Empty").rect(RECT::default()));
+        assert_eq!(sim.verdict(ime).rejected_by, Some(WegRule::IgnoredClass));
+        assert_eq!(sim.verdict(empty).rejected_by, Some(WegRule::ZeroSize));
+
+        sim.host.settings.borrow_mut().popup_filter.enabled = false;
+        assert!(sim.verdict(ime).accepted && sim.verdict(empty).accepted);
+    }
+}
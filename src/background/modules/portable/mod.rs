@@ -0,0 +1,64 @@
+use std::{path::PathBuf, sync::OnceLock};
+
+use clap::ArgMatches;
+
+use crate::error_handler::Result;
+
+/// Name of the marker file that enables portable mode when placed next to the executable
+pub const PORTABLE_MARKER: &str = "portable";
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Relocates everything that is usually stored on the user profile (settings, themes,
+/// icon cache, logs, etc) to a single folder, e.g. to run Seelen UI from a USB stick.
+///
+/// Enabled by `--data-dir <path>` or by a `portable` file next to `seelen-ui.exe`,
+/// in the latter case the data is stored on a `data` folder next to the executable.
+pub struct Portable;
+
+impl Portable {
+    /// Should be called before building the tauri app, so plugins also use the redirected paths
+    pub fn init(matches: &ArgMatches) -> Result<()> {
+        let dir = match matches.get_one::<PathBuf>("data-dir") {
+            Some(dir) => Some(dir.clone()),
+            None => Self::marker_data_dir(),
+        };
+
+        if let Some(dir) = dir {
+            let dir = if dir.is_relative() {
+                std::env::current_dir()?.join(dir)
+            } else {
+                dir
+            };
+            std::fs::create_dir_all(&dir)?;
+            let _ = DATA_DIR.set(dir);
+        }
+        Ok(())
+    }
+
+    fn marker_data_dir() -> Option<PathBuf> {
+        let exe = std::env::current_exe().ok()?;
+        let exe_dir = exe.parent()?;
+        if exe_dir.join(PORTABLE_MARKER).is_file() {
+            return Some(exe_dir.join("data"));
+        }
+        None
+    }
+
+    pub fn is_active() -> bool {
+        DATA_DIR.get().is_some()
+    }
+
+    /// Replaces the roaming and local app data folders
+    pub fn data_dir() -> Option<&'static PathBuf> {
+        DATA_DIR.get()
+    }
+
+    pub fn cache_dir() -> Option<PathBuf> {
+        Self::data_dir().map(|dir| dir.join("cache"))
+    }
+
+    pub fn log_dir() -> Option<PathBuf> {
+        Self::data_dir().map(|dir| dir.join("logs"))
+    }
+}
@@ -0,0 +1,6 @@
+use super::Posture;
+
+#[tauri::command(async)]
+pub fn get_posture() -> Posture {
+    Posture::current()
+}
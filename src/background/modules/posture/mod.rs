@@ -0,0 +1,142 @@
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+use serde::Serialize;
+use tauri::Emitter;
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            GetSystemMetrics, PostMessageW, PostQuitMessage, RegisterClassW, TranslateMessage, MSG,
+            SM_CONVERTIBLESLATEMODE, WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE, WM_DESTROY,
+            WM_SETTINGCHANGE, WNDCLASSW,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+/// hidden window receiving the setting changes, 0 if the watcher is not running
+static WATCHER_HWND: AtomicIsize = AtomicIsize::new(0);
+static IS_TABLET: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Posture {
+    Desktop,
+    Tablet,
+}
+
+impl Posture {
+    /// On 2-in-1 devices the slate mode is 0 while the keyboard is detached or folded back
+    pub fn current() -> Self {
+        match unsafe { GetSystemMetrics(SM_CONVERTIBLESLATEMODE) } {
+            0 => Posture::Tablet,
+            _ => Posture::Desktop,
+        }
+    }
+}
+
+/// Emits `posture-changed` when a convertible device switches between laptop and tablet.
+pub struct PostureWatcher;
+
+impl PostureWatcher {
+    fn is_slate_mode_change(l_param: LPARAM) -> bool {
+        if l_param.0 == 0 {
+            return false;
+        }
+        let area = unsafe { PCWSTR(l_param.0 as *const u16).to_string() };
+        area.is_ok_and(|area| area == "ConvertibleSlateMode")
+    }
+
+    fn notify() {
+        let posture = Posture::current();
+        let is_tablet = posture == Posture::Tablet;
+        if IS_TABLET.swap(is_tablet, Ordering::SeqCst) != is_tablet {
+            log::trace!("Posture changed to {:?}", posture);
+            log_error!(get_app_handle().emit("posture-changed", posture));
+        }
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_SETTINGCHANGE if Self::is_slate_mode_change(l_param) => {
+                Self::notify();
+                LRESULT(0)
+            }
+            WM_CLOSE => {
+                log_error!(DestroyWindow(hwnd));
+                LRESULT(0)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if WATCHER_HWND.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Starting posture watcher");
+        IS_TABLET.store(Posture::current() == Posture::Tablet, Ordering::SeqCst);
+
+        let class_name = w!("SeelenPostureWatcher");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        spawn_named_thread("Posture Watcher", move || unsafe {
+            // setting changes are only broadcasted to top-level windows
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+            WATCHER_HWND.store(hwnd.0, Ordering::Release);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            WATCHER_HWND.store(0, Ordering::Release);
+            log::trace!("Posture watcher stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let hwnd = WATCHER_HWND.load(Ordering::Acquire);
+        if hwnd != 0 {
+            log_error!(unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) });
+        }
+    }
+}
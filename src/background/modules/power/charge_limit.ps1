@@ -0,0 +1,58 @@
+# Reads or changes the battery charge limit on the vendors that expose it.
+# Usage: charge_limit.ps1 [get | set <percent>]
+# Writes `{ vendor, limit }` as json, or `null` if the device is not supported.
+param(
+  [string]$Action = "get",
+  [int]$Limit = 100
+)
+
+$ErrorActionPreference = "Stop"
+$manufacturer = (Get-ItemProperty "HKLM:\HARDWARE\DESCRIPTION\System\BIOS").SystemManufacturer
+
+function Write-Limit($vendor, $value) {
+  @{ vendor = $vendor; limit = $value } | ConvertTo-Json -Compress
+}
+
+# ASUS ATK ACPI device, same method used by MyASUS and G-Helper
+if ($manufacturer -match "ASUS") {
+  $BATTERY_CHARGE_LIMIT = [uint32]0x00120057
+  $atk = Get-CimInstance -Namespace "root/wmi" -ClassName "AsusAtkWmi_WMNB" -ErrorAction SilentlyContinue
+  if ($atk) {
+    if ($Action -eq "set") {
+      Invoke-CimMethod -InputObject $atk -MethodName DEVS -Arguments @{
+        Device_ID      = $BATTERY_CHARGE_LIMIT
+        Control_status = [uint32]$Limit
+      } | Out-Null
+    }
+    $status = (Invoke-CimMethod -InputObject $atk -MethodName DSTS -Arguments @{
+        Device_ID = $BATTERY_CHARGE_LIMIT
+      }).device_status
+    $current = $status -band 0xFF
+    if ($current -le 0 -or $current -gt 100) { $current = 100 }
+    Write-Limit "Asus" $current
+    exit 0
+  }
+}
+
+# Dell Command | Configure, custom charge stops at the limit and starts 5% below it
+if ($manufacturer -match "Dell") {
+  $cctk = Join-Path ${env:ProgramFiles(x86)} "Dell\Command Configure\X86_64\cctk.exe"
+  if (Test-Path $cctk) {
+    if ($Action -eq "set") {
+      if ($Limit -ge 100) {
+        & $cctk "--PrimaryBattChargeCfg=Standard" | Out-Null
+      }
+      else {
+        $stop = [Math]::Max($Limit, 55)
+        & $cctk "--PrimaryBattChargeCfg=Custom:$($stop - 5)-$stop" | Out-Null
+      }
+    }
+    $config = & $cctk "--PrimaryBattChargeCfg"
+    $current = 100
+    if ("$config" -match "Custom:\d+-(\d+)") { $current = [int]$Matches[1] }
+    Write-Limit "Dell" $current
+    exit 0
+  }
+}
+
+"null"
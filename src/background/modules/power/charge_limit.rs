@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error_handler::Result, utils::pwsh::PwshScript};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ChargeLimitVendor {
+    Asus,
+    Dell,
+}
+
+/// Max charge percent configured on the firmware, 100 if not limited
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChargeLimit {
+    vendor: ChargeLimitVendor,
+    limit: u8,
+}
+
+impl ChargeLimit {
+    /// limit applied by the toggle of the battery panel
+    pub const DEFAULT_LIMIT: u8 = 80;
+
+    async fn run(args: Vec<String>) -> Result<Option<Self>> {
+        let mut script = PwshScript::new(include_str!("charge_limit.ps1"));
+        script.with_args(args);
+        let output = script.execute().await?;
+        Ok(serde_json::from_str(&output)?)
+    }
+
+    /// `None` if the vendor of the device has no supported charge limit
+    pub async fn get() -> Result<Option<Self>> {
+        Self::run(vec!["get".to_string()]).await
+    }
+
+    pub async fn set(limit: u8) -> Result<Option<Self>> {
+        let limit = limit.clamp(50, 100);
+        Self::run(vec!["set".to_string(), limit.to_string()]).await
+    }
+}
@@ -0,0 +1,189 @@
+use std::ffi::c_void;
+
+use serde::Serialize;
+use windows::{
+    core::{GUID, PCWSTR},
+    Win32::{
+        Devices::DeviceAndDriverInstallation::{
+            SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInterfaces, SetupDiGetClassDevsW,
+            SetupDiGetDeviceInterfaceDetailW, DIGCF_DEVICEINTERFACE, DIGCF_PRESENT,
+            GUID_DEVCLASS_BATTERY, HDEVINFO, SP_DEVICE_INTERFACE_DATA,
+            SP_DEVICE_INTERFACE_DETAIL_DATA_W,
+        },
+        Foundation::{CloseHandle, GENERIC_READ, GENERIC_WRITE, HANDLE, HWND},
+        Storage::FileSystem::{
+            CreateFileW, FILE_ATTRIBUTE_NORMAL, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+        },
+        System::{
+            Power::{
+                BatteryInformation, BATTERY_INFORMATION, BATTERY_QUERY_INFORMATION,
+                IOCTL_BATTERY_QUERY_INFORMATION, IOCTL_BATTERY_QUERY_TAG,
+            },
+            IO::DeviceIoControl,
+        },
+    },
+};
+
+use crate::error_handler::Result;
+
+/// Capacity reported by the battery driver, wear is the lost capacity since it was new
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatteryHealth {
+    /// capacity when new, in mWh
+    designed_capacity: u32,
+    /// capacity of the current full charge, in mWh
+    full_charged_capacity: u32,
+    /// full charged capacity relative to the designed one, 0 to 100
+    health: f32,
+    /// 0 if the battery doesn't report it
+    cycle_count: u32,
+    /// chemistry code, e.g. `LION` or `LiP`
+    chemistry: String,
+}
+
+impl From<BATTERY_INFORMATION> for BatteryHealth {
+    fn from(info: BATTERY_INFORMATION) -> Self {
+        let health = if info.DesignedCapacity > 0 {
+            (info.FullChargedCapacity as f32 / info.DesignedCapacity as f32 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+        Self {
+            designed_capacity: info.DesignedCapacity,
+            full_charged_capacity: info.FullChargedCapacity,
+            health,
+            cycle_count: info.CycleCount,
+            chemistry: String::from_utf8_lossy(&info.Chemistry)
+                .trim_end_matches('\0')
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+/// Device info set destroyed on drop
+struct DeviceInfoSet(HDEVINFO);
+
+impl Drop for DeviceInfoSet {
+    fn drop(&mut self) {
+        let _ = unsafe { SetupDiDestroyDeviceInfoList(self.0) };
+    }
+}
+
+/// Battery device handle closed on drop
+struct BatteryDevice(HANDLE);
+
+impl BatteryDevice {
+    fn open(path: PCWSTR) -> Result<Self> {
+        let handle = unsafe {
+            CreateFileW(
+                path,
+                (GENERIC_READ | GENERIC_WRITE).0,
+                FILE_SHARE_READ | FILE_SHARE_WRITE,
+                None,
+                OPEN_EXISTING,
+                FILE_ATTRIBUTE_NORMAL,
+                None,
+            )?
+        };
+        Ok(Self(handle))
+    }
+
+    fn control<I, O: Default>(&self, code: u32, input: &I) -> Result<O> {
+        let mut output = O::default();
+        let mut returned = 0;
+        unsafe {
+            DeviceIoControl(
+                self.0,
+                code,
+                Some(input as *const I as *const c_void),
+                std::mem::size_of::<I>() as u32,
+                Some(&mut output as *mut O as *mut c_void),
+                std::mem::size_of::<O>() as u32,
+                Some(&mut returned),
+                None,
+            )?
+        };
+        Ok(output)
+    }
+
+    fn information(&self) -> Result<BATTERY_INFORMATION> {
+        // the tag identifies the battery currently inserted, 0 if there is none
+        let tag: u32 = self.control(IOCTL_BATTERY_QUERY_TAG, &0u32)?;
+        if tag == 0 {
+            return Err("No battery inserted".into());
+        }
+        let query = BATTERY_QUERY_INFORMATION {
+            BatteryTag: tag,
+            InformationLevel: BatteryInformation,
+            ..Default::default()
+        };
+        self.control(IOCTL_BATTERY_QUERY_INFORMATION, &query)
+    }
+}
+
+impl Drop for BatteryDevice {
+    fn drop(&mut self) {
+        let _ = unsafe { CloseHandle(self.0) };
+    }
+}
+
+/// Health of each battery of the system, queried directly to the battery class driver
+pub fn batteries_health() -> Result<Vec<BatteryHealth>> {
+    let guid: GUID = GUID_DEVCLASS_BATTERY;
+    let set = DeviceInfoSet(unsafe {
+        SetupDiGetClassDevsW(
+            Some(&guid),
+            PCWSTR::null(),
+            HWND(0),
+            DIGCF_PRESENT | DIGCF_DEVICEINTERFACE,
+        )?
+    });
+
+    let mut batteries = Vec::new();
+    let mut index = 0;
+    loop {
+        let mut interface = SP_DEVICE_INTERFACE_DATA {
+            cbSize: std::mem::size_of::<SP_DEVICE_INTERFACE_DATA>() as u32,
+            ..Default::default()
+        };
+        if unsafe { SetupDiEnumDeviceInterfaces(set.0, None, &guid, index, &mut interface) }
+            .is_err()
+        {
+            break;
+        }
+        index += 1;
+
+        let mut required = 0;
+        // fails with ERROR_INSUFFICIENT_BUFFER, only used to get the required size
+        let _ = unsafe {
+            SetupDiGetDeviceInterfaceDetailW(set.0, &interface, None, 0, Some(&mut required), None)
+        };
+        if required == 0 {
+            continue;
+        }
+
+        // u64 buffer to keep the alignment of the detail struct
+        let mut buffer = vec![0u64; (required as usize).div_ceil(8)];
+        let detail = buffer.as_mut_ptr() as *mut SP_DEVICE_INTERFACE_DETAIL_DATA_W;
+        unsafe {
+            (*detail).cbSize = std::mem::size_of::<SP_DEVICE_INTERFACE_DETAIL_DATA_W>() as u32;
+            SetupDiGetDeviceInterfaceDetailW(
+                set.0,
+                &interface,
+                Some(detail),
+                required,
+                None,
+                None,
+            )?;
+        }
+
+        let path = PCWSTR(unsafe { std::ptr::addr_of!((*detail).DevicePath) } as *const u16);
+        match BatteryDevice::open(path).and_then(|device| device.information()) {
+            Ok(info) => batteries.push(info.into()),
+            Err(err) => log::debug!("Skipping battery device: {:?}", err),
+        }
+    }
+    Ok(batteries)
+}
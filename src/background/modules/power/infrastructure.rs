@@ -15,8 +15,19 @@ use windows::{
 };
 
 use crate::{
-    error_handler::Result, log_error, modules::power::domain::Battery, seelen::get_app_handle,
-    utils::spawn_named_thread, windows_api::WindowsApi,
+    error_handler::Result,
+    log_error,
+    modules::{
+        game_mode::GameMode,
+        power::{
+            charge_limit::ChargeLimit,
+            domain::Battery,
+            health::{batteries_health, BatteryHealth},
+        },
+    },
+    seelen::get_app_handle,
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
 };
 
 use super::domain::PowerStatus;
@@ -98,7 +109,9 @@ impl PowerManager {
         // TODO search for a better way to do this, WM_POWERBROADCAST only register status events
         // like charging, discharging, battery low, etc.
         spawn_named_thread("Power Manager Loop", move || loop {
-            log_error!(PowerManager::emit_system_power_info());
+            if !GameMode::is_active() {
+                log_error!(PowerManager::emit_system_power_info());
+            }
             std::thread::sleep(std::time::Duration::from_secs(60));
         })?;
         Ok(())
@@ -122,6 +135,21 @@ impl PowerManager {
     }
 }
 
+#[tauri::command(async)]
+pub fn get_battery_health() -> Result<Vec<BatteryHealth>> {
+    batteries_health()
+}
+
+#[tauri::command(async)]
+pub async fn get_charge_limit() -> Result<Option<ChargeLimit>> {
+    ChargeLimit::get().await
+}
+
+#[tauri::command(async)]
+pub async fn set_charge_limit(limit: u8) -> Result<Option<ChargeLimit>> {
+    ChargeLimit::set(limit).await
+}
+
 #[tauri::command(async)]
 pub fn log_out() {
     log_error!(WindowsApi::exit_windows(EWX_LOGOFF, SHTDN_REASON_NONE));
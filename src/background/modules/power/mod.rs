@@ -1,2 +1,4 @@
+pub mod charge_limit;
 pub mod domain;
+pub mod health;
 pub mod infrastructure;
@@ -0,0 +1,259 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use rumqttc::{
+    Client, Connection, Event, LastWill, MqttOptions, NetworkOptions, Packet, QoS, Transport,
+};
+use serde::Serialize;
+use windows::Win32::UI::Shell::{
+    SHQueryUserNotificationState, QUNS_ACCEPTS_NOTIFICATIONS, QUNS_NOT_PRESENT,
+};
+
+use crate::{
+    error_handler::Result,
+    modules::{game_mode::GameMode, idle::IdleManager},
+    seelen::get_app_handle,
+    state::{application::FULL_STATE, domain::PresenceSettings},
+    utils::{app_data_path, sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+/// true while the publisher thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Active,
+    Idle,
+    /// session locked or screen saver running
+    Away,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Active => "active",
+            Status::Idle => "idle",
+            Status::Away => "away",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Presence {
+    status: Status,
+    /// executable of the focused window
+    app: Option<String>,
+    /// category of the focused app, as set on the app configs
+    category: Option<String>,
+    dnd: bool,
+}
+
+impl Presence {
+    fn current(settings: &PresenceSettings) -> Self {
+        let notifications = unsafe { SHQueryUserNotificationState() };
+        let status = if notifications.is_ok_and(|state| state == QUNS_NOT_PRESENT) {
+            Status::Away
+        } else if IdleManager::idle_seconds().is_ok_and(|secs| secs >= settings.idle_after) {
+            Status::Idle
+        } else {
+            Status::Active
+        };
+
+        let foreground = WindowsApi::get_foreground_window();
+        let category = FULL_STATE
+            .load()
+            .get_app_config_by_window(foreground)
+            .and_then(|config| config.category.clone());
+
+        // quiet hours, presentations and fullscreen apps don't accept notifications
+        let dnd = GameMode::is_active()
+            || notifications.is_ok_and(|state| {
+                state != QUNS_ACCEPTS_NOTIFICATIONS && state != QUNS_NOT_PRESENT
+            });
+
+        Self {
+            status,
+            app: WindowsApi::exe(foreground).ok(),
+            category,
+            dnd,
+        }
+    }
+}
+
+/// Publishes what the user is doing to a MQTT broker, for home automation (status lights,
+/// lighting scenes, etc). All the topics are retained, under the configured prefix:
+///
+/// - `status`: `online` or `offline`, the last one set as last will of the connection
+/// - `presence`: `active`, `idle` or `away`
+/// - `app`: executable of the focused window
+/// - `category`: category of the focused app, empty if none
+/// - `dnd`: `true` or `false`
+/// - `state`: all the above as json
+pub struct PresencePublisher;
+
+impl PresencePublisher {
+    const INTERVAL: u64 = 2000;
+    const RETRY_INTERVAL: u64 = 30_000;
+    const KEEP_ALIVE: Duration = Duration::from_secs(60);
+    /// max seconds to open the connection and to receive the acknowledgement of the broker
+    const CONNECT_TIMEOUT: u64 = 10;
+    /// kept out of the settings, so it is never shared with the webviews
+    const PASSWORD_FILENAME: &str = "presence.password";
+
+    fn password() -> Option<String> {
+        let path = app_data_path(&get_app_handle()).join(Self::PASSWORD_FILENAME);
+        let password = std::fs::read_to_string(path).ok()?;
+        let password = password.trim();
+        (!password.is_empty()).then(|| password.to_string())
+    }
+
+    /// Connects with a retained `offline` last will on `status_topic`,
+    /// published by the broker if the connection is lost.
+    fn connect(settings: &PresenceSettings, status_topic: &str) -> Result<(Client, Connection)> {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let mut options = MqttOptions::new(
+            format!("seelen-ui-{}", &id[..8]),
+            settings.host.as_str(),
+            settings.port,
+        );
+        options.set_keep_alive(Self::KEEP_ALIVE);
+        options.set_last_will(LastWill::new(
+            status_topic,
+            "offline",
+            QoS::AtLeastOnce,
+            true,
+        ));
+        if !settings.username.is_empty() {
+            options.set_credentials(
+                settings.username.as_str(),
+                Self::password().unwrap_or_default(),
+            );
+        }
+        if settings.tls {
+            options.set_transport(Transport::tls_with_default_config());
+        }
+
+        let (client, mut connection) = Client::new(options, 16);
+        let mut network = NetworkOptions::new();
+        network.set_connection_timeout(Self::CONNECT_TIMEOUT);
+        connection.eventloop.set_network_options(network);
+
+        let deadline = Instant::now() + Duration::from_secs(Self::CONNECT_TIMEOUT);
+        loop {
+            let remaining = deadline
+                .checked_duration_since(Instant::now())
+                .ok_or("timed out waiting for the broker")?;
+            match connection.recv_timeout(remaining) {
+                Ok(Ok(Event::Incoming(Packet::ConnAck(_)))) => return Ok((client, connection)),
+                Ok(Ok(_)) => {}
+                Ok(Err(err)) => return Err(format!("connection failed: {}", err).into()),
+                Err(_) => return Err("timed out waiting for the broker".into()),
+            }
+        }
+    }
+
+    /// Sends the queued messages and the pings until `duration` has passed
+    fn drive(connection: &mut Connection, duration: Duration) -> Result<()> {
+        let deadline = Instant::now() + duration;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            match connection.recv_timeout(remaining) {
+                Ok(Ok(_)) => {}
+                Ok(Err(err)) => return Err(format!("connection lost: {}", err).into()),
+                Err(_) => break,
+            }
+        }
+        Ok(())
+    }
+
+    fn send(client: &Client, topic: &str, payload: &str) -> Result<()> {
+        client
+            .try_publish(topic, QoS::AtLeastOnce, true, payload.as_bytes().to_vec())
+            .map_err(|err| format!("failed to publish {}: {}", topic, err).into())
+    }
+
+    /// false once stopped or reconfigured
+    fn should_continue(settings: &PresenceSettings) -> bool {
+        RUNNING.load(Ordering::Acquire) && FULL_STATE.load().settings().presence == *settings
+    }
+
+    fn publish(client: &Client, topic: &str, presence: &Presence) -> Result<()> {
+        Self::send(
+            client,
+            &format!("{topic}/presence"),
+            presence.status.as_str(),
+        )?;
+        let app = presence.app.as_deref().unwrap_or("");
+        Self::send(client, &format!("{topic}/app"), app)?;
+        let category = presence.category.as_deref().unwrap_or("");
+        Self::send(client, &format!("{topic}/category"), category)?;
+        Self::send(client, &format!("{topic}/dnd"), &presence.dnd.to_string())?;
+        Self::send(
+            client,
+            &format!("{topic}/state"),
+            &serde_json::to_string(presence)?,
+        )?;
+        Ok(())
+    }
+
+    /// Publishes the changes until the publisher is stopped or the connection settings change.
+    fn run(settings: &PresenceSettings) -> Result<()> {
+        let status_topic = format!("{}/status", settings.topic);
+        let (client, mut connection) = Self::connect(settings, &status_topic)?;
+        log::info!(
+            "Presence publisher connected to {}:{} (tls: {})",
+            settings.host,
+            settings.port,
+            settings.tls
+        );
+        Self::send(&client, &status_topic, "online")?;
+
+        let mut last: Option<Presence> = None;
+        while Self::should_continue(settings) {
+            let presence = Presence::current(settings);
+            if last.as_ref() != Some(&presence) {
+                Self::publish(&client, &settings.topic, &presence)?;
+                last = Some(presence);
+            }
+            Self::drive(&mut connection, Duration::from_millis(Self::INTERVAL))?;
+        }
+
+        Self::send(&client, &status_topic, "offline")?;
+        client
+            .try_disconnect()
+            .map_err(|err| format!("failed to disconnect: {}", err))?;
+        // flushes the offline status and the disconnect packet
+        Self::drive(&mut connection, Duration::from_secs(1))
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting presence publisher");
+
+        spawn_named_thread("Presence Publisher", || {
+            while RUNNING.load(Ordering::Acquire) {
+                let settings = FULL_STATE.load().settings().presence.clone();
+                if let Err(err) = Self::run(&settings) {
+                    log::warn!("Presence publisher disconnected: {:?}", err);
+                    let mut waited = 0;
+                    while waited < Self::RETRY_INTERVAL && Self::should_continue(&settings) {
+                        sleep_millis(Self::INTERVAL);
+                        waited += Self::INTERVAL;
+                    }
+                }
+            }
+            log::trace!("Presence publisher stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Printer {
+    pub name: String,
+    pub is_default: bool,
+    pub is_paused: bool,
+    /// offline, out of paper, paper jam, etc.
+    pub has_error: bool,
+    /// jobs currently in the queue
+    pub jobs: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJob {
+    pub id: u32,
+    pub printer: String,
+    pub document: String,
+    pub user: String,
+    pub is_paused: bool,
+    pub total_pages: u32,
+    pub pages_printed: u32,
+}
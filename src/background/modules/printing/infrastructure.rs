@@ -0,0 +1,41 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{PrintJob, Printer},
+    PrintManager,
+};
+
+#[tauri::command(async)]
+pub fn get_printers() -> Result<Vec<Printer>> {
+    PrintManager::printers()
+}
+
+#[tauri::command(async)]
+pub fn set_default_printer(name: String) -> Result<()> {
+    PrintManager::set_default_printer(&name)
+}
+
+#[tauri::command(async)]
+pub fn get_print_jobs(printer: String) -> Result<Vec<PrintJob>> {
+    PrintManager::jobs(&printer)
+}
+
+#[tauri::command(async)]
+pub fn pause_print_job(printer: String, job_id: u32) -> Result<()> {
+    PrintManager::pause_job(&printer, job_id)
+}
+
+#[tauri::command(async)]
+pub fn resume_print_job(printer: String, job_id: u32) -> Result<()> {
+    PrintManager::resume_job(&printer, job_id)
+}
+
+#[tauri::command(async)]
+pub fn cancel_print_job(printer: String, job_id: u32) -> Result<()> {
+    PrintManager::cancel_job(&printer, job_id)
+}
+
+#[tauri::command(async)]
+pub fn set_printer_paused(printer: String, paused: bool) -> Result<()> {
+    PrintManager::set_printer_paused(&printer, paused)
+}
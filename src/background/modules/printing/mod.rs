@@ -0,0 +1,218 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use domain::{PrintJob, Printer};
+use tauri::Emitter;
+use windows::{
+    core::{HSTRING, PCWSTR, PWSTR},
+    Win32::Graphics::Printing::{
+        ClosePrinter, EnumJobsW, EnumPrintersW, GetDefaultPrinterW, OpenPrinterW,
+        SetDefaultPrinterW, SetJobW, SetPrinterW, JOB_CONTROL_DELETE, JOB_CONTROL_PAUSE,
+        JOB_CONTROL_RESUME, JOB_INFO_1W, JOB_STATUS_PAUSED, PRINTER_CONTROL_PAUSE,
+        PRINTER_CONTROL_RESUME, PRINTER_ENUM_CONNECTIONS, PRINTER_ENUM_LOCAL, PRINTER_HANDLE,
+        PRINTER_INFO_2W, PRINTER_STATUS_ERROR, PRINTER_STATUS_OFFLINE, PRINTER_STATUS_PAPER_JAM,
+        PRINTER_STATUS_PAPER_OUT, PRINTER_STATUS_PAUSED,
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::game_mode::GameMode,
+    seelen::get_app_handle,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+
+const PRINTER_ERROR_MASK: u32 = PRINTER_STATUS_ERROR
+    | PRINTER_STATUS_OFFLINE
+    | PRINTER_STATUS_PAPER_JAM
+    | PRINTER_STATUS_PAPER_OUT;
+
+fn pwstr_to_string(value: PWSTR) -> String {
+    if value.is_null() {
+        return String::new();
+    }
+    unsafe { value.to_string() }.unwrap_or_default()
+}
+
+/// Printer handle closed on drop
+struct PrinterHandle(PRINTER_HANDLE);
+
+impl PrinterHandle {
+    fn open(name: &str) -> Result<Self> {
+        let mut handle = PRINTER_HANDLE::default();
+        unsafe { OpenPrinterW(&HSTRING::from(name), &mut handle, None)? };
+        Ok(Self(handle))
+    }
+}
+
+impl Drop for PrinterHandle {
+    fn drop(&mut self) {
+        let _ = unsafe { ClosePrinter(self.0) };
+    }
+}
+
+pub struct PrintManager;
+
+impl PrintManager {
+    pub fn default_printer() -> Option<String> {
+        let mut len = 0;
+        unsafe { GetDefaultPrinterW(PWSTR::null(), &mut len) };
+        if len == 0 {
+            return None;
+        }
+        let mut buffer = vec![0u16; len as usize];
+        if !unsafe { GetDefaultPrinterW(PWSTR(buffer.as_mut_ptr()), &mut len) }.as_bool() {
+            return None;
+        }
+        Some(String::from_utf16_lossy(&buffer[..len as usize - 1]))
+    }
+
+    pub fn set_default_printer(name: &str) -> Result<()> {
+        unsafe { SetDefaultPrinterW(&HSTRING::from(name)).ok()? };
+        Ok(())
+    }
+
+    pub fn printers() -> Result<Vec<Printer>> {
+        let flags = PRINTER_ENUM_LOCAL | PRINTER_ENUM_CONNECTIONS;
+        let mut needed = 0;
+        let mut count = 0;
+        // first call fails with ERROR_INSUFFICIENT_BUFFER and returns the needed size
+        let _ = unsafe { EnumPrintersW(flags, PCWSTR::null(), 2, None, &mut needed, &mut count) };
+        if needed == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut buffer = vec![0u8; needed as usize];
+        unsafe {
+            EnumPrintersW(
+                flags,
+                PCWSTR::null(),
+                2,
+                Some(&mut buffer),
+                &mut needed,
+                &mut count,
+            )?
+        };
+
+        let default = Self::default_printer();
+        let infos = unsafe {
+            std::slice::from_raw_parts(buffer.as_ptr() as *const PRINTER_INFO_2W, count as usize)
+        };
+        Ok(infos
+            .iter()
+            .map(|info| {
+                let name = pwstr_to_string(info.pPrinterName);
+                Printer {
+                    is_default: default.as_ref() == Some(&name),
+                    is_paused: info.Status & PRINTER_STATUS_PAUSED != 0,
+                    has_error: info.Status & PRINTER_ERROR_MASK != 0,
+                    jobs: info.cJobs,
+                    name,
+                }
+            })
+            .collect())
+    }
+
+    pub fn jobs(printer: &str) -> Result<Vec<PrintJob>> {
+        let handle = PrinterHandle::open(printer)?;
+        let mut needed = 0;
+        let mut count = 0;
+        let _ = unsafe { EnumJobsW(handle.0, 0, u32::MAX, 1, None, &mut needed, &mut count) };
+        if needed == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut buffer = vec![0u8; needed as usize];
+        unsafe {
+            EnumJobsW(
+                handle.0,
+                0,
+                u32::MAX,
+                1,
+                Some(&mut buffer),
+                &mut needed,
+                &mut count,
+            )?
+        };
+
+        let infos = unsafe {
+            std::slice::from_raw_parts(buffer.as_ptr() as *const JOB_INFO_1W, count as usize)
+        };
+        Ok(infos
+            .iter()
+            .map(|info| PrintJob {
+                id: info.JobId,
+                printer: printer.to_string(),
+                document: pwstr_to_string(info.pDocument),
+                user: pwstr_to_string(info.pUserName),
+                is_paused: info.Status & JOB_STATUS_PAUSED != 0,
+                total_pages: info.TotalPages,
+                pages_printed: info.PagesPrinted,
+            })
+            .collect())
+    }
+
+    fn control_job(printer: &str, job_id: u32, command: u32) -> Result<()> {
+        let handle = PrinterHandle::open(printer)?;
+        unsafe { SetJobW(handle.0, job_id, 0, None, command).ok()? };
+        Ok(())
+    }
+
+    pub fn pause_job(printer: &str, job_id: u32) -> Result<()> {
+        Self::control_job(printer, job_id, JOB_CONTROL_PAUSE)
+    }
+
+    pub fn resume_job(printer: &str, job_id: u32) -> Result<()> {
+        Self::control_job(printer, job_id, JOB_CONTROL_RESUME)
+    }
+
+    pub fn cancel_job(printer: &str, job_id: u32) -> Result<()> {
+        Self::control_job(printer, job_id, JOB_CONTROL_DELETE)
+    }
+
+    /// Pauses or resumes the whole queue of the printer
+    pub fn set_printer_paused(printer: &str, paused: bool) -> Result<()> {
+        let command = if paused {
+            PRINTER_CONTROL_PAUSE
+        } else {
+            PRINTER_CONTROL_RESUME
+        };
+        let handle = PrinterHandle::open(printer)?;
+        unsafe { SetPrinterW(handle.0, 0, None, command).ok()? };
+        Ok(())
+    }
+
+    /// Emits the printers each time their status or queue changes.
+    /// The spooler notifications are too noisy for the toolbar, so the state is polled.
+    pub fn register_printer_events() -> Result<()> {
+        if REGISTERED.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Registering printer events");
+
+        spawn_named_thread("Printers Loop", || {
+            let mut last = None;
+            loop {
+                if GameMode::is_active() {
+                    sleep_millis(2000);
+                    continue;
+                }
+                match Self::printers() {
+                    Ok(printers) if last.as_ref() != Some(&printers) => {
+                        log_error!(get_app_handle().emit("printers", &printers));
+                        last = Some(printers);
+                    }
+                    Ok(_) => {}
+                    Err(err) => log::error!("Failed to enumerate printers: {:?}", err),
+                }
+                sleep_millis(2000);
+            }
+        })?;
+        Ok(())
+    }
+}
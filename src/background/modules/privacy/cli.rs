@@ -0,0 +1,29 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::PrivacyWatcher;
+
+get_subcommands![
+    /** Mutes the default microphone if it is unmuted, otherwise unmutes it */
+    ToggleMicMute,
+];
+
+impl PrivacyWatcher {
+    pub const CLI_IDENTIFIER: &'static str = "privacy";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Microphone and camera privacy")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::ToggleMicMute => Self::toggle_microphone_mute()?,
+        };
+        Ok(())
+    }
+}
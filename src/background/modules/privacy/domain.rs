@@ -0,0 +1,73 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Capability {
+    Microphone,
+    Camera,
+}
+
+impl Capability {
+    /// name of the capability on the consent store of the registry
+    pub fn consent_key(&self) -> &'static str {
+        match self {
+            Capability::Microphone => "microphone",
+            Capability::Camera => "webcam",
+        }
+    }
+}
+
+/// An app currently using the microphone or the camera
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapabilityUsage {
+    /// executable path, or package family name for packaged apps
+    pub app: String,
+    pub name: String,
+    pub capability: Capability,
+    pub packaged: bool,
+    /// unix timestamp in seconds
+    pub since: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacyState {
+    pub microphone: Vec<CapabilityUsage>,
+    pub camera: Vec<CapabilityUsage>,
+    /// the default recording device is muted
+    pub microphone_muted: bool,
+}
+
+/// Non packaged apps are stored on the consent store with `#` instead of `\` on their path
+pub fn decode_app_path(key: &str) -> String {
+    key.replace('#', "\\")
+}
+
+/// `Microsoft.WindowsCamera_8wekyb3d8bbwe` to `Microsoft.WindowsCamera`
+pub fn package_name(family: &str) -> &str {
+    family.split_once('_').map_or(family, |(name, _)| name)
+}
+
+/// Windows file time (100ns since 1601) to unix seconds
+pub fn filetime_to_unix(filetime: u64) -> u64 {
+    (filetime / 10_000_000).saturating_sub(11_644_473_600)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consent_store_names() {
+        assert_eq!(
+            decode_app_path("C:#Program Files#Zoom#bin#Zoom.exe"),
+            r"C:\Program Files\Zoom\bin\Zoom.exe"
+        );
+        assert_eq!(
+            package_name("Microsoft.WindowsCamera_8wekyb3d8bbwe"),
+            "Microsoft.WindowsCamera"
+        );
+        assert_eq!(package_name("NoPublisher"), "NoPublisher");
+        assert_eq!(filetime_to_unix(133_000_000_000_000_000), 1_655_526_400);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::error_handler::Result;
+
+use super::{domain::PrivacyState, PrivacyWatcher};
+
+#[tauri::command(async)]
+pub fn privacy_get_state() -> PrivacyState {
+    PrivacyWatcher::state()
+}
+
+#[tauri::command(async)]
+pub fn privacy_toggle_microphone_mute() -> Result<()> {
+    PrivacyWatcher::toggle_microphone_mute()
+}
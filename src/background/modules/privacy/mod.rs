@@ -0,0 +1,158 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use domain::{
+    decode_app_path, filetime_to_unix, package_name, Capability, CapabilityUsage, PrivacyState,
+};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        media::{application::MEDIA_MANAGER, domain::SEELEN_AUDIO_CONTEXT},
+        osd::{domain::OsdEvent, Osd},
+    },
+    seelen::get_app_handle,
+    seelen_weg::app_names::AppNames,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+lazy_static! {
+    static ref STATE: Mutex<PrivacyState> = Mutex::new(PrivacyState::default());
+}
+
+/// true while the polling thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+const CONSENT_STORE: &str =
+    r"Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore";
+
+/// Start time of the current usage of the capability by the app, none if not in use.
+/// Windows writes the start time when the app opens the device and the stop time on release.
+fn active_since(parent: &RegKey, name: &str) -> Option<u64> {
+    let key = parent.open_subkey(name).ok()?;
+    let start: u64 = key.get_value("LastUsedTimeStart").ok()?;
+    let stop: u64 = key.get_value("LastUsedTimeStop").unwrap_or(0);
+    (start != 0 && stop == 0).then(|| filetime_to_unix(start))
+}
+
+fn apps_using(capability: Capability) -> Vec<CapabilityUsage> {
+    let mut usages = Vec::new();
+    let path = format!(r"{}\{}", CONSENT_STORE, capability.consent_key());
+    let Ok(store) = RegKey::predef(HKEY_CURRENT_USER).open_subkey(path) else {
+        return usages;
+    };
+
+    for name in store.enum_keys().flatten() {
+        if name == "NonPackaged" {
+            let Ok(non_packaged) = store.open_subkey(&name) else {
+                continue;
+            };
+            for app in non_packaged.enum_keys().flatten() {
+                if let Some(since) = active_since(&non_packaged, &app) {
+                    let path = decode_app_path(&app);
+                    usages.push(CapabilityUsage {
+                        name: AppNames::get(&path),
+                        app: path,
+                        capability,
+                        packaged: false,
+                        since,
+                    });
+                }
+            }
+        } else if let Some(since) = active_since(&store, &name) {
+            usages.push(CapabilityUsage {
+                name: package_name(&name).to_string(),
+                app: name,
+                capability,
+                packaged: true,
+                since,
+            });
+        }
+    }
+    usages.sort_by_key(|usage| usage.since);
+    usages
+}
+
+/// Default recording device, `(id, muted)`
+fn default_microphone() -> Option<(String, bool)> {
+    let manager = trace_lock!(MEDIA_MANAGER);
+    manager
+        .inputs()
+        .iter()
+        .find(|device| device.is_default_multimedia)
+        .map(|device| (device.id.clone(), device.muted))
+}
+
+/// Emits `privacy-changed` with the apps using the microphone or the camera, read from the
+/// capability access manager of Windows (the same source of the privacy indicator of the
+/// taskbar), and the mute state of the default microphone.
+pub struct PrivacyWatcher;
+
+impl PrivacyWatcher {
+    const INTERVAL: u64 = 1500;
+
+    pub fn state() -> PrivacyState {
+        trace_lock!(STATE).clone()
+    }
+
+    fn refresh() -> Result<()> {
+        let state = PrivacyState {
+            microphone: apps_using(Capability::Microphone),
+            camera: apps_using(Capability::Camera),
+            microphone_muted: default_microphone().is_some_and(|(_, muted)| muted),
+        };
+        let mut current = trace_lock!(STATE);
+        if *current != state {
+            get_app_handle().emit("privacy-changed", &state)?;
+            *current = state;
+        }
+        Ok(())
+    }
+
+    /// Mutes or unmutes the default microphone, the new state is shown on the OSD
+    pub fn toggle_microphone_mute() -> Result<()> {
+        let (id, muted) = default_microphone().ok_or("There is no default microphone")?;
+        {
+            let manager = trace_lock!(MEDIA_MANAGER);
+            let (endpoint, _) = manager
+                .devices_audio_endpoint()
+                .get(&id)
+                .ok_or("The default microphone is not loaded")?;
+            unsafe { endpoint.SetMute(!muted, &SEELEN_AUDIO_CONTEXT)? };
+        }
+        log_error!(Osd::show(OsdEvent::Microphone { muted: !muted }));
+        // the volume event updates the media manager on another thread
+        spawn_named_thread("Privacy Refresh", || {
+            sleep_millis(200);
+            log_error!(Self::refresh());
+        })?;
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting privacy watcher");
+        spawn_named_thread("Privacy Watcher", || {
+            while RUNNING.load(Ordering::Acquire) {
+                log_error!(Self::refresh());
+                sleep_millis(Self::INTERVAL);
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use windows::Win32::System::Threading::{
+    ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS,
+    IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, PROCESS_CREATION_FLAGS,
+};
+
+/// Realtime is intentionally not available, it can freeze the whole system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProcessPriority {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+}
+
+impl From<ProcessPriority> for PROCESS_CREATION_FLAGS {
+    fn from(priority: ProcessPriority) -> Self {
+        match priority {
+            ProcessPriority::Idle => IDLE_PRIORITY_CLASS,
+            ProcessPriority::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+            ProcessPriority::Normal => NORMAL_PRIORITY_CLASS,
+            ProcessPriority::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            ProcessPriority::High => HIGH_PRIORITY_CLASS,
+        }
+    }
+}
+
+/// Stored by executable name, applied each time a window of the app is shown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessRule {
+    pub priority: Option<ProcessPriority>,
+    /// bit mask of the logical processors the process can run on
+    pub affinity: Option<usize>,
+}
+
+impl ProcessRule {
+    pub fn is_empty(&self) -> bool {
+        self.priority.is_none() && self.affinity.is_none()
+    }
+}
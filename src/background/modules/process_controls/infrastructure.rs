@@ -0,0 +1,23 @@
+use windows::Win32::Foundation::HWND;
+
+use crate::error_handler::Result;
+
+use super::{
+    domain::{ProcessPriority, ProcessRule},
+    ProcessControls,
+};
+
+#[tauri::command(async)]
+pub fn get_process_rule(hwnd: isize) -> Result<ProcessRule> {
+    ProcessControls::get_rule(HWND(hwnd))
+}
+
+#[tauri::command(async)]
+pub fn set_process_priority(hwnd: isize, level: ProcessPriority) -> Result<()> {
+    ProcessControls::set_priority(HWND(hwnd), level)
+}
+
+#[tauri::command(async)]
+pub fn set_process_affinity(hwnd: isize, mask: Option<usize>) -> Result<()> {
+    ProcessControls::set_affinity(HWND(hwnd), mask)
+}
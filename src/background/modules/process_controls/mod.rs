@@ -0,0 +1,99 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use domain::{ProcessPriority, ProcessRule};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result, seelen::get_app_handle, trace_lock, utils::app_data_path,
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref RULES: Arc<Mutex<HashMap<String, ProcessRule>>> =
+        Arc::new(Mutex::new(ProcessControls::load().unwrap_or_default()));
+}
+
+/// Priority and affinity of the apps, changes made from the dock are remembered by
+/// executable so they are applied again the next time the app is opened.
+pub struct ProcessControls;
+
+impl ProcessControls {
+    const FILENAME: &'static str = "process_rules.yml";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn load() -> Result<HashMap<String, ProcessRule>> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+        Ok(serde_yaml::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    fn key(hwnd: HWND) -> Result<String> {
+        Ok(WindowsApi::exe(hwnd)?.to_lowercase())
+    }
+
+    fn update_rule<F: FnOnce(&mut ProcessRule)>(hwnd: HWND, f: F) -> Result<()> {
+        let key = Self::key(hwnd)?;
+        let mut rules = trace_lock!(RULES);
+        let rule = rules.entry(key.clone()).or_default();
+        f(rule);
+        if rule.is_empty() {
+            rules.remove(&key);
+        }
+        std::fs::write(Self::path(), serde_yaml::to_string(&*rules)?)?;
+        Ok(())
+    }
+
+    pub fn get_rule(hwnd: HWND) -> Result<ProcessRule> {
+        let key = Self::key(hwnd)?;
+        Ok(trace_lock!(RULES).get(&key).cloned().unwrap_or_default())
+    }
+
+    pub fn set_priority(hwnd: HWND, priority: ProcessPriority) -> Result<()> {
+        let (process_id, _) = WindowsApi::window_thread_process_id(hwnd);
+        WindowsApi::set_priority_class(process_id, priority.into())?;
+        Self::update_rule(hwnd, |rule| {
+            rule.priority = (priority != ProcessPriority::Normal).then_some(priority);
+        })
+    }
+
+    /// `None` lets the process run on all the processors again
+    pub fn set_affinity(hwnd: HWND, mask: Option<usize>) -> Result<()> {
+        let all = WindowsApi::system_affinity_mask()?;
+        let mask = mask
+            .map(|mask| mask & all)
+            .filter(|mask| *mask != 0 && *mask != all);
+        let (process_id, _) = WindowsApi::window_thread_process_id(hwnd);
+        WindowsApi::set_affinity_mask(process_id, mask.unwrap_or(all))?;
+        Self::update_rule(hwnd, |rule| rule.affinity = mask)
+    }
+
+    /// Applies the remembered rule of the app to a recently shown window
+    pub fn apply_rule(hwnd: HWND) -> Result<()> {
+        // elevated or protected processes can't be queried, they can't have rules either
+        let Ok(key) = Self::key(hwnd) else {
+            return Ok(());
+        };
+        let rule = match trace_lock!(RULES).get(&key) {
+            Some(rule) => rule.clone(),
+            None => return Ok(()),
+        };
+        let (process_id, _) = WindowsApi::window_thread_process_id(hwnd);
+        if let Some(priority) = rule.priority {
+            WindowsApi::set_priority_class(process_id, priority.into())?;
+        }
+        if let Some(mask) = rule.affinity {
+            WindowsApi::set_affinity_mask(process_id, mask)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::error_handler::Result;
+
+use super::{QuickAccess, QuickAccessItem};
+
+#[tauri::command(async)]
+pub fn quick_access_get_folders() -> Result<Vec<QuickAccessItem>> {
+    QuickAccess::folders()
+}
+
+#[tauri::command(async)]
+pub fn quick_access_get_recent_files(limit: Option<usize>) -> Result<Vec<QuickAccessItem>> {
+    QuickAccess::recent_files(limit.unwrap_or(20))
+}
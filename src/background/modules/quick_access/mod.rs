@@ -0,0 +1,121 @@
+pub mod infrastructure;
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use windows::{
+    core::HSTRING,
+    Win32::{
+        System::Com::CoTaskMemFree,
+        UI::Shell::{
+            BHID_EnumItems, FOLDERID_Recent, IEnumShellItems, IShellItem,
+            SHCreateItemFromParsingName, SHGetKnownFolderPath, KF_FLAG_DEFAULT, SIGDN,
+            SIGDN_FILESYSPATH, SIGDN_NORMALDISPLAY,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result, seelen::get_app_handle,
+    seelen_weg::icon_extractor::extract_and_save_shell_icon, windows_api::Com,
+};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickAccessItem {
+    name: String,
+    path: PathBuf,
+    is_folder: bool,
+    icon: Option<PathBuf>,
+}
+
+impl QuickAccessItem {
+    fn new(name: String, path: PathBuf) -> Self {
+        Self {
+            icon: extract_and_save_shell_icon(&get_app_handle(), &path).ok(),
+            is_folder: path.is_dir(),
+            name,
+            path,
+        }
+    }
+}
+
+/// Folders pinned or frequently used on the Explorer Quick Access and recently opened files
+pub struct QuickAccess {}
+
+impl QuickAccess {
+    const QUICK_ACCESS_NAMESPACE: &'static str = "shell:::{679f85cb-0220-4080-b29b-5540cc05aab6}";
+
+    unsafe fn display_name(item: &IShellItem, kind: SIGDN) -> Result<String> {
+        let name = item.GetDisplayName(kind)?;
+        let result = name.to_string();
+        CoTaskMemFree(Some(name.0 as _));
+        Ok(result?)
+    }
+
+    pub fn folders() -> Result<Vec<QuickAccessItem>> {
+        Com::run_with_context(|| unsafe {
+            let namespace: IShellItem =
+                SHCreateItemFromParsingName(&HSTRING::from(Self::QUICK_ACCESS_NAMESPACE), None)?;
+            let children: IEnumShellItems = namespace.BindToHandler(None, &BHID_EnumItems)?;
+
+            let mut items = Vec::new();
+            loop {
+                let mut batch = [None];
+                let mut fetched = 0;
+                if children.Next(&mut batch, Some(&mut fetched)).is_err() || fetched == 0 {
+                    break;
+                }
+                let item = match batch[0].take() {
+                    Some(item) => item,
+                    None => break,
+                };
+                // virtual items (libraries, Home contents) have no filesystem path
+                let path = match Self::display_name(&item, SIGDN_FILESYSPATH) {
+                    Ok(path) => PathBuf::from(path),
+                    Err(_) => continue,
+                };
+                if path.is_dir() {
+                    let name = Self::display_name(&item, SIGDN_NORMALDISPLAY)?;
+                    items.push(QuickAccessItem::new(name, path));
+                }
+            }
+            Ok(items)
+        })
+    }
+
+    /// Shortcuts of the Recent Items folder, most recent first
+    pub fn recent_files(limit: usize) -> Result<Vec<QuickAccessItem>> {
+        let folder = unsafe {
+            let path = SHGetKnownFolderPath(&FOLDERID_Recent, KF_FLAG_DEFAULT, None)?;
+            let result = path.to_string();
+            CoTaskMemFree(Some(path.0 as _));
+            PathBuf::from(result?)
+        };
+
+        let mut shortcuts: Vec<_> = std::fs::read_dir(folder)?
+            .flatten()
+            .filter(|entry| Self::is_shortcut(&entry.path()))
+            .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+            .collect();
+        shortcuts.sort_by(|a, b| b.0.cmp(&a.0));
+
+        Ok(shortcuts
+            .into_iter()
+            .take(limit)
+            .map(|(_, path)| {
+                let name = path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                QuickAccessItem::new(name, path)
+            })
+            .collect())
+    }
+
+    fn is_shortcut(path: &Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("lnk"))
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::QuietLaunchState;
+use sysinfo::System;
+use windows::Win32::{
+    Foundation::HWND,
+    UI::WindowsAndMessaging::{SHOW_WINDOW_CMD, SW_HIDE, SW_SHOWMINNOACTIVE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::virtual_desk::get_vd_manager,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    /// apps launched whose new windows are still being placed
+    static ref PENDING: Mutex<Vec<PendingApp>> = Mutex::new(Vec::new());
+}
+
+struct PendingApp {
+    pid: Option<u32>,
+    /// lowercased executable path, used to match the windows of apps using a launcher process
+    exe: Option<String>,
+    workspace: Option<usize>,
+    state: QuietLaunchState,
+    until: Instant,
+    placed: HashSet<isize>,
+}
+
+impl PendingApp {
+    fn owns(&self, hwnd: HWND) -> bool {
+        let (pid, _) = WindowsApi::window_thread_process_id(hwnd);
+        self.pid == Some(pid)
+            || self.exe.as_ref().is_some_and(|exe| {
+                WindowsApi::exe_path_v2(hwnd)
+                    .is_ok_and(|path| path.to_string_lossy().to_lowercase() == *exe)
+            })
+    }
+}
+
+fn show_command(state: QuietLaunchState) -> SHOW_WINDOW_CMD {
+    match state {
+        QuietLaunchState::Minimized => SW_SHOWMINNOACTIVE,
+        QuietLaunchState::Hidden => SW_HIDE,
+    }
+}
+
+/// Launches the apps of `settings.quiet_launch` when Seelen UI starts, minimized or hidden and
+/// directly on their workspace, so they don't steal the focus or flash while the session boots.
+/// The show command is passed to the app as hint and the first windows it shows are also
+/// minimized or hidden from the windows hook, before they are painted.
+pub struct QuietLaunch;
+
+impl QuietLaunch {
+    /// Executables can be compared with the running processes, shortcuts and documents can't
+    fn exe_of(path: &str) -> Option<String> {
+        let path = PathBuf::from(path);
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+            .then(|| path.to_string_lossy().to_lowercase())
+    }
+
+    pub fn run() -> Result<()> {
+        let settings = FULL_STATE.load().settings().quiet_launch.clone();
+        sleep_millis(settings.delay * 1000);
+
+        let mut sys = System::new();
+        sys.refresh_processes();
+        let running = sys
+            .processes()
+            .values()
+            .filter_map(|p| p.exe().map(|exe| exe.to_string_lossy().to_lowercase()))
+            .collect::<HashSet<String>>();
+
+        for app in settings.apps {
+            if app.path.trim().is_empty() {
+                continue;
+            }
+            let exe = Self::exe_of(&app.path);
+            if exe.as_ref().is_some_and(|exe| running.contains(exe)) {
+                log::trace!("Skipping quiet launch of running app {}", app.path);
+                continue;
+            }
+
+            log::trace!("Quietly launching {}", app.path);
+            match WindowsApi::shell_launch(&app.path, &app.args, show_command(app.state)) {
+                Ok(pid) => trace_lock!(PENDING).push(PendingApp {
+                    pid,
+                    exe,
+                    workspace: app.workspace,
+                    state: app.state,
+                    until: Instant::now() + Duration::from_secs(settings.timeout),
+                    placed: HashSet::new(),
+                }),
+                Err(err) => log::error!("Failed to launch {}: {:?}", app.path, err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Workspace of the launch entry or the one bound on the app configurations
+    fn target_workspace(workspace: Option<usize>, hwnd: HWND) -> Option<usize> {
+        if workspace.is_some() {
+            return workspace;
+        }
+        let state = FULL_STATE.load();
+        let bound = state
+            .get_app_config_by_window(hwnd)?
+            .bound_workspace
+            .as_ref()?;
+        get_vd_manager()
+            .get_all()
+            .ok()?
+            .iter()
+            .position(|desktop| desktop.name().is_some_and(|name| name == *bound))
+    }
+
+    /// Called on the hook thread each time a window is shown
+    pub fn on_window_shown(hwnd: HWND) {
+        let mut pending = trace_lock!(PENDING);
+        if pending.is_empty() {
+            return;
+        }
+        let now = Instant::now();
+        pending.retain(|app| app.until > now);
+
+        let Some(app) = pending.iter_mut().find(|app| app.owns(hwnd)) else {
+            return;
+        };
+        if !SeelenWeg::should_be_added(hwnd) || !app.placed.insert(hwnd.0) {
+            return;
+        }
+        log_error!(WindowsApi::show_window_async(hwnd, show_command(app.state)));
+
+        let workspace = app.workspace;
+        drop(pending);
+        log_error!(spawn_named_thread("Quiet Launch", move || {
+            if let Some(idx) = Self::target_workspace(workspace, hwnd) {
+                log_error!(get_vd_manager().send_to(idx, hwnd.0));
+            }
+        }));
+    }
+}
@@ -0,0 +1,29 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::RecentlyClosed;
+
+get_subcommands![
+    /** Reopens the app of the last closed window */
+    ReopenLast,
+];
+
+impl RecentlyClosed {
+    pub const CLI_IDENTIFIER: &'static str = "recently-closed";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Reopen apps closed in the last minutes")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::ReopenLast => Self::reopen_last_closed()?,
+        };
+        Ok(())
+    }
+}
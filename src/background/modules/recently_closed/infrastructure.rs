@@ -0,0 +1,18 @@
+use crate::error_handler::Result;
+
+use super::{ClosedWindow, RecentlyClosed};
+
+#[tauri::command(async)]
+pub fn reopen_last_closed() -> Result<()> {
+    RecentlyClosed::reopen_last_closed()
+}
+
+#[tauri::command(async)]
+pub fn reopen_closed(id: u32) -> Result<()> {
+    RecentlyClosed::reopen(id)
+}
+
+#[tauri::command(async)]
+pub fn get_recently_closed() -> Vec<ClosedWindow> {
+    RecentlyClosed::list()
+}
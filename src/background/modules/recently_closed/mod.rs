@@ -0,0 +1,179 @@
+pub mod cli;
+pub mod infrastructure;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    process::Command,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crossbeam_channel::{unbounded, Sender};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+use sysinfo::{Pid, System};
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result, log_error, modules::uwp::UWP_MANAGER, seelen_weg::SeelenWeg, trace_lock,
+    utils::spawn_named_thread, windows_api::WindowsApi, winevent::WinEvent,
+};
+
+lazy_static! {
+    /// app windows currently open, destroyed windows can't be queried anymore
+    static ref OPEN: Arc<Mutex<HashMap<isize, ClosedWindow>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+    static ref CLOSED: Arc<Mutex<VecDeque<(Instant, ClosedWindow)>>> =
+        Arc::new(Mutex::new(VecDeque::new()));
+    static ref OPENED_QUEUE: Sender<HWND> = spawn_opened_worker();
+}
+
+/// Reading the process command line is slow, so the opened windows are read off the hook
+/// thread, one by one in the order they appeared
+fn spawn_opened_worker() -> Sender<HWND> {
+    let (sender, receiver) = unbounded::<HWND>();
+    log_error!(spawn_named_thread("Recently Closed Worker", move || {
+        for origin in receiver {
+            if trace_lock!(OPEN).contains_key(&origin.0) {
+                continue;
+            }
+            let Some(window) = ClosedWindow::from_window(origin) else {
+                continue;
+            };
+            let mut open = trace_lock!(OPEN);
+            open.insert(origin.0, window);
+            // destroyed while it was being read, the destroy event already went by
+            if !WindowsApi::is_window(origin) {
+                open.remove(&origin.0);
+            }
+        }
+    }));
+    sender
+}
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedWindow {
+    pub id: u32,
+    pub title: String,
+    pub path: PathBuf,
+    /// command line arguments, without the executable
+    pub args: Vec<String>,
+    /// `shell:AppsFolder` path of packaged apps, as they can't be launched by path
+    pub shell_path: Option<String>,
+}
+
+impl ClosedWindow {
+    fn from_window(hwnd: HWND) -> Option<Self> {
+        let (pid, _) = WindowsApi::window_thread_process_id(hwnd);
+        let mut sys = System::new();
+        let pid = Pid::from_u32(pid);
+        sys.refresh_process(pid);
+        let process = sys.process(pid)?;
+        let path = process.exe()?.to_path_buf();
+
+        let exe = path.file_name()?.to_string_lossy().to_string();
+        let shell_path = trace_lock!(UWP_MANAGER)
+            .get_from_path(&path)
+            .and_then(|package| package.get_shell_path(&exe));
+
+        Some(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            title: WindowsApi::get_window_text(hwnd),
+            // packaged apps are relaunched by their shell path, so the args are dropped
+            args: match shell_path {
+                Some(_) => Vec::new(),
+                None => process.cmd().iter().skip(1).cloned().collect(),
+            },
+            path,
+            shell_path,
+        })
+    }
+
+    fn reopen(&self) -> Result<()> {
+        log::trace!("Reopening {}", self.path.display());
+        match &self.shell_path {
+            Some(shell_path) => WindowsApi::shell_open(shell_path)?,
+            None => {
+                Command::new(&self.path).args(&self.args).spawn()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Remembers the app windows closed in the last minutes so they can be reopened,
+/// like restoring closed tabs on a browser.
+pub struct RecentlyClosed;
+
+impl RecentlyClosed {
+    const MAX_ENTRIES: usize = 20;
+    const MAX_AGE: Duration = Duration::from_secs(30 * 60);
+
+    pub fn on_win_event(event: WinEvent, origin: HWND) {
+        match event {
+            WinEvent::ObjectShow | WinEvent::SystemForeground => {
+                if trace_lock!(OPEN).contains_key(&origin.0) || !SeelenWeg::should_be_added(origin)
+                {
+                    return;
+                }
+                log_error!(OPENED_QUEUE.send(origin));
+            }
+            WinEvent::ObjectNameChange => {
+                if let Some(window) = trace_lock!(OPEN).get_mut(&origin.0) {
+                    window.title = WindowsApi::get_window_text(origin);
+                }
+            }
+            WinEvent::ObjectDestroy => {
+                if let Some(window) = trace_lock!(OPEN).remove(&origin.0) {
+                    let mut closed = trace_lock!(CLOSED);
+                    closed.push_back((Instant::now(), window));
+                    while closed.len() > Self::MAX_ENTRIES {
+                        closed.pop_front();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Most recently closed first
+    pub fn list() -> Vec<ClosedWindow> {
+        let mut closed = trace_lock!(CLOSED);
+        closed.retain(|(at, _)| at.elapsed() < Self::MAX_AGE);
+        closed
+            .iter()
+            .rev()
+            .map(|(_, window)| window.clone())
+            .collect()
+    }
+
+    pub fn reopen(id: u32) -> Result<()> {
+        let window = {
+            let mut closed = trace_lock!(CLOSED);
+            let idx = closed
+                .iter()
+                .position(|(_, window)| window.id == id)
+                .ok_or("Closed window not found")?;
+            closed.remove(idx).map(|(_, window)| window)
+        };
+        match window {
+            Some(window) => window.reopen(),
+            None => Ok(()),
+        }
+    }
+
+    pub fn reopen_last_closed() -> Result<()> {
+        match Self::list().first() {
+            Some(window) => Self::reopen(window.id),
+            None => Err("No recently closed windows".into()),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::{stream_deck, RemoteApi};
+
+get_subcommands![
+    /** Prints the token required to run commands through the remote api */
+    Token,
+    /** Prints buttons for the dock items and modules, to be used on Stream Deck request plugins */
+    StreamDeckProfile,
+];
+
+impl RemoteApi {
+    pub const CLI_IDENTIFIER: &'static str = "remote-api";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Local http api for external tools")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    /// Returns the text to be printed on the console of the caller
+    pub fn process(matches: &clap::ArgMatches) -> Result<String> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Token => Self::token(),
+            SubCommand::StreamDeckProfile => stream_deck::profile(),
+        }
+    }
+}
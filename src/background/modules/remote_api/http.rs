@@ -0,0 +1,206 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+};
+
+use serde::Serialize;
+
+use crate::error_handler::Result;
+
+/// bodies are small json commands, bigger ones are rejected
+const MAX_BODY: usize = 64 * 1024;
+/// request line and headers together
+const MAX_HEAD: usize = 8 * 1024;
+
+pub struct Request {
+    pub method: String,
+    /// path without the query string
+    pub path: String,
+    /// values are not percent-decoded, only plain names are expected
+    pub query: HashMap<String, String>,
+    /// header names are lowercased
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// Reads a line without going over the remaining `budget` of the request head
+fn read_head_line(
+    reader: &mut BufReader<TcpStream>,
+    line: &mut String,
+    budget: &mut usize,
+) -> Result<usize> {
+    line.clear();
+    let read = reader.by_ref().take(*budget as u64).read_line(line)?;
+    *budget -= read;
+    if *budget == 0 && !line.ends_with('\n') {
+        return Err("request head too large".into());
+    }
+    Ok(read)
+}
+
+impl Request {
+    pub fn read(reader: &mut BufReader<TcpStream>) -> Result<Self> {
+        let mut budget = MAX_HEAD;
+        let mut line = String::new();
+        read_head_line(reader, &mut line, &mut budget)?;
+        let mut parts = line.split_whitespace();
+        let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+            return Err("malformed request line".into());
+        };
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let query = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        let (method, path) = (method.to_string(), path.to_string());
+
+        let mut headers = HashMap::new();
+        loop {
+            if read_head_line(reader, &mut line, &mut budget)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+            }
+        }
+
+        let length: usize = headers
+            .get("content-length")
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+        if length > MAX_BODY {
+            return Err("request body too large".into());
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+
+        Ok(Self {
+            method,
+            path,
+            query,
+            headers,
+            body,
+        })
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(|value| value.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.get(name).map(|value| value.as_str())
+    }
+
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.header("authorization")?.strip_prefix("Bearer ")
+    }
+}
+
+pub struct Response {
+    status: u16,
+    content_type: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Self {
+                status: 200,
+                content_type: "application/json",
+                headers: Vec::new(),
+                body,
+            },
+            Err(err) => Self::error(500, &err.to_string()),
+        }
+    }
+
+    pub fn text(status: u16, text: &str) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            headers: Vec::new(),
+            body: text.as_bytes().to_vec(),
+        }
+    }
+
+    pub fn error(status: u16, message: &str) -> Self {
+        let mut response = Self::json(&serde_json::json!({ "error": message }));
+        response.status = status;
+        response
+    }
+
+    pub fn empty(status: u16) -> Self {
+        Self::text(status, "")
+    }
+
+    /// Answer to the browser preflight sent before the cross origin requests
+    pub fn preflight() -> Self {
+        let mut response = Self::empty(204);
+        response.headers = vec![
+            ("Access-Control-Allow-Methods", "GET, POST".to_string()),
+            (
+                "Access-Control-Allow-Headers",
+                "Authorization, Content-Type".to_string(),
+            ),
+            ("Access-Control-Max-Age", "600".to_string()),
+        ];
+        response
+    }
+
+    /// Lets the page of the origin read the response
+    pub fn with_origin(mut self, origin: &str) -> Self {
+        self.headers
+            .push(("Access-Control-Allow-Origin", origin.to_string()));
+        self.headers.push(("Vary", "Origin".to_string()));
+        self
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// CORS headers are only sent to allowed origins (see `with_origin`), so other web
+    /// pages can't read the responses
+    pub fn write(&self, stream: &mut TcpStream) -> Result<()> {
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\n\
+            Content-Type: {}\r\n\
+            Content-Length: {}\r\n\
+            Connection: close\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len()
+        );
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(&self.body)?;
+        stream.flush()?;
+        Ok(())
+    }
+}
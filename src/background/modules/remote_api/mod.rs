@@ -0,0 +1,415 @@
+pub mod cli;
+mod http;
+mod stream_deck;
+mod websocket;
+
+use std::{
+    io::BufReader,
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::Settings;
+use serde::Serialize;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        action_history::{
+            domain::{ActionOutcome, ActionSource},
+            ActionHistory,
+        },
+        cli::{
+            application::{handle_cli_events, SEELEN_COMMAND_LINE},
+            Client,
+        },
+        metrics::Metrics,
+        permissions::{
+            domain::{tokens_equal, Scope},
+            Permissions,
+        },
+        virtual_desk::get_vd_manager,
+    },
+    seelen::get_app_handle,
+    seelen_weg::{rules::WegVerdict, SeelenWeg},
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{app_data_path, sleep_millis, spawn_named_thread},
+    windows_api::{WindowEnumerator, WindowsApi},
+};
+
+use http::{Request, Response};
+
+struct Server {
+    port: u16,
+    running: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl Server {
+    /// The accept loop polls the flag, so it exits right away and drops the listener,
+    /// waiting for it lets the port be bound again.
+    fn stop(self) {
+        self.running.store(false, Ordering::Release);
+        if self.thread.join().is_err() {
+            log::error!("Remote API thread panicked");
+        }
+    }
+}
+
+lazy_static! {
+    static ref SERVER: Mutex<Option<Server>> = Mutex::new(None);
+}
+
+/// open connections, websockets included
+static CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+const MAX_CONNECTIONS: usize = 32;
+/// max time to receive a request, slow clients are dropped to not hold a connection slot
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Place of an open connection on `CONNECTIONS`, released on drop
+struct ConnectionSlot;
+
+impl ConnectionSlot {
+    fn acquire() -> Option<Self> {
+        if CONNECTIONS.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+            CONNECTIONS.fetch_sub(1, Ordering::AcqRel);
+            return None;
+        }
+        Some(Self)
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        CONNECTIONS.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OpenApp {
+    hwnd: isize,
+    title: String,
+    exe: Option<String>,
+}
+
+/// Local http api for external tools (stream decks, widgets, home automation).
+/// Only binds to localhost and every endpoint requires a token with the scope of the request.
+///
+/// - `GET /api/apps`: windows shown on the dock
+/// - `GET /api/workspaces`: workspaces and the active one
+/// - `GET /api/metrics`: system usage and usage of the open apps
+/// - `GET /api/dock/items`: pinned apps and open windows of the dock, with icons
+/// - `GET /api/modules`: enabled state of the modules that can be toggled
+/// - `POST /api/command`: json array of cli arguments, e.g. `["overview", "toggle"]`
+/// - `POST /api/dock/activate`: same as clicking a dock item
+/// - `POST /api/modules/{name}/toggle`: enables or disables a module
+/// - `GET /api/events`: websocket pushing `{ event, payload }` messages
+pub struct RemoteApi;
+
+impl RemoteApi {
+    const TOKEN_FILENAME: &str = "remote_api.token";
+
+    /// Starts the server, restarting it if the port changed.
+    pub fn start() -> Result<()> {
+        let port = FULL_STATE.load().settings().remote_api.port;
+        let mut server = trace_lock!(SERVER);
+        if server.as_ref().is_some_and(|s| s.port == port) {
+            return Ok(());
+        }
+        if let Some(old) = server.take() {
+            old.stop();
+        }
+
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        // polled to be able to stop the server without a connection to unblock it
+        listener.set_nonblocking(true)?;
+        let running = Arc::new(AtomicBool::new(true));
+        log::info!("Remote API listening on 127.0.0.1:{}", port);
+
+        let flag = running.clone();
+        let thread = spawn_named_thread("Remote API", move || {
+            while flag.load(Ordering::Acquire) {
+                match listener.accept() {
+                    Ok((stream, addr)) => {
+                        let Some(slot) = ConnectionSlot::acquire() else {
+                            log::warn!("Remote API: too many connections, dropping {}", addr);
+                            continue;
+                        };
+                        log_error!(spawn_named_thread("Remote API Connection", move || {
+                            log_error!(Self::handle(stream));
+                            drop(slot);
+                        }));
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => sleep_millis(50),
+                    Err(err) => log::error!("Remote API failed to accept connection: {}", err),
+                }
+            }
+            log::trace!("Remote API stopped");
+        })?;
+
+        *server = Some(Server {
+            port,
+            running,
+            thread,
+        });
+        Ok(())
+    }
+
+    pub fn stop() {
+        if let Some(server) = trace_lock!(SERVER).take() {
+            server.stop();
+        }
+    }
+
+    /// Token saved on the app data folder, generated on the first use. It is not part of the
+    /// settings as they are shared with the webviews, third party widgets included.
+    fn token() -> Result<String> {
+        let path = app_data_path(&get_app_handle()).join(Self::TOKEN_FILENAME);
+        if let Ok(token) = std::fs::read_to_string(&path) {
+            if !token.trim().is_empty() {
+                return Ok(token.trim().to_string());
+            }
+        }
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        std::fs::write(&path, &token)?;
+        Ok(token)
+    }
+
+    /// Scopes of the token sent on the `Authorization` header, or on the `token` query
+    /// parameter as the websocket clients can't always set headers. `None` if it is unknown.
+    fn token_scopes(request: &Request) -> Result<Option<Vec<Scope>>> {
+        let Some(token) = request.bearer_token().or_else(|| request.query("token")) else {
+            return Ok(None);
+        };
+        if tokens_equal(token, &Self::token()?) {
+            return Ok(Some(Scope::ALL.to_vec()));
+        }
+        Ok(Permissions::token_scopes(token))
+    }
+
+    /// Error response if the token of the request doesn't grant `scope`
+    fn authorize(request: &Request, scope: Scope) -> Option<Response> {
+        let scopes = match Self::token_scopes(request) {
+            Ok(Some(scopes)) => scopes,
+            Ok(None) => return Some(Response::error(401, "invalid token")),
+            Err(err) => return Some(Response::error(500, &format!("{:?}", err))),
+        };
+        if !scopes.contains(&scope) {
+            let message = format!("the token has not the {} scope", scope.id());
+            return Some(Response::error(403, &message));
+        }
+        None
+    }
+
+    /// Browsers always send the origin on websocket upgrades and cross origin requests,
+    /// only the configured ones are allowed. Other clients don't send it.
+    fn is_allowed_origin(origin: Option<&str>) -> bool {
+        let Some(origin) = origin else {
+            return true;
+        };
+        FULL_STATE
+            .load()
+            .settings()
+            .remote_api
+            .allowed_origins
+            .iter()
+            .any(|allowed| allowed.trim_end_matches('/').eq_ignore_ascii_case(origin))
+    }
+
+    fn handle(stream: TcpStream) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        stream.set_write_timeout(Some(READ_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut stream = stream;
+        let request = match Request::read(&mut reader) {
+            Ok(request) => request,
+            Err(err) => return Response::error(400, &format!("{:?}", err)).write(&mut stream),
+        };
+
+        let origin = request.header("origin");
+        if !Self::is_allowed_origin(origin) {
+            return Response::error(403, "origin not allowed").write(&mut stream);
+        }
+
+        if request.method == "GET" && request.path == "/api/events" {
+            if let Some(response) = Self::authorize(&request, Scope::ReadState) {
+                return response.write(&mut stream);
+            }
+            // the connection stays open waiting for the client frames
+            stream.set_read_timeout(None)?;
+            return websocket::serve(stream, &request);
+        }
+
+        let response = match request.method.as_str() {
+            "OPTIONS" => Response::preflight(),
+            _ => Self::route(&request),
+        };
+        // the command endpoint is recorded by itself as its body is the argv
+        if request.method == "POST" && request.path != "/api/command" {
+            let outcome = match response.status() {
+                200..=299 => ActionOutcome::Success,
+                status => ActionOutcome::Failed(format!(
+                    "{}: {}",
+                    status,
+                    String::from_utf8_lossy(response.body())
+                )),
+            };
+            ActionHistory::record(
+                ActionSource::RemoteApi,
+                "remote api",
+                request.path.as_str(),
+                serde_json::from_slice(&request.body).unwrap_or_default(),
+                outcome,
+            );
+        }
+        match origin {
+            Some(origin) => response.with_origin(origin).write(&mut stream),
+            None => response.write(&mut stream),
+        }
+    }
+
+    /// Arguments of `/api/command` parsed as the cli does, `None` if the body is invalid
+    fn command_argv(request: &Request) -> Option<Vec<String>> {
+        let args = serde_json::from_slice::<Vec<String>>(&request.body).ok()?;
+        Some(
+            std::iter::once("seelen-ui".to_string())
+                .chain(args)
+                .collect(),
+        )
+    }
+
+    /// Scope needed by each POST endpoint
+    fn required_scope(request: &Request) -> Scope {
+        match request.path.as_str() {
+            "/api/command" => {
+                let command = trace_lock!(SEELEN_COMMAND_LINE).clone();
+                let matches = Self::command_argv(request)
+                    .and_then(|argv| command.try_get_matches_from(argv).ok());
+                Scope::of_cli(matches.as_ref().and_then(|m| m.subcommand_name()))
+            }
+            "/api/dock/activate" => Scope::ControlWindows,
+            _ => Scope::RunCommands,
+        }
+    }
+
+    /// The main token has all the scopes, the ones created on the permissions only the
+    /// granted ones. Reading needs `read-state`, the POST endpoints the scope of the action.
+    fn route(request: &Request) -> Response {
+        let scope = match request.method.as_str() {
+            "POST" => Self::required_scope(request),
+            _ => Scope::ReadState,
+        };
+        if let Some(response) = Self::authorize(request, scope) {
+            return response;
+        }
+
+        let result = match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/api/apps") => Self::apps().map(|apps| Response::json(&apps)),
+            ("GET", "/api/workspaces") => Self::workspaces(),
+            ("GET", "/api/metrics") => Ok(Self::metrics()),
+            ("GET", "/api/dock/items") => stream_deck::dock_items().map(|i| Response::json(&i)),
+            ("GET", "/api/modules") => Ok(Response::json(&stream_deck::modules())),
+            ("POST", "/api/command") => Self::command(request),
+            ("POST", "/api/dock/activate") => Self::activate(request),
+            ("POST", path) if path.starts_with("/api/modules/") => Self::toggle_module(path),
+            (
+                _,
+                "/api/apps" | "/api/workspaces" | "/api/metrics" | "/api/dock/items"
+                | "/api/modules" | "/api/command" | "/api/dock/activate",
+            ) => return Response::error(405, "method not allowed"),
+            _ => return Response::error(404, "not found"),
+        };
+        result.unwrap_or_else(|err| Response::error(500, &format!("{:?}", err)))
+    }
+
+    fn apps() -> Result<Vec<OpenApp>> {
+        let mut apps = Vec::new();
+        WindowEnumerator::new().for_each(|hwnd| {
+            if WegVerdict::evaluate(hwnd).accepted {
+                apps.push(OpenApp {
+                    hwnd: hwnd.0,
+                    title: WindowsApi::get_window_text(hwnd),
+                    exe: WindowsApi::exe_path(hwnd).ok(),
+                });
+            }
+        })?;
+        Ok(apps)
+    }
+
+    fn workspaces() -> Result<Response> {
+        let vd = get_vd_manager();
+        let workspaces: Vec<_> = vd.get_all()?.iter().map(|d| d.as_serializable()).collect();
+        Ok(Response::json(&serde_json::json!({
+            "active": vd.get_current()?.id(),
+            "workspaces": workspaces,
+        })))
+    }
+
+    /// cpu usage is the difference between refreshes, so it is 0 on the first request
+    fn metrics() -> Response {
+        Metrics::refresh();
+        Response::json(&serde_json::json!({
+            "system": Metrics::system(),
+            "apps": Metrics::usage_by_exe(&SeelenWeg::open_exes()),
+        }))
+    }
+
+    /// Dock items only need `control-windows`, launching anything else needs `run-commands`
+    fn activate(request: &Request) -> Result<Response> {
+        let Ok(body) = serde_json::from_slice(&request.body) else {
+            return Ok(Response::error(400, "body should be a json object"));
+        };
+        let target = stream_deck::resolve(body)?;
+        if !target.is_dock_item() {
+            if let Some(response) = Self::authorize(request, Scope::RunCommands) {
+                return Ok(response);
+            }
+        }
+        stream_deck::activate(target)?;
+        Ok(Response::empty(204))
+    }
+
+    /// path as `/api/modules/{name}/toggle`
+    fn toggle_module(path: &str) -> Result<Response> {
+        let name = path
+            .trim_start_matches("/api/modules/")
+            .trim_end_matches("/toggle");
+        if !path.ends_with("/toggle") || !Settings::TOGGLEABLE_MODULES.contains(&name) {
+            return Ok(Response::error(404, "unknown module"));
+        }
+        let enabled = stream_deck::toggle_module(name)?;
+        Ok(Response::json(&serde_json::json!({ "enabled": enabled })))
+    }
+
+    fn command(request: &Request) -> Result<Response> {
+        let Some(argv) = Self::command_argv(request) else {
+            return Ok(Response::error(
+                400,
+                "body should be a json array of strings",
+            ));
+        };
+
+        let command = trace_lock!(SEELEN_COMMAND_LINE).clone();
+        let matches = match command.try_get_matches_from(&argv) {
+            Ok(matches) => matches,
+            Err(err) => return Ok(Response::error(400, &err.to_string())),
+        };
+        if let Some(reply) = Client::reply(&matches) {
+            return Ok(Response::text(200, &reply));
+        }
+        let result = handle_cli_events(&matches);
+        ActionHistory::record_argv(ActionSource::RemoteApi, "remote api", &argv, &result);
+        result?;
+        Ok(Response::empty(204))
+    }
+}
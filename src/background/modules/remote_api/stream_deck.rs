@@ -0,0 +1,240 @@
+use base64::Engine;
+use seelen_core::state::Settings;
+use serde::{Deserialize, Serialize};
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    seelen_weg::{handler::weg_toggle_window_state, SeelenWeg},
+    state::application::FULL_STATE,
+    windows_api::WindowsApi,
+};
+
+use super::{OpenApp, RemoteApi};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DockItemKind {
+    Pinned,
+    Window,
+}
+
+/// Item of the dock as shown on a button, the icon is a png data url ready to be used as image.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DockItem {
+    kind: DockItemKind,
+    title: String,
+    exe: String,
+    execution_path: String,
+    /// first open window of the item, if any
+    hwnd: Option<isize>,
+    icon: String,
+}
+
+/// Body of the activation request, `hwnd` is preferred and `exe` is used to find
+/// the current window of pinned items as the listed one could be closed.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivateRequest {
+    hwnd: Option<isize>,
+    exe: Option<String>,
+    execution_path: Option<String>,
+}
+
+/// Item to be activated, resolved from the request
+pub enum ActivateTarget {
+    /// open window shown on the dock
+    Window(isize),
+    /// pinned item, launched with the execution path saved on the dock
+    Pinned(Option<isize>, String),
+    /// anything not on the dock, launching it requires the `run-commands` scope
+    Other(String),
+}
+
+impl ActivateTarget {
+    pub fn is_dock_item(&self) -> bool {
+        !matches!(self, ActivateTarget::Other(_))
+    }
+}
+
+fn icon_data_url(exe: &str) -> String {
+    let path = SeelenWeg::extract_icon(exe).unwrap_or_else(|_| SeelenWeg::missing_icon());
+    match std::fs::read(&path) {
+        Ok(bytes) => format!(
+            "data:image/png;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        ),
+        Err(err) => {
+            log::warn!("Failed to read icon {}: {}", path, err);
+            String::new()
+        }
+    }
+}
+
+fn window_of(windows: &[OpenApp], exe: &str) -> Option<isize> {
+    windows
+        .iter()
+        .find(|w| {
+            w.exe
+                .as_deref()
+                .is_some_and(|e| e.eq_ignore_ascii_case(exe))
+        })
+        .map(|w| w.hwnd)
+}
+
+fn title_of(exe: &str) -> String {
+    std::path::Path::new(exe)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| exe.to_string())
+}
+
+/// Pinned apps first, in the order of the dock, followed by the windows of unpinned apps.
+pub fn dock_items() -> Result<Vec<DockItem>> {
+    let windows = RemoteApi::apps()?;
+    let state = FULL_STATE.load();
+    let pinned = state.weg_items().pinned_apps();
+
+    let mut items: Vec<DockItem> = pinned
+        .iter()
+        .map(|app| DockItem {
+            kind: DockItemKind::Pinned,
+            title: title_of(app.exe()),
+            exe: app.exe().to_string(),
+            execution_path: app.execution_path().to_string(),
+            hwnd: window_of(&windows, app.exe()),
+            icon: icon_data_url(app.exe()),
+        })
+        .collect();
+
+    for window in windows {
+        let Some(exe) = window.exe else {
+            continue;
+        };
+        if pinned
+            .iter()
+            .any(|app| app.exe().eq_ignore_ascii_case(&exe))
+        {
+            continue;
+        }
+        items.push(DockItem {
+            kind: DockItemKind::Window,
+            title: window.title,
+            icon: icon_data_url(&exe),
+            execution_path: exe.clone(),
+            exe,
+            hwnd: Some(window.hwnd),
+        });
+    }
+    Ok(items)
+}
+
+/// Only windows listed on the dock are accepted by `hwnd`, and pinned items are looked up
+/// by `exe`, the requested `executionPath` is never used for them.
+pub fn resolve(request: ActivateRequest) -> Result<ActivateTarget> {
+    let windows = RemoteApi::apps()?;
+    if let Some(hwnd) = request
+        .hwnd
+        .filter(|hwnd| windows.iter().any(|w| w.hwnd == *hwnd))
+    {
+        return Ok(ActivateTarget::Window(hwnd));
+    }
+
+    let state = FULL_STATE.load();
+    let pinned = request.exe.as_deref().and_then(|exe| {
+        state
+            .weg_items()
+            .pinned_apps()
+            .into_iter()
+            .find(|app| app.exe().eq_ignore_ascii_case(exe))
+    });
+    if let Some(app) = pinned {
+        return Ok(ActivateTarget::Pinned(
+            window_of(&windows, app.exe()),
+            app.execution_path().to_string(),
+        ));
+    }
+
+    match request.execution_path.or(request.exe) {
+        Some(path) => Ok(ActivateTarget::Other(path)),
+        None => Err("hwnd of an open window, exe or executionPath is required".into()),
+    }
+}
+
+/// Same as clicking the item on the dock: opens, focuses or minimizes it.
+pub fn activate(target: ActivateTarget) -> Result<()> {
+    match target {
+        ActivateTarget::Window(hwnd) => {
+            weg_toggle_window_state(hwnd, WindowsApi::exe_path(HWND(hwnd))?)
+        }
+        ActivateTarget::Pinned(hwnd, execution_path) => {
+            weg_toggle_window_state(hwnd.unwrap_or(0), execution_path)
+        }
+        ActivateTarget::Other(path) => weg_toggle_window_state(0, path),
+    }
+}
+
+pub fn modules() -> serde_json::Map<String, serde_json::Value> {
+    let state = FULL_STATE.load();
+    Settings::TOGGLEABLE_MODULES
+        .iter()
+        .filter_map(|name| {
+            let enabled = state.settings().module_enabled(name)?;
+            Some((name.to_string(), enabled.into()))
+        })
+        .collect()
+}
+
+/// Toggled modules are applied by the settings file watcher as any other settings change.
+pub fn toggle_module(name: &str) -> Result<bool> {
+    FULL_STATE.load().cloned().toggle_module(name)
+}
+
+/// Buttons for http request plugins of the Stream Deck (e.g. "API Ninja" or "Web Requests"),
+/// one per dock item and module, printed by `seelen-ui remote-api stream-deck-profile`.
+pub fn profile() -> Result<String> {
+    if !FULL_STATE.load().is_remote_api_enabled() {
+        return Err("Remote API is disabled, enable it on the settings first".into());
+    }
+    let port = FULL_STATE.load().settings().remote_api.port;
+    let token = RemoteApi::token()?;
+    let url = |path: &str| format!("http://127.0.0.1:{port}{path}");
+    let headers = serde_json::json!({
+        "Authorization": format!("Bearer {token}"),
+        "Content-Type": "application/json",
+    });
+
+    let mut buttons = Vec::new();
+    for item in dock_items()? {
+        let body = match item.kind {
+            DockItemKind::Pinned => serde_json::json!({ "exe": item.exe }),
+            DockItemKind::Window => serde_json::json!({ "hwnd": item.hwnd }),
+        };
+        buttons.push(serde_json::json!({
+            "title": item.title,
+            "icon": item.icon,
+            "method": "POST",
+            "url": url("/api/dock/activate"),
+            "headers": headers,
+            "body": body.to_string(),
+        }));
+    }
+
+    for name in Settings::TOGGLEABLE_MODULES {
+        buttons.push(serde_json::json!({
+            "title": name,
+            "method": "POST",
+            "url": url(&format!("/api/modules/{name}/toggle")),
+            "headers": headers,
+        }));
+    }
+
+    Ok(serde_json::to_string_pretty(&serde_json::json!({
+        "name": "Seelen UI",
+        "events": url(&format!(
+            "/api/events?events=global-focus-changed,active-workspace-changed&token={token}"
+        )),
+        "buttons": buttons,
+    }))?)
+}
@@ -0,0 +1,263 @@
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use base64::Engine;
+use crossbeam_channel::{unbounded, Sender};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Listener;
+
+use crate::{error_handler::Result, log_error, seelen::get_app_handle, trace_lock};
+
+use super::http::Request;
+
+/// events forwarded to the websocket clients, as the payload emitted to the webviews
+const FORWARDED_EVENTS: [&str; 5] = [
+    "workspaces-changed",
+    "active-workspace-changed",
+    "global-focus-changed",
+    "weg-resource-usage",
+    "data-source-changed",
+];
+
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+struct Client {
+    sender: Sender<Frame>,
+    /// subscribed events, all if none
+    events: Option<Vec<String>>,
+}
+
+impl Client {
+    fn is_subscribed(&self, event: &str) -> bool {
+        self.events
+            .as_ref()
+            .map_or(true, |events| events.iter().any(|e| e == event))
+    }
+}
+
+lazy_static! {
+    static ref CLIENTS: Mutex<Vec<Client>> = Mutex::new(Vec::new());
+}
+
+static FORWARDING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone)]
+struct Frame {
+    opcode: u8,
+    payload: Vec<u8>,
+}
+
+/// Minimal SHA-1, only used to compute the handshake accept key as required by RFC 6455.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (state, value) in h.iter_mut().zip([a, b, c, d, e]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+    for (i, state) in h.iter().enumerate() {
+        digest[i * 4..i * 4 + 4].copy_from_slice(&state.to_be_bytes());
+    }
+    digest
+}
+
+fn accept_key(key: &str) -> String {
+    const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+    base64::engine::general_purpose::STANDARD.encode(sha1(format!("{key}{GUID}").as_bytes()))
+}
+
+fn write_frame(stream: &mut TcpStream, frame: &Frame) -> Result<()> {
+    let mut head = vec![0x80 | frame.opcode];
+    let len = frame.payload.len();
+    match len {
+        0..=125 => head.push(len as u8),
+        126..=0xFFFF => {
+            head.push(126);
+            head.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        _ => {
+            head.push(127);
+            head.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    stream.write_all(&head)?;
+    stream.write_all(&frame.payload)?;
+    Ok(())
+}
+
+/// client frames are always masked, fragmented messages are not expected from the clients
+fn read_frame(stream: &mut TcpStream) -> Result<Frame> {
+    let mut head = [0u8; 2];
+    stream.read_exact(&mut head)?;
+    let opcode = head[0] & 0x0F;
+    let masked = head[1] & 0x80 != 0;
+    let len = match head[1] & 0x7F {
+        126 => {
+            let mut len = [0u8; 2];
+            stream.read_exact(&mut len)?;
+            u16::from_be_bytes(len) as u64
+        }
+        127 => {
+            let mut len = [0u8; 8];
+            stream.read_exact(&mut len)?;
+            u64::from_be_bytes(len)
+        }
+        len => len as u64,
+    };
+    // clients only send control frames, which are limited to 125 bytes
+    if len > 125 {
+        return Err("websocket frame too large".into());
+    }
+
+    let mut mask = [0u8; 4];
+    if masked {
+        stream.read_exact(&mut mask)?;
+    }
+    let mut payload = vec![0u8; len as usize];
+    stream.read_exact(&mut payload)?;
+    if masked {
+        for (i, byte) in payload.iter_mut().enumerate() {
+            *byte ^= mask[i % 4];
+        }
+    }
+    Ok(Frame { opcode, payload })
+}
+
+fn broadcast(event: &str, frame: Frame) {
+    trace_lock!(CLIENTS)
+        .retain(|client| !client.is_subscribed(event) || client.sender.send(frame.clone()).is_ok());
+}
+
+/// the listeners are kept for the app lifetime, without clients they only skip the event
+fn forward_events() {
+    if FORWARDING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    let handle = get_app_handle();
+    for event in FORWARDED_EVENTS {
+        handle.listen_any(event, move |e| {
+            if trace_lock!(CLIENTS).is_empty() {
+                return;
+            }
+            // payloads are already json, so they are embedded as they come
+            let message = format!(r#"{{"event":"{}","payload":{}}}"#, event, e.payload());
+            broadcast(
+                event,
+                Frame {
+                    opcode: OPCODE_TEXT,
+                    payload: message.into_bytes(),
+                },
+            );
+        });
+    }
+}
+
+/// Upgrades the connection and keeps it open until the client closes it, the stream is
+/// written by a second thread that receives the forwarded events.
+/// Clients can subscribe to some of the events as `/api/events?events=name,other-name`.
+pub fn serve(mut stream: TcpStream, request: &Request) -> Result<()> {
+    let key = request
+        .header("sec-websocket-key")
+        .ok_or("missing websocket key")?;
+    let handshake = format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+        Upgrade: websocket\r\n\
+        Connection: Upgrade\r\n\
+        Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(key)
+    );
+    stream.write_all(handshake.as_bytes())?;
+    forward_events();
+
+    let (sender, receiver) = unbounded::<Frame>();
+    trace_lock!(CLIENTS).push(Client {
+        sender: sender.clone(),
+        events: request
+            .query("events")
+            .map(|events| events.split(',').map(String::from).collect()),
+    });
+
+    let mut writer = stream.try_clone()?;
+    std::thread::spawn(move || {
+        for frame in receiver {
+            let closing = frame.opcode == OPCODE_CLOSE;
+            if write_frame(&mut writer, &frame).is_err() || closing {
+                break;
+            }
+        }
+    });
+
+    loop {
+        let frame = match read_frame(&mut stream) {
+            Ok(frame) => frame,
+            Err(err) => {
+                log::trace!("Remote API websocket closed: {:?}", err);
+                break;
+            }
+        };
+        match frame.opcode {
+            OPCODE_CLOSE => break,
+            OPCODE_PING => log_error!(sender
+                .send(Frame {
+                    opcode: OPCODE_PONG,
+                    payload: frame.payload,
+                })
+                .map_err(|_| "websocket writer closed")),
+            _ => {}
+        }
+    }
+
+    trace_lock!(CLIENTS).retain(|client| !client.sender.same_channel(&sender));
+    // the writer thread ends with the close frame
+    let _ = sender.send(Frame {
+        opcode: OPCODE_CLOSE,
+        payload: Vec::new(),
+    });
+    Ok(())
+}
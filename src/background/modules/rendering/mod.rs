@@ -0,0 +1,107 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tauri::{Listener, WebviewWindow};
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    log_error,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    windows_api::{Toast, WindowsApi},
+};
+
+/// max time for the webview to report that it finished loading
+const READY_TIMEOUT: Duration = Duration::from_secs(20);
+/// time given to the webview to paint after it reported to be ready
+const PAINT_DELAY: Duration = Duration::from_secs(3);
+
+static FALLBACK_TRIGGERED: AtomicBool = AtomicBool::new(false);
+
+/// Some gpu drivers fail to compose transparent WebView2 windows, ending in an invisible
+/// or fully black dock/toolbar. This detects those failures and enables the rendering
+/// fallback setting (software rendering and no backdrop effects).
+pub struct RenderingProbe;
+
+impl RenderingProbe {
+    /// Should be called before creating any webview as the browser arguments are
+    /// shared by all the webviews of the process.
+    pub fn apply_fallback() {
+        if FULL_STATE.load().settings().rendering_fallback {
+            log::warn!("Rendering fallback enabled, gpu acceleration is disabled on webviews");
+            std::env::set_var("WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS", "--disable-gpu");
+        }
+    }
+
+    /// Checks the first render of the window, `ready_event` should be emitted by
+    /// the webview once it is loaded.
+    pub fn watch(window: &WebviewWindow, ready_event: &str) {
+        if FULL_STATE.load().settings().rendering_fallback {
+            return;
+        }
+
+        let ready = Arc::new(AtomicBool::new(false));
+        let label = window.label().to_string();
+
+        if let Ok(hwnd) = window.hwnd() {
+            let ready = ready.clone();
+            let label = label.clone();
+            let hwnd = hwnd.0;
+            window.once(ready_event, move |_| {
+                ready.store(true, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    std::thread::sleep(PAINT_DELAY);
+                    Self::check_paint(&label, HWND(hwnd));
+                });
+            });
+        }
+
+        std::thread::spawn(move || {
+            std::thread::sleep(READY_TIMEOUT);
+            if !ready.load(Ordering::SeqCst) {
+                Self::on_failure(&label, "the webview did not finish loading");
+            }
+        });
+    }
+
+    fn check_paint(label: &str, hwnd: HWND) {
+        // hidden windows can't be captured, e.g. weg hidden by a fullscreen app
+        if !WindowsApi::is_window_visible(hwnd) || WindowsApi::is_iconic(hwnd) {
+            return;
+        }
+        let Some(image) = SeelenWeg::capture_window(hwnd) else {
+            return;
+        };
+        let image = image.to_rgba8();
+        // a transparent webview can't be completely opaque black unless the gpu failed
+        let is_black = !image.is_empty() && image.pixels().all(|pixel| pixel.0 == [0, 0, 0, 255]);
+        if is_black {
+            Self::on_failure(label, "the webview was painted completely black");
+        }
+    }
+
+    fn on_failure(label: &str, reason: &str) {
+        if FALLBACK_TRIGGERED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        log::error!("Rendering failure detected on {}: {}", label, reason);
+
+        let mut state = FULL_STATE.load().cloned();
+        match state.enable_rendering_fallback() {
+            Ok(true) => {
+                state.store();
+                log_error!(Toast::show(
+                    "Seelen UI detected a rendering problem with your graphics driver. \
+                    Compatibility rendering was enabled, restart Seelen UI to apply it."
+                ));
+            }
+            Ok(false) => {}
+            Err(err) => log::error!("Failed to enable rendering fallback: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::ScreenRuler;
+
+get_subcommands![
+    /** Opens the screen ruler if it is closed, otherwise closes it */
+    Toggle,
+    /** Opens the screen ruler over the monitor under the cursor */
+    Show,
+    /** Closes the screen ruler */
+    Hide,
+];
+
+impl ScreenRuler {
+    pub const CLI_IDENTIFIER: &'static str = "ruler";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Measures the distances between window edges")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Toggle => Self::toggle()?,
+            SubCommand::Show => Self::show()?,
+            SubCommand::Hide => Self::hide(),
+        };
+        Ok(())
+    }
+}
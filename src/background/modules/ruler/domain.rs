@@ -0,0 +1,154 @@
+use seelen_core::rect::Rect;
+use serde::Serialize;
+use windows::Win32::Foundation::{POINT, RECT};
+
+/// Nearest window edges around the cursor, the monitor edges if there is none
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct Guides {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+/// Everything is relative to the monitor and in physical pixels
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Measurement {
+    pub x: i32,
+    pub y: i32,
+    /// topmost window under the cursor
+    pub hovered: Option<Rect>,
+    pub guides: Guides,
+}
+
+impl Measurement {
+    /// `rects` are the windows of the monitor sorted by z-order, topmost first
+    pub fn measure(cursor: POINT, monitor: &RECT, rects: &[RECT]) -> Self {
+        let (x, y) = (cursor.x, cursor.y);
+        let mut guides = Guides {
+            left: monitor.left,
+            top: monitor.top,
+            right: monitor.right,
+            bottom: monitor.bottom,
+        };
+
+        for rect in rects {
+            if y >= rect.top && y < rect.bottom {
+                for edge in [rect.left, rect.right] {
+                    if edge <= x && edge > guides.left {
+                        guides.left = edge;
+                    }
+                    if edge > x && edge < guides.right {
+                        guides.right = edge;
+                    }
+                }
+            }
+            if x >= rect.left && x < rect.right {
+                for edge in [rect.top, rect.bottom] {
+                    if edge <= y && edge > guides.top {
+                        guides.top = edge;
+                    }
+                    if edge > y && edge < guides.bottom {
+                        guides.bottom = edge;
+                    }
+                }
+            }
+        }
+
+        let relative = |rect: &RECT| RECT {
+            left: rect.left - monitor.left,
+            top: rect.top - monitor.top,
+            right: rect.right - monitor.left,
+            bottom: rect.bottom - monitor.top,
+        };
+        let hovered = rects
+            .iter()
+            .find(|r| x >= r.left && x < r.right && y >= r.top && y < r.bottom)
+            .map(|rect| relative(rect).into());
+
+        Self {
+            x: x - monitor.left,
+            y: y - monitor.top,
+            hovered,
+            guides: Guides {
+                left: guides.left - monitor.left,
+                top: guides.top - monitor.top,
+                right: guides.right - monitor.left,
+                bottom: guides.bottom - monitor.top,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONITOR: RECT = RECT {
+        left: 1920,
+        top: 0,
+        right: 3840,
+        bottom: 1080,
+    };
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+        RECT {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn guides_fall_back_to_the_monitor() {
+        let m = Measurement::measure(POINT { x: 2000, y: 500 }, &MONITOR, &[]);
+        assert_eq!(m.x, 80);
+        assert_eq!(m.y, 500);
+        assert_eq!(m.hovered, None);
+        assert_eq!(
+            m.guides,
+            Guides {
+                left: 0,
+                top: 0,
+                right: 1920,
+                bottom: 1080
+            }
+        );
+    }
+
+    #[test]
+    fn guides_snap_to_the_nearest_edges() {
+        // a toolbar on top, a window under the cursor and another on its right
+        let rects = [
+            rect(1920, 0, 3840, 30),
+            rect(2000, 100, 2600, 700),
+            rect(2700, 50, 3000, 900),
+        ];
+        let m = Measurement::measure(POINT { x: 2100, y: 400 }, &MONITOR, &rects);
+        assert_eq!(m.hovered, Some(rect(80, 100, 680, 700).into()));
+        assert_eq!(
+            m.guides,
+            Guides {
+                left: 80,
+                top: 100,
+                right: 680,
+                bottom: 700
+            }
+        );
+
+        // between the two windows the guides are their facing edges
+        let m = Measurement::measure(POINT { x: 2650, y: 400 }, &MONITOR, &rects);
+        assert_eq!(m.hovered, None);
+        assert_eq!(
+            m.guides,
+            Guides {
+                left: 680,
+                top: 30,
+                right: 780,
+                bottom: 1080
+            }
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use crate::error_handler::Result;
+
+use super::ScreenRuler;
+
+#[tauri::command(async)]
+pub fn screen_ruler_toggle() -> Result<()> {
+    ScreenRuler::toggle()
+}
+
+#[tauri::command(async)]
+pub fn screen_ruler_is_open() -> bool {
+    ScreenRuler::is_open()
+}
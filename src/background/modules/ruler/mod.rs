@@ -0,0 +1,163 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use domain::Measurement;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::{Emitter, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SWP_NOACTIVATE, SW_SHOWNOACTIVATE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::Mouse,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{overlay::OverlayBuilder, sleep_millis, spawn_named_thread},
+    windows_api::{WindowEnumerator, WindowsApi},
+};
+
+lazy_static! {
+    static ref SCREEN_RULER: Arc<Mutex<Option<ScreenRuler>>> = Arc::new(Mutex::new(None));
+}
+
+/// Incremented on each show/hide, the measuring thread exits when it changes
+static RULER_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+/// Click-through overlay showing the distances from the cursor to the nearest window edges,
+/// used by theme authors to align the dock and the toolbar. The measurements are also emitted
+/// to the settings window.
+pub struct ScreenRuler {
+    window: WebviewWindow<Wry>,
+    monitor: HMONITOR,
+}
+
+impl Drop for ScreenRuler {
+    fn drop(&mut self) {
+        log::trace!("Closing screen ruler");
+        log_error!(self.window.destroy());
+    }
+}
+
+impl ScreenRuler {
+    const TARGET: &'static str = "screen-ruler";
+    const INTERVAL: u64 = 30;
+
+    fn new() -> Result<Self> {
+        let cursor = Mouse::get_cursor_pos()?;
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+
+        let window = OverlayBuilder::new(
+            Self::TARGET,
+            "screen-ruler/index.html",
+            "Seelen Screen Ruler",
+        )
+        .unfocused()
+        .build()?;
+
+        // the ruler should never steal the focus nor the clicks of the measured windows
+        let hwnd = HWND(window.hwnd()?.0);
+        WindowsApi::set_position(hwnd, Some(HWND_TOPMOST), &monitor_rect, SWP_NOACTIVATE)?;
+        WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+        window.set_ignore_cursor_events(true)?;
+        Ok(Self { window, monitor })
+    }
+
+    pub fn is_open() -> bool {
+        trace_lock!(SCREEN_RULER).is_some()
+    }
+
+    pub fn show() -> Result<()> {
+        let mut ruler = trace_lock!(SCREEN_RULER);
+        if ruler.is_some() {
+            return Ok(());
+        }
+        *ruler = Some(Self::new()?);
+
+        let generation = RULER_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+        spawn_named_thread("Screen Ruler", move || {
+            let mut last = None;
+            while RULER_GENERATION.load(Ordering::Acquire) == generation {
+                match Self::measure() {
+                    Ok(measurement) if last.as_ref() != Some(&measurement) => {
+                        log_error!(Self::emit(&measurement));
+                        last = Some(measurement);
+                    }
+                    Err(err) => log::trace!("Failed to measure: {:?}", err),
+                    _ => {}
+                }
+                sleep_millis(Self::INTERVAL);
+            }
+        })?;
+        get_app_handle().emit("screen-ruler-open", true)?;
+        Ok(())
+    }
+
+    pub fn hide() {
+        RULER_GENERATION.fetch_add(1, Ordering::AcqRel);
+        if trace_lock!(SCREEN_RULER).take().is_some() {
+            log_error!(get_app_handle().emit("screen-ruler-open", false));
+        }
+    }
+
+    pub fn toggle() -> Result<()> {
+        if Self::is_open() {
+            Self::hide();
+            Ok(())
+        } else {
+            Self::show()
+        }
+    }
+
+    /// Visible windows intersecting the monitor, topmost first
+    fn window_rects(monitor: &RECT, ruler: HWND) -> Result<Vec<RECT>> {
+        let mut rects = Vec::new();
+        WindowEnumerator::new().for_each(|hwnd| {
+            if hwnd == ruler
+                || !WindowsApi::is_window_visible(hwnd)
+                || WindowsApi::is_iconic(hwnd)
+                || WindowsApi::is_cloaked(hwnd).unwrap_or(false)
+            {
+                return;
+            }
+            let rect = WindowsApi::get_window_rect_without_margins(hwnd);
+            let intersects = rect.left < monitor.right
+                && rect.right > monitor.left
+                && rect.top < monitor.bottom
+                && rect.bottom > monitor.top;
+            if intersects && rect.right > rect.left && rect.bottom > rect.top {
+                rects.push(rect);
+            }
+        })?;
+        Ok(rects)
+    }
+
+    fn measure() -> Result<Measurement> {
+        let (monitor, ruler) = match trace_lock!(SCREEN_RULER).as_ref() {
+            Some(ruler) => (ruler.monitor, HWND(ruler.window.hwnd()?.0)),
+            None => return Err("Screen ruler is not open".into()),
+        };
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+        let cursor = Mouse::get_cursor_pos()?;
+        let rects = Self::window_rects(&monitor_rect, ruler)?;
+        Ok(Measurement::measure(cursor.into(), &monitor_rect, &rects))
+    }
+
+    fn emit(measurement: &Measurement) -> Result<()> {
+        let handle = get_app_handle();
+        handle.emit_to(Self::TARGET, "screen-ruler-measurement", measurement)?;
+        handle.emit_to("settings", "screen-ruler-measurement", measurement)?;
+        Ok(())
+    }
+}
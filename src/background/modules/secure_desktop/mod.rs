@@ -0,0 +1,113 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::Emitter;
+use windows::Win32::{
+    Foundation::HANDLE,
+    System::StationsAndDesktops::{
+        CloseDesktop, GetUserObjectInformationW, OpenInputDesktop, DESKTOP_CONTROL_FLAGS,
+        DESKTOP_READOBJECTS, UOI_NAME,
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::{get_app_handle, SEELEN},
+    seelen_weg::zorder::ZOrderGuardian,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    winevent::WinEvent,
+};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Tracks the switches to the secure desktop (UAC prompts, Ctrl+Alt+Del, lock screen).
+/// While it is shown our windows and hooks are on an inactive desktop, so topmost
+/// re-asserts and input hooks are paused and the shell windows are resynchronized
+/// once the user is back on the default desktop.
+pub struct SecureDesktop;
+
+impl SecureDesktop {
+    const POLL_INTERVAL: u64 = 500;
+    /// time for the default desktop to be repainted before resynchronizing
+    const RESYNC_DELAY: u64 = 300;
+
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::Acquire)
+    }
+
+    /// Name of the desktop receiving the input, `None` if it can't be opened as
+    /// happens with the secure desktop of UAC.
+    fn input_desktop_name() -> Option<String> {
+        unsafe {
+            let desktop =
+                OpenInputDesktop(DESKTOP_CONTROL_FLAGS(0), false, DESKTOP_READOBJECTS).ok()?;
+            let mut name = [0u16; 256];
+            let mut needed = 0;
+            let result = GetUserObjectInformationW(
+                HANDLE(desktop.0),
+                UOI_NAME,
+                Some(name.as_mut_ptr().cast()),
+                std::mem::size_of_val(&name) as u32,
+                Some(&mut needed),
+            );
+            let _ = CloseDesktop(desktop);
+            result.ok()?;
+            Some(
+                String::from_utf16_lossy(&name)
+                    .trim_end_matches('\0')
+                    .to_string(),
+            )
+        }
+    }
+
+    fn is_secure_desktop_shown() -> bool {
+        Self::input_desktop_name().map_or(true, |name| !name.eq_ignore_ascii_case("Default"))
+    }
+
+    pub fn on_win_event(event: WinEvent) {
+        if event != WinEvent::SystemDesktopSwitch || Self::is_active() {
+            return;
+        }
+        if Self::is_secure_desktop_shown() {
+            log_error!(Self::on_enter());
+        }
+    }
+
+    /// The switch back is not always notified to our hook, so it is polled instead
+    fn on_enter() -> Result<()> {
+        if ACTIVE.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Secure desktop shown, pausing z-order and input handling");
+        get_app_handle().emit("secure-desktop-changed", true)?;
+        spawn_named_thread("Secure Desktop Watcher", || {
+            while Self::is_secure_desktop_shown() {
+                sleep_millis(Self::POLL_INTERVAL);
+            }
+            log_error!(Self::on_leave());
+        })?;
+        Ok(())
+    }
+
+    fn on_leave() -> Result<()> {
+        sleep_millis(Self::RESYNC_DELAY);
+        ACTIVE.store(false, Ordering::Release);
+        log::trace!("Back on the default desktop, resynchronizing");
+
+        let seelen = trace_lock!(SEELEN);
+        for monitor in seelen.monitors() {
+            if let Some(toolbar) = monitor.toolbar() {
+                log_error!(toolbar.restore_visibility());
+            }
+            if let Some(weg) = monitor.weg() {
+                log_error!(weg.restore_visibility());
+            }
+        }
+        drop(seelen);
+
+        ZOrderGuardian::request();
+        get_app_handle().emit("secure-desktop-changed", false)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::SessionRestore;
+
+get_subcommands![
+    /** Saves the apps currently running, they will be relaunched on the next login */
+    Save,
+    /** Relaunches the apps of the last saved session */
+    Restore,
+    /** Deletes the last saved session */
+    Discard,
+];
+
+impl SessionRestore {
+    pub const CLI_IDENTIFIER: &'static str = "session";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Restore of the running apps between sessions")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Save => Self::save()?,
+            SubCommand::Restore => Self::restore()?,
+            SubCommand::Discard => Self::discard()?,
+        };
+        Ok(())
+    }
+}
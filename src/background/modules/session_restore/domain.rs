@@ -0,0 +1,26 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::modules::window_memory::domain::StoredPlacement;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionApp {
+    pub path: PathBuf,
+    /// command line arguments, without the executable
+    pub args: Vec<String>,
+    /// device name of the monitor where the window was
+    pub monitor: Option<String>,
+    /// index of the virtual desktop where the window was
+    pub workspace: Option<usize>,
+    pub placement: StoredPlacement,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSnapshot {
+    /// unix timestamp in seconds
+    pub created_at: u64,
+    pub apps: Vec<SessionApp>,
+}
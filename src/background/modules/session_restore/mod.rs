@@ -0,0 +1,205 @@
+pub mod cli;
+pub mod domain;
+pub mod watcher;
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use domain::{SessionApp, SessionSnapshot};
+use sysinfo::{Pid, System};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{SWP_NOACTIVATE, SWP_NOZORDER, SW_MAXIMIZE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{virtual_desk::get_vd_manager, window_memory::domain::StoredPlacement},
+    seelen::get_app_handle,
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    utils::{app_data_path, spawn_named_thread},
+    windows_api::{WindowEnumerator, WindowsApi},
+};
+
+/// how long to wait for a relaunched app to show its window
+const WINDOW_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Records the apps running when the session ends and relaunches them on the next login,
+/// placing their windows back on the same monitor and workspace.
+pub struct SessionRestore;
+
+impl SessionRestore {
+    const FILENAME: &'static str = "last_session.json";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn is_excluded(path: &Path) -> bool {
+        let path = path.to_string_lossy().to_lowercase();
+        // packaged apps can't be launched by path, and the shell is managed by the system
+        if path.contains("\\windowsapps\\")
+            || path.ends_with("\\explorer.exe")
+            || path.ends_with("seelen-ui.exe")
+        {
+            return true;
+        }
+        FULL_STATE
+            .load()
+            .settings()
+            .session_restore
+            .exclude
+            .iter()
+            .any(|exclude| path.ends_with(&exclude.to_lowercase()))
+    }
+
+    /// One entry per process, using its first visible app window for the placement
+    pub fn capture() -> Result<SessionSnapshot> {
+        let mut sys = System::new();
+        sys.refresh_processes();
+
+        let vd = get_vd_manager();
+        let workspaces = vd.get_all()?;
+
+        let mut seen = HashSet::new();
+        let mut apps = Vec::new();
+        WindowEnumerator::new().for_each(|hwnd| {
+            if !SeelenWeg::should_be_added(hwnd) {
+                return;
+            }
+            let (pid, _) = WindowsApi::window_thread_process_id(hwnd);
+            if !seen.insert(pid) {
+                return;
+            }
+            let Some(process) = sys.process(Pid::from_u32(pid)) else {
+                return;
+            };
+            let Some(path) = process.exe() else {
+                return;
+            };
+            if Self::is_excluded(path) {
+                return;
+            }
+
+            let workspace = vd
+                .get_by_window(hwnd.0)
+                .ok()
+                .and_then(|desktop| workspaces.iter().position(|w| w.id() == desktop.id()));
+            apps.push(SessionApp {
+                path: path.to_path_buf(),
+                args: process.cmd().iter().skip(1).cloned().collect(),
+                monitor: WindowsApi::monitor_name(WindowsApi::monitor_from_window(hwnd)).ok(),
+                workspace,
+                placement: StoredPlacement {
+                    rect: WindowsApi::get_window_rect(hwnd).into(),
+                    maximized: WindowsApi::is_maximized(hwnd),
+                },
+            });
+        })?;
+
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        Ok(SessionSnapshot { created_at, apps })
+    }
+
+    pub fn save() -> Result<()> {
+        let snapshot = Self::capture()?;
+        log::info!("Saving session with {} apps", snapshot.apps.len());
+        std::fs::write(Self::path(), serde_json::to_string_pretty(&snapshot)?)?;
+        Ok(())
+    }
+
+    pub fn discard() -> Result<()> {
+        let path = Self::path();
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Relaunches the apps of the last saved session that are not already running
+    /// (started by the system or other startup entries). The snapshot is consumed so
+    /// a crash while restoring doesn't relaunch the apps again on the next start.
+    pub fn restore() -> Result<()> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(());
+        }
+        let snapshot: SessionSnapshot = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        std::fs::remove_file(path)?;
+
+        let mut sys = System::new();
+        sys.refresh_processes();
+        let running = sys
+            .processes()
+            .values()
+            .filter_map(|p| p.exe().map(|exe| exe.to_path_buf()))
+            .collect::<HashSet<PathBuf>>();
+
+        for app in snapshot.apps {
+            if running.contains(&app.path) || !app.path.exists() {
+                continue;
+            }
+            log::trace!("Relaunching {}", app.path.display());
+            let child = match Command::new(&app.path).args(&app.args).spawn() {
+                Ok(child) => child,
+                Err(err) => {
+                    log::error!("Failed to relaunch {}: {:?}", app.path.display(), err);
+                    continue;
+                }
+            };
+            let pid = child.id();
+            spawn_named_thread("Session Restore", move || {
+                if let Some(hwnd) = Self::wait_for_window(pid, &app.path) {
+                    log_error!(Self::place(hwnd, &app));
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Some apps use a launcher process, so the window is matched by pid or executable
+    fn wait_for_window(pid: u32, path: &Path) -> Option<HWND> {
+        let started = Instant::now();
+        while started.elapsed() < WINDOW_TIMEOUT {
+            let mut found = None;
+            log_error!(WindowEnumerator::new().for_each(|hwnd| {
+                if found.is_some() || !SeelenWeg::should_be_added(hwnd) {
+                    return;
+                }
+                let (window_pid, _) = WindowsApi::window_thread_process_id(hwnd);
+                if window_pid == pid || WindowsApi::exe_path_v2(hwnd).is_ok_and(|exe| exe == path) {
+                    found = Some(hwnd);
+                }
+            }));
+            if found.is_some() {
+                return found;
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+        None
+    }
+
+    fn place(hwnd: HWND, app: &SessionApp) -> Result<()> {
+        if let Some(workspace) = app.workspace {
+            get_vd_manager().send_to(workspace, hwnd.0)?;
+        }
+        if WindowsApi::is_maximized(hwnd) {
+            WindowsApi::unmaximize_window(hwnd)?;
+        }
+        let rect: RECT = app.placement.rect.clone().into();
+        WindowsApi::set_position(hwnd, None, &rect, SWP_NOACTIVATE | SWP_NOZORDER)?;
+        if app.placement.maximized {
+            WindowsApi::show_window_async(hwnd, SW_MAXIMIZE)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use windows::{
+    core::{w, PCWSTR},
+    Win32::{
+        Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            PostMessageW, PostQuitMessage, RegisterClassW, TranslateMessage, MSG, WINDOW_EX_STYLE,
+            WINDOW_STYLE, WM_CLOSE, WM_DESTROY, WM_ENDSESSION, WM_QUERYENDSESSION, WNDCLASSW,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result, log_error, modules::shutdown::Shutdown, state::application::FULL_STATE,
+    utils::spawn_named_thread, windows_api::WindowsApi,
+};
+
+use super::SessionRestore;
+
+/// hidden window receiving the session end messages, 0 if the watcher is not running
+static WATCHER_HWND: AtomicIsize = AtomicIsize::new(0);
+
+/// Saves the running apps (if session restore is enabled) when the user logs off or shuts down,
+/// and shuts down Seelen UI before the system kills it. Only top-level windows receive the
+/// session end messages, so a message-only window can't be used here.
+pub struct SessionWatcher;
+
+impl SessionWatcher {
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            // the apps are still running at this point, the session end can be cancelled
+            WM_QUERYENDSESSION => {
+                if FULL_STATE.load().is_session_restore_enabled() {
+                    log_error!(SessionRestore::save());
+                }
+                LRESULT(1)
+            }
+            // the user or an app cancelled the logoff, the session continues
+            WM_ENDSESSION if w_param.0 == 0 => {
+                if FULL_STATE.load().is_session_restore_enabled() {
+                    log_error!(SessionRestore::discard());
+                }
+                LRESULT(0)
+            }
+            WM_ENDSESSION => {
+                Shutdown::run(true);
+                LRESULT(0)
+            }
+            WM_CLOSE => {
+                log_error!(DestroyWindow(hwnd));
+                LRESULT(0)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if WATCHER_HWND.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Starting session watcher");
+
+        let class_name = w!("SeelenSessionWatcher");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        spawn_named_thread("Session Watcher", move || unsafe {
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+            WATCHER_HWND.store(hwnd.0, Ordering::Release);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            WATCHER_HWND.store(0, Ordering::Release);
+            log::trace!("Session watcher stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let hwnd = WATCHER_HWND.load(Ordering::Acquire);
+        if hwnd != 0 {
+            log_error!(unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) });
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::ShellMode;
+
+get_subcommands![
+    /** Sets Seelen UI as the shell of the current user, takes effect on the next login */
+    Register,
+    /** Restores explorer as the shell of the current user, takes effect on the next login */
+    Unregister,
+    /** Launches explorer, if no shell is running it will also restore the desktop and taskbar */
+    Explorer,
+    /** Unregisters Seelen UI as shell and launches explorer, useful from the Task Manager */
+    Restore,
+];
+
+impl ShellMode {
+    pub const CLI_IDENTIFIER: &'static str = "shell";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Full shell mode, run Seelen UI instead of explorer")
+            .long_about(
+                "Full shell mode, run Seelen UI instead of explorer. \
+                These commands don't need Seelen UI to be running.",
+            )
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Register => Self::register()?,
+            SubCommand::Unregister => Self::unregister()?,
+            SubCommand::Explorer => Self::launch_explorer()?,
+            SubCommand::Restore => Self::restore()?,
+        };
+        Ok(())
+    }
+}
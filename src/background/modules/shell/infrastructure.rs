@@ -0,0 +1,27 @@
+use crate::error_handler::Result;
+
+use super::ShellMode;
+
+#[tauri::command(async)]
+pub fn shell_is_registered() -> bool {
+    ShellMode::is_registered()
+}
+
+#[tauri::command(async)]
+pub fn shell_is_active() -> bool {
+    ShellMode::is_active()
+}
+
+#[tauri::command(async)]
+pub fn shell_set_registered(registered: bool) -> Result<()> {
+    if registered {
+        ShellMode::register()
+    } else {
+        ShellMode::unregister()
+    }
+}
+
+#[tauri::command(async)]
+pub fn shell_launch_explorer() -> Result<()> {
+    ShellMode::launch_explorer()
+}
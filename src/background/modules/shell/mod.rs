@@ -0,0 +1,92 @@
+pub mod cli;
+pub mod infrastructure;
+
+use std::{
+    process::Command,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use windows::Win32::UI::WindowsAndMessaging::GetShellWindow;
+use winreg::{
+    enums::{HKEY_CURRENT_USER, KEY_READ, KEY_WRITE},
+    RegKey,
+};
+
+use crate::error_handler::Result;
+
+/// per user override of the shell launched by winlogon at login
+const WINLOGON_KEY: &str = r"Software\Microsoft\Windows NT\CurrentVersion\Winlogon";
+
+/// true if Seelen UI was started as the shell (registered and explorer was not running)
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Full shell mode, Seelen UI replaces explorer.exe as the shell of the current user.
+/// Explorer can still be launched on demand, and it is launched automatically if
+/// Seelen UI exits or crashes so the session is never left without a shell.
+pub struct ShellMode;
+
+impl ShellMode {
+    pub fn is_registered() -> bool {
+        RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey_with_flags(WINLOGON_KEY, KEY_READ)
+            .and_then(|key| key.get_value::<String, _>("Shell"))
+            .is_ok_and(|shell| shell.to_lowercase().contains("seelen-ui.exe"))
+    }
+
+    /// Takes effect on the next login
+    pub fn register() -> Result<()> {
+        let exe = std::env::current_exe()?;
+        let (key, _) = RegKey::predef(HKEY_CURRENT_USER).create_subkey(WINLOGON_KEY)?;
+        key.set_value("Shell", &format!("\"{}\" --silent", exe.display()))?;
+        Ok(())
+    }
+
+    /// Removes the per user override so the machine shell (explorer.exe) is used again
+    pub fn unregister() -> Result<()> {
+        let key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey_with_flags(WINLOGON_KEY, KEY_READ | KEY_WRITE)?;
+        if key.get_raw_value("Shell").is_ok() {
+            key.delete_value("Shell")?;
+        }
+        Ok(())
+    }
+
+    /// Should be called at startup, before any window of the app is created
+    pub fn init() {
+        let no_shell_running = unsafe { GetShellWindow() }.0 == 0;
+        if Self::is_registered() && no_shell_running {
+            log::info!("Running as the shell of the session");
+            ACTIVE.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::SeqCst)
+    }
+
+    /// If no shell is running explorer will take the role, restoring the desktop and taskbar.
+    pub fn launch_explorer() -> Result<()> {
+        Command::new("explorer.exe").spawn()?;
+        Ok(())
+    }
+
+    /// Called on exit and on panics, launches explorer if we were the shell of the session
+    pub fn recover() {
+        if ACTIVE.swap(false, Ordering::SeqCst) {
+            log::warn!("Seelen UI is no longer running as shell, launching explorer");
+            if let Err(err) = Self::launch_explorer() {
+                log::error!("Failed to launch explorer: {:?}", err);
+            }
+        }
+    }
+
+    /// Unregisters the shell and starts explorer, meant to be run from the Task Manager
+    /// (Ctrl+Alt+Del > Task Manager > Run new task) if the session is left unusable.
+    pub fn restore() -> Result<()> {
+        Self::unregister()?;
+        if unsafe { GetShellWindow() }.0 == 0 {
+            Self::launch_explorer()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use tauri::{Emitter, Listener, Manager};
+use windows::Win32::{
+    Foundation::BOOL,
+    System::Console::{
+        SetConsoleCtrlHandler, CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT, CTRL_C_EVENT, CTRL_LOGOFF_EVENT,
+        CTRL_SHUTDOWN_EVENT,
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::window_memory::WindowMemory,
+    seelen::{get_app_handle, SEELEN},
+    trace_lock,
+};
+
+/// true once the teardown was done, the exit of the app and the session end can both trigger it
+static DONE: AtomicBool = AtomicBool::new(false);
+
+/// Teardown shared by the exit of the app and the end of the Windows session (logoff, shutdown
+/// or restart). On session end the system kills the process right after the notification, so
+/// the pending writes are flushed and the native taskbar is restored before returning.
+pub struct Shutdown;
+
+impl Shutdown {
+    /// Labels (before the monitor postfix) of the webviews that write debounced changes, like
+    /// the pinned items of the dock, they answer `flush-pending-writes` once written.
+    const FLUSHING_WEBVIEWS: [&'static str; 1] = ["seelenweg"];
+    /// max time waiting for the answers, a webview still loading never answers and Windows
+    /// kills the apps that take too long to end the session
+    const FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// `flush_webviews` is false on the exit of the app, as the webviews are already closed and
+    /// their answers are handled by the main thread, which is the one running the teardown.
+    pub fn run(flush_webviews: bool) {
+        if DONE.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        log::info!("Shutting down");
+        log_error!(WindowMemory::flush());
+        if flush_webviews {
+            log_error!(Self::flush_webviews());
+        }
+        trace_lock!(SEELEN).stop();
+    }
+
+    /// Asks the webviews to write their pending changes and waits for each of them to answer
+    fn flush_webviews() -> Result<()> {
+        let handle = get_app_handle();
+        let mut pending: HashSet<String> = handle
+            .webview_windows()
+            .into_keys()
+            .filter(|label| {
+                let target = label.split('/').next().unwrap_or_default();
+                Self::FLUSHING_WEBVIEWS.contains(&target)
+            })
+            .collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let (tx, rx) = crossbeam_channel::unbounded::<String>();
+        let listener = handle.listen_any("pending-writes-flushed", move |event| {
+            if let Ok(label) = serde_json::from_str::<String>(event.payload()) {
+                let _ = tx.send(label);
+            }
+        });
+        handle.emit("flush-pending-writes", ())?;
+
+        let deadline = Instant::now() + Self::FLUSH_TIMEOUT;
+        while !pending.is_empty() {
+            match rx.recv_deadline(deadline) {
+                Ok(label) => {
+                    pending.remove(&label);
+                }
+                Err(_) => {
+                    log::warn!("Pending writes of {:?} were not flushed in time", pending);
+                    break;
+                }
+            }
+        }
+        handle.unlisten(listener);
+        Ok(())
+    }
+
+    /// Called on a new thread created by the system
+    unsafe extern "system" fn console_handler(ctrl_type: u32) -> BOOL {
+        match ctrl_type {
+            // the session is ending, the process is killed after returning
+            CTRL_LOGOFF_EVENT | CTRL_SHUTDOWN_EVENT => {
+                Self::run(true);
+                BOOL::from(true)
+            }
+            CTRL_C_EVENT | CTRL_BREAK_EVENT | CTRL_CLOSE_EVENT => {
+                Self::run(true);
+                get_app_handle().exit(0);
+                BOOL::from(true)
+            }
+            _ => BOOL::from(false),
+        }
+    }
+
+    /// The console events are only received while attached to a console, like on development
+    pub fn listen_console_events() -> Result<()> {
+        unsafe { SetConsoleCtrlHandler(Some(Self::console_handler), true)? };
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+    thread::JoinHandle,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::{
+    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::{
+        Input::KeyboardAndMouse::{
+            GetKeyState, ToUnicodeEx, VIRTUAL_KEY, VK_BACK, VK_CAPITAL, VK_CONTROL, VK_LCONTROL,
+            VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU, VK_RCONTROL, VK_RMENU, VK_RSHIFT, VK_RWIN,
+            VK_SHIFT,
+        },
+        WindowsAndMessaging::{
+            CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+            TranslateMessage, UnhookWindowsHookEx, HC_ACTION, HHOOK, KBDLLHOOKSTRUCT,
+            LLKHF_INJECTED, MSG, WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{
+        input::{layout::KeyboardLayout, Keyboard},
+        secure_desktop::SecureDesktop,
+    },
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::spawn_named_thread,
+    windows_api::{window::Window, WindowsApi},
+};
+
+/// True from the moment the expander is started until its hook loop ends
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Thread id of the keyboard hook loop, 0 if the hook is not running
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Characters kept of the typed text, longer abbreviations are never expanded
+const MAX_TYPED: usize = 64;
+
+lazy_static! {
+    /// text typed on the foreground window since the last reset
+    static ref TYPED: Mutex<(isize, String)> = Mutex::new((0, String::new()));
+    static ref HOOK_THREAD: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+}
+
+/// Expands the abbreviations of the settings into their text while typing. The key that
+/// completes an abbreviation is swallowed, the rest of it is erased with backspaces and the
+/// text is typed in its place.
+pub struct SnippetExpander;
+
+impl SnippetExpander {
+    pub fn is_running() -> bool {
+        RUNNING.load(Ordering::Acquire)
+    }
+
+    pub fn start() -> Result<()> {
+        // claimed before spawning so two quick calls can not start two hooks
+        if RUNNING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Ok(());
+        }
+        log::trace!("Starting snippet expander");
+        if let Err(err) = Self::spawn_hook() {
+            RUNNING.store(false, Ordering::Release);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn spawn_hook() -> Result<()> {
+        let h_module = WindowsApi::module_handle_w()?;
+        let (ready_tx, ready_rx) = crossbeam_channel::bounded::<windows::core::Result<()>>(1);
+        let thread = spawn_named_thread("Snippets Keyboard Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            let hook = match SetWindowsHookExW(
+                WH_KEYBOARD_LL,
+                Some(keyboard_hook_proc),
+                HINSTANCE(h_module.0),
+                0,
+            ) {
+                Ok(hook) => hook,
+                Err(err) => {
+                    HOOK_THREAD_ID.store(0, Ordering::Release);
+                    let _ = ready_tx.send(Err(err));
+                    return;
+                }
+            };
+            let _ = ready_tx.send(Ok(()));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            log_error!(UnhookWindowsHookEx(hook));
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+            RUNNING.store(false, Ordering::Release);
+        })?;
+        // the thread id should be known before `stop` can be called
+        ready_rx.recv()??;
+        *trace_lock!(HOOK_THREAD) = Some(thread);
+        Ok(())
+    }
+
+    pub fn stop() {
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            log_error!(unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) });
+        }
+        // waited, so a `start` right after is not ignored because the old loop is still running
+        let thread = trace_lock!(HOOK_THREAD).take();
+        if let Some(thread) = thread {
+            if thread.join().is_err() {
+                log::error!("Snippets keyboard hook thread panicked");
+            }
+        }
+        trace_lock!(TYPED).1.clear();
+    }
+
+    /// Character produced by the key on the layout of the foreground window
+    fn to_char(data: &KBDLLHOOKSTRUCT, foreground: HWND) -> Option<char> {
+        let mut state = [0u8; 256];
+        for key in [VK_SHIFT, VK_CONTROL, VK_MENU] {
+            if Keyboard::is_pressed(key) {
+                state[key.0 as usize] = 0x80;
+            }
+        }
+        state[VK_CAPITAL.0 as usize] = unsafe { GetKeyState(VK_CAPITAL.0 as i32) } as u8 & 1;
+
+        let mut buffer = [0u16; 4];
+        // flag 4 keeps the dead keys state of the focused app untouched
+        let len = unsafe {
+            ToUnicodeEx(
+                data.vkCode,
+                data.scanCode,
+                &state,
+                &mut buffer,
+                4,
+                KeyboardLayout::hkl_of(foreground),
+            )
+        };
+        if len != 1 {
+            return None;
+        }
+        char::from_u32(buffer[0] as u32).filter(|c| !c.is_control())
+    }
+
+    fn is_excluded(foreground: HWND, excluded_apps: &[String]) -> bool {
+        let Ok(exe) = Window::from(foreground).exe() else {
+            return false;
+        };
+        let Some(name) = exe.file_name() else {
+            return false;
+        };
+        let name = name.to_string_lossy();
+        excluded_apps
+            .iter()
+            .any(|app| app.eq_ignore_ascii_case(&name))
+    }
+
+    /// Tracks the key, returns the snippet to expand if it completes an abbreviation
+    fn on_key_down(data: &KBDLLHOOKSTRUCT) -> Option<(usize, String)> {
+        let key = VIRTUAL_KEY(data.vkCode as u16);
+        let foreground = WindowsApi::get_foreground_window();
+        let mut typed = trace_lock!(TYPED);
+        if typed.0 != foreground.0 {
+            *typed = (foreground.0, String::new());
+        }
+
+        match key {
+            VK_BACK => {
+                typed.1.pop();
+                return None;
+            }
+            // modifiers are read from the state of the next key
+            VK_SHIFT | VK_LSHIFT | VK_RSHIFT | VK_CONTROL | VK_LCONTROL | VK_RCONTROL | VK_MENU
+            | VK_LMENU | VK_RMENU | VK_CAPITAL => return None,
+            _ => {}
+        }
+
+        // shortcuts and navigation keys move the caret, the typed text is no longer reliable
+        let alt_gr = Keyboard::is_pressed(VK_RMENU);
+        let shortcut =
+            !alt_gr && (Keyboard::is_pressed(VK_CONTROL) || Keyboard::is_pressed(VK_MENU));
+        let win = Keyboard::is_pressed(VK_LWIN) || Keyboard::is_pressed(VK_RWIN);
+        let Some(ch) = Self::to_char(data, foreground).filter(|_| !shortcut && !win) else {
+            typed.1.clear();
+            return None;
+        };
+
+        typed.1.push(ch);
+        if typed.1.chars().count() > MAX_TYPED {
+            typed.1.remove(0);
+        }
+
+        let state = FULL_STATE.load();
+        let settings = &state.settings().snippets;
+        let snippet = settings
+            .snippets
+            .iter()
+            .filter(|s| !s.abbreviation.is_empty() && typed.1.ends_with(&s.abbreviation))
+            .max_by_key(|s| s.abbreviation.len())?;
+        if Self::is_excluded(foreground, &settings.excluded_apps) {
+            return None;
+        }
+
+        typed.1.clear();
+        // the completing key is swallowed so it is not erased
+        let erase = snippet.abbreviation.chars().count() - 1;
+        Some((erase, snippet.text.clone()))
+    }
+
+    fn expand(erase: usize, text: &str) -> Result<()> {
+        let keyboard = Keyboard::new();
+        if erase > 0 {
+            keyboard.send_keys(&"{back}".repeat(erase))?;
+        }
+        keyboard.send_text(text)
+    }
+}
+
+unsafe extern "system" fn keyboard_hook_proc(
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION as i32 && !SecureDesktop::is_active() {
+        let data = &*(l_param.0 as *const KBDLLHOOKSTRUCT);
+        let msg = w_param.0 as u32;
+        // injected keys are ignored, as the ones typed by the expander itself
+        let injected = data.flags.0 & LLKHF_INJECTED.0 != 0;
+        if !injected && (msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN) {
+            if let Some((erase, text)) = SnippetExpander::on_key_down(data) {
+                // the hook must return fast, the input is sent outside of it
+                std::thread::spawn(move || log_error!(SnippetExpander::expand(erase, &text)));
+                return LRESULT(1);
+            }
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
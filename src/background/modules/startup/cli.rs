@@ -0,0 +1,47 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::StartupManager;
+
+get_subcommands![
+    /** Enables an app launched at login */
+    Enable(name: String => "Registry value, file name or package task of the entry."),
+    /** Disables an app launched at login without removing it */
+    Disable(name: String => "Registry value, file name or package task of the entry."),
+    /** Adds a command to be launched at login for the current user */
+    Add(name: String => "Name of the entry.", command: String => "Command to run."),
+    /** Removes an app launched at login */
+    Remove(name: String => "Registry value or file name of the entry."),
+];
+
+impl StartupManager {
+    pub const CLI_IDENTIFIER: &'static str = "startup";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Apps launched at login")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Enable(name) => {
+                let entry = Self::find(&name)?;
+                Self::set_enabled(entry.source, &entry.name, true)?;
+            }
+            SubCommand::Disable(name) => {
+                let entry = Self::find(&name)?;
+                Self::set_enabled(entry.source, &entry.name, false)?;
+            }
+            SubCommand::Add(name, command) => Self::add(&name, &command)?,
+            SubCommand::Remove(name) => {
+                let entry = Self::find(&name)?;
+                Self::remove(entry.source, &entry.name)?;
+            }
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum StartupSource {
+    /// `Run` key of the current user
+    UserRegistry,
+    /// `Run` key of the machine, changes require admin rights
+    MachineRegistry,
+    /// `Run` key of the machine for 32 bits apps (`WOW6432Node`), changes require admin rights
+    MachineRegistry32,
+    /// Startup folder of the current user
+    UserFolder,
+    /// Startup folder shared by all the users
+    CommonFolder,
+    /// `StartupTask` declared by a packaged (UWP/MSIX) app
+    Package,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupEntry {
+    /// registry value name, file name or `{family name}\{task id}`, unique by source
+    pub name: String,
+    pub command: String,
+    pub source: StartupSource,
+    pub enabled: bool,
+}
+
+/// Startup folder entries are plain file names, anything pointing outside of the folder is rejected
+pub fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\', ':'])
+}
+
+/// `StartupApproved` values are 12 bytes, the first one is even when the entry is enabled.
+/// Entries without value were never disabled.
+pub fn is_approved(data: Option<&[u8]>) -> bool {
+    data.and_then(|data| data.first())
+        .map_or(true, |flag| flag % 2 == 0)
+}
+
+/// Same format written by the Task Manager, the timestamp of the change is left empty
+pub fn approval_value(enabled: bool) -> Vec<u8> {
+    let mut value = vec![0; 12];
+    value[0] = if enabled { 0x02 } else { 0x03 };
+    value
+}
+
+/// `State` of the startup tasks of packaged apps
+pub mod task_state {
+    pub const DISABLED_BY_USER: u32 = 1;
+    pub const ENABLED: u32 = 2;
+    pub const ENABLED_BY_POLICY: u32 = 4;
+
+    pub fn is_enabled(state: u32) -> bool {
+        state == ENABLED || state == ENABLED_BY_POLICY
+    }
+}
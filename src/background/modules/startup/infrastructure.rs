@@ -0,0 +1,26 @@
+use crate::error_handler::Result;
+
+use super::{
+    domain::{StartupEntry, StartupSource},
+    StartupManager,
+};
+
+#[tauri::command(async)]
+pub fn startup_get_apps() -> Result<Vec<StartupEntry>> {
+    StartupManager::get_all()
+}
+
+#[tauri::command(async)]
+pub fn startup_set_enabled(source: StartupSource, name: String, enabled: bool) -> Result<()> {
+    StartupManager::set_enabled(source, &name, enabled)
+}
+
+#[tauri::command(async)]
+pub fn startup_add(name: String, command: String) -> Result<()> {
+    StartupManager::add(&name, &command)
+}
+
+#[tauri::command(async)]
+pub fn startup_remove(source: StartupSource, name: String) -> Result<()> {
+    StartupManager::remove(source, &name)
+}
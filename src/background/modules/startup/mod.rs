@@ -0,0 +1,237 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::path::PathBuf;
+
+use domain::{
+    approval_value, is_approved, is_plain_file_name, task_state, StartupEntry, StartupSource,
+};
+use winreg::{
+    enums::{RegType, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, KEY_READ, KEY_WRITE},
+    RegKey, RegValue,
+};
+
+use crate::error_handler::Result;
+
+const RUN_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Run";
+const RUN32_KEY: &str = r"Software\WOW6432Node\Microsoft\Windows\CurrentVersion\Run";
+const APPROVED_RUN_KEY: &str =
+    r"Software\Microsoft\Windows\CurrentVersion\Explorer\StartupApproved\Run";
+const APPROVED_RUN32_KEY: &str =
+    r"Software\Microsoft\Windows\CurrentVersion\Explorer\StartupApproved\Run32";
+const APPROVED_FOLDER_KEY: &str =
+    r"Software\Microsoft\Windows\CurrentVersion\Explorer\StartupApproved\StartupFolder";
+const PACKAGES_KEY: &str = r"Software\Classes\Local Settings\Software\Microsoft\Windows\CurrentVersion\AppModel\SystemAppData";
+const STARTUP_FOLDER: &str = r"Microsoft\Windows\Start Menu\Programs\Startup";
+
+/// Apps launched at login, the same sources and enable/disable flags used by the Task Manager,
+/// so changes made here are reflected there and vice versa.
+pub struct StartupManager {}
+
+impl StartupManager {
+    fn hive(source: StartupSource) -> RegKey {
+        match source {
+            StartupSource::MachineRegistry
+            | StartupSource::MachineRegistry32
+            | StartupSource::CommonFolder => RegKey::predef(HKEY_LOCAL_MACHINE),
+            _ => RegKey::predef(HKEY_CURRENT_USER),
+        }
+    }
+
+    fn folder(source: StartupSource) -> Option<PathBuf> {
+        let base = match source {
+            StartupSource::UserFolder => std::env::var("APPDATA").ok()?,
+            StartupSource::CommonFolder => std::env::var("PROGRAMDATA").ok()?,
+            _ => return None,
+        };
+        Some(PathBuf::from(base).join(STARTUP_FOLDER))
+    }
+
+    /// Full path of a file inside of the startup folder, ensuring it can not escape from it
+    fn folder_entry(source: StartupSource, name: &str) -> Result<PathBuf> {
+        if !is_plain_file_name(name) {
+            return Err(format!("Invalid startup entry name: {}", name).into());
+        }
+        let folder = Self::folder(source)
+            .ok_or("Startup folder not found")?
+            .canonicalize()?;
+        let path = folder.join(name).canonicalize()?;
+        if path.parent() != Some(folder.as_path()) {
+            return Err(format!("Startup entry is outside of the folder: {}", name).into());
+        }
+        Ok(path)
+    }
+
+    fn run_key(source: StartupSource) -> &'static str {
+        match source {
+            StartupSource::MachineRegistry32 => RUN32_KEY,
+            _ => RUN_KEY,
+        }
+    }
+
+    fn approved_key(source: StartupSource) -> &'static str {
+        match source {
+            StartupSource::UserFolder | StartupSource::CommonFolder => APPROVED_FOLDER_KEY,
+            StartupSource::MachineRegistry32 => APPROVED_RUN32_KEY,
+            _ => APPROVED_RUN_KEY,
+        }
+    }
+
+    fn is_entry_approved(source: StartupSource, name: &str) -> bool {
+        let value = Self::hive(source)
+            .open_subkey(Self::approved_key(source))
+            .and_then(|key| key.get_raw_value(name))
+            .ok();
+        is_approved(value.as_ref().map(|v| v.bytes.as_slice()))
+    }
+
+    fn registry_entries(source: StartupSource) -> Result<Vec<StartupEntry>> {
+        let mut entries = Vec::new();
+        let key = match Self::hive(source).open_subkey(Self::run_key(source)) {
+            Ok(key) => key,
+            Err(_) => return Ok(entries),
+        };
+        for (name, _) in key.enum_values().flatten() {
+            entries.push(StartupEntry {
+                command: key.get_value(&name).unwrap_or_default(),
+                enabled: Self::is_entry_approved(source, &name),
+                name,
+                source,
+            });
+        }
+        Ok(entries)
+    }
+
+    fn folder_entries(source: StartupSource) -> Result<Vec<StartupEntry>> {
+        let mut entries = Vec::new();
+        let folder = match Self::folder(source) {
+            Some(folder) if folder.exists() => folder,
+            _ => return Ok(entries),
+        };
+        for entry in std::fs::read_dir(folder)?.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.eq_ignore_ascii_case("desktop.ini") {
+                continue;
+            }
+            entries.push(StartupEntry {
+                command: entry.path().to_string_lossy().to_string(),
+                enabled: Self::is_entry_approved(source, &name),
+                name,
+                source,
+            });
+        }
+        Ok(entries)
+    }
+
+    fn package_entries() -> Result<Vec<StartupEntry>> {
+        let mut entries = Vec::new();
+        let packages = match RegKey::predef(HKEY_CURRENT_USER).open_subkey(PACKAGES_KEY) {
+            Ok(key) => key,
+            Err(_) => return Ok(entries),
+        };
+        for family in packages.enum_keys().flatten() {
+            let package = match packages.open_subkey(&family) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+            for task in package.enum_keys().flatten() {
+                let state: u32 = match package
+                    .open_subkey(&task)
+                    .and_then(|key| key.get_value("State"))
+                {
+                    Ok(state) => state,
+                    Err(_) => continue,
+                };
+                entries.push(StartupEntry {
+                    name: format!(r"{}\{}", family, task),
+                    command: family.clone(),
+                    source: StartupSource::Package,
+                    enabled: task_state::is_enabled(state),
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    pub fn get_all() -> Result<Vec<StartupEntry>> {
+        let mut entries = Self::registry_entries(StartupSource::UserRegistry)?;
+        entries.extend(Self::registry_entries(StartupSource::MachineRegistry)?);
+        entries.extend(Self::registry_entries(StartupSource::MachineRegistry32)?);
+        entries.extend(Self::folder_entries(StartupSource::UserFolder)?);
+        entries.extend(Self::folder_entries(StartupSource::CommonFolder)?);
+        entries.extend(Self::package_entries()?);
+        Ok(entries)
+    }
+
+    pub fn find(name: &str) -> Result<StartupEntry> {
+        Self::get_all()?
+            .into_iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("Startup entry not found: {}", name).into())
+    }
+
+    pub fn set_enabled(source: StartupSource, name: &str, enabled: bool) -> Result<()> {
+        log::trace!(
+            "Setting startup entry {} ({:?}) to {}",
+            name,
+            source,
+            enabled
+        );
+        if source == StartupSource::Package {
+            let state = if enabled {
+                task_state::ENABLED
+            } else {
+                task_state::DISABLED_BY_USER
+            };
+            let key = RegKey::predef(HKEY_CURRENT_USER)
+                .open_subkey_with_flags(format!(r"{}\{}", PACKAGES_KEY, name), KEY_WRITE)?;
+            key.set_value("State", &state)?;
+            return Ok(());
+        }
+
+        let (key, _) = Self::hive(source).create_subkey(Self::approved_key(source))?;
+        key.set_raw_value(
+            name,
+            &RegValue {
+                bytes: approval_value(enabled),
+                vtype: RegType::REG_BINARY,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Adds a command to the `Run` key of the current user
+    pub fn add(name: &str, command: &str) -> Result<()> {
+        let (key, _) = RegKey::predef(HKEY_CURRENT_USER).create_subkey(RUN_KEY)?;
+        key.set_value(name, &command.to_string())?;
+        Ok(())
+    }
+
+    /// Packaged apps can not be removed, only disabled
+    pub fn remove(source: StartupSource, name: &str) -> Result<()> {
+        match source {
+            StartupSource::UserRegistry
+            | StartupSource::MachineRegistry
+            | StartupSource::MachineRegistry32 => {
+                let key = Self::hive(source)
+                    .open_subkey_with_flags(Self::run_key(source), KEY_READ | KEY_WRITE)?;
+                key.delete_value(name)?;
+            }
+            StartupSource::UserFolder | StartupSource::CommonFolder => {
+                std::fs::remove_file(Self::folder_entry(source, name)?)?;
+            }
+            StartupSource::Package => {
+                return Err("Startup tasks of packages can not be removed".into());
+            }
+        }
+
+        // also clean the enabled/disabled flag
+        if let Ok(key) =
+            Self::hive(source).open_subkey_with_flags(Self::approved_key(source), KEY_WRITE)
+        {
+            let _ = key.delete_value(name);
+        }
+        Ok(())
+    }
+}
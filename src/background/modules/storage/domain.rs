@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveUsage {
+    /// drive letter with colon, ex: `C:`
+    pub letter: String,
+    pub label: String,
+    pub removable: bool,
+    /// bytes
+    pub total: u64,
+    /// bytes available to the current user
+    pub free: u64,
+    /// bytes per second since the previous sample
+    pub read_rate: u64,
+    pub write_rate: u64,
+    /// percentage of the time the disk was busy since the previous sample, 0-100
+    pub active_time: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum StorageWarning {
+    /// the disk was almost always busy during the last samples
+    HighIo { letter: String, active_time: f32 },
+    /// less than 10% of the drive is free
+    LowSpace { letter: String, free: u64 },
+}
@@ -0,0 +1,18 @@
+use crate::{error_handler::Result, windows_api::WindowsApi};
+
+use super::{domain::DriveUsage, StorageMonitor};
+
+#[tauri::command(async)]
+pub fn get_storage_usage() -> Vec<DriveUsage> {
+    StorageMonitor::drives()
+}
+
+#[tauri::command(async)]
+pub fn open_drive(letter: String) -> Result<()> {
+    StorageMonitor::open_drive(&letter)
+}
+
+#[tauri::command(async)]
+pub fn open_storage_settings() -> Result<()> {
+    WindowsApi::shell_open("ms-settings:storagesense")
+}
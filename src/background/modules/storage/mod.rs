@@ -0,0 +1,268 @@
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::c_void,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use domain::{DriveUsage, StorageWarning};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::CloseHandle,
+        Storage::FileSystem::{
+            CreateFileW, GetDiskFreeSpaceExW, GetDriveTypeW, GetLogicalDrives,
+            GetVolumeInformationW, FILE_FLAGS_AND_ATTRIBUTES, FILE_SHARE_READ, FILE_SHARE_WRITE,
+            OPEN_EXISTING,
+        },
+        System::{
+            Ioctl::{DISK_PERFORMANCE, IOCTL_DISK_PERFORMANCE},
+            WindowsProgramming::{DRIVE_FIXED, DRIVE_REMOVABLE},
+            IO::DeviceIoControl,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::game_mode::GameMode,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+const SAMPLE_INTERVAL: u64 = 2000;
+/// active time percentage considered high I/O
+const HIGH_IO_THRESHOLD: f32 = 90.0;
+/// consecutive busy samples needed to warn, to ignore short bursts
+const HIGH_IO_SAMPLES: u32 = 5;
+const LOW_SPACE_RATIO: f64 = 0.1;
+
+/// Disk counters of a drive at a given time
+struct Counters {
+    at: Instant,
+    read: i64,
+    written: i64,
+    idle: i64,
+    query: i64,
+}
+
+#[derive(Default)]
+struct StorageState {
+    counters: HashMap<String, Counters>,
+    last: Vec<DriveUsage>,
+    busy_samples: HashMap<String, u32>,
+    /// drives already warned, cleared when the condition is gone
+    warned_io: HashSet<String>,
+    warned_space: HashSet<String>,
+}
+
+lazy_static! {
+    static ref STATE: Arc<Mutex<StorageState>> = Arc::new(Mutex::new(StorageState::default()));
+}
+
+/// Samples the free space and the activity of the fixed and removable drives, emitting
+/// `storage-usage` on each sample and `storage-warning` on high I/O or low free space.
+pub struct StorageMonitor;
+
+impl StorageMonitor {
+    fn performance(letter: &str) -> Result<DISK_PERFORMANCE> {
+        let path = HSTRING::from(format!(r"\\.\{}", letter));
+        let mut perf = DISK_PERFORMANCE::default();
+        let mut returned = 0;
+        unsafe {
+            // no access rights are needed to query the performance counters
+            let handle = CreateFileW(
+                &path,
+                0,
+                FILE_SHARE_READ | FILE_SHARE_WRITE,
+                None,
+                OPEN_EXISTING,
+                FILE_FLAGS_AND_ATTRIBUTES(0),
+                None,
+            )?;
+            let result = DeviceIoControl(
+                handle,
+                IOCTL_DISK_PERFORMANCE,
+                None,
+                0,
+                Some(&mut perf as *mut _ as *mut c_void),
+                std::mem::size_of::<DISK_PERFORMANCE>() as u32,
+                Some(&mut returned),
+                None,
+            );
+            let _ = CloseHandle(handle);
+            result?;
+        }
+        Ok(perf)
+    }
+
+    fn label(root: &HSTRING) -> String {
+        let mut label = [0u16; 261];
+        match unsafe { GetVolumeInformationW(root, Some(&mut label), None, None, None, None) } {
+            Ok(_) => String::from_utf16_lossy(&label)
+                .trim_end_matches('\0')
+                .to_string(),
+            Err(_) => String::new(),
+        }
+    }
+
+    fn sample(state: &mut StorageState) -> Vec<DriveUsage> {
+        let mask = unsafe { GetLogicalDrives() };
+        let mut drives = Vec::new();
+        for (idx, letter) in ('A'..='Z').enumerate() {
+            if mask & (1 << idx) == 0 {
+                continue;
+            }
+            let letter = format!("{}:", letter);
+            let root = HSTRING::from(format!(r"{}\", letter));
+            let removable = match unsafe { GetDriveTypeW(&root) } {
+                DRIVE_FIXED => false,
+                DRIVE_REMOVABLE => true,
+                _ => continue,
+            };
+
+            let mut free = 0;
+            let mut total = 0;
+            // fails on empty card readers
+            if unsafe { GetDiskFreeSpaceExW(&root, Some(&mut free), Some(&mut total), None) }
+                .is_err()
+            {
+                continue;
+            }
+
+            let mut usage = DriveUsage {
+                label: Self::label(&root),
+                letter: letter.clone(),
+                removable,
+                total,
+                free,
+                ..Default::default()
+            };
+
+            // counters can be unavailable, ex: on virtual drives
+            if let Ok(perf) = Self::performance(&letter) {
+                let current = Counters {
+                    at: Instant::now(),
+                    read: perf.BytesRead,
+                    written: perf.BytesWritten,
+                    idle: perf.IdleTime,
+                    query: perf.QueryTime,
+                };
+                if let Some(prev) = state.counters.get(&letter) {
+                    let secs = current.at.duration_since(prev.at).as_secs_f64().max(0.001);
+                    usage.read_rate = ((current.read - prev.read).max(0) as f64 / secs) as u64;
+                    usage.write_rate =
+                        ((current.written - prev.written).max(0) as f64 / secs) as u64;
+                    let elapsed = current.query - prev.query;
+                    if elapsed > 0 {
+                        let idle = (current.idle - prev.idle).clamp(0, elapsed);
+                        usage.active_time = 100.0 - (idle as f32 * 100.0 / elapsed as f32);
+                    }
+                }
+                state.counters.insert(letter, current);
+            }
+            drives.push(usage);
+        }
+        state
+            .counters
+            .retain(|letter, _| drives.iter().any(|d| &d.letter == letter));
+        drives
+    }
+
+    fn warnings(state: &mut StorageState, drives: &[DriveUsage]) -> Vec<StorageWarning> {
+        let mut warnings = Vec::new();
+        for drive in drives {
+            let busy = state.busy_samples.entry(drive.letter.clone()).or_default();
+            if drive.active_time >= HIGH_IO_THRESHOLD {
+                *busy += 1;
+            } else {
+                *busy = 0;
+                state.warned_io.remove(&drive.letter);
+            }
+            if *busy >= HIGH_IO_SAMPLES && state.warned_io.insert(drive.letter.clone()) {
+                warnings.push(StorageWarning::HighIo {
+                    letter: drive.letter.clone(),
+                    active_time: drive.active_time,
+                });
+            }
+
+            let low_space = (drive.free as f64) < drive.total as f64 * LOW_SPACE_RATIO;
+            if !low_space {
+                state.warned_space.remove(&drive.letter);
+            } else if state.warned_space.insert(drive.letter.clone()) {
+                warnings.push(StorageWarning::LowSpace {
+                    letter: drive.letter.clone(),
+                    free: drive.free,
+                });
+            }
+        }
+        warnings
+    }
+
+    fn tick() -> Result<()> {
+        let mut state = trace_lock!(STATE);
+        let drives = Self::sample(&mut state);
+        let warnings = Self::warnings(&mut state, &drives);
+        state.last = drives.clone();
+        drop(state);
+
+        let handle = get_app_handle();
+        handle.emit("storage-usage", drives)?;
+        for warning in warnings {
+            handle.emit("storage-warning", warning)?;
+        }
+        Ok(())
+    }
+
+    /// Drives as of the last sample, sampled now if the monitor is not running
+    pub fn drives() -> Vec<DriveUsage> {
+        let mut state = trace_lock!(STATE);
+        if !RUNNING.load(Ordering::Acquire) {
+            state.last = Self::sample(&mut state);
+        }
+        state.last.clone()
+    }
+
+    /// Opens the drive on the explorer, `letter` can be given as `C`, `C:` or `C:\`.
+    pub fn open_drive(letter: &str) -> Result<()> {
+        let letter = match letter.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => c.to_ascii_uppercase(),
+            _ => return Err(format!("Invalid drive letter: {}", letter).into()),
+        };
+        WindowsApi::shell_open(&format!(r"{}:\", letter))
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting storage monitor");
+        spawn_named_thread("Storage Monitor", || {
+            while RUNNING.load(Ordering::Acquire) {
+                if !GameMode::is_active() {
+                    log_error!(Self::tick());
+                }
+                sleep_millis(SAMPLE_INTERVAL);
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
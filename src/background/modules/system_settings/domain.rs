@@ -14,3 +14,11 @@ pub struct UIColors {
     pub accent_lightest: String,
     pub complement: Option<String>,
 }
+
+/// Most representative colors of the current wallpaper, ordered by presence
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WallpaperPalette {
+    pub wallpaper_primary: String,
+    pub wallpaper_secondary: String,
+    pub wallpaper_tertiary: String,
+}
@@ -1,12 +1,34 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::SystemTime,
+};
 
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
 use tauri::Emitter;
 
-use crate::{log_error, seelen::get_app_handle, trace_lock};
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::monitors::theme::MonitorThemeVars,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
 
-use super::{application::SYSTEM_SETTINGS, domain::UIColors};
+use super::{
+    application::SYSTEM_SETTINGS,
+    domain::{UIColors, WallpaperPalette},
+    wallpaper::extract_palette,
+};
+
+lazy_static! {
+    static ref WALLPAPER_PALETTE: Mutex<Option<WallpaperPalette>> = Mutex::new(None);
+}
 
 fn emit_colors(colors: &UIColors) {
+    MonitorThemeVars::on_accent_changed(&colors.accent);
     get_app_handle()
         .emit("colors", colors)
         .expect("failed to emit");
@@ -33,3 +55,39 @@ pub fn release_colors_events() {
         log_error!(trace_lock!(SYSTEM_SETTINGS).release());
     }
 }
+
+static WALLPAPER_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Emits the palette of the wallpaper each time it changes.
+/// The wallpaper is checked by path and modification time, slideshows reuse the same path.
+pub fn register_wallpaper_palette_events() -> Result<()> {
+    if WALLPAPER_REGISTERED.swap(true, Ordering::AcqRel) {
+        if let Some(palette) = trace_lock!(WALLPAPER_PALETTE).as_ref() {
+            get_app_handle().emit("wallpaper-palette", palette)?;
+        }
+        return Ok(());
+    }
+    log::trace!("Registering wallpaper palette events");
+
+    spawn_named_thread("Wallpaper Palette", || {
+        let mut last: Option<(std::path::PathBuf, Option<SystemTime>)> = None;
+        loop {
+            if let Ok(path) = WindowsApi::get_wallpaper() {
+                let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+                let current = Some((path.clone(), modified));
+                if current != last {
+                    last = current;
+                    match extract_palette(&path) {
+                        Ok(palette) => {
+                            log_error!(get_app_handle().emit("wallpaper-palette", &palette));
+                            *trace_lock!(WALLPAPER_PALETTE) = Some(palette);
+                        }
+                        Err(err) => log::warn!("Failed to extract wallpaper palette: {:?}", err),
+                    }
+                }
+            }
+            sleep_millis(5000);
+        }
+    })?;
+    Ok(())
+}
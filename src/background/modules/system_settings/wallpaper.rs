@@ -0,0 +1,68 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::error_handler::Result;
+
+use super::domain::WallpaperPalette;
+
+/// minimum distance between the palette colors, avoids three shades of the same color
+const MIN_COLOR_DISTANCE: u32 = 48 * 48;
+
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (*a as i32 - *b as i32).pow(2) as u32)
+        .sum()
+}
+
+fn to_hex(color: [u8; 3]) -> String {
+    format!("#{:02X}{:02X}{:02X}FF", color[0], color[1], color[2])
+}
+
+/// Extracts the palette by grouping the pixels of a thumbnail of the image
+/// on buckets of 4 bits per channel, most populated buckets win.
+pub fn extract_palette(path: &Path) -> Result<WallpaperPalette> {
+    let image = image::open(path)?.thumbnail(64, 64).to_rgb8();
+
+    let mut buckets: HashMap<u16, (u32, [u32; 3])> = HashMap::new();
+    for pixel in image.pixels() {
+        let [r, g, b] = pixel.0;
+        let key = ((r as u16 >> 4) << 8) | ((g as u16 >> 4) << 4) | (b as u16 >> 4);
+        let (count, sum) = buckets.entry(key).or_default();
+        *count += 1;
+        sum[0] += r as u32;
+        sum[1] += g as u32;
+        sum[2] += b as u32;
+    }
+
+    let mut buckets: Vec<(u32, [u8; 3])> = buckets
+        .into_values()
+        .map(|(count, sum)| {
+            let average = sum.map(|channel| (channel / count) as u8);
+            (count, average)
+        })
+        .collect();
+    buckets.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut palette: Vec<[u8; 3]> = Vec::new();
+    for (_, color) in buckets {
+        if palette
+            .iter()
+            .all(|c| distance(*c, color) >= MIN_COLOR_DISTANCE)
+        {
+            palette.push(color);
+            if palette.len() == 3 {
+                break;
+            }
+        }
+    }
+
+    // plain wallpapers have less than three different colors
+    let primary = palette.first().copied().unwrap_or_default();
+    let secondary = palette.get(1).copied().unwrap_or(primary);
+    let tertiary = palette.get(2).copied().unwrap_or(secondary);
+    Ok(WallpaperPalette {
+        wallpaper_primary: to_hex(primary),
+        wallpaper_secondary: to_hex(secondary),
+        wallpaper_tertiary: to_hex(tertiary),
+    })
+}
@@ -0,0 +1,35 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::TaskManager;
+
+get_subcommands![
+    /** Opens the task manager if it is closed, otherwise closes it */
+    Toggle,
+    /** Opens the task manager on the monitor under the cursor */
+    Show,
+    /** Closes the task manager */
+    Hide,
+];
+
+impl TaskManager {
+    pub const CLI_IDENTIFIER: &'static str = "task-manager";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Mini task manager")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Toggle => Self::toggle()?,
+            SubCommand::Show => Self::show()?,
+            SubCommand::Hide => Self::hide(),
+        };
+        Ok(())
+    }
+}
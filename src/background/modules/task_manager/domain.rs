@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+use crate::modules::metrics::ProcessSample;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskEntry {
+    #[serde(flatten)]
+    pub process: ProcessSample,
+    /// main visible window of the process, if any
+    pub window: Option<isize>,
+    pub title: Option<String>,
+}
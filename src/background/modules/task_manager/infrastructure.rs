@@ -0,0 +1,33 @@
+use crate::error_handler::Result;
+
+use super::{domain::TaskEntry, TaskManager};
+
+#[tauri::command(async)]
+pub fn task_manager_show() -> Result<()> {
+    TaskManager::show()
+}
+
+#[tauri::command(async)]
+pub fn task_manager_list(query: String) -> Vec<TaskEntry> {
+    TaskManager::list(&query)
+}
+
+#[tauri::command(async)]
+pub fn task_manager_end_task(pid: u32) -> Result<()> {
+    TaskManager::end_task(pid)
+}
+
+#[tauri::command(async)]
+pub fn task_manager_activate(hwnd: isize) -> Result<()> {
+    TaskManager::activate(hwnd)
+}
+
+#[tauri::command(async)]
+pub fn task_manager_open_system() -> Result<()> {
+    TaskManager::open_system_task_manager()
+}
+
+#[tauri::command(async)]
+pub fn task_manager_close() {
+    TaskManager::hide();
+}
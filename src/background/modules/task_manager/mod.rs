@@ -0,0 +1,174 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{collections::HashMap, sync::Arc};
+
+use domain::TaskEntry;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::{WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SET_WINDOW_POS_FLAGS},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{input::Mouse, metrics::Metrics},
+    trace_lock,
+    utils::overlay::OverlayBuilder,
+    windows_api::{WindowEnumerator, WindowsApi},
+};
+
+lazy_static! {
+    static ref TASK_MANAGER: Arc<Mutex<Option<TaskManager>>> = Arc::new(Mutex::new(None));
+}
+
+pub struct TaskManager {
+    window: WebviewWindow<Wry>,
+}
+
+impl Drop for TaskManager {
+    fn drop(&mut self) {
+        log::trace!("Closing task manager");
+        log_error!(self.window.destroy());
+    }
+}
+
+impl TaskManager {
+    const TARGET: &'static str = "task-manager";
+    const WIDTH: i32 = 480;
+    const HEIGHT: i32 = 540;
+
+    /// Centered on the monitor under the cursor
+    fn rect_on_cursor_monitor() -> Result<RECT> {
+        let cursor = Mouse::get_cursor_pos().unwrap_or_default();
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+        let scale = WindowsApi::get_device_pixel_ratio(monitor)?;
+        let width = (Self::WIDTH as f32 * scale) as i32;
+        let height = (Self::HEIGHT as f32 * scale) as i32;
+
+        let left = monitor_rect.left + (monitor_rect.right - monitor_rect.left - width) / 2;
+        let top = monitor_rect.top + (monitor_rect.bottom - monitor_rect.top - height) / 2;
+        Ok(RECT {
+            left,
+            top,
+            right: left + width,
+            bottom: top + height,
+        })
+    }
+
+    fn new() -> Result<Self> {
+        let window = OverlayBuilder::new(
+            Self::TARGET,
+            "task-manager/index.html",
+            "Seelen Task Manager",
+        )
+        .build()?;
+        WindowsApi::set_position(
+            HWND(window.hwnd()?.0),
+            Some(HWND_TOPMOST),
+            &Self::rect_on_cursor_monitor()?,
+            SET_WINDOW_POS_FLAGS::default(),
+        )?;
+        window.show()?;
+        window.set_focus()?;
+        Ok(Self { window })
+    }
+
+    pub fn show() -> Result<()> {
+        let mut manager = trace_lock!(TASK_MANAGER);
+        if manager.is_none() {
+            *manager = Some(Self::new()?);
+        }
+        Ok(())
+    }
+
+    pub fn hide() {
+        trace_lock!(TASK_MANAGER).take();
+    }
+
+    pub fn toggle() -> Result<()> {
+        let is_open = trace_lock!(TASK_MANAGER).is_some();
+        if is_open {
+            Self::hide();
+            Ok(())
+        } else {
+            Self::show()
+        }
+    }
+
+    /// First visible and titled top level window of each process
+    fn main_windows() -> HashMap<u32, HWND> {
+        let mut windows = HashMap::new();
+        let result = WindowEnumerator::new().for_each(|hwnd| {
+            if WindowsApi::is_window_visible(hwnd)
+                && WindowsApi::get_owner(hwnd).0 == 0
+                && !WindowsApi::get_window_text(hwnd).is_empty()
+            {
+                let (process_id, _) = WindowsApi::window_thread_process_id(hwnd);
+                windows.entry(process_id).or_insert(hwnd);
+            }
+        });
+        log_error!(result);
+        windows
+    }
+
+    /// Running processes matching `query` by name or window title, most cpu intensive first
+    pub fn list(query: &str) -> Vec<TaskEntry> {
+        Metrics::refresh();
+        Metrics::refresh_network();
+        let windows = Self::main_windows();
+        let query = query.trim().to_lowercase();
+
+        let mut entries: Vec<TaskEntry> = Metrics::processes()
+            .into_iter()
+            .map(|process| {
+                let window = windows.get(&process.pid).copied();
+                TaskEntry {
+                    title: window.map(WindowsApi::get_window_text),
+                    window: window.map(|hwnd| hwnd.0),
+                    process,
+                }
+            })
+            .filter(|entry| {
+                query.is_empty()
+                    || entry.process.name.to_lowercase().contains(&query)
+                    || entry
+                        .title
+                        .as_ref()
+                        .is_some_and(|title| title.to_lowercase().contains(&query))
+            })
+            .collect();
+
+        entries.sort_by(|a, b| b.process.usage.cpu.total_cmp(&a.process.usage.cpu));
+        entries
+    }
+
+    pub fn end_task(pid: u32) -> Result<()> {
+        if pid == std::process::id() {
+            return Err("Seelen UI can not end its own task".into());
+        }
+        WindowsApi::terminate_process(pid)
+    }
+
+    /// Brings the window to the foreground, closing the task manager
+    pub fn activate(hwnd: isize) -> Result<()> {
+        let hwnd = HWND(hwnd);
+        Self::hide();
+        if WindowsApi::is_iconic(hwnd) {
+            WindowsApi::restore_window(hwnd)?;
+        }
+        WindowsApi::force_set_foreground(hwnd)
+    }
+
+    /// Opens the Windows task manager, for anything beyond the mini task manager
+    pub fn open_system_task_manager() -> Result<()> {
+        Self::hide();
+        std::process::Command::new("taskmgr.exe").spawn()?;
+        Ok(())
+    }
+}
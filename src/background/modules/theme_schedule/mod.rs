@@ -0,0 +1,179 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{ThemeScheduleMode, ThemeScheduleSettings};
+use serde::Serialize;
+use tauri::Emitter;
+use windows::{
+    core::w,
+    Win32::{
+        Foundation::{HWND, LPARAM, WPARAM},
+        System::SystemInformation::GetLocalTime,
+        UI::WindowsAndMessaging::{
+            SendMessageTimeoutW, HWND_BROADCAST, SMTO_ABORTIFHUNG, WM_SETTINGCHANGE,
+        },
+    },
+};
+use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+lazy_static! {
+    /// last themes selected by the scheduler, `None` until the first tick
+    static ref LAST_APPLIED: Arc<Mutex<Option<Vec<String>>>> = Arc::new(Mutex::new(None));
+}
+
+/// true while the scheduler thread is running
+static SCHEDULER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+const PERSONALIZE_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeSchemeChanged {
+    dark: bool,
+    themes: Vec<String>,
+}
+
+/// parses `HH:MM` into minutes since midnight
+pub fn parse_time(time: &str) -> Option<u32> {
+    let (hours, minutes) = time.trim().split_once(':')?;
+    let hours: u32 = hours.parse().ok()?;
+    let minutes: u32 = minutes.parse().ok()?;
+    (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+}
+
+/// `now`, `light_start` and `dark_start` are minutes since midnight
+fn is_dark_at(now: u32, light_start: u32, dark_start: u32) -> bool {
+    if light_start <= dark_start {
+        now < light_start || now >= dark_start
+    } else {
+        // light period crosses midnight
+        now >= dark_start && now < light_start
+    }
+}
+
+/// Switches the selected Seelen themes between the configured light and dark ones.
+/// Themes are only written when the expected selection changes, so a theme
+/// manually selected by the user is kept until the next switch.
+pub struct ThemeScheduler;
+
+impl ThemeScheduler {
+    /// seconds between each check of the schedule
+    const TICK_INTERVAL: u32 = 30;
+
+    fn system_uses_dark_theme() -> Result<bool> {
+        let key = RegKey::predef(HKEY_CURRENT_USER).open_subkey(PERSONALIZE_KEY)?;
+        let light: u32 = key.get_value("SystemUsesLightTheme")?;
+        Ok(light == 0)
+    }
+
+    /// minutes since midnight, local time
+    pub fn local_minutes() -> u32 {
+        let time = unsafe { GetLocalTime() };
+        time.wHour as u32 * 60 + time.wMinute as u32
+    }
+
+    fn is_dark(settings: &ThemeScheduleSettings) -> Result<bool> {
+        match settings.mode {
+            ThemeScheduleMode::FollowSystem => Self::system_uses_dark_theme(),
+            ThemeScheduleMode::Time => {
+                let light_start = parse_time(&settings.light_start)
+                    .ok_or_else(|| format!("Invalid light start: {}", settings.light_start))?;
+                let dark_start = parse_time(&settings.dark_start)
+                    .ok_or_else(|| format!("Invalid dark start: {}", settings.dark_start))?;
+                Ok(is_dark_at(Self::local_minutes(), light_start, dark_start))
+            }
+        }
+    }
+
+    /// Changes the theme of the Windows apps and notifies the running apps
+    fn set_windows_apps_dark(dark: bool) -> Result<()> {
+        let (key, _) = RegKey::predef(HKEY_CURRENT_USER).create_subkey(PERSONALIZE_KEY)?;
+        let light = if dark { 0u32 } else { 1u32 };
+        if key.get_value::<u32, _>("AppsUseLightTheme").ok() == Some(light) {
+            return Ok(());
+        }
+        key.set_value("AppsUseLightTheme", &light)?;
+        unsafe {
+            SendMessageTimeoutW(
+                HWND_BROADCAST,
+                WM_SETTINGCHANGE,
+                WPARAM(0),
+                LPARAM(w!("ImmersiveColorSet").as_ptr() as isize),
+                SMTO_ABORTIFHUNG,
+                100,
+                None,
+            )
+        };
+        Ok(())
+    }
+
+    fn tick() -> Result<()> {
+        let settings = FULL_STATE.load().settings().theme_schedule.clone();
+        let dark = Self::is_dark(&settings)?;
+        let themes = if dark {
+            settings.dark_themes.clone()
+        } else {
+            settings.light_themes.clone()
+        };
+
+        let mut last_applied = trace_lock!(LAST_APPLIED);
+        if last_applied.as_ref() == Some(&themes) {
+            return Ok(());
+        }
+        *last_applied = Some(themes.clone());
+        drop(last_applied);
+
+        log::trace!(
+            "Applying {} themes: {:?}",
+            if dark { "dark" } else { "light" },
+            themes
+        );
+        if settings.sync_windows_apps && settings.mode == ThemeScheduleMode::Time {
+            log_error!(Self::set_windows_apps_dark(dark));
+        }
+
+        let mut state = FULL_STATE.load().cloned();
+        state.select_themes(themes.clone())?;
+        get_app_handle().emit("theme-scheme-changed", ThemeSchemeChanged { dark, themes })?;
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if SCHEDULER_RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::trace!("Starting theme scheduler");
+
+        spawn_named_thread("Theme Scheduler", || {
+            let mut elapsed = Self::TICK_INTERVAL;
+            while SCHEDULER_RUNNING.load(Ordering::Acquire) {
+                if elapsed >= Self::TICK_INTERVAL {
+                    elapsed = 0;
+                    log_error!(Self::tick());
+                }
+                sleep_millis(1000);
+                elapsed += 1;
+            }
+            *trace_lock!(LAST_APPLIED) = None;
+            log::trace!("Theme scheduler stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        SCHEDULER_RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::error_handler::Result;
+
+use super::TouchKeyboard;
+
+#[tauri::command(async)]
+pub fn touch_keyboard_show() -> Result<()> {
+    TouchKeyboard::show()
+}
+
+#[tauri::command(async)]
+pub fn touch_keyboard_hide() -> Result<()> {
+    TouchKeyboard::hide()
+}
+
+#[tauri::command(async)]
+pub fn touch_keyboard_toggle() -> Result<()> {
+    TouchKeyboard::toggle()
+}
+
+#[tauri::command(async)]
+pub fn touch_keyboard_is_visible() -> bool {
+    TouchKeyboard::is_visible()
+}
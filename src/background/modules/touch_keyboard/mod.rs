@@ -0,0 +1,86 @@
+pub mod infrastructure;
+
+use std::{path::PathBuf, process::Command};
+
+use windows::{
+    core::{w, GUID, HRESULT, PCWSTR},
+    Win32::{
+        Foundation::HWND,
+        UI::WindowsAndMessaging::{FindWindowW, GetDesktopWindow, WS_DISABLED},
+    },
+};
+use windows_core::IUnknown;
+
+use crate::{
+    error_handler::Result,
+    utils::sleep_millis,
+    windows_api::{Com, WindowsApi},
+};
+
+/// Undocumented interface of TabTip.exe, used by the taskbar keyboard button
+#[windows_core::interface("37c994e7-432b-4834-a2f7-dce1f13b834b")]
+unsafe trait ITipInvocation: IUnknown {
+    fn Toggle(&self, hwnd: HWND) -> HRESULT;
+}
+
+const CLSID_UI_HOST_NO_LAUNCH: GUID = GUID::from_u128(0x4ce576fa_83dc_4f88_951c_9d0782b4e376);
+
+/// Windows touch keyboard, so it can be opened when the native taskbar is hidden
+pub struct TouchKeyboard;
+
+impl TouchKeyboard {
+    fn tab_tip_path() -> PathBuf {
+        let common = std::env::var("CommonProgramFiles")
+            .unwrap_or_else(|_| r"C:\Program Files\Common Files".to_string());
+        PathBuf::from(common).join(r"microsoft shared\ink\TabTip.exe")
+    }
+
+    pub fn is_visible() -> bool {
+        // Windows 10 host window, stays alive while hidden
+        let hwnd = unsafe { FindWindowW(w!("IPTip_Main_Window"), PCWSTR::null()) };
+        if hwnd.0 != 0 {
+            return WindowsApi::is_window_visible(hwnd)
+                && !WindowsApi::get_styles(hwnd).contains(WS_DISABLED);
+        }
+        // Windows 11 hosts the keyboard in a core window that is cloaked while hidden
+        let hwnd = unsafe {
+            FindWindowW(
+                w!("Windows.UI.Core.CoreWindow"),
+                w!("Microsoft Text Input Application"),
+            )
+        };
+        hwnd.0 != 0 && !WindowsApi::is_cloaked(hwnd).unwrap_or(true)
+    }
+
+    pub fn toggle() -> Result<()> {
+        let invoke = || -> Result<()> {
+            let tip: ITipInvocation = Com::create_instance(&CLSID_UI_HOST_NO_LAUNCH)?;
+            unsafe { tip.Toggle(GetDesktopWindow()).ok()? };
+            Ok(())
+        };
+
+        Com::run_with_context(|| {
+            if invoke().is_ok() {
+                return Ok(());
+            }
+            // the interface is only registered while TabTip.exe is running
+            Command::new(Self::tab_tip_path()).spawn()?;
+            sleep_millis(500);
+            invoke()
+        })
+    }
+
+    pub fn show() -> Result<()> {
+        if !Self::is_visible() {
+            Self::toggle()?;
+        }
+        Ok(())
+    }
+
+    pub fn hide() -> Result<()> {
+        if Self::is_visible() {
+            Self::toggle()?;
+        }
+        Ok(())
+    }
+}
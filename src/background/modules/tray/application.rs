@@ -18,10 +18,11 @@ use winreg::{
 
 use crate::{
     error_handler::Result,
+    modules::os_capabilities::domain::OsCapabilities,
     pcstr,
     seelen::get_app_handle,
     seelen_weg::icon_extractor::extract_and_save_icon,
-    utils::{is_windows_10, is_windows_11, resolve_guid_path, sleep_millis},
+    utils::{resolve_guid_path, sleep_millis},
     windows_api::{AppBarData, AppBarDataState, Com, WindowsApi},
 };
 
@@ -45,7 +46,7 @@ pub fn get_sub_tree(
 
 fn get_tray_overflow_handle() -> Option<HWND> {
     unsafe {
-        if is_windows_10() {
+        if !OsCapabilities::get().xaml_tray_overflow {
             let tray_overflow = FindWindowA(pcstr!("NotifyIconOverFlowWindow"), None);
             if tray_overflow.0 == 0 {
                 return None;
@@ -64,7 +65,7 @@ fn get_tray_overflow_handle() -> Option<HWND> {
 fn get_tray_overflow_content_handle() -> Option<HWND> {
     let tray_overflow = get_tray_overflow_handle()?;
     unsafe {
-        if is_windows_10() {
+        if !OsCapabilities::get().xaml_tray_overflow {
             let tray_overflow_content =
                 FindWindowExA(tray_overflow, HWND(0), pcstr!("ToolbarWindow32"), None);
             if tray_overflow_content.0 == 0 {
@@ -87,7 +88,7 @@ fn get_tray_overflow_content_handle() -> Option<HWND> {
 }
 
 pub fn ensure_tray_overflow_creation() -> Result<()> {
-    if !is_windows_11() || get_tray_overflow_content_handle().is_some() {
+    if !OsCapabilities::get().xaml_tray_overflow || get_tray_overflow_content_handle().is_some() {
         return Ok(());
     }
 
@@ -144,7 +145,7 @@ pub fn get_tray_icons() -> Result<Vec<TrayIcon>> {
             children.extend(get_sub_tree(&element, &condition, TreeScope_Descendants)?);
         }
 
-        let is_win10 = is_windows_10();
+        let is_win10 = OsCapabilities::get().is_windows_10();
         for element in children {
             let name = element.CurrentName()?.to_string();
             if is_win10 || element.CurrentAutomationId()? == "NotifyItemIcon" {
@@ -231,6 +232,28 @@ impl TrayIcon {
     }
 }
 
+/// Lowercased executable paths of the apps that registered a tray icon, without the icons
+/// of devices. Unlike `get_tray_icons` the registry is only read.
+pub fn get_tray_executables() -> Result<Vec<String>> {
+    let settings =
+        RegKey::predef(HKEY_CURRENT_USER).open_subkey("Control Panel\\NotifyIconSettings")?;
+    let mut executables = Vec::new();
+    for id in settings.enum_keys().flatten() {
+        let Ok(key) = settings.open_subkey(&id) else {
+            continue;
+        };
+        if key.get_raw_value("IconGuid").is_ok() {
+            continue;
+        }
+        if let Ok(path) = key.get_value::<String, _>("ExecutablePath") {
+            if let Ok(path) = resolve_guid_path(path) {
+                executables.push(path.to_string_lossy().to_lowercase());
+            }
+        }
+    }
+    Ok(executables)
+}
+
 struct TrayIconManager {}
 impl TrayIconManager {
     pub fn enum_from_registry() -> Result<Vec<RegistryNotifyIcon>> {
@@ -0,0 +1,32 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::Undo;
+
+get_subcommands![
+    /** Reverts the last unpin, close, move to workspace or workspace size reset */
+    Last,
+    /** Forgets all the recorded actions */
+    Clear,
+];
+
+impl Undo {
+    pub const CLI_IDENTIFIER: &'static str = "undo";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Undo recent dock and window manager actions")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Last => Self::undo_last()?,
+            SubCommand::Clear => Self::clear(),
+        };
+        Ok(())
+    }
+}
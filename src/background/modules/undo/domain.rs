@@ -0,0 +1,24 @@
+use seelen_core::state::WegItems;
+
+#[derive(Debug, Clone)]
+pub enum UndoableAction {
+    /// weg items before an app was unpinned
+    UnpinWegItems { previous: WegItems },
+    /// windows of an app closed from the weg, undone by reopening the app
+    CloseWindows { execution_path: String },
+    /// window sent to another workspace, `from` is the index of the original workspace
+    MoveToWorkspace { hwnd: isize, from: usize },
+    /// container sizes of the active workspace were reset by the window manager
+    ResetWorkspaceSize,
+}
+
+impl UndoableAction {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::UnpinWegItems { .. } => "Unpinned app restored",
+            Self::CloseWindows { .. } => "Closed app reopened",
+            Self::MoveToWorkspace { .. } => "Window moved back to its workspace",
+            Self::ResetWorkspaceSize => "Workspace sizes restored",
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::error_handler::Result;
+
+use super::Undo;
+
+#[tauri::command(async)]
+pub fn undo_last_action() -> Result<()> {
+    Undo::undo_last()
+}
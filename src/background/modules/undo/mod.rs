@@ -0,0 +1,100 @@
+pub mod cli;
+pub mod domain;
+pub mod infrastructure;
+
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use domain::UndoableAction;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use tauri_plugin_shell::ShellExt;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::virtual_desk::get_vd_manager,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::{Toast, WindowsApi},
+};
+
+lazy_static! {
+    static ref UNDO_STACK: Arc<Mutex<VecDeque<(Instant, UndoableAction)>>> =
+        Arc::new(Mutex::new(VecDeque::new()));
+}
+
+/// Keeps the recent destructive actions done from the dock or the window manager
+/// so they can be reverted from a shortcut.
+pub struct Undo;
+
+impl Undo {
+    const MAX_ENTRIES: usize = 20;
+    /// older actions are probably not expected to be undone anymore
+    const MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+    pub fn record(action: UndoableAction) {
+        log::trace!("Recording undoable action: {:?}", action);
+        let mut stack = trace_lock!(UNDO_STACK);
+        stack.push_back((Instant::now(), action));
+        while stack.len() > Self::MAX_ENTRIES {
+            stack.pop_front();
+        }
+    }
+
+    pub fn clear() {
+        trace_lock!(UNDO_STACK).clear();
+    }
+
+    fn pop() -> Option<UndoableAction> {
+        let mut stack = trace_lock!(UNDO_STACK);
+        stack.retain(|(at, _)| at.elapsed() < Self::MAX_AGE);
+        stack.pop_back().map(|(_, action)| action)
+    }
+
+    fn revert(action: &UndoableAction) -> Result<()> {
+        match action {
+            UndoableAction::UnpinWegItems { previous } => {
+                FULL_STATE.load().write_weg_items(previous)?;
+            }
+            UndoableAction::CloseWindows { execution_path } => {
+                get_app_handle()
+                    .shell()
+                    .command("explorer")
+                    .arg(execution_path)
+                    .spawn()?;
+            }
+            UndoableAction::MoveToWorkspace { hwnd, from } => {
+                if !WindowsApi::is_window(HWND(*hwnd)) {
+                    return Err("The moved window was closed".into());
+                }
+                get_vd_manager().send_to(*from, *hwnd)?;
+            }
+            UndoableAction::ResetWorkspaceSize => {
+                get_app_handle().emit("undo-reset-workspace-size", ())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reverts the most recent action and confirms it with a toast
+    pub fn undo_last() -> Result<()> {
+        let action = match Self::pop() {
+            Some(action) => action,
+            None => {
+                log_error!(Toast::show("Nothing to undo"));
+                return Ok(());
+            }
+        };
+        log::trace!("Undoing action: {:?}", action);
+        Self::revert(&action)?;
+        log_error!(Toast::show(action.description()));
+        Ok(())
+    }
+}
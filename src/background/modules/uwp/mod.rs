@@ -5,17 +5,27 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
-use tauri::Manager;
+use windows::{
+    core::{HSTRING, PCWSTR},
+    ApplicationModel::Package,
+    Management::Deployment::PackageManager,
+    Win32::{
+        Foundation::HWND,
+        UI::WindowsAndMessaging::{
+            MessageBoxW, IDYES, MB_ICONWARNING, MB_SETFOREGROUND, MB_TOPMOST, MB_YESNO,
+        },
+    },
+};
 
 use crate::{
     error_handler::Result,
     seelen::get_app_handle,
     trace_lock,
-    utils::{pwsh::PwshScript, PERFORMANCE_HELPER},
+    utils::{app_data_path, pwsh::PwshScript, PERFORMANCE_HELPER},
+    windows_api::WindowsApi,
 };
 
 pub static UWP_LIGHTUNPLATED_POSTFIX: &str = "_altform-lightunplated";
-#[allow(dead_code)]
 pub static UWP_UNPLATED_POSTFIX: &str = "_altform-unplated";
 
 lazy_static! {
@@ -87,19 +97,23 @@ impl UWPPackage {
             .find(|app| app.executable.ends_with(exe) || app.alias.as_deref() == Some(exe))
     }
 
-    pub fn get_light_icon_path(icon_path: &Path) -> Option<PathBuf> {
+    /// `light` selects the variant made for light surfaces, otherwise the one for dark surfaces
+    pub fn get_themed_icon_path(icon_path: &Path, light: bool) -> Option<PathBuf> {
         let filename = icon_path.file_stem()?.to_str()?;
         let extension = icon_path.extension()?.to_str()?;
+        let variant = if light {
+            UWP_LIGHTUNPLATED_POSTFIX
+        } else {
+            UWP_UNPLATED_POSTFIX
+        };
 
         let postfixes = (*UWP_TARGET_SIZE_POSTFIXES)
             .iter()
             .chain((*UWP_SCALE_POSTFIXES).iter());
 
         for postfix in postfixes {
-            let maybe_icon_path = icon_path.with_file_name(format!(
-                "{}{}{}.{}",
-                filename, postfix, UWP_LIGHTUNPLATED_POSTFIX, extension
-            ));
+            let maybe_icon_path = icon_path
+                .with_file_name(format!("{}{}{}.{}", filename, postfix, variant, extension));
             if maybe_icon_path.exists() {
                 return Some(maybe_icon_path);
             }
@@ -120,21 +134,20 @@ impl UWPPackage {
         None
     }
 
-    pub fn get_light_icon(&self, exe: &str) -> Option<PathBuf> {
+    pub fn get_themed_icon(&self, exe: &str, light: bool) -> Option<PathBuf> {
         let app = self.get_app(exe)?;
+        let themed = |sub_path: &String| {
+            Self::get_themed_icon_path(&self.install_location.join(sub_path), light)
+        };
 
         app.get_44_icon()
-            .and_then(|sub_path| Self::get_light_icon_path(&self.install_location.join(sub_path)))
-            .or_else(|| {
-                app.get_150_icon().and_then(|sub_path| {
-                    Self::get_light_icon_path(&self.install_location.join(sub_path))
-                })
-            })
-            .or_else(|| {
-                self.get_store_logo().and_then(|sub_path| {
-                    Self::get_light_icon_path(&self.install_location.join(sub_path))
-                })
-            })
+            .and_then(themed)
+            .or_else(|| app.get_150_icon().and_then(themed))
+            .or_else(|| self.get_store_logo().and_then(themed))
+    }
+
+    pub fn full_name(&self) -> &str {
+        &self.package_full_name
     }
 
     pub fn get_shell_path(&self, exe: &str) -> Option<String> {
@@ -153,10 +166,7 @@ pub struct WindowsAppsManager {
 
 impl WindowsAppsManager {
     fn get_save_path() -> Result<std::path::PathBuf> {
-        Ok(get_app_handle()
-            .path()
-            .app_data_dir()?
-            .join("uwp_manifests.json"))
+        Ok(app_data_path(&get_app_handle()).join("uwp_manifests.json"))
     }
 
     pub fn refresh(&mut self) -> Result<()> {
@@ -168,11 +178,16 @@ impl WindowsAppsManager {
         std::fs::write(Self::get_save_path()?, &contents)?;
         log::trace!(
             "UWP packages loaded in: {:.2}s",
-            trace_lock!(PERFORMANCE_HELPER).elapsed("uwp").as_secs_f64()
+            trace_lock!(PERFORMANCE_HELPER).end("uwp").as_secs_f64()
         );
         Ok(())
     }
 
+    /// package of an app by its executable file name
+    pub fn get_from_exe(&self, exe: &str) -> Option<&UWPPackage> {
+        self.packages.iter().find(|p| p.get_app(exe).is_some())
+    }
+
     pub fn get_from_path(&self, exe_path: &Path) -> Option<&UWPPackage> {
         let exe = exe_path.file_name()?.to_string_lossy().to_string();
         self.packages.iter().find(|p| {
@@ -183,3 +198,59 @@ impl WindowsAppsManager {
         })
     }
 }
+
+/// Actions over the package of an app launched by `shell:AppsFolder\{family name}!{app id}`
+pub struct PackagedApp;
+
+impl PackagedApp {
+    pub fn family_name(execution_path: &str) -> Option<&str> {
+        let aumid = execution_path.strip_prefix("shell:AppsFolder\\")?;
+        aumid.split_once('!').map(|(family, _)| family)
+    }
+
+    fn package(execution_path: &str) -> Result<Package> {
+        let family = Self::family_name(execution_path).ok_or("Not a packaged app")?;
+        // empty user security id means the current user
+        let packages = PackageManager::new()?.FindPackagesByUserSecurityIdPackageFamilyName(
+            &HSTRING::new(),
+            &HSTRING::from(family),
+        )?;
+        let package = packages.into_iter().next().ok_or("Package not found")?;
+        Ok(package)
+    }
+
+    pub fn open_settings(execution_path: &str) -> Result<()> {
+        let family = Self::family_name(execution_path).ok_or("Not a packaged app")?;
+        WindowsApi::shell_open(&format!("ms-settings:appsfeatures-app?{family}"))
+    }
+
+    /// Asks for confirmation before removing the package, returns false if cancelled.
+    pub fn uninstall(execution_path: &str) -> Result<bool> {
+        let package = Self::package(execution_path)?;
+        let name = package.DisplayName()?.to_string();
+        let text = HSTRING::from(format!(
+            "{name} and all its data will be removed from this device. Continue?"
+        ));
+        let answer = unsafe {
+            MessageBoxW(
+                HWND(0),
+                PCWSTR(text.as_ptr()),
+                windows::core::w!("Uninstall app"),
+                MB_YESNO | MB_ICONWARNING | MB_SETFOREGROUND | MB_TOPMOST,
+            )
+        };
+        if answer != IDYES {
+            return Ok(false);
+        }
+
+        let full_name = package.Id()?.FullName()?;
+        log::info!("Uninstalling package {}", full_name);
+        let result = PackageManager::new()?
+            .RemovePackageAsync(&full_name)?
+            .get()?;
+        if result.ExtendedErrorCode()?.is_err() {
+            return Err(result.ErrorText()?.to_string().into());
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,81 @@
+use serde_json::Value;
+use tauri_plugin_shell::{process::CommandEvent, ShellExt};
+
+use crate::{error_handler::Result, seelen::get_app_handle};
+
+use super::{collect_handles, run, ExternalState, ExternalWorkspace};
+
+const CLI: &str = "glazewm";
+
+/// events that change the workspaces or the windows on them
+const EVENTS: [&str; 6] = [
+    "workspace_activated",
+    "workspace_deactivated",
+    "workspace_updated",
+    "focus_changed",
+    "window_managed",
+    "window_unmanaged",
+];
+
+/// GlazeWM lists the workspaces of all the monitors, only the ones sharing
+/// the monitor (parent) of the focused workspace are used.
+fn query() -> Result<ExternalState> {
+    let response: Value = serde_json::from_str(&run(CLI, &["query", "workspaces"])?)?;
+    let all = response["data"]["workspaces"]
+        .as_array()
+        .ok_or("Unexpected GlazeWM response")?;
+
+    let focused = all.iter().find(|w| w["hasFocus"].as_bool() == Some(true));
+    let monitor = focused.map(|w| &w["parentId"]);
+    let on_monitor = all
+        .iter()
+        .filter(|w| monitor.is_none() || Some(&w["parentId"]) == monitor);
+
+    let mut state = ExternalState::default();
+    for (idx, workspace) in on_monitor.enumerate() {
+        let Some(name) = workspace["name"].as_str() else {
+            continue;
+        };
+        if workspace["hasFocus"].as_bool() == Some(true) {
+            state.focused = idx;
+        }
+        let mut windows = Vec::new();
+        collect_handles(&workspace["children"], "handle", &mut windows);
+        state.workspaces.push(ExternalWorkspace {
+            id: name.to_string(),
+            name: workspace["displayName"].as_str().map(String::from),
+            windows,
+        });
+    }
+    Ok(state)
+}
+
+/// Each event is followed by a query of the workspaces, as the events only include the
+/// changed container. Blocks until the subscription process ends.
+pub fn subscribe(on_state: &mut dyn FnMut(ExternalState)) -> Result<()> {
+    let mut args = vec!["sub", "--events"];
+    args.extend(EVENTS);
+    let (mut receiver, _child) = get_app_handle().shell().command(CLI).args(args).spawn()?;
+    log::info!("Subscribed to GlazeWM events");
+
+    on_state(query()?);
+    while let Some(event) = receiver.blocking_recv() {
+        match event {
+            CommandEvent::Stdout(_) => on_state(query()?),
+            CommandEvent::Error(err) => return Err(err.into()),
+            CommandEvent::Terminated(_) => break,
+            _ => {}
+        }
+    }
+    Err("GlazeWM subscription ended".into())
+}
+
+pub fn focus_workspace(name: &str) -> Result<()> {
+    run(CLI, &["command", "focus", "--workspace", name])?;
+    Ok(())
+}
+
+pub fn move_to_workspace(name: &str) -> Result<()> {
+    run(CLI, &["command", "move", "--workspace", name])?;
+    Ok(())
+}
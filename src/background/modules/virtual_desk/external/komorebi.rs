@@ -0,0 +1,100 @@
+use std::{fs::File, io::BufReader, os::windows::io::FromRawHandle};
+
+use serde_json::Value;
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::ERROR_PIPE_CONNECTED,
+        Storage::FileSystem::PIPE_ACCESS_INBOUND,
+        System::Pipes::{
+            ConnectNamedPipe, CreateNamedPipeW, PIPE_READMODE_BYTE, PIPE_TYPE_BYTE, PIPE_WAIT,
+        },
+    },
+};
+
+use crate::error_handler::Result;
+
+use super::{collect_handles, run, ExternalState, ExternalWorkspace};
+
+const CLI: &str = "komorebic";
+const PIPE_NAME: &str = "seelen-ui";
+
+/// Komorebi has per monitor workspaces, only the ones of the focused monitor are used.
+/// The state is a tree of rings as `{ elements: [...], focused: idx }`.
+fn parse_state(state: &Value) -> Result<ExternalState> {
+    let monitors = &state["monitors"];
+    let monitor_idx = monitors["focused"].as_u64().unwrap_or(0) as usize;
+    let monitor = &monitors["elements"][monitor_idx];
+    let ring = &monitor["workspaces"];
+    let elements = ring["elements"]
+        .as_array()
+        .ok_or("Unexpected komorebi state")?;
+
+    let workspaces = elements
+        .iter()
+        .enumerate()
+        .map(|(idx, workspace)| {
+            let mut windows = Vec::new();
+            collect_handles(workspace, "hwnd", &mut windows);
+            ExternalWorkspace {
+                id: format!("komorebi-{monitor_idx}-{idx}"),
+                name: workspace["name"].as_str().map(String::from),
+                windows,
+            }
+        })
+        .collect();
+
+    Ok(ExternalState {
+        workspaces,
+        focused: ring["focused"].as_u64().unwrap_or(0) as usize,
+    })
+}
+
+/// Creates the named pipe and asks komorebi to write its notifications on it,
+/// each notification includes the full state. Blocks until the pipe is closed.
+pub fn subscribe(on_state: &mut dyn FnMut(ExternalState)) -> Result<()> {
+    let name = HSTRING::from(format!(r"\\.\pipe\{PIPE_NAME}"));
+    let pipe = unsafe {
+        CreateNamedPipeW(
+            &name,
+            PIPE_ACCESS_INBOUND,
+            PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+            1,
+            0,
+            64 * 1024,
+            0,
+            None,
+        )
+    };
+    if pipe.is_invalid() {
+        return Err(windows::core::Error::from_win32().into());
+    }
+    // the file owns the handle from here, closing it on drop
+    let file = unsafe { File::from_raw_handle(pipe.0 as _) };
+
+    run(CLI, &["subscribe-pipe", PIPE_NAME])?;
+    if let Err(err) = unsafe { ConnectNamedPipe(pipe, None) } {
+        // komorebi connected before the call
+        if err.code() != ERROR_PIPE_CONNECTED.to_hresult() {
+            return Err(err.into());
+        }
+    }
+    log::info!("Subscribed to komorebi notifications");
+
+    // notifications are concatenated json objects
+    let reader = BufReader::new(file);
+    for notification in serde_json::Deserializer::from_reader(reader).into_iter::<Value>() {
+        on_state(parse_state(&notification?["state"])?);
+    }
+    Err("komorebi closed the pipe".into())
+}
+
+pub fn focus_workspace(idx: usize) -> Result<()> {
+    run(CLI, &["focus-workspace", &idx.to_string()])?;
+    Ok(())
+}
+
+pub fn move_to_workspace(idx: usize) -> Result<()> {
+    run(CLI, &["move-to-workspace", &idx.to_string()])?;
+    Ok(())
+}
@@ -0,0 +1,291 @@
+mod glazewm;
+mod komorebi;
+
+use std::sync::mpsc::Sender;
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::WmInterop;
+use serde_json::Value;
+use tauri_plugin_shell::ShellExt;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::{AppError, Result},
+    modules::focus::FocusRequest,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+use super::{VirtualDesktop, VirtualDesktopEvent, VirtualDesktopManagerTrait, VirtualDesktopTrait};
+
+lazy_static! {
+    /// last state reported by the external window manager
+    static ref STATE: Mutex<ExternalState> = Mutex::new(ExternalState::default());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalWorkspace {
+    id: String,
+    name: Option<String>,
+    windows: Vec<isize>,
+}
+
+impl From<ExternalWorkspace> for VirtualDesktop {
+    fn from(value: ExternalWorkspace) -> Self {
+        VirtualDesktop::External(value)
+    }
+}
+
+impl VirtualDesktopTrait for ExternalWorkspace {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+}
+
+/// Workspaces of the focused monitor, as the seelen model only has one list of workspaces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExternalState {
+    workspaces: Vec<ExternalWorkspace>,
+    focused: usize,
+}
+
+impl ExternalState {
+    fn current(&self) -> Option<&ExternalWorkspace> {
+        self.workspaces.get(self.focused)
+    }
+
+    /// the events the native and seelen managers would send for the same change
+    fn diff(&self, new: &ExternalState) -> Vec<VirtualDesktopEvent> {
+        let mut events = Vec::new();
+        let Some(new_current) = new.current() else {
+            return events;
+        };
+        // first state received, reported as a change to itself to refresh the active one
+        let Some(old_current) = self.current() else {
+            events.push(VirtualDesktopEvent::DesktopCreated(
+                new_current.clone().into(),
+            ));
+            events.push(VirtualDesktopEvent::DesktopChanged {
+                new: new_current.clone().into(),
+                old: new_current.clone().into(),
+            });
+            return events;
+        };
+
+        let names = |state: &ExternalState| -> Vec<(String, Option<String>)> {
+            let list = state.workspaces.iter();
+            list.map(|w| (w.id.clone(), w.name.clone())).collect()
+        };
+        if names(self) != names(new) {
+            let last = new.workspaces.last().unwrap_or(new_current).clone();
+            events.push(match new.workspaces.len().cmp(&self.workspaces.len()) {
+                std::cmp::Ordering::Greater => VirtualDesktopEvent::DesktopCreated(last.into()),
+                std::cmp::Ordering::Less => VirtualDesktopEvent::DesktopDestroyed {
+                    destroyed: self.workspaces.last().cloned().unwrap_or(last).into(),
+                    fallback: new_current.clone().into(),
+                },
+                std::cmp::Ordering::Equal => {
+                    let name = new_current.name.clone().unwrap_or_default();
+                    VirtualDesktopEvent::DesktopNameChanged(new_current.clone().into(), name)
+                }
+            });
+        }
+
+        if old_current.id != new_current.id {
+            events.push(VirtualDesktopEvent::DesktopChanged {
+                new: new_current.clone().into(),
+                old: old_current.clone().into(),
+            });
+        }
+
+        for workspace in &new.workspaces {
+            for window in &workspace.windows {
+                let moved = self
+                    .workspaces
+                    .iter()
+                    .all(|old| old.id != workspace.id || !old.windows.contains(window));
+                if moved {
+                    events.push(VirtualDesktopEvent::WindowChanged(*window));
+                }
+            }
+        }
+        events
+    }
+}
+
+/// Collects the numbers found on the `key` fields of the tree, used to get the windows
+/// of a workspace without depending on how the external manager nests its containers.
+fn collect_handles(value: &Value, key: &str, handles: &mut Vec<isize>) {
+    match value {
+        Value::Object(map) => {
+            for (name, value) in map {
+                match value.as_i64() {
+                    Some(handle) if name == key => handles.push(handle as isize),
+                    _ => collect_handles(value, key, handles),
+                }
+            }
+        }
+        Value::Array(list) => list.iter().for_each(|v| collect_handles(v, key, handles)),
+        _ => {}
+    }
+}
+
+/// Runs a command of the external manager cli and returns its output.
+fn run(program: &str, args: &[&str]) -> Result<String> {
+    let output = tauri::async_runtime::block_on(
+        get_app_handle()
+            .shell()
+            .command(program)
+            .args(args)
+            .output(),
+    )?;
+    if !output.status.success() {
+        return Err(output.into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn none_err() -> AppError {
+    "External workspace not found".into()
+}
+
+/// Workspaces provided by komorebi or GlazeWM, switching and moving windows is forwarded
+/// to their cli and the state is received by subscribing to their events.
+#[derive(Debug)]
+pub struct ExternalWorkspacesManager {
+    interop: WmInterop,
+}
+
+impl ExternalWorkspacesManager {
+    const RETRY_INTERVAL: u64 = 5000;
+
+    pub fn new(interop: WmInterop) -> Self {
+        Self { interop }
+    }
+
+    fn state() -> ExternalState {
+        trace_lock!(STATE).clone()
+    }
+
+    fn update(new: ExternalState, sender: &Sender<VirtualDesktopEvent>) {
+        let old = std::mem::replace(&mut *trace_lock!(STATE), new.clone());
+        for event in old.diff(&new) {
+            if sender.send(event).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn subscribe(interop: WmInterop, on_state: &mut dyn FnMut(ExternalState)) -> Result<()> {
+        match interop {
+            WmInterop::Komorebi => komorebi::subscribe(on_state),
+            WmInterop::GlazeWM => glazewm::subscribe(on_state),
+            WmInterop::None => Err("No external window manager selected".into()),
+        }
+    }
+
+    fn workspace(idx: usize) -> Result<ExternalWorkspace> {
+        Self::state()
+            .workspaces
+            .get(idx)
+            .cloned()
+            .ok_or_else(none_err)
+    }
+}
+
+impl VirtualDesktopManagerTrait for ExternalWorkspacesManager {
+    fn create_desktop(&self) -> Result<()> {
+        Err("Workspaces are managed by the external window manager".into())
+    }
+
+    fn get(&self, idx: usize) -> Result<Option<VirtualDesktop>> {
+        Ok(Self::state().workspaces.get(idx).cloned().map(Into::into))
+    }
+
+    fn get_all(&self) -> Result<Vec<VirtualDesktop>> {
+        Ok(Self::state()
+            .workspaces
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    fn get_by_window(&self, window: isize) -> Result<VirtualDesktop> {
+        let state = Self::state();
+        state
+            .workspaces
+            .iter()
+            .find(|w| w.windows.contains(&window))
+            .or(state.current())
+            .cloned()
+            .map(Into::into)
+            .ok_or_else(none_err)
+    }
+
+    fn get_current(&self) -> Result<VirtualDesktop> {
+        Self::state()
+            .current()
+            .cloned()
+            .map(Into::into)
+            .ok_or_else(none_err)
+    }
+
+    fn get_current_idx(&self) -> Result<usize> {
+        Ok(Self::state().focused)
+    }
+
+    fn switch_to(&self, idx: usize) -> Result<()> {
+        let workspace = Self::workspace(idx)?;
+        match self.interop {
+            WmInterop::Komorebi => komorebi::focus_workspace(idx),
+            WmInterop::GlazeWM => glazewm::focus_workspace(&workspace.id),
+            WmInterop::None => Ok(()),
+        }
+    }
+
+    /// both managers move the focused window, so the window is focused first
+    fn send_to(&self, idx: usize, window: isize) -> Result<()> {
+        let workspace = Self::workspace(idx)?;
+        FocusRequest::request(HWND(window))?;
+        match self.interop {
+            WmInterop::Komorebi => komorebi::move_to_workspace(idx),
+            WmInterop::GlazeWM => glazewm::move_to_workspace(&workspace.id),
+            WmInterop::None => Ok(()),
+        }
+    }
+
+    fn pin_window(&self, _window: isize) -> Result<()> {
+        Err("Pinning windows is not supported on interop mode".into())
+    }
+
+    fn unpin_window(&self, _window: isize) -> Result<()> {
+        Err("Pinning windows is not supported on interop mode".into())
+    }
+
+    fn is_pinned_window(&self, _window: isize) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn listen_events(&self, sender: Sender<VirtualDesktopEvent>) -> Result<()> {
+        let interop = self.interop;
+        spawn_named_thread("External WM Interop", move || loop {
+            let mut on_state = |state| Self::update(state, &sender);
+            if let Err(err) = Self::subscribe(interop, &mut on_state) {
+                log::warn!("Lost connection with {:?}: {:?}", interop, err);
+            }
+            sleep_millis(Self::RETRY_INTERVAL);
+        })?;
+        Ok(())
+    }
+
+    /// both komorebi and GlazeWM hide the windows of other workspaces by cloaking them
+    fn uses_cloak(&self) -> bool {
+        true
+    }
+}
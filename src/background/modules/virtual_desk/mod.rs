@@ -1,24 +1,23 @@
+mod external;
+pub mod naming;
 mod native;
+pub mod visuals;
 mod workspaces;
 
 use arc_swap::ArcSwap;
 use lazy_static::lazy_static;
-use seelen_core::state::VirtualDesktopStrategy;
+use seelen_core::state::{VirtualDesktopStrategy, WmInterop};
 use serde::Serialize;
 use std::sync::Arc;
 
-use crate::{error_handler::Result, state::application::FULL_STATE};
+use crate::{
+    error_handler::Result, modules::os_capabilities::domain::OsCapabilities,
+    state::application::FULL_STATE,
+};
 
 lazy_static! {
     static ref VIRTUAL_DESKTOP_MANAGER: Arc<ArcSwap<VirtualDesktopManager>> =
-        Arc::new(ArcSwap::from_pointee(
-            match FULL_STATE.load().settings().virtual_desktop_strategy {
-                VirtualDesktopStrategy::Native =>
-                    VirtualDesktopManager::Native(native::NativeVirtualDesktopManager::new()),
-                VirtualDesktopStrategy::Seelen =>
-                    VirtualDesktopManager::Seelen(workspaces::SeelenWorkspacesManager::new()),
-            }
-        ));
+        Arc::new(ArcSwap::from_pointee(VirtualDesktopManager::from_settings()));
 }
 
 trait VirtualDesktopTrait: std::fmt::Debug + Clone {
@@ -53,6 +52,7 @@ trait VirtualDesktopManagerTrait {
 pub enum VirtualDesktop {
     Native(native::NativeVirtualDesktop),
     Seelen(workspaces::SeelenWorkspace),
+    External(external::ExternalWorkspace),
 }
 
 #[derive(Serialize)]
@@ -66,6 +66,7 @@ impl VirtualDesktop {
         match self {
             VirtualDesktop::Native(d) => d.id(),
             VirtualDesktop::Seelen(d) => d.id(),
+            VirtualDesktop::External(d) => d.id(),
         }
     }
 
@@ -73,6 +74,7 @@ impl VirtualDesktop {
         match self {
             VirtualDesktop::Native(d) => d.name(),
             VirtualDesktop::Seelen(d) => d.name(),
+            VirtualDesktop::External(d) => d.name(),
         }
     }
 
@@ -88,13 +90,38 @@ impl VirtualDesktop {
 pub enum VirtualDesktopManager {
     Native(native::NativeVirtualDesktopManager),
     Seelen(workspaces::SeelenWorkspacesManager),
+    External(external::ExternalWorkspacesManager),
 }
 
 impl VirtualDesktopManager {
+    /// the workspaces of an external window manager replace the selected strategy,
+    /// and the native one fallbacks to seelen workspaces if the OS interfaces are not supported
+    fn from_settings() -> Self {
+        let state = FULL_STATE.load();
+        let settings = state.settings();
+        match (
+            settings.window_manager.interop,
+            &settings.virtual_desktop_strategy,
+        ) {
+            (WmInterop::None, VirtualDesktopStrategy::Native)
+                if OsCapabilities::get().native_virtual_desktops =>
+            {
+                VirtualDesktopManager::Native(native::NativeVirtualDesktopManager::new())
+            }
+            (WmInterop::None, _) => {
+                VirtualDesktopManager::Seelen(workspaces::SeelenWorkspacesManager::new())
+            }
+            (interop, _) => {
+                VirtualDesktopManager::External(external::ExternalWorkspacesManager::new(interop))
+            }
+        }
+    }
+
     pub fn create_desktop(&self) -> Result<()> {
         match self {
             VirtualDesktopManager::Native(m) => m.create_desktop(),
             VirtualDesktopManager::Seelen(m) => m.create_desktop(),
+            VirtualDesktopManager::External(m) => m.create_desktop(),
         }
     }
 
@@ -102,6 +129,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.get(idx),
             VirtualDesktopManager::Seelen(m) => m.get(idx),
+            VirtualDesktopManager::External(m) => m.get(idx),
         }
     }
 
@@ -109,6 +137,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.get_all(),
             VirtualDesktopManager::Seelen(m) => m.get_all(),
+            VirtualDesktopManager::External(m) => m.get_all(),
         }
     }
 
@@ -116,6 +145,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.get_by_window(window),
             VirtualDesktopManager::Seelen(m) => m.get_by_window(window),
+            VirtualDesktopManager::External(m) => m.get_by_window(window),
         }
     }
 
@@ -123,6 +153,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.get_current(),
             VirtualDesktopManager::Seelen(m) => m.get_current(),
+            VirtualDesktopManager::External(m) => m.get_current(),
         }
     }
 
@@ -130,6 +161,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.get_current_idx(),
             VirtualDesktopManager::Seelen(m) => m.get_current_idx(),
+            VirtualDesktopManager::External(m) => m.get_current_idx(),
         }
     }
 
@@ -137,6 +169,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.switch_to(idx),
             VirtualDesktopManager::Seelen(m) => m.switch_to(idx),
+            VirtualDesktopManager::External(m) => m.switch_to(idx),
         }
     }
 
@@ -144,6 +177,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.send_to(idx, window),
             VirtualDesktopManager::Seelen(m) => m.send_to(idx, window),
+            VirtualDesktopManager::External(m) => m.send_to(idx, window),
         }
     }
 
@@ -151,6 +185,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.pin_window(window),
             VirtualDesktopManager::Seelen(m) => m.pin_window(window),
+            VirtualDesktopManager::External(m) => m.pin_window(window),
         }
     }
 
@@ -158,6 +193,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.unpin_window(window),
             VirtualDesktopManager::Seelen(m) => m.unpin_window(window),
+            VirtualDesktopManager::External(m) => m.unpin_window(window),
         }
     }
 
@@ -165,6 +201,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.is_pinned_window(window),
             VirtualDesktopManager::Seelen(m) => m.is_pinned_window(window),
+            VirtualDesktopManager::External(m) => m.is_pinned_window(window),
         }
     }
 
@@ -172,6 +209,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.listen_events(cb),
             VirtualDesktopManager::Seelen(m) => m.listen_events(cb),
+            VirtualDesktopManager::External(m) => m.listen_events(cb),
         }
     }
 
@@ -179,6 +217,7 @@ impl VirtualDesktopManager {
         match self {
             VirtualDesktopManager::Native(m) => m.uses_cloak(),
             VirtualDesktopManager::Seelen(m) => m.uses_cloak(),
+            VirtualDesktopManager::External(m) => m.uses_cloak(),
         }
     }
 }
@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::Emitter;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::get_app_handle,
+    seelen_weg::{icon_extractor::extract_and_save_icon, rules::WegVerdict},
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::{WindowEnumerator, WindowsApi},
+};
+
+use super::get_vd_manager;
+
+static SCHEDULED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref LAST_NAMES: Mutex<Vec<WorkspaceAutoName>> = Mutex::new(Vec::new());
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceAutoName {
+    id: String,
+    name: String,
+    /// icon of the app the workspace was named after
+    icon: Option<PathBuf>,
+}
+
+/// Names the workspaces after the app with more windows on each one.
+pub struct WorkspaceNames;
+
+impl WorkspaceNames {
+    /// windows are usually opened and closed in bursts, so the names are calculated once
+    /// the burst ends instead of on each event.
+    const DEBOUNCE_MS: u64 = 500;
+
+    pub fn request_update() {
+        if !FULL_STATE
+            .load()
+            .settings()
+            .window_manager
+            .auto_workspace_names
+            || SCHEDULED.swap(true, Ordering::SeqCst)
+        {
+            return;
+        }
+
+        let spawned = spawn_named_thread("Workspace Names", || {
+            sleep_millis(Self::DEBOUNCE_MS);
+            SCHEDULED.store(false, Ordering::SeqCst);
+            log_error!(Self::update());
+        });
+        if spawned.is_err() {
+            SCHEDULED.store(false, Ordering::SeqCst);
+        }
+    }
+
+    pub fn get() -> Vec<WorkspaceAutoName> {
+        trace_lock!(LAST_NAMES).clone()
+    }
+
+    /// clears the names so the toolbar shows the original ones again
+    pub fn clear() -> Result<()> {
+        let mut last = trace_lock!(LAST_NAMES);
+        if !last.is_empty() {
+            last.clear();
+            get_app_handle().emit("workspaces-auto-names", &*last)?;
+        }
+        Ok(())
+    }
+
+    fn update() -> Result<()> {
+        let names = Self::calculate()?;
+        let mut last = trace_lock!(LAST_NAMES);
+        if *last != names {
+            get_app_handle().emit("workspaces-auto-names", &names)?;
+            *last = names;
+        }
+        Ok(())
+    }
+
+    /// windows that would be shown on the dock, grouped by workspace and then by exe
+    fn windows_by_workspace() -> Result<HashMap<String, HashMap<String, Vec<HWND>>>> {
+        let vd = get_vd_manager();
+        let mut result: HashMap<String, HashMap<String, Vec<HWND>>> = HashMap::new();
+        for hwnd in WindowEnumerator::new().map(|hwnd| hwnd)? {
+            if !WegVerdict::evaluate(hwnd).accepted || vd.is_pinned_window(hwnd.0)? {
+                continue;
+            }
+            let (Ok(exe), Ok(desktop)) = (WindowsApi::exe_path(hwnd), vd.get_by_window(hwnd.0))
+            else {
+                continue;
+            };
+            result
+                .entry(desktop.id())
+                .or_default()
+                .entry(exe)
+                .or_default()
+                .push(hwnd);
+        }
+        Ok(result)
+    }
+
+    fn calculate() -> Result<Vec<WorkspaceAutoName>> {
+        let template = FULL_STATE
+            .load()
+            .settings()
+            .window_manager
+            .workspace_name_template
+            .clone();
+        let mut windows = Self::windows_by_workspace()?;
+
+        let mut names = Vec::new();
+        for (idx, desktop) in get_vd_manager().get_all()?.iter().enumerate() {
+            let id = desktop.id();
+            // ties are resolved by exe path to avoid flickering between equally used apps
+            let dominant = windows.remove(&id).and_then(|by_exe| {
+                by_exe
+                    .into_iter()
+                    .max_by(|(a_exe, a), (b_exe, b)| a.len().cmp(&b.len()).then(b_exe.cmp(a_exe)))
+            });
+            let Some((exe, handles)) = dominant else {
+                continue;
+            };
+
+            let app = WindowsApi::get_window_display_name(handles[0]).unwrap_or_else(|_| {
+                exe.rsplit('\\')
+                    .next()
+                    .unwrap_or_default()
+                    .trim_end_matches(".exe")
+                    .to_string()
+            });
+            let user_name = desktop
+                .name()
+                .unwrap_or_else(|| format!("Workspace {}", idx + 1));
+
+            names.push(WorkspaceAutoName {
+                name: template
+                    .replace("{index}", &(idx + 1).to_string())
+                    .replace("{app}", &app)
+                    .replace("{name}", &user_name),
+                icon: extract_and_save_icon(&get_app_handle(), &exe).ok(),
+                id,
+            });
+        }
+        Ok(names)
+    }
+}
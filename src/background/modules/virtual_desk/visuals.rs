@@ -0,0 +1,99 @@
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use windows::{
+    core::HSTRING,
+    Win32::{
+        System::Com::CoTaskMemFree,
+        UI::Shell::{DesktopWallpaper, IDesktopWallpaper},
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::{Com, MonitorEnumerator, WindowsApi},
+};
+
+lazy_static! {
+    static ref ACCENT: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// Wallpaper and accent color of the workspaces, as set on the per monitor settings.
+/// Both are applied from here on switch instead of by each webview, so the toolbars,
+/// docks and the desktop change together and only when the values really change.
+pub struct WorkspaceVisuals;
+
+impl WorkspaceVisuals {
+    pub fn accent() -> Option<String> {
+        trace_lock!(ACCENT).clone()
+    }
+
+    pub fn apply(idx: usize) -> Result<()> {
+        let state = FULL_STATE.load();
+        let settings = state.settings();
+        let configured = settings.monitors.iter().any(|monitor| {
+            monitor
+                .workspaces
+                .iter()
+                .any(|w| w.wallpaper.is_some() || w.accent.is_some())
+        });
+        if !configured {
+            return Ok(());
+        }
+
+        let mut accent = None;
+        let mut wallpapers = Vec::new();
+        for hmonitor in MonitorEnumerator::new_refreshed()? {
+            let device_id = WindowsApi::monitor_stable_id(hmonitor)?;
+            let Some(workspace) = settings
+                .monitor_by_id(&device_id)
+                .and_then(|m| m.workspaces.get(idx))
+            else {
+                continue;
+            };
+            if let Some(wallpaper) = &workspace.wallpaper {
+                wallpapers.push((device_id, wallpaper.clone()));
+            }
+            // the accent is global to the webviews, so the primary monitor decides it
+            if hmonitor == WindowsApi::primary_monitor() {
+                accent = workspace.accent.clone();
+            }
+        }
+
+        Self::set_wallpapers(&wallpapers)?;
+
+        let mut current = trace_lock!(ACCENT);
+        if *current != accent {
+            get_app_handle().emit("workspace-accent", &accent)?;
+            *current = accent;
+        }
+        Ok(())
+    }
+
+    /// setting the same wallpaper again makes the desktop blink, so unchanged ones are skipped
+    fn set_wallpapers(wallpapers: &[(String, String)]) -> Result<()> {
+        if wallpapers.is_empty() {
+            return Ok(());
+        }
+        Com::run_with_context(|| {
+            let manager: IDesktopWallpaper = Com::create_instance(&DesktopWallpaper)?;
+            for (device_id, path) in wallpapers {
+                let device_id = HSTRING::from(device_id);
+                let current = unsafe {
+                    let current = manager.GetWallpaper(&device_id)?;
+                    let value = current.to_string().unwrap_or_default();
+                    CoTaskMemFree(Some(current.0 as _));
+                    value
+                };
+                if current.eq_ignore_ascii_case(path) {
+                    continue;
+                }
+                unsafe { manager.SetWallpaper(&device_id, &HSTRING::from(path))? };
+            }
+            Ok(())
+        })
+    }
+}
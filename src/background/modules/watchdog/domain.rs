@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ComponentStatus {
+    Running,
+    /// the thread is alive but stopped sending heartbeats
+    Unresponsive,
+    /// the thread finished unexpectedly, usually by a panic
+    Crashed,
+}
+
+impl Display for ComponentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComponentStatus::Running => write!(f, "running"),
+            ComponentStatus::Unresponsive => write!(f, "unresponsive"),
+            ComponentStatus::Crashed => write!(f, "crashed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: ComponentStatus,
+    /// milliseconds since the last heartbeat
+    pub last_heartbeat: u64,
+    pub restarts: u32,
+}
+
+impl Display for ComponentHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<20} {:<14} last heartbeat {:>6}ms ago   restarts: {}",
+            self.name, self.status, self.last_heartbeat, self.restarts
+        )
+    }
+}
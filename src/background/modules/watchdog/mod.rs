@@ -0,0 +1,210 @@
+pub mod domain;
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use domain::{ComponentHealth, ComponentStatus};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::{
+    Foundation::{LPARAM, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::WindowsAndMessaging::{PostThreadMessageW, WM_NULL, WM_QUIT},
+};
+
+use crate::{error_handler::Result, log_error, trace_lock, utils::spawn_named_thread};
+
+/// Spawns the thread of a component, the generation is used by the thread to know
+/// if it was replaced by a restart (see `Watchdog::heartbeat`).
+pub type ComponentSpawner = fn(u32) -> Result<JoinHandle<()>>;
+
+lazy_static! {
+    static ref COMPONENTS: Arc<Mutex<HashMap<&'static str, Component>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+}
+
+static SUPERVISOR_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Shared by all the components and never reset, so a component supervised again can not
+/// take the generation of a previous thread that is still running.
+static NEXT_GENERATION: AtomicU32 = AtomicU32::new(0);
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+const MAX_RESTARTS: u32 = 3;
+
+struct Component {
+    spawn: ComponentSpawner,
+    /// `None` while the thread is being spawned
+    thread: Option<JoinHandle<()>>,
+    generation: u32,
+    /// max time without heartbeats before the component is considered hung
+    timeout: Duration,
+    last_heartbeat: Instant,
+    /// os thread id of components running a message loop, pinged on each check
+    message_loop: Option<u32>,
+    status: ComponentStatus,
+    restarts: u32,
+    /// stopped on purpose, it is not restarted anymore
+    released: bool,
+}
+
+impl Component {
+    /// Updates the status, returns the generation to be spawned if the component needs a restart
+    fn check(&mut self, name: &str) -> Option<u32> {
+        if self.released {
+            return None;
+        }
+        let finished = self
+            .thread
+            .as_ref()
+            .is_some_and(|thread| thread.is_finished());
+        self.status = if finished {
+            ComponentStatus::Crashed
+        } else if self.last_heartbeat.elapsed() > self.timeout {
+            ComponentStatus::Unresponsive
+        } else {
+            ComponentStatus::Running
+        };
+
+        if self.status == ComponentStatus::Running {
+            if let Some(thread_id) = self.message_loop {
+                // any message wakes up the loop and makes it send a heartbeat
+                log_error!(unsafe { PostThreadMessageW(thread_id, WM_NULL, WPARAM(0), LPARAM(0)) });
+            }
+            return None;
+        }
+
+        if self.restarts >= MAX_RESTARTS {
+            return None;
+        }
+
+        log::warn!("Watchdog: {} is {}, restarting it", name, self.status);
+        self.restarts += 1;
+        self.generation = NEXT_GENERATION.fetch_add(1, Ordering::SeqCst);
+        self.message_loop = None;
+        self.last_heartbeat = Instant::now();
+        self.thread = None;
+        if self.restarts == MAX_RESTARTS {
+            log::error!("Watchdog: {} reached the max amount of restarts", name);
+        }
+        Some(self.generation)
+    }
+}
+
+/// Supervises the long running threads of the app (hooks, message loops, pollers),
+/// restarting them when they panic or stop sending heartbeats.
+pub struct Watchdog;
+
+impl Watchdog {
+    /// Spawns the component and keeps track of it
+    pub fn supervise(name: &'static str, timeout: Duration, spawn: ComponentSpawner) -> Result<()> {
+        let generation = NEXT_GENERATION.fetch_add(1, Ordering::SeqCst);
+        // registered first so the new thread can already send heartbeats
+        trace_lock!(COMPONENTS).insert(
+            name,
+            Component {
+                spawn,
+                thread: None,
+                generation,
+                timeout,
+                last_heartbeat: Instant::now(),
+                message_loop: None,
+                status: ComponentStatus::Running,
+                restarts: 0,
+                released: false,
+            },
+        );
+        if let Err(err) = Self::spawn_component(name, spawn, generation) {
+            trace_lock!(COMPONENTS).remove(name);
+            return Err(err);
+        }
+        Self::start_supervisor()
+    }
+
+    /// Spawns without holding the lock, the component thread could need it right away
+    fn spawn_component(name: &str, spawn: ComponentSpawner, generation: u32) -> Result<()> {
+        let thread = spawn(generation)?;
+        if let Some(component) = trace_lock!(COMPONENTS).get_mut(name) {
+            if component.generation == generation {
+                component.thread = Some(thread);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns false if the caller was replaced by a newer generation and should exit
+    pub fn heartbeat(name: &str, generation: u32) -> bool {
+        match trace_lock!(COMPONENTS).get_mut(name) {
+            Some(component) if component.released => false,
+            Some(component) if component.generation == generation => {
+                component.last_heartbeat = Instant::now();
+                true
+            }
+            Some(_) => false,
+            None => true,
+        }
+    }
+
+    /// Should be called from the thread that owns the message loop, the watchdog will
+    /// post a message to it on each check so the loop can send heartbeats while idle.
+    pub fn attach_message_loop(name: &str, generation: u32) {
+        if let Some(component) = trace_lock!(COMPONENTS).get_mut(name) {
+            if component.generation == generation {
+                component.message_loop = Some(unsafe { GetCurrentThreadId() });
+            }
+        }
+    }
+
+    /// Stops supervising the component and asks it to exit, the next heartbeat returns false
+    /// and the message loops receive a `WM_QUIT`.
+    pub fn release(name: &str) -> Result<()> {
+        if let Some(component) = trace_lock!(COMPONENTS).get_mut(name) {
+            component.released = true;
+            if let Some(thread_id) = component.message_loop {
+                unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0))? };
+            }
+        }
+        Ok(())
+    }
+
+    pub fn report() -> Vec<ComponentHealth> {
+        let mut report: Vec<ComponentHealth> = trace_lock!(COMPONENTS)
+            .iter()
+            .map(|(name, component)| ComponentHealth {
+                name: name.to_string(),
+                status: component.status,
+                last_heartbeat: component.last_heartbeat.elapsed().as_millis() as u64,
+                restarts: component.restarts,
+            })
+            .collect();
+        report.sort_by(|a, b| a.name.cmp(&b.name));
+        report
+    }
+
+    fn start_supervisor() -> Result<()> {
+        if SUPERVISOR_STARTED.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        spawn_named_thread("Watchdog", || loop {
+            std::thread::sleep(CHECK_INTERVAL);
+            let restarts: Vec<(&'static str, ComponentSpawner, u32)> = trace_lock!(COMPONENTS)
+                .iter_mut()
+                .filter_map(|(name, component)| {
+                    let generation = component.check(name)?;
+                    Some((*name, component.spawn, generation))
+                })
+                .collect();
+            for (name, spawn, generation) in restarts {
+                log_error!(Self::spawn_component(name, spawn, generation));
+            }
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands, windows_api::WindowsApi};
+
+use super::{resize_mode::ResizeMode, WindowControls};
+
+get_subcommands![
+    /** Toggles the always on top state of the focused window */
+    ToggleTopmost,
+    /** Sets the opacity of the focused window */
+    Opacity(alpha: u8 => "Alpha value from 50 to 255."),
+    /** Increases the opacity of the focused window */
+    IncreaseOpacity,
+    /** Decreases the opacity of the focused window */
+    DecreaseOpacity,
+    /** Rolls up the focused window to its title bar, or restores it if it is already shaded */
+    ToggleShade,
+    /** Enters a mode where the arrows move the focused window and shift + arrows resize it, Enter to confirm, Escape to cancel */
+    ResizeMode,
+    /** Shows the focused window on all the workspaces, or only on the current one if it was pinned */
+    PinToAllDesktops,
+];
+
+impl WindowControls {
+    pub const CLI_IDENTIFIER: &'static str = "window";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Controls over the focused window")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        let hwnd = WindowsApi::get_foreground_window();
+        match subcommand {
+            SubCommand::ToggleTopmost => Self::toggle_topmost(hwnd)?,
+            SubCommand::Opacity(alpha) => Self::set_opacity(hwnd, alpha)?,
+            SubCommand::IncreaseOpacity => Self::increase_opacity(hwnd)?,
+            SubCommand::DecreaseOpacity => Self::decrease_opacity(hwnd)?,
+            SubCommand::ToggleShade => Self::toggle_shade(hwnd)?,
+            SubCommand::ResizeMode => ResizeMode::enter(hwnd)?,
+            SubCommand::PinToAllDesktops => {
+                Self::toggle_pin_to_all_desktops(hwnd)?;
+            }
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use windows::Win32::Foundation::HWND;
+
+use crate::{error_handler::Result, windows_api::WindowsApi};
+
+use super::WindowControls;
+
+#[tauri::command(async)]
+pub fn window_set_topmost(hwnd: isize, topmost: bool) -> Result<()> {
+    WindowsApi::set_topmost(HWND(hwnd), topmost)
+}
+
+#[tauri::command(async)]
+pub fn window_toggle_topmost(hwnd: isize) -> Result<()> {
+    WindowControls::toggle_topmost(HWND(hwnd))
+}
+
+#[tauri::command(async)]
+pub fn window_toggle_shade(hwnd: isize) -> Result<()> {
+    WindowControls::toggle_shade(HWND(hwnd))
+}
+
+#[tauri::command(async)]
+pub fn window_pin_to_all_desktops(hwnd: isize) -> Result<bool> {
+    WindowControls::toggle_pin_to_all_desktops(HWND(hwnd))
+}
+
+#[tauri::command(async)]
+pub fn window_get_pinned_to_all_desktops() -> Vec<isize> {
+    WindowControls::pinned_to_all_desktops()
+}
+
+#[tauri::command(async)]
+pub fn window_set_opacity(hwnd: isize, alpha: u8) -> Result<()> {
+    WindowControls::set_opacity(HWND(hwnd), alpha)
+}
@@ -0,0 +1,266 @@
+pub mod cli;
+pub mod infrastructure;
+pub mod resize_mode;
+
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::{AppExtraFlag, DialogCentering, WindowCorners};
+use tauri::Emitter;
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Dwm::{
+        DWMSBT_NONE, DWMSBT_TRANSIENTWINDOW, DWMWA_SYSTEMBACKDROP_TYPE,
+        DWMWA_WINDOW_CORNER_PREFERENCE, DWMWCP_DEFAULT, DWMWCP_DONOTROUND, DWMWCP_ROUND,
+        DWMWCP_ROUNDSMALL,
+    },
+    UI::WindowsAndMessaging::{SWP_NOACTIVATE, WS_CAPTION, WS_CHILD},
+};
+
+use crate::{
+    error_handler::Result,
+    modules::{
+        input::Mouse, os_capabilities::domain::OsCapabilities, virtual_desk::get_vd_manager,
+    },
+    seelen::{get_app_handle, SEELEN},
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::{window::Window, WindowsApi},
+};
+
+lazy_static! {
+    /// shaded windows and their rect before being rolled up
+    static ref SHADED: Mutex<HashMap<isize, RECT>> = Mutex::new(HashMap::new());
+    /// dialogs already centered, so they are not moved again if hidden and shown
+    static ref CENTERED: Mutex<HashSet<isize>> = Mutex::new(HashSet::new());
+}
+
+/// Manual controls over windows attributes like z-order and transparency.
+pub struct WindowControls;
+
+impl WindowControls {
+    const OPACITY_STEP: u8 = 25;
+    /// lower values make the window almost invisible and hard to recover
+    const MIN_OPACITY: u8 = 50;
+
+    pub fn toggle_topmost(hwnd: HWND) -> Result<()> {
+        WindowsApi::set_topmost(hwnd, !WindowsApi::is_topmost(hwnd))
+    }
+
+    pub fn set_opacity(hwnd: HWND, alpha: u8) -> Result<()> {
+        WindowsApi::set_opacity(hwnd, alpha.max(Self::MIN_OPACITY))
+    }
+
+    /// The acrylic backdrop is the one used by the system for transient windows (menus),
+    /// removing it also removes the mica of the apps that use it.
+    pub fn set_acrylic(hwnd: HWND, enabled: bool) -> Result<()> {
+        if !OsCapabilities::get().system_backdrop {
+            log::trace!("system backdrop is not supported, skipping acrylic for {hwnd:?}");
+            return Ok(());
+        }
+        let backdrop = if enabled {
+            DWMSBT_TRANSIENTWINDOW
+        } else {
+            DWMSBT_NONE
+        };
+        WindowsApi::dwm_set_window_attribute(hwnd, DWMWA_SYSTEMBACKDROP_TYPE, &backdrop)
+    }
+
+    /// Windows 10 has square corners only, so there is nothing to change there.
+    pub fn set_corners(hwnd: HWND, corners: WindowCorners) -> Result<()> {
+        if !OsCapabilities::get().corner_preference {
+            return Ok(());
+        }
+        let preference = match corners {
+            WindowCorners::Default => DWMWCP_DEFAULT,
+            WindowCorners::Round => DWMWCP_ROUND,
+            WindowCorners::RoundSmall => DWMWCP_ROUNDSMALL,
+            WindowCorners::Square => DWMWCP_DONOTROUND,
+        };
+        WindowsApi::dwm_set_window_attribute(hwnd, DWMWA_WINDOW_CORNER_PREFERENCE, &preference)
+    }
+
+    pub fn increase_opacity(hwnd: HWND) -> Result<()> {
+        let alpha = WindowsApi::get_opacity(hwnd).saturating_add(Self::OPACITY_STEP);
+        Self::set_opacity(hwnd, alpha)
+    }
+
+    pub fn decrease_opacity(hwnd: HWND) -> Result<()> {
+        let alpha = WindowsApi::get_opacity(hwnd).saturating_sub(Self::OPACITY_STEP);
+        Self::set_opacity(hwnd, alpha)
+    }
+
+    pub fn is_shaded(hwnd: HWND) -> bool {
+        trace_lock!(SHADED).contains_key(&hwnd.0)
+    }
+
+    /// Rolls up the window to its title bar or restores it if it was already shaded.
+    pub fn toggle_shade(hwnd: HWND) -> Result<()> {
+        let restore = trace_lock!(SHADED).remove(&hwnd.0);
+        match restore {
+            Some(rect) => {
+                WindowsApi::set_position(hwnd, None, &rect, SWP_NOACTIVATE)?;
+                // let the window manager tile it again
+                let mut seelen = trace_lock!(SEELEN);
+                for monitor in seelen.monitors_mut() {
+                    if let Some(wm) = monitor.wm_mut() {
+                        if wm.should_be_added(hwnd) {
+                            wm.add_hwnd(hwnd)?;
+                        }
+                    }
+                }
+            }
+            None => {
+                if WindowsApi::is_iconic(hwnd) || WindowsApi::is_maximized(hwnd) {
+                    return Ok(());
+                }
+
+                let rect = WindowsApi::get_window_rect(hwnd);
+                trace_lock!(SHADED).insert(hwnd.0, rect);
+
+                // shaded windows should not take space on the tiling layout
+                {
+                    let mut seelen = trace_lock!(SEELEN);
+                    for monitor in seelen.monitors_mut() {
+                        if let Some(wm) = monitor.wm_mut() {
+                            wm.remove_hwnd(hwnd)?;
+                        }
+                    }
+                }
+
+                let shaded = RECT {
+                    bottom: rect.top + WindowsApi::get_title_bar_height(hwnd),
+                    ..rect
+                };
+                WindowsApi::set_position(hwnd, None, &shaded, SWP_NOACTIVATE)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// open windows shown on all the virtual desktops
+    pub fn pinned_to_all_desktops() -> Vec<isize> {
+        let vd = get_vd_manager();
+        SeelenWeg::open_handles()
+            .into_iter()
+            .filter(|hwnd| vd.is_pinned_window(*hwnd).unwrap_or(false))
+            .collect()
+    }
+
+    /// Shows the window on all the virtual desktops or only on its current one if it was pinned.
+    /// Returns true if the window is now pinned.
+    pub fn toggle_pin_to_all_desktops(hwnd: HWND) -> Result<bool> {
+        let vd = get_vd_manager();
+        let pinned = !vd.is_pinned_window(hwnd.0)?;
+        if pinned {
+            vd.pin_window(hwnd.0)?;
+        } else {
+            vd.unpin_window(hwnd.0)?;
+        }
+
+        // pinned windows are not tiled as they would take space on every workspace
+        {
+            let mut seelen = trace_lock!(SEELEN);
+            for monitor in seelen.monitors_mut() {
+                if let Some(wm) = monitor.wm_mut() {
+                    if pinned {
+                        wm.remove_hwnd(hwnd)?;
+                    } else if wm.should_be_added(hwnd) {
+                        wm.add_hwnd(hwnd)?;
+                    }
+                }
+            }
+        }
+
+        get_app_handle().emit("pinned-to-all-desktops", Self::pinned_to_all_desktops())?;
+        Ok(pinned)
+    }
+
+    /// should be called when a window is destroyed to release its cached state
+    pub fn forget(hwnd: HWND) {
+        trace_lock!(SHADED).remove(&hwnd.0);
+        trace_lock!(CENTERED).remove(&hwnd.0);
+    }
+
+    fn is_dialog(hwnd: HWND) -> bool {
+        let styles = WindowsApi::get_styles(hwnd);
+        if styles.contains(WS_CHILD)
+            || WindowsApi::is_maximized(hwnd)
+            || Window::from(hwnd).is_seelen_window()
+        {
+            return false;
+        }
+        WindowsApi::get_class(hwnd).is_ok_and(|class| class == "#32770")
+            || (WindowsApi::get_owner(hwnd).0 != 0 && styles.contains(WS_CAPTION))
+    }
+
+    /// Centers a dialog or owned floating window on the monitor of its owner or under the cursor,
+    /// should be called as soon as the window is shown to avoid visible jumps.
+    pub fn center_dialog(hwnd: HWND) -> Result<()> {
+        let state = FULL_STATE.load();
+        let mut strategy = state.settings().window_controls.center_dialogs;
+        if strategy == DialogCentering::Disabled {
+            let forced = state
+                .get_app_config_by_window(hwnd)
+                .is_some_and(|config| config.options.contains(&AppExtraFlag::CenterDialogs));
+            if !forced {
+                return Ok(());
+            }
+            strategy = DialogCentering::OwnerMonitor;
+        }
+
+        if trace_lock!(CENTERED).contains(&hwnd.0) || !Self::is_dialog(hwnd) {
+            return Ok(());
+        }
+
+        let monitor = match strategy {
+            DialogCentering::CursorMonitor => {
+                WindowsApi::monitor_from_point(Mouse::get_cursor_pos()?.into())
+            }
+            _ => {
+                let owner = WindowsApi::get_owner(hwnd);
+                WindowsApi::monitor_from_window(if owner.0 != 0 { owner } else { hwnd })
+            }
+        };
+        let work_area = WindowsApi::monitor_info(monitor)?.monitorInfo.rcWork;
+        let rect = WindowsApi::get_window_rect(hwnd);
+        let width = rect.right - rect.left;
+        let height = rect.bottom - rect.top;
+
+        let left = work_area.left + (work_area.right - work_area.left - width) / 2;
+        let top = work_area.top + (work_area.bottom - work_area.top - height) / 2;
+        WindowsApi::move_window(
+            hwnd,
+            &RECT {
+                left,
+                top,
+                right: left + width,
+                bottom: top + height,
+            },
+        )?;
+        trace_lock!(CENTERED).insert(hwnd.0);
+        Ok(())
+    }
+
+    /// Applies the attributes defined on the app-configs to a recently shown window
+    pub fn apply_app_config(hwnd: HWND) -> Result<()> {
+        let state = FULL_STATE.load();
+        if let Some(config) = state.get_app_config_by_window(hwnd) {
+            if config.options.contains(&AppExtraFlag::Topmost) && !WindowsApi::is_topmost(hwnd) {
+                WindowsApi::set_topmost(hwnd, true)?;
+            }
+            if let Some(alpha) = config.opacity {
+                Self::set_opacity(hwnd, alpha)?;
+            }
+            if let Some(acrylic) = config.acrylic {
+                Self::set_acrylic(hwnd, acrylic)?;
+            }
+            if let Some(corners) = config.corners {
+                Self::set_corners(hwnd, corners)?;
+            }
+        }
+        Ok(())
+    }
+}
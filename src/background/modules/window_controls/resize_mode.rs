@@ -0,0 +1,272 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
+use lazy_static::lazy_static;
+use tauri::{WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, RECT, WPARAM},
+    System::Threading::GetCurrentThreadId,
+    UI::{
+        Input::KeyboardAndMouse::{
+            GetAsyncKeyState, VIRTUAL_KEY, VK_DOWN, VK_ESCAPE, VK_LEFT, VK_RETURN, VK_RIGHT,
+            VK_SHIFT, VK_UP,
+        },
+        WindowsAndMessaging::{
+            CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+            TranslateMessage, UnhookWindowsHookEx, HC_ACTION, HHOOK, KBDLLHOOKSTRUCT, MSG,
+            SWP_NOACTIVATE, WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    state::application::FULL_STATE,
+    utils::{overlay::OverlayBuilder, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    /// keys captured by the keyboard hook, applied by the resize mode worker
+    static ref KEYS: (Sender<ResizeKey>, Receiver<ResizeKey>) = unbounded();
+}
+
+/// True from the moment the mode is entered until the worker finishes
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Thread id of the keyboard hook loop, 0 if the hook is not running
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+enum ResizeKey {
+    Arrow(VIRTUAL_KEY, bool),
+    Exit(bool),
+}
+
+/// Modal mode where the arrows move the window and shift + arrows resize it.
+/// Enter commits the changes and Escape restores the original rect.
+pub struct ResizeMode {
+    hwnd: HWND,
+    original: RECT,
+    current: RECT,
+    hint: Option<WebviewWindow<Wry>>,
+}
+
+impl ResizeMode {
+    const HINT_LABEL: &'static str = "resize-mode-hint";
+
+    fn create_hint(hwnd: HWND) -> Result<WebviewWindow<Wry>> {
+        let monitor = WindowsApi::monitor_rect(WindowsApi::monitor_from_window(hwnd))?;
+        let hint = OverlayBuilder::new(
+            Self::HINT_LABEL,
+            "resize-mode-hint/index.html",
+            "Seelen Resize Mode",
+        )
+        .unfocused()
+        .click_through()
+        .build()?;
+
+        let width = 420;
+        let height = 120;
+        let left = monitor.left + (monitor.right - monitor.left - width) / 2;
+        let top = monitor.bottom - height - 100;
+        WindowsApi::set_position(
+            HWND(hint.hwnd()?.0),
+            None,
+            &RECT {
+                left,
+                top,
+                right: left + width,
+                bottom: top + height,
+            },
+            SWP_NOACTIVATE,
+        )?;
+        Ok(hint)
+    }
+
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::Acquire)
+    }
+
+    pub fn enter(hwnd: HWND) -> Result<()> {
+        if !WindowsApi::is_window(hwnd) || WindowsApi::is_iconic(hwnd) {
+            return Ok(());
+        }
+
+        // claimed before anything is spawned so two quick calls can not both enter
+        if ACTIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        if let Err(err) = Self::start(hwnd) {
+            ACTIVE.store(false, Ordering::Release);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn start(hwnd: HWND) -> Result<()> {
+        if WindowsApi::is_maximized(hwnd) {
+            WindowsApi::unmaximize_window(hwnd)?;
+        }
+
+        // keys left from a previous session
+        while KEYS.1.try_recv().is_ok() {}
+
+        let rect = WindowsApi::get_window_rect(hwnd);
+        let hint = match Self::create_hint(hwnd) {
+            Ok(hint) => Some(hint),
+            Err(err) => {
+                log::warn!("Failed to create resize mode hint: {:?}", err);
+                None
+            }
+        };
+
+        let mode = Self {
+            hwnd,
+            original: rect,
+            current: rect,
+            hint,
+        };
+
+        log::trace!("Entering resize mode for {:?}", hwnd);
+        let h_module = WindowsApi::module_handle_w()?;
+        let (ready_tx, ready_rx) = bounded::<()>(1);
+        spawn_named_thread("Resize Mode Keyboard Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            let hook = SetWindowsHookExW(
+                WH_KEYBOARD_LL,
+                Some(keyboard_hook_proc),
+                HINSTANCE(h_module.0),
+                0,
+            );
+            let _ = ready_tx.send(());
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+        })?;
+        // the thread id should be known before anyone can try to leave the mode
+        ready_rx.recv()?;
+
+        spawn_named_thread("Resize Mode", move || mode.run())?;
+        Ok(())
+    }
+
+    /// leaves the mode, if `commit` is false the window is restored to its original rect
+    pub fn exit(commit: bool) -> Result<()> {
+        if Self::is_active() {
+            KEYS.0
+                .send(ResizeKey::Exit(commit))
+                .map_err(|_| "Failed to send resize mode exit")?;
+        }
+        Ok(())
+    }
+
+    fn run(mut self) {
+        let mut commit = false;
+        for key in KEYS.1.iter() {
+            match key {
+                ResizeKey::Arrow(key, shift) => self.on_key_down(key, shift),
+                ResizeKey::Exit(value) => {
+                    commit = value;
+                    break;
+                }
+            }
+        }
+
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            log_error!(unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) });
+        }
+
+        log::trace!("Leaving resize mode (commit: {})", commit);
+        if !commit {
+            log_error!(WindowsApi::set_position(
+                self.hwnd,
+                None,
+                &self.original,
+                SWP_NOACTIVATE
+            ));
+        }
+        if let Some(hint) = self.hint.take() {
+            log_error!(hint.destroy());
+        }
+        ACTIVE.store(false, Ordering::Release);
+    }
+
+    fn on_key_down(&mut self, key: VIRTUAL_KEY, shift: bool) {
+        let settings = &FULL_STATE.load().settings().window_controls;
+        let step = if shift {
+            settings.keyboard_resize_step
+        } else {
+            settings.keyboard_move_step
+        } as i32;
+
+        let (dx, dy) = match key {
+            VK_LEFT => (-step, 0),
+            VK_RIGHT => (step, 0),
+            VK_UP => (0, -step),
+            VK_DOWN => (0, step),
+            _ => return,
+        };
+
+        let rect = &mut self.current;
+        if shift {
+            // resize from the bottom right corner, keeping a usable min size
+            rect.right = (rect.right + dx).max(rect.left + 100);
+            rect.bottom = (rect.bottom + dy).max(rect.top + 50);
+        } else {
+            rect.left += dx;
+            rect.right += dx;
+            rect.top += dy;
+            rect.bottom += dy;
+        }
+
+        log_error!(WindowsApi::set_position(
+            self.hwnd,
+            None,
+            &self.current,
+            SWP_NOACTIVATE
+        ));
+    }
+}
+
+/// Runs on the hook thread, it should only forward the keys and never block
+unsafe extern "system" fn keyboard_hook_proc(
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        let data = &*(l_param.0 as *const KBDLLHOOKSTRUCT);
+        let key = VIRTUAL_KEY(data.vkCode as u16);
+        let msg = w_param.0 as u32;
+
+        if msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN {
+            let resize_key = match key {
+                VK_RETURN | VK_ESCAPE => Some(ResizeKey::Exit(key == VK_RETURN)),
+                VK_LEFT | VK_RIGHT | VK_UP | VK_DOWN => {
+                    let shift = GetAsyncKeyState(VK_SHIFT.0 as i32) as u16 & 0x8000 != 0;
+                    Some(ResizeKey::Arrow(key, shift))
+                }
+                _ => None,
+            };
+            if let Some(resize_key) = resize_key {
+                let _ = KEYS.0.send(resize_key);
+                return LRESULT(1);
+            }
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
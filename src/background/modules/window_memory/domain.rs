@@ -0,0 +1,46 @@
+use seelen_core::rect::Rect;
+use serde::{Deserialize, Serialize};
+use windows::Win32::Foundation::RECT;
+
+/// Monitors connected at a given moment, identified by stable id and position
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonitorTopology(Vec<(String, Rect)>);
+
+impl MonitorTopology {
+    pub fn new(mut monitors: Vec<(String, RECT)>) -> Self {
+        monitors.sort_by(|a, b| a.0.cmp(&b.0));
+        Self(monitors.into_iter().map(|(n, r)| (n, r.into())).collect())
+    }
+
+    /// FNV-1a hash of the topology, stable between runs and versions
+    /// so it can be used as key on the stored file.
+    pub fn hash(&self) -> String {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        };
+        for (name, rect) in &self.0 {
+            write(name.as_bytes());
+            for value in [rect.left, rect.top, rect.right, rect.bottom] {
+                write(&value.to_le_bytes());
+            }
+        }
+        format!("{:016x}", hash)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredPlacement {
+    /// maximized windows are restored to this rect and then maximized
+    /// again, so they end up on the monitor containing it
+    pub rect: Rect,
+    pub maximized: bool,
+}
+
+pub fn memory_key(topology: &str, exe: &str, class: &str) -> String {
+    format!("{}|{}|{}", topology, exe.to_lowercase(), class)
+}
@@ -0,0 +1,242 @@
+pub mod domain;
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use domain::{memory_key, MonitorTopology, StoredPlacement};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    UI::WindowsAndMessaging::{SWP_NOACTIVATE, SWP_NOZORDER, SW_MAXIMIZE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    seelen::{get_app_handle, SEELEN},
+    seelen_weg::SeelenWeg,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{app_data_path, sleep_millis, spawn_named_thread},
+    windows_api::{MonitorEnumerator, WindowEnumerator, WindowsApi},
+    winevent::WinEvent,
+};
+
+lazy_static! {
+    static ref WINDOW_MEMORY: Arc<Mutex<WindowMemory>> = Arc::new(Mutex::new(WindowMemory::load()));
+}
+
+/// Incremented on each monitor change, used to only run the last scheduled restore
+static TOPOLOGY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// True while a save of the placements is waiting to be written
+static SAVE_SCHEDULED: AtomicBool = AtomicBool::new(false);
+
+/// Remembers the placement of the windows by app and monitor topology, so windows
+/// are put back where they were when the same set of monitors is connected again.
+#[derive(Debug, Default)]
+pub struct WindowMemory {
+    topology: String,
+    placements: HashMap<String, StoredPlacement>,
+    /// windows already restored once, to avoid moving them on each show
+    restored: HashSet<isize>,
+    /// true while windows are being moved by the system after a monitor change
+    restore_pending: bool,
+}
+
+impl WindowMemory {
+    const FILENAME: &'static str = "window_positions.json";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle()).join(Self::FILENAME)
+    }
+
+    fn load() -> Self {
+        let placements = std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            topology: Self::current_topology().unwrap_or_default(),
+            placements,
+            ..Default::default()
+        }
+    }
+
+    /// placements change on each move, so they are written at most once per second
+    fn schedule_save() {
+        if SAVE_SCHEDULED.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let result = spawn_named_thread("Window Memory Save", || {
+            sleep_millis(1000);
+            log_error!(Self::flush());
+        });
+        if let Err(err) = result {
+            SAVE_SCHEDULED.store(false, Ordering::Release);
+            log::error!("{:?}", err);
+        }
+    }
+
+    /// writes the scheduled save now, if any
+    pub fn flush() -> Result<()> {
+        if SAVE_SCHEDULED.swap(false, Ordering::AcqRel) {
+            let content = serde_json::to_string(&trace_lock!(WINDOW_MEMORY).placements)?;
+            std::fs::write(Self::path(), content)?;
+        }
+        Ok(())
+    }
+
+    fn current_topology() -> Result<String> {
+        let mut monitors = Vec::new();
+        for m in MonitorEnumerator::new_refreshed()? {
+            monitors.push((
+                WindowsApi::monitor_stable_id(m)?,
+                WindowsApi::monitor_rect(m)?,
+            ));
+        }
+        Ok(MonitorTopology::new(monitors).hash())
+    }
+
+    fn is_enabled() -> bool {
+        FULL_STATE
+            .load()
+            .settings()
+            .window_controls
+            .remember_positions
+    }
+
+    fn key(&self, hwnd: HWND) -> Result<String> {
+        Ok(memory_key(
+            &self.topology,
+            &WindowsApi::exe(hwnd)?,
+            &WindowsApi::get_class(hwnd)?,
+        ))
+    }
+
+    fn remember(&mut self, hwnd: HWND) -> Result<()> {
+        if self.restore_pending || WindowsApi::is_iconic(hwnd) || !SeelenWeg::should_be_added(hwnd)
+        {
+            return Ok(());
+        }
+
+        let placement = StoredPlacement {
+            rect: WindowsApi::get_window_rect(hwnd).into(),
+            maximized: WindowsApi::is_maximized(hwnd),
+        };
+        let key = self.key(hwnd)?;
+        let changed = self.placements.get(&key).map_or(true, |old| {
+            old.rect != placement.rect || old.maximized != placement.maximized
+        });
+
+        if changed {
+            self.placements.insert(key, placement);
+            Self::schedule_save();
+        }
+        Ok(())
+    }
+
+    fn restore(&mut self, hwnd: HWND) -> Result<()> {
+        self.restored.insert(hwnd.0);
+        let placement = match self.placements.get(&self.key(hwnd)?) {
+            Some(placement) => placement.clone(),
+            None => return Ok(()),
+        };
+
+        if WindowsApi::is_maximized(hwnd) {
+            WindowsApi::unmaximize_window(hwnd)?;
+        }
+        let rect: RECT = placement.rect.into();
+        WindowsApi::set_position(hwnd, None, &rect, SWP_NOACTIVATE | SWP_NOZORDER)?;
+        if placement.maximized {
+            WindowsApi::show_window_async(hwnd, SW_MAXIMIZE)?;
+        }
+        Ok(())
+    }
+
+    /// `managed` should be true if the window is being tiled by the window manager,
+    /// in that case the position is owned by the layout and not remembered.
+    pub fn process_win_event(event: WinEvent, origin: HWND, managed: bool) -> Result<()> {
+        if managed || !Self::is_enabled() {
+            return Ok(());
+        }
+
+        let mut memory = trace_lock!(WINDOW_MEMORY);
+        match event {
+            WinEvent::SystemMoveSizeEnd | WinEvent::SystemForeground => {
+                memory.remember(origin)?;
+            }
+            WinEvent::ObjectShow => {
+                if !memory.restored.contains(&origin.0) && SeelenWeg::should_be_added(origin) {
+                    memory.restore(origin)?;
+                }
+            }
+            WinEvent::ObjectDestroy => {
+                memory.restored.remove(&origin.0);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Should be called on any monitor change, once the system finishes moving
+    /// the windows, the placements stored for the new topology are restored.
+    pub fn on_topology_changed() {
+        if !Self::is_enabled() {
+            return;
+        }
+
+        let generation = TOPOLOGY_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+        {
+            let mut memory = trace_lock!(WINDOW_MEMORY);
+            memory.restore_pending = true;
+            memory.topology = Self::current_topology().unwrap_or_default();
+        }
+
+        std::thread::spawn(move || {
+            sleep_millis(1500);
+            if TOPOLOGY_GENERATION.load(Ordering::Acquire) != generation {
+                return;
+            }
+            log_error!(Self::restore_all());
+        });
+    }
+
+    fn restore_all() -> Result<()> {
+        let result = Self::restore_windows();
+        // placements are remembered again even if the restore failed
+        trace_lock!(WINDOW_MEMORY).restore_pending = false;
+        result
+    }
+
+    fn restore_windows() -> Result<()> {
+        let mut windows = Vec::new();
+        {
+            let seelen = trace_lock!(SEELEN);
+            WindowEnumerator::new().for_each(|hwnd| {
+                let managed = seelen
+                    .monitors()
+                    .iter()
+                    .any(|m| m.wm().as_ref().is_some_and(|wm| wm.is_managed(hwnd)));
+                if !managed && SeelenWeg::should_be_added(hwnd) {
+                    windows.push(hwnd);
+                }
+            })?;
+        }
+
+        let mut memory = trace_lock!(WINDOW_MEMORY);
+        memory.topology = Self::current_topology()?;
+        log::trace!("Restoring windows for monitor topology {}", memory.topology);
+        for hwnd in windows {
+            log_error!(memory.restore(hwnd));
+        }
+        Ok(())
+    }
+}
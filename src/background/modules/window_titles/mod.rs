@@ -0,0 +1,92 @@
+use seelen_core::state::WindowTitlesSettings;
+
+use crate::host::with_host;
+
+/// Applies the user rewrite rules to the window titles before they are sent to the webviews,
+/// so the dock, the previews and the toolbar show the same clean labels.
+pub struct WindowTitles;
+
+impl WindowTitles {
+    pub fn normalize(title: &str) -> String {
+        with_host(|host| host.normalize_title(title))
+    }
+}
+
+pub fn normalize_with(title: &str, settings: &WindowTitlesSettings) -> String {
+    let mut result = title.to_string();
+    for rule in &settings.rules {
+        if rule.find.is_empty() {
+            continue;
+        }
+        result = match (rule.regex, &rule.compiled) {
+            (true, Some(re)) => re.replace_all(&result, rule.replace.as_str()).into_owned(),
+            // invalid expressions are ignored
+            (true, None) => result,
+            (false, _) => result.replace(&rule.find, &rule.replace),
+        };
+    }
+
+    let result = result.trim();
+    if settings.max_length > 0 && result.chars().count() > settings.max_length {
+        let truncated: String = result.chars().take(settings.max_length).collect();
+        return format!("{}…", truncated.trim_end());
+    }
+    // a rule removing the whole title would leave the item without label
+    if result.is_empty() {
+        return title.to_string();
+    }
+    result.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use seelen_core::state::TitleRule;
+
+    use super::*;
+
+    fn rule(find: &str, replace: &str, regex: bool) -> TitleRule {
+        let mut rule = TitleRule {
+            find: find.to_string(),
+            replace: replace.to_string(),
+            regex,
+            ..Default::default()
+        };
+        rule.cache_regex();
+        rule
+    }
+
+    #[test]
+    fn rules_are_applied_in_order() {
+        let settings = WindowTitlesSettings {
+            rules: vec![
+                rule(" - Google Chrome", "", false),
+                rule(r"^\(\d+\) ", "", true),
+            ],
+            max_length: 0,
+        };
+        assert_eq!(
+            normalize_with("(3) Inbox - Google Chrome", &settings),
+            "Inbox"
+        );
+        assert_eq!(normalize_with("Notepad", &settings), "Notepad");
+    }
+
+    #[test]
+    fn long_titles_are_truncated() {
+        let settings = WindowTitlesSettings {
+            rules: vec![rule("[", "", true)],
+            max_length: 5,
+        };
+        assert_eq!(normalize_with("Hello world", &settings), "Hello…");
+        assert_eq!(normalize_with("Hi", &settings), "Hi");
+    }
+
+    #[test]
+    fn empty_results_keep_the_original_title() {
+        let settings = WindowTitlesSettings {
+            rules: vec![rule(".*", "", true)],
+            max_length: 0,
+        };
+        assert_eq!(normalize_with("Chrome", &settings), "Chrome");
+    }
+}
@@ -0,0 +1,34 @@
+use clap::Command;
+
+use crate::{error_handler::Result, get_subcommands, windows_api::WindowsApi};
+
+use super::ZonesManager;
+
+get_subcommands![
+    /** Moves the focused window to a zone of the layout assigned to its monitor */
+    Snap(zone: usize => "Index of the zone, starting from 0."),
+    /** Moves the focused window to the next zone of the layout assigned to its monitor */
+    Cycle,
+];
+
+impl ZonesManager {
+    pub const CLI_IDENTIFIER: &'static str = "zones";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Snap-layout zones")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    pub fn process(matches: &clap::ArgMatches) -> Result<()> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Snap(zone) => {
+                Self::snap_to_zone(WindowsApi::get_foreground_window(), zone)?
+            }
+            SubCommand::Cycle => Self::cycle_zone(WindowsApi::get_foreground_window())?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use seelen_core::state::{Zone, ZoneLayout};
+use windows::Win32::Foundation::RECT;
+
+use crate::{error_handler::Result, modules::input::domain::Point};
+
+/// tolerance for the rounding of the percentages sent by the layout editor
+const EPSILON: f64 = 0.01;
+
+/// Converts a zone defined in percentages to physical pixels inside `work_area`.
+pub fn zone_rect(zone: &Zone, work_area: &RECT) -> RECT {
+    let width = (work_area.right - work_area.left) as f64;
+    let height = (work_area.bottom - work_area.top) as f64;
+
+    let left = work_area.left + (width * zone.left / 100.0).round() as i32;
+    let top = work_area.top + (height * zone.top / 100.0).round() as i32;
+    RECT {
+        left,
+        top,
+        right: left + (width * zone.width / 100.0).round() as i32,
+        bottom: top + (height * zone.height / 100.0).round() as i32,
+    }
+}
+
+/// Returns the index of the smallest zone containing `point`, so nested
+/// zones can be picked over the zones that contain them.
+pub fn zone_at(rects: &[RECT], point: &Point) -> Option<usize> {
+    rects
+        .iter()
+        .enumerate()
+        .filter(|(_, rect)| {
+            point.get_x() >= rect.left
+                && point.get_x() < rect.right
+                && point.get_y() >= rect.top
+                && point.get_y() < rect.bottom
+        })
+        .min_by_key(|(_, rect)| (rect.right - rect.left) * (rect.bottom - rect.top))
+        .map(|(idx, _)| idx)
+}
+
+/// Checks that every zone of the layout has a size and fits inside the work area.
+pub fn validate_zone_layout(layout: &ZoneLayout) -> Result<()> {
+    if layout.name.trim().is_empty() {
+        return Err("Zone layout name can't be empty".into());
+    }
+    if layout.zones.is_empty() {
+        return Err(format!("Zone layout {} has no zones", layout.name).into());
+    }
+    for (idx, zone) in layout.zones.iter().enumerate() {
+        let values = [zone.left, zone.top, zone.width, zone.height];
+        let valid = values.iter().all(|v| v.is_finite() && *v >= 0.0)
+            && zone.width > 0.0
+            && zone.height > 0.0
+            && zone.left + zone.width <= 100.0 + EPSILON
+            && zone.top + zone.height <= 100.0 + EPSILON;
+        if !valid {
+            let name = &layout.name;
+            return Err(format!("Zone {} of {name} is out of the work area", idx + 1).into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(zones: Vec<Zone>) -> ZoneLayout {
+        ZoneLayout {
+            name: "Columns".to_string(),
+            zones,
+        }
+    }
+
+    #[test]
+    fn zones_inside_the_work_area_are_valid() {
+        let columns = layout(vec![
+            Zone::new(0.0, 0.0, 33.333, 100.0),
+            Zone::new(33.333, 0.0, 33.333, 100.0),
+            Zone::new(66.666, 0.0, 33.334, 100.0),
+        ]);
+        assert!(validate_zone_layout(&columns).is_ok());
+    }
+
+    #[test]
+    fn overflowing_or_empty_zones_are_rejected() {
+        let overflow = layout(vec![Zone::new(60.0, 0.0, 50.0, 100.0)]);
+        assert!(validate_zone_layout(&overflow).is_err());
+
+        let empty = layout(vec![Zone::new(0.0, 0.0, 0.0, 100.0)]);
+        assert!(validate_zone_layout(&empty).is_err());
+
+        let nan = layout(vec![Zone::new(f64::NAN, 0.0, 50.0, 100.0)]);
+        assert!(validate_zone_layout(&nan).is_err());
+
+        assert!(validate_zone_layout(&layout(vec![])).is_err());
+    }
+}
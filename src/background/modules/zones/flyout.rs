@@ -0,0 +1,137 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use windows::{
+    core::w,
+    Win32::{
+        Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM},
+        System::Threading::GetCurrentThreadId,
+        UI::{
+            Input::KeyboardAndMouse::{VIRTUAL_KEY, VK_LWIN, VK_RWIN, VK_Z},
+            WindowsAndMessaging::{
+                CallNextHookEx, DispatchMessageW, GetMessageW, PostThreadMessageW,
+                SendMessageTimeoutW, SetWindowsHookExW, TranslateMessage, UnhookWindowsHookEx,
+                HC_ACTION, HHOOK, HWND_BROADCAST, KBDLLHOOKSTRUCT, MSG, SMTO_ABORTIFHUNG,
+                WH_KEYBOARD_LL, WM_KEYDOWN, WM_QUIT, WM_SETTINGCHANGE, WM_SYSKEYDOWN,
+            },
+        },
+    },
+};
+use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{input::Keyboard, secure_desktop::SecureDesktop},
+    utils::spawn_named_thread,
+    windows_api::WindowsApi,
+};
+
+use super::ZonesManager;
+
+/// Thread id of the keyboard hook loop, 0 if the replacement is not running
+static HOOK_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+
+/// The native flyout is only restored on stop if it was disabled by us
+static FLYOUT_DISABLED: AtomicBool = AtomicBool::new(false);
+
+const ADVANCED_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Explorer\Advanced";
+const FLYOUT_VALUE: &str = "EnableSnapAssistFlyout";
+
+/// Replaces the native Snap Layouts flyout by the Seelen zones, the flyout is disabled and
+/// `Win + Z` moves the focused window to the next zone of its monitor.
+pub struct SnapFlyout;
+
+impl SnapFlyout {
+    pub fn is_running() -> bool {
+        HOOK_THREAD_ID.load(Ordering::Acquire) != 0
+    }
+
+    pub fn start() -> Result<()> {
+        if Self::is_running() {
+            return Ok(());
+        }
+        log::trace!("Replacing native snap flyout");
+        Self::set_native_flyout(false)?;
+        FLYOUT_DISABLED.store(true, Ordering::Release);
+
+        let h_module = WindowsApi::module_handle_w()?;
+        spawn_named_thread("Snap Flyout Keyboard Hook", move || unsafe {
+            HOOK_THREAD_ID.store(GetCurrentThreadId(), Ordering::Release);
+            let hook = SetWindowsHookExW(
+                WH_KEYBOARD_LL,
+                Some(keyboard_hook_proc),
+                HINSTANCE(h_module.0),
+                0,
+            );
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, HWND(0), 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            if let Ok(hook) = hook {
+                log_error!(UnhookWindowsHookEx(hook));
+            }
+            HOOK_THREAD_ID.store(0, Ordering::Release);
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let thread_id = HOOK_THREAD_ID.load(Ordering::Acquire);
+        if thread_id != 0 {
+            log_error!(unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) });
+        }
+        if FLYOUT_DISABLED.swap(false, Ordering::AcqRel) {
+            log_error!(Self::set_native_flyout(true));
+        }
+    }
+
+    fn set_native_flyout(enabled: bool) -> Result<()> {
+        let (key, _) = RegKey::predef(HKEY_CURRENT_USER).create_subkey(ADVANCED_KEY)?;
+        let value = enabled as u32;
+        if key.get_value::<u32, _>(FLYOUT_VALUE).ok() == Some(value) {
+            return Ok(());
+        }
+        key.set_value(FLYOUT_VALUE, &value)?;
+        // explorer reads the value again on this notification
+        unsafe {
+            SendMessageTimeoutW(
+                HWND_BROADCAST,
+                WM_SETTINGCHANGE,
+                WPARAM(0),
+                LPARAM(w!("TraySettings").as_ptr() as isize),
+                SMTO_ABORTIFHUNG,
+                100,
+                None,
+            )
+        };
+        Ok(())
+    }
+
+    fn on_shortcut() -> Result<()> {
+        Keyboard::new().send_menu_mask()?;
+        ZonesManager::cycle_zone(WindowsApi::get_foreground_window())
+    }
+}
+
+unsafe extern "system" fn keyboard_hook_proc(
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION as i32 && !SecureDesktop::is_active() {
+        let data = &*(l_param.0 as *const KBDLLHOOKSTRUCT);
+        let msg = w_param.0 as u32;
+        let win = Keyboard::is_pressed(VK_LWIN) || Keyboard::is_pressed(VK_RWIN);
+        if win && VIRTUAL_KEY(data.vkCode as u16) == VK_Z {
+            if msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN {
+                // the hook must return fast, the window is moved outside of it
+                std::thread::spawn(|| log_error!(SnapFlyout::on_shortcut()));
+            }
+            return LRESULT(1);
+        }
+    }
+    CallNextHookEx(HHOOK::default(), code, w_param, l_param)
+}
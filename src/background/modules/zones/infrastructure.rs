@@ -0,0 +1,42 @@
+use seelen_core::state::ZoneLayout;
+use windows::Win32::Foundation::HWND;
+
+use crate::{error_handler::Result, state::application::FULL_STATE};
+
+use super::{preview::ZonePreview, ZonesManager};
+
+#[tauri::command(async)]
+pub fn snap_to_zone(hwnd: isize, zone: usize) -> Result<()> {
+    ZonesManager::snap_to_zone(HWND(hwnd), zone)
+}
+
+#[tauri::command(async)]
+pub fn zones_save_layout(layout: ZoneLayout, previous_name: Option<String>) -> Result<()> {
+    FULL_STATE
+        .load()
+        .cloned()
+        .save_zone_layout(layout, previous_name.as_deref())
+}
+
+#[tauri::command(async)]
+pub fn zones_delete_layout(name: String) -> Result<bool> {
+    FULL_STATE.load().cloned().delete_zone_layout(&name)
+}
+
+#[tauri::command(async)]
+pub fn zones_set_monitor_layout(monitor: String, name: Option<String>) -> Result<()> {
+    FULL_STATE
+        .load()
+        .cloned()
+        .set_monitor_zone_layout(&monitor, name.as_deref())
+}
+
+#[tauri::command(async)]
+pub fn zones_preview_layout(layout: ZoneLayout, monitor: Option<String>) -> Result<()> {
+    ZonePreview::show(&layout, monitor.as_deref())
+}
+
+#[tauri::command(async)]
+pub fn zones_close_preview() {
+    ZonePreview::hide();
+}
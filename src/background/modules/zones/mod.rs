@@ -0,0 +1,297 @@
+pub mod cli;
+pub mod domain;
+pub mod flyout;
+pub mod infrastructure;
+pub mod preview;
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicIsize, Ordering},
+    Arc,
+};
+
+use domain::{zone_at, zone_rect};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::rect::Rect;
+use serde::Serialize;
+use tauri::{Emitter, Listener, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::{
+        Input::KeyboardAndMouse::VK_SHIFT,
+        WindowsAndMessaging::{
+            HWND_TOPMOST, SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE, SWP_NOZORDER, SW_HIDE,
+            SW_SHOWNOACTIVATE,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::{Keyboard, Mouse},
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::overlay::OverlayBuilder,
+    windows_api::WindowsApi,
+    winevent::WinEvent,
+};
+
+lazy_static! {
+    static ref ZONE_PICKER: Arc<Mutex<Option<ZonePicker>>> = Arc::new(Mutex::new(None));
+}
+
+/// Window being dragged by the user, 0 if there is no drag in progress
+static DRAGGING: AtomicIsize = AtomicIsize::new(0);
+
+/// True while the picker webview is being created on the main thread
+static CREATING_PICKER: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZonePickerState {
+    /// zones relative to the picker window in physical pixels
+    zones: Vec<Rect>,
+    hovered: Option<usize>,
+}
+
+/// Overlay shown over a monitor while dragging a window with shift pressed.
+/// The webview is created once and then only shown and hidden.
+pub struct ZonePicker {
+    /// monitor where the picker is shown, None while hidden
+    monitor: Option<HMONITOR>,
+    /// zones of the monitor in screen coordinates
+    zones: Vec<RECT>,
+    hovered: Option<usize>,
+    window: WebviewWindow<Wry>,
+}
+
+impl ZonePicker {
+    const TARGET: &'static str = "zone-picker";
+
+    /// webviews should be built on the main thread, so it is requested from there
+    /// and the picker is available on the next drag events.
+    fn request_creation() {
+        if CREATING_PICKER.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let result = get_app_handle().run_on_main_thread(|| {
+            match Self::create() {
+                Ok(picker) => *trace_lock!(ZONE_PICKER) = Some(picker),
+                Err(err) => log::error!("Failed to create zone picker: {:?}", err),
+            }
+            CREATING_PICKER.store(false, Ordering::Release);
+        });
+        if let Err(err) = result {
+            log::error!("Failed to create zone picker: {:?}", err);
+            CREATING_PICKER.store(false, Ordering::Release);
+        }
+    }
+
+    fn create() -> Result<Self> {
+        let window =
+            OverlayBuilder::new(Self::TARGET, "zone-picker/index.html", "Seelen Zone Picker")
+                .unfocused()
+                .click_through()
+                .build()?;
+
+        window.once("complete-setup", move |_| {
+            if let Some(picker) = trace_lock!(ZONE_PICKER).as_ref() {
+                log_error!(picker.emit_state());
+            }
+        });
+
+        Ok(Self {
+            monitor: None,
+            zones: Vec::new(),
+            hovered: None,
+            window,
+        })
+    }
+
+    fn hwnd(&self) -> Result<HWND> {
+        Ok(HWND(self.window.hwnd()?.0))
+    }
+
+    fn show_on(&mut self, monitor: HMONITOR) -> Result<()> {
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+        self.monitor = Some(monitor);
+        self.zones = ZonesManager::zones_of(monitor)?;
+        self.hovered = None;
+        self.emit_state()?;
+
+        // the picker should never steal the focus from the window being dragged
+        let hwnd = self.hwnd()?;
+        WindowsApi::set_position(
+            hwnd,
+            Some(HWND_TOPMOST),
+            &monitor_rect,
+            SWP_NOACTIVATE | SWP_ASYNCWINDOWPOS,
+        )?;
+        WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+        Ok(())
+    }
+
+    /// hides the picker returning the zone that was hovered
+    fn hide(&mut self) -> Option<usize> {
+        if self.monitor.take().is_some() {
+            if let Ok(hwnd) = self.hwnd() {
+                log_error!(WindowsApi::show_window_async(hwnd, SW_HIDE));
+            }
+        }
+        self.hovered.take()
+    }
+
+    fn emit_state(&self) -> Result<()> {
+        let monitor = match self.monitor {
+            Some(monitor) => monitor,
+            None => return Ok(()),
+        };
+        let origin = WindowsApi::monitor_rect(monitor)?;
+        let state = ZonePickerState {
+            zones: self
+                .zones
+                .iter()
+                .map(|zone| {
+                    RECT {
+                        left: zone.left - origin.left,
+                        top: zone.top - origin.top,
+                        right: zone.right - origin.left,
+                        bottom: zone.bottom - origin.top,
+                    }
+                    .into()
+                })
+                .collect(),
+            hovered: self.hovered,
+        };
+        self.window
+            .emit_to(self.window.label(), "zone-picker-state", state)?;
+        Ok(())
+    }
+}
+
+pub struct ZonesManager {}
+
+impl ZonesManager {
+    /// zones of the layout assigned to the monitor in screen coordinates
+    pub fn zones_of(monitor: HMONITOR) -> Result<Vec<RECT>> {
+        let state = FULL_STATE.load();
+        let zones = &state.settings().zones;
+        let mut key = WindowsApi::monitor_stable_id(monitor)?;
+        if !zones.by_monitor.contains_key(&key) {
+            // assignments saved by older versions use the monitor name
+            key = WindowsApi::monitor_name(monitor)?;
+        }
+        let work_area = WindowsApi::monitor_info(monitor)?.monitorInfo.rcWork;
+        Ok(zones
+            .layout_for(&key)
+            .map(|layout| {
+                layout
+                    .zones
+                    .iter()
+                    .map(|zone| zone_rect(zone, &work_area))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    pub fn snap_to_zone(hwnd: HWND, zone: usize) -> Result<()> {
+        if !WindowsApi::is_window(hwnd) {
+            return Ok(());
+        }
+
+        let zones = Self::zones_of(WindowsApi::monitor_from_window(hwnd))?;
+        let rect = zones.get(zone).ok_or("Zone not found")?;
+
+        if WindowsApi::is_maximized(hwnd) || WindowsApi::is_iconic(hwnd) {
+            WindowsApi::unmaximize_window(hwnd)?;
+        }
+        let shadow = WindowsApi::shadow_rect(hwnd)?;
+        WindowsApi::set_position(
+            hwnd,
+            None,
+            &RECT {
+                left: rect.left + shadow.left,
+                top: rect.top + shadow.top,
+                right: rect.right + shadow.right,
+                bottom: rect.bottom + shadow.bottom,
+            },
+            SWP_NOACTIVATE | SWP_NOZORDER,
+        )
+    }
+
+    /// Snaps the window to the zone after the one it is on, the first zone if it is not
+    /// on any of them.
+    pub fn cycle_zone(hwnd: HWND) -> Result<()> {
+        let zones = Self::zones_of(WindowsApi::monitor_from_window(hwnd))?;
+        if zones.is_empty() {
+            return Ok(());
+        }
+        let rect = WindowsApi::get_window_rect_without_margins(hwnd);
+        let current = zones.iter().position(|zone| {
+            (zone.left - rect.left).abs() <= 2
+                && (zone.top - rect.top).abs() <= 2
+                && (zone.right - rect.right).abs() <= 2
+                && (zone.bottom - rect.bottom).abs() <= 2
+        });
+        let next = current.map_or(0, |idx| (idx + 1) % zones.len());
+        Self::snap_to_zone(hwnd, next)
+    }
+
+    /// `managed` should be true if the window is being tiled by the window manager,
+    /// zones are only offered for windows outside of the tiling layout.
+    pub fn process_win_event(event: WinEvent, origin: HWND, managed: bool) -> Result<()> {
+        if !FULL_STATE.load().settings().zones.enabled {
+            return Ok(());
+        }
+
+        match event {
+            WinEvent::SystemMoveSizeStart if !managed => {
+                DRAGGING.store(origin.0, Ordering::Release);
+            }
+            WinEvent::ObjectLocationChange if DRAGGING.load(Ordering::Acquire) == origin.0 => {
+                Self::on_drag()?;
+            }
+            WinEvent::SystemMoveSizeEnd if DRAGGING.load(Ordering::Acquire) == origin.0 => {
+                DRAGGING.store(0, Ordering::Release);
+                let hovered = trace_lock!(ZONE_PICKER).as_mut().and_then(|p| p.hide());
+                if let Some(zone) = hovered {
+                    Self::snap_to_zone(origin, zone)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn on_drag() -> Result<()> {
+        let mut guard = trace_lock!(ZONE_PICKER);
+        let picker = match guard.as_mut() {
+            Some(picker) => picker,
+            None => {
+                ZonePicker::request_creation();
+                return Ok(());
+            }
+        };
+
+        if !Keyboard::is_pressed(VK_SHIFT) {
+            picker.hide();
+            return Ok(());
+        }
+
+        let cursor = Mouse::get_cursor_pos()?;
+        let monitor = WindowsApi::monitor_from_point(cursor.into());
+        if picker.monitor != Some(monitor) {
+            picker.show_on(monitor)?;
+        }
+
+        let hovered = zone_at(&picker.zones, &cursor);
+        if hovered != picker.hovered {
+            picker.hovered = hovered;
+            picker.emit_state()?;
+        }
+        Ok(())
+    }
+}
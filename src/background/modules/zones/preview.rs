@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::state::ZoneLayout;
+use tauri::{Emitter, Listener, WebviewWindow, Wry};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{HWND_TOPMOST, SWP_NOACTIVATE, SW_SHOWNOACTIVATE},
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::Mouse,
+    trace_lock,
+    utils::overlay::OverlayBuilder,
+    windows_api::{MonitorEnumerator, WindowsApi},
+};
+
+use super::{
+    domain::{validate_zone_layout, zone_rect},
+    ZonePickerState,
+};
+
+lazy_static! {
+    static ref ZONE_PREVIEW: Arc<Mutex<Option<ZonePreview>>> = Arc::new(Mutex::new(None));
+}
+
+/// Overlay showing the zones of a layout being edited over the actual monitor,
+/// it uses the same app as the zone picker.
+pub struct ZonePreview {
+    monitor: HMONITOR,
+    state: ZonePickerState,
+    window: WebviewWindow<Wry>,
+}
+
+impl Drop for ZonePreview {
+    fn drop(&mut self) {
+        log_error!(self.window.destroy());
+    }
+}
+
+impl ZonePreview {
+    const TARGET: &'static str = "zone-preview";
+
+    fn new(monitor: HMONITOR) -> Result<Self> {
+        let monitor_rect = WindowsApi::monitor_rect(monitor)?;
+        let window = OverlayBuilder::new(
+            Self::TARGET,
+            "zone-picker/index.html",
+            "Seelen Zone Preview",
+        )
+        .unfocused()
+        .build()?;
+
+        // the layout editor keeps the focus while the preview is shown
+        let hwnd = HWND(window.hwnd()?.0);
+        WindowsApi::set_position(hwnd, Some(HWND_TOPMOST), &monitor_rect, SWP_NOACTIVATE)?;
+        WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+        window.set_ignore_cursor_events(true)?;
+
+        window.once("complete-setup", move |_| {
+            if let Some(preview) = trace_lock!(ZONE_PREVIEW).as_ref() {
+                log_error!(preview.emit_state());
+            }
+        });
+
+        Ok(Self {
+            monitor,
+            state: ZonePickerState {
+                zones: Vec::new(),
+                hovered: None,
+            },
+            window,
+        })
+    }
+
+    fn emit_state(&self) -> Result<()> {
+        self.window
+            .emit_to(self.window.label(), "zone-picker-state", &self.state)?;
+        Ok(())
+    }
+
+    /// Monitor with the id or device name (ex: `DISPLAY1`), or the one under the cursor if not set
+    fn find_monitor(name: Option<&str>) -> Result<HMONITOR> {
+        let Some(name) = name else {
+            let cursor = Mouse::get_cursor_pos()?;
+            return Ok(WindowsApi::monitor_from_point(cursor.into()));
+        };
+        for monitor in MonitorEnumerator::new_refreshed()? {
+            if WindowsApi::monitor_stable_id(monitor)? == name
+                || WindowsApi::monitor_name(monitor)? == name
+            {
+                return Ok(monitor);
+            }
+        }
+        Err(format!("Monitor not found: {name}").into())
+    }
+
+    /// Shows the layout over the monitor or updates the shown one, so it can be called
+    /// on each change made on the editor.
+    pub fn show(layout: &ZoneLayout, monitor: Option<&str>) -> Result<()> {
+        validate_zone_layout(layout)?;
+        let monitor = Self::find_monitor(monitor)?;
+        let origin = WindowsApi::monitor_rect(monitor)?;
+        let work_area = WindowsApi::monitor_info(monitor)?.monitorInfo.rcWork;
+
+        let mut preview = trace_lock!(ZONE_PREVIEW);
+        if preview.as_ref().is_some_and(|p| p.monitor != monitor) {
+            preview.take();
+        }
+        if preview.is_none() {
+            *preview = Some(Self::new(monitor)?);
+        }
+
+        if let Some(preview) = preview.as_mut() {
+            preview.state.zones = layout
+                .zones
+                .iter()
+                .map(|zone| {
+                    let rect = zone_rect(zone, &work_area);
+                    RECT {
+                        left: rect.left - origin.left,
+                        top: rect.top - origin.top,
+                        right: rect.right - origin.left,
+                        bottom: rect.bottom - origin.top,
+                    }
+                    .into()
+                })
+                .collect();
+            preview.emit_state()?;
+        }
+        Ok(())
+    }
+
+    pub fn hide() {
+        trace_lock!(ZONE_PREVIEW).take();
+    }
+}
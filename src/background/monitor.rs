@@ -2,8 +2,18 @@ use color_eyre::eyre::eyre;
 use getset::{Getters, MutGetters};
 
 use crate::{
-    error_handler::Result, log_error, seelen_bar::FancyToolbar, seelen_weg::SeelenWeg,
-    seelen_wm::WindowManager, state::application::FullState, utils::sleep_millis,
+    error_handler::Result,
+    hook::MonitorShell,
+    log_error,
+    modules::{
+        desktop_icons::DesktopIcons,
+        monitors::{projection::DisplayProjection, theme::MonitorThemeVars},
+    },
+    seelen_bar::FancyToolbar,
+    seelen_weg::SeelenWeg,
+    seelen_wm::WindowManager,
+    state::application::FullState,
+    utils::sleep_millis,
     windows_api::WindowsApi,
 };
 
@@ -14,14 +24,35 @@ use windows::Win32::Graphics::Gdi::HMONITOR;
 pub struct Monitor {
     handle: HMONITOR,
     name: String,
+    /// stable device id, falls back to the name for monitors without device interface
+    id: String,
     toolbar: Option<FancyToolbar>,
     weg: Option<SeelenWeg>,
     wm: Option<WindowManager>,
+    desktop_icons: Option<DesktopIcons>,
 }
 
 impl Monitor {
+    /// Dock and window manager of the monitor, borrowed together for the hook
+    pub fn shell_mut(&mut self) -> MonitorShell<'_> {
+        (self.weg.as_mut(), self.wm.as_mut())
+    }
+
     pub fn update_handle(&mut self, id: HMONITOR) {
         self.handle = id;
+        // the window manager is bound to the handle and work area of creation time,
+        // it will be created again by `load_settings`
+        self.wm = None;
+        // the names can be swapped between monitors on reconnection, the webviews are labeled
+        // with the name so these are also created again
+        if let Ok(name) = WindowsApi::monitor_name(id) {
+            if name != self.name {
+                self.name = name;
+                self.toolbar = None;
+                self.weg = None;
+                self.desktop_icons = None;
+            }
+        }
         log_error!(self.ensure_positions());
     }
 
@@ -33,16 +64,36 @@ impl Monitor {
         if let Some(weg) = &mut self.weg {
             weg.set_positions(self.handle.0)?;
         }
+        if let Some(icons) = &self.desktop_icons {
+            icons.set_position(self.handle)?;
+        }
+        self.update_theme_vars()?;
+        Ok(())
+    }
+
+    /// Sends the current theme variables of the monitor to its dock and toolbar
+    pub fn update_theme_vars(&self) -> Result<()> {
+        if self.toolbar.is_none() && self.weg.is_none() {
+            return Ok(());
+        }
+        let vars = MonitorThemeVars::new(self.handle)?;
+        if let Some(bar) = &self.toolbar {
+            bar.set_theme_vars(&vars)?;
+        }
+        if let Some(weg) = &self.weg {
+            weg.set_theme_vars(&vars)?;
+        }
         Ok(())
     }
 
     fn add_toolbar(&mut self) -> Result<()> {
         if self.toolbar.is_none() {
+            let vars = MonitorThemeVars::new(self.handle)?;
             // Tauri can fail the on creation of the first window, thats's why we only should retry
             // for the first window created, the next windows should work normally.
             // Update(08/13/2024): I think this can be removed on recent tauri versions
             for attempt in 1..4 {
-                match FancyToolbar::new(&self.name) {
+                match FancyToolbar::new(&self.name, &vars) {
                     Ok(bar) => {
                         self.toolbar = Some(bar);
                         break;
@@ -59,7 +110,8 @@ impl Monitor {
 
     fn add_weg(&mut self) -> Result<()> {
         if self.weg.is_none() {
-            self.weg = Some(SeelenWeg::new(&self.name)?)
+            let vars = MonitorThemeVars::new(self.handle)?;
+            self.weg = Some(SeelenWeg::new(&self.name, &vars)?)
         }
         Ok(())
     }
@@ -71,25 +123,45 @@ impl Monitor {
         Ok(())
     }
 
+    fn add_desktop_icons(&mut self) -> Result<()> {
+        if self.desktop_icons.is_none() {
+            self.desktop_icons = Some(DesktopIcons::new(&self.name)?)
+        }
+        Ok(())
+    }
+
     pub fn load_settings(&mut self, settings: &FullState) -> Result<()> {
-        if settings.is_bar_enabled() {
+        let policy = &settings.settings().projection;
+        let projection = DisplayProjection::current().unwrap_or_default();
+        let is_primary = self.handle == WindowsApi::primary_monitor();
+
+        let bar_moved_to_primary =
+            policy.toolbar_on_primary_only && projection.is_projecting() && !is_primary;
+        if settings.is_bar_enabled() && !bar_moved_to_primary {
             self.add_toolbar()?;
         } else {
             self.toolbar = None;
         }
 
-        if settings.is_weg_enabled() {
+        let weg_on_projected = policy.hide_weg_on_projected && projection.is_projected(&self.name);
+        if settings.is_weg_enabled() && !weg_on_projected {
             self.add_weg()?;
         } else {
             self.weg = None;
         }
 
-        if settings.is_window_manager_enabled() && self.handle == WindowsApi::primary_monitor() {
+        if settings.is_window_manager_enabled() && is_primary {
             self.add_wm()?;
         } else {
             self.wm = None;
         }
 
+        if settings.is_desktop_icons_enabled() {
+            self.add_desktop_icons()?;
+        } else {
+            self.desktop_icons = None;
+        }
+
         self.ensure_positions()?;
         Ok(())
     }
@@ -98,12 +170,15 @@ impl Monitor {
         if hmonitor.is_invalid() {
             return Err(eyre!("Invalid Monitor").into());
         }
+        let name = WindowsApi::monitor_name(hmonitor)?;
         let mut monitor = Self {
             handle: hmonitor,
-            name: WindowsApi::monitor_name(hmonitor)?,
+            id: WindowsApi::monitor_stable_id(hmonitor)?,
+            name,
             toolbar: None,
             weg: None,
             wm: None,
+            desktop_icons: None,
         };
         monitor.load_settings(settings)?;
         Ok(monitor)
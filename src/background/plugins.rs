@@ -3,11 +3,21 @@ use tauri::{Builder, Wry};
 use tauri_plugin_autostart::MacosLauncher;
 use tauri_plugin_log::{Target, TargetKind};
 
+use crate::modules::portable::Portable;
+
 pub fn register_plugins(app_builder: Builder<Wry>) -> Builder<Wry> {
+    let log_file_target = match Portable::log_dir() {
+        Some(path) => TargetKind::Folder {
+            path,
+            file_name: None,
+        },
+        None => TargetKind::LogDir { file_name: None },
+    };
+
     let mut log_plugin_builder = tauri_plugin_log::Builder::new()
         .targets([
             Target::new(TargetKind::Stdout),
-            Target::new(TargetKind::LogDir { file_name: None }),
+            Target::new(log_file_target),
             Target::new(TargetKind::Webview),
         ])
         .level_for("tao", log::LevelFilter::Off)
@@ -1,4 +1,10 @@
-use std::{env::temp_dir, sync::Arc};
+use std::{
+    env::temp_dir,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use arc_swap::ArcSwap;
 use getset::{Getters, MutGetters};
@@ -10,16 +16,58 @@ use windows::Win32::Graphics::Gdi::HMONITOR;
 
 use crate::{
     error_handler::Result,
-    hook::register_win_hook,
+    hook::{register_win_hook, release_win_hook},
     log_error,
-    modules::monitors::{MonitorManagerEvent, MONITOR_MANAGER},
+    modules::{
+        alerts::AlertWatcher,
+        app_usage::AppUsageTracker,
+        automation::Automation,
+        color_scheme::ColorSchemeWatcher,
+        conflicts::ConflictDetector,
+        data_bridge::DataBridge,
+        do_not_disturb::DoNotDisturb,
+        focus_sessions::FocusSessions,
+        game_mode::GameMode,
+        gestures::GesturesManager,
+        idle::IdleManager,
+        launcher::LAUNCHER,
+        lock_screen::LockScreenWatcher,
+        metrics::ResourceMonitor,
+        mode_manager::ModeManager,
+        monitors::{MonitorManager, MonitorManagerEvent, MONITOR_MANAGER},
+        mouse_buttons::MouseButtonsHandler,
+        notes::ReminderScheduler,
+        onboarding::Onboarding,
+        osd::{Osd, OsdKeys, OsdWatcher},
+        pen::PenButtonHandler,
+        posture::PostureWatcher,
+        presence::PresencePublisher,
+        privacy::PrivacyWatcher,
+        quiet_launch::QuietLaunch,
+        remote_api::RemoteApi,
+        session_restore::{watcher::SessionWatcher, SessionRestore},
+        shell::ShellMode,
+        shutdown::Shutdown,
+        snippets::SnippetExpander,
+        theme_schedule::ThemeScheduler,
+        uwp::UWP_MANAGER,
+        virtual_desk::{get_vd_manager, naming::WorkspaceNames, visuals::WorkspaceVisuals},
+        window_memory::WindowMemory,
+        zones::flyout::SnapFlyout,
+    },
     monitor::Monitor,
-    seelen_weg::SeelenWeg,
+    seelen_weg::{
+        background::BackgroundApps, folders::FolderBadges, hang::HangDetector,
+        overlay::TaskbarOverlays, suspension::SuspensionWatcher, zorder::ZOrderGuardian, SeelenWeg,
+    },
     seelen_wm::WindowManager,
     state::application::{FullState, FULL_STATE},
     system::{declare_system_events_handlers, release_system_events_handlers},
     trace_lock,
-    utils::{ahk::AutoHotKey, sleep_millis, spawn_named_thread, PERFORMANCE_HELPER},
+    utils::{
+        ahk::AutoHotKey, app_data_path, measure, sleep_millis, spawn_named_thread,
+        PERFORMANCE_HELPER,
+    },
     windows_api::{WindowEnumerator, WindowsApi},
 };
 
@@ -28,6 +76,12 @@ lazy_static! {
     pub static ref APP_HANDLE: Arc<Mutex<Option<AppHandle<Wry>>>> = Arc::new(Mutex::new(None));
 }
 
+/// Set by `--safe-mode`, only the settings window is started
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+/// Set by `--minimal` or `settings.minimal_mode`, only the tray icon and the settings window
+/// are started until the user starts the rest from the tray or disables the setting
+static MINIMAL_MODE: AtomicBool = AtomicBool::new(false);
+
 pub fn get_app_handle() -> AppHandle<Wry> {
     APP_HANDLE
         .lock()
@@ -35,6 +89,165 @@ pub fn get_app_handle() -> AppHandle<Wry> {
         .expect("get_app_handle called but app is still not initialized")
 }
 
+/// Modules with their own windows, they are started or stopped at runtime when toggled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct EnabledModules {
+    weg: bool,
+    toolbar: bool,
+    wm: bool,
+}
+
+impl From<&FullState> for EnabledModules {
+    fn from(state: &FullState) -> Self {
+        Self {
+            weg: state.is_weg_enabled(),
+            toolbar: state.is_bar_enabled(),
+            wm: state.is_window_manager_enabled(),
+        }
+    }
+}
+
+/// Background module that can be enabled or disabled on the settings
+struct RuntimeModule {
+    is_enabled: fn(&FullState) -> bool,
+    /// called on each state change while enabled, so it should also apply the new settings
+    start: fn() -> Result<()>,
+    stop: fn(),
+}
+
+const RUNTIME_MODULES: &[RuntimeModule] = &[
+    RuntimeModule {
+        is_enabled: FullState::is_weg_enabled,
+        start: HangDetector::start,
+        stop: HangDetector::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_weg_enabled,
+        start: FolderBadges::start,
+        stop: FolderBadges::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_weg_enabled,
+        start: ZOrderGuardian::start,
+        stop: ZOrderGuardian::stop,
+    },
+    RuntimeModule {
+        is_enabled: |state| state.is_weg_enabled() && ShellMode::is_active(),
+        start: TaskbarOverlays::start,
+        stop: TaskbarOverlays::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_weg_suspension_watcher_enabled,
+        start: SuspensionWatcher::start,
+        stop: SuspensionWatcher::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_weg_background_apps_enabled,
+        start: BackgroundApps::start,
+        stop: BackgroundApps::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_weg_resource_usage_enabled,
+        start: ResourceMonitor::start,
+        stop: ResourceMonitor::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_gestures_enabled,
+        start: GesturesManager::start,
+        stop: || log_error!(GesturesManager::stop()),
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_idle_monitor_enabled,
+        start: IdleManager::start,
+        stop: IdleManager::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_osd_enabled,
+        start: OsdWatcher::start,
+        stop: || {
+            OsdWatcher::stop();
+            Osd::destroy();
+        },
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_osd_keys_interception_enabled,
+        start: OsdKeys::start,
+        stop: OsdKeys::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_theme_schedule_enabled,
+        start: ThemeScheduler::start,
+        stop: ThemeScheduler::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_app_usage_enabled,
+        start: AppUsageTracker::start,
+        stop: AppUsageTracker::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_focus_sessions_enabled,
+        start: FocusSessions::start,
+        stop: FocusSessions::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_automation_enabled,
+        start: Automation::start,
+        stop: Automation::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_pen_enabled,
+        start: PenButtonHandler::start,
+        stop: PenButtonHandler::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_mouse_buttons_enabled,
+        start: MouseButtonsHandler::start,
+        stop: MouseButtonsHandler::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_snippets_enabled,
+        start: SnippetExpander::start,
+        stop: SnippetExpander::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_snap_flyout_replaced,
+        start: SnapFlyout::start,
+        stop: SnapFlyout::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_game_mode_enabled,
+        start: GameMode::start,
+        stop: GameMode::stop,
+    },
+    // also restarts the server if the port changed
+    RuntimeModule {
+        is_enabled: FullState::is_remote_api_enabled,
+        start: RemoteApi::start,
+        stop: RemoteApi::stop,
+    },
+    // the publisher reconnects by itself if the broker settings changed
+    RuntimeModule {
+        is_enabled: FullState::is_presence_enabled,
+        start: PresencePublisher::start,
+        stop: PresencePublisher::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_data_bridge_enabled,
+        start: DataBridge::start,
+        stop: DataBridge::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_do_not_disturb_enabled,
+        start: DoNotDisturb::start,
+        stop: DoNotDisturb::stop,
+    },
+    RuntimeModule {
+        is_enabled: FullState::is_alerts_enabled,
+        start: AlertWatcher::start,
+        stop: AlertWatcher::stop,
+    },
+];
+
 /** Struct should be initialized first before calling any other methods */
 #[derive(Getters, MutGetters, Default)]
 pub struct Seelen {
@@ -42,6 +255,12 @@ pub struct Seelen {
     #[getset(get = "pub", get_mut = "pub")]
     monitors: Vec<Monitor>,
     state: Option<Arc<ArcSwap<FullState>>>,
+    /// as of the last start or state change
+    enabled: EnabledModules,
+    /// the shell was started, false on safe mode, minimal mode or while onboarding
+    started: bool,
+    /// `settings.minimal_mode` as of the last state change
+    minimal_setting: bool,
 }
 
 /* ============== Getters ============== */
@@ -67,31 +286,139 @@ impl Seelen {
         self.monitors.iter_mut().find(|m| m.name() == name)
     }
 
+    pub fn monitor_by_stable_id_mut(&mut self, id: &str) -> Option<&mut Monitor> {
+        self.monitors.iter_mut().find(|m| m.id() == id)
+    }
+
     pub fn state(&self) -> Arc<FullState> {
         self.state
             .as_ref()
             .expect("Seelen State not initialized")
             .load_full()
     }
+
+    pub fn is_safe_mode() -> bool {
+        SAFE_MODE.load(Ordering::Acquire)
+    }
+
+    pub fn is_minimal_mode() -> bool {
+        MINIMAL_MODE.load(Ordering::Acquire)
+    }
 }
 
 /* ============== Methods ============== */
 impl Seelen {
     pub fn on_state_changed(&mut self) -> Result<()> {
+        if Self::is_safe_mode() || Onboarding::is_pending() {
+            return Ok(());
+        }
         let state = self.state();
 
+        let minimal_setting = state.settings().minimal_mode;
+        let was_minimal_setting = std::mem::replace(&mut self.minimal_setting, minimal_setting);
+        if Self::is_minimal_mode() {
+            // enabling the setting while running takes effect on the next launch
+            if was_minimal_setting && !minimal_setting {
+                return self.leave_minimal_mode();
+            }
+            return Ok(());
+        }
+
         log_error!(if state.is_ahk_enabled() {
             Self::start_ahk_shortcuts()
         } else {
             Self::kill_ahk_shortcuts()
         });
 
+        for module in RUNTIME_MODULES {
+            if (module.is_enabled)(&state) {
+                log_error!((module.start)());
+            } else {
+                (module.stop)();
+            }
+        }
+
+        // also applies template changes
+        if state.is_auto_workspace_names_enabled() {
+            WorkspaceNames::request_update();
+        } else {
+            log_error!(WorkspaceNames::clear());
+        }
+
+        // wallpapers or accents of the current workspace could have been edited
+        std::thread::spawn(|| {
+            if let Ok(idx) = get_vd_manager().get_current_idx() {
+                log_error!(WorkspaceVisuals::apply(idx));
+            }
+        });
+
+        // windows of the toggled modules are created or destroyed here
         for monitor in &mut self.monitors {
             monitor.load_settings(&state)?;
         }
+
+        let enabled = EnabledModules::from(&*state);
+        let previous = std::mem::replace(&mut self.enabled, enabled);
+        if previous != enabled {
+            self.on_modules_toggled(previous, enabled)?;
+        }
+        Ok(())
+    }
+
+    /// Handles what is shared by the instances of all the monitors, so the modules can be
+    /// enabled or disabled without restarting the app.
+    fn on_modules_toggled(&self, previous: EnabledModules, current: EnabledModules) -> Result<()> {
+        log::info!("Modules toggled: {:?} -> {:?}", previous, current);
+        if previous.weg != current.weg {
+            if current.weg {
+                SeelenWeg::hide_taskbar();
+                // the open apps are not tracked while the dock is disabled
+                spawn_named_thread("Weg Refill", || log_error!(SeelenWeg::refill_open_apps()))?;
+            } else {
+                SeelenWeg::show_taskbar()?;
+            }
+        }
+
+        // the work area changed, the tiled windows are placed again
+        if previous.toolbar != current.toolbar {
+            for monitor in &self.monitors {
+                if let Some(wm) = monitor.wm() {
+                    log_error!(wm.force_retiling());
+                }
+            }
+        }
+
+        if !previous.wm && current.wm {
+            spawn_named_thread("Window Manager Refill", || {
+                log_error!(Self::refill_window_manager())
+            })?;
+        }
         Ok(())
     }
 
+    /// Should be called before `init`, safe mode lets the users fix a broken config
+    /// or remove a crashing theme without the shell features getting in the way.
+    pub fn enable_safe_mode() {
+        log::warn!("Safe mode enabled, shell features and hooks will not be started");
+        SAFE_MODE.store(true, Ordering::Release);
+    }
+
+    /// Should be called before `start`, the dock, toolbar, window manager and the rest of the
+    /// shell are not started, useful on remote sessions or low-resource machines.
+    pub fn enable_minimal_mode() {
+        log::info!("Minimal mode enabled, only the tray icon and settings will be started");
+        MINIMAL_MODE.store(true, Ordering::Release);
+    }
+
+    /// Starts the shell on demand, does nothing if minimal mode was already left
+    pub fn leave_minimal_mode(&mut self) -> Result<()> {
+        if !MINIMAL_MODE.swap(false, Ordering::AcqRel) {
+            return Ok(());
+        }
+        log::info!("Leaving minimal mode");
+        measure("start", || self.start())
+    }
+
     pub fn init(&mut self, app: AppHandle<Wry>) -> Result<()> {
         Self::ensure_folders(&app)?;
         log::trace!("Initializing Seelen");
@@ -99,6 +426,10 @@ impl Seelen {
         *APP_HANDLE.lock() = Some(app.clone());
         self.handle = Some(app.clone());
         self.state = Some(Arc::clone(&FULL_STATE));
+        self.minimal_setting = self.state().settings().minimal_mode;
+        if self.minimal_setting {
+            Self::enable_minimal_mode();
+        }
         Ok(())
     }
 
@@ -111,18 +442,72 @@ impl Seelen {
             }
             MonitorManagerEvent::Removed(_name, id) => {
                 log_error!(seelen.remove_monitor(id));
+                log_error!(MonitorManager::migrate_orphaned_windows());
             }
-            MonitorManagerEvent::Updated(name, id) => {
-                if let Some(m) = seelen.monitor_by_name_mut(&name) {
+            MonitorManagerEvent::Updated(stable_id, id) => {
+                if let Some(m) = seelen.monitor_by_stable_id_mut(&stable_id) {
                     m.update_handle(id);
                 }
             }
         }
+        log_error!(seelen.on_topology_changed());
     }
 
-    fn start_async() -> Result<()> {
-        log_error!(Self::start_ahk_shortcuts());
+    /// Re-targets the instances after any monitor change, the tiling window manager
+    /// only lives on the primary monitor and that can change on add/remove.
+    fn on_topology_changed(&mut self) -> Result<()> {
+        let state = self.state();
+        for monitor in &mut self.monitors {
+            monitor.load_settings(&state)?;
+        }
+
+        let wm_created = self
+            .monitors
+            .iter()
+            .any(|m| m.wm().as_ref().is_some_and(|wm| !wm.ready()));
+        if wm_created {
+            spawn_named_thread("Window Manager Refill", || {
+                log_error!(Self::refill_window_manager())
+            })?;
+        }
 
+        // the workspaces settings follow the monitors by id, wherever they are connected now
+        std::thread::spawn(|| {
+            if let Ok(idx) = get_vd_manager().get_current_idx() {
+                log_error!(WorkspaceVisuals::apply(idx));
+            }
+        });
+        Ok(())
+    }
+
+    /// Adds the manageable windows to the window managers created after startup
+    fn refill_window_manager() -> Result<()> {
+        let mut attempts = 0;
+        while !trace_lock!(SEELEN).monitors().iter().all(|m| m.is_ready()) {
+            attempts += 1;
+            if attempts > 100 {
+                return Err("Window manager was not ready in time".into());
+            }
+            sleep_millis(50);
+        }
+
+        WindowEnumerator::new().for_each(|hwnd| {
+            if !WindowManager::is_manageable_window(hwnd) {
+                return;
+            }
+            let mut seelen = trace_lock!(SEELEN);
+            for monitor in seelen.monitors_mut() {
+                if let Some(wm) = monitor.wm_mut() {
+                    log_error!(wm.add_hwnd(hwnd));
+                }
+            }
+        })?;
+        Ok(())
+    }
+
+    /// Heavy modules are started here once the dock and toolbar are painted, so they
+    /// don't compete with the webviews on startup.
+    fn start_async() -> Result<()> {
         let mut all_ready = false;
         while !all_ready {
             sleep_millis(50);
@@ -131,12 +516,24 @@ impl Seelen {
 
         log::debug!(
             "Seelen UI ready in: {:.2}s",
-            trace_lock!(PERFORMANCE_HELPER)
-                .elapsed("init")
-                .as_secs_f64()
+            trace_lock!(PERFORMANCE_HELPER).end("init").as_secs_f64()
         );
 
+        measure("ahk", || log_error!(Self::start_ahk_shortcuts()));
+        // loaded before the windows are added, as the dock needs it for each item
+        measure("uwp manager", || lazy_static::initialize(&UWP_MANAGER));
+
         log::trace!("Enumerating windows");
+        measure("windows", Self::add_open_windows)?;
+        register_win_hook()?;
+
+        spawn_named_thread("Launcher Warm Up", || {
+            measure("launcher index", || LAUNCHER.warm_up())
+        })?;
+        Ok(())
+    }
+
+    fn add_open_windows() -> Result<()> {
         WindowEnumerator::new().for_each(|hwnd| {
             let mut seelen = trace_lock!(SEELEN);
 
@@ -152,13 +549,15 @@ impl Seelen {
                 }
             }
         })?;
-
-        register_win_hook()?;
         Ok(())
     }
 
     pub fn start(&mut self) -> Result<()> {
+        self.started = true;
+        self.enabled = EnabledModules::from(&*self.state());
         declare_system_events_handlers()?;
+        // before the taskbar is hidden and the window manager created
+        measure("conflicts", ConflictDetector::detect);
 
         if self.state().is_weg_enabled() {
             SeelenWeg::hide_taskbar();
@@ -166,10 +565,38 @@ impl Seelen {
 
         log::trace!("Enumerating Monitors");
         let mut monitor_manager = trace_lock!(MONITOR_MANAGER);
-        for (_name, id) in &monitor_manager.monitors {
+        trace_lock!(PERFORMANCE_HELPER).start("monitors");
+        for (_stable_id, id) in &monitor_manager.monitors {
             log_error!(self.add_monitor(*id));
         }
+        trace_lock!(PERFORMANCE_HELPER).end("monitors");
         monitor_manager.listen_changes(Self::on_monitor_event);
+        monitor_manager.listen_changes(|_| WindowMemory::on_topology_changed());
+        monitor_manager.listen_changes(Automation::on_monitor_event);
+
+        let state = self.state();
+        for module in RUNTIME_MODULES {
+            if (module.is_enabled)(&state) {
+                log_error!((module.start)());
+            }
+        }
+
+        log_error!(PostureWatcher::start());
+        log_error!(ColorSchemeWatcher::start());
+        log_error!(LockScreenWatcher::start());
+        log_error!(PrivacyWatcher::start());
+        log_error!(ReminderScheduler::start());
+
+        if state.is_quiet_launch_enabled() {
+            spawn_named_thread("Quiet Launch", || log_error!(QuietLaunch::run()))?;
+        }
+
+        // always running, it also shuts down the app when the session ends
+        log_error!(SessionWatcher::start());
+        log_error!(Shutdown::listen_console_events());
+        if state.is_session_restore_enabled() {
+            spawn_named_thread("Session Restore", || log_error!(SessionRestore::restore()))?;
+        }
 
         spawn_named_thread("Start Async", || log_error!(Self::start_async()))?;
         tauri::async_runtime::spawn(async {
@@ -180,7 +607,23 @@ impl Seelen {
 
     /// Stop and release all resources
     pub fn stop(&self) {
+        if Self::is_safe_mode() || !self.started {
+            return;
+        }
         release_system_events_handlers();
+        log_error!(release_win_hook());
+        trace_lock!(MONITOR_MANAGER).clear_listeners();
+        // restores what the active mode changed, like the wallpaper or the animations
+        log_error!(ModeManager::exit());
+        for module in RUNTIME_MODULES {
+            (module.stop)();
+        }
+        SessionWatcher::stop();
+        PostureWatcher::stop();
+        ColorSchemeWatcher::stop();
+        LockScreenWatcher::stop();
+        PrivacyWatcher::stop();
+        ReminderScheduler::stop();
         if self.state().is_weg_enabled() {
             log_error!(SeelenWeg::show_taskbar());
         }
@@ -190,7 +633,12 @@ impl Seelen {
     }
 
     fn add_monitor(&mut self, hmonitor: HMONITOR) -> Result<()> {
-        self.monitors.push(Monitor::new(hmonitor, &self.state())?);
+        let monitor = Monitor::new(hmonitor, &self.state())?;
+        let mut state = self.state().cloned();
+        if state.bind_monitor_settings(monitor.id())? {
+            state.store();
+        }
+        self.monitors.push(monitor);
         Ok(())
     }
 
@@ -202,7 +650,7 @@ impl Seelen {
     fn ensure_folders(handle: &AppHandle<Wry>) -> Result<()> {
         log::trace!("Ensuring folders");
         let path = handle.path();
-        let data_path = path.app_data_dir()?;
+        let data_path = app_data_path(handle);
 
         // migration of user settings files below v1.8.3
         let old_path = path.resolve(".config/seelen", BaseDirectory::Home)?;
@@ -230,6 +678,8 @@ impl Seelen {
         create_if_needed("layouts")?;
         create_if_needed("icons")?;
         create_if_needed("wallpapers")?;
+        create_if_needed("data_sources")?;
+        create_if_needed("profiles")?;
 
         Ok(())
     }
@@ -299,10 +749,13 @@ impl Seelen {
         let state = FULL_STATE.load();
         if state.is_ahk_enabled() {
             log::trace!("Starting AHK shortcuts");
-            AutoHotKey::new(include_str!("utils/ahk/mocks/seelen.ahk"))
-                .name("seelen.ahk")
-                .with_lib()
-                .execute()?;
+            AutoHotKey::from_template(
+                include_str!("utils/ahk/mocks/seelen.ahk"),
+                state.get_ahk_variables(),
+            )
+            .name("seelen.ahk")
+            .with_lib()
+            .execute()?;
 
             if state.is_window_manager_enabled() {
                 log::trace!("Starting seelen.wm.ahk");
@@ -14,6 +14,7 @@ impl FancyToolbar {
             }
             WinEvent::SystemForeground | WinEvent::ObjectFocus => {
                 self.focus_changed(origin)?;
+                self.handle_focused_app_behavior(origin)?;
                 self.handle_overlaped_status(origin)?;
             }
             WinEvent::ObjectLocationChange => {
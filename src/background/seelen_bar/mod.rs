@@ -4,17 +4,18 @@ pub mod hook;
 use crate::{
     error_handler::Result,
     log_error,
-    modules::virtual_desk::get_vd_manager,
+    modules::{
+        monitors::theme::MonitorThemeVars,
+        rendering::RenderingProbe,
+        virtual_desk::{get_vd_manager, naming::WorkspaceNames},
+    },
     seelen::get_app_handle,
     state::application::FULL_STATE,
-    utils::{
-        are_overlaped,
-        constants::{OVERLAP_BLACK_LIST_BY_EXE, OVERLAP_BLACK_LIST_BY_TITLE},
-    },
-    windows_api::{AppBarData, AppBarDataEdge, WindowsApi},
+    utils::are_overlaped,
+    windows_api::{window::Window, AppBarData, AppBarDataEdge, WindowsApi},
 };
 use itertools::Itertools;
-use seelen_core::state::HideMode;
+use seelen_core::state::{FocusedAppBehavior, HideMode};
 use serde::Serialize;
 use tauri::{Emitter, Listener, Manager, WebviewWindow};
 use windows::Win32::{
@@ -29,6 +30,7 @@ pub struct FancyToolbar {
     last_focus: Option<isize>,
     hidden: bool,
     overlaped: bool,
+    focused_app_behavior: Option<FocusedAppBehavior>,
 }
 
 impl Drop for FancyToolbar {
@@ -42,14 +44,15 @@ impl Drop for FancyToolbar {
 }
 
 impl FancyToolbar {
-    pub fn new(postfix: &str) -> Result<Self> {
+    pub fn new(postfix: &str, theme_vars: &MonitorThemeVars) -> Result<Self> {
         log::info!("Creating {}/{}", Self::TARGET, postfix);
         Ok(Self {
-            window: Self::create_window(postfix)?,
+            window: Self::create_window(postfix, theme_vars)?,
             last_focus: None,
             hidden: false,
             cached_monitor: HMONITOR(-1),
             overlaped: false,
+            focused_app_behavior: None,
         })
     }
 
@@ -58,6 +61,11 @@ impl FancyToolbar {
         Ok(())
     }
 
+    pub fn set_theme_vars(&self, vars: &MonitorThemeVars) -> Result<()> {
+        self.window.eval(&vars.script())?;
+        Ok(())
+    }
+
     pub fn is_overlapping(&self, hwnd: HWND) -> Result<bool> {
         let rect = WindowsApi::get_window_rect_without_margins(hwnd);
         let monitor_info = WindowsApi::monitor_info(self.cached_monitor)?;
@@ -79,17 +87,28 @@ impl FancyToolbar {
     }
 
     pub fn handle_overlaped_status(&mut self, hwnd: HWND) -> Result<()> {
-        let should_handle_hidden = WindowsApi::is_window_visible(hwnd)
-            && !OVERLAP_BLACK_LIST_BY_TITLE.contains(&WindowsApi::get_window_text(hwnd).as_str())
-            && !OVERLAP_BLACK_LIST_BY_EXE
-                .contains(&WindowsApi::exe(hwnd).unwrap_or_default().as_str());
-
-        if !should_handle_hidden {
+        if !Window::from(hwnd).can_overlap() {
             return Ok(());
         }
         self.set_overlaped_status(self.is_overlapping(hwnd)?)
     }
 
+    /// Hides or dims the toolbar while the focused app has it set on its app config
+    pub fn handle_focused_app_behavior(&mut self, hwnd: HWND) -> Result<()> {
+        if Window::from(hwnd).is_seelen_window() {
+            return Ok(());
+        }
+        let behavior = FULL_STATE
+            .load()
+            .get_app_config_by_window(hwnd)
+            .and_then(|config| config.toolbar_while_focused);
+        if self.focused_app_behavior != behavior {
+            self.focused_app_behavior = behavior;
+            self.emit("set-focused-app-behavior", behavior)?;
+        }
+        Ok(())
+    }
+
     pub fn hide(&mut self) -> Result<()> {
         WindowsApi::show_window_async(self.window.hwnd()?, SW_HIDE)?;
         self.hidden = true;
@@ -102,6 +121,18 @@ impl FancyToolbar {
         Ok(())
     }
 
+    /// Shows again the toolbar if it should be visible but was hidden by the system
+    pub fn restore_visibility(&self) -> Result<()> {
+        if self.hidden {
+            return Ok(());
+        }
+        let hwnd = HWND(self.window.hwnd()?.0);
+        if !WindowsApi::is_window_visible(hwnd) {
+            WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+        }
+        self.ensure_hitbox_zorder()
+    }
+
     pub fn focus_changed(&mut self, hwnd: HWND) -> Result<()> {
         self.last_focus = Some(hwnd.0);
         Ok(())
@@ -169,29 +200,34 @@ impl FancyToolbar {
         Ok(())
     }
 
-    fn create_window(postfix: &str) -> Result<WebviewWindow> {
+    fn create_window(postfix: &str, theme_vars: &MonitorThemeVars) -> Result<WebviewWindow> {
         let manager = get_app_handle();
 
         let label = format!("{}/{}", Self::TARGET, postfix);
         let window = match manager.get_webview_window(&label) {
             Some(window) => window,
-            None => tauri::WebviewWindowBuilder::new(
-                &manager,
-                label,
-                tauri::WebviewUrl::App("toolbar/index.html".into()),
-            )
-            .title("Seelen Fancy Toolbar")
-            .maximizable(false)
-            .minimizable(false)
-            .resizable(false)
-            .visible(false)
-            .decorations(false)
-            .transparent(true)
-            .shadow(false)
-            .skip_taskbar(true)
-            .always_on_top(true)
-            .drag_and_drop(false)
-            .build()?,
+            None => {
+                let window = tauri::WebviewWindowBuilder::new(
+                    &manager,
+                    label,
+                    tauri::WebviewUrl::App("toolbar/index.html".into()),
+                )
+                .title("Seelen Fancy Toolbar")
+                .initialization_script(&theme_vars.script())
+                .maximizable(false)
+                .minimizable(false)
+                .resizable(false)
+                .visible(false)
+                .decorations(false)
+                .transparent(true)
+                .shadow(false)
+                .skip_taskbar(true)
+                .always_on_top(true)
+                .drag_and_drop(false)
+                .build()?;
+                RenderingProbe::watch(&window, "store-events-ready");
+                window
+            }
         };
 
         window.set_ignore_cursor_events(true)?;
@@ -211,6 +247,7 @@ impl FancyToolbar {
                 .collect_vec();
             handler.emit("workspaces-changed", &desktops)?;
             handler.emit("active-workspace-changed", vd.get_current()?.id())?;
+            handler.emit("workspaces-auto-names", WorkspaceNames::get())?;
             Ok(())
         });
     }
@@ -0,0 +1,105 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, trace_lock, utils::app_data_path,
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    /// loaded from disk on first use, keyed by lowercased exe path
+    static ref APP_NAMES: Mutex<Option<HashMap<String, AppName>>> = Mutex::new(None);
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AppName {
+    name: String,
+    /// modification time of the executable, the name is resolved again after an update
+    modified: u64,
+}
+
+/// Display names of the executables taken from their version resources, so the dock, the
+/// launcher and the toolbar show `Google Chrome` instead of `chrome`. The names are cached
+/// next to the extracted icons and cleared with them.
+pub struct AppNames;
+
+impl AppNames {
+    const FILENAME: &'static str = "display_names.json";
+
+    fn path() -> PathBuf {
+        app_data_path(&get_app_handle())
+            .join("icons")
+            .join(Self::FILENAME)
+    }
+
+    fn load() -> HashMap<String, AppName> {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(names: &HashMap<String, AppName>) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(names)?)?;
+        Ok(())
+    }
+
+    fn file_stem(exe_path: &str) -> String {
+        Path::new(exe_path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| exe_path.to_string())
+    }
+
+    /// Localized name of the executable, or its filename without extension if it has none
+    pub fn get(exe_path: &str) -> String {
+        let key = exe_path.to_lowercase();
+        let modified = Path::new(exe_path)
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |duration| duration.as_secs());
+
+        let mut names = trace_lock!(APP_NAMES);
+        let names = names.get_or_insert_with(Self::load);
+        if let Some(cached) = names.get(&key) {
+            if cached.modified == modified {
+                return cached.name.clone();
+            }
+        }
+
+        let name =
+            WindowsApi::exe_version_name(exe_path).unwrap_or_else(|_| Self::file_stem(exe_path));
+        names.insert(
+            key,
+            AppName {
+                name: name.clone(),
+                modified,
+            },
+        );
+        log_error!(Self::save(names));
+        name
+    }
+
+    /// Forgets the cached names, they are resolved again on demand like the icons
+    pub fn clear() -> Result<()> {
+        trace_lock!(APP_NAMES).replace(HashMap::new());
+        let path = Self::path();
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use sysinfo::System;
+use tauri::Emitter;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::tray::application::get_tray_executables,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+use super::SeelenWeg;
+
+lazy_static! {
+    static ref BACKGROUND_APPS: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+}
+
+/// true while the watcher thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Tracks the pinned apps running without windows but with a tray icon (as the apps
+/// minimized to the tray), emitting `weg-background-apps` with their lowercased exe paths
+/// when the set changes, so the dock still shows them as running.
+pub struct BackgroundApps;
+
+impl BackgroundApps {
+    const INTERVAL: u64 = 3000;
+
+    pub fn background_apps() -> Vec<String> {
+        trace_lock!(BACKGROUND_APPS).iter().cloned().collect()
+    }
+
+    fn emit(apps: &HashSet<String>) -> Result<()> {
+        get_app_handle().emit("weg-background-apps", apps.iter().collect::<Vec<_>>())?;
+        Ok(())
+    }
+
+    fn tick(sys: &mut System) -> Result<()> {
+        let pinned = FULL_STATE
+            .load()
+            .weg_items()
+            .pinned_apps()
+            .iter()
+            .map(|app| app.exe().to_lowercase())
+            .collect::<HashSet<String>>();
+
+        let with_windows = SeelenWeg::open_exes()
+            .into_iter()
+            .map(|exe| exe.to_lowercase())
+            .collect::<HashSet<String>>();
+
+        let tray = get_tray_executables()?;
+        sys.refresh_processes();
+        let running = sys
+            .processes()
+            .values()
+            .filter_map(|p| p.exe())
+            .map(|exe| exe.to_string_lossy().to_lowercase())
+            .collect::<HashSet<String>>();
+
+        let background = pinned
+            .into_iter()
+            .filter(|exe| {
+                !with_windows.contains(exe) && running.contains(exe) && tray.contains(exe)
+            })
+            .collect::<HashSet<String>>();
+
+        let mut last = trace_lock!(BACKGROUND_APPS);
+        if *last != background {
+            *last = background;
+            Self::emit(&last)?;
+        }
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        spawn_named_thread("Background Apps Watcher", || {
+            let mut sys = System::new();
+            while RUNNING.load(Ordering::Acquire) {
+                log_error!(Self::tick(&mut sys));
+                sleep_millis(Self::INTERVAL);
+            }
+            let mut apps = trace_lock!(BACKGROUND_APPS);
+            if !apps.is_empty() {
+                apps.clear();
+                log_error!(Self::emit(&apps));
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
@@ -1,15 +1,51 @@
-use clap::Command;
+use clap::{Command, ValueEnum};
+use seelen_core::state::{CustomWegButton, WegItem, WegItemsSide};
+use serde::{Deserialize, Serialize};
 use tauri::Emitter;
 
-use crate::{error_handler::Result, get_subcommands};
+use crate::{
+    error_handler::Result, get_subcommands, seelen::SEELEN, state::application::FULL_STATE,
+    trace_lock,
+};
 
 use super::SeelenWeg;
 
+#[derive(Debug, Clone, Serialize, Deserialize, ValueEnum)]
+pub enum Side {
+    Left,
+    Center,
+    Right,
+}
+
+impl From<Side> for WegItemsSide {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Left => WegItemsSide::Left,
+            Side::Center => WegItemsSide::Center,
+            Side::Right => WegItemsSide::Right,
+        }
+    }
+}
+
 get_subcommands![
     /** Open Dev Tools (only works if the app is running in dev mode) */
     Debug,
     /** Shows the invisible hitbox */
     DebugHitbox,
+    /** Adds a separator at the end of the side. */
+    AddSeparator(side: Side => "The side of the dock."),
+    /** Adds a flexible spacer at the end of the side. */
+    AddSpacer(side: Side => "The side of the dock."),
+    /** Adds a button that runs a command when clicked. */
+    AddButton(
+        side: Side => "The side of the dock.",
+        id: String => "Unique identifier of the button, also used as tooltip.",
+        command: String => "The command to run, executed using cmd."
+    ),
+    /** Removes the item at the index of the side. */
+    RemoveItem(side: Side => "The side of the dock.", index: usize => "Index of the item."),
+    /** Removes the custom button with the given id. */
+    RemoveButton(id: String => "Identifier of the button."),
 ];
 
 impl SeelenWeg {
@@ -22,8 +58,52 @@ impl SeelenWeg {
             .subcommands(SubCommand::commands())
     }
 
-    pub fn process(&mut self, matches: &clap::ArgMatches) -> Result<()> {
+    /// Item subcommands are applied once to the shared items file,
+    /// the rest are forwarded to the dock of each monitor.
+    pub fn process_cli(matches: &clap::ArgMatches) -> Result<()> {
         let subcommand = SubCommand::try_from(matches)?;
+        let state = FULL_STATE.load();
+        let mut items = state.weg_items().clone();
+        match subcommand {
+            SubCommand::AddSeparator(side) => items.push(side.into(), WegItem::Separator),
+            SubCommand::AddSpacer(side) => items.push(side.into(), WegItem::Spacer),
+            SubCommand::AddButton(side, id, command) => {
+                if items.remove_custom_button(&id) {
+                    log::info!("Replacing weg button: {id}");
+                }
+                let button = CustomWegButton {
+                    tooltip: id.clone(),
+                    id,
+                    icon: None,
+                    command: Some(command),
+                    event: None,
+                };
+                items.push(side.into(), WegItem::CustomButton(button));
+            }
+            SubCommand::RemoveItem(side, index) => {
+                items
+                    .remove(side.into(), index)
+                    .ok_or(format!("No item at index {index}"))?;
+            }
+            SubCommand::RemoveButton(id) => {
+                if !items.remove_custom_button(&id) {
+                    return Err(format!("Button not found: {id}").into());
+                }
+            }
+            _ => {
+                let mut seelen = trace_lock!(SEELEN);
+                for monitor in seelen.monitors_mut() {
+                    if let Some(weg) = monitor.weg_mut() {
+                        weg.process(&subcommand)?;
+                    }
+                }
+                return Ok(());
+            }
+        }
+        state.write_weg_items(&items)
+    }
+
+    fn process(&mut self, subcommand: &SubCommand) -> Result<()> {
         match subcommand {
             SubCommand::Debug => {
                 #[cfg(any(debug_assertions, feature = "devtools"))]
@@ -33,6 +113,7 @@ impl SeelenWeg {
                 self.hitbox
                     .emit_to(self.hitbox.label(), "debug-hitbox", ())?;
             }
+            _ => {}
         };
         Ok(())
     }
@@ -0,0 +1,202 @@
+use std::{
+    collections::{HashMap, HashSet},
+    os::windows::fs::MetadataExt,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use lazy_static::lazy_static;
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{ReadDirectoryChangesWatcher, RecursiveMode, Watcher},
+    DebounceEventResult, Debouncer, FileIdMap,
+};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::Emitter;
+use windows::Win32::Storage::FileSystem::{FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_SYSTEM};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, state::application::FULL_STATE,
+    trace_lock,
+};
+
+lazy_static! {
+    static ref WATCHER: Arc<Mutex<Option<Debouncer<ReadDirectoryChangesWatcher, FileIdMap>>>> =
+        Arc::new(Mutex::new(None));
+    /// last state of the watched folders, keyed by the path as it was pinned
+    static ref BADGES: Arc<Mutex<HashMap<String, FolderBadge>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+}
+
+/// extensions used by browsers and download managers while the file is being downloaded
+const PARTIAL_EXTENSIONS: [&str; 6] = [
+    "crdownload",
+    "part",
+    "partial",
+    "download",
+    "opdownload",
+    "tmp",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderBadge {
+    /// folder path as it was pinned
+    path: String,
+    /// visible items in the folder
+    count: usize,
+    /// name of the most recently modified item
+    newest: Option<String>,
+    /// a new item finished arriving since the last notification, e.g. a completed download
+    completed: bool,
+}
+
+/// Watches the folders pinned on the dock and notifies `weg-folder-badge` when their content
+/// changes, so the dock can badge the item count or flash the Downloads pin.
+pub struct FolderBadges;
+
+impl FolderBadges {
+    pub fn badges() -> Vec<FolderBadge> {
+        trace_lock!(BADGES).values().cloned().collect()
+    }
+
+    fn is_partial(name: &str) -> bool {
+        Path::new(name).extension().is_some_and(|ext| {
+            PARTIAL_EXTENSIONS
+                .iter()
+                .any(|p| ext.eq_ignore_ascii_case(p))
+        })
+    }
+
+    fn scan(path: &str) -> Result<(usize, Option<String>)> {
+        let hidden = FILE_ATTRIBUTE_HIDDEN.0 | FILE_ATTRIBUTE_SYSTEM.0;
+        let mut count = 0;
+        let mut newest: Option<(SystemTime, String)> = None;
+        for entry in std::fs::read_dir(path)?.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.file_attributes() & hidden != 0 {
+                continue;
+            }
+            count += 1;
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            if newest.as_ref().map_or(true, |(time, _)| modified > *time) {
+                newest = Some((modified, entry.file_name().to_string_lossy().to_string()));
+            }
+        }
+        Ok((count, newest.map(|(_, name)| name)))
+    }
+
+    fn on_folder_changed(path: &str) -> Result<()> {
+        let (count, newest) = Self::scan(path)?;
+        let mut badges = trace_lock!(BADGES);
+        let Some(previous) = badges.get(path) else {
+            return Ok(());
+        };
+        if previous.count == count && previous.newest == newest {
+            return Ok(());
+        }
+
+        let completed = newest
+            .as_ref()
+            .is_some_and(|name| previous.newest.as_ref() != Some(name) && !Self::is_partial(name));
+        let badge = FolderBadge {
+            path: path.to_string(),
+            count,
+            newest,
+            completed,
+        };
+        badges.insert(path.to_string(), badge.clone());
+        drop(badges);
+
+        get_app_handle().emit("weg-folder-badge", badge)?;
+        Ok(())
+    }
+
+    fn on_events(result: DebounceEventResult) {
+        let events = match result {
+            Ok(events) => events,
+            Err(errors) => {
+                errors
+                    .iter()
+                    .for_each(|e| log::error!("Folder Badges Watcher Error: {:?}", e));
+                return;
+            }
+        };
+
+        let watched = trace_lock!(BADGES).keys().cloned().collect::<Vec<_>>();
+        let changed = events
+            .iter()
+            .flat_map(|event| event.paths.iter())
+            .filter_map(|changed| {
+                let parent = changed.parent()?;
+                watched.iter().find(|path| Path::new(path) == parent)
+            })
+            .collect::<HashSet<_>>();
+
+        for path in changed {
+            log_error!(Self::on_folder_changed(path));
+        }
+    }
+
+    fn pinned_folders() -> Vec<String> {
+        FULL_STATE
+            .load()
+            .weg_items()
+            .pinned_execution_paths()
+            .into_iter()
+            .filter(|path| Path::new(path).is_dir())
+            .map(|path| path.to_string())
+            .collect()
+    }
+
+    /// Starts watching the pinned folders, or updates the watched ones if already running
+    pub fn start() -> Result<()> {
+        let folders = Self::pinned_folders();
+        let mut watcher = trace_lock!(WATCHER);
+        {
+            let badges = trace_lock!(BADGES);
+            let unchanged = badges.len() == folders.len()
+                && folders.iter().all(|path| badges.contains_key(path));
+            if unchanged && (watcher.is_some() || folders.is_empty()) {
+                return Ok(());
+            }
+        }
+
+        // dropped outside the lock of the badges, used by the debouncer thread
+        watcher.take();
+        trace_lock!(BADGES).clear();
+        if folders.is_empty() {
+            return Ok(());
+        }
+        log::trace!("Watching {} pinned folders", folders.len());
+
+        let mut debouncer = new_debouncer(Duration::from_millis(500), None, Self::on_events)?;
+        let mut badges = HashMap::new();
+        for path in folders {
+            debouncer
+                .watcher()
+                .watch(&PathBuf::from(&path), RecursiveMode::NonRecursive)?;
+            let (count, newest) = Self::scan(&path)?;
+            badges.insert(
+                path.clone(),
+                FolderBadge {
+                    path,
+                    count,
+                    newest,
+                    completed: false,
+                },
+            );
+        }
+        *trace_lock!(BADGES) = badges;
+        *watcher = Some(debouncer);
+        Ok(())
+    }
+
+    pub fn stop() {
+        trace_lock!(WATCHER).take();
+        trace_lock!(BADGES).clear();
+    }
+}
@@ -1,19 +1,40 @@
-use std::sync::atomic::Ordering;
+use std::{collections::HashMap, sync::atomic::Ordering};
 
 use image::ImageFormat;
 use tauri::Emitter;
 use tauri_plugin_shell::ShellExt;
 
 use crate::{
-    error_handler::Result, hook::LAST_ACTIVE_NOT_SEELEN, seelen::get_app_handle,
-    windows_api::WindowsApi,
+    error_handler::Result,
+    hook::LAST_ACTIVE_NOT_SEELEN,
+    modules::cache::{domain::CacheKind, Cache},
+    modules::focus::FocusRequest,
+    modules::undo::{domain::UndoableAction, Undo},
+    modules::uwp::PackagedApp,
+    seelen::{get_app_handle, SEELEN},
+    state::application::FULL_STATE,
+    trace_lock,
+    windows_api::{WindowEnumerator, WindowsApi},
 };
 use windows::Win32::{
     Foundation::{HWND, LPARAM, WPARAM},
-    UI::WindowsAndMessaging::{PostMessageW, SW_MINIMIZE, SW_RESTORE, SW_SHOWNORMAL, WM_CLOSE},
+    UI::WindowsAndMessaging::{
+        PostMessageW, SW_MINIMIZE, SW_RESTORE, SW_SHOWNORMAL, WA_ACTIVE, WM_ACTIVATE, WM_CLOSE,
+    },
 };
 
-use super::SeelenWeg;
+use super::{
+    background::BackgroundApps,
+    folders::{FolderBadge, FolderBadges},
+    hang::HangDetector,
+    overlay::TaskbarOverlays,
+    reveal::RevealIntent,
+    rules::WegVerdict,
+    spring::SpringLoader,
+    suspension::SuspensionWatcher,
+    thumbnails::{ThumbnailSlot, ThumbnailStrip},
+    HitboxLayout, SeelenWeg, WindowMatch,
+};
 
 #[tauri::command(async)]
 pub fn weg_request_update_previews(handles: Vec<isize>) -> Result<()> {
@@ -22,6 +43,10 @@ pub fn weg_request_update_previews(handles: Vec<isize>) -> Result<()> {
     for hwnd in handles {
         let hwnd: HWND = HWND(hwnd);
 
+        // tab thumbnails are drawn by DWM, see `weg_tab_thumbnails`
+        let tabs = SeelenWeg::get_tabs(hwnd).unwrap_or_default();
+        get_app_handle().emit(format!("weg-preview-tabs-{}", hwnd.0).as_str(), &tabs)?;
+
         if WindowsApi::is_iconic(hwnd) {
             continue;
         }
@@ -40,7 +65,9 @@ pub fn weg_request_update_previews(handles: Vec<isize>) -> Result<()> {
                 height as u32,
             );
 
-            image.save_with_format(temp_dir.join(format!("{}.png", hwnd.0)), ImageFormat::Png)?;
+            let path = temp_dir.join(format!("{}.png", hwnd.0));
+            image.save_with_format(&path, ImageFormat::Png)?;
+            Cache::record(CacheKind::Thumbnails, &path);
             get_app_handle().emit(format!("weg-preview-update-{}", hwnd.0).as_str(), ())?;
         }
     }
@@ -58,12 +85,116 @@ pub fn weg_close_app(hwnd: isize) -> Result<(), String> {
     }
 }
 
+#[tauri::command(async)]
+pub fn weg_get_window_verdict(hwnd: isize) -> WegVerdict {
+    WegVerdict::evaluate(HWND(hwnd))
+}
+
+#[tauri::command(async)]
+pub fn weg_set_force_show(exe: String, enabled: bool) -> Result<()> {
+    let mut state = FULL_STATE.load().cloned();
+    if !state.set_weg_force_show(&exe, enabled)? {
+        return Ok(());
+    }
+    state.store();
+
+    // the verdict of the already open windows of the app changed
+    WindowEnumerator::new().for_each(|hwnd| {
+        if !WindowsApi::exe(hwnd).is_ok_and(|e| e.eq_ignore_ascii_case(&exe)) {
+            return;
+        }
+        let should_be_added = SeelenWeg::should_be_added(hwnd);
+        let contains = SeelenWeg::contains_app(hwnd);
+        if should_be_added && !contains {
+            SeelenWeg::add_hwnd(hwnd);
+        } else if !should_be_added && contains {
+            SeelenWeg::remove_hwnd(hwnd);
+        }
+    })?;
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn weg_close_windows(hwnds: Vec<isize>, execution_path: String) -> Result<()> {
+    for hwnd in hwnds {
+        unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0))? };
+    }
+    Undo::record(UndoableAction::CloseWindows { execution_path });
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn weg_search_windows(query: String) -> Vec<WindowMatch> {
+    SeelenWeg::search_windows(&query)
+}
+
+#[tauri::command(async)]
+pub fn weg_scrub_thumbnails(window: tauri::WebviewWindow, slots: Vec<ThumbnailSlot>) -> Result<()> {
+    ThumbnailStrip::update(HWND(window.hwnd()?.0), slots)
+}
+
+#[tauri::command(async)]
+pub fn weg_scrub_end() {
+    ThumbnailStrip::clear();
+}
+
+#[tauri::command(async)]
+pub fn weg_tab_thumbnails(
+    window: tauri::WebviewWindow,
+    owner: isize,
+    slots: Vec<ThumbnailSlot>,
+) -> Result<()> {
+    ThumbnailStrip::update_tabs(HWND(window.hwnd()?.0), owner, slots)
+}
+
+#[tauri::command(async)]
+pub fn weg_tab_thumbnails_end(owner: isize) {
+    ThumbnailStrip::clear_tabs(owner);
+}
+
+#[tauri::command(async)]
+pub fn weg_get_overlay_icons() -> HashMap<isize, String> {
+    TaskbarOverlays::overlays()
+}
+
+#[tauri::command(async)]
+pub fn weg_get_folder_badges() -> Vec<FolderBadge> {
+    FolderBadges::badges()
+}
+
+#[tauri::command(async)]
+pub fn weg_get_suspended_windows() -> Vec<isize> {
+    SuspensionWatcher::suspended_windows()
+}
+
+#[tauri::command(async)]
+pub fn weg_get_background_apps() -> Vec<String> {
+    BackgroundApps::background_apps()
+}
+
+#[tauri::command(async)]
+pub fn weg_get_hung_windows() -> Vec<isize> {
+    HangDetector::hung_windows()
+}
+
+/// Terminates the process of a window that is not responding
+#[tauri::command(async)]
+pub fn weg_kill_app(hwnd: isize) -> Result<()> {
+    let (process_id, _) = WindowsApi::window_thread_process_id(HWND(hwnd));
+    WindowsApi::terminate_process(process_id)
+}
+
 #[tauri::command(async)]
 pub fn weg_toggle_window_state(hwnd: isize, exe_path: String) -> Result<()> {
     let hwnd = HWND(hwnd);
 
     // If the window is not open, open it
     if !WindowsApi::is_window(hwnd) {
+        let state = FULL_STATE.load();
+        let pinned = state.weg_items().pinned_app(&exe_path);
+        if pinned.is_some_and(|app| app.run_as_admin()) {
+            return WindowsApi::shell_open_elevated(&exe_path);
+        }
         get_app_handle()
             .shell()
             .command("explorer")
@@ -72,6 +203,12 @@ pub fn weg_toggle_window_state(hwnd: isize, exe_path: String) -> Result<()> {
         return Ok(());
     }
 
+    if SuspensionWatcher::is_suspended(hwnd) {
+        SuspensionWatcher::resume(hwnd)?;
+        FocusRequest::async_request(hwnd);
+        return Ok(());
+    }
+
     if WindowsApi::is_iconic(hwnd) {
         WindowsApi::show_window(hwnd, SW_SHOWNORMAL)?;
         WindowsApi::show_window(hwnd, SW_RESTORE)?;
@@ -81,8 +218,95 @@ pub fn weg_toggle_window_state(hwnd: isize, exe_path: String) -> Result<()> {
     if LAST_ACTIVE_NOT_SEELEN.load(Ordering::Acquire) == hwnd.0 {
         WindowsApi::show_window(hwnd, SW_MINIMIZE)?;
     } else {
-        WindowsApi::async_force_set_foreground(hwnd)
+        FocusRequest::async_request(hwnd)
+    }
+
+    Ok(())
+}
+
+/// Enabling it launches the app elevated, the preference is only saved if the launch
+/// succeeded (the UAC prompt was accepted), so apps that can't be elevated aren't marked.
+#[tauri::command(async)]
+pub fn weg_set_run_as_admin(execution_path: String, enabled: bool) -> Result<()> {
+    let state = FULL_STATE.load();
+    if state.weg_items().pinned_app(&execution_path).is_none() {
+        return Err("The app is not pinned".into());
+    }
+    if enabled {
+        WindowsApi::shell_open_elevated(&execution_path)?;
+    }
+    let mut items = state.weg_items().clone();
+    items.set_run_as_admin(&execution_path, enabled);
+    state.write_weg_items(&items)
+}
+
+#[tauri::command(async)]
+pub fn weg_open_app_settings(execution_path: String) -> Result<()> {
+    PackagedApp::open_settings(&execution_path)
+}
+
+/// Returns false if the user cancelled, the app is also unpinned once uninstalled.
+#[tauri::command(async)]
+pub fn weg_uninstall_app(execution_path: String) -> Result<bool> {
+    if !PackagedApp::uninstall(&execution_path)? {
+        return Ok(false);
+    }
+    let state = FULL_STATE.load();
+    let mut items = state.weg_items().clone();
+    if items.remove_pinned_app(&execution_path) {
+        state.write_weg_items(&items)?;
+    }
+    Ok(true)
+}
+
+#[tauri::command(async)]
+pub fn weg_activate_tab(hwnd: isize, tab: isize) -> Result<()> {
+    let hwnd = HWND(hwnd);
+    // same as the native taskbar, the app switches to the tab when its proxy gets activated
+    unsafe {
+        PostMessageW(
+            HWND(tab),
+            WM_ACTIVATE,
+            WPARAM(WA_ACTIVE as usize),
+            LPARAM(0),
+        )?
+    };
+    if WindowsApi::is_iconic(hwnd) {
+        WindowsApi::show_window(hwnd, SW_RESTORE)?;
     }
+    FocusRequest::async_request(hwnd);
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn weg_hitbox_enter(window: tauri::WebviewWindow) -> Result<()> {
+    RevealIntent::on_hitbox_enter(&window)
+}
 
+#[tauri::command(async)]
+pub fn weg_hitbox_leave() {
+    RevealIntent::on_hitbox_leave();
+}
+
+#[tauri::command(async)]
+pub fn weg_drag_over(hwnd: Option<isize>) -> Result<()> {
+    SpringLoader::on_drag_over(hwnd)
+}
+
+#[tauri::command(async)]
+pub fn weg_drag_leave() {
+    SpringLoader::on_drag_leave();
+}
+
+#[tauri::command(async)]
+pub fn weg_set_hitbox(window: tauri::Window, layout: HitboxLayout) -> Result<()> {
+    let mut seelen = trace_lock!(SEELEN);
+    for monitor in seelen.monitors_mut() {
+        if let Some(weg) = monitor.weg_mut() {
+            if weg.window.label() == window.label() {
+                weg.set_hitbox_layout(layout)?;
+            }
+        }
+    }
     Ok(())
 }
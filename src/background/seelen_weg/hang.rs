@@ -0,0 +1,77 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use windows::Win32::{Foundation::HWND, UI::WindowsAndMessaging::IsHungAppWindow};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::game_mode::GameMode,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+};
+
+use super::SeelenWeg;
+
+lazy_static! {
+    static ref HUNG: Arc<Mutex<HashSet<isize>>> = Arc::new(Mutex::new(HashSet::new()));
+}
+
+/// true while the detection thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Polls the windows shown on the dock, emitting `hung-windows` when the set of windows
+/// not responding changes. The system considers a window hung after 5s without
+/// processing messages, so a shorter interval would not be more accurate.
+pub struct HangDetector;
+
+impl HangDetector {
+    const INTERVAL: u64 = 2000;
+
+    pub fn hung_windows() -> Vec<isize> {
+        trace_lock!(HUNG).iter().copied().collect()
+    }
+
+    fn tick() -> Result<()> {
+        let hung = SeelenWeg::open_handles()
+            .into_iter()
+            .filter(|hwnd| unsafe { IsHungAppWindow(HWND(*hwnd)) }.as_bool())
+            .collect::<HashSet<isize>>();
+
+        let mut last = trace_lock!(HUNG);
+        if *last != hung {
+            *last = hung;
+            get_app_handle().emit("hung-windows", last.iter().collect::<Vec<_>>())?;
+        }
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        spawn_named_thread("Hang Detector", || {
+            while RUNNING.load(Ordering::Acquire) {
+                if !GameMode::is_active() {
+                    log_error!(Self::tick());
+                }
+                sleep_millis(Self::INTERVAL);
+            }
+            trace_lock!(HUNG).clear();
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
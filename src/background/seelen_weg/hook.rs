@@ -3,7 +3,13 @@ use windows::Win32::{
     UI::WindowsAndMessaging::{FindWindowExA, EVENT_OBJECT_CREATE, EVENT_OBJECT_SHOW, SW_HIDE},
 };
 
-use crate::{error_handler::Result, pcstr, windows_api::WindowsApi, winevent::WinEvent};
+use crate::{
+    error_handler::Result,
+    host::Surface,
+    pcstr,
+    windows_api::{window::Window, WindowsApi},
+    winevent::WinEvent,
+};
 
 use super::{SeelenWeg, TASKBAR_CLASS};
 
@@ -16,9 +22,11 @@ impl SeelenWeg {
                 }
             }
             WinEvent::ObjectParentChange => {
-                let parent = WindowsApi::get_parent(origin);
-                if parent.0 != 0 && !Self::contains_app(parent) && Self::should_be_added(parent) {
-                    Self::add_hwnd(parent);
+                if let Some(parent) = Window::from(origin).parent() {
+                    let parent = parent.hwnd();
+                    if !Self::contains_app(parent) && Self::should_be_added(parent) {
+                        Self::add_hwnd(parent);
+                    }
                 }
             }
             WinEvent::ObjectDestroy | WinEvent::ObjectHide => {
@@ -40,25 +48,28 @@ impl SeelenWeg {
         }
         Ok(())
     }
+}
 
+impl<S: Surface> SeelenWeg<S> {
     pub fn process_individual_win_event(&mut self, event: WinEvent, origin: HWND) -> Result<()> {
         match event {
             WinEvent::SystemForeground | WinEvent::ObjectFocus => {
+                self.handle_focused_app_behavior(origin)?;
                 self.handle_overlaped_status(origin)?;
             }
             WinEvent::ObjectLocationChange => {
-                if origin == WindowsApi::get_foreground_window() {
+                if origin == Window::foreground().hwnd() {
                     self.handle_overlaped_status(origin)?;
                 }
             }
             WinEvent::SyntheticFullscreenStart(event_data) => {
-                let monitor = WindowsApi::monitor_from_window(self.window.hwnd()?);
+                let monitor = Window::from(self.window.handle()?).monitor();
                 if monitor == event_data.monitor {
                     self.hide()?;
                 }
             }
             WinEvent::SyntheticFullscreenEnd(event_data) => {
-                let monitor = WindowsApi::monitor_from_window(self.window.hwnd()?);
+                let monitor = Window::from(self.window.handle()?).monitor();
                 if monitor == event_data.monitor {
                     self.show()?;
                     self.set_overlaped_status(false)?;
@@ -68,7 +79,9 @@ impl SeelenWeg {
         };
         Ok(())
     }
+}
 
+impl SeelenWeg {
     pub fn process_raw_win_event(event: u32, origin_hwnd: HWND) -> Result<()> {
         match event {
             EVENT_OBJECT_SHOW | EVENT_OBJECT_CREATE => {
@@ -120,3 +133,153 @@ impl SeelenWeg {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use seelen_core::state::FocusedAppBehavior;
+    use serde_json::{json, Value};
+    use windows::Win32::Foundation::RECT;
+
+    use super::*;
+    use crate::testing::{MockWindow, Simulation};
+
+    const FRAME_HOST: &str = "C:\\Windows\\System32\\ApplicationFrameHost.exe";
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+        RECT {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn windows_of_the_same_app_are_grouped_by_exe() {
+        let mut sim = Simulation::new().with_weg().with_wm();
+        let exe = "C:\\Apps\\grouped.exe";
+        let first = sim.open(MockWindow::app(exe, "a.txt"));
+        let second = sim.open(MockWindow::app(exe, "b.txt"));
+
+        assert_eq!(sim.dock(), vec![first.0, second.0]);
+        assert!(sim.is_managed(first) && sim.is_managed(second));
+        let added = sim.host.emitted("add-open-app");
+        assert_eq!(added.len(), 2);
+        assert!(added.iter().all(|app| app["exe"] == exe));
+        assert_eq!(added[0]["display_name"], "grouped");
+        let exes = SeelenWeg::open_exes();
+        assert_eq!(exes.iter().filter(|open| *open == exe).count(), 1);
+    }
+
+    #[test]
+    fn frames_are_grouped_by_their_creator() {
+        let mut sim = Simulation::new().with_weg();
+        let exe = "C:\\Program Files\\WindowsApps\\Calculator.exe";
+        let frame = sim.desktop.add(MockWindow::app(FRAME_HOST, "Calculator"));
+        let creator = sim.desktop.add(
+            MockWindow::app(exe, "Calculator")
+                .class("Windows.UI.Core.CoreWindow")
+                .child_of(frame),
+        );
+        sim.emit(WinEvent::ObjectShow, frame);
+        sim.emit(WinEvent::ObjectShow, creator);
+        assert!(SeelenWeg::contains_app(creator));
+
+        assert_eq!(sim.dock(), vec![frame.0]);
+        let added = sim.host.emitted("add-open-app");
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0]["exe"], exe);
+        assert_eq!(added[0]["creator_hwnd"], creator.0);
+    }
+
+    #[test]
+    fn frame_is_added_when_its_creator_is_attached() {
+        let mut sim = Simulation::new().with_weg();
+        let frame = sim.open(MockWindow::app(FRAME_HOST, "Photos"));
+        assert!(sim.dock().is_empty());
+
+        let creator = sim.desktop.add(
+            MockWindow::app("C:\\Program Files\\WindowsApps\\Photos.exe", "Photos")
+                .class("Windows.UI.Core.CoreWindow")
+                .child_of(frame),
+        );
+        sim.emit(WinEvent::ObjectParentChange, creator);
+        assert_eq!(sim.dock(), vec![frame.0]);
+    }
+
+    #[test]
+    fn title_changes_update_the_item() {
+        let mut sim = Simulation::new().with_weg();
+        let hwnd = sim.open(MockWindow::app("C:\\Apps\\titled.exe", "Loading"));
+        sim.desktop
+            .update(hwnd, |window| window.title = "Document".to_string());
+        sim.emit(WinEvent::ObjectNameChange, hwnd);
+
+        let updates = sim.host.emitted("update-open-app-info");
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0]["title"], "Document");
+        assert_eq!(sim.dock(), vec![hwnd.0]);
+    }
+
+    #[test]
+    fn disabled_dock_ignores_the_windows() {
+        let mut sim = Simulation::new();
+        sim.weg_enabled = false;
+        sim.open(MockWindow::app("C:\\Apps\\ignored.exe", "Ignored"));
+        assert!(sim.dock().is_empty());
+        assert!(sim.host.emitted("add-open-app").is_empty());
+    }
+
+    #[test]
+    fn dock_hides_while_the_focused_window_overlaps_it() {
+        let mut sim = Simulation::new().with_weg();
+        let hwnd = sim.open(MockWindow::app("C:\\Apps\\overlap.exe", "Editor"));
+        let other = sim.open(MockWindow::app("C:\\Apps\\overlap.exe", "Other"));
+        sim.focus(hwnd);
+        assert!(sim.host.emitted("set-auto-hide").is_empty());
+
+        // only the foreground window is tracked
+        sim.move_to(other, rect(100, 500, 900, 1040));
+        assert!(sim.host.emitted("set-auto-hide").is_empty());
+
+        sim.move_to(hwnd, rect(100, 500, 900, 1040));
+        assert_eq!(sim.host.emitted("set-auto-hide"), vec![json!(true)]);
+
+        sim.move_to(hwnd, rect(100, 100, 900, 700));
+        assert_eq!(
+            sim.host.emitted("set-auto-hide"),
+            vec![json!(true), json!(false)]
+        );
+
+        // focusing a window over the dock also hides it
+        sim.focus(other);
+        assert_eq!(sim.host.emitted("set-auto-hide").len(), 3);
+    }
+
+    #[test]
+    fn hidden_windows_do_not_overlap() {
+        let mut sim = Simulation::new().with_weg();
+        let hwnd = sim.open(MockWindow::app("C:\\Apps\\hidden_overlap.exe", "Hidden"));
+        sim.desktop.update(hwnd, |window| {
+            window.visible = false;
+            window.rect = rect(0, 0, 1920, 1040);
+        });
+        sim.focus(hwnd);
+        assert!(sim.host.emitted("set-auto-hide").is_empty());
+    }
+
+    #[test]
+    fn focused_app_behavior() {
+        let mut sim = Simulation::new().with_weg();
+        sim.host
+            .set_weg_while_focused("player.exe", FocusedAppBehavior::Hide);
+        let player = sim.open(MockWindow::app("C:\\Apps\\player.exe", "Player"));
+        let editor = sim.open(MockWindow::app("C:\\Apps\\notes.exe", "Notes"));
+        sim.focus(player);
+        sim.focus(editor);
+        assert_eq!(
+            sim.host.emitted("set-focused-app-behavior"),
+            vec![json!("hide"), Value::Null]
+        );
+    }
+}
@@ -3,6 +3,7 @@ use image::ImageBuffer;
 use image::RgbaImage;
 use itertools::Itertools;
 use tauri::AppHandle;
+use tauri::Emitter;
 use widestring::U16CString;
 use windows::core::PCWSTR;
 use windows::Win32::Graphics::Gdi::CreateCompatibleDC;
@@ -14,6 +15,10 @@ use windows::Win32::Graphics::Gdi::BITMAPINFO;
 use windows::Win32::Graphics::Gdi::BITMAPINFOHEADER;
 use windows::Win32::Graphics::Gdi::DIB_RGB_COLORS;
 use windows::Win32::UI::Shell::ExtractIconExW;
+use windows::Win32::UI::Shell::SHGetFileInfoW;
+use windows::Win32::UI::Shell::SHFILEINFOW;
+use windows::Win32::UI::Shell::SHGFI_ICON;
+use windows::Win32::UI::Shell::SHGFI_LARGEICON;
 use windows::Win32::UI::WindowsAndMessaging::DestroyIcon;
 use windows::Win32::UI::WindowsAndMessaging::GetIconInfoExW;
 use windows::Win32::UI::WindowsAndMessaging::HICON;
@@ -25,9 +30,12 @@ use std::arch::x86_64::_mm_setr_epi8;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::_mm_shuffle_epi8;
 use std::arch::x86_64::_mm_storeu_si128;
+use std::path::Path;
 use std::path::PathBuf;
 
 use crate::error_handler::Result;
+use crate::modules::cache::{domain::CacheKind, Cache};
+use crate::modules::color_scheme::ColorScheme;
 use crate::modules::uwp::UWP_MANAGER;
 use crate::trace_lock;
 use crate::utils::app_data_path;
@@ -178,15 +186,18 @@ pub fn extract_and_save_icon(handle: &AppHandle, exe_path: &str) -> Result<PathB
     let saved_icon_path = gen_icons_paths.join(filename.replace(".exe", ".png"));
 
     if saved_icon_path.exists() {
+        Cache::touch(CacheKind::Icons, &saved_icon_path);
         return Ok(saved_icon_path);
     }
 
     log::trace!("Extracting icon for \"{}\"", filename);
 
     if let Some(package) = trace_lock!(UWP_MANAGER).get_from_path(&path) {
-        if let Some(uwp_icon_path) = package.get_light_icon(&filename) {
+        let light = !ColorScheme::current().system_dark;
+        if let Some(uwp_icon_path) = package.get_themed_icon(&filename, light) {
             log::debug!("Copying UWP icon from \"{}\"", uwp_icon_path.display());
             std::fs::copy(uwp_icon_path, &saved_icon_path)?;
+            Cache::record(CacheKind::Icons, &saved_icon_path);
             return Ok(saved_icon_path);
         }
     }
@@ -196,6 +207,7 @@ pub fn extract_and_save_icon(handle: &AppHandle, exe_path: &str) -> Result<PathB
         // icon on index 0 always is the app showed icon
         if let Some(icon) = images.first() {
             icon.save(&saved_icon_path)?;
+            Cache::record(CacheKind::Icons, &saved_icon_path);
             return Ok(saved_icon_path);
         }
     }
@@ -203,3 +215,94 @@ pub fn extract_and_save_icon(handle: &AppHandle, exe_path: &str) -> Result<PathB
     log::trace!("No icon found for \"{}\"", filename);
     Err("Failed to extract icon".into())
 }
+
+/// Replaces the cached icons of the packaged apps by the variant made for `light` or dark
+/// surfaces, custom icons of other apps are kept. Emits `icons-changed` if any was replaced.
+pub fn refresh_packaged_apps_icons(handle: &AppHandle, light: bool) -> Result<()> {
+    let gen_icons_paths = app_data_path(handle).join("icons");
+    if !gen_icons_paths.exists() {
+        return Ok(());
+    }
+
+    let mut refreshed = 0;
+    {
+        let manager = trace_lock!(UWP_MANAGER);
+        for entry in std::fs::read_dir(&gen_icons_paths)?.flatten() {
+            let saved_icon_path = entry.path();
+            if saved_icon_path.extension().map_or(true, |ext| ext != "png") {
+                continue;
+            }
+            let filename = saved_icon_path
+                .with_extension("exe")
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            let themed = manager
+                .get_from_exe(&filename)
+                .and_then(|package| package.get_themed_icon(&filename, light));
+            if let Some(uwp_icon_path) = themed {
+                std::fs::copy(uwp_icon_path, &saved_icon_path)?;
+                Cache::record(CacheKind::Icons, &saved_icon_path);
+                refreshed += 1;
+            }
+        }
+    }
+
+    log::trace!("Refreshed {} packaged app icons", refreshed);
+    if refreshed > 0 {
+        handle.emit("icons-changed", ())?;
+    }
+    Ok(())
+}
+
+/// Icon shown by the Explorer for a file or folder, unlike `extract_and_save_icon` this
+/// also works for documents and folders. Icons are cached by extension, except for the
+/// files that have their own icon (executables, shortcuts) and folders.
+pub fn extract_and_save_shell_icon(handle: &AppHandle, path: &Path) -> Result<PathBuf> {
+    let gen_icons_paths = app_data_path(handle).join("icons").join("shell");
+    if !gen_icons_paths.exists() {
+        std::fs::create_dir_all(&gen_icons_paths)?;
+    }
+
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let extension = path
+        .extension()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_lowercase();
+    let key = if path.is_dir() {
+        format!("folder-{}", name)
+    } else if ["exe", "lnk", "url", "ico"].contains(&extension.as_str()) {
+        name.to_string()
+    } else {
+        format!("ext-{}", extension)
+    };
+    let saved_icon_path = gen_icons_paths.join(format!("{}.png", key));
+
+    if saved_icon_path.exists() {
+        Cache::touch(CacheKind::Icons, &saved_icon_path);
+        return Ok(saved_icon_path);
+    }
+
+    let path_cstr = U16CString::from_os_str(path.as_os_str()).map_err(|_| eyre!("Invalid path"))?;
+    let mut info = SHFILEINFOW::default();
+    unsafe {
+        let result = SHGetFileInfoW(
+            PCWSTR(path_cstr.as_ptr()),
+            Default::default(),
+            Some(&mut info),
+            std::mem::size_of::<SHFILEINFOW>() as u32,
+            SHGFI_ICON | SHGFI_LARGEICON,
+        );
+        if result == 0 || info.hIcon.is_invalid() {
+            return Err(eyre!("Failed to get shell icon").into());
+        }
+    }
+
+    let image = convert_hicon_to_rgba_image(&info.hIcon);
+    unsafe { DestroyIcon(info.hIcon)? };
+    image?.save(&saved_icon_path)?;
+    Cache::record(CacheKind::Icons, &saved_icon_path);
+    Ok(saved_icon_path)
+}
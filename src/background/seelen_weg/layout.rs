@@ -0,0 +1,208 @@
+use seelen_core::state::{SeelenWegSettings, SeelenWegSide};
+use serde::Serialize;
+use windows::Win32::Foundation::RECT;
+
+/// How the dock items are distributed when they don't fit on a single row. On vertical
+/// docks the rows are columns, but the naming is kept to avoid duplicating the logic.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WegLayoutConstraints {
+    pub rows: u32,
+    pub items_per_row: u32,
+    /// factor applied to the configured item size when `auto_scale` shrinks the items
+    pub item_scale: f64,
+    /// the items don't fit even at `min_size`, so the dock scrolls along its edge
+    pub scrollable: bool,
+}
+
+impl Default for WegLayoutConstraints {
+    fn default() -> Self {
+        Self {
+            rows: 1,
+            items_per_row: u32::MAX,
+            item_scale: 1.0,
+            scrollable: false,
+        }
+    }
+}
+
+impl WegLayoutConstraints {
+    /// Fraction of an item (and its gap) that has to be free before growing the items back or
+    /// leaving the scroll mode, so adding and removing the same item doesn't resize the dock
+    /// back and forth.
+    const HYSTERESIS: f64 = 0.5;
+
+    /// `scale` is the device pixel ratio of the monitor, the settings are in logical pixels
+    /// while the work area is in physical ones. `previous` is the current layout of the dock.
+    pub fn calculate(
+        item_count: usize,
+        rc_work: &RECT,
+        scale: f64,
+        settings: &SeelenWegSettings,
+        previous: &Self,
+    ) -> Self {
+        let max_rows = settings.max_rows.max(1);
+        if item_count == 0 {
+            return Self::default();
+        }
+
+        let edge = match settings.position {
+            SeelenWegSide::Top | SeelenWegSide::Bottom => rc_work.right - rc_work.left,
+            SeelenWegSide::Left | SeelenWegSide::Right => rc_work.bottom - rc_work.top,
+        };
+        let available =
+            edge as f64 / scale - (settings.margin as f64 + settings.padding as f64) * 2.0;
+
+        let count = item_count as u32;
+        let size = settings.size.max(1) as f64;
+        let gap = settings.space_between_items as f64;
+        let slack = (size + gap) * Self::HYSTERESIS;
+
+        let (wrapped, overflows) = Self::wrap(count, available, size, gap, max_rows);
+        if !settings.auto_scale {
+            // when clamped, the last row overflows instead of hiding items
+            return wrapped;
+        }
+
+        let was_shrunk = previous.item_scale < 1.0 || previous.scrollable;
+        let fits_with_slack = || !Self::wrap(count, available - slack, size, gap, max_rows).1;
+        if !overflows && (!was_shrunk || fits_with_slack()) {
+            return wrapped;
+        }
+
+        // all the rows are used and the items are shrunk to fill them
+        let rows = max_rows.min(count);
+        let per_row = count.div_ceil(rows);
+        let fitting_scale = |available: f64| ((available + gap) / per_row as f64 - gap) / size;
+
+        let min_scale = (settings.min_size as f64 / size).clamp(0.0, 1.0);
+        let mut item_scale = fitting_scale(available).min(1.0);
+        if item_scale > previous.item_scale {
+            // grows only if there is room to spare, but never beyond what fits
+            item_scale = fitting_scale(available - slack)
+                .max(previous.item_scale)
+                .min(item_scale);
+        }
+
+        let scrollable = item_scale < min_scale
+            || (previous.scrollable && fitting_scale(available - slack) < min_scale);
+        Self {
+            rows,
+            items_per_row: if rows == 1 { u32::MAX } else { per_row },
+            item_scale: item_scale.max(min_scale),
+            scrollable,
+        }
+    }
+
+    /// Distributes the items in as many rows as needed up to `max_rows`, also returning
+    /// if they still overflow the available space.
+    fn wrap(count: u32, available: f64, size: f64, gap: f64, max_rows: u32) -> (Self, bool) {
+        // n items need n * size + (n - 1) * gap, so the gap is added once to the available space
+        let items_per_row = (((available + gap) / (size + gap)).floor() as u32).max(1);
+        let needed = count.div_ceil(items_per_row);
+        let overflows = needed > max_rows;
+        if needed <= 1 || max_rows == 1 {
+            return (Self::default(), overflows);
+        }
+
+        let rows = needed.min(max_rows);
+        let constraints = Self {
+            rows,
+            items_per_row: count.div_ceil(rows),
+            ..Self::default()
+        };
+        (constraints, overflows)
+    }
+
+    /// Logical thickness of the dock including its padding.
+    pub fn thickness(&self, settings: &SeelenWegSettings) -> f64 {
+        let rows = self.rows as f64;
+        rows * settings.size as f64 * self.item_scale
+            + (rows - 1.0) * settings.space_between_items as f64
+            + settings.padding as f64 * 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(auto_scale: bool) -> SeelenWegSettings {
+        SeelenWegSettings {
+            size: 40,
+            space_between_items: 8,
+            margin: 0,
+            padding: 0,
+            max_rows: 1,
+            min_size: 20,
+            auto_scale,
+            ..Default::default()
+        }
+    }
+
+    fn work_area(width: i32) -> RECT {
+        RECT {
+            left: 0,
+            top: 0,
+            right: width,
+            bottom: 1000,
+        }
+    }
+
+    #[test]
+    fn items_are_shrunk_only_when_they_overflow() {
+        let settings = settings(true);
+        // 10 items of 40px with 8px gaps need 472px
+        let fits = WegLayoutConstraints::calculate(
+            10,
+            &work_area(480),
+            1.0,
+            &settings,
+            &WegLayoutConstraints::default(),
+        );
+        assert_eq!(fits, WegLayoutConstraints::default());
+
+        // only 8 items fit at 40px, so the 10 are shrunk to 32px
+        let shrunk = WegLayoutConstraints::calculate(
+            10,
+            &work_area(392),
+            1.0,
+            &settings,
+            &WegLayoutConstraints::default(),
+        );
+        assert_eq!(shrunk.item_scale, 0.8);
+        assert!(!shrunk.scrollable);
+
+        let scrolled = WegLayoutConstraints::calculate(
+            30,
+            &work_area(400),
+            1.0,
+            &settings,
+            &WegLayoutConstraints::default(),
+        );
+        assert!(scrolled.scrollable);
+        assert_eq!(scrolled.item_scale, 0.5);
+    }
+
+    #[test]
+    fn items_grow_back_only_with_room_to_spare() {
+        let settings = settings(true);
+        let shrunk = WegLayoutConstraints::calculate(
+            11,
+            &work_area(480),
+            1.0,
+            &settings,
+            &WegLayoutConstraints::default(),
+        );
+        assert!(shrunk.item_scale < 1.0);
+
+        // removing the item leaves only 8px free at full size, less than the hysteresis
+        let after_removal =
+            WegLayoutConstraints::calculate(10, &work_area(480), 1.0, &settings, &shrunk);
+        assert!(after_removal.item_scale < 1.0);
+
+        let with_room =
+            WegLayoutConstraints::calculate(9, &work_area(480), 1.0, &settings, &shrunk);
+        assert_eq!(with_room, WegLayoutConstraints::default());
+    }
+}
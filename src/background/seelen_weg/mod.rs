@@ -1,41 +1,58 @@
+pub mod app_names;
+pub mod background;
 pub mod cli;
+pub mod folders;
 pub mod handler;
+pub mod hang;
 pub mod hook;
 pub mod icon_extractor;
-
-use std::thread::JoinHandle;
-
+pub mod layout;
+pub mod overlay;
+pub mod reveal;
+pub mod rules;
+pub mod spring;
+pub mod suspension;
+pub mod thumbnails;
+pub mod zorder;
+
+use std::{path::Path, thread::JoinHandle, time::Duration};
+
+use app_names::AppNames;
 use getset::{Getters, MutGetters};
 use icon_extractor::extract_and_save_icon;
 use image::{DynamicImage, RgbaImage};
+use itertools::Itertools;
+use layout::WegLayoutConstraints;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
-use seelen_core::state::AppExtraFlag;
-use serde::Serialize;
-use tauri::{path::BaseDirectory, Emitter, Listener, Manager, WebviewWindow, Wry};
+use seelen_core::state::{FocusedAppBehavior, SeelenWegSide};
+use serde::{Deserialize, Serialize};
+use tauri::{path::BaseDirectory, Emitter, Listener, Manager, WebviewWindow, WindowEvent, Wry};
 use win_screenshot::capture::capture_window;
 use windows::Win32::{
     Foundation::{BOOL, HWND, LPARAM, RECT},
+    Graphics::Gdi::HMONITOR,
     UI::WindowsAndMessaging::{
         EnumWindows, HWND_TOPMOST, SWP_NOACTIVATE, SW_HIDE, SW_SHOWNOACTIVATE, SW_SHOWNORMAL,
-        WS_EX_APPWINDOW, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW,
+        WS_CAPTION,
     },
 };
 
 use crate::{
     error_handler::Result,
+    host::{emit_global, with_host, Surface},
     log_error,
-    modules::uwp::UWP_MANAGER,
+    modules::{
+        conflicts::domain::Mitigation, event_recorder::EventRecorder,
+        launcher::domain::match_score, monitors::theme::MonitorThemeVars,
+        rendering::RenderingProbe, uwp::UWP_MANAGER, window_titles::WindowTitles,
+    },
     seelen::{get_app_handle, SEELEN},
     seelen_bar::FancyToolbar,
     state::application::FULL_STATE,
     trace_lock,
-    utils::{
-        are_overlaped,
-        constants::{OVERLAP_BLACK_LIST_BY_EXE, OVERLAP_BLACK_LIST_BY_TITLE},
-        sleep_millis,
-    },
-    windows_api::{window::Window, AppBarData, AppBarDataState, WindowsApi},
+    utils::{are_overlaped, sleep_millis, throttle::emit_throttled},
+    windows_api::{window::Window, AppBarData, AppBarDataState, WindowEnumerator, WindowsApi},
 };
 
 lazy_static! {
@@ -57,38 +74,79 @@ pub struct SeelenWegApp {
     hwnd: isize,
     exe: String,
     title: String,
+    /// localized name of the executable
+    display_name: String,
     icon_path: String,
     execution_path: String,
     creator_hwnd: isize,
 }
 
+/// Details of the executable of an open app
+#[derive(Debug, Clone, Default)]
+pub struct WegAppInfo {
+    pub display_name: String,
+    pub icon_path: String,
+    pub execution_path: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SeelenWegTab {
+    hwnd: isize,
+    title: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct WindowMatch {
+    pub hwnd: isize,
+    pub title: String,
+    pub exe: String,
+    pub icon: String,
+    pub score: u32,
+}
+
+/// Placement of the dock along its edge in logical pixels, as rendered by the webview.
+/// The thickness is not included because it is calculated from the configured dock size
+/// and the amount of rows needed to show all the items.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HitboxLayout {
+    /// distance from the start of the edge to the dock
+    offset: f64,
+    length: f64,
+    auto_hide: bool,
+    #[serde(default)]
+    item_count: usize,
+}
+
 #[derive(Getters, MutGetters)]
-pub struct SeelenWeg {
-    window: WebviewWindow<Wry>,
-    hitbox: WebviewWindow<Wry>,
+pub struct SeelenWeg<S: Surface = WebviewWindow<Wry>> {
+    window: S,
+    hitbox: S,
     #[getset(get = "pub")]
     ready: bool,
     hidden: bool,
     overlaped: bool,
+    focused_app_behavior: Option<FocusedAppBehavior>,
     last_hitbox_rect: Option<RECT>,
+    hitbox_layout: Option<HitboxLayout>,
+    layout_constraints: WegLayoutConstraints,
 }
 
-impl Drop for SeelenWeg {
+impl<S: Surface> Drop for SeelenWeg<S> {
     fn drop(&mut self) {
-        log::info!("Dropping {}", self.window.label());
-        log_error!(self.window.destroy());
-        log_error!(self.hitbox.destroy());
+        log::info!("Dropping {}", self.window.name());
+        log_error!(self.window.close());
+        log_error!(self.hitbox.close());
     }
 }
 
 // SINGLETON
 impl SeelenWeg {
     pub fn set_active_window(hwnd: HWND) -> Result<()> {
-        let handle = get_app_handle();
-        handle.emit("set-focused-handle", hwnd.0)?;
-        handle.emit(
+        emit_global("set-focused-handle", hwnd.0)?;
+        emit_global(
             "set-focused-executable",
-            WindowsApi::exe(hwnd).unwrap_or_default(),
+            Window::from(hwnd).exe().unwrap_or_default(),
         )?;
         Ok(())
     }
@@ -102,6 +160,33 @@ impl SeelenWeg {
             .to_uppercase()
     }
 
+    /// Name, icon and launch path of an executable, UWP apps are launched by their shell path
+    pub fn app_info(exe: Option<&Path>) -> WegAppInfo {
+        let Some(path) = exe else {
+            return WegAppInfo {
+                icon_path: Self::missing_icon(),
+                ..Default::default()
+            };
+        };
+
+        let exe_path = path.to_string_lossy().to_string();
+        let exe_name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        WegAppInfo {
+            display_name: AppNames::get(&exe_path),
+            icon_path: Self::extract_icon(&exe_path).unwrap_or_else(|_| Self::missing_icon()),
+            execution_path: match trace_lock!(UWP_MANAGER).get_from_path(path) {
+                Some(package) => package
+                    .get_shell_path(&exe_name)
+                    .unwrap_or_else(|| exe_path.clone()),
+                None => exe_path,
+            },
+        }
+    }
+
     pub fn extract_icon(exe_path: &str) -> Result<String> {
         Ok(extract_and_save_icon(&get_app_handle(), exe_path)?
             .to_string_lossy()
@@ -115,14 +200,54 @@ impl SeelenWeg {
             .any(|app| app.hwnd == hwnd.0 || app.creator_hwnd == hwnd.0)
     }
 
+    pub fn open_handles() -> Vec<isize> {
+        trace_lock!(OPEN_APPS).iter().map(|app| app.hwnd).collect()
+    }
+
+    pub fn open_exes() -> Vec<String> {
+        trace_lock!(OPEN_APPS)
+            .iter()
+            .map(|app| app.exe.clone())
+            .unique()
+            .collect()
+    }
+
+    /// Open windows matching `query` by title or executable name, best matches first
+    pub fn search_windows(query: &str) -> Vec<WindowMatch> {
+        let mut matches: Vec<WindowMatch> = trace_lock!(OPEN_APPS)
+            .iter()
+            .filter_map(|app| {
+                let title = WindowsApi::get_window_text(HWND(app.hwnd));
+                let exe_name = app.exe.rsplit('\\').next().unwrap_or(&app.exe);
+                // the app name and executable are weaker matches than the title
+                let score = match_score(query, &title)
+                    .or_else(|| match_score(query, &app.display_name).map(|score| score / 2))
+                    .or_else(|| match_score(query, exe_name).map(|score| score / 2))?;
+                Some(WindowMatch {
+                    hwnd: app.hwnd,
+                    title: WindowTitles::normalize(&title),
+                    exe: app.exe.clone(),
+                    icon: app.icon_path.clone(),
+                    score,
+                })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.cmp(&a.score));
+        matches
+    }
+
     pub fn update_app(hwnd: HWND) {
         let mut apps = trace_lock!(OPEN_APPS);
         let app = apps.iter_mut().find(|app| app.hwnd == hwnd.0);
         if let Some(app) = app {
-            app.title = WindowsApi::get_window_text(hwnd);
-            get_app_handle()
-                .emit("update-open-app-info", app.clone())
-                .expect("Failed to emit");
+            app.title = WindowTitles::normalize(&Window::from(hwnd).title());
+            // some apps (media players, terminals) change their title several times per second
+            log_error!(emit_throttled(
+                "update-open-app-info",
+                app.hwnd,
+                app.clone(),
+                Duration::from_millis(250),
+            ));
         }
     }
 
@@ -132,7 +257,7 @@ impl SeelenWeg {
         }
 
         let window = Window::from(hwnd);
-        let title = window.title();
+        let title = WindowTitles::normalize(&window.title());
 
         let creator = match window.get_frame_creator() {
             Ok(None) => return,
@@ -140,86 +265,51 @@ impl SeelenWeg {
             Err(_) => window,
         };
 
-        let mut app = SeelenWegApp {
+        let exe = creator.exe().ok();
+        let info = with_host(|host| host.weg_app_info(exe.as_deref()));
+        let app = SeelenWegApp {
             hwnd: hwnd.0,
-            exe: String::new(),
+            exe: exe
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_default(),
             title,
-            icon_path: String::new(),
-            execution_path: String::new(),
+            display_name: info.display_name,
+            icon_path: info.icon_path,
+            execution_path: info.execution_path,
             creator_hwnd: creator.hwnd().0,
         };
 
-        if let Ok(path) = creator.exe() {
-            app.exe = path.to_string_lossy().to_string();
-            app.icon_path = Self::extract_icon(&app.exe).unwrap_or_else(|_| Self::missing_icon());
-
-            let exe = path
-                .file_name()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string();
-            app.execution_path = match trace_lock!(UWP_MANAGER).get_from_path(&path) {
-                Some(package) => package
-                    .get_shell_path(&exe)
-                    .unwrap_or_else(|| app.exe.clone()),
-                None => app.exe.clone(),
-            };
-        } else {
-            app.icon_path = Self::missing_icon();
-        }
-
-        get_app_handle()
-            .emit("add-open-app", app.clone())
-            .expect("Failed to emit");
-
+        emit_global("add-open-app", app.clone()).expect("Failed to emit");
         trace_lock!(OPEN_APPS).push(app);
     }
 
     pub fn remove_hwnd(hwnd: HWND) {
         trace_lock!(OPEN_APPS).retain(|app| app.hwnd != hwnd.0);
-        get_app_handle()
-            .emit("remove-open-app", hwnd.0)
-            .expect("Failed to emit");
+        emit_global("remove-open-app", hwnd.0).expect("Failed to emit");
     }
 
-    pub fn should_be_added(hwnd: HWND) -> bool {
-        let window = Window::from(hwnd);
-
-        if !window.is_visible() || window.parent().is_some() {
-            return false;
-        }
-
-        let ex_style = WindowsApi::get_ex_styles(hwnd);
-        if (ex_style.contains(WS_EX_TOOLWINDOW) || ex_style.contains(WS_EX_NOACTIVATE))
-            && !ex_style.contains(WS_EX_APPWINDOW)
-        {
-            return false;
-        }
-
-        if let Ok(frame_creator) = window.get_frame_creator() {
-            if frame_creator.is_none() {
-                return false;
-            }
-        }
-
-        if WindowsApi::window_is_uwp_suspended(hwnd).unwrap_or_default() {
-            return false;
-        }
-
-        if let Ok(path) = window.exe() {
-            if path.starts_with("C:\\Windows\\SystemApps") {
-                return false;
+    /// Syncs the open apps with the current windows, used when the dock is enabled at
+    /// runtime as the windows are not tracked while it is disabled.
+    pub fn refill_open_apps() -> Result<()> {
+        for hwnd in Self::open_handles() {
+            if !Window::from(HWND(hwnd)).is_window() {
+                Self::remove_hwnd(HWND(hwnd));
             }
         }
-
-        if let Some(config) = FULL_STATE.load().get_app_config_by_window(hwnd) {
-            if config.options.contains(&AppExtraFlag::Hidden) {
-                log::trace!("Skipping by config: {:?}", window);
-                return false;
+        WindowEnumerator::new().for_each(|hwnd| {
+            if Self::should_be_added(hwnd) {
+                Self::add_hwnd(hwnd);
             }
-        }
+        })
+    }
 
-        !TITLE_BLACK_LIST.contains(&window.title().as_str())
+    pub fn should_be_added(hwnd: HWND) -> bool {
+        let verdict = with_host(|host| host.weg_verdict(&Window::from(hwnd)));
+        EventRecorder::record("weg", || match verdict.rejected_by {
+            Some(rule) => format!("skipped {:?}: {}", Window::from(hwnd), rule),
+            None => format!("accepted {:?}", Window::from(hwnd)),
+        });
+        verdict.accepted
     }
 
     pub fn capture_window(hwnd: HWND) -> Option<DynamicImage> {
@@ -228,13 +318,53 @@ impl SeelenWeg {
             DynamicImage::ImageRgba8(image)
         })
     }
+
+    /// Tabs registered on the taskbar with `ITaskbarList3::RegisterTab` (browsers, Office),
+    /// each one is a hidden top-level proxy window that provides its own iconic thumbnail.
+    /// The registration can not be queried, so proxies are taken from the thread of the
+    /// window and only when that thread has no other window on the dock to own them.
+    pub fn get_tabs(hwnd: HWND) -> Result<Vec<SeelenWegTab>> {
+        let (_, thread_id) = WindowsApi::window_thread_process_id(hwnd);
+        let mut tabs = Vec::new();
+        let mut ambiguous = false;
+        WindowEnumerator::new().for_each(|tab| {
+            if tab == hwnd || WindowsApi::window_thread_process_id(tab).1 != thread_id {
+                return;
+            }
+            if WindowsApi::is_window_visible(tab) {
+                ambiguous |= Self::should_be_added(tab);
+                return;
+            }
+            let is_proxy = WindowsApi::get_owner(tab).0 == 0
+                && WindowsApi::get_styles(tab).contains(WS_CAPTION)
+                && WindowsApi::has_iconic_bitmap(tab) != Some(false);
+            if !is_proxy {
+                return;
+            }
+            let title = WindowsApi::get_window_text(tab);
+            if !title.is_empty() {
+                tabs.push(SeelenWegTab {
+                    hwnd: tab.0,
+                    title: WindowTitles::normalize(&title),
+                });
+            }
+        })?;
+        if ambiguous {
+            tabs.clear();
+        }
+        // a single proxy is not worth it, it would show the same as the window capture
+        if tabs.len() < 2 {
+            tabs.clear();
+        }
+        Ok(tabs)
+    }
 }
 
 // INSTANCE
 impl SeelenWeg {
-    pub fn new(postfix: &str) -> Result<Self> {
+    pub fn new(postfix: &str, theme_vars: &MonitorThemeVars) -> Result<Self> {
         log::info!("Creating {}/{}", Self::TARGET, postfix);
-        let (window, hitbox) = Self::create_window(postfix)?;
+        let (window, hitbox) = Self::create_window(postfix, theme_vars)?;
 
         let weg = Self {
             window,
@@ -242,23 +372,47 @@ impl SeelenWeg {
             ready: false,
             hidden: false,
             overlaped: false,
+            focused_app_behavior: None,
             last_hitbox_rect: None,
+            hitbox_layout: None,
+            layout_constraints: WegLayoutConstraints::default(),
         };
 
         Ok(weg)
     }
 
-    fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<()> {
-        self.window.emit_to(self.window.label(), event, payload)?;
+    pub fn set_theme_vars(&self, vars: &MonitorThemeVars) -> Result<()> {
+        self.window.eval(&vars.script())?;
         Ok(())
     }
+}
+
+// EVENTS
+impl<S: Surface> SeelenWeg<S> {
+    /// Dock on already created webviews, used by the tests
+    #[cfg(test)]
+    pub fn from_surfaces(window: S, hitbox: S) -> Self {
+        Self {
+            window,
+            hitbox,
+            ready: true,
+            hidden: false,
+            overlaped: false,
+            focused_app_behavior: None,
+            last_hitbox_rect: None,
+            hitbox_layout: None,
+            layout_constraints: WegLayoutConstraints::default(),
+        }
+    }
+
+    fn emit<P: Serialize + Clone>(&self, event: &str, payload: P) -> Result<()> {
+        self.window.emit_self(event, payload)
+    }
 
     fn is_overlapping(&self, hwnd: HWND) -> bool {
-        let rect = WindowsApi::get_window_rect_without_margins(hwnd);
+        let rect = Window::from(hwnd).frame_rect();
         let hitbox_rect = self.last_hitbox_rect.unwrap_or_else(|| {
-            WindowsApi::get_window_rect_without_margins(HWND(
-                self.hitbox.hwnd().expect("Failed to get hitbox handle").0,
-            ))
+            Window::from(self.hitbox.handle().expect("Failed to get hitbox handle")).frame_rect()
         });
         are_overlaped(&hitbox_rect, &rect)
     }
@@ -270,9 +424,7 @@ impl SeelenWeg {
 
         self.overlaped = is_overlaped;
         self.last_hitbox_rect = if self.overlaped {
-            Some(WindowsApi::get_window_rect_without_margins(HWND(
-                self.hitbox.hwnd()?.0,
-            )))
+            Some(Window::from(self.hitbox.handle()?).frame_rect())
         } else {
             None
         };
@@ -282,32 +434,65 @@ impl SeelenWeg {
     }
 
     pub fn handle_overlaped_status(&mut self, hwnd: HWND) -> Result<()> {
-        let should_handle_hidden = self.ready
-            && WindowsApi::is_window_visible(hwnd)
-            && !OVERLAP_BLACK_LIST_BY_TITLE.contains(&WindowsApi::get_window_text(hwnd).as_str())
-            && !OVERLAP_BLACK_LIST_BY_EXE
-                .contains(&WindowsApi::exe(hwnd).unwrap_or_default().as_str());
-
-        if !should_handle_hidden {
+        if !self.ready || !Window::from(hwnd).can_overlap() {
             return Ok(());
         }
 
         self.set_overlaped_status(self.is_overlapping(hwnd))
     }
 
+    /// Hides or dims the dock while the focused app has it set on its app config
+    pub fn handle_focused_app_behavior(&mut self, hwnd: HWND) -> Result<()> {
+        if !self.ready || Window::from(hwnd).is_seelen_window() {
+            return Ok(());
+        }
+        let behavior = with_host(|host| host.weg_while_focused(&Window::from(hwnd)));
+        if self.focused_app_behavior != behavior {
+            self.focused_app_behavior = behavior;
+            self.emit("set-focused-app-behavior", behavior)?;
+        }
+        Ok(())
+    }
+
     pub fn hide(&mut self) -> Result<()> {
-        WindowsApi::show_window_async(self.window.hwnd()?, SW_HIDE)?;
-        WindowsApi::show_window_async(self.hitbox.hwnd()?, SW_HIDE)?;
+        Window::from(self.window.handle()?).show_async(SW_HIDE)?;
+        Window::from(self.hitbox.handle()?).show_async(SW_HIDE)?;
         self.hidden = true;
         Ok(())
     }
 
     pub fn show(&mut self) -> Result<()> {
-        WindowsApi::show_window_async(self.window.hwnd()?, SW_SHOWNOACTIVATE)?;
-        WindowsApi::show_window_async(self.hitbox.hwnd()?, SW_SHOWNOACTIVATE)?;
+        Window::from(self.window.handle()?).show_async(SW_SHOWNOACTIVATE)?;
+        Window::from(self.hitbox.handle()?).show_async(SW_SHOWNOACTIVATE)?;
         self.hidden = false;
         Ok(())
     }
+}
+
+impl SeelenWeg {
+    /// Shows again the dock if it should be visible but was hidden by the system,
+    /// as happens sometimes after unlocking the session.
+    pub fn restore_visibility(&self) -> Result<()> {
+        if self.hidden {
+            return Ok(());
+        }
+        for hwnd in [self.hitbox.hwnd()?, self.window.hwnd()?] {
+            if !WindowsApi::is_window_visible(hwnd) {
+                WindowsApi::show_window_async(hwnd, SW_SHOWNOACTIVATE)?;
+            }
+        }
+        self.ensure_zorder()
+    }
+
+    /// Puts the dock and its hitbox back above other topmost windows
+    pub fn ensure_zorder(&self) -> Result<()> {
+        if self.hidden {
+            return Ok(());
+        }
+        WindowsApi::bring_to(self.hitbox.hwnd()?, HWND_TOPMOST)?;
+        WindowsApi::bring_to(self.window.hwnd()?, HWND_TOPMOST)?;
+        Ok(())
+    }
 
     pub fn ensure_hitbox_zorder(&self) -> Result<()> {
         WindowsApi::bring_to(self.hitbox.hwnd()?, HWND_TOPMOST)?;
@@ -315,21 +500,112 @@ impl SeelenWeg {
         Ok(())
     }
 
+    /// Hitbox in physical pixels, scaled with the DPI of the monitor where the dock lives
+    /// instead of the DPI of the webview, that could still be the one of another monitor.
+    fn hitbox_rect(
+        layout: &HitboxLayout,
+        constraints: &WegLayoutConstraints,
+        monitor: HMONITOR,
+        rc_work: &RECT,
+    ) -> Result<RECT> {
+        let scale = WindowsApi::get_device_pixel_ratio(monitor)? as f64;
+        let state = FULL_STATE.load();
+        let settings = &state.settings().seelenweg;
+        let scaled = |value: f64| (value * scale).round() as i32;
+
+        let (margin, thickness) = if layout.auto_hide {
+            (0, 1)
+        } else {
+            let thickness = constraints.thickness(settings);
+            (scaled(settings.margin as f64), scaled(thickness))
+        };
+        let start = scaled(layout.offset);
+        let length = scaled(layout.length).max(1);
+
+        Ok(match settings.position {
+            SeelenWegSide::Bottom => RECT {
+                left: rc_work.left + start,
+                top: rc_work.bottom - margin - thickness,
+                right: rc_work.left + start + length,
+                bottom: rc_work.bottom - margin,
+            },
+            SeelenWegSide::Top => RECT {
+                left: rc_work.left + start,
+                top: rc_work.top + margin,
+                right: rc_work.left + start + length,
+                bottom: rc_work.top + margin + thickness,
+            },
+            SeelenWegSide::Left => RECT {
+                left: rc_work.left + margin,
+                top: rc_work.top + start,
+                right: rc_work.left + margin + thickness,
+                bottom: rc_work.top + start + length,
+            },
+            SeelenWegSide::Right => RECT {
+                left: rc_work.right - margin - thickness,
+                top: rc_work.top + start,
+                right: rc_work.right - margin,
+                bottom: rc_work.top + start + length,
+            },
+        })
+    }
+
     pub fn set_positions(&self, monitor_id: isize) -> Result<()> {
         let rc_work = FancyToolbar::get_work_area_by_monitor(monitor_id)?;
         let main_hwnd = HWND(self.window.hwnd()?.0);
         // pre set position before resize in case of multiples dpi
         WindowsApi::move_window(main_hwnd, &rc_work)?;
         WindowsApi::set_position(main_hwnd, None, &rc_work, SWP_NOACTIVATE)?;
+
+        if let Some(layout) = &self.hitbox_layout {
+            let rect = Self::hitbox_rect(
+                layout,
+                &self.layout_constraints,
+                HMONITOR(monitor_id),
+                &rc_work,
+            )?;
+            let hitbox_hwnd = HWND(self.hitbox.hwnd()?.0);
+            WindowsApi::move_window(hitbox_hwnd, &rect)?;
+            WindowsApi::set_position(hitbox_hwnd, None, &rect, SWP_NOACTIVATE)?;
+        }
         Ok(())
     }
+
+    pub fn set_hitbox_layout(&mut self, layout: HitboxLayout) -> Result<()> {
+        let monitor = WindowsApi::monitor_from_window(self.window.hwnd()?);
+        self.hitbox_layout = Some(layout);
+        self.update_layout_constraints(monitor)?;
+        self.set_positions(monitor.0)
+    }
+
+    /// Recalculates the rows and the item scale needed to show all the items on the monitor.
+    /// The result is always sent back, so a reloaded webview gets it on its first hitbox report.
+    fn update_layout_constraints(&mut self, monitor: HMONITOR) -> Result<()> {
+        let Some(layout) = &self.hitbox_layout else {
+            return Ok(());
+        };
+        let rc_work = FancyToolbar::get_work_area_by_monitor(monitor.0)?;
+        let scale = WindowsApi::get_device_pixel_ratio(monitor)? as f64;
+        let constraints = WegLayoutConstraints::calculate(
+            layout.item_count,
+            &rc_work,
+            scale,
+            &FULL_STATE.load().settings().seelenweg,
+            &self.layout_constraints,
+        );
+        self.layout_constraints = constraints;
+        self.emit("weg-layout-constraints", constraints)
+    }
 }
 
 impl SeelenWeg {
     const TARGET: &'static str = "seelenweg";
     const TARGET_HITBOX: &'static str = "seelenweg-hitbox";
 
-    fn create_window(postfix: &str) -> Result<(WebviewWindow, WebviewWindow)> {
+    fn create_window(
+        postfix: &str,
+        theme_vars: &MonitorThemeVars,
+    ) -> Result<(WebviewWindow, WebviewWindow)> {
         let manager = get_app_handle();
 
         let hitbox = tauri::WebviewWindowBuilder::new(
@@ -356,6 +632,7 @@ impl SeelenWeg {
             tauri::WebviewUrl::App("seelenweg/index.html".into()),
         )
         .title("SeelenWeg")
+        .initialization_script(&theme_vars.script())
         .maximizable(false)
         .minimizable(false)
         .resizable(false)
@@ -370,6 +647,26 @@ impl SeelenWeg {
         .build()?;
 
         window.set_ignore_cursor_events(true)?;
+        RenderingProbe::watch(&window, "complete-setup");
+
+        // WM_DPICHANGED resizes the windows to the suggested rect, so we re-apply our own
+        for webview in [&window, &hitbox] {
+            let postfix = postfix.to_string();
+            webview.on_window_event(move |event| {
+                if let WindowEvent::ScaleFactorChanged { .. } = event {
+                    let postfix = postfix.clone();
+                    std::thread::spawn(move || {
+                        if let Some(monitor) = trace_lock!(SEELEN).monitor_by_name_mut(&postfix) {
+                            let handle = monitor.handle().0;
+                            if let Some(weg) = monitor.weg() {
+                                log_error!(weg.set_positions(handle));
+                            }
+                            log_error!(monitor.update_theme_vars());
+                        }
+                    });
+                }
+            });
+        }
 
         let postfix = postfix.to_string();
         window.once("complete-setup", move |_event| {
@@ -392,19 +689,29 @@ impl SeelenWeg {
     }
 
     pub fn hide_taskbar() -> JoinHandle<()> {
-        std::thread::spawn(move || match get_taskbars_handles() {
-            Ok(handles) => {
-                let mut attempts = 0;
-                while attempts < 10 && FULL_STATE.load().is_weg_enabled() {
-                    for handle in &handles {
-                        AppBarData::from_handle(*handle).set_state(AppBarDataState::AutoHide);
-                        let _ = WindowsApi::show_window(*handle, SW_HIDE);
+        std::thread::spawn(move || {
+            // taskbar replacements fight back when their taskbar is hidden
+            if FULL_STATE
+                .load()
+                .is_conflict_mitigated(Mitigation::KeepTaskbar)
+            {
+                log::info!("Taskbar is kept visible due to a conflicting software");
+                return;
+            }
+            match get_taskbars_handles() {
+                Ok(handles) => {
+                    let mut attempts = 0;
+                    while attempts < 10 && FULL_STATE.load().is_weg_enabled() {
+                        for handle in &handles {
+                            AppBarData::from_handle(*handle).set_state(AppBarDataState::AutoHide);
+                            let _ = WindowsApi::show_window(*handle, SW_HIDE);
+                        }
+                        attempts += 1;
+                        sleep_millis(50);
                     }
-                    attempts += 1;
-                    sleep_millis(50);
                 }
+                Err(err) => log::error!("Failed to get taskbars handles: {:?}", err),
             }
-            Err(err) => log::error!("Failed to get taskbars handles: {:?}", err),
         })
     }
 
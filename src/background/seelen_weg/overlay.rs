@@ -0,0 +1,195 @@
+use std::{
+    collections::HashMap,
+    io::Cursor,
+    sync::{
+        atomic::{AtomicIsize, Ordering},
+        Arc,
+    },
+};
+
+use base64::Engine;
+use image::ImageFormat;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::Emitter;
+use windows::{
+    core::{s, w, PCWSTR},
+    Win32::{
+        Foundation::{BOOL, HWND, LPARAM, LRESULT, WPARAM},
+        System::LibraryLoader::{GetModuleHandleW, GetProcAddress},
+        UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetMessageW,
+            PostMessageW, PostQuitMessage, RegisterClassW, TranslateMessage, HICON, MSG,
+            WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE, WM_DESTROY, WM_USER, WNDCLASSW,
+        },
+    },
+};
+
+use crate::{
+    error_handler::Result, log_error, seelen::get_app_handle, trace_lock,
+    utils::spawn_named_thread, windows_api::WindowsApi,
+};
+
+use super::icon_extractor::convert_hicon_to_rgba_image;
+
+lazy_static! {
+    /// png data urls by window handle
+    static ref OVERLAYS: Arc<Mutex<HashMap<isize, String>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+/// hidden window registered as the taskman window, 0 if not running
+static TASKMAN_HWND: AtomicIsize = AtomicIsize::new(0);
+
+/// `ITaskbarList3::SetOverlayIcon` is forwarded to the taskman window as this message,
+/// with the target window on wParam and the icon (or null to remove it) on lParam.
+/// Undocumented, the same value is handled by other shell replacements.
+const WM_SET_OVERLAY_ICON: u32 = WM_USER + 75;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OverlayIconChange {
+    hwnd: isize,
+    /// png as data url, none if the overlay was removed
+    icon: Option<String>,
+}
+
+/// Receives the overlay icons set by Win32 apps on their taskbar buttons (unread counts
+/// on Discord, Teams, Slack...) and forwards them to the dock as `weg-overlay-icon`.
+///
+/// Windows sends these to the taskman window of the session, that belongs to the shell,
+/// so this only works while Seelen UI is running as the shell.
+pub struct TaskbarOverlays;
+
+impl TaskbarOverlays {
+    pub fn overlays() -> HashMap<isize, String> {
+        trace_lock!(OVERLAYS).clone()
+    }
+
+    fn encode(icon: HICON) -> Result<String> {
+        let image = convert_hicon_to_rgba_image(&icon)?;
+        let mut png = Cursor::new(Vec::new());
+        image.write_to(&mut png, ImageFormat::Png)?;
+        let engine = base64::engine::general_purpose::STANDARD;
+        Ok(format!(
+            "data:image/png;base64,{}",
+            engine.encode(png.into_inner())
+        ))
+    }
+
+    fn on_overlay_changed(hwnd: isize, icon: HICON) -> Result<()> {
+        let icon = if icon.is_invalid() {
+            None
+        } else {
+            Some(Self::encode(icon)?)
+        };
+
+        let mut overlays = trace_lock!(OVERLAYS);
+        overlays.retain(|hwnd, _| WindowsApi::is_window(HWND(*hwnd)));
+        match &icon {
+            Some(icon) => overlays.insert(hwnd, icon.clone()),
+            None => overlays.remove(&hwnd),
+        };
+        drop(overlays);
+
+        get_app_handle().emit("weg-overlay-icon", OverlayIconChange { hwnd, icon })?;
+        Ok(())
+    }
+
+    /// `SetTaskmanWindow` is exported by user32 but not declared on the SDK headers
+    fn set_taskman_window(hwnd: HWND) -> Result<()> {
+        type SetTaskmanWindow = unsafe extern "system" fn(HWND) -> BOOL;
+        unsafe {
+            let user32 = GetModuleHandleW(w!("user32.dll"))?;
+            let Some(address) = GetProcAddress(user32, s!("SetTaskmanWindow")) else {
+                return Err("SetTaskmanWindow not found".into());
+            };
+            let set_taskman_window: SetTaskmanWindow = std::mem::transmute(address);
+            set_taskman_window(hwnd).ok()?;
+        }
+        Ok(())
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        w_param: WPARAM,
+        l_param: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            WM_SET_OVERLAY_ICON => {
+                log_error!(Self::on_overlay_changed(
+                    w_param.0 as isize,
+                    HICON(l_param.0)
+                ));
+                LRESULT(0)
+            }
+            WM_CLOSE => {
+                log_error!(DestroyWindow(hwnd));
+                LRESULT(0)
+            }
+            WM_DESTROY => {
+                PostQuitMessage(0);
+                LRESULT(0)
+            }
+            _ => DefWindowProcW(hwnd, msg, w_param, l_param),
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if TASKMAN_HWND.load(Ordering::Acquire) != 0 {
+            return Ok(());
+        }
+        log::trace!("Starting taskbar overlays listener");
+
+        let class_name = w!("SeelenTaskmanWindow");
+        let h_module = WindowsApi::module_handle_w()?;
+        let wnd_class = WNDCLASSW {
+            lpfnWndProc: Some(Self::window_proc),
+            hInstance: h_module.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        unsafe { RegisterClassW(&wnd_class) };
+
+        spawn_named_thread("Taskbar Overlays", move || unsafe {
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                class_name,
+                PCWSTR::null(),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                h_module,
+                None,
+            );
+            if let Err(err) = Self::set_taskman_window(hwnd) {
+                log::warn!("Could not register as taskman window: {:?}", err);
+                log_error!(DestroyWindow(hwnd));
+                return;
+            }
+            TASKMAN_HWND.store(hwnd.0, Ordering::Release);
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).into() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+
+            TASKMAN_HWND.store(0, Ordering::Release);
+            trace_lock!(OVERLAYS).clear();
+            log::trace!("Taskbar overlays listener stopped");
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        let hwnd = TASKMAN_HWND.load(Ordering::Acquire);
+        if hwnd != 0 {
+            log_error!(unsafe { PostMessageW(HWND(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0)) });
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tauri::{Emitter, WebviewWindow};
+use windows::Win32::Foundation::{HWND, POINT, RECT};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::Mouse,
+    seelen::get_app_handle,
+    state::application::FULL_STATE,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+use super::SeelenWeg;
+
+/// increased each time the cursor enters or leaves a hitbox, invalidating pending reveals
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Reveals the hidden dock when the cursor rests on its hitbox. The timing is handled here
+/// instead of on the webview so the configured delays are respected even when it is busy.
+pub struct RevealIntent;
+
+impl RevealIntent {
+    fn contains(rect: &RECT, point: &POINT) -> bool {
+        point.x >= rect.left && point.x < rect.right && point.y >= rect.top && point.y < rect.bottom
+    }
+
+    fn cursor() -> Option<POINT> {
+        Mouse::get_cursor_pos().ok().map(Into::into)
+    }
+
+    /// Waits until the cursor slows down over the hitbox, moving less than the threshold
+    /// between checks, so crossing the screen edge on the way to another monitor is ignored.
+    fn wait_intent(generation: u64, hitbox: HWND, delay: u64, threshold: u32) -> bool {
+        let Some(mut last) = Self::cursor() else {
+            return false;
+        };
+        loop {
+            sleep_millis(delay);
+            if GENERATION.load(Ordering::Acquire) != generation {
+                return false;
+            }
+            let Some(cursor) = Self::cursor() else {
+                return false;
+            };
+            if !Self::contains(&WindowsApi::get_window_rect(hitbox), &cursor) {
+                return false;
+            }
+            let (dx, dy) = ((cursor.x - last.x) as i64, (cursor.y - last.y) as i64);
+            if dx * dx + dy * dy <= (threshold as i64).pow(2) {
+                return true;
+            }
+            last = cursor;
+        }
+    }
+
+    pub fn on_hitbox_enter(hitbox: &WebviewWindow) -> Result<()> {
+        let generation = GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+        let hitbox_hwnd = HWND(hitbox.hwnd()?.0);
+        let target = hitbox
+            .label()
+            .replacen(SeelenWeg::TARGET_HITBOX, SeelenWeg::TARGET, 1);
+
+        let (delay, threshold) = {
+            let state = FULL_STATE.load();
+            let settings = &state.settings().seelenweg;
+            (settings.reveal_delay, settings.hover_intent_threshold)
+        };
+
+        if delay == 0 {
+            get_app_handle().emit_to(target, "mouseenter", ())?;
+            return Ok(());
+        }
+
+        spawn_named_thread("Weg Reveal", move || {
+            if Self::wait_intent(generation, hitbox_hwnd, delay, threshold) {
+                log_error!(get_app_handle().emit_to(target, "mouseenter", ()));
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn on_hitbox_leave() {
+        GENERATION.fetch_add(1, Ordering::AcqRel);
+    }
+}
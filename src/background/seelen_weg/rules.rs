@@ -0,0 +1,274 @@
+use std::fmt::Display;
+
+use seelen_core::state::{AppExtraFlag, Settings};
+use serde::Serialize;
+use windows::Win32::{
+    Foundation::HWND,
+    UI::WindowsAndMessaging::{WS_EX_APPWINDOW, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW},
+};
+
+use crate::{
+    modules::popup_filter::PopupFilter, state::application::FULL_STATE, windows_api::window::Window,
+};
+
+use super::TITLE_BLACK_LIST;
+
+/// Conditions that prevent a window from being shown on the dock, in evaluation order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WegRule {
+    NotVisible,
+    HasParent,
+    ToolWindow,
+    FrameWithoutCreator,
+    SuspendedUwp,
+    SystemApp,
+    HiddenByConfig,
+    BlacklistedTitle,
+    IgnoredClass,
+    ZeroSize,
+    TransientPopup,
+}
+
+/// Settings and app configs the rules depend on, so they can be evaluated without the state
+pub struct RuleContext<'a> {
+    pub settings: &'a Settings,
+    pub hidden_by_config: &'a dyn Fn(&Window) -> bool,
+}
+
+type RuleCheck = fn(&Window, &RuleContext) -> bool;
+
+/// each check returns true if the window is rejected by the rule
+const RULES: [(WegRule, RuleCheck); 11] = [
+    (WegRule::NotVisible, |window, _| !window.is_visible()),
+    (WegRule::HasParent, |window, _| window.parent().is_some()),
+    (WegRule::ToolWindow, |window, _| {
+        let ex_style = window.ex_styles();
+        (ex_style.contains(WS_EX_TOOLWINDOW) || ex_style.contains(WS_EX_NOACTIVATE))
+            && !ex_style.contains(WS_EX_APPWINDOW)
+    }),
+    (WegRule::FrameWithoutCreator, |window, _| {
+        matches!(window.get_frame_creator(), Ok(None))
+    }),
+    (WegRule::SuspendedUwp, |window, _| window.is_uwp_suspended()),
+    (WegRule::SystemApp, |window, _| {
+        window
+            .exe()
+            .is_ok_and(|path| path.starts_with("C:\\Windows\\SystemApps"))
+    }),
+    (WegRule::HiddenByConfig, |window, ctx| {
+        (ctx.hidden_by_config)(window)
+    }),
+    (WegRule::BlacklistedTitle, |window, _| {
+        TITLE_BLACK_LIST.contains(&window.title().as_str())
+    }),
+    (WegRule::IgnoredClass, |window, ctx| {
+        PopupFilter::has_ignored_class(window, &ctx.settings.popup_filter)
+    }),
+    (WegRule::ZeroSize, |window, ctx| {
+        PopupFilter::is_zero_size(window, &ctx.settings.popup_filter)
+    }),
+    (WegRule::TransientPopup, |window, ctx| {
+        PopupFilter::is_transient_popup(window, &ctx.settings.popup_filter)
+    }),
+];
+
+impl WegRule {
+    /// hidden, child and suspended windows can't be interacted with even if shown
+    pub fn can_be_forced(&self) -> bool {
+        !matches!(
+            self,
+            WegRule::NotVisible | WegRule::HasParent | WegRule::SuspendedUwp
+        )
+    }
+}
+
+impl Display for WegRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            WegRule::NotVisible => "not visible",
+            WegRule::HasParent => "has parent",
+            WegRule::ToolWindow => "tool or no activate window without WS_EX_APPWINDOW",
+            WegRule::FrameWithoutCreator => "application frame without creator",
+            WegRule::SuspendedUwp => "suspended uwp app",
+            WegRule::SystemApp => "system app",
+            WegRule::HiddenByConfig => "hidden by app config",
+            WegRule::BlacklistedTitle => "blacklisted title",
+            WegRule::IgnoredClass => "class ignored by the popup filter",
+            WegRule::ZeroSize => "zero size window",
+            WegRule::TransientPopup => "transient popup",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WegVerdict {
+    pub hwnd: isize,
+    pub exe: Option<String>,
+    pub accepted: bool,
+    /// first rule that rejected the window
+    pub rejected_by: Option<WegRule>,
+    /// true if the exe is on the `force_show` list of the settings
+    pub forced: bool,
+}
+
+impl WegVerdict {
+    pub fn evaluate(hwnd: HWND) -> Self {
+        let state = FULL_STATE.load();
+        let hidden_by_config = |window: &Window| {
+            state
+                .get_app_config_by_window(window.hwnd())
+                .is_some_and(|config| config.options.contains(&AppExtraFlag::Hidden))
+        };
+        let ctx = RuleContext {
+            settings: state.settings(),
+            hidden_by_config: &hidden_by_config,
+        };
+        Self::evaluate_with(&Window::from(hwnd), &ctx)
+    }
+
+    pub fn evaluate_with(window: &Window, ctx: &RuleContext) -> Self {
+        let exe = window
+            .exe()
+            .ok()
+            .and_then(|path| Some(path.file_name()?.to_string_lossy().to_string()));
+        let forced = exe.as_ref().is_some_and(|exe| {
+            ctx.settings
+                .seelenweg
+                .force_show
+                .iter()
+                .any(|forced| forced.eq_ignore_ascii_case(exe))
+        });
+
+        let show_suspended = ctx.settings.seelenweg.show_suspended_uwp;
+
+        let rejected_by = RULES
+            .iter()
+            .filter(|(rule, _)| !forced || !rule.can_be_forced())
+            // shown greyed out and resumed on click by the suspension watcher
+            .filter(|(rule, _)| !show_suspended || *rule != WegRule::SuspendedUwp)
+            .find(|(_, rejects)| rejects(window, ctx))
+            .map(|(rule, _)| *rule);
+
+        Self {
+            hwnd: window.hwnd().0,
+            exe,
+            accepted: rejected_by.is_none(),
+            rejected_by,
+            forced,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{MockWindow, Simulation},
+        winevent::WinEvent,
+    };
+
+    const FRAME_HOST: &str = "C:\\Windows\\System32\\ApplicationFrameHost.exe";
+
+    #[test]
+    fn visible_app_is_added() {
+        let mut sim = Simulation::new();
+        let hwnd = sim.open(MockWindow::app("C:\\Apps\\notepad.exe", "Notepad"));
+        assert!(sim.dock().contains(&hwnd.0));
+        assert_eq!(sim.verdict(hwnd).exe.as_deref(), Some("notepad.exe"));
+    }
+
+    #[test]
+    fn rejected_windows() {
+        let mut sim = Simulation::new();
+        let owner = sim.open(MockWindow::app("C:\\Apps\\app.exe", "App"));
+
+        let app = |title: &str| MockWindow::app("C:\\Apps\\app.exe", title);
+        let tool = sim.open(app("Tool").ex_styles(WS_EX_TOOLWINDOW));
+        let hidden = sim.desktop.add(app("Hidden").hidden());
+        let child = sim.open(app("Child").child_of(owner));
+        let untitled = sim.open(app(""));
+
+        assert_eq!(sim.verdict(tool).rejected_by, Some(WegRule::ToolWindow));
+        assert_eq!(sim.verdict(hidden).rejected_by, Some(WegRule::NotVisible));
+        assert_eq!(sim.verdict(child).rejected_by, Some(WegRule::HasParent));
+        assert_eq!(
+            sim.verdict(untitled).rejected_by,
+            Some(WegRule::BlacklistedTitle)
+        );
+        assert_eq!(sim.dock(), vec![owner.0]);
+    }
+
+    #[test]
+    fn application_frame_needs_creator() {
+        let mut sim = Simulation::new();
+        let frame = sim.desktop.add(MockWindow::app(FRAME_HOST, "Settings"));
+        assert_eq!(
+            sim.verdict(frame).rejected_by,
+            Some(WegRule::FrameWithoutCreator)
+        );
+
+        sim.desktop.add(
+            MockWindow::app(FRAME_HOST, "")
+                .class("ApplicationFrameTitleBarWindow")
+                .child_of(frame),
+        );
+        assert_eq!(
+            sim.verdict(frame).rejected_by,
+            Some(WegRule::FrameWithoutCreator)
+        );
+
+        sim.desktop.add(
+            MockWindow::app("C:\\Program Files\\WindowsApps\\Settings.exe", "Settings")
+                .class("Windows.UI.Core.CoreWindow")
+                .child_of(frame),
+        );
+        sim.emit(WinEvent::ObjectShow, frame);
+        assert!(sim.dock().contains(&frame.0));
+    }
+
+    #[test]
+    fn suspended_uwp_apps() {
+        let mut sim = Simulation::new();
+        let hwnd = sim
+            .desktop
+            .add(MockWindow::app("C:\\Apps\\store.exe", "Store").suspended());
+        assert_eq!(sim.verdict(hwnd).rejected_by, Some(WegRule::SuspendedUwp));
+
+        sim.host.settings.borrow_mut().seelenweg.show_suspended_uwp = true;
+        assert!(sim.verdict(hwnd).accepted);
+    }
+
+    #[test]
+    fn hidden_by_config_and_forced() {
+        let mut sim = Simulation::new();
+        sim.host
+            .set_app_flags("hidden.exe", vec![AppExtraFlag::Hidden]);
+        let hwnd = sim.open(MockWindow::app("C:\\Apps\\hidden.exe", "Hidden"));
+        assert_eq!(sim.verdict(hwnd).rejected_by, Some(WegRule::HiddenByConfig));
+        assert!(sim.dock().is_empty());
+
+        sim.host
+            .settings
+            .borrow_mut()
+            .seelenweg
+            .force_show
+            .push("HIDDEN.exe".to_string());
+        let verdict = sim.verdict(hwnd);
+        assert!(verdict.accepted && verdict.forced);
+
+        // forcing can't show windows that can't be interacted with
+        sim.desktop.update(hwnd, |window| window.visible = false);
+        assert_eq!(sim.verdict(hwnd).rejected_by, Some(WegRule::NotVisible));
+    }
+
+    #[test]
+    fn closed_windows_are_removed() {
+        let mut sim = Simulation::new();
+        let first = sim.open(MockWindow::app("C:\\Apps\\a.exe", "A"));
+        let second = sim.open(MockWindow::app("C:\\Apps\\b.exe", "B"));
+        sim.close(first);
+        assert_eq!(sim.dock(), vec![second.0]);
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::{
+    Foundation::{HWND, POINT},
+    UI::{
+        Input::KeyboardAndMouse::{VK_ESCAPE, VK_LBUTTON, VK_RBUTTON},
+        WindowsAndMessaging::{GetAncestor, GetCursorPos, WindowFromPoint, GA_ROOT},
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::input::Keyboard,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::WindowsApi,
+};
+
+lazy_static! {
+    static ref SPRING: Arc<Mutex<SpringState>> = Arc::new(Mutex::new(SpringState::default()));
+}
+
+#[derive(Debug, Default)]
+struct SpringState {
+    /// window of the item under the dragged file and since when
+    hovered: Option<(isize, Instant)>,
+    /// window brought to the foreground during the current drag
+    activated: Option<isize>,
+    /// foreground window before the first activation of the drag
+    previous: Option<isize>,
+    cancelled: bool,
+    watching: bool,
+}
+
+/// Spring-loaded activation: while a file is dragged over a dock item, its window is brought
+/// to the foreground after a short dwell so the file can be dropped on it. If the drag ends
+/// elsewhere or is cancelled with Esc, the previously focused window is focused again.
+pub struct SpringLoader;
+
+impl SpringLoader {
+    const DWELL: Duration = Duration::from_millis(700);
+    const POLL_INTERVAL: u64 = 50;
+
+    /// Called while dragging over the dock, `hwnd` is the window of the item under the cursor
+    pub fn on_drag_over(hwnd: Option<isize>) -> Result<()> {
+        let mut state = trace_lock!(SPRING);
+        match hwnd {
+            Some(hwnd) if state.hovered.map(|(current, _)| current) != Some(hwnd) => {
+                state.hovered = Some((hwnd, Instant::now()));
+            }
+            Some(_) => {}
+            None => state.hovered = None,
+        }
+
+        if !state.watching {
+            state.watching = true;
+            spawn_named_thread("Weg Spring Loader", || {
+                while Self::tick() {
+                    sleep_millis(Self::POLL_INTERVAL);
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    /// The drag left the dock, it can continue on the activated window
+    pub fn on_drag_leave() {
+        trace_lock!(SPRING).hovered = None;
+    }
+
+    fn is_dragging() -> bool {
+        Keyboard::is_pressed(VK_LBUTTON) || Keyboard::is_pressed(VK_RBUTTON)
+    }
+
+    /// Returns false once the drag ends
+    fn tick() -> bool {
+        let mut state = trace_lock!(SPRING);
+        state.cancelled |= Keyboard::is_pressed(VK_ESCAPE);
+        if state.cancelled || !Self::is_dragging() {
+            Self::finish(&mut state);
+            return false;
+        }
+
+        let Some((hwnd, since)) = state.hovered else {
+            return true;
+        };
+        if since.elapsed() >= Self::DWELL && state.activated != Some(hwnd) {
+            if state.previous.is_none() {
+                state.previous = Some(WindowsApi::get_foreground_window().0);
+            }
+            log_error!(Self::activate(HWND(hwnd)));
+            state.activated = Some(hwnd);
+        }
+        true
+    }
+
+    fn activate(hwnd: HWND) -> Result<()> {
+        if WindowsApi::is_iconic(hwnd) {
+            WindowsApi::restore_window(hwnd)?;
+        }
+        WindowsApi::force_set_foreground(hwnd)
+    }
+
+    fn window_under_cursor() -> Result<isize> {
+        let mut point = POINT::default();
+        unsafe {
+            GetCursorPos(&mut point)?;
+            Ok(GetAncestor(WindowFromPoint(point), GA_ROOT).0)
+        }
+    }
+
+    /// The file was dropped if the drag ended over the activated window, otherwise the
+    /// focus is given back to the window that had it before the drag.
+    fn finish(state: &mut SpringState) {
+        let activated = state.activated.take();
+        let previous = state.previous.take();
+        let cancelled = std::mem::take(&mut state.cancelled);
+        state.hovered = None;
+        state.watching = false;
+
+        let (Some(activated), Some(previous)) = (activated, previous) else {
+            return;
+        };
+        let dropped = !cancelled && Self::window_under_cursor().ok() == Some(activated);
+        if !dropped && previous != activated && WindowsApi::is_window(HWND(previous)) {
+            log_error!(WindowsApi::force_set_foreground(HWND(previous)));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use tauri::Emitter;
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::HWND,
+        UI::Shell::{IPackageDebugSettings, PackageDebugSettings},
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::uwp::UWP_MANAGER,
+    seelen::get_app_handle,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::{Com, WindowsApi},
+};
+
+use super::SeelenWeg;
+
+lazy_static! {
+    static ref SUSPENDED: Arc<Mutex<HashSet<isize>>> = Arc::new(Mutex::new(HashSet::new()));
+}
+
+/// true while the watcher thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Tracks the uwp windows on the dock that were suspended by the system, emitting
+/// `weg-suspended-windows` when the set changes, so the dock can show them greyed out.
+/// Only used when `show_suspended_uwp` is enabled, otherwise those windows are skipped.
+pub struct SuspensionWatcher;
+
+impl SuspensionWatcher {
+    const INTERVAL: u64 = 2000;
+
+    pub fn suspended_windows() -> Vec<isize> {
+        trace_lock!(SUSPENDED).iter().copied().collect()
+    }
+
+    pub fn is_suspended(hwnd: HWND) -> bool {
+        trace_lock!(SUSPENDED).contains(&hwnd.0)
+    }
+
+    fn emit(suspended: &HashSet<isize>) -> Result<()> {
+        get_app_handle().emit(
+            "weg-suspended-windows",
+            suspended.iter().collect::<Vec<_>>(),
+        )?;
+        Ok(())
+    }
+
+    fn tick() -> Result<()> {
+        let suspended = SeelenWeg::open_handles()
+            .into_iter()
+            .filter(|hwnd| WindowsApi::window_is_uwp_suspended(HWND(*hwnd)).unwrap_or_default())
+            .collect::<HashSet<isize>>();
+
+        let mut last = trace_lock!(SUSPENDED);
+        if *last != suspended {
+            *last = suspended;
+            Self::emit(&last)?;
+        }
+        Ok(())
+    }
+
+    /// Resumes the package of the window, the system suspends it again if it stays unused
+    pub fn resume(hwnd: HWND) -> Result<()> {
+        let exe = WindowsApi::exe_path_v2(hwnd)?;
+        let full_name = trace_lock!(UWP_MANAGER)
+            .get_from_path(&exe)
+            .map(|package| package.full_name().to_string())
+            .ok_or(format!("No package found for {}", exe.display()))?;
+
+        Com::run_with_context(|| {
+            let settings: IPackageDebugSettings = Com::create_instance(&PackageDebugSettings)?;
+            unsafe { settings.Resume(&HSTRING::from(&full_name))? };
+            Ok(())
+        })?;
+
+        let mut suspended = trace_lock!(SUSPENDED);
+        if suspended.remove(&hwnd.0) {
+            Self::emit(&suspended)?;
+        }
+        Ok(())
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        spawn_named_thread("Suspension Watcher", || {
+            while RUNNING.load(Ordering::Acquire) {
+                log_error!(Self::tick());
+                sleep_millis(Self::INTERVAL);
+            }
+            trace_lock!(SUSPENDED).clear();
+        })?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
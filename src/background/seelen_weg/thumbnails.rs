@@ -0,0 +1,105 @@
+use std::{collections::HashMap, sync::Arc};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use seelen_core::rect::Rect;
+use serde::Deserialize;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result, log_error, modules::overview::domain::fit_in_cell, trace_lock,
+    windows_api::DwmThumbnail,
+};
+
+lazy_static! {
+    /// strip of the grouped item being scrubbed
+    static ref STRIP: Arc<Mutex<Option<ThumbnailStrip>>> = Arc::new(Mutex::new(None));
+    /// thumbnails of the tabs by the window owning them
+    static ref TAB_STRIPS: Arc<Mutex<HashMap<isize, ThumbnailStrip>>> =
+        Arc::new(Mutex::new(HashMap::new()));
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThumbnailSlot {
+    hwnd: isize,
+    /// physical pixels relative to the client area of the dock window
+    rect: Rect,
+}
+
+/// Live previews for the tabs of a window and for grouped items with many windows. Only the
+/// slots currently visible have a DWM thumbnail registered, thumbnails are registered and
+/// released as the user scrubs so the cost doesn't grow with the amount of windows of the app.
+pub struct ThumbnailStrip {
+    host: HWND,
+    thumbnails: HashMap<isize, DwmThumbnail>,
+}
+
+impl ThumbnailStrip {
+    /// max thumbnails alive at the same time, slots beyond it are left empty
+    const MAX_THUMBNAILS: usize = 8;
+
+    fn new(host: HWND) -> Self {
+        Self {
+            host,
+            thumbnails: HashMap::new(),
+        }
+    }
+
+    /// Shows `slots` over the host, releasing the thumbnails of the slots no longer visible.
+    fn sync(&mut self, slots: &[ThumbnailSlot]) -> Result<()> {
+        let slots = &slots[..slots.len().min(Self::MAX_THUMBNAILS)];
+        self.thumbnails
+            .retain(|hwnd, _| slots.iter().any(|slot| slot.hwnd == *hwnd));
+
+        for slot in slots {
+            if !self.thumbnails.contains_key(&slot.hwnd) {
+                match DwmThumbnail::new(self.host, HWND(slot.hwnd)) {
+                    Ok(thumbnail) => {
+                        self.thumbnails.insert(slot.hwnd, thumbnail);
+                    }
+                    Err(err) => {
+                        log::warn!("Failed to register thumbnail for {}: {:?}", slot.hwnd, err);
+                        continue;
+                    }
+                }
+            }
+            let thumbnail = &self.thumbnails[&slot.hwnd];
+            let rect = fit_in_cell(thumbnail.source_size()?, &slot.rect.clone().into());
+            log_error!(thumbnail.show(rect, 255));
+        }
+        Ok(())
+    }
+
+    /// Shows `slots` over `host`, releasing the thumbnails of the slots no longer visible.
+    pub fn update(host: HWND, slots: Vec<ThumbnailSlot>) -> Result<()> {
+        let mut strip = trace_lock!(STRIP);
+        if strip.as_ref().is_some_and(|strip| strip.host != host) {
+            strip.take();
+        }
+        strip.get_or_insert_with(|| Self::new(host)).sync(&slots)
+    }
+
+    /// Releases every thumbnail, should be called when the strip is closed
+    pub fn clear() {
+        trace_lock!(STRIP).take();
+    }
+
+    /// Shows the tabs of `owner` over `host`. Tab proxies are never rendered by the app, a
+    /// registered thumbnail makes DWM request the iconic bitmap of the tab to the app with
+    /// `WM_DWMSENDICONICTHUMBNAIL`, as the native taskbar does.
+    pub fn update_tabs(host: HWND, owner: isize, slots: Vec<ThumbnailSlot>) -> Result<()> {
+        let mut strips = trace_lock!(TAB_STRIPS);
+        if strips.get(&owner).is_some_and(|strip| strip.host != host) {
+            strips.remove(&owner);
+        }
+        strips
+            .entry(owner)
+            .or_insert_with(|| Self::new(host))
+            .sync(&slots)
+    }
+
+    /// Releases the thumbnails of the tabs of `owner`
+    pub fn clear_tabs(owner: isize) {
+        trace_lock!(TAB_STRIPS).remove(&owner);
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use windows::Win32::Foundation::HWND;
+
+use crate::{
+    error_handler::Result,
+    log_error,
+    modules::{game_mode::GameMode, secure_desktop::SecureDesktop},
+    seelen::SEELEN,
+    trace_lock,
+    utils::{sleep_millis, spawn_named_thread},
+    windows_api::window::Window,
+    winevent::WinEvent,
+};
+
+/// true while the guardian thread is running
+static RUNNING: AtomicBool = AtomicBool::new(false);
+/// set by the hook when the z-order could have changed
+static PENDING: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    /// reorders caused by our own re-asserts are ignored until this instant
+    static ref SUPPRESS_UNTIL: Arc<Mutex<Option<Instant>>> = Arc::new(Mutex::new(None));
+}
+
+/// Keeps the dock above fullscreen notifications, OSDs and other topmost windows.
+///
+/// Explorer restarts and other apps calling `SetWindowPos(HWND_TOPMOST)` bury the dock
+/// without any notification, so after each reorder or foreground change the topmost
+/// band is re-asserted a few times with increasing delays. If another window keeps
+/// fighting for the top, the guardian backs off instead of entering a topmost war.
+pub struct ZOrderGuardian;
+
+impl ZOrderGuardian {
+    const TICK: u64 = 25;
+    /// delays after the event at which the z-order is re-asserted
+    const SCHEDULE: [Duration; 4] = [
+        Duration::from_millis(0),
+        Duration::from_millis(150),
+        Duration::from_millis(500),
+        Duration::from_millis(1500),
+    ];
+    /// re-asserts allowed on `BURST_WINDOW` before backing off
+    const MAX_BURST: usize = 12;
+    const BURST_WINDOW: Duration = Duration::from_secs(2);
+    const MAX_COOLDOWN: Duration = Duration::from_secs(16);
+    /// z-order events caused by our own `SetWindowPos` arrive shortly after the call
+    const SELF_EVENT_GRACE: Duration = Duration::from_millis(100);
+
+    pub fn on_win_event(event: WinEvent, origin: HWND) {
+        if !RUNNING.load(Ordering::Acquire) {
+            return;
+        }
+        match event {
+            WinEvent::ObjectReorder => {}
+            WinEvent::SystemForeground if !Window::from(origin).is_seelen_window() => {}
+            _ => return,
+        }
+        let suppressed = trace_lock!(SUPPRESS_UNTIL).is_some_and(|until| Instant::now() < until);
+        if !suppressed {
+            PENDING.store(true, Ordering::Release);
+        }
+    }
+
+    /// Schedules the re-asserts as if the z-order had changed
+    pub fn request() {
+        if RUNNING.load(Ordering::Acquire) {
+            PENDING.store(true, Ordering::Release);
+        }
+    }
+
+    fn assert_zorder() -> Result<()> {
+        *trace_lock!(SUPPRESS_UNTIL) = Some(Instant::now() + Self::SELF_EVENT_GRACE);
+        let seelen = trace_lock!(SEELEN);
+        for monitor in seelen.monitors() {
+            if let Some(weg) = monitor.weg() {
+                weg.ensure_zorder()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn run() {
+        let mut started_at: Option<Instant> = None;
+        let mut next_step = 0;
+        let mut asserts: VecDeque<Instant> = VecDeque::new();
+        let mut cooldown = Duration::ZERO;
+        let mut cooldown_until: Option<Instant> = None;
+
+        while RUNNING.load(Ordering::Acquire) {
+            sleep_millis(Self::TICK);
+            let now = Instant::now();
+
+            if PENDING.swap(false, Ordering::AcqRel) {
+                started_at = Some(now);
+                next_step = 0;
+            }
+
+            let Some(start) = started_at else {
+                continue;
+            };
+            if next_step >= Self::SCHEDULE.len() {
+                started_at = None;
+                continue;
+            }
+            if now.duration_since(start) < Self::SCHEDULE[next_step] {
+                continue;
+            }
+            next_step += 1;
+
+            // our windows can't be reordered while the secure desktop is shown
+            if cooldown_until.is_some_and(|until| now < until)
+                || GameMode::is_active()
+                || SecureDesktop::is_active()
+            {
+                continue;
+            }
+
+            asserts.retain(|at| now.duration_since(*at) < Self::BURST_WINDOW);
+            if asserts.len() >= Self::MAX_BURST {
+                cooldown = (cooldown * 2).clamp(Duration::from_secs(1), Self::MAX_COOLDOWN);
+                cooldown_until = Some(now + cooldown);
+                asserts.clear();
+                log::debug!(
+                    "Weg z-order is being contested, backing off for {:?}",
+                    cooldown
+                );
+                continue;
+            }
+            if asserts.is_empty() && cooldown_until.is_some_and(|until| now >= until + cooldown) {
+                // quiet for a while, the contest is over
+                cooldown = Duration::ZERO;
+                cooldown_until = None;
+            }
+
+            asserts.push_back(now);
+            log_error!(Self::assert_zorder());
+        }
+    }
+
+    pub fn start() -> Result<()> {
+        if RUNNING.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+        spawn_named_thread("Weg ZOrder Guardian", Self::run)?;
+        Ok(())
+    }
+
+    pub fn stop() {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
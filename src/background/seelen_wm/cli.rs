@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use clap::{Command, ValueEnum};
 use seelen_core::state::VirtualDesktopStrategy;
 use serde::{Deserialize, Serialize};
@@ -5,12 +7,14 @@ use windows::Win32::Foundation::HWND;
 
 use crate::error_handler::Result;
 use crate::get_subcommands;
+use crate::log_error;
+use crate::modules::undo::{domain::UndoableAction, Undo};
 use crate::modules::virtual_desk::get_vd_manager;
 use crate::seelen::Seelen;
 use crate::state::application::FULL_STATE;
 use crate::windows_api::WindowsApi;
 
-use super::WindowManager;
+use super::{teleport::MonitorTarget, WindowManager};
 
 #[derive(Debug, Clone, Serialize, Deserialize, ValueEnum)]
 pub enum AllowedReservations {
@@ -22,6 +26,27 @@ pub enum AllowedReservations {
     Float,
 }
 
+/// Orientation of the split made on the focused tile for the next opened window.
+/// `Auto` alternates the orientation of the parent container, like a dwindle layout.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum)]
+pub enum SplitOrientation {
+    Horizontal,
+    Vertical,
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum)]
+pub enum AllowedStackModes {
+    Tabbed,
+    Stacked,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum)]
+pub enum CycleDirection {
+    Next,
+    Previous,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ValueEnum)]
 pub enum AllowedFocus {
     Left,
@@ -48,6 +73,8 @@ get_subcommands![
     Reserve(side: AllowedReservations => "The position of the new window."),
     /** Cancels the current reservation */
     CancelReservation,
+    /** Splits the focused tile, the next opened window will be placed on the new half. */
+    Split(orientation: SplitOrientation => "horizontal, vertical or auto."),
     /** Switches to the specified workspace. */
     SwitchWorkspace(index: usize => "The index of the workspace to switch to."),
     /** Moves the window to the specified workspace. */
@@ -62,6 +89,18 @@ get_subcommands![
     ResetWorkspaceSize,
     /** Focuses the window in the specified position. */
     Focus(side: AllowedFocus => "The position of the window to focus."),
+    /** Groups the focused tile as tabs, or changes how the tabs of its stack are shown. */
+    StackMode(mode: AllowedStackModes => "tabbed or stacked."),
+    /** Activates the next or previous tab of the stack containing the focused window. */
+    CycleTab(direction: CycleDirection => "next or previous."),
+    /** Sends the window to another monitor. */
+    SendToMonitor(target: String => "left, right, up, down, next, previous or index."),
+    /** Sends all the windows on the current monitor and workspace to another monitor. */
+    SendWorkspaceToMonitor(target: String => "left, right, up, down, next, previous or index."),
+    /** Saves the layout tree of the current workspace, windows are stored by exe and title. */
+    ExportLayout(path: String => "The json file to write."),
+    /** Applies a saved layout tree to the windows of the current workspace. */
+    ApplyLayout(path: String => "The json file to read."),
 ];
 
 impl WindowManager {
@@ -85,6 +124,12 @@ impl WindowManager {
         Ok(())
     }
 
+    /// The webview resolves the split as a reservation, so it is shown and cancelled the same way
+    pub fn set_split(&self, orientation: SplitOrientation) -> Result<()> {
+        self.emit("set-split", orientation)?;
+        Ok(())
+    }
+
     pub fn process(&mut self, matches: &clap::ArgMatches) -> Result<()> {
         let subcommand = SubCommand::try_from(matches)?;
         match subcommand {
@@ -109,7 +154,12 @@ impl WindowManager {
             }
             SubCommand::SendToWorkspace(index) => {
                 let to_move = WindowsApi::get_foreground_window();
+                let from = get_vd_manager().get_current_idx()?;
                 get_vd_manager().send_to(index, to_move.0)?;
+                Undo::record(UndoableAction::MoveToWorkspace {
+                    hwnd: to_move.0,
+                    from,
+                });
                 if FULL_STATE.load().settings().virtual_desktop_strategy
                     == VirtualDesktopStrategy::Native
                 {
@@ -120,8 +170,13 @@ impl WindowManager {
             }
             SubCommand::MoveToWorkspace(index) => {
                 let to_move = WindowsApi::get_foreground_window();
+                let from = get_vd_manager().get_current_idx()?;
                 get_vd_manager().send_to(index, to_move.0)?;
                 get_vd_manager().switch_to(index)?;
+                Undo::record(UndoableAction::MoveToWorkspace {
+                    hwnd: to_move.0,
+                    from,
+                });
             }
             SubCommand::Reserve(side) => {
                 self.reserve(side)?;
@@ -129,6 +184,9 @@ impl WindowManager {
             SubCommand::CancelReservation => {
                 self.discard_reservation()?;
             }
+            SubCommand::Split(orientation) => {
+                self.set_split(orientation)?;
+            }
             SubCommand::Debug => {
                 #[cfg(any(debug_assertions, feature = "devtools"))]
                 self.window.open_devtools();
@@ -141,10 +199,36 @@ impl WindowManager {
             }
             SubCommand::ResetWorkspaceSize => {
                 self.emit("reset-workspace-size", ())?;
+                Undo::record(UndoableAction::ResetWorkspaceSize);
             }
             SubCommand::Focus(side) => {
                 self.emit("focus", side)?;
             }
+            SubCommand::StackMode(mode) => {
+                self.emit("set-stack-mode", mode)?;
+            }
+            SubCommand::CycleTab(direction) => {
+                self.emit("cycle-tab", direction)?;
+            }
+            // spawned as the caller is holding the lock of the monitors
+            SubCommand::SendToMonitor(target) => {
+                let target: MonitorTarget = target.parse()?;
+                let hwnd = WindowsApi::get_foreground_window();
+                std::thread::spawn(move || log_error!(Self::send_to_monitor(hwnd, target)));
+            }
+            SubCommand::ExportLayout(path) => {
+                self.export_layout(Path::new(&path))?;
+            }
+            SubCommand::ApplyLayout(path) => {
+                self.apply_layout(Path::new(&path))?;
+            }
+            SubCommand::SendWorkspaceToMonitor(target) => {
+                let target: MonitorTarget = target.parse()?;
+                let hwnd = WindowsApi::get_foreground_window();
+                std::thread::spawn(move || {
+                    log_error!(Self::send_workspace_to_monitor(hwnd, target))
+                });
+            }
         };
         Ok(())
     }
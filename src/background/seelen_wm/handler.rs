@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+
+use serde_json::Value;
 use tauri::{Webview, Wry};
 use windows::Win32::{
     Foundation::{HWND, RECT},
@@ -7,7 +10,18 @@ use windows::Win32::{
     },
 };
 
-use crate::{seelen::SEELEN, trace_lock, windows_api::WindowsApi};
+use crate::{
+    seelen::SEELEN,
+    trace_lock,
+    windows_api::{Toast, WindowsApi},
+};
+
+use super::{
+    cli::{AllowedStackModes, CycleDirection, SplitOrientation},
+    teleport::MonitorTarget,
+    tree::LayoutTree,
+    WindowManager,
+};
 use seelen_core::rect::Rect;
 
 #[tauri::command(async)]
@@ -51,6 +65,19 @@ pub fn bounce_handle(webview: Webview<Wry>, hwnd: isize) {
     }
 }
 
+/// called by the webview when the window can't be shrunk to its tile, it is floated instead
+/// of overlapping its neighbors
+#[tauri::command(async)]
+pub fn wm_float_unfittable(webview: Webview<Wry>, hwnd: isize) -> Result<(), String> {
+    bounce_handle(webview, hwnd);
+    let title = WindowsApi::get_window_text(HWND(hwnd));
+    Toast::show_titled(
+        "Window floated",
+        &format!("{title} can't be resized to fit its tile"),
+    )?;
+    Ok(())
+}
+
 #[tauri::command(async)]
 pub fn request_focus(hwnd: isize) -> Result<(), String> {
     let hwnd = HWND(hwnd);
@@ -68,3 +95,72 @@ pub fn request_focus(hwnd: isize) -> Result<(), String> {
     WindowsApi::force_set_foreground(hwnd)?;
     Ok(())
 }
+
+#[tauri::command(async)]
+pub fn wm_set_split(orientation: SplitOrientation) -> Result<(), String> {
+    if let Some(monitor) = trace_lock!(SEELEN).focused_monitor() {
+        if let Some(wm) = monitor.wm() {
+            wm.set_split(orientation)?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn wm_set_stack_mode(mode: AllowedStackModes) -> Result<(), String> {
+    if let Some(monitor) = trace_lock!(SEELEN).focused_monitor() {
+        if let Some(wm) = monitor.wm() {
+            wm.emit("set-stack-mode", mode)?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn wm_cycle_tab(direction: CycleDirection) -> Result<(), String> {
+    if let Some(monitor) = trace_lock!(SEELEN).focused_monitor() {
+        if let Some(wm) = monitor.wm() {
+            wm.emit("cycle-tab", direction)?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn wm_export_layout(path: PathBuf) -> Result<(), String> {
+    if let Some(monitor) = trace_lock!(SEELEN).focused_monitor() {
+        if let Some(wm) = monitor.wm() {
+            wm.export_layout(&path)?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn wm_apply_layout(path: PathBuf) -> Result<(), String> {
+    if let Some(monitor) = trace_lock!(SEELEN).focused_monitor() {
+        if let Some(wm) = monitor.wm() {
+            wm.apply_layout(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// called by the webview as response of `export-layout`
+#[tauri::command(async)]
+pub fn wm_write_layout_tree(path: PathBuf, structure: Value) -> Result<(), String> {
+    LayoutTree::write(&path, structure)?;
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn wm_send_to_monitor(hwnd: isize, target: MonitorTarget) -> Result<(), String> {
+    WindowManager::send_to_monitor(HWND(hwnd), target)?;
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub fn wm_send_workspace_to_monitor(hwnd: isize, target: MonitorTarget) -> Result<(), String> {
+    WindowManager::send_workspace_to_monitor(HWND(hwnd), target)?;
+    Ok(())
+}
@@ -2,11 +2,12 @@ use windows::Win32::Foundation::HWND;
 
 use crate::{
     error_handler::Result,
+    host::Surface,
     modules::virtual_desk::VirtualDesktopEvent,
     seelen::SEELEN,
     trace_lock,
     utils::{constants::FORCE_RETILING_AFTER_ADD, sleep_millis},
-    windows_api::WindowsApi,
+    windows_api::window::Window,
     winevent::WinEvent,
 };
 
@@ -28,7 +29,9 @@ impl WindowManager {
         }
         Ok(())
     }
+}
 
+impl<S: Surface> WindowManager<S> {
     pub fn process_win_event(&mut self, event: WinEvent, origin: HWND) -> Result<()> {
         match event {
             WinEvent::SystemMoveSizeStart => {
@@ -59,8 +62,8 @@ impl WindowManager {
                 }
             }
             WinEvent::ObjectDestroy => {
-                let title = WindowsApi::get_window_text(origin);
-                if Self::VIRTUAL_PREVIEWS.contains(&title.as_str()) {
+                let title = Window::from(origin).title();
+                if WindowManager::VIRTUAL_PREVIEWS.contains(&title.as_str()) {
                     self.pseudo_resume()?;
                 }
                 if self.is_managed(origin) {
@@ -68,7 +71,7 @@ impl WindowManager {
                 }
             }
             WinEvent::ObjectShow | WinEvent::ObjectCreate => {
-                let title = WindowsApi::get_window_text(origin);
+                let title = Window::from(origin).title();
                 if WindowManager::VIRTUAL_PREVIEWS.contains(&title.as_str()) {
                     self.pseudo_pause()?;
                 }
@@ -88,9 +91,12 @@ impl WindowManager {
                 }
             }
             WinEvent::ObjectNameChange => {
+                if self.is_managed(origin) {
+                    self.update_title(origin)?;
+                }
                 if self.should_be_added(origin) {
                     self.set_active_window(origin)?;
-                    let title = WindowsApi::get_window_text(origin);
+                    let title = Window::from(origin).title();
                     if self.add_hwnd(origin)? && FORCE_RETILING_AFTER_ADD.contains(&title) {
                         // Todo search a better way to do this
                         std::thread::spawn(|| -> Result<()> {
@@ -107,8 +113,10 @@ impl WindowManager {
                 self.set_active_window(origin)?;
             }
             WinEvent::ObjectLocationChange => {
-                if WindowsApi::is_maximized(origin) {
+                if Window::from(origin).is_maximized() {
                     self.pseudo_pause()?;
+                } else if self.is_managed(origin) {
+                    self.reconcile_native_snap(origin)?;
                 }
             }
             WinEvent::SyntheticFullscreenStart(_) => self.pseudo_pause()?,
@@ -118,3 +126,107 @@ impl WindowManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use windows::Win32::{Foundation::RECT, Graphics::Gdi::HMONITOR};
+
+    use crate::{
+        state::domain::AppExtraFlag,
+        testing::{MockWindow, Simulation},
+        winevent::WinEvent,
+    };
+
+    fn handles(payloads: Vec<Value>) -> Vec<Value> {
+        payloads
+            .into_iter()
+            .map(|app| app["hwnd"].clone())
+            .collect()
+    }
+
+    #[test]
+    fn windows_are_tiled_and_untiled() {
+        let mut sim = Simulation::new().with_wm();
+        let first = sim.open(MockWindow::app("C:\\Apps\\tiled_a.exe", "A"));
+        let second = sim.open(MockWindow::app("C:\\Apps\\tiled_b.exe", "B"));
+        assert!(sim.is_managed(first) && sim.is_managed(second));
+        assert_eq!(
+            handles(sim.host.emitted("add-window")),
+            vec![json!(first.0), json!(second.0)]
+        );
+
+        sim.minimize(first);
+        assert!(!sim.is_managed(first));
+        sim.restore(first);
+        assert!(sim.is_managed(first));
+
+        sim.desktop
+            .update(second, |window| window.title = "B - edited".to_string());
+        assert!(sim.emit(WinEvent::ObjectNameChange, second));
+        let titles = sim.host.emitted("update-window-title");
+        assert_eq!(
+            titles,
+            vec![json!({ "hwnd": second.0, "title": "B - edited" })]
+        );
+
+        sim.close(second);
+        assert!(!sim.is_managed(second));
+        assert_eq!(
+            sim.host.emitted("remove-window"),
+            vec![json!(first.0), json!(second.0)]
+        );
+    }
+
+    #[test]
+    fn app_configs_and_monitors() {
+        let mut sim = Simulation::new().with_wm();
+        sim.host
+            .set_app_flags("float.exe", vec![AppExtraFlag::Float]);
+        sim.host
+            .set_app_flags("unmanaged.exe", vec![AppExtraFlag::Unmanage]);
+
+        let floating = sim.open(MockWindow::app("C:\\Apps\\float.exe", "Float"));
+        let unmanaged = sim.open(MockWindow::app("C:\\Apps\\unmanaged.exe", "Unmanaged"));
+        let elsewhere = MockWindow::app("C:\\Apps\\elsewhere.exe", "Elsewhere");
+        let elsewhere = sim.open(elsewhere.on_monitor(HMONITOR(2)));
+        let popup = sim.open(MockWindow::popup("C:\\Apps\\borderless.exe").title("Popup"));
+
+        assert!(sim.is_managed(floating));
+        let added = sim.host.emitted("add-window");
+        assert_eq!(handles(added.clone()), vec![json!(floating.0)]);
+        assert_eq!(added[0]["is_floating"], json!(true));
+        assert_eq!(added[0]["monitor"], json!("\\\\.\\DISPLAY1"));
+        for hwnd in [unmanaged, elsewhere, popup] {
+            assert!(!sim.is_managed(hwnd));
+        }
+        // still shown on the dock
+        assert!(sim.dock().contains(&popup.0));
+    }
+
+    #[test]
+    fn native_snaps_are_adopted() {
+        let mut sim = Simulation::new().with_wm();
+        let hwnd = sim.open(MockWindow::app("C:\\Apps\\snapped.exe", "Snapped"));
+
+        sim.desktop.update(hwnd, |window| window.arranged = true);
+        let left_half = RECT {
+            left: 0,
+            top: 0,
+            right: 960,
+            bottom: 1040,
+        };
+        sim.move_to(hwnd, left_half);
+        let snaps = sim.host.emitted("native-snap");
+        assert_eq!(snaps.len(), 1);
+        assert_eq!(snaps[0]["x"], json!(0.0));
+        assert_eq!(snaps[0]["width"], json!(0.5));
+        assert_eq!(snaps[0]["height"], json!(1.0));
+
+        // restored from the snap, it goes back to its tile
+        sim.desktop.update(hwnd, |window| window.arranged = false);
+        sim.move_to(hwnd, MockWindow::app("", "").rect);
+        assert_eq!(sim.host.emitted("native-snap").len(), 1);
+        assert_eq!(sim.host.emitted("force-retiling").len(), 1);
+    }
+}
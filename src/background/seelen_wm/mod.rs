@@ -1,12 +1,15 @@
 pub mod cli;
 pub mod handler;
 pub mod hook;
+pub mod snap;
+pub mod teleport;
+pub mod tree;
 
 use std::sync::atomic::{AtomicIsize, Ordering};
 
 use getset::{Getters, MutGetters};
 use serde::Serialize;
-use tauri::{AppHandle, Emitter, Listener, WebviewWindow, Wry};
+use tauri::{AppHandle, Listener, WebviewWindow, Wry};
 use windows::Win32::{
     Foundation::{BOOL, HWND, LPARAM},
     Graphics::Gdi::HMONITOR,
@@ -17,14 +20,15 @@ use windows::Win32::{
 
 use crate::{
     error_handler::Result,
+    host::{emit_global, with_host, Surface},
     log_error,
-    modules::virtual_desk::get_vd_manager,
+    modules::window_controls::WindowControls,
     seelen::{get_app_handle, SEELEN},
     seelen_bar::FancyToolbar,
     seelen_weg::SeelenWeg,
-    state::{application::FULL_STATE, domain::AppExtraFlag},
+    state::domain::AppExtraFlag,
     trace_lock,
-    windows_api::WindowsApi,
+    windows_api::{window::Window, WindowsApi},
 };
 
 #[derive(Serialize, Clone)]
@@ -33,11 +37,51 @@ pub struct ManagingApp {
     monitor: String,
     desktop_id: String,
     is_floating: bool,
+    /// shown on the tabs of stacked containers
+    title: String,
+    /// none if the window did not answer in time
+    size_limits: Option<SizeLimits>,
+    /// snapped by Windows, used to only adopt the snap once
+    #[serde(skip)]
+    is_snapped: bool,
+}
+
+/// Size limits of the visible frame of a window in physical pixels, read from
+/// `WM_GETMINMAXINFO` so the tiles never ask a window for a size it can't take.
+#[derive(Serialize, Clone, Copy)]
+pub struct SizeLimits {
+    min_width: i32,
+    min_height: i32,
+    max_width: i32,
+    max_height: i32,
+}
+
+impl SizeLimits {
+    pub fn of(hwnd: HWND) -> Result<Self> {
+        let window = Window::from(hwnd);
+        let info = window.min_max_info()?;
+        // tiles are the visible frame, the invisible borders are added on positioning
+        let shadow = window.shadow_rect()?;
+        let borders_width = shadow.right - shadow.left;
+        let borders_height = shadow.bottom - shadow.top;
+        Ok(Self {
+            min_width: (info.ptMinTrackSize.x - borders_width).max(0),
+            min_height: (info.ptMinTrackSize.y - borders_height).max(0),
+            max_width: (info.ptMaxTrackSize.x - borders_width).max(0),
+            max_height: (info.ptMaxTrackSize.y - borders_height).max(0),
+        })
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct WindowTitleChange {
+    hwnd: isize,
+    title: String,
 }
 
 #[derive(Getters, MutGetters)]
-pub struct WindowManager {
-    window: WebviewWindow,
+pub struct WindowManager<S: Surface = WebviewWindow> {
+    window: S,
     monitor: HMONITOR,
     apps: Vec<ManagingApp>,
     pub current_virtual_desktop: String,
@@ -46,10 +90,10 @@ pub struct WindowManager {
     ready: bool,
 }
 
-impl Drop for WindowManager {
+impl<S: Surface> Drop for WindowManager<S> {
     fn drop(&mut self) {
-        log::info!("Dropping {}", self.window.label());
-        log_error!(self.window.destroy());
+        log::info!("Dropping {}", self.window.name());
+        log_error!(self.window.close());
     }
 }
 
@@ -69,21 +113,40 @@ impl WindowManager {
             window: Self::create_window(&handle, monitor)?,
             monitor: HMONITOR(monitor),
             apps: Vec::new(),
-            current_virtual_desktop: get_vd_manager().get_current()?.id(),
+            current_virtual_desktop: with_host(|host| host.current_desktop())?,
             paused: true, // paused until complete-setup is called
             ready: false,
         })
     }
+}
 
-    pub fn emit<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<()> {
-        self.window.emit_to(self.window.label(), event, payload)?;
-        Ok(())
+impl<S: Surface> WindowManager<S> {
+    /// Window manager on an already created webview, used by the tests
+    #[cfg(test)]
+    pub fn from_surface(window: S, monitor: HMONITOR, desktop_id: &str) -> Self {
+        Self {
+            window,
+            monitor,
+            apps: Vec::new(),
+            current_virtual_desktop: desktop_id.to_string(),
+            paused: false,
+            ready: true,
+        }
+    }
+
+    pub fn emit<P: Serialize + Clone>(&self, event: &str, payload: P) -> Result<()> {
+        self.window.emit_self(event, payload)
     }
 
     pub fn is_managed(&self, hwnd: HWND) -> bool {
         self.get_app(hwnd).is_some()
     }
 
+    /// handles of the managed windows, in the order they were added
+    pub fn handles(&self) -> Vec<isize> {
+        self.apps.iter().map(|app| app.hwnd).collect()
+    }
+
     pub fn is_floating(&self, hwnd: HWND) -> bool {
         self.get_app(hwnd)
             .map(|app| app.is_floating)
@@ -99,19 +162,15 @@ impl WindowManager {
     }
 
     pub fn set_active_window(&mut self, hwnd: HWND) -> Result<()> {
-        if WindowsApi::get_window_text(hwnd) == "Task Switching" {
+        let window = Window::from(hwnd);
+        let title = window.title();
+        if title == "Task Switching" {
             return Ok(());
         }
 
-        log::trace!(
-            "Setting active window to {} <=> {:?}",
-            hwnd.0,
-            WindowsApi::get_window_text(hwnd),
-        );
+        log::trace!("Setting active window to {} <=> {:?}", hwnd.0, title);
 
-        let hwnd = match self.is_managed(hwnd)
-            && !self.is_floating(hwnd)
-            && !WindowsApi::is_maximized(hwnd)
+        let hwnd = match self.is_managed(hwnd) && !self.is_floating(hwnd) && !window.is_maximized()
         {
             true => {
                 self.pseudo_resume()?;
@@ -132,8 +191,7 @@ impl WindowManager {
         }
         log::trace!("Setting active workspace to: {}", virtual_desktop_id);
         self.current_virtual_desktop = virtual_desktop_id;
-        self.window
-            .emit("set-active-workspace", &self.current_virtual_desktop)?;
+        emit_global("set-active-workspace", &self.current_virtual_desktop)?;
         Ok(())
     }
 
@@ -142,30 +200,31 @@ impl WindowManager {
             return Ok(false);
         }
 
-        let desktop_to_add = if WindowsApi::is_cloaked(hwnd)? {
-            get_vd_manager().get_by_window(hwnd.0)?.id()
+        let window = Window::from(hwnd);
+        let desktop_to_add = if window.is_cloaked()? {
+            with_host(|host| host.desktop_of(&window))?
         } else {
             self.current_virtual_desktop.clone()
         };
 
         log::trace!(
             "Adding {}({}) <=> {} on desktop: {}",
-            WindowsApi::exe(hwnd).unwrap_or_default(),
+            window.exe().unwrap_or_default().display(),
             hwnd.0,
-            WindowsApi::get_window_text(hwnd),
+            window.title(),
             desktop_to_add
         );
 
-        let mut is_floating = false;
-        if let Some(config) = FULL_STATE.load().get_app_config_by_window(hwnd) {
-            is_floating = config.options.contains(&AppExtraFlag::Float);
-        }
+        let is_floating = with_host(|host| host.app_flags(&window)).contains(&AppExtraFlag::Float);
 
         let app = ManagingApp {
             hwnd: hwnd.0,
-            monitor: WindowsApi::monitor_name(WindowsApi::monitor_from_window(hwnd))?,
+            monitor: window.monitor_name()?,
             desktop_id: desktop_to_add,
             is_floating,
+            title: window.title(),
+            size_limits: SizeLimits::of(hwnd).ok(),
+            is_snapped: window.is_arranged(),
         };
 
         self.emit("add-window", &app)?;
@@ -183,7 +242,7 @@ impl WindowManager {
                 None => return Ok(()),
             };
 
-            let current_desktop = get_vd_manager().get_by_window(app.hwnd)?.id();
+            let current_desktop = with_host(|host| host.desktop_of(&Window::from(hwnd)))?;
             if app.desktop_id != current_desktop {
                 app.desktop_id = current_desktop;
             }
@@ -193,6 +252,22 @@ impl WindowManager {
         Ok(())
     }
 
+    pub fn update_title(&mut self, hwnd: HWND) -> Result<()> {
+        let title = Window::from(hwnd).title();
+        let app = match self.get_app_mut(hwnd) {
+            Some(app) if app.title != title => app,
+            _ => return Ok(()),
+        };
+        app.title = title.clone();
+        self.emit(
+            "update-window-title",
+            WindowTitleChange {
+                hwnd: hwnd.0,
+                title,
+            },
+        )
+    }
+
     /** triggered when a window is bounced by the front-end on adding action */
     pub fn bounce_handle(&mut self, hwnd: HWND) {
         if let Some(app) = self.get_app_mut(hwnd) {
@@ -212,11 +287,7 @@ impl WindowManager {
         if !self.remove_hwnd_no_emit(hwnd) {
             return Ok(false);
         }
-        log::trace!(
-            "Removing {} <=> {:?}",
-            hwnd.0,
-            WindowsApi::get_window_text(hwnd)
-        );
+        log::trace!("Removing {} <=> {:?}", hwnd.0, Window::from(hwnd).title());
         self.emit("remove-window", hwnd.0)?;
         Ok(true)
     }
@@ -228,11 +299,11 @@ impl WindowManager {
     }
 
     pub fn pseudo_pause(&self) -> Result<()> {
-        WindowsApi::bring_to(self.window.hwnd()?, HWND_BOTTOM)
+        Window::from(self.window.handle()?).bring_to(HWND_BOTTOM)
     }
 
     pub fn pseudo_resume(&self) -> Result<()> {
-        WindowsApi::bring_to(self.window.hwnd()?, HWND_TOPMOST)
+        Window::from(self.window.handle()?).bring_to(HWND_TOPMOST)
     }
 
     pub fn pause(&mut self, action: bool, visuals: bool) -> Result<()> {
@@ -248,30 +319,32 @@ impl WindowManager {
 
     pub fn should_be_added(&self, hwnd: HWND) -> bool {
         !self.is_managed(hwnd)
-            && self.monitor == WindowsApi::monitor_from_window(hwnd)
-            && Self::should_be_managed(hwnd)
+            && self.monitor == Window::from(hwnd).monitor()
+            && WindowManager::should_be_managed(hwnd)
     }
 }
 
 // UTILS AND STATICS
 impl WindowManager {
     fn should_be_managed(hwnd: HWND) -> bool {
-        if let Some(config) = FULL_STATE.load().get_app_config_by_window(hwnd) {
-            if config.options.contains(&AppExtraFlag::Force) {
-                return true;
-            }
-
-            if config.options.contains(&AppExtraFlag::Unmanage)
-                || config.options.contains(&AppExtraFlag::Pinned)
-            {
-                return false;
-            }
+        let window = Window::from(hwnd);
+        let flags = with_host(|host| host.app_flags(&window));
+        if flags.contains(&AppExtraFlag::Force) {
+            return true;
+        }
+        if flags.contains(&AppExtraFlag::Unmanage) || flags.contains(&AppExtraFlag::Pinned) {
+            return false;
+        }
+        // pinned manually to all desktops
+        if with_host(|host| host.is_pinned_to_all_desktops(&window)) {
+            return false;
         }
         Self::is_manageable_window(hwnd)
     }
 
     pub fn is_manageable_window(hwnd: HWND) -> bool {
-        let exe = WindowsApi::exe(hwnd);
+        let window = Window::from(hwnd);
+        let exe = window.exe();
 
         if let Ok(exe) = &exe {
             if exe.ends_with("ApplicationFrameHost.exe") && SeelenWeg::should_be_added(hwnd) {
@@ -283,9 +356,10 @@ impl WindowManager {
         exe.is_ok()
         && SeelenWeg::should_be_added(hwnd)
         // Ignore windows without a title bar, and top most windows normally are widgets or tools so they should not be managed
-        && (WindowsApi::get_styles(hwnd).contains(WS_CAPTION) && !WindowsApi::get_ex_styles(hwnd).contains(WS_EX_TOPMOST))
-        && !WindowsApi::is_iconic(hwnd)
-        && (get_vd_manager().uses_cloak() || !WindowsApi::is_cloaked(hwnd).unwrap_or(false))
+        && (window.styles().contains(WS_CAPTION) && !window.ex_styles().contains(WS_EX_TOPMOST))
+        && !window.is_minimized()
+        && !WindowControls::is_shaded(hwnd)
+        && (with_host(|host| host.uses_cloak()) || !window.is_cloaked().unwrap_or(false))
     }
 
     fn create_window(handle: &AppHandle<Wry>, monitor_id: isize) -> Result<WebviewWindow> {
@@ -320,8 +394,7 @@ impl WindowManager {
                     if let Some(wm) = monitor.wm_mut() {
                         wm.paused = false;
                         wm.ready = true;
-                        wm.window
-                            .emit("set-active-workspace", &wm.current_virtual_desktop)?;
+                        emit_global("set-active-workspace", &wm.current_virtual_desktop)?;
                     }
                 }
                 Ok(())
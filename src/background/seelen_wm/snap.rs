@@ -0,0 +1,53 @@
+use serde::Serialize;
+use windows::Win32::Foundation::HWND;
+
+use crate::{error_handler::Result, host::Surface, windows_api::window::Window};
+
+use super::WindowManager;
+
+/// Window snapped by Windows, the values are fractions (0 - 1) of the monitor's work area
+#[derive(Serialize, Clone)]
+pub struct NativeSnap {
+    hwnd: isize,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl<S: Surface> WindowManager<S> {
+    /// Adopts the size of the windows snapped with `Win + Arrow` or the Snap Layouts flyout
+    /// as the ratio of their tiles, windows restored from a snap go back to their tiles.
+    pub fn reconcile_native_snap(&mut self, hwnd: HWND) -> Result<()> {
+        let window = Window::from(hwnd);
+        let arranged = window.is_arranged();
+        let app = match self.get_app_mut(hwnd) {
+            Some(app) if !app.is_floating && app.is_snapped != arranged => app,
+            _ => return Ok(()),
+        };
+        app.is_snapped = arranged;
+        if !arranged {
+            return self.force_retiling();
+        }
+
+        let work_area = window.work_area()?;
+        let rect = window.frame_rect();
+        let width = (work_area.right - work_area.left) as f64;
+        let height = (work_area.bottom - work_area.top) as f64;
+        if width <= 0.0 || height <= 0.0 {
+            return Ok(());
+        }
+
+        log::trace!("Adopting native snap of {}", hwnd.0);
+        self.emit(
+            "native-snap",
+            NativeSnap {
+                hwnd: hwnd.0,
+                x: (rect.left - work_area.left) as f64 / width,
+                y: (rect.top - work_area.top) as f64 / height,
+                width: (rect.right - rect.left) as f64 / width,
+                height: (rect.bottom - rect.top) as f64 / height,
+            },
+        )
+    }
+}
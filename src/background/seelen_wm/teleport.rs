@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{SWP_NOACTIVATE, SWP_NOZORDER, SW_MAXIMIZE},
+};
+
+use crate::{
+    error_handler::{AppError, Result},
+    log_error,
+    seelen::SEELEN,
+    seelen_weg::SeelenWeg,
+    trace_lock,
+    windows_api::{MonitorEnumerator, WindowEnumerator, WindowsApi},
+};
+
+use super::WindowManager;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MonitorDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+    Next,
+    Previous,
+}
+
+/// Monitor to send a window to, relative to the current one or by index.
+/// Indexes follow the position of the monitors, from left to right and top to bottom.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum MonitorTarget {
+    Index(usize),
+    Direction(MonitorDirection),
+}
+
+impl FromStr for MonitorTarget {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(index) = s.parse::<usize>() {
+            return Ok(Self::Index(index));
+        }
+        let direction = match s.to_lowercase().as_str() {
+            "left" => MonitorDirection::Left,
+            "right" => MonitorDirection::Right,
+            "up" => MonitorDirection::Up,
+            "down" => MonitorDirection::Down,
+            "next" => MonitorDirection::Next,
+            "previous" | "prev" => MonitorDirection::Previous,
+            _ => return Err(format!("Invalid monitor target: {s}").into()),
+        };
+        Ok(Self::Direction(direction))
+    }
+}
+
+fn center(rect: &RECT) -> (i32, i32) {
+    ((rect.left + rect.right) / 2, (rect.top + rect.bottom) / 2)
+}
+
+/// Maps the rect from one area to the other keeping its relative position and size
+fn scale_rect(rect: &RECT, from: &RECT, to: &RECT) -> RECT {
+    let scale_x = (to.right - to.left) as f64 / (from.right - from.left).max(1) as f64;
+    let scale_y = (to.bottom - to.top) as f64 / (from.bottom - from.top).max(1) as f64;
+    let x = |value: i32| to.left + ((value - from.left) as f64 * scale_x).round() as i32;
+    let y = |value: i32| to.top + ((value - from.top) as f64 * scale_y).round() as i32;
+    RECT {
+        left: x(rect.left),
+        top: y(rect.top),
+        right: x(rect.right),
+        bottom: y(rect.bottom),
+    }
+}
+
+impl WindowManager {
+    fn work_area(monitor: HMONITOR) -> Result<RECT> {
+        Ok(WindowsApi::monitor_info(monitor)?.monitorInfo.rcWork)
+    }
+
+    fn resolve_monitor(from: HMONITOR, target: MonitorTarget) -> Result<HMONITOR> {
+        let mut monitors = Vec::new();
+        for monitor in MonitorEnumerator::new_refreshed()? {
+            monitors.push((monitor, WindowsApi::monitor_rect(monitor)?));
+        }
+        monitors.sort_by_key(|(_, rect)| (rect.left, rect.top));
+
+        let current = monitors
+            .iter()
+            .position(|(monitor, _)| *monitor == from)
+            .ok_or("Monitor not found")?;
+        let (origin_x, origin_y) = center(&monitors[current].1);
+        let len = monitors.len();
+
+        let index = match target {
+            MonitorTarget::Index(index) => index,
+            MonitorTarget::Direction(MonitorDirection::Next) => (current + 1) % len,
+            MonitorTarget::Direction(MonitorDirection::Previous) => (current + len - 1) % len,
+            MonitorTarget::Direction(direction) => monitors
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, (_, rect))| {
+                    let (x, y) = center(rect);
+                    let (dx, dy) = ((x - origin_x) as i64, (y - origin_y) as i64);
+                    let in_direction = match direction {
+                        MonitorDirection::Left => dx < 0 && dx.abs() >= dy.abs(),
+                        MonitorDirection::Right => dx > 0 && dx.abs() >= dy.abs(),
+                        MonitorDirection::Up => dy < 0 && dy.abs() >= dx.abs(),
+                        MonitorDirection::Down => dy > 0 && dy.abs() >= dx.abs(),
+                        _ => false,
+                    };
+                    in_direction.then_some((idx, dx * dx + dy * dy))
+                })
+                .min_by_key(|(_, distance)| *distance)
+                .map(|(idx, _)| idx)
+                .ok_or(format!("No monitor found to the {direction:?}"))?,
+        };
+
+        monitors
+            .get(index)
+            .map(|(monitor, _)| *monitor)
+            .ok_or(format!("Monitor {index} not found").into())
+    }
+
+    /// Moves the window between monitors without changing its size relative to the work area
+    fn move_between_monitors(hwnd: HWND, from: HMONITOR, to: HMONITOR) -> Result<()> {
+        let maximized = WindowsApi::is_maximized(hwnd);
+        if maximized {
+            WindowsApi::unmaximize_window(hwnd)?;
+        }
+
+        let rect = scale_rect(
+            &WindowsApi::get_window_rect(hwnd),
+            &Self::work_area(from)?,
+            &Self::work_area(to)?,
+        );
+        // the first move can trigger a dpi change where the app resizes itself,
+        // the second one ensures the final rect
+        WindowsApi::set_position(hwnd, None, &rect, SWP_NOACTIVATE | SWP_NOZORDER)?;
+        WindowsApi::set_position(hwnd, None, &rect, SWP_NOACTIVATE | SWP_NOZORDER)?;
+
+        if maximized {
+            WindowsApi::show_window_async(hwnd, SW_MAXIMIZE)?;
+        }
+        Ok(())
+    }
+
+    /// Tiled windows are handed over to the window manager of the target monitor
+    fn hand_over(hwnd: HWND, from: HMONITOR, to: HMONITOR) -> Result<()> {
+        if !WindowsApi::is_window(hwnd) || WindowsApi::is_iconic(hwnd) {
+            return Ok(());
+        }
+
+        let was_managed = {
+            let mut seelen = trace_lock!(SEELEN);
+            match seelen
+                .monitor_by_id_mut(from.0)
+                .and_then(|m| m.wm_mut().as_mut())
+            {
+                Some(wm) => wm.remove_hwnd(hwnd)?,
+                None => false,
+            }
+        };
+
+        Self::move_between_monitors(hwnd, from, to)?;
+
+        if was_managed {
+            let mut seelen = trace_lock!(SEELEN);
+            if let Some(wm) = seelen
+                .monitor_by_id_mut(to.0)
+                .and_then(|m| m.wm_mut().as_mut())
+            {
+                wm.add_hwnd(hwnd)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn send_to_monitor(hwnd: HWND, target: MonitorTarget) -> Result<()> {
+        let from = WindowsApi::monitor_from_window(hwnd);
+        let to = Self::resolve_monitor(from, target)?;
+        if from != to {
+            Self::hand_over(hwnd, from, to)?;
+        }
+        Ok(())
+    }
+
+    /// Sends all the windows of the current workspace on the monitor of `hwnd`
+    pub fn send_workspace_to_monitor(hwnd: HWND, target: MonitorTarget) -> Result<()> {
+        let from = WindowsApi::monitor_from_window(hwnd);
+        let to = Self::resolve_monitor(from, target)?;
+        if from == to {
+            return Ok(());
+        }
+
+        let mut windows = Vec::new();
+        WindowEnumerator::new().for_each(|hwnd| {
+            if SeelenWeg::should_be_added(hwnd)
+                && !WindowsApi::is_cloaked(hwnd).unwrap_or(true)
+                && WindowsApi::monitor_from_window(hwnd) == from
+            {
+                windows.push(hwnd);
+            }
+        })?;
+
+        for hwnd in windows {
+            log_error!(Self::hand_over(hwnd, from, to));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashSet, path::Path};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use windows::Win32::Foundation::HWND;
+
+use crate::{error_handler::Result, windows_api::window::Window};
+
+use super::WindowManager;
+
+/// Replaces a window handle on saved trees, as handles are only valid until the window closes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowMatcher {
+    exe: String,
+    title: String,
+}
+
+impl WindowMatcher {
+    fn from_handle(hwnd: isize) -> Option<Self> {
+        let window = Window::from(HWND(hwnd));
+        Some(Self {
+            exe: window.exe().ok()?.to_string_lossy().to_string(),
+            title: window.title(),
+        })
+    }
+}
+
+/// Layout trees are handled as plain json, so the nodes are kept as the webview sends them
+/// and unknown properties (as the ones added by themes or newer versions) are preserved.
+pub struct LayoutTree;
+
+impl LayoutTree {
+    /// Converts the handles of the tree into window matchers, `active` is dropped
+    /// because the first window of each stack is activated on apply.
+    pub fn to_portable(node: &mut Value) {
+        let Some(obj) = node.as_object_mut() else {
+            return;
+        };
+
+        obj.remove("active");
+        if let Some(handle) = obj.remove("handle") {
+            let window = handle
+                .as_i64()
+                .and_then(|h| WindowMatcher::from_handle(h as isize));
+            obj.insert(
+                "window".into(),
+                serde_json::to_value(window).unwrap_or_default(),
+            );
+        }
+        if let Some(Value::Array(handles)) = obj.remove("handles") {
+            let windows: Vec<WindowMatcher> = handles
+                .iter()
+                .filter_map(|h| {
+                    h.as_i64()
+                        .and_then(|h| WindowMatcher::from_handle(h as isize))
+                })
+                .collect();
+            obj.insert(
+                "windows".into(),
+                serde_json::to_value(windows).unwrap_or_default(),
+            );
+        }
+        if let Some(Value::Array(children)) = obj.get_mut("children") {
+            children.iter_mut().for_each(Self::to_portable);
+        }
+    }
+
+    /// Inverse of `to_portable`, matchers are resolved to the first unused window with the
+    /// same exe, preferring the one with the same title. Unmatched windows are left empty.
+    pub fn resolve(node: &mut Value, candidates: &[isize], used: &mut HashSet<isize>) {
+        let Some(obj) = node.as_object_mut() else {
+            return;
+        };
+
+        if let Some(window) = obj.remove("window") {
+            let hwnd = serde_json::from_value::<WindowMatcher>(window)
+                .ok()
+                .and_then(|m| Self::find(&m, candidates, used));
+            obj.insert("handle".into(), hwnd.into());
+        }
+        if let Some(windows) = obj.remove("windows") {
+            let matchers: Vec<WindowMatcher> = serde_json::from_value(windows).unwrap_or_default();
+            let handles: Vec<isize> = matchers
+                .iter()
+                .filter_map(|m| Self::find(m, candidates, used))
+                .collect();
+            obj.insert("active".into(), handles.first().copied().into());
+            obj.insert("handles".into(), handles.into());
+        }
+        if let Some(Value::Array(children)) = obj.get_mut("children") {
+            for child in children {
+                Self::resolve(child, candidates, used);
+            }
+        }
+    }
+
+    fn find(
+        matcher: &WindowMatcher,
+        candidates: &[isize],
+        used: &mut HashSet<isize>,
+    ) -> Option<isize> {
+        let same_exe: Vec<isize> = candidates
+            .iter()
+            .copied()
+            .filter(|hwnd| !used.contains(hwnd))
+            .filter(|hwnd| {
+                Window::from(HWND(*hwnd))
+                    .exe()
+                    .is_ok_and(|exe| exe.to_string_lossy().eq_ignore_ascii_case(&matcher.exe))
+            })
+            .collect();
+        let found = same_exe
+            .iter()
+            .copied()
+            .find(|hwnd| Window::from(HWND(*hwnd)).title() == matcher.title)
+            .or(same_exe.first().copied())?;
+        used.insert(found);
+        Some(found)
+    }
+
+    pub fn write(path: &Path, mut structure: Value) -> Result<()> {
+        Self::to_portable(&mut structure);
+        std::fs::write(path, serde_json::to_string_pretty(&structure)?)?;
+        Ok(())
+    }
+}
+
+impl WindowManager {
+    /// tiled windows on the current workspace, the ones a saved tree can be applied to
+    fn tiled_on_current_workspace(&self) -> Vec<isize> {
+        self.apps
+            .iter()
+            .filter(|app| !app.is_floating && app.desktop_id == self.current_virtual_desktop)
+            .map(|app| app.hwnd)
+            .collect()
+    }
+
+    pub fn export_layout(&self, path: &Path) -> Result<()> {
+        self.emit("export-layout", path)
+    }
+
+    pub fn apply_layout(&self, path: &Path) -> Result<()> {
+        let mut structure: Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        LayoutTree::resolve(
+            &mut structure,
+            &self.tiled_on_current_workspace(),
+            &mut HashSet::new(),
+        );
+        self.emit("apply-layout-tree", structure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        testing::{MockDesktop, MockWindow},
+        windows_api::backend::set_thread_backend,
+    };
+
+    #[test]
+    fn portable_tree_round_trip() {
+        let desktop = MockDesktop::install();
+        let editor = desktop.add(MockWindow::app("C:\\Apps\\code.exe", "main.rs"));
+        let term = desktop.add(MockWindow::app("C:\\Apps\\term.exe", "Terminal"));
+        let notes = desktop.add(MockWindow::app("C:\\Apps\\code.exe", "notes.md"));
+
+        let mut tree = json!({
+            "type": "Horizontal",
+            "children": [
+                { "type": "Leaf", "handle": editor.0, "priority": 1 },
+                { "type": "Stack", "handles": [term.0, notes.0], "active": term.0 },
+            ],
+        });
+        LayoutTree::to_portable(&mut tree);
+        assert_eq!(tree["children"][0]["window"]["title"], "main.rs");
+        assert!(tree["children"][1].get("active").is_none());
+
+        // the same windows reopened get new handles, titles prefer the right window of each exe
+        let desktop = MockDesktop::install();
+        let notes = desktop.add(MockWindow::app("C:\\Apps\\code.exe", "notes.md"));
+        let editor = desktop.add(MockWindow::app("C:\\Apps\\code.exe", "main.rs"));
+        let term = desktop.add(MockWindow::app("C:\\Apps\\term.exe", "Terminal"));
+
+        let candidates = [notes.0, editor.0, term.0];
+        LayoutTree::resolve(&mut tree, &candidates, &mut HashSet::new());
+        assert_eq!(tree["children"][0]["handle"], editor.0);
+        assert_eq!(tree["children"][0]["priority"], 1);
+        assert_eq!(tree["children"][1]["handles"], json!([term.0, notes.0]));
+        assert_eq!(tree["children"][1]["active"], term.0);
+        set_thread_backend(None);
+    }
+
+    #[test]
+    fn unmatched_windows_are_left_empty() {
+        let desktop = MockDesktop::install();
+        let closed = desktop.add(MockWindow::app("C:\\Apps\\paint.exe", "Paint"));
+        let mut tree = json!({ "type": "Leaf", "handle": closed.0 });
+        LayoutTree::to_portable(&mut tree);
+
+        desktop.remove(closed);
+        let other = desktop.add(MockWindow::app("C:\\Apps\\notepad.exe", "Notepad"));
+        LayoutTree::resolve(&mut tree, &[other.0], &mut HashSet::new());
+        assert!(tree["handle"].is_null());
+        set_thread_backend(None);
+    }
+}
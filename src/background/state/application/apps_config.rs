@@ -1,9 +1,30 @@
+use serde::Serialize;
 use windows::Win32::Foundation::HWND;
 
-use crate::{state::domain::AppConfig, windows_api::WindowsApi};
+use crate::{
+    error_handler::Result,
+    state::domain::{AppConfig, AppExtraFlag},
+    windows_api::WindowsApi,
+};
 
 use super::FullState;
 
+/// Result of a dry run of the app configs against a window
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleMatchReport {
+    pub title: String,
+    pub class: String,
+    pub exe: String,
+    pub path: String,
+    /// all the configs whose identifier matched, in priority order
+    pub matched: Vec<AppConfig>,
+    /// name of the config applied, only the first match is used
+    pub applied: Option<String>,
+    /// flags that would be applied to the window
+    pub flags: Vec<AppExtraFlag>,
+}
+
 impl FullState {
     pub fn get_app_config_by_window(&self, hwnd: HWND) -> Option<&AppConfig> {
         // Can no cache apps that changes titles
@@ -36,4 +57,32 @@ impl FullState {
 
         None
     }
+
+    pub fn match_rules(&self, title: &str, class: &str, exe: &str, path: &str) -> RuleMatchReport {
+        let matched: Vec<AppConfig> = self
+            .settings_by_app
+            .iter()
+            .filter(|app| app.identifier.validate(title, class, exe, path))
+            .cloned()
+            .collect();
+        let first = matched.first();
+        RuleMatchReport {
+            title: title.to_string(),
+            class: class.to_string(),
+            exe: exe.to_string(),
+            path: path.to_string(),
+            applied: first.map(|app| app.name.clone()),
+            flags: first.map(|app| app.options.clone()).unwrap_or_default(),
+            matched,
+        }
+    }
+
+    pub fn match_rules_by_window(&self, hwnd: HWND) -> Result<RuleMatchReport> {
+        Ok(self.match_rules(
+            &WindowsApi::get_window_text(hwnd),
+            &WindowsApi::get_class(hwnd)?,
+            &WindowsApi::exe(hwnd)?,
+            &WindowsApi::exe_path(hwnd)?,
+        ))
+    }
 }
@@ -1,4 +1,8 @@
 mod apps_config;
+mod validation;
+
+pub use apps_config::RuleMatchReport;
+pub use validation::ConfigIssue;
 
 use arc_swap::ArcSwap;
 use getset::Getters;
@@ -9,7 +13,9 @@ use notify_debouncer_full::{
     notify::{ReadDirectoryChangesWatcher, RecursiveMode, Watcher},
     DebounceEventResult, DebouncedEvent, Debouncer, FileIdMap,
 };
-use seelen_core::state::{VirtualDesktopStrategy, WegItems, WindowManagerLayout};
+use seelen_core::state::{
+    HideMode, Monitor, VirtualDesktopStrategy, WegItems, WindowManagerLayout, ZoneLayout,
+};
 use serde::Serialize;
 use std::{
     collections::{HashMap, VecDeque},
@@ -25,10 +31,16 @@ use tauri::{AppHandle, Emitter, Manager};
 use crate::{
     error_handler::Result,
     log_error,
-    modules::cli::domain::Resource,
+    modules::{
+        cli::domain::Resource,
+        os_capabilities::domain::OsCapabilities,
+        undo::{domain::UndoableAction, Undo},
+        zones::domain::validate_zone_layout,
+    },
     seelen::{get_app_handle, SEELEN},
+    seelen_weg::folders::FolderBadges,
     trace_lock,
-    utils::is_virtual_desktop_supported,
+    utils::app_data_path,
     windows_api::WindowsApi,
 };
 
@@ -72,7 +84,7 @@ impl FullState {
     fn new() -> Result<Self> {
         let handle = get_app_handle();
         let mut manager = Self {
-            data_dir: handle.path().app_data_dir()?,
+            data_dir: app_data_path(&handle),
             resources_dir: handle.path().resource_dir()?,
             handle,
             watcher: Arc::new(None),
@@ -126,9 +138,16 @@ impl FullState {
 
         if event.paths.contains(&weg_items_path) {
             log::info!("Weg Items changed");
+            let previous = self.weg_items.clone();
             self.load_weg_items()?;
+            if self.weg_items.pinned_count() < previous.pinned_count() {
+                Undo::record(UndoableAction::UnpinWegItems { previous });
+            }
             self.store_cloned();
             self.emit_weg_items()?;
+            if self.is_weg_enabled() {
+                log_error!(FolderBadges::start());
+            }
         }
 
         if event.paths.contains(&self.settings_path()) {
@@ -226,10 +245,11 @@ impl FullState {
                     .language
                     .or_else(|| Some(Settings::get_system_language()));
                 if settings.virtual_desktop_strategy == VirtualDesktopStrategy::Native
-                    && !is_virtual_desktop_supported()
+                    && !OsCapabilities::get().native_virtual_desktops
                 {
                     settings.virtual_desktop_strategy = VirtualDesktopStrategy::Seelen;
                 }
+                settings.window_titles.cache_regex();
                 Ok(settings)
             }
             _ => Err("Invalid settings file extension".into()),
@@ -247,6 +267,14 @@ impl FullState {
         Ok(())
     }
 
+    pub fn write_weg_items(&self, items: &WegItems) -> Result<()> {
+        std::fs::write(
+            self.data_dir.join("seelenweg_items.yaml"),
+            serde_yaml::to_string(items)?,
+        )?;
+        Ok(())
+    }
+
     fn load_weg_items(&mut self) -> Result<()> {
         let path = self.data_dir.join("seelenweg_items.yaml");
         if path.exists() {
@@ -368,6 +396,8 @@ impl FullState {
         let user_path = self.data_dir.join("layouts");
         let resources_path = self.resources_dir.join("static/layouts");
         let entries = std::fs::read_dir(&resources_path)?.chain(std::fs::read_dir(&user_path)?);
+        // cleared so the deleted presets are not kept
+        self.layouts.clear();
         for entry in entries.flatten() {
             let path = entry.path();
             if path.is_dir() {
@@ -475,6 +505,228 @@ impl FullState {
         Ok(())
     }
 
+    /// Replaces the selected themes, saving the settings only if they changed.
+    /// Returns true if the settings were saved.
+    pub fn select_themes(&mut self, themes: Vec<String>) -> Result<bool> {
+        if self.settings.selected_theme == themes {
+            return Ok(false);
+        }
+        self.settings.selected_theme = themes;
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Adds or removes the exe from the list of apps always shown on the dock.
+    /// Returns true if the settings changed and were saved.
+    pub fn set_weg_force_show(&mut self, exe: &str, enabled: bool) -> Result<bool> {
+        let list = &mut self.settings.seelenweg.force_show;
+        let is_listed = list.iter().any(|item| item.eq_ignore_ascii_case(exe));
+        if is_listed == enabled {
+            return Ok(false);
+        }
+        if enabled {
+            list.push(exe.to_string());
+        } else {
+            list.retain(|item| !item.eq_ignore_ascii_case(exe));
+        }
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Enables or disables the dock, toolbar and window manager, then saves the settings.
+    pub fn set_modules_enabled(&mut self, weg: bool, toolbar: bool, wm: bool) -> Result<()> {
+        self.settings.seelenweg.enabled = weg;
+        self.settings.fancy_toolbar.enabled = toolbar;
+        self.settings.window_manager.enabled = wm;
+        self.save_settings()
+    }
+
+    /// Returns true if the settings changed and were saved.
+    pub fn set_weg_hide_mode(&mut self, mode: HideMode) -> Result<bool> {
+        if self.settings.seelenweg.hide_mode == mode {
+            return Ok(false);
+        }
+        self.settings.seelenweg.hide_mode = mode;
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Returns true if the settings changed and were saved.
+    pub fn set_brightness_linked(&mut self, linked: bool) -> Result<bool> {
+        if self.settings.brightness.linked == linked {
+            return Ok(false);
+        }
+        self.settings.brightness.linked = linked;
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Replaces the settings by the ones saved on `profiles/<name>.json`,
+    /// the automation rules are kept to not lose the rule that switched the profile.
+    pub fn apply_settings_profile(&mut self, name: &str) -> Result<()> {
+        let path = self.data_dir.join("profiles").join(format!("{name}.json"));
+        if !path.exists() {
+            return Err(format!("Settings profile not found: {name}").into());
+        }
+        let mut settings = Self::get_settings_from_path(path)?;
+        settings.automation = self.settings.automation.clone();
+        self.settings = settings;
+        self.save_settings()
+    }
+
+    /// Replaces all the settings, used to restore the ones saved before applying a profile
+    pub fn replace_settings(&mut self, settings: Settings) -> Result<()> {
+        self.settings = settings;
+        self.save_settings()
+    }
+
+    /// Flips the enabled flag of a module listed on `Settings::TOGGLEABLE_MODULES`.
+    /// Returns the new state of the module.
+    pub fn toggle_module(&mut self, name: &str) -> Result<bool> {
+        let enabled = self
+            .settings
+            .module_enabled_mut(name)
+            .ok_or_else(|| format!("Unknown module: {name}"))?;
+        *enabled = !*enabled;
+        let enabled = *enabled;
+        self.save_settings()?;
+        Ok(enabled)
+    }
+
+    /// Returns true if the settings changed and were saved.
+    pub fn enable_rendering_fallback(&mut self) -> Result<bool> {
+        if self.settings.rendering_fallback {
+            return Ok(false);
+        }
+        self.settings.rendering_fallback = true;
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Ensures an entry of the per monitor settings is bound to the device id.
+    /// Entries without id (saved by older versions) are adopted in order.
+    /// Returns true if the settings changed and were saved.
+    pub fn bind_monitor_settings(&mut self, device_id: &str) -> Result<bool> {
+        if self.settings.monitor_by_id(device_id).is_some() {
+            return Ok(false);
+        }
+
+        let monitors = &mut self.settings.monitors;
+        match monitors.iter_mut().find(|m| m.id.is_none()) {
+            Some(monitor) => monitor.id = Some(device_id.to_string()),
+            None => monitors.push(Monitor {
+                id: Some(device_id.to_string()),
+                ..Default::default()
+            }),
+        }
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Adds the zone layout or replaces the one named `previous` (the name of the layout if
+    /// not set), the monitors using a renamed layout are updated.
+    pub fn save_zone_layout(&mut self, layout: ZoneLayout, previous: Option<&str>) -> Result<()> {
+        validate_zone_layout(&layout)?;
+        let zones = &mut self.settings.zones;
+        let previous = previous.unwrap_or(&layout.name).to_string();
+        if previous != layout.name && zones.layouts.iter().any(|l| l.name == layout.name) {
+            return Err(format!("Zone layout already exists: {}", layout.name).into());
+        }
+
+        for name in zones.by_monitor.values_mut() {
+            if *name == previous {
+                *name = layout.name.clone();
+            }
+        }
+        match zones.layouts.iter_mut().find(|l| l.name == previous) {
+            Some(existing) => *existing = layout,
+            None => zones.layouts.push(layout),
+        }
+        self.save_settings()
+    }
+
+    /// Removes the zone layout and its monitor assignments.
+    /// Returns true if the layout existed and the settings were saved.
+    pub fn delete_zone_layout(&mut self, name: &str) -> Result<bool> {
+        let zones = &mut self.settings.zones;
+        let count = zones.layouts.len();
+        zones.layouts.retain(|l| l.name != name);
+        if zones.layouts.len() == count {
+            return Ok(false);
+        }
+        zones.by_monitor.retain(|_, layout| layout != name);
+        self.save_settings()?;
+        Ok(true)
+    }
+
+    /// Assigns the zone layout to the monitor, `None` restores the default (first) layout.
+    pub fn set_monitor_zone_layout(&mut self, monitor: &str, name: Option<&str>) -> Result<()> {
+        let zones = &mut self.settings.zones;
+        match name {
+            Some(name) => {
+                if !zones.layouts.iter().any(|l| l.name == name) {
+                    return Err(format!("Zone layout not found: {name}").into());
+                }
+                zones
+                    .by_monitor
+                    .insert(monitor.to_string(), name.to_string());
+            }
+            None => {
+                zones.by_monitor.remove(monitor);
+            }
+        }
+        self.save_settings()
+    }
+
+    /// Checks the filename of a window manager preset, only yml, yaml and json files
+    /// directly inside the user layouts folder are allowed.
+    fn user_layout_path(&self, filename: &str) -> Result<PathBuf> {
+        let path = Path::new(filename);
+        let is_plain_name = path.file_name().is_some_and(|name| name == filename);
+        let has_valid_ext = path
+            .extension()
+            .is_some_and(|ext| ext == "yml" || ext == "yaml" || ext == "json");
+        if !is_plain_name || !has_valid_ext {
+            return Err(format!("Invalid layout filename: {filename}").into());
+        }
+        if self
+            .resources_dir
+            .join("static/layouts")
+            .join(filename)
+            .exists()
+        {
+            return Err(format!("Bundled layouts can't be modified: {filename}").into());
+        }
+        Ok(self.data_dir.join("layouts").join(filename))
+    }
+
+    /// Writes the window manager preset on the user layouts folder, the file watcher
+    /// reloads and emits the layouts.
+    pub fn save_wm_layout(&self, layout: &WindowManagerLayout) -> Result<()> {
+        if layout.info.display_name.trim().is_empty() {
+            return Err("Layout display name can't be empty".into());
+        }
+        let path = self.user_layout_path(&layout.info.filename)?;
+        let content = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::to_string_pretty(layout)?
+        } else {
+            serde_yaml::to_string(layout)?
+        };
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Deletes a preset of the user layouts folder, the selected layout falls back to the
+    /// default one on reload if it was the deleted one.
+    pub fn delete_wm_layout(&self, filename: &str) -> Result<()> {
+        let path = self.user_layout_path(filename)?;
+        if !path.exists() {
+            return Err(format!("Layout not found: {filename}").into());
+        }
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
     async fn set_wallpaper(url: &str, path: &Path) -> Result<()> {
         let response = tauri_plugin_http::reqwest::get(url).await?;
         let contents = response.bytes().await?;
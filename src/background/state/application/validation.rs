@@ -0,0 +1,191 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+use seelen_core::state::{MatchingStrategy, WegItems, WindowManagerLayout};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::state::domain::{AppConfig, Placeholder, Settings, Theme};
+
+use super::FullState;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigIssue {
+    pub file: PathBuf,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// path to the invalid field, e.g. `fancyToolbar.height` or `[2].identifier.id`
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new<S: ToString>(file: &Path, field: Option<String>, message: S) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            line: None,
+            column: None,
+            field,
+            message: message.to_string(),
+        }
+    }
+
+    fn from_yaml(file: &Path, err: serde_yaml::Error) -> Self {
+        let location = err.location();
+        let mut message = err.to_string();
+        if let Some(idx) = message.rfind(" at line ") {
+            message.truncate(idx);
+        }
+
+        // nested errors are prefixed with the path of the field: `a.b[0].c: message`
+        let (field, message) = match message.split_once(": ") {
+            Some((path, rest)) if !path.contains(' ') => (Some(path.to_string()), rest.to_string()),
+            _ => (None, message),
+        };
+
+        Self {
+            file: file.to_path_buf(),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+            field,
+            message,
+        }
+    }
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, ":{}:{}", line, column)?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, " ({})", field)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Parses the file as `T`, json files are also parsed with the yaml parser as json is
+/// valid yaml and its errors include the path of the invalid field.
+fn check_file<T: DeserializeOwned>(path: &Path, issues: &mut Vec<ConfigIssue>) -> Option<T> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => {
+            issues.push(ConfigIssue::new(path, None, err));
+            return None;
+        }
+    };
+
+    if path.extension().is_some_and(|ext| ext == "json") {
+        if let Err(err) = serde_json::from_str::<T>(&content) {
+            let mut issue = match serde_yaml::from_str::<T>(&content) {
+                Err(yaml_err) => ConfigIssue::from_yaml(path, yaml_err),
+                Ok(_) => ConfigIssue::new(path, None, &err),
+            };
+            issue.line = Some(err.line());
+            issue.column = Some(err.column());
+            issues.push(issue);
+            return None;
+        }
+    }
+
+    match serde_yaml::from_str::<T>(&content) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            issues.push(ConfigIssue::from_yaml(path, err));
+            None
+        }
+    }
+}
+
+/// Yaml and json files on the folder, not recursive
+fn config_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext == "yml" || ext == "yaml" || ext == "json")
+        })
+        .collect()
+}
+
+impl FullState {
+    /// Checks the user config files, unlike the loaders this reports every error found
+    /// instead of skipping the invalid files.
+    pub fn validate_config(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        let settings_path = self.settings_path();
+        if settings_path.exists() {
+            if let Some(settings) = check_file::<Settings>(&settings_path, &mut issues) {
+                for theme in &settings.selected_theme {
+                    if !self.themes.contains_key(theme) {
+                        issues.push(ConfigIssue::new(
+                            &settings_path,
+                            Some("selectedTheme".to_string()),
+                            format!("theme \"{}\" is not installed", theme),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let apps_path = self.data_dir.join("applications.yml");
+        if apps_path.exists() {
+            let apps = check_file::<Vec<AppConfig>>(&apps_path, &mut issues).unwrap_or_default();
+            for (idx, app) in apps.iter().enumerate() {
+                if !matches!(app.identifier.matching_strategy, MatchingStrategy::Regex) {
+                    continue;
+                }
+                if let Err(err) = Regex::new(&app.identifier.id) {
+                    issues.push(ConfigIssue::new(
+                        &apps_path,
+                        Some(format!("[{}].identifier.id", idx)),
+                        err,
+                    ));
+                }
+            }
+        }
+
+        let weg_items_path = self.data_dir.join("seelenweg_items.yaml");
+        if weg_items_path.exists() {
+            check_file::<WegItems>(&weg_items_path, &mut issues);
+        }
+
+        if let Ok(entries) = std::fs::read_dir(self.data_dir.join("themes")) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    let file = path.join("theme.yml");
+                    if file.exists() {
+                        check_file::<Theme>(&file, &mut issues);
+                    } else {
+                        issues.push(ConfigIssue::new(&path, None, "theme.yml not found"));
+                    }
+                } else {
+                    check_file::<Theme>(&path, &mut issues);
+                }
+            }
+        }
+
+        for path in config_files(&self.data_dir.join("placeholders")) {
+            check_file::<Placeholder>(&path, &mut issues);
+        }
+
+        for path in config_files(&self.data_dir.join("layouts")) {
+            check_file::<WindowManagerLayout>(&path, &mut issues);
+        }
+
+        issues
+    }
+}
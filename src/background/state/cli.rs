@@ -0,0 +1,40 @@
+use clap::Command;
+use itertools::Itertools;
+
+use crate::{error_handler::Result, get_subcommands};
+
+use super::application::{FullState, FULL_STATE};
+
+get_subcommands![
+    /** Checks settings, app configs, weg items, themes, placeholders and layouts for errors */
+    Validate,
+];
+
+impl FullState {
+    pub const CLI_IDENTIFIER: &'static str = "config";
+
+    pub fn get_cli() -> Command {
+        Command::new(Self::CLI_IDENTIFIER)
+            .about("Seelen config files")
+            .arg_required_else_help(true)
+            .subcommands(SubCommand::commands())
+    }
+
+    /// Returns the text to be printed on the console of the caller
+    pub fn process(matches: &clap::ArgMatches) -> Result<String> {
+        let subcommand = SubCommand::try_from(matches)?;
+        match subcommand {
+            SubCommand::Validate => {
+                let issues = FULL_STATE.load().validate_config();
+                if issues.is_empty() {
+                    return Ok("No issues found.".to_string());
+                }
+                Ok(format!(
+                    "{} issue(s) found:\n{}",
+                    issues.len(),
+                    issues.iter().join("\n")
+                ))
+            }
+        }
+    }
+}
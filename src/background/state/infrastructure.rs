@@ -2,11 +2,12 @@ use std::path::PathBuf;
 
 use itertools::Itertools;
 use seelen_core::state::{WegItems, WindowManagerLayout};
+use windows::Win32::Foundation::HWND;
 
 use crate::{error_handler::Result, windows_api::WindowsApi};
 
 use super::{
-    application::{FullState, FULL_STATE},
+    application::{ConfigIssue, FullState, RuleMatchReport, FULL_STATE},
     domain::{AppConfig, Placeholder, Settings, Theme},
 };
 
@@ -30,6 +31,16 @@ pub fn state_get_layouts() -> Vec<WindowManagerLayout> {
     FULL_STATE.load().layouts().values().cloned().collect_vec()
 }
 
+#[tauri::command(async)]
+pub fn state_save_layout(layout: WindowManagerLayout) -> Result<()> {
+    FULL_STATE.load().save_wm_layout(&layout)
+}
+
+#[tauri::command(async)]
+pub fn state_delete_layout(filename: String) -> Result<()> {
+    FULL_STATE.load().delete_wm_layout(&filename)
+}
+
 #[tauri::command(async)]
 pub fn state_get_weg_items() -> WegItems {
     FULL_STATE.load().weg_items().clone()
@@ -44,6 +55,11 @@ pub fn state_get_settings(path: Option<PathBuf>) -> Result<Settings> {
     }
 }
 
+#[tauri::command(async)]
+pub fn state_validate_config() -> Vec<ConfigIssue> {
+    FULL_STATE.load().validate_config()
+}
+
 #[tauri::command(async)]
 pub fn state_get_specific_apps_configurations() -> Vec<AppConfig> {
     FULL_STATE
@@ -54,6 +70,27 @@ pub fn state_get_specific_apps_configurations() -> Vec<AppConfig> {
         .collect_vec()
 }
 
+/// Dry run of the app configs, using the window or the given exe, title, class and path
+#[tauri::command(async)]
+pub fn match_window_rules(
+    hwnd: Option<isize>,
+    exe: Option<String>,
+    title: Option<String>,
+    class: Option<String>,
+    path: Option<String>,
+) -> Result<RuleMatchReport> {
+    let state = FULL_STATE.load();
+    if let Some(hwnd) = hwnd {
+        return state.match_rules_by_window(HWND(hwnd));
+    }
+    Ok(state.match_rules(
+        &title.unwrap_or_default(),
+        &class.unwrap_or_default(),
+        &exe.unwrap_or_default(),
+        &path.unwrap_or_default(),
+    ))
+}
+
 #[tauri::command(async)]
 pub fn state_get_wallpaper() -> Result<PathBuf> {
     WindowsApi::get_wallpaper()
@@ -1,23 +1,137 @@
 pub mod application;
+pub mod cli;
 pub mod domain;
 pub mod infrastructure;
 
 use std::collections::HashMap;
 
 use application::FullState;
-use domain::AhkVar;
+use domain::{AhkVar, WmInterop};
+
+use crate::modules::conflicts::{domain::Mitigation, ConflictDetector};
+use crate::modules::shell::ShellMode;
 
 impl FullState {
     pub fn is_weg_enabled(&self) -> bool {
         self.settings().seelenweg.enabled
     }
 
+    pub fn is_weg_suspension_watcher_enabled(&self) -> bool {
+        self.is_weg_enabled() && self.settings().seelenweg.show_suspended_uwp
+    }
+
+    pub fn is_weg_background_apps_enabled(&self) -> bool {
+        self.is_weg_enabled() && self.settings().seelenweg.show_background_apps
+    }
+
+    pub fn is_weg_resource_usage_enabled(&self) -> bool {
+        self.is_weg_enabled() && self.settings().seelenweg.resource_usage
+    }
+
     pub fn is_bar_enabled(&self) -> bool {
         self.settings().fancy_toolbar.enabled
     }
 
+    /// the seelen tiling is replaced by the external window manager on interop mode,
+    /// and not started while another tiling window manager is running
     pub fn is_window_manager_enabled(&self) -> bool {
-        self.settings().window_manager.enabled
+        let settings = &self.settings().window_manager;
+        settings.enabled
+            && settings.interop == WmInterop::None
+            && !self.is_conflict_mitigated(Mitigation::SkipTiling)
+    }
+
+    pub fn is_conflict_mitigated(&self, mitigation: Mitigation) -> bool {
+        ConflictDetector::is_mitigated(&self.settings().conflicts, mitigation)
+    }
+
+    pub fn is_gestures_enabled(&self) -> bool {
+        self.settings().gestures.enabled
+    }
+
+    pub fn is_idle_monitor_enabled(&self) -> bool {
+        self.settings().idle.enabled
+    }
+
+    pub fn is_desktop_icons_enabled(&self) -> bool {
+        self.settings().desktop_icons.enabled || ShellMode::is_active()
+    }
+
+    pub fn is_osd_enabled(&self) -> bool {
+        self.settings().osd.enabled
+    }
+
+    pub fn is_osd_keys_interception_enabled(&self) -> bool {
+        self.settings().osd.enabled && self.settings().osd.intercept_keys
+    }
+
+    pub fn is_theme_schedule_enabled(&self) -> bool {
+        self.settings().theme_schedule.enabled
+    }
+
+    pub fn is_automation_enabled(&self) -> bool {
+        self.settings().automation.enabled
+    }
+
+    pub fn is_session_restore_enabled(&self) -> bool {
+        self.settings().session_restore.enabled
+    }
+
+    pub fn is_pen_enabled(&self) -> bool {
+        self.settings().pen.enabled
+    }
+
+    pub fn is_mouse_buttons_enabled(&self) -> bool {
+        self.settings().mouse_buttons.enabled
+    }
+
+    pub fn is_snap_flyout_replaced(&self) -> bool {
+        self.settings().zones.replace_snap_flyout
+    }
+
+    pub fn is_snippets_enabled(&self) -> bool {
+        self.settings().snippets.enabled
+    }
+
+    pub fn is_auto_workspace_names_enabled(&self) -> bool {
+        self.settings().window_manager.auto_workspace_names
+    }
+
+    pub fn is_game_mode_enabled(&self) -> bool {
+        self.settings().game_mode.enabled
+    }
+
+    pub fn is_remote_api_enabled(&self) -> bool {
+        self.settings().remote_api.enabled
+    }
+
+    pub fn is_presence_enabled(&self) -> bool {
+        self.settings().presence.enabled
+    }
+
+    pub fn is_data_bridge_enabled(&self) -> bool {
+        self.settings().data_bridge.enabled
+    }
+
+    pub fn is_do_not_disturb_enabled(&self) -> bool {
+        self.settings().do_not_disturb.enabled
+    }
+
+    pub fn is_app_usage_enabled(&self) -> bool {
+        self.settings().app_usage.enabled
+    }
+
+    pub fn is_focus_sessions_enabled(&self) -> bool {
+        self.settings().focus_sessions.enabled
+    }
+
+    pub fn is_alerts_enabled(&self) -> bool {
+        self.settings().alerts.enabled
+    }
+
+    pub fn is_quiet_launch_enabled(&self) -> bool {
+        let settings = &self.settings().quiet_launch;
+        settings.enabled && !settings.apps.is_empty()
     }
 
     pub fn is_ahk_enabled(&self) -> bool {
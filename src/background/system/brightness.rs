@@ -1,9 +1,22 @@
 use serde::Serialize;
-use windows::Win32::Devices::Display::{
-    GetMonitorBrightness, GetMonitorCapabilities, SetMonitorBrightness,
+use windows::Win32::{
+    Devices::Display::{
+        DestroyPhysicalMonitor, GetMonitorBrightness, GetMonitorCapabilities,
+        GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR,
+        GetVCPFeatureAndVCPFeatureReply, SetMonitorBrightness, SetVCPFeature, PHYSICAL_MONITOR,
+    },
+    Graphics::Gdi::HMONITOR,
 };
 
-use crate::windows_api::WindowsApi;
+use crate::{
+    error_handler::Result as AppResult,
+    log_error,
+    state::application::FULL_STATE,
+    windows_api::{MonitorEnumerator, WindowsApi},
+};
+
+/// luminance control of the MCCS standard, read and written through DDC/CI
+const VCP_BRIGHTNESS: u8 = 0x10;
 
 #[derive(Debug, Serialize)]
 pub struct Brightness {
@@ -12,6 +25,14 @@ pub struct Brightness {
     current: u32,
 }
 
+impl Brightness {
+    /// current brightness as percent of the range
+    pub fn percent(&self) -> u32 {
+        let range = self.max.saturating_sub(self.min).max(1);
+        self.current.saturating_sub(self.min) * 100 / range
+    }
+}
+
 #[tauri::command(async)]
 pub fn get_main_monitor_brightness() -> Result<Brightness, String> {
     let mut brightness = Brightness {
@@ -50,6 +71,19 @@ pub fn get_main_monitor_brightness() -> Result<Brightness, String> {
     Ok(brightness)
 }
 
+/// Changes the brightness of the main monitor by `percent` of its range, or of all the
+/// monitors if the brightness is linked
+pub fn change_main_monitor_brightness(percent: i32) -> Result<(), String> {
+    if FULL_STATE.load().settings().brightness.linked {
+        return change_all_brightness(percent).map_err(|err| format!("{:?}", err));
+    }
+    let brightness = get_main_monitor_brightness()?;
+    let range = (brightness.max - brightness.min) as i32;
+    let target = (brightness.current as i32 + range * percent / 100)
+        .clamp(brightness.min as i32, brightness.max as i32);
+    set_main_monitor_brightness(target as u32)
+}
+
 #[tauri::command(async)]
 pub fn set_main_monitor_brightness(brightness: u32) -> Result<(), String> {
     let result = unsafe {
@@ -61,3 +95,156 @@ pub fn set_main_monitor_brightness(brightness: u32) -> Result<(), String> {
     }
     Ok(())
 }
+
+/// Brightness of a physical monitor controlled through DDC/CI
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorBrightness {
+    /// device name of the display and index of the physical monitor, e.g. `DISPLAY1/0`
+    id: String,
+    name: String,
+    current: u32,
+    max: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrightnessState {
+    linked: bool,
+    monitors: Vec<MonitorBrightness>,
+}
+
+/// Physical monitors of a display, their handles are released on drop
+struct PhysicalMonitors(Vec<PHYSICAL_MONITOR>);
+
+impl PhysicalMonitors {
+    fn of(hmonitor: HMONITOR) -> AppResult<Self> {
+        let mut count: u32 = 0;
+        let mut monitors = Vec::new();
+        unsafe {
+            GetNumberOfPhysicalMonitorsFromHMONITOR(hmonitor, &mut count)?;
+            monitors.resize(count as usize, std::mem::zeroed());
+            GetPhysicalMonitorsFromHMONITOR(hmonitor, monitors.as_mut())?;
+        }
+        Ok(Self(monitors))
+    }
+}
+
+impl Drop for PhysicalMonitors {
+    fn drop(&mut self) {
+        for monitor in &self.0 {
+            let _ = unsafe { DestroyPhysicalMonitor(monitor.hPhysicalMonitor) };
+        }
+    }
+}
+
+/// `(current, max)` of the monitor, `None` if it doesn't support DDC/CI as most built-in panels
+fn read_vcp_brightness(monitor: &PHYSICAL_MONITOR) -> Option<(u32, u32)> {
+    let (mut current, mut max) = (0, 0);
+    let ok = unsafe {
+        GetVCPFeatureAndVCPFeatureReply(
+            monitor.hPhysicalMonitor,
+            VCP_BRIGHTNESS,
+            None,
+            &mut current,
+            Some(&mut max),
+        )
+    };
+    (ok != 0 && max > 0).then_some((current, max))
+}
+
+fn write_vcp_brightness(monitor: &PHYSICAL_MONITOR, value: u32) -> AppResult<()> {
+    if unsafe { SetVCPFeature(monitor.hPhysicalMonitor, VCP_BRIGHTNESS, value) } == 0 {
+        return Err("SetVCPFeature failed".into());
+    }
+    Ok(())
+}
+
+/// Calls `f` with each monitor supporting DDC/CI. The communication is slow (tens of
+/// milliseconds per call), so this should not run on the ui thread.
+fn for_each_monitor(mut f: impl FnMut(&PHYSICAL_MONITOR, MonitorBrightness)) -> AppResult<()> {
+    for hmonitor in MonitorEnumerator::new_refreshed()? {
+        let display = WindowsApi::monitor_name(hmonitor)?;
+        let physical = PhysicalMonitors::of(hmonitor)?;
+        for (index, monitor) in physical.0.iter().enumerate() {
+            let Some((current, max)) = read_vcp_brightness(monitor) else {
+                continue;
+            };
+            let name = String::from_utf16_lossy(&monitor.szPhysicalMonitorDescription);
+            let brightness = MonitorBrightness {
+                id: format!("{}/{}", display, index),
+                name: name.trim_end_matches('\0').trim().to_string(),
+                current,
+                max,
+            };
+            f(monitor, brightness);
+        }
+    }
+    Ok(())
+}
+
+fn scale(value: u32, from_max: u32, to_max: u32) -> u32 {
+    (value as u64 * to_max as u64 / from_max.max(1) as u64) as u32
+}
+
+/// Changes the brightness of all the monitors by `percent` of their range
+fn change_all_brightness(percent: i32) -> AppResult<()> {
+    for_each_monitor(|monitor, brightness| {
+        let delta = brightness.max as i32 * percent / 100;
+        let target = (brightness.current as i32 + delta).clamp(0, brightness.max as i32);
+        log_error!(write_vcp_brightness(monitor, target as u32));
+    })
+}
+
+#[tauri::command(async)]
+pub fn brightness_get_state() -> AppResult<BrightnessState> {
+    let mut monitors = Vec::new();
+    for_each_monitor(|_, brightness| monitors.push(brightness))?;
+    Ok(BrightnessState {
+        linked: FULL_STATE.load().settings().brightness.linked,
+        monitors,
+    })
+}
+
+/// Sets the brightness of the monitor, if linked the others are set to the same percent
+#[tauri::command(async)]
+pub fn brightness_set(id: String, value: u32) -> AppResult<()> {
+    let linked = FULL_STATE.load().settings().brightness.linked;
+    // the range of the monitor is needed to apply the same percent to the others
+    let mut source_max = None;
+    if linked {
+        for_each_monitor(|_, brightness| {
+            if brightness.id == id {
+                source_max = Some(brightness.max);
+            }
+        })?;
+    }
+
+    let mut found = false;
+    let mut result = Ok(());
+    // the handles are only valid during the enumeration, so the values are written on it
+    for_each_monitor(|monitor, brightness| {
+        let target = if brightness.id == id {
+            found = true;
+            value.min(brightness.max)
+        } else if let Some(source_max) = source_max {
+            scale(value, source_max, brightness.max)
+        } else {
+            return;
+        };
+        if let Err(err) = write_vcp_brightness(monitor, target) {
+            result = Err(err);
+        }
+    })?;
+
+    if !found {
+        return Err(format!("Monitor not found: {id}").into());
+    }
+    result
+}
+
+#[tauri::command(async)]
+pub fn brightness_set_linked(linked: bool) -> AppResult<()> {
+    FULL_STATE.load().cloned().set_brightness_linked(linked)?;
+    Ok(())
+}
@@ -6,13 +6,20 @@ use crate::{
     error_handler::Result,
     log_error,
     modules::{
+        data_sources::DataSources,
+        devices::DeviceWatcher,
         media::infrastructure::{register_media_events, release_media_events},
+        metrics::gpu::GpuMonitor,
         network::infrastructure::register_network_events,
         notifications::infrastructure::{
             register_notification_events, release_notification_events,
         },
         power::infrastructure::PowerManager,
-        system_settings::infrastructure::{register_colors_events, release_colors_events},
+        printing::PrintManager,
+        storage::StorageMonitor,
+        system_settings::infrastructure::{
+            register_colors_events, register_wallpaper_palette_events, release_colors_events,
+        },
         tray::infrastructure::register_tray_events,
     },
     seelen::get_app_handle,
@@ -32,6 +39,23 @@ pub fn declare_system_events_handlers() -> Result<()> {
         log_error!(register_network_events());
     });
 
+    handle.listen("register-device-events", move |_| {
+        log_error!(DeviceWatcher::register_device_events());
+        log_error!(DeviceWatcher::emit_removable_drives());
+    });
+
+    handle.listen("register-storage-events", move |_| {
+        log_error!(StorageMonitor::start());
+    });
+
+    handle.listen("register-gpu-events", move |_| {
+        log_error!(GpuMonitor::start());
+    });
+
+    handle.listen("register-printer-events", move |_| {
+        log_error!(PrintManager::register_printer_events());
+    });
+
     handle.listen("register-bluetooth-events", move |_| {
         // todo
     });
@@ -48,6 +72,14 @@ pub fn declare_system_events_handlers() -> Result<()> {
         register_colors_events();
     });
 
+    handle.listen("register-wallpaper-palette-events", move |_| {
+        log_error!(register_wallpaper_palette_events());
+    });
+
+    handle.listen("register-data-sources-events", move |_| {
+        log_error!(DataSources::start());
+    });
+
     Ok(())
 }
 
@@ -55,4 +87,7 @@ pub fn release_system_events_handlers() {
     release_media_events();
     release_notification_events();
     release_colors_events();
+    StorageMonitor::stop();
+    GpuMonitor::stop();
+    DataSources::stop();
 }
@@ -0,0 +1,594 @@
+//! Simulated desktop used by the tests. It replaces the `WindowsBackend` and the `ShellHost`
+//! of the test thread, so the win events can be sent through the real hook dispatch to the
+//! dock and the window manager without a session or an app handle.
+
+pub mod replay;
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::atomic::{AtomicIsize, Ordering},
+};
+
+use seelen_core::state::{AppExtraFlag, FocusedAppBehavior, PopupFilterSettings, Settings};
+use serde::Serialize;
+use serde_json::Value;
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{
+        MINMAXINFO, SHOW_WINDOW_CMD, SW_HIDE, WINDOW_EX_STYLE, WINDOW_STYLE, WS_CAPTION,
+        WS_EX_APPWINDOW, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_POPUP, WS_VISIBLE,
+    },
+};
+
+use crate::{
+    error_handler::Result,
+    hook::HookManager,
+    host::{set_thread_host, ShellHost, Surface},
+    modules::window_titles::normalize_with,
+    seelen_weg::{
+        rules::{RuleContext, WegVerdict},
+        SeelenWeg, WegAppInfo,
+    },
+    seelen_wm::WindowManager,
+    windows_api::{
+        backend::{set_thread_backend, WindowsBackend},
+        window::Window,
+    },
+    winevent::WinEvent,
+};
+
+/// handles are unique across the tests as some modules keep global state by handle
+static NEXT_HANDLE: AtomicIsize = AtomicIsize::new(0x10000);
+
+/// monitor of the windows unless other is set
+pub const PRIMARY_MONITOR: HMONITOR = HMONITOR(1);
+pub const WORK_AREA: RECT = RECT {
+    left: 0,
+    top: 0,
+    right: 1920,
+    bottom: 1040,
+};
+pub const DESKTOP_ID: &str = "mock-desktop";
+
+#[derive(Debug, Clone)]
+pub struct MockWindow {
+    pub title: String,
+    pub class: String,
+    pub exe: Option<PathBuf>,
+    pub parent: isize,
+    pub visible: bool,
+    pub styles: WINDOW_STYLE,
+    pub ex_styles: WINDOW_EX_STYLE,
+    pub rect: RECT,
+    pub suspended: bool,
+    pub minimized: bool,
+    pub maximized: bool,
+    pub arranged: bool,
+    pub cloaked: bool,
+    pub monitor: HMONITOR,
+}
+
+impl MockWindow {
+    /// visible top-level window with a title bar, as most apps
+    pub fn app(exe: &str, title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            class: "MockAppWindow".to_string(),
+            exe: Some(PathBuf::from(exe)),
+            parent: 0,
+            visible: true,
+            styles: WS_VISIBLE | WS_CAPTION,
+            ex_styles: WS_EX_APPWINDOW,
+            rect: RECT {
+                left: 100,
+                top: 100,
+                right: 900,
+                bottom: 700,
+            },
+            suspended: false,
+            minimized: false,
+            maximized: false,
+            arranged: false,
+            cloaked: false,
+            monitor: PRIMARY_MONITOR,
+        }
+    }
+
+    /// borderless popup, as splash screens and overlays
+    pub fn popup(exe: &str) -> Self {
+        Self {
+            styles: WS_VISIBLE | WS_POPUP,
+            ex_styles: WINDOW_EX_STYLE::default(),
+            ..Self::app(exe, "")
+        }
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn class(mut self, class: &str) -> Self {
+        self.class = class.to_string();
+        self
+    }
+
+    pub fn child_of(mut self, parent: HWND) -> Self {
+        self.parent = parent.0;
+        self
+    }
+
+    pub fn ex_styles(mut self, ex_styles: WINDOW_EX_STYLE) -> Self {
+        self.ex_styles = ex_styles;
+        self
+    }
+
+    pub fn rect(mut self, rect: RECT) -> Self {
+        self.rect = rect;
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+
+    pub fn suspended(mut self) -> Self {
+        self.suspended = true;
+        self
+    }
+
+    pub fn on_monitor(mut self, monitor: HMONITOR) -> Self {
+        self.monitor = monitor;
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct MockDesktop {
+    windows: RefCell<HashMap<isize, MockWindow>>,
+    /// every handle created on this desktop, in creation order
+    created: RefCell<Vec<isize>>,
+    foreground: Cell<isize>,
+}
+
+impl MockDesktop {
+    /// Creates an empty desktop and installs it as the backend of the current thread
+    pub fn install() -> Rc<Self> {
+        let desktop = Rc::new(Self::default());
+        set_thread_backend(Some(desktop.clone()));
+        desktop
+    }
+
+    pub fn add(&self, window: MockWindow) -> HWND {
+        let hwnd = NEXT_HANDLE.fetch_add(1, Ordering::SeqCst);
+        self.windows.borrow_mut().insert(hwnd, window);
+        self.created.borrow_mut().push(hwnd);
+        HWND(hwnd)
+    }
+
+    pub fn update(&self, hwnd: HWND, cb: impl FnOnce(&mut MockWindow)) {
+        if let Some(window) = self.windows.borrow_mut().get_mut(&hwnd.0) {
+            cb(window);
+        }
+    }
+
+    pub fn remove(&self, hwnd: HWND) {
+        self.windows.borrow_mut().remove(&hwnd.0);
+    }
+
+    /// true if the handle was created on this desktop, even if it was already removed
+    pub fn created(&self, hwnd: isize) -> bool {
+        self.created.borrow().contains(&hwnd)
+    }
+
+    pub fn set_foreground(&self, hwnd: HWND) {
+        self.foreground.set(hwnd.0);
+    }
+
+    fn get<T: Default>(&self, hwnd: HWND, cb: impl FnOnce(&MockWindow) -> T) -> T {
+        self.windows
+            .borrow()
+            .get(&hwnd.0)
+            .map(cb)
+            .unwrap_or_default()
+    }
+}
+
+impl WindowsBackend for MockDesktop {
+    fn title(&self, hwnd: HWND) -> String {
+        self.get(hwnd, |w| w.title.clone())
+    }
+
+    fn class(&self, hwnd: HWND) -> String {
+        self.get(hwnd, |w| w.class.clone())
+    }
+
+    fn exe(&self, hwnd: HWND) -> Result<PathBuf> {
+        self.get(hwnd, |w| w.exe.clone())
+            .ok_or_else(|| "exe path is empty".into())
+    }
+
+    fn parent(&self, hwnd: HWND) -> HWND {
+        HWND(self.get(hwnd, |w| w.parent))
+    }
+
+    fn children(&self, hwnd: HWND) -> Result<Vec<HWND>> {
+        let mut children: Vec<HWND> = self
+            .windows
+            .borrow()
+            .iter()
+            .filter(|(_, w)| w.parent == hwnd.0)
+            .map(|(handle, _)| HWND(*handle))
+            .collect();
+        // enumeration order is the creation order
+        children.sort_by_key(|child| child.0);
+        Ok(children)
+    }
+
+    fn is_visible(&self, hwnd: HWND) -> bool {
+        self.get(hwnd, |w| w.visible)
+    }
+
+    fn styles(&self, hwnd: HWND) -> WINDOW_STYLE {
+        self.get(hwnd, |w| w.styles)
+    }
+
+    fn ex_styles(&self, hwnd: HWND) -> WINDOW_EX_STYLE {
+        self.get(hwnd, |w| w.ex_styles)
+    }
+
+    fn rect(&self, hwnd: HWND) -> RECT {
+        self.get(hwnd, |w| w.rect)
+    }
+
+    fn is_uwp_suspended(&self, hwnd: HWND) -> bool {
+        self.get(hwnd, |w| w.suspended)
+    }
+
+    fn is_window(&self, hwnd: HWND) -> bool {
+        self.windows.borrow().contains_key(&hwnd.0)
+    }
+
+    fn is_iconic(&self, hwnd: HWND) -> bool {
+        self.get(hwnd, |w| w.minimized)
+    }
+
+    fn is_maximized(&self, hwnd: HWND) -> bool {
+        self.get(hwnd, |w| w.maximized)
+    }
+
+    fn is_arranged(&self, hwnd: HWND) -> bool {
+        self.get(hwnd, |w| w.arranged)
+    }
+
+    fn is_cloaked(&self, hwnd: HWND) -> Result<bool> {
+        Ok(self.get(hwnd, |w| w.cloaked))
+    }
+
+    fn frame_rect(&self, hwnd: HWND) -> RECT {
+        self.get(hwnd, |w| w.rect)
+    }
+
+    fn shadow_rect(&self, _hwnd: HWND) -> Result<RECT> {
+        Ok(RECT::default())
+    }
+
+    fn min_max_info(&self, hwnd: HWND) -> Result<MINMAXINFO> {
+        if !self.is_window(hwnd) {
+            return Err("invalid window handle".into());
+        }
+        let mut info = MINMAXINFO::default();
+        info.ptMaxTrackSize.x = WORK_AREA.right;
+        info.ptMaxTrackSize.y = WORK_AREA.bottom;
+        Ok(info)
+    }
+
+    fn foreground(&self) -> HWND {
+        HWND(self.foreground.get())
+    }
+
+    fn monitor(&self, hwnd: HWND) -> HMONITOR {
+        self.get(hwnd, |w| w.monitor)
+    }
+
+    fn monitor_name(&self, monitor: HMONITOR) -> Result<String> {
+        Ok(format!("\\\\.\\DISPLAY{}", monitor.0))
+    }
+
+    fn work_area(&self, _monitor: HMONITOR) -> Result<RECT> {
+        Ok(WORK_AREA)
+    }
+
+    fn show_window_async(&self, hwnd: HWND, command: SHOW_WINDOW_CMD) -> Result<()> {
+        self.update(hwnd, |w| w.visible = command != SW_HIDE);
+        Ok(())
+    }
+
+    fn bring_to(&self, _hwnd: HWND, _after: HWND) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// State of the app for the tests, the app configs are matched by the exe file name
+#[derive(Default)]
+pub struct MockHost {
+    pub settings: RefCell<Settings>,
+    flags: RefCell<HashMap<String, Vec<AppExtraFlag>>>,
+    focused_behaviors: RefCell<HashMap<String, FocusedAppBehavior>>,
+    /// events sent to the webviews, with their payload
+    events: RefCell<Vec<(String, Value)>>,
+}
+
+impl MockHost {
+    /// Creates the host and installs it on the current thread
+    pub fn install() -> Rc<Self> {
+        let host = Rc::new(Self::default());
+        set_thread_host(Some(host.clone()));
+        host
+    }
+
+    pub fn set_app_flags(&self, exe_name: &str, flags: Vec<AppExtraFlag>) {
+        self.flags
+            .borrow_mut()
+            .insert(exe_name.to_lowercase(), flags);
+    }
+
+    pub fn set_weg_while_focused(&self, exe_name: &str, behavior: FocusedAppBehavior) {
+        self.focused_behaviors
+            .borrow_mut()
+            .insert(exe_name.to_lowercase(), behavior);
+    }
+
+    /// payloads of `event`, in the order they were emitted
+    pub fn emitted(&self, event: &str) -> Vec<Value> {
+        self.events
+            .borrow()
+            .iter()
+            .filter(|(name, _)| name == event)
+            .map(|(_, payload)| payload.clone())
+            .collect()
+    }
+
+    fn record(&self, event: &str, payload: Value) {
+        self.events.borrow_mut().push((event.to_string(), payload));
+    }
+
+    fn exe_name(window: &Window) -> Option<String> {
+        let exe = window.exe().ok()?;
+        Some(exe.file_name()?.to_string_lossy().to_lowercase())
+    }
+}
+
+impl ShellHost for MockHost {
+    fn emit(&self, event: &str, payload: Value) -> Result<()> {
+        self.record(event, payload);
+        Ok(())
+    }
+
+    fn popup_filter(&self) -> PopupFilterSettings {
+        self.settings.borrow().popup_filter.clone()
+    }
+
+    fn normalize_title(&self, title: &str) -> String {
+        normalize_with(title, &self.settings.borrow().window_titles)
+    }
+
+    fn weg_verdict(&self, window: &Window) -> WegVerdict {
+        let hidden_by_config =
+            |window: &Window| self.app_flags(window).contains(&AppExtraFlag::Hidden);
+        let settings = self.settings.borrow();
+        let ctx = RuleContext {
+            settings: &settings,
+            hidden_by_config: &hidden_by_config,
+        };
+        WegVerdict::evaluate_with(window, &ctx)
+    }
+
+    fn weg_app_info(&self, exe: Option<&Path>) -> WegAppInfo {
+        let Some(exe) = exe else {
+            return WegAppInfo {
+                icon_path: "missing.png".to_string(),
+                ..Default::default()
+            };
+        };
+        WegAppInfo {
+            display_name: exe
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            icon_path: "icon.png".to_string(),
+            execution_path: exe.to_string_lossy().to_string(),
+        }
+    }
+
+    fn app_flags(&self, window: &Window) -> Vec<AppExtraFlag> {
+        Self::exe_name(window)
+            .and_then(|exe| self.flags.borrow().get(&exe).cloned())
+            .unwrap_or_default()
+    }
+
+    fn weg_while_focused(&self, window: &Window) -> Option<FocusedAppBehavior> {
+        Self::exe_name(window).and_then(|exe| self.focused_behaviors.borrow().get(&exe).copied())
+    }
+
+    fn current_desktop(&self) -> Result<String> {
+        Ok(DESKTOP_ID.to_string())
+    }
+
+    fn desktop_of(&self, _window: &Window) -> Result<String> {
+        Ok(DESKTOP_ID.to_string())
+    }
+
+    fn is_pinned_to_all_desktops(&self, _window: &Window) -> bool {
+        false
+    }
+
+    fn uses_cloak(&self) -> bool {
+        false
+    }
+}
+
+/// Webview of the dock or the window manager, it is a window of the simulated desktop so
+/// its rect can be queried as the one of the real webview.
+pub struct MockSurface {
+    label: String,
+    hwnd: HWND,
+    host: Rc<MockHost>,
+}
+
+impl Surface for MockSurface {
+    fn name(&self) -> &str {
+        &self.label
+    }
+
+    fn handle(&self) -> Result<HWND> {
+        Ok(self.hwnd)
+    }
+
+    fn emit_self<S: Serialize + Clone>(&self, event: &str, payload: S) -> Result<()> {
+        let payload = serde_json::to_value(payload)?;
+        self.host.record(event, payload);
+        Ok(())
+    }
+
+    fn close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Sends synthetic win events through `HookManager::dispatch_to_shell`, as the hook does,
+/// to the real dock and window manager of a single monitor.
+pub struct Simulation {
+    pub desktop: Rc<MockDesktop>,
+    pub host: Rc<MockHost>,
+    pub weg: Option<SeelenWeg<MockSurface>>,
+    pub wm: Option<WindowManager<MockSurface>>,
+    pub weg_enabled: bool,
+}
+
+impl Simulation {
+    const SEELEN_EXE: &'static str = "C:\\Program Files\\Seelen\\seelen-ui.exe";
+
+    pub fn new() -> Self {
+        Self {
+            desktop: MockDesktop::install(),
+            host: MockHost::install(),
+            weg: None,
+            wm: None,
+            weg_enabled: true,
+        }
+    }
+
+    fn surface(&self, label: &str, title: &str, rect: RECT) -> MockSurface {
+        let window = MockWindow::app(Self::SEELEN_EXE, title)
+            .ex_styles(WS_EX_TOOLWINDOW | WS_EX_TOPMOST)
+            .rect(rect);
+        MockSurface {
+            label: label.to_string(),
+            hwnd: self.desktop.add(window),
+            host: self.host.clone(),
+        }
+    }
+
+    /// Adds the dock of the monitor, with its hitbox at the bottom of the work area
+    pub fn with_weg(mut self) -> Self {
+        let window = self.surface("seelenweg/mock", "SeelenWeg", WORK_AREA);
+        let hitbox = RECT {
+            top: WORK_AREA.bottom - 60,
+            ..WORK_AREA
+        };
+        let hitbox = self.surface("seelenweg-hitbox/mock", "SeelenWeg Hitbox", hitbox);
+        self.weg = Some(SeelenWeg::from_surfaces(window, hitbox));
+        self
+    }
+
+    /// Adds the window manager of the monitor
+    pub fn with_wm(mut self) -> Self {
+        let window = self.surface("window-manager/mock", "Seelen Window Manager", WORK_AREA);
+        self.wm = Some(WindowManager::from_surface(
+            window,
+            PRIMARY_MONITOR,
+            DESKTOP_ID,
+        ));
+        self
+    }
+
+    pub fn verdict(&self, hwnd: HWND) -> WegVerdict {
+        self.host.weg_verdict(&Window::from(hwnd))
+    }
+
+    /// Handles shown on the dock, in the order they were added
+    pub fn dock(&self) -> Vec<isize> {
+        SeelenWeg::open_handles()
+            .into_iter()
+            .filter(|hwnd| self.desktop.created(*hwnd))
+            .collect()
+    }
+
+    pub fn is_managed(&self, hwnd: HWND) -> bool {
+        self.wm.as_ref().is_some_and(|wm| wm.is_managed(hwnd))
+    }
+
+    /// Handles managed by the window manager, in the order they were added
+    pub fn managed(&self) -> Vec<isize> {
+        self.wm.as_ref().map(|wm| wm.handles()).unwrap_or_default()
+    }
+
+    /// Returns true if the window is managed after the event
+    pub fn emit(&mut self, event: WinEvent, hwnd: HWND) -> bool {
+        let shell = (self.weg.as_mut(), self.wm.as_mut());
+        HookManager::dispatch_to_shell(event, hwnd, self.weg_enabled, [shell])
+    }
+
+    /// Creates and shows the window
+    pub fn open(&mut self, window: MockWindow) -> HWND {
+        let hwnd = self.desktop.add(window);
+        self.emit(WinEvent::ObjectCreate, hwnd);
+        self.emit(WinEvent::ObjectShow, hwnd);
+        hwnd
+    }
+
+    /// Hides and destroys the window
+    pub fn close(&mut self, hwnd: HWND) {
+        self.desktop.update(hwnd, |window| window.visible = false);
+        self.emit(WinEvent::ObjectHide, hwnd);
+        self.emit(WinEvent::ObjectDestroy, hwnd);
+        self.desktop.remove(hwnd);
+    }
+
+    pub fn focus(&mut self, hwnd: HWND) {
+        self.desktop.set_foreground(hwnd);
+        self.emit(WinEvent::SystemForeground, hwnd);
+    }
+
+    pub fn minimize(&mut self, hwnd: HWND) {
+        self.desktop.update(hwnd, |window| window.minimized = true);
+        self.emit(WinEvent::SystemMinimizeStart, hwnd);
+    }
+
+    pub fn restore(&mut self, hwnd: HWND) {
+        self.desktop.update(hwnd, |window| window.minimized = false);
+        self.emit(WinEvent::SystemMinimizeEnd, hwnd);
+    }
+
+    /// Moves the window and sends the location change
+    pub fn move_to(&mut self, hwnd: HWND, rect: RECT) {
+        self.desktop.update(hwnd, |window| window.rect = rect);
+        self.emit(WinEvent::ObjectLocationChange, hwnd);
+    }
+}
+
+impl Drop for Simulation {
+    fn drop(&mut self) {
+        self.weg = None;
+        self.wm = None;
+        set_thread_backend(None);
+        set_thread_host(None);
+    }
+}
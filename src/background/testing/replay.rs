@@ -0,0 +1,310 @@
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{WINDOW_EX_STYLE, WINDOW_STYLE},
+};
+
+use crate::{
+    error_handler::Result,
+    modules::event_recorder::domain::{Trace, WindowSnapshot},
+    winevent::WinEvent,
+};
+
+use super::{MockWindow, Simulation, PRIMARY_MONITOR};
+
+/// Reproduces a trace dumped by the event recorder against the simulated desktop, so traces
+/// attached to bug reports can be saved on `testing/traces` and turned into regression tests.
+/// The events go through the same dispatch as the hook, so the dock and the window manager
+/// of the simulation react as they did on the recorded session.
+pub struct Replay {
+    trace: Trace,
+    /// recorded handles to the handles of the simulated desktop
+    handles: HashMap<isize, HWND>,
+    /// recorded monitors to the simulated ones, the first monitor of the trace is the one
+    /// of the simulation and the rest have no dock or window manager
+    monitors: HashMap<isize, HMONITOR>,
+    /// sleeps the recorded time between events, needed by time based rules as the popup filter
+    realtime: bool,
+}
+
+impl Replay {
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(Self {
+            trace: serde_json::from_str(json)?,
+            handles: HashMap::new(),
+            monitors: HashMap::new(),
+            realtime: false,
+        })
+    }
+
+    /// Loads `testing/traces/{name}.json`
+    pub fn load(name: &str) -> Self {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/background/testing/traces")
+            .join(format!("{}.json", name));
+        let json = std::fs::read_to_string(&path).expect("Failed to read trace");
+        Self::from_json(&json).expect("Failed to parse trace")
+    }
+
+    pub fn realtime(mut self) -> Self {
+        self.realtime = true;
+        self
+    }
+
+    /// Handle on the simulated desktop of a recorded window
+    pub fn handle(&self, recorded: isize) -> Option<HWND> {
+        self.handles.get(&recorded).copied()
+    }
+
+    fn translate(&self, recorded: &[isize]) -> Vec<isize> {
+        recorded
+            .iter()
+            .filter_map(|recorded| self.handle(*recorded))
+            .map(|hwnd| hwnd.0)
+            .collect()
+    }
+
+    /// Dock of the trace when it was dumped, translated to the simulated handles
+    pub fn recorded_dock(&self) -> Vec<isize> {
+        self.translate(&self.trace.dock)
+    }
+
+    /// Managed windows of the trace when it was dumped, translated to the simulated handles
+    pub fn recorded_managed(&self) -> Vec<isize> {
+        self.translate(&self.trace.managed)
+    }
+
+    pub fn run(&mut self, sim: &mut Simulation) {
+        let mut last_time = 0;
+        let events = std::mem::take(&mut self.trace.events);
+        for trace_event in &events {
+            if self.realtime {
+                let elapsed = trace_event.time.saturating_sub(last_time);
+                std::thread::sleep(Duration::from_millis(elapsed as u64));
+                last_time = trace_event.time;
+            }
+
+            let hwnd = self.apply(sim, trace_event.hwnd, &trace_event.window);
+            // events not consumed by the simulation still update the windows
+            let event = Self::parse_event(&trace_event.event);
+            if event == Some(WinEvent::SystemForeground) {
+                sim.desktop.set_foreground(hwnd);
+            }
+            if let Some(event) = event {
+                sim.emit(event, hwnd);
+            }
+            if event == Some(WinEvent::ObjectDestroy) {
+                sim.desktop.remove(hwnd);
+                self.handles.remove(&trace_event.hwnd);
+            }
+        }
+        self.trace.events = events;
+    }
+
+    /// Events handled by the dock and the window manager, the synthetic ones are not
+    /// recorded as the hook generates them from these.
+    fn parse_event(name: &str) -> Option<WinEvent> {
+        match name {
+            "ObjectCreate" => Some(WinEvent::ObjectCreate),
+            "ObjectShow" => Some(WinEvent::ObjectShow),
+            "ObjectHide" => Some(WinEvent::ObjectHide),
+            "ObjectDestroy" => Some(WinEvent::ObjectDestroy),
+            "ObjectNameChange" => Some(WinEvent::ObjectNameChange),
+            "ObjectParentChange" => Some(WinEvent::ObjectParentChange),
+            "ObjectLocationChange" => Some(WinEvent::ObjectLocationChange),
+            "ObjectFocus" => Some(WinEvent::ObjectFocus),
+            "SystemForeground" => Some(WinEvent::SystemForeground),
+            "SystemMinimizeStart" => Some(WinEvent::SystemMinimizeStart),
+            "SystemMinimizeEnd" => Some(WinEvent::SystemMinimizeEnd),
+            "SystemMoveSizeStart" => Some(WinEvent::SystemMoveSizeStart),
+            "SystemMoveSizeEnd" => Some(WinEvent::SystemMoveSizeEnd),
+            _ => None,
+        }
+    }
+
+    /// Maps the recorded handle, creating the window on the first event
+    fn handle_or_create(&mut self, sim: &Simulation, recorded: isize) -> HWND {
+        *self
+            .handles
+            .entry(recorded)
+            .or_insert_with(|| sim.desktop.add(MockWindow::app("", "").hidden()))
+    }
+
+    /// Traces of older versions have no monitor, their windows are on the simulated one
+    fn monitor(&mut self, recorded: isize) -> HMONITOR {
+        if recorded == 0 {
+            return PRIMARY_MONITOR;
+        }
+        let next = HMONITOR(PRIMARY_MONITOR.0 + self.monitors.len() as isize);
+        *self.monitors.entry(recorded).or_insert(next)
+    }
+
+    fn apply(&mut self, sim: &Simulation, recorded: isize, snapshot: &WindowSnapshot) -> HWND {
+        let hwnd = self.handle_or_create(sim, recorded);
+        let parent = match snapshot.parent {
+            0 => 0,
+            parent => self.handle_or_create(sim, parent).0,
+        };
+        let monitor = self.monitor(snapshot.monitor);
+        sim.desktop.update(hwnd, |window| {
+            *window = MockWindow {
+                title: snapshot.title.clone(),
+                class: snapshot.class.clone(),
+                exe: snapshot.exe.as_ref().map(PathBuf::from),
+                parent,
+                visible: snapshot.visible,
+                styles: WINDOW_STYLE(snapshot.styles),
+                ex_styles: WINDOW_EX_STYLE(snapshot.ex_styles),
+                rect: RECT {
+                    left: snapshot.rect[0],
+                    top: snapshot.rect[1],
+                    right: snapshot.rect[2],
+                    bottom: snapshot.rect[3],
+                },
+                suspended: snapshot.suspended,
+                minimized: snapshot.minimized,
+                maximized: snapshot.maximized,
+                arranged: snapshot.arranged,
+                cloaked: snapshot.cloaked,
+                monitor,
+            }
+        });
+        for (child, child_snapshot) in &snapshot.children {
+            self.apply(sim, *child, child_snapshot);
+        }
+        hwnd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Recorded event of an editor window with a title bar, `state` overrides its fields
+    fn editor(hwnd: isize, time: u128, event: &str, state: Value) -> Value {
+        let mut window = json!({
+            "title": "notes.txt - Editor", "class": "EditorWindow",
+            "exe": "C:\\Apps\\replay_editor.exe", "parent": 0, "visible": true,
+            "styles": 0x10C0_0000, "exStyles": 0x0004_0000, "rect": [100, 100, 900, 700],
+            "suspended": false, "monitor": 65537
+        });
+        if let (Some(window), Some(state)) = (window.as_object_mut(), state.as_object()) {
+            window.extend(state.clone());
+        }
+        json!({ "time": time, "event": event, "hwnd": hwnd, "window": window })
+    }
+
+    fn replay(events: Vec<Value>, dock: Vec<isize>, managed: Vec<isize>) -> Replay {
+        let trace = json!({
+            "version": "2.0.0",
+            "events": events,
+            "dock": dock,
+            "managed": managed,
+        });
+        Replay::from_json(&trace.to_string()).unwrap()
+    }
+
+    #[test]
+    fn frame_creator_attached_late() {
+        let mut sim = Simulation::new();
+        let mut replay = Replay::load("frame_creator_attached_late");
+        replay.run(&mut sim);
+        assert_eq!(sim.dock(), replay.recorded_dock());
+    }
+
+    #[test]
+    fn destroyed_windows_are_forgotten() {
+        let json = r#"{
+            "version": "1.10.0",
+            "events": [
+                { "time": 0, "event": "ObjectShow", "hwnd": 10, "window": {
+                    "title": "Paint", "class": "MSPaintApp", "exe": "C:\\Apps\\mspaint.exe",
+                    "parent": 0, "visible": true, "styles": 348061696, "exStyles": 262144,
+                    "rect": [0, 0, 800, 600], "suspended": false
+                } },
+                { "time": 5, "event": "ObjectDestroy", "hwnd": 10, "window": {
+                    "title": "", "class": "", "exe": null, "parent": 0, "visible": false,
+                    "styles": 0, "exStyles": 0, "rect": [0, 0, 0, 0], "suspended": false
+                } }
+            ],
+            "dock": []
+        }"#;
+        let mut sim = Simulation::new().with_wm();
+        let mut replay = Replay::from_json(json).unwrap().realtime();
+        replay.run(&mut sim);
+        assert!(sim.dock().is_empty());
+        assert!(sim.managed().is_empty());
+        assert!(replay.handle(10).is_none());
+    }
+
+    #[test]
+    fn minimized_windows_are_tiled_again_on_restore() {
+        let mut replay = replay(
+            vec![
+                editor(10, 0, "ObjectCreate", json!({ "visible": false })),
+                editor(10, 2, "ObjectShow", json!({})),
+                editor(10, 40, "SystemMinimizeStart", json!({ "minimized": true })),
+                editor(10, 90, "SystemMinimizeEnd", json!({})),
+            ],
+            vec![10],
+            vec![10],
+        );
+        let mut sim = Simulation::new().with_weg().with_wm();
+        replay.run(&mut sim);
+
+        let hwnd = replay.handle(10).unwrap();
+        assert_eq!(sim.dock(), replay.recorded_dock());
+        assert_eq!(sim.managed(), replay.recorded_managed());
+        assert_eq!(
+            sim.host.emitted("remove-window"),
+            vec![json!(hwnd.0)],
+            "minimizing should untile the window"
+        );
+    }
+
+    #[test]
+    fn snaps_and_overlap_of_the_focused_window() {
+        let mut replay = replay(
+            vec![
+                editor(10, 0, "ObjectShow", json!({})),
+                editor(10, 10, "SystemForeground", json!({})),
+                editor(
+                    10,
+                    50,
+                    "ObjectLocationChange",
+                    json!({ "arranged": true, "rect": [0, 0, 960, 1040] }),
+                ),
+            ],
+            vec![10],
+            vec![10],
+        );
+        let mut sim = Simulation::new().with_weg().with_wm();
+        replay.run(&mut sim);
+
+        assert_eq!(sim.managed(), replay.recorded_managed());
+        assert_eq!(sim.host.emitted("native-snap").len(), 1);
+        assert_eq!(sim.host.emitted("set-auto-hide"), vec![json!(true)]);
+    }
+
+    #[test]
+    fn windows_on_other_monitors_are_not_managed() {
+        let mut replay = replay(
+            vec![
+                editor(10, 0, "ObjectShow", json!({})),
+                editor(11, 30, "ObjectShow", json!({ "monitor": 131073 })),
+            ],
+            vec![10, 11],
+            vec![10],
+        );
+        let mut sim = Simulation::new().with_wm();
+        replay.run(&mut sim);
+
+        assert_eq!(sim.dock(), replay.recorded_dock());
+        assert_eq!(sim.managed(), replay.recorded_managed());
+        assert!(!sim.is_managed(replay.handle(11).unwrap()));
+    }
+}
@@ -10,8 +10,9 @@ use tauri::{
 
 use crate::error_handler::Result;
 use crate::log_error;
-use crate::seelen::Seelen;
-use crate::utils::sleep_millis;
+use crate::seelen::{Seelen, SEELEN};
+use crate::trace_lock;
+use crate::utils::{sleep_millis, spawn_named_thread};
 
 pub fn try_register_tray_icon(app: &mut App) -> Result<()> {
     log::trace!("registering tray icon");
@@ -38,8 +39,14 @@ fn register_tray_icon(app: &mut App) -> Result<()> {
 
     let quit = MenuItemBuilder::with_id("quit", "Quit").build(app)?;
 
-    let menu = MenuBuilder::new(app)
-        .item(&settings)
+    let minimal_mode = Seelen::is_minimal_mode();
+    let start = MenuItemBuilder::with_id("start", "Start Seelen UI").build(app)?;
+
+    let mut menu = MenuBuilder::new(app).item(&settings);
+    if minimal_mode {
+        menu = menu.item(&start);
+    }
+    let menu = menu
         .separator()
         .item(&toggle_pause)
         .item(&restart)
@@ -52,13 +59,26 @@ fn register_tray_icon(app: &mut App) -> Result<()> {
             "static/icons/32x32.png",
             BaseDirectory::Resource,
         )?)?)
-        .tooltip("Seelen UI")
+        .tooltip(if Seelen::is_safe_mode() {
+            "Seelen UI (Safe Mode)"
+        } else if minimal_mode {
+            "Seelen UI (Minimal Mode)"
+        } else {
+            "Seelen UI"
+        })
         .menu(&menu)
         .on_menu_event(
             move |app: &AppHandle, event: MenuEvent| match event.id().as_ref() {
                 "settings" => {
                     log_error!(Seelen::show_settings());
                 }
+                "start" => {
+                    log_error!(start.set_enabled(false));
+                    // started out of the event loop, the shell creates its windows on it
+                    log_error!(spawn_named_thread("Leave Minimal Mode", || {
+                        log_error!(trace_lock!(SEELEN).leave_minimal_mode());
+                    }));
+                }
                 "pause" => {}
                 "restart" => app.restart(),
                 "quit" => app.exit(0),
@@ -5,7 +5,10 @@ use regex::Regex;
 use tauri::{path::BaseDirectory, Manager};
 use tauri_plugin_shell::ShellExt;
 
-use crate::{error_handler::Result, seelen::get_app_handle, state::domain::AhkVar};
+use crate::{
+    error_handler::Result, seelen::get_app_handle, state::domain::AhkVar,
+    utils::app_local_data_path,
+};
 
 lazy_static! {
     pub static ref LIB_AHK_PATH: PathBuf = {
@@ -56,8 +59,7 @@ impl AutoHotKey {
 
     pub fn save(&self) -> Result<PathBuf> {
         let script_path = if let Some(name) = &self.name {
-            let handle = get_app_handle();
-            handle.path().app_local_data_dir()?.join(name)
+            app_local_data_path(&get_app_handle())?.join(name)
         } else {
             temp_dir().join(format!("slu-{}.ahk", uuid::Uuid::new_v4()))
         };
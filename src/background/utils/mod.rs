@@ -1,10 +1,9 @@
 pub mod ahk;
 pub mod constants;
+pub mod overlay;
 pub mod pwsh;
+pub mod throttle;
 pub mod virtual_desktop;
-mod winver;
-
-pub use winver::*;
 
 use std::{
     collections::HashMap,
@@ -15,6 +14,7 @@ use std::{
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use parking_lot::Mutex;
+use serde::Serialize;
 use tauri::{AppHandle, Manager};
 use windows::{
     core::GUID,
@@ -25,6 +25,7 @@ use windows::{
 };
 
 use crate::error_handler::Result;
+use crate::modules::portable::Portable;
 
 pub fn pcwstr(s: &str) -> windows::core::PCWSTR {
     windows::core::PCWSTR::from_raw(s.encode_utf16().chain(Some(0)).collect_vec().as_ptr())
@@ -102,12 +103,37 @@ pub fn resolve_guid_path<S: AsRef<str>>(path: S) -> Result<PathBuf> {
 }
 
 pub fn app_data_path(handle: &AppHandle) -> PathBuf {
+    if let Some(dir) = Portable::data_dir() {
+        return dir.clone();
+    }
     handle
         .path()
         .app_data_dir()
         .expect("Failed to resolve App Data path")
 }
 
+/// Local app data is only used for generated files, portable mode shares the data folder
+pub fn app_local_data_path(handle: &AppHandle) -> Result<PathBuf> {
+    match Portable::data_dir() {
+        Some(dir) => Ok(dir.clone()),
+        None => Ok(handle.path().app_local_data_dir()?),
+    }
+}
+
+pub fn app_cache_path(handle: &AppHandle) -> Result<PathBuf> {
+    match Portable::cache_dir() {
+        Some(dir) => Ok(dir),
+        None => Ok(handle.path().app_cache_dir()?),
+    }
+}
+
+pub fn app_log_path(handle: &AppHandle) -> Result<PathBuf> {
+    match Portable::log_dir() {
+        Some(dir) => Ok(dir),
+        None => Ok(handle.path().app_log_dir()?),
+    }
+}
+
 #[macro_export]
 macro_rules! trace_lock {
     ($mutex:expr) => {{
@@ -136,14 +162,27 @@ macro_rules! trace_lock {
 lazy_static! {
     pub static ref PERFORMANCE_HELPER: Mutex<PerformanceHelper> = Mutex::new(PerformanceHelper {
         time: HashMap::new(),
+        spans: Vec::new(),
     });
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct TimingSpan {
+    pub name: String,
+    /// since the process started (the `init` span)
+    pub start: Duration,
+    pub duration: Duration,
+}
+
 pub struct PerformanceHelper {
     time: HashMap<String, Instant>,
+    spans: Vec<TimingSpan>,
 }
 
 impl PerformanceHelper {
+    /// spans recorded after startup (refreshes) are not kept forever
+    const MAX_SPANS: usize = 100;
+
     pub fn start(&mut self, name: &str) {
         self.time.insert(name.to_string(), Instant::now());
     }
@@ -151,6 +190,34 @@ impl PerformanceHelper {
     pub fn elapsed(&self, name: &str) -> Duration {
         self.time.get(name).unwrap().elapsed()
     }
+
+    /// Records the span started by `start(name)` and returns its duration
+    pub fn end(&mut self, name: &str) -> Duration {
+        let now = Instant::now();
+        let started = self.time.get(name).copied().unwrap_or(now);
+        let origin = self.time.get("init").copied().unwrap_or(started);
+        let duration = now.duration_since(started);
+        if self.spans.len() < Self::MAX_SPANS {
+            self.spans.push(TimingSpan {
+                name: name.to_string(),
+                start: started.saturating_duration_since(origin),
+                duration,
+            });
+        }
+        duration
+    }
+
+    pub fn spans(&self) -> Vec<TimingSpan> {
+        self.spans.clone()
+    }
+}
+
+/// Runs `cb` recording its duration as a timing span, shown by `status --timings`
+pub fn measure<T>(name: &str, cb: impl FnOnce() -> T) -> T {
+    trace_lock!(PERFORMANCE_HELPER).start(name);
+    let result = cb();
+    trace_lock!(PERFORMANCE_HELPER).end(name);
+    result
 }
 
 /// Useful when spawning threads that will allocate a loop or some other blocking operation
@@ -0,0 +1,85 @@
+use crossbeam_channel::bounded;
+use tauri::{WebviewWindow, Wry};
+
+use crate::{error_handler::Result, seelen::get_app_handle};
+
+/// Builder of the frameless, transparent and always on top webviews used as overlays
+/// (overview, hints, pickers, etc). They are created hidden, so the caller can place
+/// them before showing them.
+pub struct OverlayBuilder {
+    label: String,
+    url: String,
+    title: String,
+    focused: bool,
+    click_through: bool,
+    topmost: bool,
+}
+
+impl OverlayBuilder {
+    pub fn new(label: &str, url: &str, title: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            url: url.to_string(),
+            title: title.to_string(),
+            focused: true,
+            click_through: false,
+            topmost: true,
+        }
+    }
+
+    /// The overlay will not take the focus from the foreground window when shown
+    pub fn unfocused(mut self) -> Self {
+        self.focused = false;
+        self
+    }
+
+    /// Mouse events will pass through the overlay to the windows below
+    pub fn click_through(mut self) -> Self {
+        self.click_through = true;
+        self
+    }
+
+    /// The overlay will not be kept above the other windows
+    pub fn not_topmost(mut self) -> Self {
+        self.topmost = false;
+        self
+    }
+
+    /// Webviews should be created on the main thread, so if this is called from another
+    /// thread the creation is requested there and this blocks until it is done.
+    pub fn build(self) -> Result<WebviewWindow<Wry>> {
+        if std::thread::current().name() == Some("main") {
+            return self.create();
+        }
+        let (tx, rx) = bounded(1);
+        get_app_handle().run_on_main_thread(move || {
+            let _ = tx.send(self.create());
+        })?;
+        rx.recv()?
+    }
+
+    fn create(self) -> Result<WebviewWindow<Wry>> {
+        let window = tauri::WebviewWindowBuilder::new(
+            &get_app_handle(),
+            self.label,
+            tauri::WebviewUrl::App(self.url.into()),
+        )
+        .title(self.title)
+        .maximizable(false)
+        .minimizable(false)
+        .resizable(false)
+        .visible(false)
+        .focused(self.focused)
+        .decorations(false)
+        .transparent(true)
+        .shadow(false)
+        .skip_taskbar(true)
+        .always_on_top(self.topmost)
+        .drag_and_drop(false)
+        .build()?;
+        if self.click_through {
+            window.set_ignore_cursor_events(true)?;
+        }
+        Ok(window)
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::{error_handler::Result, host::emit_global, log_error, trace_lock};
+
+use super::spawn_named_thread;
+
+lazy_static! {
+    static ref THROTTLE_SLOTS: Mutex<HashMap<String, ThrottleSlot>> = Mutex::new(HashMap::new());
+}
+
+/// slots without activity for this long are dropped to not leak closed windows
+const STALE_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct ThrottleSlot {
+    last_emit: Option<Instant>,
+    /// latest payload received while the slot was cooling down
+    pending: Option<serde_json::Value>,
+}
+
+/// Emits `event` at most once per `interval` for each `key`, the first event is sent
+/// immediately and the ones received during the cooldown are coalesced, only the
+/// latest payload is sent when the cooldown ends.
+pub fn emit_throttled<K, S>(event: &str, key: K, payload: S, interval: Duration) -> Result<()>
+where
+    K: Display,
+    S: Serialize + Clone,
+{
+    let id = format!("{}:{}", event, key);
+    let mut slots = trace_lock!(THROTTLE_SLOTS);
+    if !slots.contains_key(&id) {
+        slots.retain(|_, slot| {
+            slot.pending.is_some() || slot.last_emit.is_some_and(|t| t.elapsed() < STALE_AFTER)
+        });
+    }
+
+    let slot = slots.entry(id.clone()).or_default();
+    let now = Instant::now();
+    match slot.last_emit {
+        Some(last) if now.duration_since(last) < interval => {
+            let already_scheduled = slot.pending.is_some();
+            slot.pending = Some(serde_json::to_value(payload)?);
+            if !already_scheduled {
+                let wait = interval - now.duration_since(last);
+                let event = event.to_string();
+                spawn_named_thread("Throttled Emit", move || {
+                    std::thread::sleep(wait);
+                    log_error!(flush(&event, &id));
+                })?;
+            }
+        }
+        _ => {
+            slot.last_emit = Some(now);
+            drop(slots);
+            emit_global(event, payload)?;
+        }
+    }
+    Ok(())
+}
+
+fn flush(event: &str, id: &str) -> Result<()> {
+    let payload = {
+        let mut slots = trace_lock!(THROTTLE_SLOTS);
+        match slots.get_mut(id) {
+            Some(slot) => {
+                slot.last_emit = Some(Instant::now());
+                slot.pending.take()
+            }
+            None => None,
+        }
+    };
+    if let Some(payload) = payload {
+        emit_global(event, payload)?;
+    }
+    Ok(())
+}
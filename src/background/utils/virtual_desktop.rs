@@ -1,9 +1,10 @@
 use windows::core::GUID;
 use winreg::{enums::HKEY_CURRENT_USER, RegKey};
 
-use crate::{error_handler::Result, windows_api::WindowsApi};
-
-use super::is_windows_10;
+use crate::{
+    error_handler::Result, modules::os_capabilities::domain::OsCapabilities,
+    windows_api::WindowsApi,
+};
 
 pub struct RegistryVirtualDesktopManager {}
 
@@ -59,7 +60,7 @@ impl RegistryVirtualDesktop {
 impl RegistryVirtualDesktopManager {
     fn get_virtual_desktops_folder() -> Result<RegKey> {
         let hkcu = RegKey::predef(HKEY_CURRENT_USER);
-        Ok(if is_windows_10() {
+        Ok(if OsCapabilities::get().is_windows_10() {
             let session_id = WindowsApi::current_session_id()?;
             hkcu.open_subkey(format!(r"SOFTWARE\Microsoft\Windows\CurrentVersion\Explorer\SessionInfo\{session_id}\VirtualDesktops"))?
         } else {
@@ -0,0 +1,165 @@
+use std::path::PathBuf;
+
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{MINMAXINFO, SHOW_WINDOW_CMD, WINDOW_EX_STYLE, WINDOW_STYLE},
+};
+
+use crate::error_handler::Result;
+
+use super::{WindowEnumerator, WindowsApi};
+
+/// Window queries and commands used by `Window` and the hook path (dock, window manager and
+/// popup filter), so they can be tested against a simulated desktop instead of a real session.
+pub trait WindowsBackend {
+    fn title(&self, hwnd: HWND) -> String;
+    fn class(&self, hwnd: HWND) -> String;
+    fn exe(&self, hwnd: HWND) -> Result<PathBuf>;
+    /// `HWND(0)` if the window has no parent
+    fn parent(&self, hwnd: HWND) -> HWND;
+    fn children(&self, hwnd: HWND) -> Result<Vec<HWND>>;
+    fn is_visible(&self, hwnd: HWND) -> bool;
+    fn styles(&self, hwnd: HWND) -> WINDOW_STYLE;
+    fn ex_styles(&self, hwnd: HWND) -> WINDOW_EX_STYLE;
+    fn rect(&self, hwnd: HWND) -> RECT;
+    fn is_uwp_suspended(&self, hwnd: HWND) -> bool;
+    fn is_window(&self, hwnd: HWND) -> bool;
+    fn is_iconic(&self, hwnd: HWND) -> bool;
+    fn is_maximized(&self, hwnd: HWND) -> bool;
+    /// snapped by Windows
+    fn is_arranged(&self, hwnd: HWND) -> bool;
+    fn is_cloaked(&self, hwnd: HWND) -> Result<bool>;
+    /// rect of the visible frame, without the invisible borders
+    fn frame_rect(&self, hwnd: HWND) -> RECT;
+    /// invisible borders around the visible frame
+    fn shadow_rect(&self, hwnd: HWND) -> Result<RECT>;
+    fn min_max_info(&self, hwnd: HWND) -> Result<MINMAXINFO>;
+    fn foreground(&self) -> HWND;
+    fn monitor(&self, hwnd: HWND) -> HMONITOR;
+    fn monitor_name(&self, monitor: HMONITOR) -> Result<String>;
+    fn work_area(&self, monitor: HMONITOR) -> Result<RECT>;
+    fn show_window_async(&self, hwnd: HWND, command: SHOW_WINDOW_CMD) -> Result<()>;
+    fn bring_to(&self, hwnd: HWND, after: HWND) -> Result<()>;
+}
+
+pub struct Win32Backend;
+
+impl WindowsBackend for Win32Backend {
+    fn title(&self, hwnd: HWND) -> String {
+        WindowsApi::get_window_text(hwnd)
+    }
+
+    fn class(&self, hwnd: HWND) -> String {
+        WindowsApi::get_class(hwnd).unwrap_or_default()
+    }
+
+    fn exe(&self, hwnd: HWND) -> Result<PathBuf> {
+        WindowsApi::exe_path_v2(hwnd)
+    }
+
+    fn parent(&self, hwnd: HWND) -> HWND {
+        WindowsApi::get_parent(hwnd)
+    }
+
+    fn children(&self, hwnd: HWND) -> Result<Vec<HWND>> {
+        WindowEnumerator::new().with_parent(hwnd).map(|child| child)
+    }
+
+    fn is_visible(&self, hwnd: HWND) -> bool {
+        WindowsApi::is_window_visible(hwnd)
+    }
+
+    fn styles(&self, hwnd: HWND) -> WINDOW_STYLE {
+        WindowsApi::get_styles(hwnd)
+    }
+
+    fn ex_styles(&self, hwnd: HWND) -> WINDOW_EX_STYLE {
+        WindowsApi::get_ex_styles(hwnd)
+    }
+
+    fn rect(&self, hwnd: HWND) -> RECT {
+        WindowsApi::get_window_rect(hwnd)
+    }
+
+    fn is_uwp_suspended(&self, hwnd: HWND) -> bool {
+        WindowsApi::window_is_uwp_suspended(hwnd).unwrap_or_default()
+    }
+
+    fn is_window(&self, hwnd: HWND) -> bool {
+        WindowsApi::is_window(hwnd)
+    }
+
+    fn is_iconic(&self, hwnd: HWND) -> bool {
+        WindowsApi::is_iconic(hwnd)
+    }
+
+    fn is_maximized(&self, hwnd: HWND) -> bool {
+        WindowsApi::is_maximized(hwnd)
+    }
+
+    fn is_arranged(&self, hwnd: HWND) -> bool {
+        WindowsApi::is_window_arranged(hwnd)
+    }
+
+    fn is_cloaked(&self, hwnd: HWND) -> Result<bool> {
+        WindowsApi::is_cloaked(hwnd)
+    }
+
+    fn frame_rect(&self, hwnd: HWND) -> RECT {
+        WindowsApi::get_window_rect_without_margins(hwnd)
+    }
+
+    fn shadow_rect(&self, hwnd: HWND) -> Result<RECT> {
+        WindowsApi::shadow_rect(hwnd)
+    }
+
+    fn min_max_info(&self, hwnd: HWND) -> Result<MINMAXINFO> {
+        WindowsApi::get_min_max_info(hwnd)
+    }
+
+    fn foreground(&self) -> HWND {
+        WindowsApi::get_foreground_window()
+    }
+
+    fn monitor(&self, hwnd: HWND) -> HMONITOR {
+        WindowsApi::monitor_from_window(hwnd)
+    }
+
+    fn monitor_name(&self, monitor: HMONITOR) -> Result<String> {
+        WindowsApi::monitor_name(monitor)
+    }
+
+    fn work_area(&self, monitor: HMONITOR) -> Result<RECT> {
+        Ok(WindowsApi::monitor_info(monitor)?.monitorInfo.rcWork)
+    }
+
+    fn show_window_async(&self, hwnd: HWND, command: SHOW_WINDOW_CMD) -> Result<()> {
+        WindowsApi::show_window_async(hwnd, command)
+    }
+
+    fn bring_to(&self, hwnd: HWND, after: HWND) -> Result<()> {
+        WindowsApi::bring_to(hwnd, after)
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    static MOCK_BACKEND: std::cell::RefCell<Option<std::rc::Rc<dyn WindowsBackend>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Runs `cb` with the backend of the current thread, always Win32 outside of tests
+pub fn with_backend<T>(cb: impl FnOnce(&dyn WindowsBackend) -> T) -> T {
+    #[cfg(test)]
+    if let Some(mock) = MOCK_BACKEND.with(|mock| mock.borrow().clone()) {
+        return cb(mock.as_ref());
+    }
+    cb(&Win32Backend)
+}
+
+/// Replaces the backend of the current thread, `None` restores the Win32 one
+#[cfg(test)]
+pub fn set_thread_backend(backend: Option<std::rc::Rc<dyn WindowsBackend>>) {
+    MOCK_BACKEND.with(|mock| *mock.borrow_mut() = backend);
+}
@@ -1,7 +1,10 @@
 mod app_bar;
+pub mod backend;
 mod com;
 mod iterator;
 mod process;
+mod thumbnail;
+mod toast;
 pub mod window;
 
 pub use app_bar::*;
@@ -9,13 +12,15 @@ pub use com::*;
 pub use iterator::*;
 use itertools::Itertools;
 use process::ProcessInformationFlag;
+pub use thumbnail::*;
+pub use toast::*;
 use widestring::U16CStr;
 
 use std::{ffi::c_void, path::PathBuf, thread::sleep, time::Duration};
 
 use color_eyre::eyre::eyre;
 use windows::{
-    core::{GUID, PCWSTR, PWSTR},
+    core::{w, GUID, HSTRING, PCWSTR, PWSTR},
     Storage::Streams::{
         DataReader, IRandomAccessStreamReference, IRandomAccessStreamWithContentType,
     },
@@ -29,16 +34,20 @@ use windows::{
             PHYSICAL_MONITOR,
         },
         Foundation::{
-            CloseHandle, FALSE, HANDLE, HMODULE, HWND, LPARAM, LUID, MAX_PATH, RECT, STATUS_SUCCESS,
+            CloseHandle, BOOL, COLORREF, FALSE, HANDLE, HMODULE, HWND, LPARAM, LUID, MAX_PATH,
+            POINT, RECT, STATUS_SUCCESS, STILL_ACTIVE, WPARAM,
         },
+        Globalization::GetUserDefaultUILanguage,
         Graphics::{
             Dwm::{
-                DwmGetWindowAttribute, DWMWA_CLOAKED, DWMWA_EXTENDED_FRAME_BOUNDS,
-                DWMWINDOWATTRIBUTE, DWM_CLOAKED_APP, DWM_CLOAKED_INHERITED, DWM_CLOAKED_SHELL,
+                DwmGetWindowAttribute, DwmSetWindowAttribute, DWMWA_CLOAKED,
+                DWMWA_EXTENDED_FRAME_BOUNDS, DWMWA_HAS_ICONIC_BITMAP, DWMWINDOWATTRIBUTE,
+                DWM_CLOAKED_APP, DWM_CLOAKED_INHERITED, DWM_CLOAKED_SHELL,
             },
             Gdi::{
-                EnumDisplayMonitors, GetMonitorInfoW, MonitorFromWindow, HDC, HMONITOR,
-                MONITORENUMPROC, MONITORINFOEXW, MONITOR_DEFAULTTOPRIMARY,
+                EnumDisplayDevicesW, EnumDisplayMonitors, GetMonitorInfoW, MonitorFromPoint,
+                MonitorFromWindow, DISPLAY_DEVICEW, HDC, HMONITOR, MONITORENUMPROC, MONITORINFOEXW,
+                MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTOPRIMARY,
             },
         },
         Security::{
@@ -46,34 +55,54 @@ use windows::{
             SE_PRIVILEGE_ENABLED, TOKEN_ADJUST_PRIVILEGES, TOKEN_ELEVATION, TOKEN_PRIVILEGES,
             TOKEN_QUERY,
         },
-        Storage::EnhancedStorage::PKEY_FileDescription,
+        Storage::{
+            EnhancedStorage::PKEY_AppUserModel_ID,
+            FileSystem::{GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW},
+        },
         System::{
+            DataExchange::{CloseClipboard, EmptyClipboard, OpenClipboard, SetClipboardData},
             LibraryLoader::GetModuleHandleW,
+            Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE},
+            Ole::CF_UNICODETEXT,
             Power::{GetSystemPowerStatus, SetSuspendState, SYSTEM_POWER_STATUS},
+            ProcessStatus::{EnumProcessModulesEx, GetModuleFileNameExW, LIST_MODULES_ALL},
             RemoteDesktop::ProcessIdToSessionId,
             Shutdown::{ExitWindowsEx, EXIT_WINDOWS_FLAGS, SHUTDOWN_REASON},
             Threading::{
-                GetCurrentProcess, GetCurrentProcessId, OpenProcess, OpenProcessToken,
-                QueryFullProcessImageNameW, PROCESS_ACCESS_RIGHTS, PROCESS_NAME_WIN32,
-                PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION,
+                GetCurrentProcess, GetCurrentProcessId, GetExitCodeProcess, GetProcessAffinityMask,
+                GetProcessId, OpenProcess, OpenProcessToken, QueryFullProcessImageNameW,
+                SetPriorityClass, SetProcessAffinityMask, TerminateProcess, PROCESS_ACCESS_RIGHTS,
+                PROCESS_CREATION_FLAGS, PROCESS_NAME_WIN32, PROCESS_QUERY_INFORMATION,
+                PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SET_INFORMATION, PROCESS_TERMINATE,
+                PROCESS_VM_READ,
             },
         },
         UI::{
-            HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
+            Accessibility::{HCF_HIGHCONTRASTON, HIGHCONTRASTW},
+            HiDpi::{GetDpiForMonitor, GetDpiForWindow, GetSystemMetricsForDpi, MDT_EFFECTIVE_DPI},
             Shell::{
-                IShellItem2, IVirtualDesktopManager, SHCreateItemFromParsingName,
-                VirtualDesktopManager, SIGDN_NORMALDISPLAY,
+                IShellItem2, IVirtualDesktopManager, PropertiesSystem::IPropertyStore,
+                SHCreateItemFromParsingName, SHGetPropertyStoreForWindow, ShellExecuteExW,
+                ShellExecuteW, VirtualDesktopManager, SEE_MASK_NOASYNC, SEE_MASK_NOCLOSEPROCESS,
+                SHELLEXECUTEINFOW,
             },
             WindowsAndMessaging::{
-                EnumWindows, GetClassNameW, GetDesktopWindow, GetForegroundWindow, GetParent,
-                GetWindow, GetWindowLongW, GetWindowRect, GetWindowTextW, GetWindowThreadProcessId,
-                IsIconic, IsWindow, IsWindowVisible, IsZoomed, SetForegroundWindow, SetWindowPos,
-                ShowWindow, ShowWindowAsync, SystemParametersInfoW, ANIMATIONINFO, GWL_EXSTYLE,
-                GWL_STYLE, GW_OWNER, SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD, SPIF_SENDCHANGE,
-                SPIF_UPDATEINIFILE, SPI_GETANIMATION, SPI_GETDESKWALLPAPER, SPI_SETANIMATION,
-                SPI_SETDESKWALLPAPER, SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE,
-                SWP_NOZORDER, SW_MINIMIZE, SW_NORMAL, SW_RESTORE,
-                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, WINDOW_EX_STYLE, WINDOW_STYLE, WNDENUMPROC,
+                EnumWindows, GetClassNameW, GetDesktopWindow, GetForegroundWindow,
+                GetLayeredWindowAttributes, GetParent, GetSystemMetrics, GetWindow, GetWindowLongW,
+                GetWindowRect, GetWindowTextW, GetWindowThreadProcessId, IsIconic, IsWindow,
+                IsWindowArranged, IsWindowVisible, IsZoomed, SendMessageTimeoutW,
+                SetForegroundWindow, SetLayeredWindowAttributes, SetWindowLongW, SetWindowPos,
+                ShowWindow, ShowWindowAsync, SystemParametersInfoW, ANIMATIONINFO,
+                EDD_GET_DEVICE_INTERFACE_NAME, GWL_EXSTYLE, GWL_STYLE, GW_OWNER, HWND_NOTOPMOST,
+                HWND_TOPMOST, LAYERED_WINDOW_ATTRIBUTES_FLAGS, LWA_ALPHA, MINMAXINFO,
+                SET_WINDOW_POS_FLAGS, SHOW_WINDOW_CMD, SMTO_ABORTIFHUNG, SM_CXMAXTRACK,
+                SM_CXMINTRACK, SM_CXPADDEDBORDER, SM_CYCAPTION, SM_CYFRAME, SM_CYMAXTRACK,
+                SM_CYMINTRACK, SPIF_SENDCHANGE, SPIF_UPDATEINIFILE, SPI_GETANIMATION,
+                SPI_GETDESKWALLPAPER, SPI_GETHIGHCONTRAST, SPI_SETANIMATION, SPI_SETDESKWALLPAPER,
+                SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+                SW_MINIMIZE, SW_NORMAL, SW_RESTORE, SW_SHOWNORMAL,
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, WINDOW_EX_STYLE, WINDOW_STYLE,
+                WM_GETMINMAXINFO, WNDENUMPROC, WS_EX_LAYERED, WS_EX_TOPMOST,
             },
         },
     },
@@ -82,8 +111,14 @@ use windows::{
 use crate::{
     error_handler::{AppError, Result},
     hook::HOOK_MANAGER,
-    log_error, trace_lock,
-    utils::{is_virtual_desktop_supported, is_windows_11},
+    log_error,
+    modules::{
+        cache::{domain::CacheKind, Cache},
+        mode_manager::ModeManager,
+        os_capabilities::domain::OsCapabilities,
+    },
+    seelen_weg::app_names::AppNames,
+    trace_lock,
     winevent::WinEvent,
 };
 
@@ -189,6 +224,12 @@ impl WindowsApi {
         unsafe { IsZoomed(hwnd) }.into()
     }
 
+    /// True if the window was snapped by Windows (Win + Arrow, drag to the edges or the
+    /// Snap Layouts flyout)
+    pub fn is_window_arranged(hwnd: HWND) -> bool {
+        unsafe { IsWindowArranged(hwnd) }.into()
+    }
+
     pub fn is_fullscreen(hwnd: HWND) -> Result<bool> {
         let rc_monitor = WindowsApi::monitor_rect(WindowsApi::monitor_from_window(hwnd))?;
         let window_rect = WindowsApi::get_window_rect_without_margins(hwnd);
@@ -198,6 +239,14 @@ impl WindowsApi {
             && window_rect.bottom >= rc_monitor.bottom)
     }
 
+    /// True if the app provides the thumbnail of the window on `WM_DWMSENDICONICTHUMBNAIL`,
+    /// as done for tab proxies. Not every system allows reading it, None in that case.
+    pub fn has_iconic_bitmap(hwnd: HWND) -> Option<bool> {
+        let mut value = BOOL(0);
+        Self::dwm_get_window_attribute(hwnd, DWMWA_HAS_ICONIC_BITMAP, &mut value).ok()?;
+        Some(value.as_bool())
+    }
+
     pub fn is_cloaked(hwnd: HWND) -> Result<bool> {
         let mut cloaked: u32 = 0;
         Self::dwm_get_window_attribute(hwnd, DWMWA_CLOAKED, &mut cloaked)?;
@@ -243,6 +292,76 @@ impl WindowsApi {
         WINDOW_EX_STYLE(unsafe { GetWindowLongW(hwnd, GWL_EXSTYLE) } as u32)
     }
 
+    pub fn set_ex_styles(hwnd: HWND, styles: WINDOW_EX_STYLE) {
+        unsafe { SetWindowLongW(hwnd, GWL_EXSTYLE, styles.0 as i32) };
+    }
+
+    pub fn get_window_dpi(hwnd: HWND) -> u32 {
+        unsafe { GetDpiForWindow(hwnd) }
+    }
+
+    /// explicit AppUserModelID of the window, empty if the window does not define one
+    pub fn get_window_app_user_model_id(hwnd: HWND) -> Result<String> {
+        let store: IPropertyStore = unsafe { SHGetPropertyStoreForWindow(hwnd)? };
+        let value = unsafe { store.GetValue(&PKEY_AppUserModel_ID)? };
+        Ok(value.to_string())
+    }
+
+    /// height in physical pixels of the caption area including the resize frame
+    pub fn get_title_bar_height(hwnd: HWND) -> i32 {
+        unsafe {
+            let dpi = GetDpiForWindow(hwnd);
+            GetSystemMetricsForDpi(SM_CYCAPTION, dpi)
+                + GetSystemMetricsForDpi(SM_CYFRAME, dpi)
+                + GetSystemMetricsForDpi(SM_CXPADDEDBORDER, dpi)
+        }
+    }
+
+    pub fn is_topmost(hwnd: HWND) -> bool {
+        Self::get_ex_styles(hwnd).contains(WS_EX_TOPMOST)
+    }
+
+    pub fn set_topmost(hwnd: HWND, topmost: bool) -> Result<()> {
+        let order = if topmost {
+            HWND_TOPMOST
+        } else {
+            HWND_NOTOPMOST
+        };
+        Self::set_position(
+            hwnd,
+            Some(order),
+            &Default::default(),
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+        )
+    }
+
+    /// returns the alpha value of the window, 255 if the window is not layered
+    pub fn get_opacity(hwnd: HWND) -> u8 {
+        if !Self::get_ex_styles(hwnd).contains(WS_EX_LAYERED) {
+            return 255;
+        }
+        let mut alpha = 255;
+        let mut flags = LAYERED_WINDOW_ATTRIBUTES_FLAGS::default();
+        let result =
+            unsafe { GetLayeredWindowAttributes(hwnd, None, Some(&mut alpha), Some(&mut flags)) };
+        match result {
+            Ok(()) if flags.contains(LWA_ALPHA) => alpha,
+            _ => 255,
+        }
+    }
+
+    pub fn set_opacity(hwnd: HWND, alpha: u8) -> Result<()> {
+        let styles = Self::get_ex_styles(hwnd);
+        if !styles.contains(WS_EX_LAYERED) {
+            if alpha == 255 {
+                return Ok(());
+            }
+            Self::set_ex_styles(hwnd, styles | WS_EX_LAYERED);
+        }
+        unsafe { SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA)? };
+        Ok(())
+    }
+
     fn _set_position(
         hwnd: HWND,
         order: HWND,
@@ -406,6 +525,43 @@ impl WindowsApi {
         Ok(String::from_utf16(&path[..len as usize])?)
     }
 
+    /// Full paths of the modules (exe and dlls) loaded by the process
+    pub fn process_module_paths(process_id: u32) -> Result<Vec<String>> {
+        let handle = Self::open_process(
+            PROCESS_QUERY_INFORMATION | PROCESS_VM_READ,
+            false,
+            process_id,
+        )?;
+
+        let mut modules = vec![HMODULE::default(); 1024];
+        let mut needed = 0;
+        let result = unsafe {
+            EnumProcessModulesEx(
+                handle,
+                modules.as_mut_ptr(),
+                (modules.len() * std::mem::size_of::<HMODULE>()) as u32,
+                &mut needed,
+                LIST_MODULES_ALL,
+            )
+        };
+        if let Err(err) = result {
+            Self::close_handle(handle)?;
+            return Err(err.into());
+        }
+
+        let count = (needed as usize / std::mem::size_of::<HMODULE>()).min(modules.len());
+        let mut paths = Vec::with_capacity(count);
+        for module in &modules[..count] {
+            let mut path = [0u16; MAX_PATH as usize];
+            let len = unsafe { GetModuleFileNameExW(handle, *module, &mut path) };
+            if len > 0 {
+                paths.push(String::from_utf16_lossy(&path[..len as usize]));
+            }
+        }
+        Self::close_handle(handle)?;
+        Ok(paths)
+    }
+
     pub fn window_is_uwp_suspended(hwnd: HWND) -> Result<bool> {
         let (process_id, _) = Self::window_thread_process_id(hwnd);
         let handle = Self::open_process(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)?;
@@ -436,6 +592,48 @@ impl WindowsApi {
         Ok(is_frozen)
     }
 
+    pub fn set_priority_class(process_id: u32, priority: PROCESS_CREATION_FLAGS) -> Result<()> {
+        let handle = Self::open_process(PROCESS_SET_INFORMATION, false, process_id)?;
+        let result = unsafe { SetPriorityClass(handle, priority) };
+        Self::close_handle(handle)?;
+        Ok(result?)
+    }
+
+    pub fn terminate_process(process_id: u32) -> Result<()> {
+        let handle = Self::open_process(PROCESS_TERMINATE, false, process_id)?;
+        let result = unsafe { TerminateProcess(handle, 1) };
+        Self::close_handle(handle)?;
+        Ok(result?)
+    }
+
+    pub fn is_process_running(process_id: u32) -> bool {
+        let Ok(handle) = Self::open_process(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+        else {
+            return false;
+        };
+        let mut exit_code = 0;
+        let result = unsafe { GetExitCodeProcess(handle, &mut exit_code) };
+        let _ = Self::close_handle(handle);
+        result.is_ok() && exit_code == STILL_ACTIVE.0 as u32
+    }
+
+    pub fn set_affinity_mask(process_id: u32, mask: usize) -> Result<()> {
+        let handle = Self::open_process(PROCESS_SET_INFORMATION, false, process_id)?;
+        let result = unsafe { SetProcessAffinityMask(handle, mask) };
+        Self::close_handle(handle)?;
+        Ok(result?)
+    }
+
+    /// mask of the logical processors available on the system
+    pub fn system_affinity_mask() -> Result<usize> {
+        let mut process_mask = 0;
+        let mut system_mask = 0;
+        unsafe {
+            GetProcessAffinityMask(Self::current_process(), &mut process_mask, &mut system_mask)?
+        };
+        Ok(system_mask)
+    }
+
     pub fn exe_path(hwnd: HWND) -> Result<String> {
         let (process_id, _) = Self::window_thread_process_id(hwnd);
         Self::exe_path_by_process(process_id)
@@ -471,17 +669,124 @@ impl WindowsApi {
         Ok(item)
     }
 
+    /// Opens the path with its default handler, unlike launching `explorer <path>` this
+    /// doesn't start explorer as the shell when Seelen UI is the shell of the session.
+    pub fn shell_open(path: &str) -> Result<()> {
+        let wide_path: Vec<u16> = path.encode_utf16().chain(Some(0)).collect();
+        let instance = unsafe {
+            ShellExecuteW(
+                HWND(0),
+                windows::core::w!("open"),
+                PCWSTR(wide_path.as_ptr()),
+                PCWSTR::null(),
+                PCWSTR::null(),
+                SW_SHOWNORMAL,
+            )
+        };
+        // values greater than 32 indicate success
+        if instance.0 <= 32 {
+            return Err(eyre!("ShellExecuteW failed with code {}", instance.0).into());
+        }
+        Ok(())
+    }
+
+    /// Launches the path elevated, blocking until the UAC prompt is answered.
+    /// Fails if the user declines the prompt.
+    pub fn shell_open_elevated(path: &str) -> Result<()> {
+        let wide_path: Vec<u16> = path.encode_utf16().chain(Some(0)).collect();
+        let mut info = SHELLEXECUTEINFOW {
+            cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
+            fMask: SEE_MASK_NOASYNC,
+            lpVerb: windows::core::w!("runas"),
+            lpFile: PCWSTR(wide_path.as_ptr()),
+            nShow: SW_SHOWNORMAL.0,
+            ..Default::default()
+        };
+        unsafe { ShellExecuteExW(&mut info)? };
+        Ok(())
+    }
+
+    /// Launches the path (executable, shortcut or document) with the show command as hint for
+    /// its first window. Returns the id of the new process, none if the launch was handed to
+    /// an already running process.
+    pub fn shell_launch(path: &str, args: &str, show: SHOW_WINDOW_CMD) -> Result<Option<u32>> {
+        let wide_path: Vec<u16> = path.encode_utf16().chain(Some(0)).collect();
+        let wide_args: Vec<u16> = args.encode_utf16().chain(Some(0)).collect();
+        let mut info = SHELLEXECUTEINFOW {
+            cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
+            fMask: SEE_MASK_NOASYNC | SEE_MASK_NOCLOSEPROCESS,
+            lpFile: PCWSTR(wide_path.as_ptr()),
+            lpParameters: PCWSTR(wide_args.as_ptr()),
+            nShow: show.0,
+            ..Default::default()
+        };
+        unsafe { ShellExecuteExW(&mut info)? };
+        if info.hProcess.is_invalid() {
+            return Ok(None);
+        }
+        let pid = unsafe { GetProcessId(info.hProcess) };
+        unsafe { CloseHandle(info.hProcess)? };
+        Ok((pid != 0).then_some(pid))
+    }
+
     pub fn get_window_display_name(hwnd: HWND) -> Result<String> {
-        let shell_item = Self::get_shell_item(&Self::exe_path(hwnd)?)?;
+        Ok(AppNames::get(&Self::exe_path(hwnd)?))
+    }
+
+    /// `FileDescription` or `ProductName` of the version resource of the executable, in the
+    /// user language if the executable has several translations.
+    pub fn exe_version_name(path: &str) -> Result<String> {
+        let path = HSTRING::from(path);
         unsafe {
-            match shell_item.GetString(&PKEY_FileDescription) {
-                Ok(description) => Ok(description.to_string()?),
-                Err(_) => Ok(shell_item
-                    .GetDisplayName(SIGDN_NORMALDISPLAY)?
-                    .to_string()?
-                    .replace(".exe", "")),
+            let size = GetFileVersionInfoSizeW(&path, None);
+            if size == 0 {
+                return Err("Executable without version info".into());
+            }
+            let mut data = vec![0u8; size as usize];
+            GetFileVersionInfoW(&path, 0, size, data.as_mut_ptr() as *mut c_void)?;
+            let block = data.as_ptr() as *const c_void;
+
+            let mut buffer = std::ptr::null_mut();
+            let mut len = 0;
+            let mut translations = Vec::new();
+            if VerQueryValueW(
+                block,
+                w!("\\VarFileInfo\\Translation"),
+                &mut buffer,
+                &mut len,
+            )
+            .as_bool()
+            {
+                // pairs of language and code page
+                let pairs = std::slice::from_raw_parts(buffer as *const [u16; 2], len as usize / 4);
+                translations.extend(pairs.iter().map(|pair| (pair[0], pair[1])));
+            }
+
+            // exact language first, then the same primary language (ex: es-ES for es-MX)
+            let user_lang = GetUserDefaultUILanguage();
+            translations
+                .sort_by_key(|(lang, _)| (*lang != user_lang, lang & 0x3FF != user_lang & 0x3FF));
+            // en-US unicode, used by most of the executables without translation table
+            translations.push((0x0409, 0x04B0));
+
+            for key in ["FileDescription", "ProductName"] {
+                for (lang, code_page) in &translations {
+                    let sub_block = format!("\\StringFileInfo\\{lang:04x}{code_page:04x}\\{key}");
+                    let sub_block = HSTRING::from(sub_block);
+                    if !VerQueryValueW(block, &sub_block, &mut buffer, &mut len).as_bool() {
+                        continue;
+                    }
+                    // len is in characters including the null terminator
+                    let value = std::slice::from_raw_parts(buffer as *const u16, len as usize);
+                    let value = String::from_utf16_lossy(value);
+                    let value = value.trim_end_matches('\0').trim();
+                    if !value.is_empty() {
+                        return Ok(value.to_string());
+                    }
+                }
             }
         }
+        Err("Executable without display name".into())
     }
 
     pub fn get_window_text(hwnd: HWND) -> String {
@@ -508,6 +813,22 @@ impl WindowsApi {
         Ok(())
     }
 
+    pub fn dwm_set_window_attribute<T>(
+        hwnd: HWND,
+        attribute: DWMWINDOWATTRIBUTE,
+        value: &T,
+    ) -> Result<()> {
+        unsafe {
+            DwmSetWindowAttribute(
+                hwnd,
+                attribute,
+                (value as *const T).cast(),
+                u32::try_from(std::mem::size_of::<T>())?,
+            )?;
+        }
+        Ok(())
+    }
+
     pub fn get_window_rect(hwnd: HWND) -> RECT {
         let mut rect = unsafe { std::mem::zeroed() };
         unsafe { GetWindowRect(hwnd, &mut rect).ok() };
@@ -533,6 +854,10 @@ impl WindowsApi {
         unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTOPRIMARY) }
     }
 
+    pub fn monitor_from_point(point: POINT) -> HMONITOR {
+        unsafe { MonitorFromPoint(point, MONITOR_DEFAULTTONEAREST) }
+    }
+
     pub fn primary_monitor() -> HMONITOR {
         unsafe { MonitorFromWindow(GetDesktopWindow(), MONITOR_DEFAULTTOPRIMARY) }
     }
@@ -563,6 +888,33 @@ impl WindowsApi {
             .to_string())
     }
 
+    /// Device interface path of the display attached to the monitor, unlike the monitor name
+    /// it does not change when the monitors are connected again in a different order.
+    pub fn monitor_device_id(hmonitor: HMONITOR) -> Result<String> {
+        let ex_info = Self::monitor_info(hmonitor)?;
+        let mut device = DISPLAY_DEVICEW {
+            cb: u32::try_from(std::mem::size_of::<DISPLAY_DEVICEW>())?,
+            ..Default::default()
+        };
+        unsafe {
+            EnumDisplayDevicesW(
+                PCWSTR(ex_info.szDevice.as_ptr()),
+                0,
+                &mut device,
+                EDD_GET_DEVICE_INTERFACE_NAME,
+            )
+            .ok()?
+        };
+        Ok(U16CStr::from_slice_truncate(&device.DeviceID)
+            .map_err(|_| AppError::Seelen("device id was not a valid u16 c string".to_owned()))?
+            .to_string_lossy())
+    }
+
+    /// Device id of the monitor, or its name for monitors without device interface
+    pub fn monitor_stable_id(hmonitor: HMONITOR) -> Result<String> {
+        Self::monitor_device_id(hmonitor).or_else(|_| Self::monitor_name(hmonitor))
+    }
+
     pub fn monitor_info(hmonitor: HMONITOR) -> Result<MONITORINFOEXW> {
         let mut ex_info = MONITORINFOEXW::default();
         ex_info.monitorInfo.cbSize = u32::try_from(std::mem::size_of::<MONITORINFOEXW>())?;
@@ -588,6 +940,36 @@ impl WindowsApi {
         })
     }
 
+    /// Minimum and maximum tracking sizes of the window including its invisible borders,
+    /// windows that don't handle `WM_GETMINMAXINFO` keep the system defaults.
+    pub fn get_min_max_info(hwnd: HWND) -> Result<MINMAXINFO> {
+        let mut info = MINMAXINFO::default();
+        unsafe {
+            info.ptMinTrackSize = POINT {
+                x: GetSystemMetrics(SM_CXMINTRACK),
+                y: GetSystemMetrics(SM_CYMINTRACK),
+            };
+            info.ptMaxTrackSize = POINT {
+                x: GetSystemMetrics(SM_CXMAXTRACK),
+                y: GetSystemMetrics(SM_CYMAXTRACK),
+            };
+            // the system marshals the struct when the window belongs to other process
+            let result = SendMessageTimeoutW(
+                hwnd,
+                WM_GETMINMAXINFO,
+                WPARAM(0),
+                LPARAM(&mut info as *mut MINMAXINFO as isize),
+                SMTO_ABORTIFHUNG,
+                200,
+                None,
+            );
+            if result.0 == 0 {
+                return Err("Window did not answer WM_GETMINMAXINFO".into());
+            }
+        }
+        Ok(info)
+    }
+
     pub fn _get_virtual_desktop_manager() -> Result<IVirtualDesktopManager> {
         Com::create_instance(&VirtualDesktopManager)
     }
@@ -631,7 +1013,8 @@ impl WindowsApi {
             return Err("File not found".into());
         }
 
-        if is_windows_11() && is_virtual_desktop_supported() {
+        let os = OsCapabilities::get();
+        if os.is_windows_11() && os.native_virtual_desktops {
             for v_desktop in winvd::get_desktops()? {
                 v_desktop.set_wallpaper(&path)?;
             }
@@ -664,7 +1047,25 @@ impl WindowsApi {
         Ok(anim_info)
     }
 
+    pub fn is_high_contrast() -> Result<bool> {
+        let mut high_contrast = HIGHCONTRASTW {
+            cbSize: core::mem::size_of::<HIGHCONTRASTW>() as u32,
+            ..Default::default()
+        };
+        unsafe {
+            SystemParametersInfoW(
+                SPI_GETHIGHCONTRAST,
+                high_contrast.cbSize,
+                Some(&mut high_contrast as *mut HIGHCONTRASTW as *mut c_void),
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+            )?;
+        }
+        Ok(high_contrast.dwFlags.contains(HCF_HIGHCONTRASTON))
+    }
+
+    /// Animations are kept disabled while a mode disables them, like the presentation mode
     pub fn set_minimize_animation(enable: bool) -> Result<()> {
+        let enable = enable && !ModeManager::animations_disabled();
         let mut anim_info = ANIMATIONINFO {
             cbSize: core::mem::size_of::<ANIMATIONINFO>() as u32,
             iMinAnimate: enable.into(),
@@ -735,8 +1136,9 @@ impl WindowsApi {
         data_reader.ReadBytes(&mut buffer)?;
 
         let image = image::load_from_memory_with_format(&buffer, image::ImageFormat::Png)?;
-        let image_path = std::env::temp_dir().join(format!("{}.png", uuid::Uuid::new_v4()));
+        let image_path = Cache::thumbnails_dir()?.join(format!("{}.png", uuid::Uuid::new_v4()));
         image.save(&image_path)?;
+        Cache::record(CacheKind::Thumbnails, &image_path);
 
         Ok(image_path)
     }
@@ -744,4 +1146,27 @@ impl WindowsApi {
     pub fn extract_thumbnail_from_ref(stream: IRandomAccessStreamReference) -> Result<PathBuf> {
         Self::extract_thumbnail_from_stream(stream.OpenReadAsync()?.get()?)
     }
+
+    pub fn set_clipboard_text(text: &str) -> Result<()> {
+        let wide: Vec<u16> = text.encode_utf16().chain(Some(0)).collect();
+        unsafe {
+            OpenClipboard(None)?;
+            let result = (|| -> Result<()> {
+                EmptyClipboard()?;
+                let memory = GlobalAlloc(GMEM_MOVEABLE, wide.len() * 2)?;
+                let ptr = GlobalLock(memory) as *mut u16;
+                if ptr.is_null() {
+                    return Err(eyre!("Failed to lock clipboard memory").into());
+                }
+                std::ptr::copy_nonoverlapping(wide.as_ptr(), ptr, wide.len());
+                // returns an error when the memory is unlocked, which is the expected result
+                let _ = GlobalUnlock(memory);
+                // the system owns the memory after this call
+                SetClipboardData(CF_UNICODETEXT.0 as u32, HANDLE(memory.0 as isize))?;
+                Ok(())
+            })();
+            CloseClipboard()?;
+            result
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use windows::Win32::{
+    Foundation::{HWND, RECT, SIZE},
+    Graphics::Dwm::{
+        DwmQueryThumbnailSourceSize, DwmRegisterThumbnail, DwmUnregisterThumbnail,
+        DwmUpdateThumbnailProperties, DWM_THUMBNAIL_PROPERTIES, DWM_TNP_OPACITY,
+        DWM_TNP_RECTDESTINATION, DWM_TNP_SOURCECLIENTAREAONLY, DWM_TNP_VISIBLE,
+    },
+};
+
+use crate::{error_handler::Result, log_error};
+
+/// Live preview of a window drawn by DWM over a destination window,
+/// the thumbnail is unregistered on drop.
+#[derive(Debug)]
+pub struct DwmThumbnail {
+    id: isize,
+    source: HWND,
+}
+
+impl DwmThumbnail {
+    pub fn new(destination: HWND, source: HWND) -> Result<Self> {
+        let id = unsafe { DwmRegisterThumbnail(destination, source)? };
+        Ok(Self { id, source })
+    }
+
+    pub fn source(&self) -> HWND {
+        self.source
+    }
+
+    /// size of the source window in physical pixels
+    pub fn source_size(&self) -> Result<SIZE> {
+        Ok(unsafe { DwmQueryThumbnailSourceSize(self.id)? })
+    }
+
+    /// `rect` is relative to the client area of the destination window
+    pub fn show(&self, rect: RECT, opacity: u8) -> Result<()> {
+        let properties = DWM_THUMBNAIL_PROPERTIES {
+            dwFlags: DWM_TNP_RECTDESTINATION
+                | DWM_TNP_VISIBLE
+                | DWM_TNP_OPACITY
+                | DWM_TNP_SOURCECLIENTAREAONLY,
+            rcDestination: rect,
+            opacity,
+            fVisible: true.into(),
+            fSourceClientAreaOnly: false.into(),
+            ..Default::default()
+        };
+        unsafe { DwmUpdateThumbnailProperties(self.id, &properties)? };
+        Ok(())
+    }
+
+    pub fn hide(&self) -> Result<()> {
+        let properties = DWM_THUMBNAIL_PROPERTIES {
+            dwFlags: DWM_TNP_VISIBLE,
+            fVisible: false.into(),
+            ..Default::default()
+        };
+        unsafe { DwmUpdateThumbnailProperties(self.id, &properties)? };
+        Ok(())
+    }
+}
+
+impl Drop for DwmThumbnail {
+    fn drop(&mut self) {
+        log_error!(unsafe { DwmUnregisterThumbnail(self.id) });
+    }
+}
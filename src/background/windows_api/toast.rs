@@ -0,0 +1,111 @@
+use tauri::Manager;
+use windows::{
+    core::{IInspectable, Interface, HSTRING},
+    Data::Xml::Dom::XmlDocument,
+    Foundation::TypedEventHandler,
+    UI::Notifications::{ToastActivatedEventArgs, ToastNotification, ToastNotificationManager},
+};
+
+use crate::{error_handler::Result, seelen::get_app_handle};
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Native Windows toast notification sent as Seelen UI
+pub struct Toast;
+
+impl Toast {
+    pub fn show(message: &str) -> Result<()> {
+        Self::show_xml(format!(
+            concat!(
+                r#"<toast><visual><binding template="ToastGeneric">"#,
+                "<text>{}</text>",
+                "</binding></visual></toast>"
+            ),
+            escape_xml(message)
+        ))
+    }
+
+    /// The title is shown in bold above the message
+    pub fn show_titled(title: &str, message: &str) -> Result<()> {
+        Self::show_xml(format!(
+            concat!(
+                r#"<toast><visual><binding template="ToastGeneric">"#,
+                "<text>{}</text><text>{}</text>",
+                "</binding></visual></toast>"
+            ),
+            escape_xml(title),
+            escape_xml(message)
+        ))
+    }
+
+    /// Shows a button for each `(label, argument)` of `actions`, `on_action` receives the
+    /// argument of the pressed one. The buttons only work while this process is running.
+    pub fn show_with_actions<F>(
+        title: &str,
+        message: &str,
+        actions: &[(&str, &str)],
+        on_action: F,
+    ) -> Result<()>
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        let buttons = actions
+            .iter()
+            .map(|(label, argument)| {
+                format!(
+                    r#"<action content="{}" arguments="{}"/>"#,
+                    escape_xml(label),
+                    escape_xml(argument)
+                )
+            })
+            .collect::<String>();
+        let toast = Self::create(format!(
+            concat!(
+                r#"<toast><visual><binding template="ToastGeneric">"#,
+                "<text>{}</text><text>{}</text>",
+                "</binding></visual><actions>{}</actions></toast>"
+            ),
+            escape_xml(title),
+            escape_xml(message),
+            buttons
+        ))?;
+        toast.Activated(&TypedEventHandler::new(
+            move |_: &Option<ToastNotification>, args: &Option<IInspectable>| {
+                let args = args
+                    .as_ref()
+                    .and_then(|args| args.cast::<ToastActivatedEventArgs>().ok());
+                if let Some(args) = args {
+                    // empty when the toast itself is clicked
+                    let argument = args.Arguments()?.to_string();
+                    if !argument.is_empty() {
+                        on_action(argument);
+                    }
+                }
+                Ok(())
+            },
+        ))?;
+        Self::notify(&toast)
+    }
+
+    fn create(content: String) -> Result<ToastNotification> {
+        let xml = XmlDocument::new()?;
+        xml.LoadXml(&HSTRING::from(content))?;
+        Ok(ToastNotification::CreateToastNotification(&xml)?)
+    }
+
+    fn show_xml(content: String) -> Result<()> {
+        Self::notify(&Self::create(content)?)
+    }
+
+    fn notify(toast: &ToastNotification) -> Result<()> {
+        // the installer registers the app user model id of the shortcut as the app identifier
+        let app_id = HSTRING::from(&get_app_handle().config().identifier);
+        ToastNotificationManager::CreateToastNotifierWithId(&app_id)?.Show(toast)?;
+        Ok(())
+    }
+}
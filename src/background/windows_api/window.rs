@@ -3,11 +3,18 @@ use std::{
     path::PathBuf,
 };
 
-use windows::Win32::Foundation::HWND;
+use windows::Win32::{
+    Foundation::{HWND, RECT},
+    Graphics::Gdi::HMONITOR,
+    UI::WindowsAndMessaging::{MINMAXINFO, SHOW_WINDOW_CMD, WINDOW_EX_STYLE, WINDOW_STYLE},
+};
 
-use crate::error_handler::Result;
+use crate::{
+    error_handler::Result,
+    utils::constants::{OVERLAP_BLACK_LIST_BY_EXE, OVERLAP_BLACK_LIST_BY_TITLE},
+};
 
-use super::{WindowEnumerator, WindowsApi};
+use super::{backend::with_backend, WindowsApi};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Window(HWND);
@@ -41,16 +48,20 @@ impl Window {
         self.0
     }
 
+    pub fn foreground() -> Window {
+        Window(with_backend(|api| api.foreground()))
+    }
+
     pub fn title(&self) -> String {
-        WindowsApi::get_window_text(self.0)
+        with_backend(|api| api.title(self.0))
     }
 
     pub fn class(&self) -> String {
-        WindowsApi::get_class(self.0).unwrap_or_default()
+        with_backend(|api| api.class(self.0))
     }
 
     pub fn exe(&self) -> Result<PathBuf> {
-        WindowsApi::exe_path_v2(self.0)
+        with_backend(|api| api.exe(self.0))
     }
 
     pub fn app_display_name(&self) -> Result<String> {
@@ -58,7 +69,7 @@ impl Window {
     }
 
     pub fn parent(&self) -> Option<Window> {
-        let parent = WindowsApi::get_parent(self.0);
+        let parent = with_backend(|api| api.parent(self.0));
         if parent.0 != 0 {
             Some(Window(parent))
         } else {
@@ -67,13 +78,83 @@ impl Window {
     }
 
     pub fn children(&self) -> Result<Vec<Window>> {
-        WindowEnumerator::new()
-            .with_parent(self.0)
-            .map(Window::from)
+        let children = with_backend(|api| api.children(self.0))?;
+        Ok(children.into_iter().map(Window::from).collect())
     }
 
     pub fn is_visible(&self) -> bool {
-        WindowsApi::is_window_visible(self.0)
+        with_backend(|api| api.is_visible(self.0))
+    }
+
+    pub fn styles(&self) -> WINDOW_STYLE {
+        with_backend(|api| api.styles(self.0))
+    }
+
+    pub fn ex_styles(&self) -> WINDOW_EX_STYLE {
+        with_backend(|api| api.ex_styles(self.0))
+    }
+
+    pub fn rect(&self) -> RECT {
+        with_backend(|api| api.rect(self.0))
+    }
+
+    pub fn is_uwp_suspended(&self) -> bool {
+        with_backend(|api| api.is_uwp_suspended(self.0))
+    }
+
+    /// false if the handle was already destroyed
+    pub fn is_window(&self) -> bool {
+        with_backend(|api| api.is_window(self.0))
+    }
+
+    pub fn is_minimized(&self) -> bool {
+        with_backend(|api| api.is_iconic(self.0))
+    }
+
+    pub fn is_maximized(&self) -> bool {
+        with_backend(|api| api.is_maximized(self.0))
+    }
+
+    pub fn is_arranged(&self) -> bool {
+        with_backend(|api| api.is_arranged(self.0))
+    }
+
+    pub fn is_cloaked(&self) -> Result<bool> {
+        with_backend(|api| api.is_cloaked(self.0))
+    }
+
+    pub fn frame_rect(&self) -> RECT {
+        with_backend(|api| api.frame_rect(self.0))
+    }
+
+    pub fn shadow_rect(&self) -> Result<RECT> {
+        with_backend(|api| api.shadow_rect(self.0))
+    }
+
+    pub fn min_max_info(&self) -> Result<MINMAXINFO> {
+        with_backend(|api| api.min_max_info(self.0))
+    }
+
+    pub fn monitor(&self) -> HMONITOR {
+        with_backend(|api| api.monitor(self.0))
+    }
+
+    pub fn monitor_name(&self) -> Result<String> {
+        let monitor = self.monitor();
+        with_backend(|api| api.monitor_name(monitor))
+    }
+
+    pub fn work_area(&self) -> Result<RECT> {
+        let monitor = self.monitor();
+        with_backend(|api| api.work_area(monitor))
+    }
+
+    pub fn show_async(&self, command: SHOW_WINDOW_CMD) -> Result<()> {
+        with_backend(|api| api.show_window_async(self.0, command))
+    }
+
+    pub fn bring_to(&self, after: HWND) -> Result<()> {
+        with_backend(|api| api.bring_to(self.0, after))
     }
 
     /// is the window an Application Frame Host
@@ -98,6 +179,18 @@ impl Window {
         WindowsApi::get_desktop_window() == self.0 || self.class() == "Progman"
     }
 
+    /// visible windows that should auto hide the dock and toolbar when overlapping them
+    pub fn can_overlap(&self) -> bool {
+        let exe = self
+            .exe()
+            .ok()
+            .and_then(|path| Some(path.file_name()?.to_string_lossy().to_string()))
+            .unwrap_or_default();
+        self.is_visible()
+            && !OVERLAP_BLACK_LIST_BY_TITLE.contains(&self.title().as_str())
+            && !OVERLAP_BLACK_LIST_BY_EXE.contains(&exe.as_str())
+    }
+
     pub fn is_seelen_window(&self) -> bool {
         if let Ok(exe) = self.exe() {
             return exe.ends_with("seelen-ui.exe");
@@ -105,3 +198,45 @@ impl Window {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{MockDesktop, MockWindow},
+        utils::are_overlaped,
+        windows_api::backend::set_thread_backend,
+    };
+
+    #[test]
+    fn overlap_candidates() {
+        let desktop = MockDesktop::install();
+        let window =
+            |exe: &str, title: &str| Window::from(desktop.add(MockWindow::app(exe, title)));
+        let app = window("C:\\Apps\\app.exe", "App");
+        let search = window("C:\\Windows\\SystemApps\\SearchHost.exe", "Search");
+        let dock = window("C:\\Seelen\\seelen-ui.exe", "SeelenWeg");
+        let hidden = MockWindow::app("C:\\Apps\\app.exe", "Hidden").hidden();
+        let hidden = Window::from(desktop.add(hidden));
+
+        assert!(app.can_overlap());
+        assert!(!hidden.can_overlap());
+        assert!(!search.can_overlap());
+        assert!(!dock.can_overlap());
+        assert!(dock.is_seelen_window());
+        set_thread_backend(None);
+    }
+
+    #[test]
+    fn overlaped_rects() {
+        let rect = |left, top, right, bottom| RECT {
+            left,
+            top,
+            right,
+            bottom,
+        };
+        let dock = rect(0, 1000, 1920, 1080);
+        assert!(are_overlaped(&dock, &rect(0, 0, 1920, 1040)));
+        assert!(!are_overlaped(&dock, &rect(200, 200, 800, 600)));
+    }
+}